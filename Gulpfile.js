@@ -54,8 +54,9 @@ const watchLib = () => watch(["src/lib/**/*"], generateLibs);
 const diagnosticInformationMapTs = "src/compiler/diagnosticInformationMap.generated.ts";
 const diagnosticMessagesJson = "src/compiler/diagnosticMessages.json";
 const diagnosticMessagesGeneratedJson = "src/compiler/diagnosticMessages.generated.json";
+const diagnosticInformationMapRs = "native/src/compiler/diagnostic_information_map.rs";
 const generateDiagnostics = async () => {
-    if (needsUpdate(diagnosticMessagesJson, [diagnosticMessagesGeneratedJson, diagnosticInformationMapTs])) {
+    if (needsUpdate(diagnosticMessagesJson, [diagnosticMessagesGeneratedJson, diagnosticInformationMapTs, diagnosticInformationMapRs])) {
         await exec(process.execPath, ["scripts/processDiagnosticMessages.js", diagnosticMessagesJson]);
     }
 };
@@ -0,0 +1,260 @@
+// @ts-check
+// Measures the scanner functions of the addon from Node, where every call also pays for crossing
+// N-API, against the JS implementations the compiler falls back to, over the corpora of
+// `native/benches/scanner.rs`. Build both first, with `gulp local` and `npm run addon:build`,
+// then run `npm run addon:bench`, optionally with a part of the names of the benchmarks to run.
+const fs = require("fs");
+const path = require("path");
+const { loadAddon } = require("../test/loadAddon");
+
+const root = path.join(__dirname, "../..");
+const built = path.join(root, "built/local");
+
+const addon = loadAddon();
+/** @type {any} The scanner internals, like `getTokenFlags`, aren't in the public typings. */
+const ts = require(path.join(built, "typescript.js"));
+
+// The JS implementations of `computeLineStarts` and `lookupInUnicodeMap` in `scanner.ts`, which
+// the compiler only runs without the addon, so they are measured from copies.
+function computeLineStarts(text) {
+    const result = [];
+    let pos = 0;
+    let lineStart = 0;
+    while (pos < text.length) {
+        const ch = text.charCodeAt(pos);
+        pos++;
+        switch (ch) {
+            case 0x0D:
+                if (text.charCodeAt(pos) === 0x0A) {
+                    pos++;
+                }
+            // falls through
+            case 0x0A:
+            case 0x2028:
+            case 0x2029:
+                result.push(lineStart);
+                lineStart = pos;
+                break;
+        }
+    }
+    result.push(lineStart);
+    return result;
+}
+
+function lookupInUnicodeMap(code, map) {
+    if (code < map[0]) {
+        return false;
+    }
+    let lo = 0;
+    let hi = map.length;
+    let mid;
+    while (lo + 1 < hi) {
+        mid = lo + (hi - lo) / 2;
+        mid -= mid % 2;
+        if (map[mid] <= code && code <= map[mid + 1]) {
+            return true;
+        }
+        if (code < map[mid]) {
+            hi = mid;
+        }
+        else {
+            lo = mid + 2;
+        }
+    }
+    return false;
+}
+
+/** The ranges of `unicodeESNextIdentifierStart`, made like `scripts/regenerate-unicode-identifier-parts.js` does. */
+function createIdentifierStartMap() {
+    const isStart = c => /[\p{ID_Start}\u{2118}\u{212E}\u{309B}\u{309C}]/u.test(c);
+    const map = [];
+    let active = false;
+    for (let i = 0; i < 0x10FFFF; i++) {
+        if (isStart(String.fromCodePoint(i)) !== active) {
+            map.push(i - +active);
+            active = !active;
+        }
+    }
+    return map;
+}
+
+/** Whether a `/` after a token of the kind divides rather than starts a regular expression. */
+function endsExpression(kind) {
+    switch (kind) {
+        case ts.SyntaxKind.Identifier:
+        case ts.SyntaxKind.TemplateTail:
+        case ts.SyntaxKind.CloseParenToken:
+        case ts.SyntaxKind.CloseBracketToken:
+        case ts.SyntaxKind.CloseBraceToken:
+        case ts.SyntaxKind.ThisKeyword:
+        case ts.SyntaxKind.SuperKeyword:
+        case ts.SyntaxKind.NullKeyword:
+        case ts.SyntaxKind.TrueKeyword:
+        case ts.SyntaxKind.FalseKeyword:
+            return true;
+        default:
+            return ts.isLiteralKind(kind);
+    }
+}
+
+const isWordCharacter = ch => /[\p{L}\p{N}_$]/u.test(ch);
+
+/** The text like `minify` of `native/benches/scanner.rs` makes it: its tokens on a single line. */
+function minify(text) {
+    const scanner = ts.createScanner(ts.ScriptTarget.ESNext, /*skipTrivia*/ true, ts.LanguageVariant.Standard, text);
+    let result = "";
+    // The braces left open in each template substitution the scanner is in.
+    const templateBraces = [];
+    let previous = ts.SyntaxKind.Unknown;
+    for (let kind = scanner.scan(); kind !== ts.SyntaxKind.EndOfFileToken; kind = scanner.scan()) {
+        if (kind === ts.SyntaxKind.OpenBraceToken && templateBraces.length) {
+            templateBraces[templateBraces.length - 1]++;
+        }
+        else if (kind === ts.SyntaxKind.CloseBraceToken && templateBraces.length) {
+            if (templateBraces[templateBraces.length - 1] === 0) {
+                kind = scanner.reScanTemplateToken(/*isTaggedTemplate*/ false);
+                if (kind === ts.SyntaxKind.TemplateTail) {
+                    templateBraces.pop();
+                }
+            }
+            else {
+                templateBraces[templateBraces.length - 1]--;
+            }
+        }
+        else if ((kind === ts.SyntaxKind.SlashToken || kind === ts.SyntaxKind.SlashEqualsToken) && !endsExpression(previous)) {
+            kind = scanner.reScanSlashToken();
+        }
+        if (kind === ts.SyntaxKind.TemplateHead) {
+            templateBraces.push(0);
+        }
+        const tokenText = scanner.getTokenText();
+        const last = result.slice(-1);
+        const first = tokenText.charAt(0);
+        const runsTogether = isWordCharacter(last) && isWordCharacter(first) || !isWordCharacter(last) && last === first;
+        if (last && scanner.getStartPos() !== scanner.getTokenPos() && runsTogether) {
+            result += " ";
+        }
+        result += tokenText;
+        previous = kind;
+    }
+    return result;
+}
+
+function readCorpora() {
+    const libDirectory = path.join(root, "src/lib");
+    const lib = fs.readdirSync(libDirectory)
+        .filter(name => name.endsWith(".d.ts"))
+        .sort()
+        .map(name => fs.readFileSync(path.join(libDirectory, name), "utf8"))
+        .join("");
+    const checker = fs.readFileSync(path.join(root, "src/compiler/checker.ts"), "utf8");
+    return [
+        { name: "lib", text: lib },
+        { name: "checker.min", text: minify(checker) },
+        { name: "checker", text: checker },
+    ];
+}
+
+/** Scans the text up to the end of file token like the native `tokenize`, reading what it reports of each token. */
+function tokenize(text, skipTrivia) {
+    const scanner = ts.createScanner(ts.ScriptTarget.ESNext, skipTrivia, ts.LanguageVariant.Standard, text);
+    let count = 0;
+    let kind;
+    do {
+        kind = scanner.scan();
+        scanner.getStartPos();
+        scanner.getTokenPos();
+        scanner.getTokenFlags();
+        scanner.getTokenValue();
+        count++;
+    }
+    while (kind !== ts.SyntaxKind.EndOfFileToken);
+    return count;
+}
+
+/** The tokens of the native `tokenize` read into objects, like `decodeNativeTokens` does. */
+function tokenizeAndDecode(text, skipTrivia) {
+    const { tokens, values } = addon.tokenize(text, { skipTrivia });
+    const result = [];
+    for (let i = 0; i < tokens.length; i += 6) {
+        const valueIndex = tokens[i + 5];
+        result.push({
+            kind: tokens[i],
+            pos: tokens[i + 1],
+            start: tokens[i + 2],
+            end: tokens[i + 3],
+            flags: tokens[i + 4],
+            value: valueIndex === 0xFFFFFFFF ? undefined : values[valueIndex],
+        });
+    }
+    return result;
+}
+
+/** The median time of a run in milliseconds, over as many runs as fit in a second, after a warm-up run. */
+function measure(run) {
+    run();
+    const samples = [];
+    const deadline = Date.now() + 1000;
+    do {
+        const start = process.hrtime.bigint();
+        run();
+        samples.push(Number(process.hrtime.bigint() - start) / 1e6);
+    }
+    while (Date.now() < deadline || samples.length < 5);
+    samples.sort((a, b) => a - b);
+    return samples[samples.length >> 1];
+}
+
+function main() {
+    const filter = process.argv[2] || "";
+    const corpora = readCorpora();
+    const identifierStart = createIdentifierStartMap();
+    // Every 101st code point past ASCII, like the lookups of `native/benches/scanner.rs`.
+    const codePoints = [];
+    for (let code = 0x80; code <= 0x10FFFF; code += 101) {
+        codePoints.push(code);
+    }
+
+    /** @type {{ name: string, js: () => unknown, native: () => unknown }[]} */
+    const benchmarks = [];
+    for (const { name, text } of corpora) {
+        benchmarks.push({
+            name: `computeLineStarts/${name}`,
+            js: () => computeLineStarts(text),
+            native: () => addon.computeLineStarts(text),
+        });
+    }
+    benchmarks.push({
+        name: "lookupInUnicodeMap/esnext_identifier_start",
+        js: () => codePoints.filter(code => lookupInUnicodeMap(code, identifierStart)).length,
+        native: () => codePoints.filter(code => addon.lookupInUnicodeMap(code, identifierStart)).length,
+    });
+    for (const { name, text } of corpora) {
+        for (const skipTrivia of [true, false]) {
+            const mode = skipTrivia ? "skip_trivia" : "with_trivia";
+            benchmarks.push({
+                name: `tokenize/${mode}/${name}`,
+                js: () => tokenize(text, skipTrivia),
+                native: () => addon.tokenize(text, { skipTrivia }),
+            });
+            benchmarks.push({
+                name: `tokenize+decode/${mode}/${name}`,
+                js: () => tokenize(text, skipTrivia),
+                native: () => tokenizeAndDecode(text, skipTrivia),
+            });
+        }
+    }
+
+    const format = ms => `${ms.toFixed(3)} ms`.padStart(14);
+    console.log(`${"benchmark".padEnd(48)}${"js".padStart(14)}${"native".padStart(14)}   native/js`);
+    for (const { name, js, native } of benchmarks) {
+        if (!name.includes(filter)) {
+            continue;
+        }
+        const jsTime = measure(js);
+        const nativeTime = measure(native);
+        console.log(`${name.padEnd(48)}${format(jsTime)}${format(nativeTime)}   ${(nativeTime / jsTime).toFixed(2)}x`);
+    }
+}
+
+main();
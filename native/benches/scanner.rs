@@ -0,0 +1,265 @@
+// Benchmarks of the scanner functions the addon exports, over the sources of the compiler: the
+// declaration files of `src/lib`, `checker.ts`, and `checker.ts` minified into a single line.
+// Run them with `cargo bench` from `native`; `native/bench/scanner.js` measures the same calls
+// from Node, where they also pay for crossing N-API, against the JS implementations.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use typescript::compiler::scanner::{
+    compute_line_starts, create_scanner, is_identifier_part, is_identifier_start, tokenize, Scanner,
+};
+use typescript::compiler::types::{
+    KeywordSyntaxKind, LanguageVariant, PseudoLiteralSyntaxKind, PunctuationSyntaxKind,
+    ScriptTarget, SyntaxKind, TokenSyntaxKind,
+};
+use typescript::compiler::unicode::{
+    lookup_in_unicode_map, UNICODE_ES5_IDENTIFIER_START, UNICODE_ESNEXT_IDENTIFIER_PART,
+    UNICODE_ESNEXT_IDENTIFIER_START,
+};
+
+struct Corpus {
+    name: &'static str,
+    text: String,
+}
+
+fn repository_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(path)
+}
+
+fn read_file(path: &Path) -> String {
+    fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("Cannot read file '{}': {}", path.display(), error))
+}
+
+// All the declaration files of the default libraries, one after the other.
+fn read_lib_files() -> String {
+    let mut paths: Vec<PathBuf> = fs::read_dir(repository_path("src/lib"))
+        .expect("src/lib can be read")
+        .map(|entry| entry.expect("src/lib can be read").path())
+        .filter(|path| path.to_string_lossy().ends_with(".d.ts"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| read_file(path)).collect()
+}
+
+// Whether a `/` after the token divides rather than starts a regular expression.
+fn ends_expression(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+            | SyntaxKind::Literals(_)
+            | SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail)
+            | SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseParenToken)
+            | SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBracketToken)
+            | SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken)
+            | SyntaxKind::Keywords(KeywordSyntaxKind::ThisKeyword)
+            | SyntaxKind::Keywords(KeywordSyntaxKind::SuperKeyword)
+            | SyntaxKind::Keywords(KeywordSyntaxKind::NullKeyword)
+            | SyntaxKind::Keywords(KeywordSyntaxKind::TrueKeyword)
+            | SyntaxKind::Keywords(KeywordSyntaxKind::FalseKeyword)
+    )
+}
+
+fn is_word_character(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '$'
+}
+
+// The tokens of the text without its comments and line breaks, separated by a space only where
+// they would otherwise run together, like the output of a minifier. Templates and regular
+// expressions are rescanned like the parser does, so that their text is kept whole.
+fn minify(text: &str) -> String {
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let mut scanner = create_scanner(
+        ScriptTarget::ESNext,
+        true,
+        Some(LanguageVariant::Standard),
+        Some(text.to_string()),
+        None,
+        None,
+        None,
+    );
+    let mut result = String::with_capacity(text.len() / 2);
+    // The braces left open in each template substitution the scanner is in.
+    let mut template_braces: Vec<usize> = Vec::new();
+    let mut previous = SyntaxKind::Tokens(TokenSyntaxKind::Unknown);
+    loop {
+        let mut kind = scanner.scan();
+        match kind {
+            SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken) => break,
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken) => {
+                if let Some(braces) = template_braces.last_mut() {
+                    *braces += 1;
+                }
+            }
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken) => {
+                match template_braces.last_mut() {
+                    Some(0) => {
+                        kind = scanner.re_scan_template_token(false);
+                        if kind == SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail)
+                        {
+                            template_braces.pop();
+                        }
+                    }
+                    Some(braces) => *braces -= 1,
+                    None => {}
+                }
+            }
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashToken)
+            | SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashEqualsToken)
+                if !ends_expression(previous) =>
+            {
+                kind = scanner.re_scan_slash_token();
+            }
+            _ => {}
+        }
+        if kind == SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateHead) {
+            template_braces.push(0);
+        }
+        let token_text =
+            String::from_utf16_lossy(&units[scanner.get_token_pos()..scanner.get_text_pos()]);
+        if let (Some(last), Some(first)) = (result.chars().last(), token_text.chars().next()) {
+            let runs_together = is_word_character(last) && is_word_character(first)
+                || !is_word_character(last) && last == first;
+            if scanner.get_start_pos() != scanner.get_token_pos() && runs_together {
+                result.push(' ');
+            }
+        }
+        result.push_str(&token_text);
+        previous = kind;
+    }
+    result
+}
+
+fn read_corpora() -> Vec<Corpus> {
+    let checker = read_file(&repository_path("src/compiler/checker.ts"));
+    vec![
+        Corpus {
+            name: "lib",
+            text: read_lib_files(),
+        },
+        Corpus {
+            name: "checker.min",
+            text: minify(&checker),
+        },
+        Corpus {
+            name: "checker",
+            text: checker,
+        },
+    ]
+}
+
+fn bench_line_starts(c: &mut Criterion, corpora: &[Corpus]) {
+    let mut group = c.benchmark_group("compute_line_starts");
+    for corpus in corpora {
+        group.throughput(Throughput::Bytes(corpus.text.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(corpus.name),
+            &corpus.text,
+            |b, text| b.iter(|| compute_line_starts(black_box(text))),
+        );
+    }
+    group.finish();
+}
+
+// Classifies every character of the texts, like the scanner does for the characters of an
+// identifier, and looks code points outside of ASCII up in the identifier tables, which is the
+// part of it `lookupInUnicodeMap` does.
+fn bench_identifier_classification(c: &mut Criterion, corpora: &[Corpus]) {
+    let mut group = c.benchmark_group("identifier_classification");
+    for corpus in corpora {
+        let code_points: Vec<u32> = corpus.text.chars().map(u32::from).collect();
+        group.throughput(Throughput::Elements(code_points.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("is_identifier_start", corpus.name),
+            &code_points,
+            |b, code_points| {
+                b.iter(|| {
+                    code_points
+                        .iter()
+                        .filter(|&&ch| is_identifier_start(black_box(ch), ScriptTarget::ESNext))
+                        .count()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("is_identifier_part", corpus.name),
+            &code_points,
+            |b, code_points| {
+                b.iter(|| {
+                    code_points
+                        .iter()
+                        .filter(|&&ch| {
+                            is_identifier_part(black_box(ch), ScriptTarget::ESNext, None)
+                        })
+                        .count()
+                })
+            },
+        );
+    }
+    // Every 101st code point past ASCII, so the lookups land all over the tables.
+    let code_points: Vec<u32> = (0x80..=0x10FFFF).step_by(101).collect();
+    group.throughput(Throughput::Elements(code_points.len() as u64));
+    for &(name, map) in &[
+        ("es5_identifier_start", UNICODE_ES5_IDENTIFIER_START),
+        ("esnext_identifier_start", UNICODE_ESNEXT_IDENTIFIER_START),
+        ("esnext_identifier_part", UNICODE_ESNEXT_IDENTIFIER_PART),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::new("lookup_in_unicode_map", name),
+            &code_points,
+            |b, code_points| {
+                b.iter(|| {
+                    code_points
+                        .iter()
+                        .filter(|&&code| lookup_in_unicode_map(black_box(code), map))
+                        .count()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_tokenize(c: &mut Criterion, corpora: &[Corpus]) {
+    let mut group = c.benchmark_group("tokenize");
+    group.sample_size(20);
+    for corpus in corpora {
+        group.throughput(Throughput::Bytes(corpus.text.len() as u64));
+        for &(mode, skip_trivia) in &[("skip_trivia", true), ("with_trivia", false)] {
+            group.bench_with_input(
+                BenchmarkId::new(mode, corpus.name),
+                &corpus.text,
+                |b, text| {
+                    b.iter_batched(
+                        || text.clone(),
+                        |text| {
+                            tokenize(
+                                text,
+                                ScriptTarget::ESNext,
+                                LanguageVariant::Standard,
+                                skip_trivia,
+                                None,
+                            )
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+fn scanner_benches(c: &mut Criterion) {
+    let corpora = read_corpora();
+    bench_line_starts(c, &corpora);
+    bench_identifier_classification(c, &corpora);
+    bench_tokenize(c, &corpora);
+}
+
+criterion_group!(benches, scanner_benches);
+criterion_main!(benches);
@@ -1,5 +1,4 @@
-use napi_build;
-
 fn main() {
-  napi_build::setup();
-}
\ No newline at end of file
+    #[cfg(feature = "napi")]
+    napi_build::setup();
+}
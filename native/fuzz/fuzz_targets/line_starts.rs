@@ -0,0 +1,67 @@
+#![no_main]
+// Checks `compute_line_starts` against what the line map of a text is to the JS compiler: the
+// first line starts at 0, and every other one right after a line break, counting CRLF as one,
+// with positions counted in UTF-16 code units.
+
+use libfuzzer_sys::fuzz_target;
+use typescript::compiler::scanner::{compute_line_starts, is_line_break};
+
+const CARRIAGE_RETURN: u16 = 0x0D;
+const LINE_FEED: u16 = 0x0A;
+
+fuzz_target!(|data: &[u8]| {
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let line_starts = compute_line_starts(text);
+    assert_eq!(
+        line_starts.first(),
+        Some(&0),
+        "the first line doesn't start at 0 in {:?}",
+        text
+    );
+    for pair in line_starts.windows(2) {
+        assert!(
+            pair[0] < pair[1],
+            "the line starts {:?} aren't increasing in {:?}",
+            pair,
+            text
+        );
+    }
+    for &line_start in &line_starts[1..] {
+        assert!(
+            line_start <= units.len(),
+            "the line start {} is past the end of {:?}",
+            line_start,
+            text
+        );
+        let before = units[line_start - 1];
+        assert!(
+            is_line_break(u32::from(before)),
+            "the line start {} doesn't follow a line break in {:?}",
+            line_start,
+            text
+        );
+        let splits_crlf = before == CARRIAGE_RETURN && units.get(line_start) == Some(&LINE_FEED);
+        assert!(
+            !splits_crlf,
+            "the line start {} splits a CRLF in {:?}",
+            line_start, text
+        );
+    }
+    let line_breaks = units
+        .iter()
+        .enumerate()
+        .filter(|&(index, &unit)| {
+            is_line_break(u32::from(unit)) && !(unit == CARRIAGE_RETURN && units.get(index + 1) == Some(&LINE_FEED))
+        })
+        .count();
+    assert_eq!(
+        line_starts.len(),
+        line_breaks + 1,
+        "lines are missing from the line starts of {:?}",
+        text
+    );
+});
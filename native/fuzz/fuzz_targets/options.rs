@@ -0,0 +1,39 @@
+// The scanner options of the scanner targets, picked by the first byte of the input so that the
+// fuzzer explores every language variant, script target and trivia mode.
+
+use typescript::compiler::types::{LanguageVariant, ScriptTarget};
+
+const TARGETS: &[ScriptTarget] = &[
+    ScriptTarget::ES3,
+    ScriptTarget::ES5,
+    ScriptTarget::ES2015,
+    ScriptTarget::ES2016,
+    ScriptTarget::ES2017,
+    ScriptTarget::ES2018,
+    ScriptTarget::ES2019,
+    ScriptTarget::ES2020,
+    ScriptTarget::ES2021,
+    ScriptTarget::ESNext,
+];
+
+pub struct ScannerOptions {
+    pub language_version: ScriptTarget,
+    pub language_variant: LanguageVariant,
+    pub skip_trivia: bool,
+}
+
+/// The options of the first byte of the input, and the text of the rest, if it is UTF-8.
+pub fn split_input(data: &[u8]) -> Option<(ScannerOptions, &str)> {
+    let (&options, text) = data.split_first()?;
+    let text = std::str::from_utf8(text).ok()?;
+    let options = ScannerOptions {
+        language_version: TARGETS[usize::from(options >> 2) % TARGETS.len()],
+        language_variant: if options & 1 == 0 {
+            LanguageVariant::Standard
+        } else {
+            LanguageVariant::JSX
+        },
+        skip_trivia: options & 2 == 0,
+    };
+    Some((options, text))
+}
@@ -0,0 +1,139 @@
+#![no_main]
+// Drives the scanner the way the parser does, mixing `scan` with the JSX, JSDoc and template scans
+// and rescans in the order the input picks, then scans the rest of the text. The input is a count
+// of operations, the operations, then the options byte of `options` and the text. Checks that no
+// operation panics or moves the scanner back past its token or past the end of the text, and that
+// the scanner still reaches an `EndOfFileToken` at the end of the text.
+
+mod options;
+
+use libfuzzer_sys::fuzz_target;
+use typescript::compiler::scanner::{create_scanner, Scanner, TextScanner};
+use typescript::compiler::types::{
+    LiteralSyntaxKind, PseudoLiteralSyntaxKind, PunctuationSyntaxKind, SyntaxKind, TokenSyntaxKind,
+};
+
+// Runs an operation, the low bits of the byte picking which one and the high bit being its
+// flag. The rescans that only apply to one token are skipped on the others, like the parser does.
+fn run_operation(scanner: &mut TextScanner, operation: u8) {
+    let flag = operation & 0x80 != 0;
+    let token = scanner.get_token();
+    match operation & 0x7F {
+        0 => {
+            scanner.scan_jsx_token();
+        }
+        1 => {
+            scanner.re_scan_jsx_token(Some(flag));
+        }
+        2 => {
+            scanner.scan_jsx_identifier();
+        }
+        3 => {
+            scanner.scan_jsx_attribute_value();
+        }
+        4 => {
+            scanner.re_scan_jsx_attribute_value();
+        }
+        5 => {
+            scanner.re_scan_less_than_token();
+        }
+        6 => {
+            scanner.re_scan_greater_token();
+        }
+        7 => {
+            scanner.re_scan_slash_token();
+        }
+        8 => {
+            scanner.re_scan_hash_token();
+        }
+        9 if token == SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral)
+            || token == SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateHead) =>
+        {
+            scanner.re_scan_template_head_or_no_substitution_template();
+        }
+        10 if token == SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken) => {
+            scanner.re_scan_template_token(flag);
+        }
+        11 if token == SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskEqualsToken) => {
+            scanner.re_scan_asterisk_equals_token();
+        }
+        12 if token == SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionQuestionToken) => {
+            scanner.re_scan_question_token();
+        }
+        13 if token == SyntaxKind::Tokens(TokenSyntaxKind::Unknown) => {
+            scanner.re_scan_invalid_identifier();
+        }
+        14 => {
+            scanner.scan_js_doc_token();
+        }
+        15 => scanner.set_in_jsdoc_type(flag),
+        _ => {
+            scanner.scan();
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let (&count, rest) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    if rest.len() < usize::from(count) + 1 {
+        return;
+    }
+    let (options, text) = match options::split_input(&rest[usize::from(count)..]) {
+        Some(input) => input,
+        None => return,
+    };
+    let operations = &rest[..usize::from(count)];
+    let length = text.encode_utf16().count();
+    let mut scanner = create_scanner(
+        options.language_version,
+        options.skip_trivia,
+        Some(options.language_variant),
+        Some(text.to_string()),
+        None,
+        None,
+        None,
+    );
+    // Like the parser, start at the first token; the rescans apply to a token that was scanned.
+    scanner.scan();
+    // A rescan can go back to the trivia before the token, but not to the tokens before it. The
+    // token can start before its trivia though: like in JS, `scan_jsx_attribute_value` leaves the
+    // token position of the `=` before a string.
+    let mut start_pos = 0;
+    for &operation in operations {
+        run_operation(&mut scanner, operation);
+        assert!(
+            scanner.get_start_pos() >= start_pos,
+            "operation {} moved the scanner back past its token in {:?}",
+            operation,
+            text
+        );
+        assert!(
+            scanner.get_start_pos() <= scanner.get_text_pos() && scanner.get_token_pos() <= scanner.get_text_pos(),
+            "operation {} inverted the token in {:?}",
+            operation,
+            text
+        );
+        assert!(
+            scanner.get_text_pos() <= length,
+            "operation {} scanned past the end of {:?}",
+            operation,
+            text
+        );
+        start_pos = scanner.get_start_pos();
+    }
+    // Every token of the rest takes up some of the text, so there are at most as many as it has.
+    let mut remaining = length - scanner.get_text_pos() + 1;
+    while scanner.scan() != SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken) {
+        assert!(remaining > 0, "scanning {:?} doesn't reach the end of the file", text);
+        remaining -= 1;
+    }
+    assert_eq!(
+        scanner.get_text_pos(),
+        length,
+        "scanning {:?} ended before the end of the text",
+        text
+    );
+});
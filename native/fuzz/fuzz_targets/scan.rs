@@ -0,0 +1,87 @@
+#![no_main]
+// Scans the input with `tokenize`, which is what the addon's `tokenize` does, and checks that
+// scanning ends at an `EndOfFileToken` at the end of the text, that every other token takes up
+// some of the text, that each token starts where the one before it ends, and that with trivia
+// kept the tokens tile the text. Like the JS scanner, the scanner skips the line separators and
+// the spaces outside of ASCII it has no case for even then, so those are the only trivia a token
+// can have before it.
+
+mod options;
+
+use libfuzzer_sys::fuzz_target;
+use typescript::compiler::scanner::{is_white_space_like, tokenize};
+use typescript::compiler::types::{SyntaxKind, TokenSyntaxKind};
+
+fuzz_target!(|data: &[u8]| {
+    let (options, text) = match options::split_input(data) {
+        Some(input) => input,
+        None => return,
+    };
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let length = units.len();
+    let tokenization = tokenize(
+        text.to_string(),
+        options.language_version,
+        options.language_variant,
+        options.skip_trivia,
+        None,
+    )
+    .expect("tokenizing without a cancellation token can't be canceled");
+    let (last, tokens) = tokenization
+        .tokens
+        .split_last()
+        .expect("there is always an end of file token");
+    assert_eq!(
+        last.kind,
+        SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+        "scanning {:?} didn't end at the end of the file",
+        text
+    );
+    assert_eq!(last.end, length, "scanning {:?} ended before the end of the text", text);
+    let mut end = 0;
+    for token in tokenization.tokens.iter() {
+        assert_eq!(
+            token.pos, end,
+            "the {:?} at {} doesn't start where the token before it ends in {:?}",
+            token.kind, token.start, text
+        );
+        assert!(
+            token.pos <= token.start && token.start <= token.end,
+            "the {:?} at {} has an inverted range in {:?}",
+            token.kind,
+            token.start,
+            text
+        );
+        if !options.skip_trivia {
+            let trivia = &units[token.pos..token.start];
+            assert!(
+                trivia.iter().all(|&unit| unit > 0x7F && is_white_space_like(u32::from(unit))),
+                "the {:?} at {} has trivia before it in {:?}",
+                token.kind,
+                token.start,
+                text
+            );
+        }
+        end = token.end;
+    }
+    for token in tokens {
+        assert!(
+            token.start < token.end,
+            "the {:?} at {} is empty in {:?}",
+            token.kind,
+            token.start,
+            text
+        );
+    }
+    for diagnostic in &tokenization.diagnostics {
+        let start = diagnostic.start.unwrap_or_default();
+        let end = start + diagnostic.length.unwrap_or_default();
+        assert!(
+            end <= length,
+            "the error '{}' at {} ends past the end of {:?}",
+            diagnostic.message_text,
+            start,
+            text
+        );
+    }
+});
@@ -0,0 +1,54 @@
+#![no_main]
+// Checks the binary search of `lookup_in_unicode_map` against a linear search through the
+// ranges, over maps made of the input and over the identifier tables of the scanner. The input
+// is little-endian `u32`s: the code point to look up, then the bounds of the ranges of a map.
+
+use libfuzzer_sys::fuzz_target;
+use typescript::compiler::unicode::{
+    lookup_in_unicode_map, UNICODE_ES3_IDENTIFIER_PART, UNICODE_ES3_IDENTIFIER_START, UNICODE_ES5_IDENTIFIER_PART,
+    UNICODE_ES5_IDENTIFIER_START, UNICODE_ESNEXT_IDENTIFIER_PART, UNICODE_ESNEXT_IDENTIFIER_START,
+};
+
+const TABLES: &[&[u32]] = &[
+    UNICODE_ES3_IDENTIFIER_START,
+    UNICODE_ES3_IDENTIFIER_PART,
+    UNICODE_ES5_IDENTIFIER_START,
+    UNICODE_ES5_IDENTIFIER_PART,
+    UNICODE_ESNEXT_IDENTIFIER_START,
+    UNICODE_ESNEXT_IDENTIFIER_PART,
+];
+
+fn is_in_ranges(code: u32, map: &[u32]) -> bool {
+    map.chunks_exact(2).any(|range| range[0] <= code && code <= range[1])
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut numbers = data
+        .chunks_exact(4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    let code = match numbers.next() {
+        Some(code) => code,
+        None => return,
+    };
+    let mut map: Vec<u32> = numbers.collect();
+    // Any map, even an empty, unsorted or odd one, can be looked up in without panicking.
+    lookup_in_unicode_map(code, &map);
+    // Sorting the bounds makes ranges that are in order and don't overlap, like the tables.
+    map.sort_unstable();
+    map.truncate(map.len() / 2 * 2);
+    assert_eq!(
+        lookup_in_unicode_map(code, &map),
+        is_in_ranges(code, &map),
+        "{} in {:?}",
+        code,
+        map
+    );
+    for table in TABLES {
+        assert_eq!(
+            lookup_in_unicode_map(code, table),
+            is_in_ranges(code, table),
+            "{} in a table",
+            code
+        );
+    }
+});
@@ -0,0 +1,226 @@
+// Prints the tokens the native scanner reads from a file, for comparing them with the ones of
+// the JS scanner. Each token is printed with the name of its `ts.SyntaxKind`, its range, its
+// flags and its value, as text or as JSON lines.
+
+use std::fmt::Write as _;
+use std::io::{self, BufWriter, Write as _};
+use std::process;
+
+use typescript::compiler::scanner::{
+    compute_line_of_position, compute_line_starts, format_syntax_kind, format_token_flags,
+    get_name_of_script_target, tokenize, Token,
+};
+use typescript::compiler::sys::LocalFileSystem;
+use typescript::compiler::types::{Diagnostic, LanguageVariant, ParseConfigHost, ScriptTarget};
+use typescript::compiler::utilities::{get_language_variant, get_script_kind_from_file_name};
+
+const USAGE: &str = "Usage: ts-tokens [options] <file>
+
+Prints the tokens the scanner reads from a .ts, .tsx, .js or .jsx file.
+
+Options:
+  --target <target>   The script target to scan for: es3, es5, es2015 ... es2021 or esnext
+                      (the default)
+  --jsx               Scan for the JSX variant of the language, the default for .tsx, .js and
+                      .jsx files
+  --no-jsx            Scan for the standard variant of the language
+  --trivia            Print whitespace, newline and comment tokens too
+  --json              Print a JSON object per token rather than a line of text
+  -h, --help          Print this message";
+
+const TARGETS: &[ScriptTarget] = &[
+    ScriptTarget::ES3,
+    ScriptTarget::ES5,
+    ScriptTarget::ES2015,
+    ScriptTarget::ES2016,
+    ScriptTarget::ES2017,
+    ScriptTarget::ES2018,
+    ScriptTarget::ES2019,
+    ScriptTarget::ES2020,
+    ScriptTarget::ES2021,
+    ScriptTarget::ESNext,
+];
+
+struct Options {
+    file_name: String,
+    language_version: ScriptTarget,
+    language_variant: Option<LanguageVariant>,
+    include_trivia: bool,
+    json: bool,
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("ts-tokens: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_target(name: &str) -> Option<ScriptTarget> {
+    match name.to_lowercase().as_str() {
+        "es6" => Some(ScriptTarget::ES2015),
+        "latest" => Some(ScriptTarget::ESNext),
+        name => TARGETS
+            .iter()
+            .copied()
+            .find(|&target| get_name_of_script_target(target) == name),
+    }
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Options {
+    let mut file_name = None;
+    let mut language_version = ScriptTarget::ESNext;
+    let mut language_variant = None;
+    let mut include_trivia = false;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            "--target" => {
+                let target = args
+                    .next()
+                    .unwrap_or_else(|| fail("--target expects a script target"));
+                language_version = parse_target(&target)
+                    .unwrap_or_else(|| fail(&format!("Unknown script target '{}'", target)));
+            }
+            "--jsx" => language_variant = Some(LanguageVariant::JSX),
+            "--no-jsx" => language_variant = Some(LanguageVariant::Standard),
+            "--trivia" => include_trivia = true,
+            "--json" => json = true,
+            _ if arg.starts_with('-') => fail(&format!("Unknown option '{}'", arg)),
+            _ if file_name.is_some() => fail("Expected a single file"),
+            _ => file_name = Some(arg),
+        }
+    }
+    Options {
+        file_name: file_name.unwrap_or_else(|| fail("Expected a file")),
+        language_version,
+        language_variant,
+        include_trivia,
+        json,
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => write!(result, "\\u{:04x}", ch as u32).unwrap(),
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn format_token_text(token: &Token) -> String {
+    let mut line = format!(
+        "{}..{} {}",
+        token.start,
+        token.end,
+        format_syntax_kind(token.kind)
+    );
+    if token.pos != token.start {
+        write!(line, " (trivia from {})", token.pos).unwrap();
+    }
+    let flags = format_token_flags(token.flags);
+    if !flags.is_empty() {
+        write!(line, " [{}]", flags.join(", ")).unwrap();
+    }
+    if let Some(value) = &token.value {
+        write!(line, " {}", json_string(value)).unwrap();
+    }
+    line
+}
+
+fn format_token_json(token: &Token) -> String {
+    let flags = format_token_flags(token.flags)
+        .iter()
+        .map(|name| json_string(name))
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut line = format!(
+        "{{\"kind\":{},\"kindValue\":{},\"pos\":{},\"start\":{},\"end\":{},\"flags\":{},\"flagNames\":[{}]",
+        json_string(&format_syntax_kind(token.kind)),
+        token.kind.js_value(),
+        token.pos,
+        token.start,
+        token.end,
+        token.flags,
+        flags,
+    );
+    if let Some(value) = &token.value {
+        write!(line, ",\"value\":{}", json_string(value)).unwrap();
+    }
+    line.push('}');
+    line
+}
+
+// Like `formatDiagnostic` of the JS compiler: `file(line,character): error TS1234: message`, with
+// the line and character of the start counted from 1.
+fn format_diagnostic(file_name: &str, line_starts: &[usize], diagnostic: &Diagnostic) -> String {
+    let start = diagnostic.start.unwrap_or_default();
+    let line = compute_line_of_position(line_starts, start);
+    format!(
+        "{}({},{}): error TS{}: {}",
+        file_name,
+        line + 1,
+        start - line_starts[line] + 1,
+        diagnostic.code,
+        diagnostic.message_text
+    )
+}
+
+fn main() {
+    let options = parse_options(std::env::args().skip(1));
+    let text = LocalFileSystem
+        .read_file(&options.file_name)
+        .unwrap_or_else(|error| {
+            eprintln!(
+                "ts-tokens: Cannot read file '{}': {}",
+                options.file_name, error
+            );
+            process::exit(1);
+        });
+    let language_variant = options.language_variant.unwrap_or_else(|| {
+        get_language_variant(get_script_kind_from_file_name(&options.file_name))
+    });
+    let line_starts = compute_line_starts(&text);
+    let tokenization = tokenize(
+        text,
+        options.language_version,
+        language_variant,
+        !options.include_trivia,
+        None,
+    )
+    .expect("tokenizing without a cancellation token can't be canceled");
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for token in &tokenization.tokens {
+        let line = if options.json {
+            format_token_json(token)
+        } else {
+            format_token_text(token)
+        };
+        // Stop quietly when the reader goes away, like when the output is piped into `head`.
+        if writeln!(out, "{}", line).is_err() {
+            return;
+        }
+    }
+    if out.flush().is_err() {
+        return;
+    }
+    for diagnostic in &tokenization.diagnostics {
+        eprintln!(
+            "{}",
+            format_diagnostic(&options.file_name, &line_starts, diagnostic)
+        );
+    }
+}
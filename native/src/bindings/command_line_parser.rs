@@ -0,0 +1,204 @@
+use napi::{CallContext, Env, JsObject, JsString, JsUnknown, Result};
+use napi_derive::js_function;
+
+use super::create_diagnostics_array;
+use crate::compiler::command_line_parser::{
+    get_parsed_command_line_of_config_file, ExtendedConfigCache,
+};
+use crate::compiler::path::normalize_slashes;
+use crate::compiler::sys::LocalFileSystem;
+use crate::compiler::types::{CompilerOptions, Diagnostic, JsonValue, ParsedCommandLine};
+
+// Converts an option value to a plain JS value.
+fn create_js_value(env: &Env, value: &Option<JsonValue>) -> Result<JsUnknown> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(env.get_undefined()?.into_unknown()),
+    };
+    Ok(match value {
+        JsonValue::Object(properties) => {
+            let mut object = env.create_object()?;
+            for property in properties {
+                object.set_named_property(
+                    &property.name,
+                    create_js_value(env, &Some(property.initializer.value.clone()))?,
+                )?;
+            }
+            object.into_unknown()
+        }
+        JsonValue::Array(elements) => {
+            let mut array = env.create_array_with_length(elements.len())?;
+            for (index, element) in elements.iter().enumerate() {
+                array.set_element(
+                    index as u32,
+                    create_js_value(env, &Some(element.value.clone()))?,
+                )?;
+            }
+            array.into_unknown()
+        }
+        JsonValue::String(value) => env.create_string(value)?.into_unknown(),
+        JsonValue::Number(value) => env.create_double(*value)?.into_unknown(),
+        JsonValue::Boolean(value) => env.get_boolean(*value)?.into_unknown(),
+        JsonValue::Null => env.get_null()?.into_unknown(),
+    })
+}
+
+fn create_options_object(env: &Env, options: &CompilerOptions) -> Result<JsObject> {
+    let mut object = env.create_object()?;
+    for (name, value) in options {
+        object.set_named_property(name, create_js_value(env, value)?)?;
+    }
+    Ok(object)
+}
+
+fn create_string_array(env: &Env, strings: &[String]) -> Result<JsObject> {
+    let mut array = env.create_array_with_length(strings.len())?;
+    for (index, string) in strings.iter().enumerate() {
+        array.set_element(index as u32, env.create_string(string)?)?;
+    }
+    Ok(array)
+}
+
+fn set_optional_string_array(
+    env: &Env,
+    object: &mut JsObject,
+    name: &str,
+    strings: &Option<Vec<String>>,
+) -> Result<()> {
+    if let Some(strings) = strings {
+        object.set_named_property(name, create_string_array(env, strings)?)?;
+    }
+    Ok(())
+}
+
+fn create_parsed_command_line_object(
+    env: &Env,
+    parsed: &std::result::Result<ParsedCommandLine, Diagnostic>,
+) -> Result<JsObject> {
+    let mut result = env.create_object()?;
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(diagnostic) => {
+            result.set_named_property(
+                "errors",
+                create_diagnostics_array(env, std::slice::from_ref(diagnostic))?,
+            )?;
+            return Ok(result);
+        }
+    };
+
+    result.set_named_property("options", create_options_object(env, &parsed.options)?)?;
+    if let Some(watch_options) = &parsed.watch_options {
+        result.set_named_property("watchOptions", create_options_object(env, watch_options)?)?;
+    }
+    if let Some(project_references) = &parsed.project_references {
+        let mut array = env.create_array_with_length(project_references.len())?;
+        for (index, reference) in project_references.iter().enumerate() {
+            let mut object = env.create_object()?;
+            object.set_named_property("path", env.create_string(&reference.path)?)?;
+            object
+                .set_named_property("originalPath", env.create_string(&reference.original_path)?)?;
+            if let Some(prepend) = reference.prepend {
+                object.set_named_property("prepend", env.get_boolean(prepend)?)?;
+            }
+            if let Some(circular) = reference.circular {
+                object.set_named_property("circular", env.get_boolean(circular)?)?;
+            }
+            array.set_element(index as u32, object)?;
+        }
+        result.set_named_property("projectReferences", array)?;
+    }
+
+    let specs = &parsed.config_file_specs;
+    let mut config_file_specs = env.create_object()?;
+    set_optional_string_array(
+        env,
+        &mut config_file_specs,
+        "filesSpecs",
+        &specs.files_specs,
+    )?;
+    set_optional_string_array(
+        env,
+        &mut config_file_specs,
+        "includeSpecs",
+        &specs.include_specs,
+    )?;
+    set_optional_string_array(
+        env,
+        &mut config_file_specs,
+        "excludeSpecs",
+        &specs.exclude_specs,
+    )?;
+    set_optional_string_array(
+        env,
+        &mut config_file_specs,
+        "validatedIncludeSpecs",
+        &specs.validated_include_specs,
+    )?;
+    set_optional_string_array(
+        env,
+        &mut config_file_specs,
+        "validatedExcludeSpecs",
+        &specs.validated_exclude_specs,
+    )?;
+    result.set_named_property("configFileSpecs", config_file_specs)?;
+
+    result.set_named_property("compileOnSave", env.get_boolean(parsed.compile_on_save)?)?;
+    result.set_named_property(
+        "extendedSourceFiles",
+        create_string_array(env, &parsed.extended_source_files)?,
+    )?;
+    result.set_named_property("errors", create_diagnostics_array(env, &parsed.errors)?)?;
+    Ok(result)
+}
+
+// The optional current directory argument, defaulting to the working directory of the process.
+fn get_current_directory(cx: &CallContext, index: usize) -> Result<String> {
+    if index < cx.length {
+        if let Ok(current_directory) = cx.get::<JsString>(index) {
+            return Ok(current_directory.into_utf8()?.as_str()?.to_string());
+        }
+    }
+    let current_directory =
+        std::env::current_dir().map_err(|error| napi::Error::from_reason(error.to_string()))?;
+    Ok(normalize_slashes(&current_directory.to_string_lossy()))
+}
+
+#[js_function(2)]
+pub fn js_parse_tsconfig(cx: CallContext) -> Result<JsObject> {
+    let config_file_name = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    let current_directory = get_current_directory(&cx, 1)?;
+    let parsed = get_parsed_command_line_of_config_file(
+        &config_file_name,
+        &LocalFileSystem,
+        &current_directory,
+        &mut ExtendedConfigCache::new(),
+    );
+    create_parsed_command_line_object(cx.env, &parsed)
+}
+
+// Parses many configs sharing one `ExtendedConfigCache`, so configs that are extended by the
+// whole project tree are read once.
+#[js_function(2)]
+pub fn js_parse_tsconfigs(cx: CallContext) -> Result<JsObject> {
+    let config_file_names = cx.get::<JsObject>(0)?;
+    let current_directory = get_current_directory(&cx, 1)?;
+    let length = config_file_names.get_array_length()?;
+    let mut extended_config_cache = ExtendedConfigCache::new();
+    let mut result = cx.env.create_array_with_length(length as usize)?;
+    for index in 0..length {
+        let config_file_name = config_file_names
+            .get_element::<JsString>(index)?
+            .into_utf8()?
+            .as_str()?
+            .to_string();
+        let parsed = get_parsed_command_line_of_config_file(
+            &config_file_name,
+            &LocalFileSystem,
+            &current_directory,
+            &mut extended_config_cache,
+        );
+        result.set_element(index, create_parsed_command_line_object(cx.env, &parsed)?)?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,205 @@
+// The N-API bindings of the addon, which `src/compiler/native/addon.ts` loads. They only convert
+// between JS values and the types of `compiler`, which is where the work is done.
+
+// The expansions of the napi macros: `module_exports` checks the `napi4` and `tokio_rt` features
+// of napi as features of this crate, and `js_function(0)` declares an empty array of arguments.
+#![allow(unexpected_cfgs, clippy::zero_repeat_side_effects)]
+
+mod command_line_parser;
+mod parser;
+mod scanner;
+mod services;
+
+use std::convert::TryFrom;
+
+use napi::{
+    CallContext, Callback, Env, Error, JsExternal, JsObject, JsTypedArray, JsUndefined, JsUnknown,
+    Result, Status, TypedArrayType, ValueType,
+};
+use napi_derive::{js_function, module_exports};
+
+use crate::compiler::scanner::TOKEN_ENCODING_VERSION;
+use crate::compiler::types::{CancellationToken, Diagnostic, OperationCanceled};
+use command_line_parser::{js_parse_tsconfig, js_parse_tsconfigs};
+use parser::{
+    js_create_atom_table, js_create_source_file_tree, js_create_source_file_tree_async,
+    js_get_atom_texts, js_get_tree_node_table, js_get_tree_nodes, js_parse_files,
+    js_parse_files_async, js_parse_json_text, js_parse_source_file, js_parse_source_file_async,
+};
+use scanner::{
+    js_compute_line_starts, js_compute_line_starts_async, js_lookup_in_unicode_map, js_tokenize,
+    js_tokenize_async,
+};
+use services::{
+    js_get_encoded_syntactic_classifications, js_get_navigation_tree, js_get_outlining_spans,
+};
+
+// The functions of the addon, by the names JS calls them. `capabilities.functions` lists the same
+// names, so JS can tell which of the functions it calls an addon has.
+const FUNCTIONS: &[(&str, Callback)] = &[
+    ("lookupInUnicodeMap", js_lookup_in_unicode_map),
+    ("computeLineStarts", js_compute_line_starts),
+    ("computeLineStartsAsync", js_compute_line_starts_async),
+    ("tokenize", js_tokenize),
+    ("tokenizeAsync", js_tokenize_async),
+    ("parseJsonText", js_parse_json_text),
+    ("parseSourceFile", js_parse_source_file),
+    ("parseSourceFileAsync", js_parse_source_file_async),
+    ("createSourceFileTree", js_create_source_file_tree),
+    (
+        "createSourceFileTreeAsync",
+        js_create_source_file_tree_async,
+    ),
+    ("getTreeNodes", js_get_tree_nodes),
+    ("getTreeNodeTable", js_get_tree_node_table),
+    ("getAtomTexts", js_get_atom_texts),
+    ("createAtomTable", js_create_atom_table),
+    ("parseFiles", js_parse_files),
+    ("parseFilesAsync", js_parse_files_async),
+    ("createCancellationToken", js_create_cancellation_token),
+    ("requestCancellation", js_request_cancellation),
+    ("parseTsconfig", js_parse_tsconfig),
+    ("parseTsconfigs", js_parse_tsconfigs),
+    (
+        "getEncodedSyntacticClassifications",
+        js_get_encoded_syntactic_classifications,
+    ),
+    ("getOutliningSpans", js_get_outlining_spans),
+    ("getNavigationTree", js_get_navigation_tree),
+];
+
+// The options `tokenize` honors: the standard and JSX language variants, and reporting trivia.
+const SCANNER_MODES: &[&str] = &["standard", "jsx", "trivia"];
+
+#[module_exports]
+fn init(mut exports: JsObject, env: Env) -> Result<()> {
+    for &(name, function) in FUNCTIONS {
+        exports.create_named_method(name, function)?;
+    }
+    exports.set_named_property("version", env.create_string(env!("CARGO_PKG_VERSION"))?)?;
+    exports.set_named_property("capabilities", create_capabilities_object(&env)?)?;
+    Ok(())
+}
+
+// What the addon implements, which `addon.ts` checks before using it: an addon built from older
+// sources can lack functions, or encode tokens in a layout the compiler doesn't read.
+fn create_capabilities_object(env: &Env) -> Result<JsObject> {
+    let mut functions = env.create_array_with_length(FUNCTIONS.len())?;
+    for (index, &(name, _)) in FUNCTIONS.iter().enumerate() {
+        functions.set_element(index as u32, env.create_string(name)?)?;
+    }
+    let mut scanner_modes = env.create_array_with_length(SCANNER_MODES.len())?;
+    for (index, &mode) in SCANNER_MODES.iter().enumerate() {
+        scanner_modes.set_element(index as u32, env.create_string(mode)?)?;
+    }
+    let mut capabilities = env.create_object()?;
+    capabilities.set_named_property("functions", functions)?;
+    capabilities.set_named_property("scannerModes", scanner_modes)?;
+    capabilities.set_named_property(
+        "tokenEncodingVersion",
+        env.create_uint32(TOKEN_ENCODING_VERSION)?,
+    )?;
+    Ok(capabilities)
+}
+
+// Hands the values over to JS as a `Uint32Array` over their own memory, rather than copying
+// them into a JS array one element at a time. The array buffer frees them when it is collected.
+fn create_uint32_array(env: &Env, mut values: Vec<u32>) -> Result<JsTypedArray> {
+    let length = values.len();
+    if length == 0 {
+        // An empty `Vec` has a dangling pointer, which V8 doesn't accept for an external buffer.
+        return env
+            .create_arraybuffer(0)?
+            .into_raw()
+            .into_typedarray(TypedArrayType::Uint32, 0, 0);
+    }
+    let data = values.as_mut_ptr() as *mut u8;
+    // Safety: moving `values` into the finalizer doesn't move its elements, so `data` stays
+    // valid until the array buffer is collected and the finalizer drops them.
+    let buffer = unsafe {
+        env.create_arraybuffer_with_borrowed_data(
+            data,
+            length * std::mem::size_of::<u32>(),
+            values,
+            |values: Vec<u32>, _env: Env| drop(values),
+        )?
+    };
+    buffer
+        .into_raw()
+        .into_typedarray(TypedArrayType::Uint32, length, 0)
+}
+
+fn create_diagnostics_array(env: &Env, diagnostics: &[Diagnostic]) -> Result<JsObject> {
+    let mut array = env.create_array_with_length(diagnostics.len())?;
+    for (index, diagnostic) in diagnostics.iter().enumerate() {
+        let mut object = env.create_object()?;
+        // Global diagnostics, like a config file that cannot be read, have no location.
+        if let Some(file_name) = &diagnostic.file_name {
+            object.set_named_property("fileName", env.create_string(file_name)?)?;
+        }
+        if let Some(start) = diagnostic.start {
+            object.set_named_property("start", env.create_uint32(start as u32)?)?;
+        }
+        if let Some(length) = diagnostic.length {
+            object.set_named_property("length", env.create_uint32(length as u32)?)?;
+        }
+        object.set_named_property("messageText", env.create_string(&diagnostic.message_text)?)?;
+        object.set_named_property(
+            "category",
+            env.create_uint32(diagnostic.category.js_value())?,
+        )?;
+        object.set_named_property("code", env.create_uint32(diagnostic.code)?)?;
+        array.set_element(index as u32, object)?;
+    }
+    Ok(array)
+}
+
+impl From<OperationCanceled> for Error {
+    fn from(_: OperationCanceled) -> Error {
+        Error::new(Status::Cancelled, "OperationCanceled".to_string())
+    }
+}
+
+// A token of `createCancellationToken`, held by JS as an external.
+#[js_function(0)]
+fn js_create_cancellation_token(cx: CallContext) -> Result<JsExternal> {
+    cx.env.create_external(CancellationToken::new(), None)
+}
+
+// Cancels the operations that were given the token; they fail with `OperationCanceled`.
+#[js_function(1)]
+fn js_request_cancellation(cx: CallContext) -> Result<JsUndefined> {
+    let token = cx.get::<JsExternal>(0)?;
+    cx.env
+        .get_value_external::<CancellationToken>(&token)?
+        .cancel();
+    cx.env.get_undefined()
+}
+
+// An optional argument, or `None` when JS leaves it out or passes `undefined`. `CallContext::get`
+// doesn't check the type of the value, so it would read an `undefined` as a `T`.
+fn get_optional_argument<T: TryFrom<JsUnknown, Error = Error>>(
+    cx: &CallContext,
+    index: usize,
+) -> Result<Option<T>> {
+    if index >= cx.length {
+        return Ok(None);
+    }
+    let value = cx.get::<JsUnknown>(index)?;
+    match value.get_type()? {
+        ValueType::Undefined => Ok(None),
+        _ => T::try_from(value).map(Some),
+    }
+}
+
+fn get_cancellation_token(
+    env: &Env,
+    token: Option<JsExternal>,
+) -> Result<Option<CancellationToken>> {
+    match token {
+        Some(token) => Ok(Some(
+            env.get_value_external::<CancellationToken>(&token)?.clone(),
+        )),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,1633 @@
+use std::sync::Arc;
+
+use napi::{
+    CallContext, Env, Error, JsExternal, JsNumber, JsObject, JsString, JsTypedArray, JsUnknown,
+    Result, Status, Task,
+};
+use napi_derive::js_function;
+
+use super::{
+    create_diagnostics_array, create_uint32_array, get_cancellation_token, get_optional_argument,
+};
+use crate::compiler::atom::AtomTable;
+use crate::compiler::parser::{
+    create_node_table, create_source_file, create_source_file_with_cancellation, parse_files,
+    parse_json_text, script_kind_from_number, script_target_from_number, ParseFileInput,
+};
+use crate::compiler::types::{
+    CancellationToken, Diagnostic, DiagnosticWithLocation, FileReference, JSDocCommentText,
+    JsonNode, JsonValue, Node, NodeArray, NodeData, NodeId, OperationCanceled, Pragma, ScriptKind,
+    ScriptTarget, SourceFile, TextRange,
+};
+
+fn create_range_object(env: &Env, object: &mut JsObject, range: TextRange) -> Result<()> {
+    object.set_named_property("pos", env.create_uint32(range.pos as u32)?)?;
+    object.set_named_property("end", env.create_uint32(range.end as u32)?)?;
+    Ok(())
+}
+
+fn create_json_node_object(env: &Env, node: &JsonNode) -> Result<JsObject> {
+    let mut object = env.create_object()?;
+    create_range_object(env, &mut object, node.range)?;
+    match &node.value {
+        JsonValue::Object(properties) => {
+            object.set_named_property("kind", env.create_string("object")?)?;
+            let mut array = env.create_array_with_length(properties.len())?;
+            for (index, property) in properties.iter().enumerate() {
+                let mut property_object = env.create_object()?;
+                create_range_object(env, &mut property_object, property.range)?;
+                property_object.set_named_property("name", env.create_string(&property.name)?)?;
+                property_object.set_named_property(
+                    "namePos",
+                    env.create_uint32(property.name_range.pos as u32)?,
+                )?;
+                property_object.set_named_property(
+                    "nameEnd",
+                    env.create_uint32(property.name_range.end as u32)?,
+                )?;
+                property_object.set_named_property(
+                    "initializer",
+                    create_json_node_object(env, &property.initializer)?,
+                )?;
+                array.set_element(index as u32, property_object)?;
+            }
+            object.set_named_property("properties", array)?;
+        }
+        JsonValue::Array(elements) => {
+            object.set_named_property("kind", env.create_string("array")?)?;
+            let mut array = env.create_array_with_length(elements.len())?;
+            for (index, element) in elements.iter().enumerate() {
+                array.set_element(index as u32, create_json_node_object(env, element)?)?;
+            }
+            object.set_named_property("elements", array)?;
+        }
+        JsonValue::String(value) => {
+            object.set_named_property("kind", env.create_string("string")?)?;
+            object.set_named_property("value", env.create_string(value)?)?;
+        }
+        JsonValue::Number(value) => {
+            object.set_named_property("kind", env.create_string("number")?)?;
+            object.set_named_property("value", env.create_double(*value)?)?;
+        }
+        JsonValue::Boolean(value) => {
+            object.set_named_property("kind", env.create_string("boolean")?)?;
+            object.set_named_property("value", env.get_boolean(*value)?)?;
+        }
+        JsonValue::Null => {
+            object.set_named_property("kind", env.create_string("null")?)?;
+            object.set_named_property("value", env.get_null()?)?;
+        }
+    }
+    Ok(object)
+}
+
+#[js_function(2)]
+pub fn js_parse_json_text(cx: CallContext) -> Result<JsObject> {
+    let file_name = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    let source_text = cx.get::<JsString>(1)?.into_utf8()?.as_str()?.to_string();
+    let source_file = parse_json_text(&file_name, source_text);
+
+    let mut result = cx.env.create_object()?;
+    result.set_named_property("fileName", cx.env.create_string(&source_file.file_name)?)?;
+    let value: JsUnknown = match &source_file.value {
+        Some(node) => create_json_node_object(cx.env, node)?.into_unknown(),
+        None => cx.env.get_undefined()?.into_unknown(),
+    };
+    result.set_named_property("value", value)?;
+    result.set_named_property(
+        "parseDiagnostics",
+        create_diagnostics_array(
+            cx.env,
+            &source_file
+                .parse_diagnostics
+                .iter()
+                .cloned()
+                .map(Diagnostic::from)
+                .collect::<Vec<_>>(),
+        )?,
+    )?;
+    result.set_named_property(
+        "jsonDiagnostics",
+        create_diagnostics_array(
+            cx.env,
+            &source_file
+                .json_diagnostics
+                .iter()
+                .cloned()
+                .map(Diagnostic::from)
+                .collect::<Vec<_>>(),
+        )?,
+    )?;
+    Ok(result)
+}
+
+fn create_file_references_array(env: &Env, references: &[FileReference]) -> Result<JsObject> {
+    let mut array = env.create_array_with_length(references.len())?;
+    for (index, reference) in references.iter().enumerate() {
+        let mut object = env.create_object()?;
+        create_range_object(env, &mut object, reference.range)?;
+        object.set_named_property("fileName", env.create_string(&reference.file_name)?)?;
+        array.set_element(index as u32, object)?;
+    }
+    Ok(array)
+}
+
+fn create_location_diagnostics_array(
+    env: &Env,
+    diagnostics: &[DiagnosticWithLocation],
+) -> Result<JsObject> {
+    create_diagnostics_array(
+        env,
+        &diagnostics
+            .iter()
+            .cloned()
+            .map(Diagnostic::from)
+            .collect::<Vec<_>>(),
+    )
+}
+
+// The `(fileName, sourceText, languageVersion, scriptKind?, cancellationToken?)` arguments of the
+// exports that parse a source file.
+struct SourceFileArguments {
+    file_name: String,
+    source_text: String,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl SourceFileArguments {
+    fn parse(self) -> std::result::Result<SourceFile, OperationCanceled> {
+        match &self.cancellation_token {
+            Some(cancellation_token) => create_source_file_with_cancellation(
+                &self.file_name,
+                self.source_text,
+                self.language_version,
+                self.script_kind,
+                cancellation_token,
+            ),
+            None => Ok(create_source_file(
+                &self.file_name,
+                self.source_text,
+                self.language_version,
+                self.script_kind,
+            )),
+        }
+    }
+}
+
+fn source_file_arguments(cx: &CallContext) -> Result<SourceFileArguments> {
+    let file_name = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    let source_text = cx.get::<JsString>(1)?.into_utf8()?.as_str()?.to_string();
+    let language_version = script_target_from_number(cx.get::<JsNumber>(2)?.get_uint32()?);
+    let script_kind = get_optional_argument::<JsNumber>(cx, 3)?
+        .map(|script_kind| script_kind.get_uint32())
+        .transpose()?
+        .and_then(script_kind_from_number);
+    let cancellation_token = get_cancellation_token(cx.env, get_optional_argument(cx, 4)?)?;
+    Ok(SourceFileArguments {
+        file_name,
+        source_text,
+        language_version,
+        script_kind,
+        cancellation_token,
+    })
+}
+
+#[js_function(5)]
+pub fn js_parse_source_file(cx: CallContext) -> Result<JsObject> {
+    let source_file = source_file_arguments(&cx)?.parse()?;
+    create_source_file_object(cx.env, &source_file)
+}
+
+fn create_source_file_object(env: &Env, source_file: &SourceFile) -> Result<JsObject> {
+    let mut result = env.create_object()?;
+    result.set_named_property("fileName", env.create_string(&source_file.file_name)?)?;
+    result.set_named_property(
+        "scriptKind",
+        env.create_uint32(source_file.script_kind as u32)?,
+    )?;
+    result.set_named_property(
+        "isDeclarationFile",
+        env.get_boolean(source_file.is_declaration_file)?,
+    )?;
+    result.set_named_property(
+        "isExternalModule",
+        env.get_boolean(source_file.external_module_indicator.is_some())?,
+    )?;
+    result.set_named_property(
+        "hasNoDefaultLib",
+        env.get_boolean(source_file.has_no_default_lib)?,
+    )?;
+    result.set_named_property(
+        "nodeCount",
+        env.create_uint32(source_file.node_count as u32)?,
+    )?;
+    result.set_named_property(
+        "identifierCount",
+        env.create_uint32(source_file.identifier_count as u32)?,
+    )?;
+    result.set_named_property(
+        "statementCount",
+        env.create_uint32(source_file.statements().len() as u32)?,
+    )?;
+    result.set_named_property(
+        "referencedFiles",
+        create_file_references_array(env, &source_file.referenced_files)?,
+    )?;
+    result.set_named_property(
+        "typeReferenceDirectives",
+        create_file_references_array(env, &source_file.type_reference_directives)?,
+    )?;
+    result.set_named_property(
+        "libReferenceDirectives",
+        create_file_references_array(env, &source_file.lib_reference_directives)?,
+    )?;
+    let mut amd_dependencies = env.create_array_with_length(source_file.amd_dependencies.len())?;
+    for (index, dependency) in source_file.amd_dependencies.iter().enumerate() {
+        let mut object = env.create_object()?;
+        object.set_named_property("path", env.create_string(&dependency.path)?)?;
+        if let Some(name) = &dependency.name {
+            object.set_named_property("name", env.create_string(name)?)?;
+        }
+        amd_dependencies.set_element(index as u32, object)?;
+    }
+    result.set_named_property("amdDependencies", amd_dependencies)?;
+    if let Some(module_name) = &source_file.module_name {
+        result.set_named_property("moduleName", env.create_string(module_name)?)?;
+    }
+    if let Some(directive) = source_file.check_js_directive {
+        let mut object = env.create_object()?;
+        create_range_object(env, &mut object, directive.range)?;
+        object.set_named_property("enabled", env.get_boolean(directive.enabled)?)?;
+        result.set_named_property("checkJsDirective", object)?;
+    }
+    result.set_named_property(
+        "parseDiagnostics",
+        create_location_diagnostics_array(env, &source_file.parse_diagnostics)?,
+    )?;
+    result.set_named_property(
+        "jsDocDiagnostics",
+        create_location_diagnostics_array(env, &source_file.js_doc_diagnostics)?,
+    )?;
+    Ok(result)
+}
+
+// A tree kept native for `addon.ts` to build the JS nodes it is asked for from, along with the
+// summary `parseSourceFile` returns. JS holds the tree as an external, so it lives as long as
+// any node of it does.
+#[js_function(5)]
+pub fn js_create_source_file_tree(cx: CallContext) -> Result<JsObject> {
+    let source_file = source_file_arguments(&cx)?.parse()?;
+    create_source_file_tree_object(cx.env, source_file)
+}
+
+pub struct ParseSourceFileTask {
+    arguments: Option<SourceFileArguments>,
+    // Whether to resolve to the tree of `createSourceFileTree` rather than the summary of
+    // `parseSourceFile`
+    keep_tree: bool,
+}
+
+impl Task for ParseSourceFileTask {
+    type Output = SourceFile;
+    type JsValue = JsObject;
+
+    fn compute(&mut self) -> Result<SourceFile> {
+        Ok(self
+            .arguments
+            .take()
+            .expect("a task is only computed once")
+            .parse()?)
+    }
+
+    fn resolve(self, env: Env, output: SourceFile) -> Result<JsObject> {
+        if self.keep_tree {
+            create_source_file_tree_object(&env, output)
+        } else {
+            create_source_file_object(&env, &output)
+        }
+    }
+}
+
+// Like `parseSourceFile`, but parses in the libuv thread pool; returns a promise of the summary.
+#[js_function(5)]
+pub fn js_parse_source_file_async(cx: CallContext) -> Result<JsObject> {
+    let task = ParseSourceFileTask {
+        arguments: Some(source_file_arguments(&cx)?),
+        keep_tree: false,
+    };
+    Ok(cx.env.spawn(task)?.promise_object())
+}
+
+// Like `createSourceFileTree`, but parses in the libuv thread pool; returns a promise of the tree.
+#[js_function(5)]
+pub fn js_create_source_file_tree_async(cx: CallContext) -> Result<JsObject> {
+    let task = ParseSourceFileTask {
+        arguments: Some(source_file_arguments(&cx)?),
+        keep_tree: true,
+    };
+    Ok(cx.env.spawn(task)?.promise_object())
+}
+
+fn create_source_file_tree_object(env: &Env, source_file: SourceFile) -> Result<JsObject> {
+    let mut result = create_source_file_object(env, &source_file)?;
+    result.set_named_property("root", env.create_uint32(source_file.root.0)?)?;
+    if let Some(indicator) = source_file.external_module_indicator {
+        result.set_named_property("externalModuleIndicator", env.create_uint32(indicator.0)?)?;
+    }
+    let mut identifiers = env.create_array_with_length(source_file.identifiers.len())?;
+    for (index, identifier) in source_file.identifiers.iter().enumerate() {
+        identifiers.set_element(index as u32, env.create_string(identifier)?)?;
+    }
+    result.set_named_property("identifiers", identifiers)?;
+    if let Some(directives) = &source_file.comment_directives {
+        let mut array = env.create_array_with_length(directives.len())?;
+        for (index, directive) in directives.iter().enumerate() {
+            let mut object = env.create_object()?;
+            let mut range = env.create_object()?;
+            create_range_object(env, &mut range, directive.range)?;
+            object.set_named_property("range", range)?;
+            object.set_named_property("type", env.create_uint32(directive._type as u32)?)?;
+            array.set_element(index as u32, object)?;
+        }
+        result.set_named_property("commentDirectives", array)?;
+    }
+    result.set_named_property("pragmas", create_pragmas_array(env, &source_file.pragmas)?)?;
+    let size_hint = source_file.nodes.len() * std::mem::size_of::<Node>() + source_file.text.len();
+    result.set_named_property(
+        "tree",
+        env.create_external(source_file, Some(size_hint as i64))?,
+    )?;
+    Ok(result)
+}
+
+// The pragmas in the order they appear, each as `{ name, args }` with `args` the value the
+// `pragmas` map of the JS parser has for it: the arguments, and the comment they were read from.
+fn create_pragmas_array(env: &Env, pragmas: &[Pragma]) -> Result<JsObject> {
+    let mut array = env.create_array_with_length(pragmas.len())?;
+    for (index, pragma) in pragmas.iter().enumerate() {
+        let mut arguments = env.create_object()?;
+        for (name, argument) in &pragma.arguments {
+            match argument.range {
+                Some(range) => {
+                    let mut object = env.create_object()?;
+                    object.set_named_property("value", env.create_string(&argument.value)?)?;
+                    create_range_object(env, &mut object, range)?;
+                    arguments.set_named_property(name, object)?;
+                }
+                None => arguments.set_named_property(name, env.create_string(&argument.value)?)?,
+            }
+        }
+        let mut range = env.create_object()?;
+        range.set_named_property("kind", env.create_uint32(pragma.range.kind.js_value())?)?;
+        create_range_object(env, &mut range, pragma.range.range)?;
+        range.set_named_property(
+            "hasTrailingNewLine",
+            env.get_boolean(pragma.range.has_trailing_new_line)?,
+        )?;
+        let mut args = env.create_object()?;
+        args.set_named_property("arguments", arguments)?;
+        args.set_named_property("range", range)?;
+        let mut object = env.create_object()?;
+        object.set_named_property("name", env.create_string(&pragma.name)?)?;
+        object.set_named_property("args", args)?;
+        array.set_element(index as u32, object)?;
+    }
+    Ok(array)
+}
+
+struct ParseFilesArguments {
+    inputs: Vec<ParseFileInput>,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+    atoms: Arc<AtomTable>,
+    cancellation_token: Option<CancellationToken>,
+}
+
+// Reads the `(files, options)` arguments of the exports that parse a batch of files.
+fn parse_files_arguments(cx: &CallContext) -> Result<ParseFilesArguments> {
+    let files = cx.get::<JsObject>(0)?;
+    let options = cx.get::<JsObject>(1)?;
+    let language_version = script_target_from_number(
+        options
+            .get_named_property::<JsNumber>("languageVersion")?
+            .get_uint32()?,
+    );
+    let script_kind = options
+        .get_named_property::<JsNumber>("scriptKind")
+        .ok()
+        .map(|script_kind| script_kind.get_uint32())
+        .transpose()?
+        .and_then(script_kind_from_number);
+    let atoms = match options.get_named_property::<JsExternal>("atomTable") {
+        Ok(atoms) => cx.env.get_value_external::<Arc<AtomTable>>(&atoms)?.clone(),
+        Err(_) => Arc::default(),
+    };
+    let cancellation_token = get_cancellation_token(
+        cx.env,
+        options
+            .get_named_property::<JsExternal>("cancellationToken")
+            .ok(),
+    )?;
+    let length = files.get_array_length()?;
+    let mut inputs = Vec::with_capacity(length as usize);
+    for index in 0..length {
+        inputs.push(match files.get_element::<JsString>(index) {
+            Ok(path) => ParseFileInput::Path(path.into_utf8()?.as_str()?.to_string()),
+            Err(_) => {
+                let file = files.get_element::<JsObject>(index)?;
+                ParseFileInput::Text {
+                    file_name: file
+                        .get_named_property::<JsString>("fileName")?
+                        .into_utf8()?
+                        .as_str()?
+                        .to_string(),
+                    text: file
+                        .get_named_property::<JsString>("text")?
+                        .into_utf8()?
+                        .as_str()?
+                        .to_string(),
+                }
+            }
+        });
+    }
+    Ok(ParseFilesArguments {
+        inputs,
+        language_version,
+        script_kind,
+        atoms,
+        cancellation_token,
+    })
+}
+
+impl ParseFilesArguments {
+    // The paths of the files given by path, which are the files to report the text of
+    fn paths(&self) -> Vec<Option<String>> {
+        self.inputs
+            .iter()
+            .map(|input| match input {
+                ParseFileInput::Path(path) => Some(path.clone()),
+                ParseFileInput::Text { .. } => None,
+            })
+            .collect()
+    }
+
+    fn parse(
+        self,
+    ) -> std::result::Result<Vec<std::result::Result<SourceFile, Diagnostic>>, OperationCanceled>
+    {
+        parse_files(
+            self.inputs,
+            self.language_version,
+            self.script_kind,
+            &self.atoms,
+            self.cancellation_token.as_ref(),
+        )
+    }
+}
+
+// Each result is a tree like the one of `createSourceFileTree`, with the text of the files read
+// from a path in `text` and the errors reading them in `errors`; only `fileName` and `errors` are
+// set when the file cannot be read.
+fn create_parsed_files_array(
+    env: &Env,
+    paths: &[Option<String>],
+    parsed_files: Vec<std::result::Result<SourceFile, Diagnostic>>,
+) -> Result<JsObject> {
+    let mut result = env.create_array_with_length(parsed_files.len())?;
+    for (index, (path, parsed)) in paths.iter().zip(parsed_files).enumerate() {
+        let object = match parsed {
+            Ok(source_file) => {
+                let text = match path {
+                    Some(_) => Some(env.create_string(&source_file.text)?),
+                    None => None,
+                };
+                let mut object = create_source_file_tree_object(env, source_file)?;
+                if let Some(text) = text {
+                    object.set_named_property("text", text)?;
+                }
+                object.set_named_property("errors", create_diagnostics_array(env, &[])?)?;
+                object
+            }
+            Err(diagnostic) => {
+                let mut object = env.create_object()?;
+                let file_name = path.as_deref().unwrap_or_default();
+                object.set_named_property("fileName", env.create_string(file_name)?)?;
+                object
+                    .set_named_property("errors", create_diagnostics_array(env, &[diagnostic])?)?;
+                object
+            }
+        };
+        result.set_element(index as u32, object)?;
+    }
+    Ok(result)
+}
+
+// Parses a batch of files, given as paths or as `{ fileName, text }` objects, in parallel.
+#[js_function(2)]
+pub fn js_parse_files(cx: CallContext) -> Result<JsObject> {
+    let arguments = parse_files_arguments(&cx)?;
+    let paths = arguments.paths();
+    create_parsed_files_array(cx.env, &paths, arguments.parse()?)
+}
+
+pub struct ParseFilesTask {
+    arguments: Option<ParseFilesArguments>,
+    paths: Vec<Option<String>>,
+}
+
+impl Task for ParseFilesTask {
+    type Output = Vec<std::result::Result<SourceFile, Diagnostic>>;
+    type JsValue = JsObject;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(self
+            .arguments
+            .take()
+            .expect("a task is only computed once")
+            .parse()?)
+    }
+
+    fn resolve(self, env: Env, output: Self::Output) -> Result<JsObject> {
+        create_parsed_files_array(&env, &self.paths, output)
+    }
+}
+
+// Like `parseFiles`, but runs the batch from the libuv thread pool, so the event loop is free
+// while the files are read and parsed; returns a promise of the results.
+#[js_function(2)]
+pub fn js_parse_files_async(cx: CallContext) -> Result<JsObject> {
+    let arguments = parse_files_arguments(&cx)?;
+    let paths = arguments.paths();
+    let task = ParseFilesTask {
+        arguments: Some(arguments),
+        paths,
+    };
+    Ok(cx.env.spawn(task)?.promise_object())
+}
+
+// Returns the nodes with the given ids from a tree of `createSourceFileTree`. Their children are
+// ids too, so that JS only asks for the nodes it reaches.
+#[js_function(2)]
+pub fn js_get_tree_nodes(cx: CallContext) -> Result<JsObject> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let ids = cx.get::<JsObject>(1)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    let length = ids.get_array_length()?;
+    let mut result = cx.env.create_array_with_length(length as usize)?;
+    for index in 0..length {
+        let id = NodeId(ids.get_element::<JsNumber>(index)?.get_uint32()?);
+        let node = source_file.nodes.get(id.index()).ok_or_else(|| {
+            Error::new(Status::InvalidArg, format!("The tree has no node {}", id.0))
+        })?;
+        result.set_element(index, create_tree_node_object(cx.env, id, node)?)?;
+    }
+    Ok(result)
+}
+
+// The kind, range, flags and parent of every node of a tree of `createSourceFileTree`, by id, in
+// one `Uint32Array`. Code that only looks at those (to find the node at a position, say) can
+// read them without creating any node.
+#[js_function(1)]
+pub fn js_get_tree_node_table(cx: CallContext) -> Result<JsTypedArray> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    create_uint32_array(cx.env, create_node_table(source_file))
+}
+
+// An atom table for `parseFiles` batches to share, held by JS as an external.
+#[js_function(0)]
+pub fn js_create_atom_table(cx: CallContext) -> Result<JsExternal> {
+    cx.env.create_external(Arc::new(AtomTable::new()), None)
+}
+
+// Returns the texts of atoms of the table of a tree of `createSourceFileTree`, which identifier
+// nodes refer to by id. JS keeps them, so that each text crosses over once per table.
+#[js_function(2)]
+pub fn js_get_atom_texts(cx: CallContext) -> Result<JsObject> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let ids = cx.get::<JsObject>(1)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    let length = ids.get_array_length()?;
+    let mut result = cx.env.create_array_with_length(length as usize)?;
+    for index in 0..length {
+        let id = ids.get_element::<JsNumber>(index)?.get_uint32()?;
+        let atom = source_file.atoms.get(id).ok_or_else(|| {
+            Error::new(
+                Status::InvalidArg,
+                format!("The atom table has no atom {}", id),
+            )
+        })?;
+        result.set_element(index, cx.env.create_string(&atom)?)?;
+    }
+    Ok(result)
+}
+
+fn create_node_ids_array(env: &Env, ids: &[NodeId]) -> Result<JsObject> {
+    let mut array = env.create_array_with_length(ids.len())?;
+    for (index, id) in ids.iter().enumerate() {
+        array.set_element(index as u32, env.create_uint32(id.0)?)?;
+    }
+    Ok(array)
+}
+
+fn set_child(env: &Env, children: &mut JsObject, name: &str, child: Option<NodeId>) -> Result<()> {
+    if let Some(child) = child {
+        children.set_named_property(name, env.create_uint32(child.0)?)?;
+    }
+    Ok(())
+}
+
+fn set_child_array(
+    env: &Env,
+    children: &mut JsObject,
+    name: &str,
+    array: Option<&NodeArray>,
+) -> Result<()> {
+    if let Some(array) = array {
+        let mut object = env.create_object()?;
+        create_range_object(env, &mut object, array.range)?;
+        object.set_named_property(
+            "hasTrailingComma",
+            env.get_boolean(array.has_trailing_comma)?,
+        )?;
+        object.set_named_property("nodes", create_node_ids_array(env, &array.nodes)?)?;
+        children.set_named_property(name, object)?;
+    }
+    Ok(())
+}
+
+// Lists that are plain arrays in the JS compiler rather than a `NodeArray`.
+fn set_child_list(
+    env: &Env,
+    children: &mut JsObject,
+    name: &str,
+    list: Option<&[NodeId]>,
+) -> Result<()> {
+    if let Some(list) = list {
+        children.set_named_property(name, create_node_ids_array(env, list)?)?;
+    }
+    Ok(())
+}
+
+fn set_js_doc_comment(
+    env: &Env,
+    object: &mut JsObject,
+    children: &mut JsObject,
+    comment: &Option<JSDocCommentText>,
+) -> Result<()> {
+    match comment {
+        Some(JSDocCommentText::Text(text)) => {
+            object.set_named_property("comment", env.create_string(text)?)
+        }
+        Some(JSDocCommentText::Nodes(nodes)) => {
+            set_child_array(env, children, "comment", Some(nodes))
+        }
+        None => Ok(()),
+    }
+}
+
+// A node as `addon.ts` reads it: the properties of the node under the names the JS compiler uses,
+// except that child nodes go in `children` as ids, and lists of them as `NodeArray`-like objects
+// of ids (or plain arrays of ids, where the JS compiler uses a plain array).
+fn create_tree_node_object(env: &Env, id: NodeId, node: &Node) -> Result<JsObject> {
+    let mut object = env.create_object()?;
+    let mut child_properties = env.create_object()?;
+    object.set_named_property("id", env.create_uint32(id.0)?)?;
+    object.set_named_property("kind", env.create_uint32(node.kind.js_value())?)?;
+    create_range_object(env, &mut object, node.range)?;
+    object.set_named_property("flags", env.create_uint32(node.flags)?)?;
+    if let Some(parent) = node.parent {
+        object.set_named_property("parent", env.create_uint32(parent.0)?)?;
+    }
+    if let Some(js_doc) = &node.js_doc {
+        object.set_named_property("jsDoc", create_node_ids_array(env, js_doc)?)?;
+    }
+    set_child_array(
+        env,
+        &mut child_properties,
+        "decorators",
+        node.decorators.as_ref(),
+    )?;
+    set_child_array(
+        env,
+        &mut child_properties,
+        "modifiers",
+        node.modifiers.as_ref(),
+    )?;
+    match &node.data {
+        NodeData::Token => {}
+        NodeData::Identifier {
+            text,
+            original_keyword_kind,
+            ..
+        } => {
+            object.set_named_property("atom", env.create_uint32(text.id())?)?;
+            if let Some(keyword) = original_keyword_kind {
+                object.set_named_property(
+                    "originalKeywordKind",
+                    env.create_uint32(keyword.js_value())?,
+                )?;
+            }
+        }
+        NodeData::PrivateIdentifier { text } => {
+            object.set_named_property("atom", env.create_uint32(text.id())?)?;
+        }
+        NodeData::Literal {
+            text,
+            raw_text,
+            is_unterminated,
+            has_extended_unicode_escape,
+            ..
+        } => {
+            object.set_named_property("text", env.create_string(text)?)?;
+            if let Some(raw_text) = raw_text {
+                object.set_named_property("rawText", env.create_string(raw_text)?)?;
+            }
+            if *is_unterminated {
+                object.set_named_property("isUnterminated", env.get_boolean(true)?)?;
+            }
+            if *has_extended_unicode_escape {
+                object.set_named_property("hasExtendedUnicodeEscape", env.get_boolean(true)?)?;
+            }
+        }
+        NodeData::JsxText {
+            text,
+            contains_only_trivia_white_spaces,
+        } => {
+            object.set_named_property("text", env.create_string(text)?)?;
+            object.set_named_property(
+                "containsOnlyTriviaWhiteSpaces",
+                env.get_boolean(*contains_only_trivia_white_spaces)?,
+            )?;
+        }
+        NodeData::QualifiedName { left, right, .. } => {
+            set_child(env, &mut child_properties, "left", Some(*left))?;
+            set_child(env, &mut child_properties, "right", Some(*right))?;
+        }
+        NodeData::Expression { expression } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?
+        }
+        NodeData::TypeParameter {
+            name,
+            constraint,
+            default,
+            expression,
+        } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "constraint", *constraint)?;
+            set_child(env, &mut child_properties, "default", *default)?;
+            set_child(env, &mut child_properties, "expression", *expression)?;
+        }
+        NodeData::Parameter {
+            dot_dot_dot_token,
+            name,
+            question_token,
+            _type,
+            initializer,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "dotDotDotToken",
+                *dot_dot_dot_token,
+            )?;
+            set_child(env, &mut child_properties, "name", *name)?;
+            set_child(env, &mut child_properties, "questionToken", *question_token)?;
+            set_child(env, &mut child_properties, "type", *_type)?;
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+        }
+        NodeData::VariableLike {
+            name,
+            question_token,
+            exclamation_token,
+            _type,
+            initializer,
+        } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "questionToken", *question_token)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "exclamationToken",
+                *exclamation_token,
+            )?;
+            set_child(env, &mut child_properties, "type", *_type)?;
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+        }
+        NodeData::ShorthandPropertyAssignment {
+            name,
+            question_token,
+            exclamation_token,
+            equals_token,
+            object_assignment_initializer,
+        } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "questionToken", *question_token)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "exclamationToken",
+                *exclamation_token,
+            )?;
+            set_child(env, &mut child_properties, "equalsToken", *equals_token)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "objectAssignmentInitializer",
+                *object_assignment_initializer,
+            )?;
+        }
+        NodeData::BindingElement {
+            dot_dot_dot_token,
+            property_name,
+            name,
+            initializer,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "dotDotDotToken",
+                *dot_dot_dot_token,
+            )?;
+            set_child(env, &mut child_properties, "propertyName", *property_name)?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+        }
+        NodeData::SignatureDeclaration(signature) => {
+            set_child(
+                env,
+                &mut child_properties,
+                "asteriskToken",
+                signature.asterisk_token,
+            )?;
+            set_child(env, &mut child_properties, "name", signature.name)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionToken",
+                signature.question_token,
+            )?;
+            set_child(
+                env,
+                &mut child_properties,
+                "exclamationToken",
+                signature.exclamation_token,
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                signature.type_parameters.as_ref(),
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "parameters",
+                Some(&signature.parameters),
+            )?;
+            set_child(env, &mut child_properties, "type", signature._type)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "equalsGreaterThanToken",
+                signature.equals_greater_than_token,
+            )?;
+            set_child(env, &mut child_properties, "body", signature.body)?;
+        }
+        NodeData::TypeReference {
+            type_name,
+            type_arguments,
+        } => {
+            set_child(env, &mut child_properties, "typeName", Some(*type_name))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+        }
+        NodeData::TypePredicate {
+            asserts_modifier,
+            parameter_name,
+            _type,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "assertsModifier",
+                *asserts_modifier,
+            )?;
+            set_child(
+                env,
+                &mut child_properties,
+                "parameterName",
+                Some(*parameter_name),
+            )?;
+            set_child(env, &mut child_properties, "type", *_type)?;
+        }
+        NodeData::TypeQuery { expr_name } => {
+            set_child(env, &mut child_properties, "exprName", Some(*expr_name))?
+        }
+        NodeData::TypeLiteral { members } => {
+            set_child_array(env, &mut child_properties, "members", Some(members))?
+        }
+        NodeData::ArrayType { element_type } => set_child(
+            env,
+            &mut child_properties,
+            "elementType",
+            Some(*element_type),
+        )?,
+        NodeData::TupleType { elements } | NodeData::BindingPattern { elements } => {
+            set_child_array(env, &mut child_properties, "elements", Some(elements))?
+        }
+        NodeData::UnionOrIntersectionType { types } => {
+            set_child_array(env, &mut child_properties, "types", Some(types))?
+        }
+        NodeData::ConditionalType {
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+        } => {
+            set_child(env, &mut child_properties, "checkType", Some(*check_type))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "extendsType",
+                Some(*extends_type),
+            )?;
+            set_child(env, &mut child_properties, "trueType", Some(*true_type))?;
+            set_child(env, &mut child_properties, "falseType", Some(*false_type))?;
+        }
+        NodeData::InferType { type_parameter } => set_child(
+            env,
+            &mut child_properties,
+            "typeParameter",
+            Some(*type_parameter),
+        )?,
+        NodeData::ImportType {
+            is_type_of,
+            argument,
+            qualifier,
+            type_arguments,
+        } => {
+            object.set_named_property("isTypeOf", env.get_boolean(*is_type_of)?)?;
+            set_child(env, &mut child_properties, "argument", Some(*argument))?;
+            set_child(env, &mut child_properties, "qualifier", *qualifier)?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+        }
+        NodeData::TypeOperator { operator, _type } => {
+            object.set_named_property("operator", env.create_uint32(operator.js_value())?)?;
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+        }
+        NodeData::Type { _type } => set_child(env, &mut child_properties, "type", *_type)?,
+        NodeData::IndexedAccessType {
+            object_type,
+            index_type,
+        } => {
+            set_child(env, &mut child_properties, "objectType", Some(*object_type))?;
+            set_child(env, &mut child_properties, "indexType", Some(*index_type))?;
+        }
+        NodeData::MappedType {
+            readonly_token,
+            type_parameter,
+            name_type,
+            question_token,
+            _type,
+        } => {
+            set_child(env, &mut child_properties, "readonlyToken", *readonly_token)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "typeParameter",
+                Some(*type_parameter),
+            )?;
+            set_child(env, &mut child_properties, "nameType", *name_type)?;
+            set_child(env, &mut child_properties, "questionToken", *question_token)?;
+            set_child(env, &mut child_properties, "type", *_type)?;
+        }
+        NodeData::LiteralType { literal } => {
+            set_child(env, &mut child_properties, "literal", Some(*literal))?
+        }
+        NodeData::NamedTupleMember {
+            dot_dot_dot_token,
+            name,
+            question_token,
+            _type,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "dotDotDotToken",
+                *dot_dot_dot_token,
+            )?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "questionToken", *question_token)?;
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+        }
+        NodeData::ArrayLiteralExpression {
+            elements,
+            multi_line,
+        } => {
+            object.set_named_property("multiLine", env.get_boolean(*multi_line)?)?;
+            set_child_array(env, &mut child_properties, "elements", Some(elements))?;
+        }
+        NodeData::ObjectLiteralExpression {
+            properties,
+            multi_line,
+        } => {
+            object.set_named_property("multiLine", env.get_boolean(*multi_line)?)?;
+            set_child_array(env, &mut child_properties, "properties", Some(properties))?;
+        }
+        NodeData::PropertyAccessExpression {
+            expression,
+            question_dot_token,
+            name,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionDotToken",
+                *question_dot_token,
+            )?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+        }
+        NodeData::ElementAccessExpression {
+            expression,
+            question_dot_token,
+            argument_expression,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionDotToken",
+                *question_dot_token,
+            )?;
+            set_child(
+                env,
+                &mut child_properties,
+                "argumentExpression",
+                Some(*argument_expression),
+            )?;
+        }
+        NodeData::CallExpression {
+            expression,
+            question_dot_token,
+            type_arguments,
+            arguments,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionDotToken",
+                *question_dot_token,
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+            set_child_array(env, &mut child_properties, "arguments", arguments.as_ref())?;
+        }
+        NodeData::TaggedTemplateExpression {
+            tag,
+            question_dot_token,
+            type_arguments,
+            template,
+        } => {
+            set_child(env, &mut child_properties, "tag", Some(*tag))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionDotToken",
+                *question_dot_token,
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+            set_child(env, &mut child_properties, "template", Some(*template))?;
+        }
+        NodeData::TypeAssertion { _type, expression } => {
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+        }
+        NodeData::UnaryExpression { operator, operand } => {
+            object.set_named_property("operator", env.create_uint32(operator.js_value())?)?;
+            set_child(env, &mut child_properties, "operand", Some(*operand))?;
+        }
+        NodeData::YieldExpression {
+            asterisk_token,
+            expression,
+        } => {
+            set_child(env, &mut child_properties, "asteriskToken", *asterisk_token)?;
+            set_child(env, &mut child_properties, "expression", *expression)?;
+        }
+        NodeData::BinaryExpression {
+            left,
+            operator_token,
+            right,
+        } => {
+            set_child(env, &mut child_properties, "left", Some(*left))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "operatorToken",
+                Some(*operator_token),
+            )?;
+            set_child(env, &mut child_properties, "right", Some(*right))?;
+        }
+        NodeData::AsExpression { expression, _type } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+        }
+        NodeData::MetaProperty {
+            keyword_token,
+            name,
+        } => {
+            object
+                .set_named_property("keywordToken", env.create_uint32(keyword_token.js_value())?)?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+        }
+        NodeData::ConditionalExpression {
+            condition,
+            question_token,
+            when_true,
+            colon_token,
+            when_false,
+        } => {
+            set_child(env, &mut child_properties, "condition", Some(*condition))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "questionToken",
+                Some(*question_token),
+            )?;
+            set_child(env, &mut child_properties, "whenTrue", Some(*when_true))?;
+            set_child(env, &mut child_properties, "colonToken", Some(*colon_token))?;
+            set_child(env, &mut child_properties, "whenFalse", Some(*when_false))?;
+        }
+        NodeData::TemplateExpression {
+            head,
+            template_spans,
+        } => {
+            set_child(env, &mut child_properties, "head", Some(*head))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "templateSpans",
+                Some(template_spans),
+            )?;
+        }
+        NodeData::TemplateSpan {
+            expression,
+            literal,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(env, &mut child_properties, "literal", Some(*literal))?;
+        }
+        NodeData::TemplateLiteralTypeSpan { _type, literal } => {
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+            set_child(env, &mut child_properties, "literal", Some(*literal))?;
+        }
+        NodeData::Block {
+            statements,
+            multi_line,
+        } => {
+            object.set_named_property("multiLine", env.get_boolean(*multi_line)?)?;
+            set_child_array(env, &mut child_properties, "statements", Some(statements))?;
+        }
+        NodeData::SourceFile {
+            statements,
+            end_of_file_token,
+        } => {
+            set_child_array(env, &mut child_properties, "statements", Some(statements))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "endOfFileToken",
+                Some(*end_of_file_token),
+            )?;
+        }
+        NodeData::VariableStatement { declaration_list } => set_child(
+            env,
+            &mut child_properties,
+            "declarationList",
+            Some(*declaration_list),
+        )?,
+        NodeData::VariableDeclarationList { declarations } => set_child_array(
+            env,
+            &mut child_properties,
+            "declarations",
+            Some(declarations),
+        )?,
+        NodeData::IfStatement {
+            expression,
+            then_statement,
+            else_statement,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "thenStatement",
+                Some(*then_statement),
+            )?;
+            set_child(env, &mut child_properties, "elseStatement", *else_statement)?;
+        }
+        NodeData::DoStatement {
+            statement,
+            expression,
+        }
+        | NodeData::WhileOrWithStatement {
+            expression,
+            statement,
+        } => {
+            set_child(env, &mut child_properties, "statement", Some(*statement))?;
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+        }
+        NodeData::ForStatement {
+            initializer,
+            condition,
+            incrementor,
+            statement,
+        } => {
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+            set_child(env, &mut child_properties, "condition", *condition)?;
+            set_child(env, &mut child_properties, "incrementor", *incrementor)?;
+            set_child(env, &mut child_properties, "statement", Some(*statement))?;
+        }
+        NodeData::ForInOrOfStatement {
+            await_modifier,
+            initializer,
+            expression,
+            statement,
+        } => {
+            set_child(env, &mut child_properties, "awaitModifier", *await_modifier)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "initializer",
+                Some(*initializer),
+            )?;
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(env, &mut child_properties, "statement", Some(*statement))?;
+        }
+        NodeData::BreakOrContinueStatement { label } => {
+            set_child(env, &mut child_properties, "label", *label)?
+        }
+        NodeData::ReturnStatement { expression } => {
+            set_child(env, &mut child_properties, "expression", *expression)?
+        }
+        NodeData::SwitchStatement {
+            expression,
+            case_block,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child(env, &mut child_properties, "caseBlock", Some(*case_block))?;
+        }
+        NodeData::CaseBlock { clauses } => {
+            set_child_array(env, &mut child_properties, "clauses", Some(clauses))?
+        }
+        NodeData::CaseOrDefaultClause {
+            expression,
+            statements,
+        } => {
+            set_child(env, &mut child_properties, "expression", *expression)?;
+            set_child_array(env, &mut child_properties, "statements", Some(statements))?;
+        }
+        NodeData::LabeledStatement { label, statement } => {
+            set_child(env, &mut child_properties, "label", Some(*label))?;
+            set_child(env, &mut child_properties, "statement", Some(*statement))?;
+        }
+        NodeData::TryStatement {
+            try_block,
+            catch_clause,
+            finally_block,
+        } => {
+            set_child(env, &mut child_properties, "tryBlock", Some(*try_block))?;
+            set_child(env, &mut child_properties, "catchClause", *catch_clause)?;
+            set_child(env, &mut child_properties, "finallyBlock", *finally_block)?;
+        }
+        NodeData::CatchClause {
+            variable_declaration,
+            block,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "variableDeclaration",
+                *variable_declaration,
+            )?;
+            set_child(env, &mut child_properties, "block", Some(*block))?;
+        }
+        NodeData::ClassLikeDeclaration {
+            name,
+            type_parameters,
+            heritage_clauses,
+            members,
+        } => {
+            set_child(env, &mut child_properties, "name", *name)?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                type_parameters.as_ref(),
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "heritageClauses",
+                heritage_clauses.as_ref(),
+            )?;
+            set_child_array(env, &mut child_properties, "members", Some(members))?;
+        }
+        NodeData::InterfaceDeclaration {
+            name,
+            type_parameters,
+            heritage_clauses,
+            members,
+        } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                type_parameters.as_ref(),
+            )?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "heritageClauses",
+                heritage_clauses.as_ref(),
+            )?;
+            set_child_array(env, &mut child_properties, "members", Some(members))?;
+        }
+        NodeData::HeritageClause { token, types } => {
+            object.set_named_property("token", env.create_uint32(token.js_value())?)?;
+            set_child_array(env, &mut child_properties, "types", Some(types))?;
+        }
+        NodeData::ExpressionWithTypeArguments {
+            expression,
+            type_arguments,
+        } => {
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+        }
+        NodeData::TypeAliasDeclaration {
+            name,
+            type_parameters,
+            _type,
+        } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                type_parameters.as_ref(),
+            )?;
+            set_child(env, &mut child_properties, "type", Some(*_type))?;
+        }
+        NodeData::EnumDeclaration { name, members } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child_array(env, &mut child_properties, "members", Some(members))?;
+        }
+        NodeData::EnumMember { name, initializer } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+        }
+        NodeData::ModuleDeclaration { name, body } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "body", *body)?;
+        }
+        NodeData::ImportEqualsDeclaration {
+            is_type_only,
+            name,
+            module_reference,
+        } => {
+            object.set_named_property("isTypeOnly", env.get_boolean(*is_type_only)?)?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "moduleReference",
+                Some(*module_reference),
+            )?;
+        }
+        NodeData::ImportDeclaration {
+            import_clause,
+            module_specifier,
+        } => {
+            set_child(env, &mut child_properties, "importClause", *import_clause)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "moduleSpecifier",
+                Some(*module_specifier),
+            )?;
+        }
+        NodeData::ImportClause {
+            is_type_only,
+            name,
+            named_bindings,
+        } => {
+            object.set_named_property("isTypeOnly", env.get_boolean(*is_type_only)?)?;
+            set_child(env, &mut child_properties, "name", *name)?;
+            set_child(env, &mut child_properties, "namedBindings", *named_bindings)?;
+        }
+        NodeData::NamespaceImportOrExport { name } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?
+        }
+        NodeData::NamedImportsOrExports { elements } => {
+            set_child_array(env, &mut child_properties, "elements", Some(elements))?
+        }
+        NodeData::ImportOrExportSpecifier {
+            property_name,
+            name,
+        } => {
+            set_child(env, &mut child_properties, "propertyName", *property_name)?;
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+        }
+        NodeData::ExportDeclaration {
+            is_type_only,
+            export_clause,
+            module_specifier,
+        } => {
+            object.set_named_property("isTypeOnly", env.get_boolean(*is_type_only)?)?;
+            set_child(env, &mut child_properties, "exportClause", *export_clause)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "moduleSpecifier",
+                *module_specifier,
+            )?;
+        }
+        NodeData::ExportAssignment {
+            is_export_equals,
+            expression,
+        } => {
+            object.set_named_property("isExportEquals", env.get_boolean(*is_export_equals)?)?;
+            set_child(env, &mut child_properties, "expression", Some(*expression))?;
+        }
+        NodeData::JsxElement {
+            opening_element,
+            children,
+            closing_element,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "openingElement",
+                Some(*opening_element),
+            )?;
+            set_child_array(env, &mut child_properties, "children", Some(children))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "closingElement",
+                Some(*closing_element),
+            )?;
+        }
+        NodeData::JsxFragment {
+            opening_fragment,
+            children,
+            closing_fragment,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "openingFragment",
+                Some(*opening_fragment),
+            )?;
+            set_child_array(env, &mut child_properties, "children", Some(children))?;
+            set_child(
+                env,
+                &mut child_properties,
+                "closingFragment",
+                Some(*closing_fragment),
+            )?;
+        }
+        NodeData::JsxOpeningLikeElement {
+            tag_name,
+            type_arguments,
+            attributes,
+        } => {
+            set_child(env, &mut child_properties, "tagName", Some(*tag_name))?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeArguments",
+                type_arguments.as_ref(),
+            )?;
+            set_child(env, &mut child_properties, "attributes", Some(*attributes))?;
+        }
+        NodeData::JsxClosingElement { tag_name } => {
+            set_child(env, &mut child_properties, "tagName", Some(*tag_name))?
+        }
+        NodeData::JsxAttributes { properties } => {
+            set_child_array(env, &mut child_properties, "properties", Some(properties))?
+        }
+        NodeData::JsxAttribute { name, initializer } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?;
+            set_child(env, &mut child_properties, "initializer", *initializer)?;
+        }
+        NodeData::JsxExpression {
+            dot_dot_dot_token,
+            expression,
+        } => {
+            set_child(
+                env,
+                &mut child_properties,
+                "dotDotDotToken",
+                *dot_dot_dot_token,
+            )?;
+            set_child(env, &mut child_properties, "expression", *expression)?;
+        }
+        NodeData::JSDoc { comment, tags } => {
+            set_js_doc_comment(env, &mut object, &mut child_properties, comment)?;
+            set_child_array(env, &mut child_properties, "tags", tags.as_ref())?;
+        }
+        NodeData::JSDocText { text } => {
+            object.set_named_property("text", env.create_string(text)?)?
+        }
+        NodeData::JSDocLink { name, text } => {
+            object.set_named_property("text", env.create_string(text)?)?;
+            set_child(env, &mut child_properties, "name", *name)?;
+        }
+        NodeData::JSDocNameReference { name } => {
+            set_child(env, &mut child_properties, "name", Some(*name))?
+        }
+        NodeData::JSDocTag(tag) => {
+            set_child(env, &mut child_properties, "tagName", Some(tag.tag_name))?;
+            set_js_doc_comment(env, &mut object, &mut child_properties, &tag.comment)?;
+            set_child(
+                env,
+                &mut child_properties,
+                "typeExpression",
+                tag.type_expression,
+            )?;
+            set_child(env, &mut child_properties, "name", tag.name)?;
+            set_child(env, &mut child_properties, "fullName", tag.full_name)?;
+            set_child(env, &mut child_properties, "class", tag.class)?;
+            set_child(env, &mut child_properties, "constraint", tag.constraint)?;
+            set_child_array(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                tag.type_parameters.as_ref(),
+            )?;
+            if tag.is_name_first {
+                object.set_named_property("isNameFirst", env.get_boolean(true)?)?;
+            }
+            if tag.is_bracketed {
+                object.set_named_property("isBracketed", env.get_boolean(true)?)?;
+            }
+        }
+        NodeData::JSDocTypeLiteral {
+            js_doc_property_tags,
+            is_array_type,
+        } => {
+            object.set_named_property("isArrayType", env.get_boolean(*is_array_type)?)?;
+            set_child_list(
+                env,
+                &mut child_properties,
+                "jsDocPropertyTags",
+                js_doc_property_tags.as_deref(),
+            )?;
+        }
+        NodeData::JSDocSignature {
+            type_parameters,
+            parameters,
+            _type,
+        } => {
+            set_child_list(
+                env,
+                &mut child_properties,
+                "typeParameters",
+                type_parameters.as_deref(),
+            )?;
+            set_child_list(env, &mut child_properties, "parameters", Some(parameters))?;
+            set_child(env, &mut child_properties, "type", *_type)?;
+        }
+    }
+    object.set_named_property("children", child_properties)?;
+    Ok(object)
+}
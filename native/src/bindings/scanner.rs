@@ -0,0 +1,203 @@
+use std::convert::TryInto;
+
+use napi::{
+    CallContext, Env, JsBoolean, JsExternal, JsNumber, JsObject, JsString, JsTypedArray, Result,
+    Task,
+};
+use napi_derive::js_function;
+
+use super::{
+    create_diagnostics_array, create_uint32_array, get_cancellation_token, get_optional_argument,
+};
+use crate::compiler::parser::script_target_from_number;
+use crate::compiler::scanner::{compute_line_starts, tokenize, Tokenization};
+use crate::compiler::types::{CancellationToken, LanguageVariant, OperationCanceled, ScriptTarget};
+use crate::compiler::unicode::lookup_in_unicode_map;
+
+#[js_function(2)]
+pub fn js_lookup_in_unicode_map(cx: CallContext) -> Result<JsBoolean> {
+    let code: u32 = cx
+        .get::<JsNumber>(0)?
+        .try_into()
+        .expect("argument of type number expected");
+    let array_of_number_err = "expected an array";
+    let map_raw = cx.get::<JsObject>(1).expect(array_of_number_err);
+    let mut map: Vec<u32> = Vec::new();
+    let validating_err = "error on array validating";
+    if map_raw.is_array().expect(validating_err) {
+        for index in 0..map_raw.get_array_length().expect(validating_err) {
+            map.push(
+                map_raw
+                    .get_element::<JsNumber>(index)
+                    .expect("element in has correct index but doesn't exist")
+                    .try_into()
+                    .expect(array_of_number_err),
+            );
+        }
+    }
+
+    cx.env.get_boolean(lookup_in_unicode_map(code, &map))
+}
+
+fn create_line_starts_array(env: &Env, line_starts: Vec<usize>) -> Result<JsTypedArray> {
+    create_uint32_array(
+        env,
+        line_starts
+            .into_iter()
+            .map(|line_start| line_start as u32)
+            .collect(),
+    )
+}
+
+#[js_function(1)]
+pub fn js_compute_line_starts(cx: CallContext) -> Result<JsTypedArray> {
+    let text = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    create_line_starts_array(cx.env, compute_line_starts(&text))
+}
+
+pub struct ComputeLineStartsTask {
+    text: String,
+}
+
+impl Task for ComputeLineStartsTask {
+    type Output = Vec<usize>;
+    type JsValue = JsTypedArray;
+
+    fn compute(&mut self) -> Result<Vec<usize>> {
+        Ok(compute_line_starts(&self.text))
+    }
+
+    fn resolve(self, env: Env, output: Vec<usize>) -> Result<JsTypedArray> {
+        create_line_starts_array(&env, output)
+    }
+}
+
+// Like `computeLineStarts`, but computed in the libuv thread pool; returns a promise of them.
+#[js_function(1)]
+pub fn js_compute_line_starts_async(cx: CallContext) -> Result<JsObject> {
+    let text = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    let task = ComputeLineStartsTask { text };
+    Ok(cx.env.spawn(task)?.promise_object())
+}
+
+struct TokenizeArguments {
+    text: String,
+    language_version: ScriptTarget,
+    language_variant: LanguageVariant,
+    skip_trivia: bool,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl TokenizeArguments {
+    fn tokenize(self) -> std::result::Result<Tokenization, OperationCanceled> {
+        tokenize(
+            self.text,
+            self.language_version,
+            self.language_variant,
+            self.skip_trivia,
+            self.cancellation_token.as_ref(),
+        )
+    }
+}
+
+// Reads the `(text, options?)` arguments of the tokenize exports, where the options are
+// `{ languageVersion?, languageVariant?, skipTrivia?, cancellationToken? }`.
+fn tokenize_arguments(cx: &CallContext) -> Result<TokenizeArguments> {
+    let text = cx.get::<JsString>(0)?.into_utf8()?.as_str()?.to_string();
+    let options = get_optional_argument::<JsObject>(cx, 1)?;
+    let get_number = |name: &str| -> Result<Option<u32>> {
+        match &options {
+            Some(options) => options
+                .get_named_property::<JsNumber>(name)
+                .ok()
+                .map(|value| value.get_uint32())
+                .transpose(),
+            None => Ok(None),
+        }
+    };
+    let language_version =
+        get_number("languageVersion")?.map_or(ScriptTarget::ESNext, script_target_from_number);
+    let language_variant = match get_number("languageVariant")? {
+        Some(1) => LanguageVariant::JSX,
+        _ => LanguageVariant::Standard,
+    };
+    let skip_trivia = match &options {
+        Some(options) => options
+            .get_named_property::<JsBoolean>("skipTrivia")
+            .ok()
+            .map(|value| value.get_value())
+            .transpose()?
+            .unwrap_or(true),
+        None => true,
+    };
+    let cancellation_token = match &options {
+        Some(options) => get_cancellation_token(
+            cx.env,
+            options
+                .get_named_property::<JsExternal>("cancellationToken")
+                .ok(),
+        )?,
+        None => None,
+    };
+    Ok(TokenizeArguments {
+        text,
+        language_version,
+        language_variant,
+        skip_trivia,
+        cancellation_token,
+    })
+}
+
+// The tokens go to JS as a `Uint32Array` in the layout of `TOKEN_ENCODING_STRIDE`, with the values
+// in a separate array of strings.
+fn create_tokenization_object(env: &Env, tokenization: &Tokenization) -> Result<JsObject> {
+    let (encoded, values) = tokenization.encode();
+    let mut value_strings = env.create_array_with_length(values.len())?;
+    for (index, value) in values.into_iter().enumerate() {
+        value_strings.set_element(index as u32, env.create_string(value)?)?;
+    }
+    let mut result = env.create_object()?;
+    result.set_named_property("tokens", create_uint32_array(env, encoded)?)?;
+    result.set_named_property("values", value_strings)?;
+    result.set_named_property(
+        "diagnostics",
+        create_diagnostics_array(env, &tokenization.diagnostics)?,
+    )?;
+    Ok(result)
+}
+
+#[js_function(2)]
+pub fn js_tokenize(cx: CallContext) -> Result<JsObject> {
+    let tokenization = tokenize_arguments(&cx)?.tokenize()?;
+    create_tokenization_object(cx.env, &tokenization)
+}
+
+pub struct TokenizeTask {
+    arguments: Option<TokenizeArguments>,
+}
+
+impl Task for TokenizeTask {
+    type Output = Tokenization;
+    type JsValue = JsObject;
+
+    fn compute(&mut self) -> Result<Tokenization> {
+        Ok(self
+            .arguments
+            .take()
+            .expect("a task is only computed once")
+            .tokenize()?)
+    }
+
+    fn resolve(self, env: Env, output: Tokenization) -> Result<JsObject> {
+        create_tokenization_object(&env, &output)
+    }
+}
+
+// Like `tokenize`, but scans in the libuv thread pool; returns a promise of the tokens.
+#[js_function(2)]
+pub fn js_tokenize_async(cx: CallContext) -> Result<JsObject> {
+    let task = TokenizeTask {
+        arguments: Some(tokenize_arguments(&cx)?),
+    };
+    Ok(cx.env.spawn(task)?.promise_object())
+}
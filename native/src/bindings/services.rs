@@ -0,0 +1,108 @@
+use napi::{CallContext, Env, JsExternal, JsNumber, JsObject, Result};
+use napi_derive::js_function;
+
+use super::{create_uint32_array, get_cancellation_token, get_optional_argument};
+use crate::compiler::types::{SourceFile, TextRange};
+use crate::services::classifier::get_encoded_syntactic_classifications;
+use crate::services::navigation_bar::get_navigation_tree;
+use crate::services::outlining_elements_collector::collect_elements;
+use crate::services::types::NavigationTree;
+
+// The syntactic classifications of a tree of `createSourceFileTree` in the span given by its start
+// and length, as `{ spans, endOfLineState }` with the spans a `Uint32Array` of triples.
+#[js_function(4)]
+pub fn js_get_encoded_syntactic_classifications(cx: CallContext) -> Result<JsObject> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let start = cx.get::<JsNumber>(1)?.get_uint32()? as usize;
+    let length = cx.get::<JsNumber>(2)?.get_uint32()? as usize;
+    let cancellation_token = get_cancellation_token(cx.env, get_optional_argument(&cx, 3)?)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    let span = TextRange {
+        pos: start,
+        end: start + length,
+    };
+    let classifications =
+        get_encoded_syntactic_classifications(source_file, span, cancellation_token.as_ref())?;
+    let mut object = cx.env.create_object()?;
+    object.set_named_property("spans", create_uint32_array(cx.env, classifications.spans)?)?;
+    object.set_named_property(
+        "endOfLineState",
+        cx.env
+            .create_uint32(classifications.end_of_line_state as u32)?,
+    )?;
+    Ok(object)
+}
+
+fn create_text_span(env: &Env, range: TextRange) -> Result<JsObject> {
+    let mut object = env.create_object()?;
+    object.set_named_property("start", env.create_uint32(range.pos as u32)?)?;
+    object.set_named_property("length", env.create_uint32((range.end - range.pos) as u32)?)?;
+    Ok(object)
+}
+
+// The outlining spans of a tree of `createSourceFileTree`, like `getOutliningSpans` of the services
+// returns them.
+#[js_function(2)]
+pub fn js_get_outlining_spans(cx: CallContext) -> Result<JsObject> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let cancellation_token = get_cancellation_token(cx.env, get_optional_argument(&cx, 1)?)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    let spans = collect_elements(source_file, cancellation_token.as_ref())?;
+    let mut array = cx.env.create_array_with_length(spans.len())?;
+    for (index, span) in spans.into_iter().enumerate() {
+        let mut object = cx.env.create_object()?;
+        object.set_named_property("textSpan", create_text_span(cx.env, span.text_span)?)?;
+        object.set_named_property("hintSpan", create_text_span(cx.env, span.hint_span)?)?;
+        object.set_named_property("bannerText", cx.env.create_string(&span.banner_text)?)?;
+        object.set_named_property("autoCollapse", cx.env.get_boolean(span.auto_collapse)?)?;
+        object.set_named_property("kind", cx.env.create_string(span.kind.js_value())?)?;
+        array.set_element(index as u32, object)?;
+    }
+    Ok(array)
+}
+
+// The navigation tree of a tree of `createSourceFileTree`, like `getNavigationTree` of the services
+// returns it.
+#[js_function(2)]
+pub fn js_get_navigation_tree(cx: CallContext) -> Result<JsObject> {
+    let tree = cx.get::<JsExternal>(0)?;
+    let cancellation_token = get_cancellation_token(cx.env, get_optional_argument(&cx, 1)?)?;
+    let source_file = cx.env.get_value_external::<SourceFile>(&tree)?;
+    let navigation_tree = get_navigation_tree(source_file, cancellation_token.as_ref())?;
+    create_navigation_tree(cx.env, &navigation_tree)
+}
+
+// `childItems` is left out when there are none, like the services leave it undefined.
+fn create_navigation_tree(env: &Env, navigation_tree: &NavigationTree) -> Result<JsObject> {
+    let mut object = env.create_object()?;
+    object.set_named_property("text", env.create_string(&navigation_tree.text)?)?;
+    object.set_named_property("kind", env.create_string(navigation_tree.kind.js_value())?)?;
+    let kind_modifiers = navigation_tree
+        .kind_modifiers
+        .iter()
+        .map(|modifier| modifier.js_value())
+        .collect::<Vec<_>>();
+    object.set_named_property(
+        "kindModifiers",
+        env.create_string(&kind_modifiers.join(","))?,
+    )?;
+    let mut spans = env.create_array_with_length(navigation_tree.spans.len())?;
+    for (index, &span) in navigation_tree.spans.iter().enumerate() {
+        spans.set_element(index as u32, create_text_span(env, span)?)?;
+    }
+    object.set_named_property("spans", spans)?;
+    match navigation_tree.name_span {
+        Some(name_span) => {
+            object.set_named_property("nameSpan", create_text_span(env, name_span)?)?
+        }
+        None => object.set_named_property("nameSpan", env.get_undefined()?)?,
+    }
+    if !navigation_tree.child_items.is_empty() {
+        let mut child_items = env.create_array_with_length(navigation_tree.child_items.len())?;
+        for (index, child_item) in navigation_tree.child_items.iter().enumerate() {
+            child_items.set_element(index as u32, create_navigation_tree(env, child_item)?)?;
+        }
+        object.set_named_property("childItems", child_items)?;
+    }
+    Ok(object)
+}
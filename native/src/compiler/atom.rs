@@ -0,0 +1,180 @@
+// Interned identifier texts. The JS compiler keeps one string per identifier text in each file's
+// `identifiers` map; here a single `AtomTable` can be shared by every file of a program (and by
+// the threads parsing them), so an identifier text is stored once and compared by id.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+/// The text of an identifier, interned in an `AtomTable`. The identifiers with the same text get
+/// atoms with the same id and share one allocation of it.
+#[derive(Clone)]
+pub struct Atom {
+    id: u32,
+    text: Arc<str>,
+}
+
+impl Atom {
+    /// The index of the atom in its table. Atoms of different tables can share an id.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// The text as a `__String`, with the extra leading underscore `escapeLeadingUnderscores`
+    /// adds to texts that start with two of them.
+    pub fn escaped_text(&self) -> Cow<'_, str> {
+        escape_leading_underscores(&self.text)
+    }
+}
+
+impl Deref for Atom {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Atom) -> bool {
+        Arc::ptr_eq(&self.text, &other.text) || self.text == other.text
+    }
+}
+
+impl Eq for Atom {}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        &*self.text == other
+    }
+}
+
+impl PartialEq<&str> for Atom {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.text == *other
+    }
+}
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Atom({}, {:?})", self.id, &*self.text)
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[derive(Default)]
+struct AtomTableEntries {
+    ids: HashMap<Arc<str>, u32>,
+    texts: Vec<Arc<str>>,
+}
+
+/// The atoms of a program. Interning only takes the write lock for texts it hasn't seen yet.
+#[derive(Default)]
+pub struct AtomTable {
+    entries: RwLock<AtomTableEntries>,
+}
+
+impl AtomTable {
+    pub fn new() -> AtomTable {
+        AtomTable::default()
+    }
+
+    pub fn intern(&self, text: &str) -> Atom {
+        if let Some(atom) = self.lookup(text) {
+            return atom;
+        }
+        let mut entries = self.entries.write().unwrap();
+        // Another thread may have interned the text since the lookup.
+        if let Some(&id) = entries.ids.get(text) {
+            return Atom {
+                id,
+                text: entries.texts[id as usize].clone(),
+            };
+        }
+        let id = entries.texts.len() as u32;
+        let text: Arc<str> = Arc::from(text);
+        entries.texts.push(text.clone());
+        entries.ids.insert(text.clone(), id);
+        Atom { id, text }
+    }
+
+    /// Interns the text of an escaped name, like the `escapedText` of an identifier.
+    pub fn intern_escaped(&self, escaped_text: &str) -> Atom {
+        self.intern(unescape_leading_underscores(escaped_text))
+    }
+
+    /// The atom of a text interned before, if any.
+    pub fn lookup(&self, text: &str) -> Option<Atom> {
+        let entries = self.entries.read().unwrap();
+        entries.ids.get(text).map(|&id| Atom {
+            id,
+            text: entries.texts[id as usize].clone(),
+        })
+    }
+
+    pub fn get(&self, id: u32) -> Option<Atom> {
+        let entries = self.entries.read().unwrap();
+        entries.texts.get(id as usize).map(|text| Atom {
+            id,
+            text: text.clone(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().texts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Tables are equal when they hold the same atoms, under the same ids.
+impl PartialEq for AtomTable {
+    fn eq(&self, other: &AtomTable) -> bool {
+        std::ptr::eq(self, other)
+            || self.entries.read().unwrap().texts == other.entries.read().unwrap().texts
+    }
+}
+
+impl fmt::Debug for AtomTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AtomTable({} atoms)", self.len())
+    }
+}
+
+// Texts starting with two underscores get a third, so that they can't collide with the names
+// the compiler gives to symbols (like `__call` or `__export`).
+pub fn escape_leading_underscores(text: &str) -> Cow<'_, str> {
+    if text.starts_with("__") {
+        Cow::Owned(format!("_{}", text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+pub fn unescape_leading_underscores(escaped_text: &str) -> &str {
+    if escaped_text.starts_with("___") {
+        &escaped_text[1..]
+    } else {
+        escaped_text
+    }
+}
@@ -0,0 +1,1688 @@
+use std::collections::HashMap;
+
+use super::core::get_spelling_suggestion;
+use super::diagnostic_information_map::Diagnostics;
+use super::module_name_resolver::resolve_tsconfig_module_name;
+use super::parser::parse_json_text;
+use super::path::{
+    combine_paths, convert_to_relative_path, directory_of_combined_path, get_base_file_name,
+    get_directory_path, get_normalized_absolute_path, is_rooted_disk_path, normalize_path,
+    normalize_slashes, to_file_name_lower_case,
+};
+use super::scanner::skip_trivia;
+use super::types::{
+    CommandLineOption, CommandLineOptionType, CompilerOptions, ConfigFileSpecs, Diagnostic,
+    IDiagnosticMessage, JsonNode, JsonProperty, JsonSourceFile, JsonValue, OptionMapValue,
+    ParseConfigHost, ParsedCommandLine, ProjectReference, TextRange,
+};
+use super::utilities::{
+    create_cannot_read_file_diagnostic, create_compiler_diagnostic, create_file_diagnostic,
+};
+
+const fn option(name: &'static str, option_type: CommandLineOptionType) -> CommandLineOption {
+    CommandLineOption {
+        name,
+        option_type,
+        is_file_path: false,
+        extra_validation: None,
+    }
+}
+
+const fn file_path_option(
+    name: &'static str,
+    option_type: CommandLineOptionType,
+) -> CommandLineOption {
+    CommandLineOption {
+        name,
+        option_type,
+        is_file_path: true,
+        extra_validation: None,
+    }
+}
+
+const TARGET_MAP: &[(&str, OptionMapValue)] = &[
+    ("es3", OptionMapValue::Number(0)),
+    ("es5", OptionMapValue::Number(1)),
+    ("es6", OptionMapValue::Number(2)),
+    ("es2015", OptionMapValue::Number(2)),
+    ("es2016", OptionMapValue::Number(3)),
+    ("es2017", OptionMapValue::Number(4)),
+    ("es2018", OptionMapValue::Number(5)),
+    ("es2019", OptionMapValue::Number(6)),
+    ("es2020", OptionMapValue::Number(7)),
+    ("es2021", OptionMapValue::Number(8)),
+    ("esnext", OptionMapValue::Number(99)),
+];
+
+const MODULE_MAP: &[(&str, OptionMapValue)] = &[
+    ("none", OptionMapValue::Number(0)),
+    ("commonjs", OptionMapValue::Number(1)),
+    ("amd", OptionMapValue::Number(2)),
+    ("system", OptionMapValue::Number(4)),
+    ("umd", OptionMapValue::Number(3)),
+    ("es6", OptionMapValue::Number(5)),
+    ("es2015", OptionMapValue::Number(5)),
+    ("es2020", OptionMapValue::Number(6)),
+    ("esnext", OptionMapValue::Number(99)),
+];
+
+const LIB_MAP: &[(&str, OptionMapValue)] = &[
+    ("es5", OptionMapValue::String("lib.es5.d.ts")),
+    ("es6", OptionMapValue::String("lib.es2015.d.ts")),
+    ("es2015", OptionMapValue::String("lib.es2015.d.ts")),
+    ("es7", OptionMapValue::String("lib.es2016.d.ts")),
+    ("es2016", OptionMapValue::String("lib.es2016.d.ts")),
+    ("es2017", OptionMapValue::String("lib.es2017.d.ts")),
+    ("es2018", OptionMapValue::String("lib.es2018.d.ts")),
+    ("es2019", OptionMapValue::String("lib.es2019.d.ts")),
+    ("es2020", OptionMapValue::String("lib.es2020.d.ts")),
+    ("es2021", OptionMapValue::String("lib.es2021.d.ts")),
+    ("esnext", OptionMapValue::String("lib.esnext.d.ts")),
+    ("dom", OptionMapValue::String("lib.dom.d.ts")),
+    (
+        "dom.iterable",
+        OptionMapValue::String("lib.dom.iterable.d.ts"),
+    ),
+    ("webworker", OptionMapValue::String("lib.webworker.d.ts")),
+    (
+        "webworker.importscripts",
+        OptionMapValue::String("lib.webworker.importscripts.d.ts"),
+    ),
+    (
+        "webworker.iterable",
+        OptionMapValue::String("lib.webworker.iterable.d.ts"),
+    ),
+    ("scripthost", OptionMapValue::String("lib.scripthost.d.ts")),
+    (
+        "es2015.core",
+        OptionMapValue::String("lib.es2015.core.d.ts"),
+    ),
+    (
+        "es2015.collection",
+        OptionMapValue::String("lib.es2015.collection.d.ts"),
+    ),
+    (
+        "es2015.generator",
+        OptionMapValue::String("lib.es2015.generator.d.ts"),
+    ),
+    (
+        "es2015.iterable",
+        OptionMapValue::String("lib.es2015.iterable.d.ts"),
+    ),
+    (
+        "es2015.promise",
+        OptionMapValue::String("lib.es2015.promise.d.ts"),
+    ),
+    (
+        "es2015.proxy",
+        OptionMapValue::String("lib.es2015.proxy.d.ts"),
+    ),
+    (
+        "es2015.reflect",
+        OptionMapValue::String("lib.es2015.reflect.d.ts"),
+    ),
+    (
+        "es2015.symbol",
+        OptionMapValue::String("lib.es2015.symbol.d.ts"),
+    ),
+    (
+        "es2015.symbol.wellknown",
+        OptionMapValue::String("lib.es2015.symbol.wellknown.d.ts"),
+    ),
+    (
+        "es2016.array.include",
+        OptionMapValue::String("lib.es2016.array.include.d.ts"),
+    ),
+    (
+        "es2017.object",
+        OptionMapValue::String("lib.es2017.object.d.ts"),
+    ),
+    (
+        "es2017.sharedmemory",
+        OptionMapValue::String("lib.es2017.sharedmemory.d.ts"),
+    ),
+    (
+        "es2017.string",
+        OptionMapValue::String("lib.es2017.string.d.ts"),
+    ),
+    (
+        "es2017.intl",
+        OptionMapValue::String("lib.es2017.intl.d.ts"),
+    ),
+    (
+        "es2017.typedarrays",
+        OptionMapValue::String("lib.es2017.typedarrays.d.ts"),
+    ),
+    (
+        "es2018.asyncgenerator",
+        OptionMapValue::String("lib.es2018.asyncgenerator.d.ts"),
+    ),
+    (
+        "es2018.asynciterable",
+        OptionMapValue::String("lib.es2018.asynciterable.d.ts"),
+    ),
+    (
+        "es2018.intl",
+        OptionMapValue::String("lib.es2018.intl.d.ts"),
+    ),
+    (
+        "es2018.promise",
+        OptionMapValue::String("lib.es2018.promise.d.ts"),
+    ),
+    (
+        "es2018.regexp",
+        OptionMapValue::String("lib.es2018.regexp.d.ts"),
+    ),
+    (
+        "es2019.array",
+        OptionMapValue::String("lib.es2019.array.d.ts"),
+    ),
+    (
+        "es2019.object",
+        OptionMapValue::String("lib.es2019.object.d.ts"),
+    ),
+    (
+        "es2019.string",
+        OptionMapValue::String("lib.es2019.string.d.ts"),
+    ),
+    (
+        "es2019.symbol",
+        OptionMapValue::String("lib.es2019.symbol.d.ts"),
+    ),
+    (
+        "es2020.bigint",
+        OptionMapValue::String("lib.es2020.bigint.d.ts"),
+    ),
+    (
+        "es2020.promise",
+        OptionMapValue::String("lib.es2020.promise.d.ts"),
+    ),
+    (
+        "es2020.sharedmemory",
+        OptionMapValue::String("lib.es2020.sharedmemory.d.ts"),
+    ),
+    (
+        "es2020.string",
+        OptionMapValue::String("lib.es2020.string.d.ts"),
+    ),
+    (
+        "es2020.symbol.wellknown",
+        OptionMapValue::String("lib.es2020.symbol.wellknown.d.ts"),
+    ),
+    (
+        "es2020.intl",
+        OptionMapValue::String("lib.es2020.intl.d.ts"),
+    ),
+    (
+        "es2021.promise",
+        OptionMapValue::String("lib.es2021.promise.d.ts"),
+    ),
+    (
+        "es2021.string",
+        OptionMapValue::String("lib.es2021.string.d.ts"),
+    ),
+    (
+        "es2021.weakref",
+        OptionMapValue::String("lib.es2021.weakref.d.ts"),
+    ),
+    (
+        "esnext.array",
+        OptionMapValue::String("lib.es2019.array.d.ts"),
+    ),
+    (
+        "esnext.symbol",
+        OptionMapValue::String("lib.es2019.symbol.d.ts"),
+    ),
+    (
+        "esnext.asynciterable",
+        OptionMapValue::String("lib.es2018.asynciterable.d.ts"),
+    ),
+    (
+        "esnext.intl",
+        OptionMapValue::String("lib.esnext.intl.d.ts"),
+    ),
+    (
+        "esnext.bigint",
+        OptionMapValue::String("lib.es2020.bigint.d.ts"),
+    ),
+    (
+        "esnext.string",
+        OptionMapValue::String("lib.es2021.string.d.ts"),
+    ),
+    (
+        "esnext.promise",
+        OptionMapValue::String("lib.es2021.promise.d.ts"),
+    ),
+    (
+        "esnext.weakref",
+        OptionMapValue::String("lib.es2021.weakref.d.ts"),
+    ),
+];
+
+const JSX_MAP: &[(&str, OptionMapValue)] = &[
+    ("preserve", OptionMapValue::Number(1)),
+    ("react-native", OptionMapValue::Number(3)),
+    ("react", OptionMapValue::Number(2)),
+    ("react-jsx", OptionMapValue::Number(4)),
+    ("react-jsxdev", OptionMapValue::Number(5)),
+];
+
+const IMPORTS_NOT_USED_AS_VALUES_MAP: &[(&str, OptionMapValue)] = &[
+    ("remove", OptionMapValue::Number(0)),
+    ("preserve", OptionMapValue::Number(1)),
+    ("error", OptionMapValue::Number(2)),
+];
+
+const MODULE_RESOLUTION_MAP: &[(&str, OptionMapValue)] = &[
+    ("node", OptionMapValue::Number(2)),
+    ("classic", OptionMapValue::Number(1)),
+];
+
+const NEW_LINE_MAP: &[(&str, OptionMapValue)] = &[
+    ("crlf", OptionMapValue::Number(0)),
+    ("lf", OptionMapValue::Number(1)),
+];
+
+const WATCH_FILE_MAP: &[(&str, OptionMapValue)] = &[
+    ("fixedpollinginterval", OptionMapValue::Number(0)),
+    ("prioritypollinginterval", OptionMapValue::Number(1)),
+    ("dynamicprioritypolling", OptionMapValue::Number(2)),
+    ("fixedchunksizepolling", OptionMapValue::Number(3)),
+    ("usefsevents", OptionMapValue::Number(4)),
+    ("usefseventsonparentdirectory", OptionMapValue::Number(5)),
+];
+
+const WATCH_DIRECTORY_MAP: &[(&str, OptionMapValue)] = &[
+    ("usefsevents", OptionMapValue::Number(0)),
+    ("fixedpollinginterval", OptionMapValue::Number(1)),
+    ("dynamicprioritypolling", OptionMapValue::Number(2)),
+    ("fixedchunksizepolling", OptionMapValue::Number(3)),
+];
+
+const FALLBACK_POLLING_MAP: &[(&str, OptionMapValue)] = &[
+    ("fixedinterval", OptionMapValue::Number(0)),
+    ("priorityinterval", OptionMapValue::Number(1)),
+    ("dynamicpriority", OptionMapValue::Number(2)),
+    ("fixedchunksize", OptionMapValue::Number(3)),
+];
+
+const LIB_ELEMENT: CommandLineOption = option("lib", CommandLineOptionType::Map(LIB_MAP));
+const ROOT_DIRS_ELEMENT: CommandLineOption =
+    file_path_option("rootDirs", CommandLineOptionType::String);
+const TYPE_ROOTS_ELEMENT: CommandLineOption =
+    file_path_option("typeRoots", CommandLineOptionType::String);
+const TYPES_ELEMENT: CommandLineOption = option("types", CommandLineOptionType::String);
+const PLUGINS_ELEMENT: CommandLineOption = option("plugin", CommandLineOptionType::Object);
+const EXCLUDE_DIRECTORIES_ELEMENT: CommandLineOption = CommandLineOption {
+    extra_validation: Some(spec_to_option_diagnostic),
+    ..file_path_option("excludeDirectory", CommandLineOptionType::String)
+};
+const EXCLUDE_FILES_ELEMENT: CommandLineOption = CommandLineOption {
+    extra_validation: Some(spec_to_option_diagnostic),
+    ..file_path_option("excludeFile", CommandLineOptionType::String)
+};
+const TYPE_ACQUISITION_INCLUDE_ELEMENT: CommandLineOption =
+    option("include", CommandLineOptionType::String);
+const TYPE_ACQUISITION_EXCLUDE_ELEMENT: CommandLineOption =
+    option("exclude", CommandLineOptionType::String);
+
+pub static OPTION_DECLARATIONS: &[CommandLineOption] = &[
+    option("help", CommandLineOptionType::Boolean),
+    option("watch", CommandLineOptionType::Boolean),
+    option("preserveWatchOutput", CommandLineOptionType::Boolean),
+    option("listFiles", CommandLineOptionType::Boolean),
+    option("explainFiles", CommandLineOptionType::Boolean),
+    option("listEmittedFiles", CommandLineOptionType::Boolean),
+    option("pretty", CommandLineOptionType::Boolean),
+    option("traceResolution", CommandLineOptionType::Boolean),
+    option("diagnostics", CommandLineOptionType::Boolean),
+    option("extendedDiagnostics", CommandLineOptionType::Boolean),
+    file_path_option("generateCpuProfile", CommandLineOptionType::String),
+    file_path_option("generateTrace", CommandLineOptionType::String),
+    option("incremental", CommandLineOptionType::Boolean),
+    option(
+        "assumeChangesOnlyAffectDirectDependencies",
+        CommandLineOptionType::Boolean,
+    ),
+    option("locale", CommandLineOptionType::String),
+    option("all", CommandLineOptionType::Boolean),
+    option("version", CommandLineOptionType::Boolean),
+    option("init", CommandLineOptionType::Boolean),
+    file_path_option("project", CommandLineOptionType::String),
+    option("build", CommandLineOptionType::Boolean),
+    option("showConfig", CommandLineOptionType::Boolean),
+    option("listFilesOnly", CommandLineOptionType::Boolean),
+    option("target", CommandLineOptionType::Map(TARGET_MAP)),
+    option("module", CommandLineOptionType::Map(MODULE_MAP)),
+    option("lib", CommandLineOptionType::List(&LIB_ELEMENT)),
+    option("allowJs", CommandLineOptionType::Boolean),
+    option("checkJs", CommandLineOptionType::Boolean),
+    option("jsx", CommandLineOptionType::Map(JSX_MAP)),
+    option("declaration", CommandLineOptionType::Boolean),
+    option("declarationMap", CommandLineOptionType::Boolean),
+    option("emitDeclarationOnly", CommandLineOptionType::Boolean),
+    option("sourceMap", CommandLineOptionType::Boolean),
+    file_path_option("outFile", CommandLineOptionType::String),
+    file_path_option("outDir", CommandLineOptionType::String),
+    file_path_option("rootDir", CommandLineOptionType::String),
+    option("composite", CommandLineOptionType::Boolean),
+    file_path_option("tsBuildInfoFile", CommandLineOptionType::String),
+    option("removeComments", CommandLineOptionType::Boolean),
+    option("noEmit", CommandLineOptionType::Boolean),
+    option("importHelpers", CommandLineOptionType::Boolean),
+    option(
+        "importsNotUsedAsValues",
+        CommandLineOptionType::Map(IMPORTS_NOT_USED_AS_VALUES_MAP),
+    ),
+    option("downlevelIteration", CommandLineOptionType::Boolean),
+    option("isolatedModules", CommandLineOptionType::Boolean),
+    option("strict", CommandLineOptionType::Boolean),
+    option("noImplicitAny", CommandLineOptionType::Boolean),
+    option("strictNullChecks", CommandLineOptionType::Boolean),
+    option("strictFunctionTypes", CommandLineOptionType::Boolean),
+    option("strictBindCallApply", CommandLineOptionType::Boolean),
+    option(
+        "strictPropertyInitialization",
+        CommandLineOptionType::Boolean,
+    ),
+    option("strictOptionalProperties", CommandLineOptionType::Boolean),
+    option("noImplicitThis", CommandLineOptionType::Boolean),
+    option("useUnknownInCatchVariables", CommandLineOptionType::Boolean),
+    option("alwaysStrict", CommandLineOptionType::Boolean),
+    option("noUnusedLocals", CommandLineOptionType::Boolean),
+    option("noUnusedParameters", CommandLineOptionType::Boolean),
+    option("noImplicitReturns", CommandLineOptionType::Boolean),
+    option("noFallthroughCasesInSwitch", CommandLineOptionType::Boolean),
+    option("noUncheckedIndexedAccess", CommandLineOptionType::Boolean),
+    option("noImplicitOverride", CommandLineOptionType::Boolean),
+    option(
+        "noPropertyAccessFromIndexSignature",
+        CommandLineOptionType::Boolean,
+    ),
+    option(
+        "moduleResolution",
+        CommandLineOptionType::Map(MODULE_RESOLUTION_MAP),
+    ),
+    file_path_option("baseUrl", CommandLineOptionType::String),
+    option("paths", CommandLineOptionType::Object),
+    option("rootDirs", CommandLineOptionType::List(&ROOT_DIRS_ELEMENT)),
+    option(
+        "typeRoots",
+        CommandLineOptionType::List(&TYPE_ROOTS_ELEMENT),
+    ),
+    option("types", CommandLineOptionType::List(&TYPES_ELEMENT)),
+    option(
+        "allowSyntheticDefaultImports",
+        CommandLineOptionType::Boolean,
+    ),
+    option("esModuleInterop", CommandLineOptionType::Boolean),
+    option("preserveSymlinks", CommandLineOptionType::Boolean),
+    option("allowUmdGlobalAccess", CommandLineOptionType::Boolean),
+    option("sourceRoot", CommandLineOptionType::String),
+    option("mapRoot", CommandLineOptionType::String),
+    option("inlineSourceMap", CommandLineOptionType::Boolean),
+    option("inlineSources", CommandLineOptionType::Boolean),
+    option("experimentalDecorators", CommandLineOptionType::Boolean),
+    option("emitDecoratorMetadata", CommandLineOptionType::Boolean),
+    option("jsxFactory", CommandLineOptionType::String),
+    option("jsxFragmentFactory", CommandLineOptionType::String),
+    option("jsxImportSource", CommandLineOptionType::String),
+    option("resolveJsonModule", CommandLineOptionType::Boolean),
+    option("out", CommandLineOptionType::String),
+    option("reactNamespace", CommandLineOptionType::String),
+    option("skipDefaultLibCheck", CommandLineOptionType::Boolean),
+    option("charset", CommandLineOptionType::String),
+    option("emitBOM", CommandLineOptionType::Boolean),
+    option("newLine", CommandLineOptionType::Map(NEW_LINE_MAP)),
+    option("noErrorTruncation", CommandLineOptionType::Boolean),
+    option("noLib", CommandLineOptionType::Boolean),
+    option("noResolve", CommandLineOptionType::Boolean),
+    option("stripInternal", CommandLineOptionType::Boolean),
+    option("disableSizeLimit", CommandLineOptionType::Boolean),
+    option(
+        "disableSourceOfProjectReferenceRedirect",
+        CommandLineOptionType::Boolean,
+    ),
+    option("disableSolutionSearching", CommandLineOptionType::Boolean),
+    option(
+        "disableReferencedProjectLoad",
+        CommandLineOptionType::Boolean,
+    ),
+    option("noImplicitUseStrict", CommandLineOptionType::Boolean),
+    option("noEmitHelpers", CommandLineOptionType::Boolean),
+    option("noEmitOnError", CommandLineOptionType::Boolean),
+    option("preserveConstEnums", CommandLineOptionType::Boolean),
+    file_path_option("declarationDir", CommandLineOptionType::String),
+    option("skipLibCheck", CommandLineOptionType::Boolean),
+    option("allowUnusedLabels", CommandLineOptionType::Boolean),
+    option("allowUnreachableCode", CommandLineOptionType::Boolean),
+    option(
+        "suppressExcessPropertyErrors",
+        CommandLineOptionType::Boolean,
+    ),
+    option(
+        "suppressImplicitAnyIndexErrors",
+        CommandLineOptionType::Boolean,
+    ),
+    option(
+        "forceConsistentCasingInFileNames",
+        CommandLineOptionType::Boolean,
+    ),
+    option("maxNodeModuleJsDepth", CommandLineOptionType::Number),
+    option("noStrictGenericChecks", CommandLineOptionType::Boolean),
+    option("useDefineForClassFields", CommandLineOptionType::Boolean),
+    option("keyofStringsOnly", CommandLineOptionType::Boolean),
+    option("plugins", CommandLineOptionType::List(&PLUGINS_ELEMENT)),
+];
+
+pub static OPTIONS_FOR_WATCH: &[CommandLineOption] = &[
+    option("watchFile", CommandLineOptionType::Map(WATCH_FILE_MAP)),
+    option(
+        "watchDirectory",
+        CommandLineOptionType::Map(WATCH_DIRECTORY_MAP),
+    ),
+    option(
+        "fallbackPolling",
+        CommandLineOptionType::Map(FALLBACK_POLLING_MAP),
+    ),
+    option("synchronousWatchDirectory", CommandLineOptionType::Boolean),
+    option(
+        "excludeDirectories",
+        CommandLineOptionType::List(&EXCLUDE_DIRECTORIES_ELEMENT),
+    ),
+    option(
+        "excludeFiles",
+        CommandLineOptionType::List(&EXCLUDE_FILES_ELEMENT),
+    ),
+];
+
+pub static TYPE_ACQUISITION_DECLARATIONS: &[CommandLineOption] = &[
+    option("enableAutoDiscovery", CommandLineOptionType::Boolean),
+    option("enable", CommandLineOptionType::Boolean),
+    option(
+        "include",
+        CommandLineOptionType::List(&TYPE_ACQUISITION_INCLUDE_ELEMENT),
+    ),
+    option(
+        "exclude",
+        CommandLineOptionType::List(&TYPE_ACQUISITION_EXCLUDE_ELEMENT),
+    ),
+    option(
+        "disableFilenameBasedTypeAcquisition",
+        CommandLineOptionType::Boolean,
+    ),
+];
+
+const REFERENCES_ELEMENT: CommandLineOption = option("references", CommandLineOptionType::Object);
+const FILES_ELEMENT: CommandLineOption = option("files", CommandLineOptionType::String);
+const INCLUDE_ELEMENT: CommandLineOption = option("include", CommandLineOptionType::String);
+const EXCLUDE_ELEMENT: CommandLineOption = option("exclude", CommandLineOptionType::String);
+
+// The keys allowed at the root of a `tsconfig.json`. Other keys are ignored without an error.
+static TSCONFIG_ROOT_OPTIONS: &[CommandLineOption] = &[
+    option("compilerOptions", CommandLineOptionType::Object),
+    option("watchOptions", CommandLineOptionType::Object),
+    option("typingOptions", CommandLineOptionType::Object),
+    option("typeAcquisition", CommandLineOptionType::Object),
+    option("extends", CommandLineOptionType::String),
+    option(
+        "references",
+        CommandLineOptionType::List(&REFERENCES_ELEMENT),
+    ),
+    option("files", CommandLineOptionType::List(&FILES_ELEMENT)),
+    option("include", CommandLineOptionType::List(&INCLUDE_ELEMENT)),
+    option("exclude", CommandLineOptionType::List(&EXCLUDE_ELEMENT)),
+    option("compileOnSave", CommandLineOptionType::Boolean),
+];
+
+// Lower cased names of the options of `tsc --build`, which are reported differently when they
+// are used in `compilerOptions`.
+static BUILD_OPTION_NAMES: &[&str] = &[
+    "help",
+    "watch",
+    "preservewatchoutput",
+    "listfiles",
+    "explainfiles",
+    "listemittedfiles",
+    "pretty",
+    "traceresolution",
+    "diagnostics",
+    "extendeddiagnostics",
+    "generatecpuprofile",
+    "generatetrace",
+    "incremental",
+    "assumechangesonlyaffectdirectdependencies",
+    "locale",
+    "verbose",
+    "dry",
+    "force",
+    "clean",
+];
+
+struct AlternateModeDiagnostics {
+    diagnostic: IDiagnosticMessage<'static>,
+    option_names: &'static [&'static str],
+}
+
+struct DidYouMeanOptionsDiagnostics {
+    alternate_mode: Option<AlternateModeDiagnostics>,
+    option_declarations: &'static [CommandLineOption],
+    unknown_option_diagnostic: IDiagnosticMessage<'static>,
+    unknown_did_you_mean_diagnostic: IDiagnosticMessage<'static>,
+}
+
+const COMPILER_OPTIONS_DID_YOU_MEAN_DIAGNOSTICS: DidYouMeanOptionsDiagnostics =
+    DidYouMeanOptionsDiagnostics {
+        alternate_mode: Some(AlternateModeDiagnostics {
+            diagnostic: Diagnostics::Compiler_option_0_may_only_be_used_with_build,
+            option_names: BUILD_OPTION_NAMES,
+        }),
+        option_declarations: OPTION_DECLARATIONS,
+        unknown_option_diagnostic: Diagnostics::Unknown_compiler_option_0,
+        unknown_did_you_mean_diagnostic: Diagnostics::Unknown_compiler_option_0_Did_you_mean_1,
+    };
+
+const WATCH_OPTIONS_DID_YOU_MEAN_DIAGNOSTICS: DidYouMeanOptionsDiagnostics =
+    DidYouMeanOptionsDiagnostics {
+        alternate_mode: None,
+        option_declarations: OPTIONS_FOR_WATCH,
+        unknown_option_diagnostic: Diagnostics::Unknown_watch_option_0,
+        unknown_did_you_mean_diagnostic: Diagnostics::Unknown_watch_option_0_Did_you_mean_1,
+    };
+
+const TYPE_ACQUISITION_DID_YOU_MEAN_DIAGNOSTICS: DidYouMeanOptionsDiagnostics =
+    DidYouMeanOptionsDiagnostics {
+        alternate_mode: None,
+        option_declarations: TYPE_ACQUISITION_DECLARATIONS,
+        unknown_option_diagnostic: Diagnostics::Unknown_type_acquisition_option_0,
+        unknown_did_you_mean_diagnostic:
+            Diagnostics::Unknown_type_acquisition_option_0_Did_you_mean_1,
+    };
+
+// The nested option objects of a `tsconfig.json`, whose keys are validated.
+fn get_did_you_mean_options_diagnostics(
+    root_option_name: &str,
+) -> Option<DidYouMeanOptionsDiagnostics> {
+    match root_option_name {
+        "compilerOptions" => Some(COMPILER_OPTIONS_DID_YOU_MEAN_DIAGNOSTICS),
+        "watchOptions" => Some(WATCH_OPTIONS_DID_YOU_MEAN_DIAGNOSTICS),
+        "typingOptions" | "typeAcquisition" => Some(TYPE_ACQUISITION_DID_YOU_MEAN_DIAGNOSTICS),
+        _ => None,
+    }
+}
+
+fn create_unknown_option_error(
+    unknown_option: &str,
+    diagnostics: &DidYouMeanOptionsDiagnostics,
+) -> (IDiagnosticMessage<'static>, Vec<String>) {
+    if let Some(alternate_mode) = &diagnostics.alternate_mode {
+        let lower_case_option = unknown_option.to_lowercase();
+        if alternate_mode
+            .option_names
+            .contains(&lower_case_option.as_str())
+        {
+            return (
+                alternate_mode.diagnostic.clone(),
+                vec![unknown_option.to_string()],
+            );
+        }
+    }
+
+    let possible_option =
+        get_spelling_suggestion(unknown_option, diagnostics.option_declarations, |option| {
+            Some(option.name)
+        });
+    match possible_option {
+        Some(possible_option) => (
+            diagnostics.unknown_did_you_mean_diagnostic.clone(),
+            vec![unknown_option.to_string(), possible_option.name.to_string()],
+        ),
+        None => (
+            diagnostics.unknown_option_diagnostic.clone(),
+            vec![unknown_option.to_string()],
+        ),
+    }
+}
+
+fn get_compiler_option_value_type_string(option: &CommandLineOption) -> &'static str {
+    match option.option_type {
+        CommandLineOptionType::String | CommandLineOptionType::Map(_) => "string",
+        CommandLineOptionType::Number => "number",
+        CommandLineOptionType::Boolean => "boolean",
+        CommandLineOptionType::Object => "object",
+        CommandLineOptionType::List(_) => "Array",
+    }
+}
+
+fn is_compiler_options_value(option: &CommandLineOption, value: &Option<JsonValue>) -> bool {
+    let value = match value {
+        // All options are undefinable/nullable
+        None | Some(JsonValue::Null) => return true,
+        Some(value) => value,
+    };
+    match option.option_type {
+        CommandLineOptionType::List(_) => matches!(value, JsonValue::Array(_)),
+        CommandLineOptionType::String | CommandLineOptionType::Map(_) => {
+            matches!(value, JsonValue::String(_))
+        }
+        CommandLineOptionType::Number => matches!(value, JsonValue::Number(_)),
+        CommandLineOptionType::Boolean => matches!(value, JsonValue::Boolean(_)),
+        // `typeof value === "object"` holds for arrays too.
+        CommandLineOptionType::Object => {
+            matches!(value, JsonValue::Object(_) | JsonValue::Array(_))
+        }
+    }
+}
+
+// The truthiness of a value in JS.
+fn is_truthy(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Object(_) | JsonValue::Array(_) => true,
+        JsonValue::String(value) => !value.is_empty(),
+        JsonValue::Number(value) => *value != 0.0 && !value.is_nan(),
+        JsonValue::Boolean(value) => *value,
+        JsonValue::Null => false,
+    }
+}
+
+fn get_map_value(map: &[(&str, OptionMapValue)], key: &str) -> Option<OptionMapValue> {
+    map.iter()
+        .find(|(map_key, _)| *map_key == key)
+        .map(|(_, value)| *value)
+}
+
+fn option_map_value_to_json(value: OptionMapValue) -> JsonValue {
+    match value {
+        OptionMapValue::Number(value) => JsonValue::Number(value as f64),
+        OptionMapValue::String(value) => JsonValue::String(value.to_string()),
+    }
+}
+
+fn normalize_option_value(
+    option: &CommandLineOption,
+    base_path: &str,
+    value: Option<JsonValue>,
+) -> Option<JsonValue> {
+    let value = match value {
+        None | Some(JsonValue::Null) => return None,
+        Some(value) => value,
+    };
+    match option.option_type {
+        CommandLineOptionType::List(element) => match value {
+            JsonValue::Array(elements)
+                if element.is_file_path
+                    || matches!(element.option_type, CommandLineOptionType::Map(_)) =>
+            {
+                let elements = elements
+                    .into_iter()
+                    .filter_map(|JsonNode { range, value }| {
+                        normalize_option_value(element, base_path, Some(value))
+                            .filter(is_truthy)
+                            .map(|value| JsonNode { range, value })
+                    })
+                    .collect();
+                Some(JsonValue::Array(elements))
+            }
+            value => Some(value),
+        },
+        CommandLineOptionType::Map(map) => match value {
+            JsonValue::String(value) => {
+                get_map_value(map, &value.to_lowercase()).map(option_map_value_to_json)
+            }
+            _ => None,
+        },
+        _ => Some(normalize_non_list_option_value(option, base_path, value)),
+    }
+}
+
+fn normalize_non_list_option_value(
+    option: &CommandLineOption,
+    base_path: &str,
+    value: JsonValue,
+) -> JsonValue {
+    match value {
+        JsonValue::String(value) if option.is_file_path => {
+            let value = get_normalized_absolute_path(&value, base_path);
+            JsonValue::String(if value.is_empty() {
+                ".".to_string()
+            } else {
+                value
+            })
+        }
+        value => value,
+    }
+}
+
+// Tests for a path that ends in a recursive directory wildcard, `(^|\/)\*\*\/?$`.
+fn has_invalid_trailing_recursion(spec: &str) -> bool {
+    let spec = spec.strip_suffix('/').unwrap_or(spec);
+    spec == "**" || spec.ends_with("/**")
+}
+
+// Tests for a path where .. appears after a recursive directory wildcard.
+fn invalid_dot_dot_after_recursive_wildcard(s: &str) -> bool {
+    // We used to use the regex /(^|\/)\*\*\/(.*\/)?\.\.($|\/)/ to check for this case, but
+    // in v8, that has polynomial performance because the recursive wildcard match - **/ -
+    // can be matched in many arbitrary positions when multiple are present, resulting
+    // in bad backtracking (and we don't care which is matched - just that some /.. segment
+    // comes after some **/ segment).
+    let wildcard_index = if s.starts_with("**/") {
+        Some(0)
+    } else {
+        s.find("/**/")
+    };
+    let wildcard_index = match wildcard_index {
+        Some(index) => index,
+        None => return false,
+    };
+    let last_dot_index = if s.ends_with("/..") {
+        Some(s.len())
+    } else {
+        s.rfind("/../")
+    };
+    last_dot_index.is_some_and(|index| index > wildcard_index)
+}
+
+fn spec_to_diagnostic(
+    spec: &str,
+    disallow_trailing_recursion: bool,
+) -> Option<IDiagnosticMessage<'static>> {
+    if disallow_trailing_recursion && has_invalid_trailing_recursion(spec) {
+        Some(Diagnostics::File_specification_cannot_end_in_a_recursive_directory_wildcard_Asterisk_Asterisk_Colon_0)
+    } else if invalid_dot_dot_after_recursive_wildcard(spec) {
+        Some(Diagnostics::File_specification_cannot_contain_a_parent_directory_that_appears_after_a_recursive_directory_wildcard_Asterisk_Asterisk_Colon_0)
+    } else {
+        None
+    }
+}
+
+// The `extraValidation` of the `excludeDirectories` and `excludeFiles` watch options.
+fn spec_to_option_diagnostic(value: &JsonValue) -> Option<(IDiagnosticMessage<'static>, String)> {
+    match value {
+        JsonValue::String(spec) => {
+            spec_to_diagnostic(spec, false).map(|message| (message, spec.to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn get_default_compiler_options(config_file_name: &str) -> CompilerOptions {
+    let mut options = CompilerOptions::new();
+    if get_base_file_name(config_file_name) == "jsconfig.json" {
+        options.insert("allowJs".to_string(), Some(JsonValue::Boolean(true)));
+        options.insert(
+            "maxNodeModuleJsDepth".to_string(),
+            Some(JsonValue::Number(2.0)),
+        );
+        options.insert(
+            "allowSyntheticDefaultImports".to_string(),
+            Some(JsonValue::Boolean(true)),
+        );
+        options.insert("skipLibCheck".to_string(), Some(JsonValue::Boolean(true)));
+        options.insert("noEmit".to_string(), Some(JsonValue::Boolean(true)));
+    }
+    options
+}
+
+/// Extended configs that were already read, keyed by path. Share one cache between the configs
+/// of a project tree so every extended config is read and converted only once.
+pub type ExtendedConfigCache = HashMap<String, ExtendedConfigCacheEntry>;
+
+pub struct ExtendedConfigCacheEntry {
+    file_name: String,
+    parse_diagnostics: Vec<Diagnostic>,
+    extended_config: Option<ParsedTsconfig>,
+}
+
+// A config file as read from disk.
+struct TsConfigSourceFile {
+    json: JsonSourceFile,
+    // The UTF-16 text of the file, to report errors at node positions.
+    text: Vec<u16>,
+    // Syntax errors, or the error for a file that could not be read.
+    parse_diagnostics: Vec<Diagnostic>,
+}
+
+impl TsConfigSourceFile {
+    fn new(file_name: &str, text: String) -> TsConfigSourceFile {
+        let utf16_text = text.encode_utf16().collect();
+        let json = parse_json_text(file_name, text);
+        let parse_diagnostics = json
+            .parse_diagnostics
+            .iter()
+            .cloned()
+            .map(Diagnostic::from)
+            .collect();
+        TsConfigSourceFile {
+            json,
+            text: utf16_text,
+            parse_diagnostics,
+        }
+    }
+
+    fn root_properties(&self) -> &[JsonProperty] {
+        match &self.json.value {
+            Some(JsonNode {
+                value: JsonValue::Object(properties),
+                ..
+            }) => properties,
+            _ => &[],
+        }
+    }
+
+    // The initializer of the root property `prop_key`, like `getTsConfigPropArray`.
+    fn get_prop_initializer(&self, prop_key: &str) -> Option<&JsonNode> {
+        self.root_properties()
+            .iter()
+            .find(|property| property.name == prop_key)
+            .map(|property| &property.initializer)
+    }
+
+    fn get_prop_array_element_value(
+        &self,
+        prop_key: &str,
+        element_value: &str,
+    ) -> Option<&JsonNode> {
+        self.root_properties()
+            .iter()
+            .filter(|property| property.name == prop_key)
+            .find_map(|property| {
+                match &property.initializer.value {
+                JsonValue::Array(elements) => elements.iter().find(|element| {
+                    matches!(&element.value, JsonValue::String(value) if value == element_value)
+                }),
+                _ => None,
+            }
+            })
+    }
+
+    fn create_diagnostic_for_node(
+        &self,
+        range: TextRange,
+        message: &IDiagnosticMessage,
+        args: &[&str],
+    ) -> Diagnostic {
+        let start = skip_trivia(&self.text, range.pos, false, false, false).min(range.end);
+        create_file_diagnostic(
+            &self.json.file_name,
+            start,
+            range.end - start,
+            message,
+            args,
+        )
+        .into()
+    }
+}
+
+fn read_json_config_file(file_name: &str, host: &dyn ParseConfigHost) -> TsConfigSourceFile {
+    match host.read_file(file_name) {
+        Ok(text) => TsConfigSourceFile::new(file_name, text),
+        Err(error) => {
+            let mut source_file = TsConfigSourceFile::new(file_name, String::new());
+            source_file.parse_diagnostics =
+                vec![create_cannot_read_file_diagnostic(file_name, &error)];
+            source_file
+        }
+    }
+}
+
+// The raw values of the root keys that are inherited or read after the `extends` chain is
+// merged. Like `raw` in the JS compiler, `None` is a key that is missing or set to `undefined`.
+#[derive(Clone, Default)]
+struct RawTsconfig {
+    compiler_options: Option<JsonValue>,
+    has_extends: bool,
+    references: Option<JsonValue>,
+    files: Option<JsonValue>,
+    include: Option<JsonValue>,
+    exclude: Option<JsonValue>,
+    compile_on_save: Option<JsonValue>,
+}
+
+#[derive(Clone)]
+struct ParsedTsconfig {
+    raw: RawTsconfig,
+    // `None` when a circular `extends` stopped the config from being converted.
+    options: Option<CompilerOptions>,
+    watch_options: Option<CompilerOptions>,
+    extended_config_path: Option<String>,
+    extended_source_files: Vec<String>,
+}
+
+// Converts the values of a config file to options, reporting errors at their nodes like
+// `convertToObjectWorker`.
+struct JsonConversion<'a> {
+    source_file: &'a TsConfigSourceFile,
+    errors: &'a mut Vec<Diagnostic>,
+}
+
+impl JsonConversion<'_> {
+    fn error_at_node(&mut self, range: TextRange, message: &IDiagnosticMessage, args: &[&str]) {
+        let diagnostic = self
+            .source_file
+            .create_diagnostic_for_node(range, message, args);
+        self.errors.push(diagnostic);
+    }
+
+    // Returns true if an error was reported.
+    fn report_invalid_option_value(
+        &mut self,
+        node: &JsonNode,
+        option: Option<&CommandLineOption>,
+        is_error: impl Fn(&CommandLineOption) -> bool,
+    ) -> bool {
+        match option {
+            Some(option) if is_error(option) => {
+                self.error_at_node(
+                    node.range,
+                    &Diagnostics::Compiler_option_0_requires_a_value_of_type_1,
+                    &[option.name, get_compiler_option_value_type_string(option)],
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Returns the value of `node` for `option`, or `None` (`undefined`) for values that fail the
+    // extra validation of the option.
+    fn convert_property_value(
+        &mut self,
+        node: &JsonNode,
+        option: Option<&CommandLineOption>,
+    ) -> Option<JsonValue> {
+        let mut invalid_reported;
+        let value = match &node.value {
+            JsonValue::Boolean(_) => {
+                invalid_reported = self.report_invalid_option_value(node, option, |option| {
+                    !matches!(option.option_type, CommandLineOptionType::Boolean)
+                });
+                node.value.clone()
+            }
+            JsonValue::Null => {
+                // "extends" is the only option we don't allow null/undefined for
+                invalid_reported = self
+                    .report_invalid_option_value(node, option, |option| option.name == "extends");
+                JsonValue::Null
+            }
+            JsonValue::String(text) => {
+                invalid_reported = self.report_invalid_option_value(node, option, |option| {
+                    !matches!(
+                        option.option_type,
+                        CommandLineOptionType::String | CommandLineOptionType::Map(_)
+                    )
+                });
+                if let Some(CommandLineOption {
+                    option_type: CommandLineOptionType::Map(map),
+                    name,
+                    ..
+                }) = option
+                {
+                    // Validate custom option type
+                    if get_map_value(map, &text.to_lowercase()).is_none() {
+                        let names_of_type = map
+                            .iter()
+                            .map(|(key, _)| format!("'{}'", key))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        self.error_at_node(
+                            node.range,
+                            &Diagnostics::Argument_for_0_option_must_be_Colon_1,
+                            &[&format!("--{}", name), &names_of_type],
+                        );
+                        invalid_reported = true;
+                    }
+                }
+                node.value.clone()
+            }
+            JsonValue::Number(_) => {
+                invalid_reported = self.report_invalid_option_value(node, option, |option| {
+                    !matches!(option.option_type, CommandLineOptionType::Number)
+                });
+                node.value.clone()
+            }
+            JsonValue::Object(_) => {
+                invalid_reported = self.report_invalid_option_value(node, option, |option| {
+                    !matches!(option.option_type, CommandLineOptionType::Object)
+                });
+                // Only the option objects at the root have declarations for their keys.
+                node.value.clone()
+            }
+            JsonValue::Array(elements) => {
+                invalid_reported = self.report_invalid_option_value(node, option, |option| {
+                    !matches!(option.option_type, CommandLineOptionType::List(_))
+                });
+                let element_option = match option {
+                    Some(CommandLineOption {
+                        option_type: CommandLineOptionType::List(element),
+                        ..
+                    }) => Some(*element),
+                    _ => None,
+                };
+                // Filter out invalid values
+                let elements = elements
+                    .iter()
+                    .filter_map(|element| {
+                        self.convert_property_value(element, element_option)
+                            .map(|value| JsonNode {
+                                range: element.range,
+                                value,
+                            })
+                    })
+                    .collect();
+                JsonValue::Array(elements)
+            }
+        };
+
+        if !invalid_reported {
+            let extra_validation = option.and_then(|option| option.extra_validation);
+            if let Some((message, arg)) = extra_validation.and_then(|validate| validate(&value)) {
+                self.error_at_node(node.range, &message, &[&arg]);
+                return None;
+            }
+        }
+        Some(value)
+    }
+
+    // Converts one of the option objects at the root, like `compilerOptions`, calling
+    // `on_set_valid_option` for every known option with a valid value.
+    fn convert_options_object(
+        &mut self,
+        properties: &[JsonProperty],
+        diagnostics: &DidYouMeanOptionsDiagnostics,
+        mut on_set_valid_option: impl FnMut(&CommandLineOption, Option<JsonValue>),
+    ) {
+        for property in properties {
+            let option = diagnostics
+                .option_declarations
+                .iter()
+                .rev()
+                .find(|option| option.name == property.name);
+            let option = match option {
+                Some(option) => option,
+                None => {
+                    let (message, args) = create_unknown_option_error(&property.name, diagnostics);
+                    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                    self.error_at_node(property.name_range, &message, &args);
+                    continue;
+                }
+            };
+            let value = self.convert_property_value(&property.initializer, Some(option));
+            if is_compiler_options_value(option, &value) {
+                on_set_valid_option(option, value);
+            }
+        }
+    }
+}
+
+fn parse_own_config_of_json_source_file(
+    source_file: &TsConfigSourceFile,
+    host: &dyn ParseConfigHost,
+    base_path: &str,
+    config_file_name: &str,
+    errors: &mut Vec<Diagnostic>,
+) -> ParsedTsconfig {
+    let mut options = get_default_compiler_options(config_file_name);
+    let mut watch_options: Option<CompilerOptions> = None;
+    let mut extended_config_path = None;
+    let mut raw = RawTsconfig::default();
+
+    // Errors for values that are not JSON were found by the parser.
+    errors.extend(
+        source_file
+            .json
+            .json_diagnostics
+            .iter()
+            .cloned()
+            .map(Diagnostic::from),
+    );
+    let mut conversion = JsonConversion {
+        source_file,
+        errors,
+    };
+
+    for property in source_file.root_properties() {
+        let root_option = TSCONFIG_ROOT_OPTIONS
+            .iter()
+            .find(|option| option.name == property.name);
+        let root_option = match root_option {
+            Some(root_option) => root_option,
+            None => {
+                if property.name == "excludes" {
+                    conversion.error_at_node(
+                        property.name_range,
+                        &Diagnostics::Unknown_option_excludes_Did_you_mean_exclude,
+                        &[],
+                    );
+                }
+                continue;
+            }
+        };
+
+        let diagnostics = get_did_you_mean_options_diagnostics(root_option.name);
+        let value = match (&property.initializer.value, diagnostics) {
+            (JsonValue::Object(properties), Some(diagnostics)) => {
+                conversion.convert_options_object(properties, &diagnostics, |option, value| {
+                    let value = normalize_option_value(option, base_path, value);
+                    match root_option.name {
+                        "compilerOptions" => {
+                            options.insert(option.name.to_string(), value);
+                        }
+                        "watchOptions" => {
+                            watch_options
+                                .get_or_insert_with(CompilerOptions::new)
+                                .insert(option.name.to_string(), value);
+                        }
+                        // Type acquisition is only validated.
+                        _ => {}
+                    }
+                });
+                Some(property.initializer.value.clone())
+            }
+            _ => conversion.convert_property_value(&property.initializer, Some(root_option)),
+        };
+
+        match root_option.name {
+            "compilerOptions" => raw.compiler_options = value,
+            "extends" => {
+                raw.has_extends = true;
+                if let Some(JsonValue::String(extended_config)) = &value {
+                    let new_base = directory_of_combined_path(config_file_name, base_path);
+                    extended_config_path =
+                        get_extends_config_path(extended_config, host, &new_base);
+                    if extended_config_path.is_none() {
+                        conversion.error_at_node(
+                            property.initializer.range,
+                            &Diagnostics::File_0_not_found,
+                            &[&normalize_slashes(extended_config)],
+                        );
+                    }
+                }
+            }
+            "references" => raw.references = value,
+            "files" => raw.files = value,
+            "include" => raw.include = value,
+            "exclude" => raw.exclude = value,
+            "compileOnSave" => raw.compile_on_save = value,
+            _ => {}
+        }
+    }
+
+    ParsedTsconfig {
+        raw,
+        options: Some(options),
+        watch_options,
+        extended_config_path,
+        extended_source_files: Vec::new(),
+    }
+}
+
+fn get_extends_config_path(
+    extended_config: &str,
+    host: &dyn ParseConfigHost,
+    base_path: &str,
+) -> Option<String> {
+    let extended_config = normalize_slashes(extended_config);
+    if is_rooted_disk_path(&extended_config)
+        || extended_config.starts_with("./")
+        || extended_config.starts_with("../")
+    {
+        let mut extended_config_path = get_normalized_absolute_path(&extended_config, base_path);
+        if !host.file_exists(&extended_config_path) && !extended_config_path.ends_with(".json") {
+            extended_config_path = format!("{}.json", extended_config_path);
+            if !host.file_exists(&extended_config_path) {
+                return None;
+            }
+        }
+        return Some(extended_config_path);
+    }
+    // If the path isn't a rooted or relative path, resolve like a module
+    resolve_tsconfig_module_name(&extended_config, base_path, host)
+}
+
+fn get_extended_config(
+    extended_config_path: &str,
+    host: &dyn ParseConfigHost,
+    resolution_stack: &[String],
+    errors: &mut Vec<Diagnostic>,
+    extended_config_cache: &mut ExtendedConfigCache,
+    extended_source_files: &mut Vec<String>,
+) -> Option<ParsedTsconfig> {
+    let path = if host.use_case_sensitive_file_names() {
+        extended_config_path.to_string()
+    } else {
+        to_file_name_lower_case(extended_config_path)
+    };
+    if !extended_config_cache.contains_key(&path) {
+        let extended_result = read_json_config_file(extended_config_path, host);
+        let extended_config = if extended_result.parse_diagnostics.is_empty() {
+            Some(parse_config(
+                &extended_result,
+                host,
+                &get_directory_path(extended_config_path),
+                &get_base_file_name(extended_config_path),
+                resolution_stack,
+                errors,
+                extended_config_cache,
+            ))
+        } else {
+            None
+        };
+        extended_config_cache.insert(
+            path.clone(),
+            ExtendedConfigCacheEntry {
+                file_name: extended_result.json.file_name,
+                parse_diagnostics: extended_result.parse_diagnostics,
+                extended_config,
+            },
+        );
+    }
+
+    let entry = &extended_config_cache[&path];
+    *extended_source_files = vec![entry.file_name.clone()];
+    if let Some(extended_config) = &entry.extended_config {
+        extended_source_files.extend(extended_config.extended_source_files.iter().cloned());
+    }
+    if !entry.parse_diagnostics.is_empty() {
+        errors.extend(entry.parse_diagnostics.iter().cloned());
+        return None;
+    }
+    entry.extended_config.clone()
+}
+
+// Sets `raw[prop]` from the extended config when it is not set, rebasing relative paths to the
+// directory of the extending config.
+fn set_property_in_raw_if_not_undefined(
+    raw: &mut Option<JsonValue>,
+    base_raw: &Option<JsonValue>,
+    get_relative_difference: &mut impl FnMut() -> String,
+) {
+    if raw.as_ref().is_some_and(is_truthy) || !base_raw.as_ref().is_some_and(is_truthy) {
+        return;
+    }
+    *raw = match base_raw {
+        Some(JsonValue::Array(elements)) => {
+            let elements = elements
+                .iter()
+                .map(|element| match &element.value {
+                    JsonValue::String(path) if !is_rooted_disk_path(path) => JsonNode {
+                        range: element.range,
+                        value: JsonValue::String(combine_paths(
+                            &get_relative_difference(),
+                            &[path],
+                        )),
+                    },
+                    _ => element.clone(),
+                })
+                .collect();
+            Some(JsonValue::Array(elements))
+        }
+        base_raw => base_raw.clone(),
+    };
+}
+
+fn parse_config(
+    source_file: &TsConfigSourceFile,
+    host: &dyn ParseConfigHost,
+    base_path: &str,
+    config_file_name: &str,
+    resolution_stack: &[String],
+    errors: &mut Vec<Diagnostic>,
+    extended_config_cache: &mut ExtendedConfigCache,
+) -> ParsedTsconfig {
+    let base_path = normalize_slashes(base_path);
+    let resolved_path = get_normalized_absolute_path(config_file_name, &base_path);
+
+    if resolution_stack.contains(&resolved_path) {
+        let mut stack = resolution_stack.to_vec();
+        stack.push(resolved_path);
+        errors.push(create_compiler_diagnostic(
+            &Diagnostics::Circularity_detected_while_resolving_configuration_Colon_0,
+            &[&stack.join(" -> ")],
+        ));
+        errors.extend(
+            source_file
+                .json
+                .json_diagnostics
+                .iter()
+                .cloned()
+                .map(Diagnostic::from),
+        );
+        return ParsedTsconfig {
+            raw: RawTsconfig::default(),
+            options: None,
+            watch_options: None,
+            extended_config_path: None,
+            extended_source_files: Vec::new(),
+        };
+    }
+
+    let mut own_config = parse_own_config_of_json_source_file(
+        source_file,
+        host,
+        &base_path,
+        config_file_name,
+        errors,
+    );
+
+    if let Some(options) = &mut own_config.options {
+        if options.get("paths").is_some_and(Option::is_some) {
+            // If we end up needing to resolve relative paths from 'paths' relative to
+            // the config file location, we'll need to know where that config file was.
+            // Since 'paths' can be inherited from an extended config in another directory,
+            // we wouldn't know which directory to use unless we store it here.
+            options.insert(
+                "pathsBasePath".to_string(),
+                Some(JsonValue::String(base_path.clone())),
+            );
+        }
+    }
+
+    if let Some(extended_config_path) = own_config.extended_config_path.clone() {
+        // copy the resolution stack so it is never reused between branches in potential
+        // diamond-problem scenarios.
+        let mut resolution_stack = resolution_stack.to_vec();
+        resolution_stack.push(resolved_path);
+        let extended_config = get_extended_config(
+            &extended_config_path,
+            host,
+            &resolution_stack,
+            errors,
+            extended_config_cache,
+            &mut own_config.extended_source_files,
+        );
+        if let Some(extended_config) = extended_config {
+            if let Some(base_options) = extended_config.options {
+                let base_raw = &extended_config.raw;
+                let raw = &mut own_config.raw;
+                let mut relative_difference = None;
+                let mut get_relative_difference = || {
+                    relative_difference
+                        .get_or_insert_with(|| {
+                            let use_case_sensitive_file_names =
+                                host.use_case_sensitive_file_names();
+                            convert_to_relative_path(
+                                &get_directory_path(&extended_config_path),
+                                &base_path,
+                                |file_name: &str| {
+                                    if use_case_sensitive_file_names {
+                                        file_name.to_string()
+                                    } else {
+                                        to_file_name_lower_case(file_name)
+                                    }
+                                },
+                            )
+                        })
+                        .clone()
+                };
+                set_property_in_raw_if_not_undefined(
+                    &mut raw.include,
+                    &base_raw.include,
+                    &mut get_relative_difference,
+                );
+                set_property_in_raw_if_not_undefined(
+                    &mut raw.exclude,
+                    &base_raw.exclude,
+                    &mut get_relative_difference,
+                );
+                set_property_in_raw_if_not_undefined(
+                    &mut raw.files,
+                    &base_raw.files,
+                    &mut get_relative_difference,
+                );
+                if raw.compile_on_save.is_none() {
+                    raw.compile_on_save = base_raw.compile_on_save.clone();
+                }
+
+                let mut options = base_options;
+                options.extend(own_config.options.take().unwrap_or_default());
+                own_config.options = Some(options);
+                own_config.watch_options = match (
+                    own_config.watch_options.take(),
+                    extended_config.watch_options,
+                ) {
+                    (Some(watch_options), Some(mut base_watch_options)) => {
+                        base_watch_options.extend(watch_options);
+                        Some(base_watch_options)
+                    }
+                    (watch_options, base_watch_options) => watch_options.or(base_watch_options),
+                };
+            }
+        }
+    }
+
+    own_config
+}
+
+enum PropOfRaw<'a> {
+    NoProp,
+    NotArray,
+    Array(&'a [JsonNode]),
+}
+
+fn get_prop_from_raw(value: &Option<JsonValue>) -> PropOfRaw<'_> {
+    match value {
+        None | Some(JsonValue::Null) => PropOfRaw::NoProp,
+        Some(JsonValue::Array(elements)) => PropOfRaw::Array(elements),
+        Some(_) => PropOfRaw::NotArray,
+    }
+}
+
+fn get_specs_from_raw(value: &Option<JsonValue>) -> Option<Vec<String>> {
+    match get_prop_from_raw(value) {
+        PropOfRaw::Array(elements) => Some(
+            elements
+                .iter()
+                .filter_map(|element| match &element.value {
+                    JsonValue::String(spec) => Some(spec.clone()),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn validate_specs(
+    specs: &[String],
+    errors: &mut Vec<Diagnostic>,
+    disallow_trailing_recursion: bool,
+    source_file: &TsConfigSourceFile,
+    spec_key: &str,
+) -> Vec<String> {
+    specs
+        .iter()
+        .filter(
+            |spec| match spec_to_diagnostic(spec, disallow_trailing_recursion) {
+                Some(message) => {
+                    let diagnostic = match source_file.get_prop_array_element_value(spec_key, spec)
+                    {
+                        Some(element) => {
+                            source_file.create_diagnostic_for_node(element.range, &message, &[spec])
+                        }
+                        None => create_compiler_diagnostic(&message, &[spec]),
+                    };
+                    errors.push(diagnostic);
+                    false
+                }
+                None => true,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+fn get_config_file_specs(
+    raw: &RawTsconfig,
+    source_file: &TsConfigSourceFile,
+    config_file_name: &str,
+    errors: &mut Vec<Diagnostic>,
+) -> ConfigFileSpecs {
+    let files_specs = get_specs_from_raw(&raw.files);
+    if let Some(files_specs) = &files_specs {
+        let has_zero_or_no_references = match get_prop_from_raw(&raw.references) {
+            PropOfRaw::NoProp => true,
+            PropOfRaw::Array(references) => references.is_empty(),
+            PropOfRaw::NotArray => false,
+        };
+        if files_specs.is_empty() && has_zero_or_no_references && !raw.has_extends {
+            let message = Diagnostics::The_files_list_in_config_file_0_is_empty;
+            let error = match source_file.get_prop_initializer("files") {
+                Some(node_value) => source_file.create_diagnostic_for_node(
+                    node_value.range,
+                    &message,
+                    &[config_file_name],
+                ),
+                None => create_compiler_diagnostic(&message, &[config_file_name]),
+            };
+            errors.push(error);
+        }
+    }
+
+    let mut include_specs = get_specs_from_raw(&raw.include);
+
+    let mut exclude_specs = get_specs_from_raw(&raw.exclude);
+    if matches!(get_prop_from_raw(&raw.exclude), PropOfRaw::NoProp) {
+        if let Some(JsonValue::Object(compiler_options)) = &raw.compiler_options {
+            let directories: Vec<String> = compiler_options
+                .iter()
+                .filter(|property| property.name == "outDir" || property.name == "declarationDir")
+                .filter_map(|property| match &property.initializer.value {
+                    JsonValue::String(directory) if !directory.is_empty() => {
+                        Some(directory.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+            if !directories.is_empty() {
+                exclude_specs = Some(directories);
+            }
+        }
+    }
+
+    if files_specs.is_none() && include_specs.is_none() {
+        include_specs = Some(vec!["**/*".to_string()]);
+    }
+
+    // The exclude spec list is converted into a regular expression, which allows us to quickly
+    // test whether a file or directory should be excluded before recursively traversing the
+    // file system.
+    let validated_include_specs = include_specs
+        .as_ref()
+        .map(|specs| validate_specs(specs, errors, true, source_file, "include"));
+    let validated_exclude_specs = exclude_specs
+        .as_ref()
+        .map(|specs| validate_specs(specs, errors, false, source_file, "exclude"));
+
+    ConfigFileSpecs {
+        files_specs,
+        include_specs,
+        exclude_specs,
+        validated_include_specs,
+        validated_exclude_specs,
+    }
+}
+
+fn get_project_references(raw: &RawTsconfig, base_path: &str) -> Option<Vec<ProjectReference>> {
+    let references = match get_prop_from_raw(&raw.references) {
+        PropOfRaw::Array(references) => references,
+        _ => return None,
+    };
+    let mut project_references: Option<Vec<ProjectReference>> = None;
+    for reference in references {
+        let properties = match &reference.value {
+            JsonValue::Object(properties) => properties,
+            _ => continue,
+        };
+        let get_property = |name: &str| {
+            properties
+                .iter()
+                .rev()
+                .find(|property| property.name == name)
+                .map(|property| &property.initializer.value)
+        };
+        let get_boolean_property = |name: &str| match get_property(name) {
+            Some(JsonValue::Boolean(value)) => Some(*value),
+            _ => None,
+        };
+        // References without a string `path` are reported by the conversion of the file.
+        if let Some(JsonValue::String(path)) = get_property("path") {
+            project_references
+                .get_or_insert_with(Vec::new)
+                .push(ProjectReference {
+                    path: get_normalized_absolute_path(path, base_path),
+                    original_path: path.clone(),
+                    prepend: get_boolean_property("prepend"),
+                    circular: get_boolean_property("circular"),
+                });
+        }
+    }
+    project_references
+}
+
+fn parse_json_source_file_config_file_content(
+    source_file: &TsConfigSourceFile,
+    host: &dyn ParseConfigHost,
+    base_path: &str,
+    config_file_name: &str,
+    extended_config_cache: &mut ExtendedConfigCache,
+) -> ParsedCommandLine {
+    let mut errors = Vec::new();
+    let parsed_config = parse_config(
+        source_file,
+        host,
+        base_path,
+        config_file_name,
+        &[],
+        &mut errors,
+        extended_config_cache,
+    );
+    let raw = &parsed_config.raw;
+    let mut options = parsed_config.options.clone().unwrap_or_default();
+    options.insert(
+        "configFilePath".to_string(),
+        Some(JsonValue::String(normalize_slashes(config_file_name))),
+    );
+    let config_file_specs = get_config_file_specs(raw, source_file, config_file_name, &mut errors);
+    let base_path_for_file_names =
+        normalize_path(&directory_of_combined_path(config_file_name, base_path));
+
+    // Report the syntax errors of the config itself first, like `getConfigFileParsingDiagnostics`.
+    let mut all_errors = source_file.parse_diagnostics.clone();
+    all_errors.extend(errors);
+    ParsedCommandLine {
+        options,
+        watch_options: parsed_config.watch_options.clone(),
+        project_references: get_project_references(raw, &base_path_for_file_names),
+        config_file_specs,
+        compile_on_save: raw.compile_on_save.as_ref().is_some_and(is_truthy),
+        extended_source_files: parsed_config.extended_source_files,
+        errors: all_errors,
+    }
+}
+
+/// Reads a config file and the configs it `extends`, like `getParsedCommandLineOfConfigFile`.
+/// Returns the error when the config file itself cannot be read. Relative paths are resolved
+/// against `current_directory`.
+///
+/// As in `tsc`, `compilerOptions`, `watchOptions`, `compileOnSave` and the `files`, `include`
+/// and `exclude` specs are inherited from the extended config while `references` are not.
+pub fn get_parsed_command_line_of_config_file(
+    config_file_name: &str,
+    host: &dyn ParseConfigHost,
+    current_directory: &str,
+    extended_config_cache: &mut ExtendedConfigCache,
+) -> Result<ParsedCommandLine, Diagnostic> {
+    let config_file_name = get_normalized_absolute_path(config_file_name, current_directory);
+    let config_file_text = host
+        .read_file(&config_file_name)
+        .map_err(|error| create_cannot_read_file_diagnostic(&config_file_name, &error))?;
+    let source_file = TsConfigSourceFile::new(&config_file_name, config_file_text);
+    Ok(parse_json_source_file_config_file_content(
+        &source_file,
+        host,
+        &get_directory_path(&config_file_name),
+        &config_file_name,
+        extended_config_cache,
+    ))
+}
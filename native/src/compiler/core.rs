@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+
+/// Given a name and a list of names that are *not* equal to the name, return a spelling
+/// suggestion if there is one that is close enough. Names less than length 3 only check for
+/// case-insensitive equality.
+///
+/// `get_spelling_suggestion` has four passes:
+/// 1. For each candidate, check that the length is within a third of the name's length.
+/// 2. Skip candidates that are exactly equal to the name.
+/// 3. Check candidates less than 3 characters for a case-insensitive match only.
+/// 4. Use the Levenshtein distance (with substitutions of case only costing 0.1) to pick the
+///    closest candidate.
+pub fn get_spelling_suggestion<'a, T>(
+    name: &str,
+    candidates: &'a [T],
+    get_name: impl Fn(&T) -> Option<&str>,
+) -> Option<&'a T> {
+    let name: Vec<u16> = name.encode_utf16().collect();
+    let maximum_length_difference = 2usize.min((name.len() as f64 * 0.34).floor() as usize);
+    // If the best result is worse than this, don't bother.
+    let mut best_distance = (name.len() as f64 * 0.4).floor() + 1.0;
+    let mut best_candidate = None;
+    for candidate in candidates {
+        let candidate_name: Vec<u16> = match get_name(candidate) {
+            Some(candidate_name) => candidate_name.encode_utf16().collect(),
+            None => continue,
+        };
+        let length_difference = if candidate_name.len() > name.len() {
+            candidate_name.len() - name.len()
+        } else {
+            name.len() - candidate_name.len()
+        };
+        if length_difference > maximum_length_difference || candidate_name == name {
+            continue;
+        }
+        // Only consider candidates less than 3 characters long when they differ by case.
+        // Otherwise, don't bother, since a user would usually notice differences of a
+        // 2-character name.
+        if candidate_name.len() < 3 && to_lower_case(&candidate_name) != to_lower_case(&name) {
+            continue;
+        }
+
+        if let Some(distance) = levenshtein_with_max(&name, &candidate_name, best_distance - 0.1) {
+            debug_assert!(distance < best_distance); // Else `levenshtein_with_max` should return `None`
+            best_distance = distance;
+            best_candidate = Some(candidate);
+        }
+    }
+    best_candidate
+}
+
+fn to_lower_case(text: &[u16]) -> Vec<u16> {
+    String::from_utf16_lossy(text)
+        .to_lowercase()
+        .encode_utf16()
+        .collect()
+}
+
+fn levenshtein_with_max(s1: &[u16], s2: &[u16], max: f64) -> Option<f64> {
+    let mut previous: Vec<f64> = (0..=s2.len()).map(|i| i as f64).collect();
+    let mut current = vec![0.0; s2.len() + 1];
+    // Represents any value > max. We don't care about the particular value.
+    let big = max + 0.01;
+
+    for i in 1..=s1.len() {
+        let c1 = s1[i - 1];
+        let min_j = if i as f64 > max {
+            (i as f64 - max).ceil() as usize
+        } else {
+            1
+        };
+        let max_j = if s2.len() as f64 > max + i as f64 {
+            (max + i as f64).floor() as usize
+        } else {
+            s2.len()
+        };
+        current[0] = i as f64;
+        // Smallest value of the matrix in the ith column.
+        let mut col_min = i as f64;
+        for value in current.iter_mut().take(min_j).skip(1) {
+            *value = big;
+        }
+        for j in min_j..=max_j {
+            // case difference should be significantly cheaper than other differences
+            let substitution_distance =
+                if to_lower_case(&s1[i - 1..i]) == to_lower_case(&s2[j - 1..j]) {
+                    previous[j - 1] + 0.1
+                } else {
+                    previous[j - 1] + 2.0
+                };
+            let dist = if c1 == s2[j - 1] {
+                previous[j - 1]
+            } else {
+                // delete, insert, substitute
+                (previous[j] + 1.0)
+                    .min(current[j - 1] + 1.0)
+                    .min(substitution_distance)
+            };
+            current[j] = dist;
+            col_min = col_min.min(dist);
+        }
+        for value in current.iter_mut().skip(max_j + 1) {
+            *value = big;
+        }
+        if col_min > max {
+            // Give up -- everything in this column is > max and it can't get better in future
+            // columns.
+            return None;
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let res = previous[s2.len()];
+    if res > max {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+// The printable ASCII characters in the order of `Intl.Collator`, with the letters that only
+// differ in case next to each other.
+const UI_COLLATION_ORDER: &str =
+    "\t\n\u{b}\u{c}\r _-,;:!?.'\"()[]{}@*/\\&#%`^+<=>|~$0123456789aAbBcCdDeEfFgGhHiIjJkKlLmMnNoOpPqQrRsStTuUvVwWxXyYzZ";
+
+// The weight of a character when comparing strings ignoring case, and whether it's an uppercase
+// letter, which sorts after the lowercase one when the strings are otherwise equal.
+fn ui_collation_key(c: char) -> (u32, bool) {
+    match UI_COLLATION_ORDER.find(c.to_ascii_lowercase()) {
+        Some(index) => (index as u32, c.is_ascii_uppercase()),
+        None => {
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            (UI_COLLATION_ORDER.len() as u32 + lower as u32, lower != c)
+        }
+    }
+}
+
+/// Compares strings like `compareStringsCaseSensitiveUI` does with the `Intl.Collator` of an
+/// English locale: case-insensitively first, then with lowercase before uppercase, and with a
+/// missing string before any other. The whitespace, punctuation, digits and letters of ASCII are
+/// ordered like the collator orders them; other characters come after them by their lowercase code point,
+/// which only approximates the collation of the Unicode tables.
+pub fn compare_strings_case_sensitive_ui(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => {
+            // The other control characters of ASCII are ignored.
+            let keys = |text: &str| {
+                text.chars()
+                    .filter(|&c| !c.is_ascii_control() || UI_COLLATION_ORDER.contains(c))
+                    .map(ui_collation_key)
+                    .collect::<Vec<_>>()
+            };
+            let (a, b) = (keys(a), keys(b));
+            let weights = |keys: &[(u32, bool)]| keys.iter().map(|key| key.0).collect::<Vec<_>>();
+            weights(&a).cmp(&weights(&b)).then_with(|| {
+                let cases = |keys: &[(u32, bool)]| keys.iter().map(|key| key.1).collect::<Vec<_>>();
+                cases(&a).cmp(&cases(&b))
+            })
+        }
+    }
+}
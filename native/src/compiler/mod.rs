@@ -1,2 +1,12 @@
+pub mod atom;
+pub mod command_line_parser;
+pub mod core;
+pub mod diagnostic_information_map;
+pub mod module_name_resolver;
+pub mod parser;
+pub mod path;
 pub mod scanner;
-pub mod types;
\ No newline at end of file
+pub mod sys;
+pub mod types;
+pub mod unicode;
+pub mod utilities;
@@ -0,0 +1,111 @@
+// The subset of node module resolution (`moduleNameResolver.ts`) that `extends` needs:
+// resolving a package name to a `tsconfig.json` (`Extensions.TSConfig`). `typesVersions`
+// redirects are not supported.
+
+use super::parser::parse_json_text;
+use super::path::{
+    combine_paths, file_extension_is, get_base_file_name, get_directory_path, normalize_path,
+    normalize_slashes,
+};
+use super::types::{JsonNode, JsonValue, ParseConfigHost};
+
+/// Resolves `module_name` like `nodeModuleNameResolver` does with `lookupConfig`, looking for the
+/// package in every `node_modules` directory from `containing_directory` up to the root.
+pub fn resolve_tsconfig_module_name(
+    module_name: &str,
+    containing_directory: &str,
+    host: &dyn ParseConfigHost,
+) -> Option<String> {
+    let mut directory = normalize_slashes(containing_directory);
+    loop {
+        if get_base_file_name(&directory) != "node_modules" {
+            if let Some(resolved) =
+                load_module_from_immediate_node_modules_directory(module_name, &directory, host)
+            {
+                // Packages are usually symlinked in monorepos, resolve to the real file like
+                // `realPath` in the JS compiler.
+                return Some(normalize_path(&host.realpath(&resolved)));
+            }
+        }
+        let parent_path = get_directory_path(&directory);
+        if parent_path == directory {
+            return None;
+        }
+        directory = parent_path;
+    }
+}
+
+fn load_module_from_immediate_node_modules_directory(
+    module_name: &str,
+    directory: &str,
+    host: &dyn ParseConfigHost,
+) -> Option<String> {
+    let node_modules_folder = combine_paths(directory, &["node_modules"]);
+    if !host.directory_exists(&node_modules_folder) {
+        return None;
+    }
+    let candidate = normalize_path(&combine_paths(&node_modules_folder, &[module_name]));
+    let tsconfig_field = read_package_json_tsconfig_field(&candidate, host);
+    load_module_from_file(&candidate, host)
+        .or_else(|| load_node_module_from_directory(&candidate, tsconfig_field, host))
+}
+
+// Tries `candidate.json`, or `candidate` itself when it already ends in `.json`.
+fn load_module_from_file(candidate: &str, host: &dyn ParseConfigHost) -> Option<String> {
+    let extension_less = candidate.strip_suffix(".json").unwrap_or(candidate);
+    try_file(&format!("{}.json", extension_less), host)
+}
+
+fn try_file(file_name: &str, host: &dyn ParseConfigHost) -> Option<String> {
+    if host.file_exists(file_name) {
+        Some(file_name.to_string())
+    } else {
+        None
+    }
+}
+
+fn load_node_module_from_directory(
+    candidate: &str,
+    package_file: Option<String>,
+    host: &dyn ParseConfigHost,
+) -> Option<String> {
+    if let Some(package_file) = package_file {
+        if let Some(from_file) = try_file(&package_file, host) {
+            if file_extension_is(&from_file, ".json") {
+                return Some(from_file);
+            }
+        }
+        // Don't do package.json lookup recursively, because Node.js' package lookup doesn't.
+        let resolved = load_module_from_file(&package_file, host)
+            .or_else(|| load_node_module_from_directory(&package_file, None, host));
+        if resolved.is_some() {
+            return resolved;
+        }
+    }
+    load_module_from_file(&combine_paths(candidate, &["tsconfig"]), host)
+}
+
+// Returns the path in the `tsconfig` field of `directory/package.json`, if there is one.
+fn read_package_json_tsconfig_field(directory: &str, host: &dyn ParseConfigHost) -> Option<String> {
+    let package_json_path = combine_paths(directory, &["package.json"]);
+    if !host.file_exists(&package_json_path) {
+        return None;
+    }
+    let text = host.read_file(&package_json_path).ok()?;
+    let package_json = parse_json_text(&package_json_path, text);
+    let properties = match package_json.value {
+        Some(JsonNode {
+            value: JsonValue::Object(properties),
+            ..
+        }) => properties,
+        _ => return None,
+    };
+    properties
+        .into_iter()
+        .find_map(|property| match property.initializer.value {
+            JsonValue::String(file_name) if property.name == "tsconfig" => {
+                Some(normalize_path(&combine_paths(directory, &[&file_name])))
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,13653 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use super::atom::{Atom, AtomTable};
+use super::diagnostic_information_map::Diagnostics;
+use super::path::normalize_path;
+use super::scanner::{
+    create_scanner, get_leading_comment_ranges, is_reserved_word, skip_trivia,
+    token_is_identifier_or_keyword, token_is_identifier_or_keyword_or_greater_than,
+    token_to_string, Scanner, ScannerStateSnapshot, TextScanner,
+};
+use super::sys::LocalFileSystem;
+use super::types::{
+    AmdDependency, CancellationToken, CharacterCodes, CheckJsDirective, CommentDirective,
+    CommentRange, Diagnostic, DiagnosticWithLocation, FileReference, IDiagnosticMessage,
+    JSDocCommentText, JSDocTag, JsonNode, JsonProperty, JsonSourceFile, JsonValue,
+    JsxTokenSyntaxKind, KeywordSyntaxKind, LanguageVariant, LiteralSyntaxKind, Node, NodeArray,
+    NodeData, NodeFlags, NodeId, OperationCanceled, ParseConfigHost, Pragma, PragmaArgument,
+    PunctuationSyntaxKind, ScriptKind, ScriptTarget, SignatureDeclaration, SourceFile, SyntaxKind,
+    TextChangeRange, TextRange, TokenFlags, TokenSyntaxKind, TriviaSyntaxKind, TypeNodeSyntaxKind,
+};
+use super::utilities::{
+    create_cannot_read_file_diagnostic, create_file_diagnostic, ensure_script_kind,
+    get_binary_operator_precedence, get_js_doc_comment_ranges, get_language_variant,
+    is_assignment_operator, is_class_member_modifier, is_declaration_file_name,
+    is_js_doc_like_text, is_keyword, is_left_hand_side_expression_kind, is_literal_kind,
+    is_modifier_kind, is_string_or_numeric_literal_like, is_template_literal_kind, node_is_missing,
+    OperatorPrecedence,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParsingContext {
+    SourceElements,           // Elements in source file
+    BlockStatements,          // Statements in block
+    SwitchClauses,            // Clauses in switch statement
+    SwitchClauseStatements,   // Statements in switch clause
+    TypeMembers,              // Members in interface or type literal
+    ClassMembers,             // Members in class declaration
+    EnumMembers,              // Members in enum declaration
+    HeritageClauseElement,    // Elements in a heritage clause
+    VariableDeclarations,     // Variable declarations in variable statement
+    ObjectBindingElements,    // Binding elements in object binding list
+    ArrayBindingElements,     // Binding elements in array binding list
+    ArgumentExpressions,      // Expressions in argument list
+    ObjectLiteralMembers,     // Members in object literal
+    JsxAttributes,            // Attributes in jsx element
+    JsxChildren,              // Things between opening and closing JSX tags
+    ArrayLiteralMembers,      // Members in array literal
+    Parameters,               // Parameters in parameter list
+    JSDocParameters,          // JSDoc parameters in parameter list of JSDoc function type
+    RestProperties,           // Property names in a rest type list
+    TypeParameters,           // Type parameters in type parameter list
+    TypeArguments,            // Type arguments in type argument list
+    TupleElementTypes,        // Element types in tuple element type list
+    HeritageClauses,          // Heritage clauses for a class or interface declaration.
+    ImportOrExportSpecifiers, // Named import clause's import specifier list
+}
+
+impl ParsingContext {
+    const ALL: [ParsingContext; 24] = [
+        ParsingContext::SourceElements,
+        ParsingContext::BlockStatements,
+        ParsingContext::SwitchClauses,
+        ParsingContext::SwitchClauseStatements,
+        ParsingContext::TypeMembers,
+        ParsingContext::ClassMembers,
+        ParsingContext::EnumMembers,
+        ParsingContext::HeritageClauseElement,
+        ParsingContext::VariableDeclarations,
+        ParsingContext::ObjectBindingElements,
+        ParsingContext::ArrayBindingElements,
+        ParsingContext::ArgumentExpressions,
+        ParsingContext::ObjectLiteralMembers,
+        ParsingContext::JsxAttributes,
+        ParsingContext::JsxChildren,
+        ParsingContext::ArrayLiteralMembers,
+        ParsingContext::Parameters,
+        ParsingContext::JSDocParameters,
+        ParsingContext::RestProperties,
+        ParsingContext::TypeParameters,
+        ParsingContext::TypeArguments,
+        ParsingContext::TupleElementTypes,
+        ParsingContext::HeritageClauses,
+        ParsingContext::ImportOrExportSpecifiers,
+    ];
+
+    // The bit of the context in the set of contexts the parser is in.
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Parses a JSON or JSONC document (comments and trailing commas are allowed) with the error
+/// recovery of `parseJsonText`. Values that parse but are not JSON are left out of the tree and
+/// reported in `json_diagnostics`, like `convertToObject` does.
+pub fn parse_json_text(file_name: &str, source_text: String) -> JsonSourceFile {
+    let mut parser = JsonParser::new(file_name, source_text);
+    parser.parse_source_file()
+}
+
+struct JsonParser {
+    file_name: String,
+    scanner: TextScanner,
+    current_token: SyntaxKind,
+    parsing_context: u32,
+    parse_diagnostics: Rc<RefCell<Vec<DiagnosticWithLocation>>>,
+    json_diagnostics: Vec<DiagnosticWithLocation>,
+}
+
+// Don't report another error if it would just be at the same position as the last error.
+fn push_parse_error(
+    parse_diagnostics: &RefCell<Vec<DiagnosticWithLocation>>,
+    diagnostic: DiagnosticWithLocation,
+) {
+    let mut parse_diagnostics = parse_diagnostics.borrow_mut();
+    let is_new_position = parse_diagnostics
+        .last()
+        .is_none_or(|last_error| last_error.start != diagnostic.start);
+    if is_new_position {
+        parse_diagnostics.push(diagnostic);
+    }
+}
+
+impl JsonParser {
+    fn new(file_name: &str, source_text: String) -> JsonParser {
+        let parse_diagnostics: Rc<RefCell<Vec<DiagnosticWithLocation>>> = Rc::default();
+        let scan_error = {
+            let file_name = file_name.to_string();
+            let parse_diagnostics = parse_diagnostics.clone();
+            move |message: &IDiagnosticMessage<'static>, pos: usize, length: usize| {
+                push_parse_error(
+                    &parse_diagnostics,
+                    create_file_diagnostic(&file_name, pos, length, message, &[]),
+                );
+            }
+        };
+        let scanner = create_scanner(
+            ScriptTarget::JSON,
+            true,
+            None,
+            Some(source_text),
+            Some(Box::new(scan_error)),
+            None,
+            None,
+        );
+        JsonParser {
+            file_name: file_name.to_string(),
+            scanner,
+            current_token: SyntaxKind::Tokens(TokenSyntaxKind::Unknown),
+            parsing_context: 0,
+            parse_diagnostics,
+            json_diagnostics: Vec::new(),
+        }
+    }
+
+    fn parse_source_file(&mut self) -> JsonSourceFile {
+        // Prime the scanner.
+        self.next_token();
+
+        let mut expressions: Vec<JsonNode> = Vec::new();
+        while self.token() != SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken) {
+            let start_pos = self.scanner.get_start_pos();
+            let expression = match self.token() {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBracketToken) => {
+                    Some(self.parse_array_literal())
+                }
+                SyntaxKind::Keywords(KeywordSyntaxKind::TrueKeyword)
+                | SyntaxKind::Keywords(KeywordSyntaxKind::FalseKeyword)
+                | SyntaxKind::Keywords(KeywordSyntaxKind::NullKeyword) => self.parse_value(),
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusToken)
+                    if self.look_ahead_is_numeric_literal_not_followed_by_colon() =>
+                {
+                    self.parse_value()
+                }
+                SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral)
+                | SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral)
+                    if !self.look_ahead_is_colon() =>
+                {
+                    self.parse_value()
+                }
+                _ => Some(self.parse_object_literal()),
+            };
+
+            // Ensure we always make progress even if no token could be consumed.
+            if self.scanner.get_start_pos() == start_pos {
+                self.next_token();
+            }
+
+            // Error recovery: collect multiple top-level expressions
+            if let Some(expression) = expression {
+                expressions.push(expression);
+            }
+            if expressions.len() == 1
+                && self.token() != SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken)
+            {
+                self.parse_error_at_current_token(&Diagnostics::Unexpected_token, &[]);
+            }
+        }
+
+        let value = if expressions.len() > 1 {
+            // Synthesize an array literal so that all input text is consumed.
+            let range = TextRange {
+                pos: expressions[0].range.pos,
+                end: expressions[expressions.len() - 1].range.end,
+            };
+            Some(JsonNode {
+                range,
+                value: JsonValue::Array(expressions),
+            })
+        } else {
+            expressions.pop()
+        };
+
+        let end_of_file_token = TextRange {
+            pos: self.scanner.get_start_pos(),
+            end: self.scanner.get_text_pos(),
+        };
+
+        JsonSourceFile {
+            file_name: self.file_name.clone(),
+            value,
+            end_of_file_token,
+            parse_diagnostics: self.parse_diagnostics.replace(Vec::new()),
+            json_diagnostics: std::mem::take(&mut self.json_diagnostics),
+        }
+    }
+
+    fn token(&self) -> SyntaxKind {
+        self.current_token
+    }
+
+    fn next_token(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.scan();
+        self.current_token
+    }
+
+    fn node_pos(&self) -> usize {
+        self.scanner.get_start_pos()
+    }
+
+    fn finish_node(&self, pos: usize) -> TextRange {
+        TextRange {
+            pos,
+            end: self.scanner.get_start_pos(),
+        }
+    }
+
+    // Scans ahead without moving the parser; errors reported while looking ahead are dropped.
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut TextScanner) -> T) -> T {
+        let save_parse_diagnostics_length = self.parse_diagnostics.borrow().len();
+        let result = self.scanner.look_ahead(callback);
+        self.parse_diagnostics
+            .borrow_mut()
+            .truncate(save_parse_diagnostics_length);
+        result
+    }
+
+    fn look_ahead_is_colon(&mut self) -> bool {
+        self.look_ahead(|scanner| {
+            scanner.scan() == SyntaxKind::Punctuations(PunctuationSyntaxKind::ColonToken)
+        })
+    }
+
+    fn look_ahead_is_numeric_literal_not_followed_by_colon(&mut self) -> bool {
+        self.look_ahead(|scanner| {
+            scanner.scan() == SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral)
+                && scanner.scan() != SyntaxKind::Punctuations(PunctuationSyntaxKind::ColonToken)
+        })
+    }
+
+    fn parse_error_at_position(
+        &mut self,
+        start: usize,
+        length: usize,
+        message: &IDiagnosticMessage,
+        args: &[&str],
+    ) {
+        push_parse_error(
+            &self.parse_diagnostics,
+            create_file_diagnostic(&self.file_name, start, length, message, args),
+        );
+    }
+
+    fn parse_error_at_current_token(&mut self, message: &IDiagnosticMessage, args: &[&str]) {
+        let start = self.scanner.get_token_pos();
+        let length = self.scanner.get_text_pos() - start;
+        self.parse_error_at_position(start, length, message, args);
+    }
+
+    // Reports an error for JSON that parses but cannot be converted to a value, spanning the
+    // given node without its leading trivia.
+    fn json_error_at_node(
+        &mut self,
+        range: TextRange,
+        message: &IDiagnosticMessage,
+        args: &[&str],
+    ) {
+        let start = skip_trivia(
+            self.scanner.get_text_utf16(),
+            range.pos,
+            false,
+            false,
+            false,
+        )
+        .min(range.end);
+        self.json_diagnostics.push(create_file_diagnostic(
+            &self.file_name,
+            start,
+            range.end - start,
+            message,
+            args,
+        ));
+    }
+
+    fn parse_expected(&mut self, kind: PunctuationSyntaxKind, text: &str) -> bool {
+        if self.token() == SyntaxKind::Punctuations(kind) {
+            self.next_token();
+            return true;
+        }
+
+        self.parse_error_at_current_token(&Diagnostics::_0_expected, &[text]);
+        false
+    }
+
+    fn parse_optional(&mut self, kind: PunctuationSyntaxKind) -> bool {
+        if self.token() == SyntaxKind::Punctuations(kind) {
+            self.next_token();
+            return true;
+        }
+        false
+    }
+
+    fn is_literal_property_name(&self) -> bool {
+        matches!(
+            self.token(),
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+                | SyntaxKind::Keywords(_)
+                | SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral)
+                | SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral)
+        )
+    }
+
+    fn is_start_of_value(&self) -> bool {
+        matches!(
+            self.token(),
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken)
+                | SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBracketToken)
+                | SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusToken)
+                | SyntaxKind::Punctuations(PunctuationSyntaxKind::PlusToken)
+                | SyntaxKind::Literals(_)
+                | SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+                | SyntaxKind::Keywords(_)
+        )
+    }
+
+    fn is_list_element(&self, parsing_context: ParsingContext) -> bool {
+        match parsing_context {
+            ParsingContext::ObjectLiteralMembers => self.is_literal_property_name(),
+            ParsingContext::ArrayLiteralMembers => {
+                self.token() == SyntaxKind::Punctuations(PunctuationSyntaxKind::CommaToken)
+                    || self.token()
+                        == SyntaxKind::Punctuations(PunctuationSyntaxKind::DotDotDotToken)
+                    || self.is_start_of_value()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_list_terminator(&self, parsing_context: ParsingContext) -> bool {
+        if self.token() == SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken) {
+            // Being at the end of the file ends all lists.
+            return true;
+        }
+
+        match parsing_context {
+            ParsingContext::ObjectLiteralMembers => {
+                self.token() == SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken)
+            }
+            ParsingContext::ArrayLiteralMembers => {
+                self.token() == SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBracketToken)
+            }
+            _ => false,
+        }
+    }
+
+    // True if positioned at element or terminator of the current list or any enclosing list
+    fn is_in_some_parsing_context(&self) -> bool {
+        for parsing_context in &[
+            ParsingContext::ObjectLiteralMembers,
+            ParsingContext::ArrayLiteralMembers,
+        ] {
+            if self.parsing_context & parsing_context.bit() != 0
+                && (self.is_list_element(*parsing_context)
+                    || self.is_list_terminator(*parsing_context))
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Returns true if we should abort parsing.
+    fn abort_parsing_list_or_move_to_next_token(
+        &mut self,
+        parsing_context: ParsingContext,
+    ) -> bool {
+        if parsing_context == ParsingContext::ObjectLiteralMembers {
+            self.parse_error_at_current_token(&Diagnostics::Property_assignment_expected, &[]);
+        } else {
+            self.parse_error_at_current_token(&Diagnostics::Expression_or_comma_expected, &[]);
+        }
+        if self.is_in_some_parsing_context() {
+            return true;
+        }
+
+        self.next_token();
+        false
+    }
+
+    // Parses a comma-delimited list of elements; trailing commas are allowed.
+    fn parse_delimited_list<T>(
+        &mut self,
+        parsing_context: ParsingContext,
+        parse_element: impl Fn(&mut Self) -> Option<T>,
+    ) -> Vec<T> {
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= parsing_context.bit();
+        let mut list = Vec::new();
+        loop {
+            if self.is_list_element(parsing_context) {
+                let start_pos = self.scanner.get_start_pos();
+                if let Some(element) = parse_element(self) {
+                    list.push(element);
+                }
+
+                if self.parse_optional(PunctuationSyntaxKind::CommaToken) {
+                    // No need to check for a zero length node since we know we parsed a comma
+                    continue;
+                }
+
+                if self.is_list_terminator(parsing_context) {
+                    break;
+                }
+
+                // We didn't get a comma, and the list wasn't terminated, explicitly parse
+                // out a comma so we give a good error message.
+                self.parse_expected(PunctuationSyntaxKind::CommaToken, ",");
+
+                // Ensure we always make progress even if no token could be consumed.
+                if start_pos == self.scanner.get_start_pos() {
+                    self.next_token();
+                }
+                continue;
+            }
+
+            if self.is_list_terminator(parsing_context) {
+                break;
+            }
+
+            if self.abort_parsing_list_or_move_to_next_token(parsing_context) {
+                break;
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        list
+    }
+
+    fn parse_object_literal(&mut self) -> JsonNode {
+        let pos = self.node_pos();
+        self.parse_expected(PunctuationSyntaxKind::OpenBraceToken, "{");
+        let properties = self.parse_delimited_list(
+            ParsingContext::ObjectLiteralMembers,
+            Self::parse_object_literal_element,
+        );
+        self.parse_expected(PunctuationSyntaxKind::CloseBraceToken, "}");
+        JsonNode {
+            range: self.finish_node(pos),
+            value: JsonValue::Object(properties),
+        }
+    }
+
+    fn parse_object_literal_element(&mut self) -> Option<JsonProperty> {
+        let pos = self.node_pos();
+        let name_token = self.token();
+        let name = self.scanner.get_token_value();
+        let is_double_quoted_name = name_token
+            == SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral)
+            && self.scanner.get_text_utf16()[self.scanner.get_token_pos()] as u32
+                == CharacterCodes::DOUBLE_QUOTE;
+        self.next_token();
+        let name_range = self.finish_node(pos);
+
+        let mut question_range = None;
+        if self.token() == SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionToken) {
+            let question_pos = self.node_pos();
+            self.next_token();
+            question_range = Some(self.finish_node(question_pos));
+        }
+
+        let is_shorthand_property_assignment = match name_token {
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier) => true,
+            SyntaxKind::Keywords(keyword) => !is_reserved_word(keyword),
+            _ => false,
+        } && self.token()
+            != SyntaxKind::Punctuations(PunctuationSyntaxKind::ColonToken);
+        if is_shorthand_property_assignment {
+            let range = self.finish_node(pos);
+            self.json_error_at_node(range, &Diagnostics::Property_assignment_expected, &[]);
+            return None;
+        }
+
+        // Like `convertToObject`, the modifier and the name are checked before the value is
+        // converted, and only for property assignments.
+        if let Some(question_range) = question_range {
+            self.json_error_at_node(
+                question_range,
+                &Diagnostics::The_0_modifier_can_only_be_used_in_TypeScript_files,
+                &["?"],
+            );
+        }
+        if !is_double_quoted_name {
+            self.json_error_at_node(
+                name_range,
+                &Diagnostics::String_literal_with_double_quotes_expected,
+                &[],
+            );
+        }
+
+        self.parse_expected(PunctuationSyntaxKind::ColonToken, ":");
+        let initializer = self.parse_value();
+
+        initializer.map(|initializer| JsonProperty {
+            range: self.finish_node(pos),
+            name,
+            name_range,
+            initializer,
+        })
+    }
+
+    fn parse_array_literal(&mut self) -> JsonNode {
+        let pos = self.node_pos();
+        self.parse_expected(PunctuationSyntaxKind::OpenBracketToken, "[");
+        let elements = self.parse_delimited_list(
+            ParsingContext::ArrayLiteralMembers,
+            Self::parse_array_literal_element,
+        );
+        self.parse_expected(PunctuationSyntaxKind::CloseBracketToken, "]");
+        JsonNode {
+            range: self.finish_node(pos),
+            value: JsonValue::Array(elements),
+        }
+    }
+
+    fn parse_array_literal_element(&mut self) -> Option<JsonNode> {
+        match self.token() {
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::CommaToken) => {
+                // An omitted expression
+                let pos = self.node_pos();
+                let range = self.finish_node(pos);
+                self.report_invalid_value(range);
+                None
+            }
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::DotDotDotToken) => {
+                let pos = self.node_pos();
+                self.next_token();
+                self.parse_value();
+                let range = self.finish_node(pos);
+                self.report_invalid_value(range);
+                None
+            }
+            _ => self.parse_value(),
+        }
+    }
+
+    fn report_invalid_value(&mut self, range: TextRange) {
+        self.json_error_at_node(
+            range,
+            &Diagnostics::Property_value_can_only_be_string_literal_numeric_literal_true_false_null_object_literal_or_array_literal,
+            &[],
+        );
+    }
+
+    // Parses the value of a property, an array element or the whole document. Returns `None`
+    // for anything that is not a JSON value.
+    fn parse_value(&mut self) -> Option<JsonNode> {
+        let pos = self.node_pos();
+        let value = match self.token() {
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken) => {
+                return Some(self.parse_object_literal());
+            }
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBracketToken) => {
+                return Some(self.parse_array_literal());
+            }
+            SyntaxKind::Keywords(KeywordSyntaxKind::TrueKeyword) => Some(JsonValue::Boolean(true)),
+            SyntaxKind::Keywords(KeywordSyntaxKind::FalseKeyword) => {
+                Some(JsonValue::Boolean(false))
+            }
+            SyntaxKind::Keywords(KeywordSyntaxKind::NullKeyword) => Some(JsonValue::Null),
+            SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral) => {
+                let is_double_quoted = self.scanner.get_text_utf16()[self.scanner.get_token_pos()]
+                    as u32
+                    == CharacterCodes::DOUBLE_QUOTE;
+                let value = self.scanner.get_token_value();
+                if !is_double_quoted {
+                    self.next_token();
+                    let range = self.finish_node(pos);
+                    self.json_error_at_node(
+                        range,
+                        &Diagnostics::String_literal_with_double_quotes_expected,
+                        &[],
+                    );
+                    return Some(JsonNode {
+                        range,
+                        value: JsonValue::String(value),
+                    });
+                }
+                Some(JsonValue::String(value))
+            }
+            SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral) => Some(JsonValue::Number(
+                self.scanner.get_token_value().parse().unwrap_or(f64::NAN),
+            )),
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusToken)
+            | SyntaxKind::Punctuations(PunctuationSyntaxKind::PlusToken) => {
+                let is_minus =
+                    self.token() == SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusToken);
+                self.next_token();
+                if is_minus
+                    && self.token() == SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral)
+                {
+                    let value: f64 = self.scanner.get_token_value().parse().unwrap_or(f64::NAN);
+                    self.next_token();
+                    return Some(JsonNode {
+                        range: self.finish_node(pos),
+                        value: JsonValue::Number(-value),
+                    });
+                }
+                self.parse_value();
+                let range = self.finish_node(pos);
+                self.report_invalid_value(range);
+                return None;
+            }
+            _ if self.is_start_of_value() => None,
+            _ => {
+                self.parse_error_at_current_token(&Diagnostics::Expression_expected, &[]);
+                return None;
+            }
+        };
+
+        self.next_token();
+        let range = self.finish_node(pos);
+        match value {
+            Some(value) => Some(JsonNode { range, value }),
+            None => {
+                self.report_invalid_value(range);
+                None
+            }
+        }
+    }
+}
+
+/// Parses a TypeScript or JavaScript file (or, with `ScriptKind::JSON`, a JSON document) into a
+/// `SourceFile`, reporting the same syntactic diagnostics as `createSourceFile` of the JS
+/// compiler. The script kind defaults to the one of the file extension.
+pub fn create_source_file(
+    file_name: &str,
+    source_text: String,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+) -> SourceFile {
+    create_source_file_worker(
+        file_name,
+        source_text,
+        language_version,
+        script_kind,
+        None,
+        Arc::default(),
+    )
+}
+
+/// Like `create_source_file`, but stops parsing soon after the cancellation token is canceled.
+pub fn create_source_file_with_cancellation(
+    file_name: &str,
+    source_text: String,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+    cancellation_token: &CancellationToken,
+) -> Result<SourceFile, OperationCanceled> {
+    cancellation_token.throw_if_cancellation_requested()?;
+    let source_file = create_source_file_worker(
+        file_name,
+        source_text,
+        language_version,
+        script_kind,
+        Some(cancellation_token.clone()),
+        Arc::default(),
+    );
+    // A canceled parse skips the rest of the text, so its tree is not worth returning.
+    cancellation_token.throw_if_cancellation_requested()?;
+    Ok(source_file)
+}
+
+/// Parses the JSDoc comment at `start` of the text on its own, like `parseIsolatedJSDocComment`
+/// of the JS compiler, for the comments the tree doesn't attach to a node. The nodes of the
+/// comment live in the returned file, whose statements are empty, along with the `JSDocComment`
+/// node. `None` if the text at `start` isn't a JSDoc comment.
+pub fn parse_isolated_js_doc_comment(
+    content: String,
+    start: usize,
+    length: usize,
+) -> Option<(SourceFile, NodeId)> {
+    let mut parser = Parser::new(
+        "",
+        content,
+        ScriptTarget::ESNext,
+        ScriptKind::JS,
+        Arc::default(),
+    );
+    let js_doc = parser.do_inside_of_context(NodeFlags::JSDOC, |parser| {
+        parser.parse_js_doc_comment_worker(start, length)
+    })?;
+    parser.fixup_parent_references(js_doc);
+    let end = parser.scanner.get_text_utf16().len();
+    let end_of_file_token = parser.finish_node_at(
+        Node::new(SyntaxKind::EndOfFileToken, NodeData::Token),
+        end,
+        end,
+    );
+    let source_file = parser.create_source_file(
+        ScriptTarget::ESNext,
+        ScriptKind::JS,
+        false,
+        NodeArray::default(),
+        end_of_file_token,
+        NodeFlags::NONE,
+    );
+    Some((source_file, js_doc))
+}
+
+fn create_source_file_worker(
+    file_name: &str,
+    source_text: String,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+    cancellation_token: Option<CancellationToken>,
+    atoms: Arc<AtomTable>,
+) -> SourceFile {
+    let script_kind = ensure_script_kind(file_name, script_kind);
+    let mut parser = Parser::new(file_name, source_text, language_version, script_kind, atoms);
+    parser.cancellation_token = cancellation_token;
+    if script_kind == ScriptKind::JSON {
+        let json_diagnostics =
+            parse_json_text(file_name, parser.source_text.clone()).json_diagnostics;
+        let mut result = parser.parse_json_text();
+        result.parse_diagnostics.extend(json_diagnostics);
+        return result;
+    }
+
+    parser.parse_source_file_worker()
+}
+
+// A file of a `parse_files` batch: a path to read, or a text that is already in memory.
+pub enum ParseFileInput {
+    Path(String),
+    Text { file_name: String, text: String },
+}
+
+/// Parses a batch of files on the rayon thread pool, reading the ones given by path there too.
+/// The results are in the order of the inputs; a file that cannot be read gets the diagnostic
+/// the JS compiler reports for it instead of a tree. The identifiers of all the files are
+/// interned in `atoms`. With a cancellation token, the whole batch stops soon after the token
+/// is canceled.
+pub fn parse_files(
+    inputs: Vec<ParseFileInput>,
+    language_version: ScriptTarget,
+    script_kind: Option<ScriptKind>,
+    atoms: &Arc<AtomTable>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Vec<Result<SourceFile, Diagnostic>>, OperationCanceled> {
+    let parse = |file_name: &str, text: String| {
+        let source_file = create_source_file_worker(
+            file_name,
+            text,
+            language_version,
+            script_kind,
+            cancellation_token.cloned(),
+            atoms.clone(),
+        );
+        if let Some(cancellation_token) = cancellation_token {
+            cancellation_token.throw_if_cancellation_requested()?;
+        }
+        Ok(source_file)
+    };
+    inputs
+        .into_par_iter()
+        .map(|input| match input {
+            ParseFileInput::Path(file_name) => {
+                if let Some(cancellation_token) = cancellation_token {
+                    cancellation_token.throw_if_cancellation_requested()?;
+                }
+                match LocalFileSystem.read_file(&file_name) {
+                    Ok(text) => parse(&file_name, text).map(Ok),
+                    Err(error) => Ok(Err(create_cannot_read_file_diagnostic(&file_name, &error))),
+                }
+            }
+            ParseFileInput::Text { file_name, text } => parse(&file_name, text).map(Ok),
+        })
+        .collect()
+}
+
+/// Parses the new text of an edited file, reusing the nodes of the old tree that the edit
+/// didn't touch, like `updateSourceFile` of the JS compiler. The result is the same tree (and
+/// reports the same diagnostics) as parsing the new text from scratch, except for the JSDoc
+/// diagnostics of reused nodes, which aren't reported again, and the flags of `import()` and
+/// `import.meta` of the file, which stay set once the old tree had them.
+///
+/// The old source file is consumed: its nodes are moved to their positions in the new text and
+/// become part of the new tree.
+pub fn update_source_file(
+    source_file: SourceFile,
+    new_text: String,
+    text_change_range: TextChangeRange,
+) -> SourceFile {
+    check_change_range(&source_file.text, &new_text, text_change_range);
+    if text_change_range.is_unchanged() {
+        // if the text didn't change, then we can just return our current source file as-is.
+        return source_file;
+    }
+
+    // If we don't have any statements in the current source file, then there's no real way to
+    // incrementally parse.  So just do a full parse instead. The same goes for JSON documents,
+    // and once earlier updates have doubled the nodes since the last full parse: a full parse
+    // leaves behind the ones they didn't reuse.
+    if source_file.statements().is_empty()
+        || source_file.script_kind == ScriptKind::JSON
+        || source_file.nodes.len() > 2 * source_file.full_parse_nodes_length
+    {
+        return create_source_file_worker(
+            &source_file.file_name,
+            new_text,
+            source_file.language_version,
+            Some(source_file.script_kind),
+            None,
+            source_file.atoms,
+        );
+    }
+
+    let SourceFile {
+        file_name,
+        text: old_text,
+        mut nodes,
+        root,
+        full_parse_nodes_length,
+        language_version,
+        script_kind,
+        comment_directives: old_comment_directives,
+        atoms,
+        ..
+    } = source_file;
+
+    // Make the actual change larger so that we know to reparse anything whose lookahead
+    // might have intersected the change.
+    let change_range = extend_to_affected_range(&nodes, root, text_change_range);
+    check_change_range(&old_text, &new_text, change_range);
+
+    // Ensure that extending the affected range only moved the start of the change range
+    // earlier in the file.
+    debug_assert!(change_range.span.pos <= text_change_range.span.pos);
+    debug_assert_eq!(change_range.span.end, text_change_range.span.end);
+    debug_assert_eq!(
+        change_range.new_span().end,
+        text_change_range.new_span().end
+    );
+
+    // If we added or removed characters during the edit, then we need to go and adjust all
+    // the nodes after the edit.  Those nodes may move forward (if we inserted chars) or they
+    // may move backward (if we deleted chars).
+    //
+    // Doing this helps us out in two ways.  First, it means that any nodes/tokens we want
+    // to reuse are already at the appropriate position in the new text.  That way when we
+    // reuse them, we don't have to figure out if they need to be adjusted.  Second, it makes
+    // it very easy to determine if we can reuse a node.  If the node's position is at where
+    // we are in the text, then we can reuse it.  Otherwise we can't.  If the node's position
+    // is ahead of us, then we'll need to rescan tokens.  If the node's position is behind
+    // us, then we'll need to skip it or crumble it as appropriate
+    //
+    // We will also adjust the positions of nodes that intersect the change range as well.
+    // By doing this, we ensure that all the positions in the old tree are consistent, not
+    // just the positions of nodes entirely before/after the change range.  By being
+    // consistent, we can then easily map from positions to nodes in the old tree easily.
+    //
+    // Also, mark any syntax elements that intersect the changed span.  We know, up front,
+    // that we cannot reuse these elements.
+    let adjustment = ChangeAdjustment {
+        change_start: change_range.span.pos,
+        change_range_old_end: change_range.span.end,
+        change_range_new_end: change_range.new_span().end,
+        delta: change_range.new_length as isize
+            - (change_range.span.end - change_range.span.pos) as isize,
+    };
+    let intersects_change = adjustment.update_token_positions_and_mark_elements(&mut nodes);
+
+    // Now that we've set up our internal incremental state just proceed and parse the source
+    // file in the normal fashion.  When possible the parser will retrieve and reuse nodes from
+    // the old tree, which stay where they are in the arena.
+    let mut parser = Parser::new(&file_name, new_text, language_version, script_kind, atoms);
+    parser.syntax_cursor = Some(SyntaxCursor::new(&nodes, root, intersects_change));
+    // Because new source file node is created, it may not have the flag PossiblyContainDynamicImport. This is the case if there is no new edit to add dynamic import.
+    // We will manually port the flag to the new source file.
+    parser.permanent_flags =
+        nodes[root.index()].flags & NodeFlags::PERMANENTLY_SET_INCREMENTAL_FLAGS;
+    let old_nodes_length = nodes.len();
+    parser.nodes = nodes;
+    let mut result = parser.parse_source_file_worker();
+    result.full_parse_nodes_length = full_parse_nodes_length;
+    let reused_node_ranges = get_reused_node_ranges(&result, old_nodes_length);
+    result.comment_directives = get_new_comment_directives(
+        old_comment_directives,
+        result.comment_directives.take(),
+        change_range.span.pos,
+        change_range.span.end,
+        adjustment.delta,
+        &reused_node_ranges,
+    );
+    result
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpeculationKind {
+    TryParse,
+    Lookahead,
+    Reparse,
+}
+
+// The state to roll back to when a speculative parse fails (or when only looking ahead).
+struct SpeculationState {
+    scanner: ScannerStateSnapshot,
+    current_token: SyntaxKind,
+    parse_diagnostics_length: usize,
+    parse_error_before_next_finished_node: bool,
+    context_flags: u32,
+    nodes_length: usize,
+}
+
+struct Parser {
+    file_name: String,
+    source_text: String,
+    language_version: ScriptTarget,
+    script_kind: ScriptKind,
+    language_variant: LanguageVariant,
+    scanner: TextScanner,
+    // Every node created so far, children before their parents. Nodes created by a failed
+    // speculative parse are dropped again.
+    nodes: Vec<Node>,
+    parse_diagnostics: Rc<RefCell<Vec<DiagnosticWithLocation>>>,
+    js_doc_diagnostics: Option<Vec<DiagnosticWithLocation>>,
+
+    current_token: SyntaxKind,
+    node_count: usize,
+    identifier_count: usize,
+    // The texts `internIdentifier` of the JS parser is given. Like there, the ones of a failed
+    // speculative parse are kept.
+    identifiers: BTreeSet<String>,
+
+    parsing_context: u32,
+
+    // The positions of parenthesized expressions known not to start arrow functions.
+    not_parenthesized_arrow: HashSet<usize>,
+
+    // Flags that dictate what parsing context we're in.  For example:
+    // Whether or not we are in strict parsing mode.  All that changes in strict parsing mode is
+    // that some tokens that would be considered identifiers may be considered keywords.
+    //
+    // When adding more parser context flags, consider which is the more common case that the
+    // flag will be in.  This should be the 'false' state for that flag.  The reason for this is
+    // that we don't store data in our nodes unless the value is in the *non-default* state.
+    //
+    // Note: 'allowIn' and 'allowYield' track 1:1 with the [in] and [yield] concepts in the ES6
+    // grammar specification.
+    //
+    // Note: it should not be necessary to save/restore these flags during speculative/lookahead
+    // parsing.  These context flags are naturally stored and restored through normal recursive
+    // descent parsing and unwinding.
+    context_flags: u32,
+
+    // Indicates whether we are currently parsing top-level statements.
+    top_level: bool,
+
+    // Whether or not we've had a parse error since creating the last AST node.  If we have
+    // encountered an error, it will be stored on the next AST node we create.  Parse errors
+    // can be broken down into three categories:
+    //
+    // 1) An error that occurred during scanning.  For example, an unterminated literal, or a
+    //    character that was completely not understood.
+    //
+    // 2) A token was expected, but was not present.  This type of error is commonly produced
+    //    by the 'parseExpected' function.
+    //
+    // 3) A token was present that no parsing function was able to consume.  This type of error
+    //    only occurs in the 'abortParsingListOrMoveToNextToken' function when the parser
+    //    decides to skip the token.
+    //
+    // In all of these cases, we want to mark the next node as having had an error before it.
+    // With this mark, we can know in incremental settings if this node can be reused, or if
+    // we have to reparse it.
+    //
+    // Note: it is necessary that this value be saved/restored during speculative/lookahead
+    // parsing.  During lookahead parsing, we will often create a node.  That node will have
+    // this value attached, and then this value will be set back to 'false'.  If we decide to
+    // rewind, we must get back to the same value we had prior to the lookahead.
+    //
+    // Note: any errors at the end of the file that do not precede a regular node, should get
+    // attached to the EOF token.
+    //
+    // Shared with the error callback of the scanner, like `parse_diagnostics`.
+    parse_error_before_next_finished_node: Rc<Cell<bool>>,
+
+    has_deprecated_tag: bool,
+    // The tags parsed so far from the JSDoc comment being parsed, since `@returns` and `@type`
+    // report duplicates.
+    js_doc_tags: Vec<NodeId>,
+    source_flags: u32,
+    // The old tree of an incremental parse, whose nodes start out in `nodes`.
+    syntax_cursor: Option<SyntaxCursor>,
+    // The flags of `import()` and `import.meta` of the old tree of an incremental parse, which
+    // the new tree keeps even where it reuses the nodes that set them.
+    permanent_flags: u32,
+    atoms: Arc<AtomTable>,
+    // Polled as tokens are scanned; once it is canceled, the parser skips to the end of the file.
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl Parser {
+    fn new(
+        file_name: &str,
+        source_text: String,
+        language_version: ScriptTarget,
+        script_kind: ScriptKind,
+        atoms: Arc<AtomTable>,
+    ) -> Parser {
+        let file_name = normalize_path(file_name);
+        let language_variant = get_language_variant(script_kind);
+        let parse_diagnostics: Rc<RefCell<Vec<DiagnosticWithLocation>>> = Rc::default();
+        let parse_error_before_next_finished_node: Rc<Cell<bool>> = Rc::default();
+        let scan_error = {
+            let file_name = file_name.clone();
+            let parse_diagnostics = parse_diagnostics.clone();
+            let parse_error_before_next_finished_node =
+                parse_error_before_next_finished_node.clone();
+            move |message: &IDiagnosticMessage<'static>, pos: usize, length: usize| {
+                push_parse_error(
+                    &parse_diagnostics,
+                    create_file_diagnostic(&file_name, pos, length, message, &[]),
+                );
+                parse_error_before_next_finished_node.set(true);
+            }
+        };
+
+        // Initialize and prime the scanner before parsing the source elements.
+        let scanner = create_scanner(
+            language_version,
+            true,
+            Some(language_variant),
+            Some(source_text.clone()),
+            Some(Box::new(scan_error)),
+            None,
+            None,
+        );
+
+        let context_flags = match script_kind {
+            ScriptKind::JS | ScriptKind::JSX => NodeFlags::JAVA_SCRIPT_FILE,
+            ScriptKind::JSON => NodeFlags::JAVA_SCRIPT_FILE | NodeFlags::JSON_FILE,
+            _ => NodeFlags::NONE,
+        };
+
+        Parser {
+            file_name,
+            source_text,
+            language_version,
+            script_kind,
+            language_variant,
+            scanner,
+            nodes: Vec::new(),
+            parse_diagnostics,
+            js_doc_diagnostics: None,
+            current_token: SyntaxKind::Unknown,
+            node_count: 0,
+            identifier_count: 0,
+            identifiers: BTreeSet::new(),
+            parsing_context: 0,
+            not_parenthesized_arrow: HashSet::new(),
+            context_flags,
+            top_level: true,
+            parse_error_before_next_finished_node,
+            has_deprecated_tag: false,
+            js_doc_tags: Vec::new(),
+            source_flags: 0,
+            syntax_cursor: None,
+            permanent_flags: NodeFlags::NONE,
+            atoms,
+            cancellation_token: None,
+        }
+    }
+
+    fn parse_json_text(mut self) -> SourceFile {
+        self.source_flags = self.context_flags;
+
+        // Prime the scanner.
+        self.next_token();
+        let pos = self.get_node_pos();
+        let statements;
+        let end_of_file_token;
+        if self.token() == SyntaxKind::EndOfFileToken {
+            statements = self.create_node_array(Vec::new(), pos, Some(pos), false);
+            end_of_file_token = self.parse_token_node();
+        } else {
+            // Loop and synthesize an ArrayLiteralExpression if there are more than
+            // one top-level expressions to ensure all input text is consumed.
+            let mut expressions: Vec<NodeId> = Vec::new();
+            while self.token() != SyntaxKind::EndOfFileToken {
+                let expression = match self.token() {
+                    SyntaxKind::OpenBracketToken => self.parse_array_literal_expression(),
+                    SyntaxKind::TrueKeyword
+                    | SyntaxKind::FalseKeyword
+                    | SyntaxKind::NullKeyword => self.parse_token_node(),
+                    SyntaxKind::MinusToken => {
+                        if self.look_ahead(|p| {
+                            p.next_token() == SyntaxKind::NumericLiteral
+                                && p.next_token() != SyntaxKind::ColonToken
+                        }) {
+                            self.parse_prefix_unary_expression()
+                        } else {
+                            self.parse_object_literal_expression()
+                        }
+                    }
+                    SyntaxKind::NumericLiteral | SyntaxKind::StringLiteral
+                        if self.look_ahead(|p| p.next_token() != SyntaxKind::ColonToken) =>
+                    {
+                        self.parse_literal_node()
+                    }
+                    _ => self.parse_object_literal_expression(),
+                };
+
+                // Error recovery: collect multiple top-level expressions
+                expressions.push(expression);
+                if expressions.len() == 1 && self.token() != SyntaxKind::EndOfFileToken {
+                    self.parse_error_at_current_token(&Diagnostics::Unexpected_token, &[]);
+                }
+            }
+
+            let expression = if expressions.len() > 1 {
+                let elements = self.create_node_array(expressions, pos, None, false);
+                self.finish_node(
+                    Node::new(
+                        SyntaxKind::ArrayLiteralExpression,
+                        NodeData::ArrayLiteralExpression {
+                            elements,
+                            multi_line: false,
+                        },
+                    ),
+                    pos,
+                )
+            } else {
+                expressions[0]
+            };
+            let statement = self.finish_node(
+                Node::new(
+                    SyntaxKind::ExpressionStatement,
+                    NodeData::Expression { expression },
+                ),
+                pos,
+            );
+            statements = self.create_node_array(vec![statement], pos, None, false);
+            end_of_file_token = self.parse_expected_token(
+                SyntaxKind::EndOfFileToken,
+                Some(&Diagnostics::Unexpected_token),
+                None,
+            );
+        }
+
+        // Set source file so that errors will be reported with this file name
+        let source_flags = self.source_flags;
+        self.create_source_file(
+            ScriptTarget::ES2015,
+            ScriptKind::JSON,
+            false,
+            statements,
+            end_of_file_token,
+            source_flags,
+        )
+    }
+
+    fn parse_source_file_worker(mut self) -> SourceFile {
+        let is_declaration_file = is_declaration_file_name(&self.file_name);
+        if is_declaration_file {
+            self.context_flags |= NodeFlags::AMBIENT;
+        }
+
+        self.source_flags = self.context_flags | self.permanent_flags;
+
+        // Prime the scanner.
+        self.next_token();
+
+        let statements = self.parse_list(ParsingContext::SourceElements, Self::parse_statement);
+        debug_assert_eq!(self.token(), SyntaxKind::EndOfFileToken);
+        let end_of_file_token = self.parse_token_node();
+        self.add_js_doc_comment(end_of_file_token);
+
+        let source_flags = self.source_flags;
+        let language_version = self.language_version;
+        let script_kind = self.script_kind;
+        let mut source_file = self.create_source_file(
+            language_version,
+            script_kind,
+            is_declaration_file,
+            statements,
+            end_of_file_token,
+            source_flags,
+        );
+
+        process_comment_pragmas(&mut source_file);
+        source_file
+    }
+
+    fn add_js_doc_comment(&mut self, node: NodeId) -> NodeId {
+        debug_assert!(self.node(node).js_doc.is_none()); // Should only be called once per node
+        let js_doc: Vec<NodeId> =
+            get_js_doc_comment_ranges(self.node(node), self.scanner.get_text_utf16())
+                .into_iter()
+                .filter_map(|comment| {
+                    self.parse_js_doc_comment(
+                        comment.range.pos,
+                        comment.range.end - comment.range.pos,
+                    )
+                })
+                .collect();
+        if !js_doc.is_empty() {
+            self.node_mut(node).js_doc = Some(js_doc);
+        }
+        if self.has_deprecated_tag {
+            self.has_deprecated_tag = false;
+            self.node_mut(node).flags |= NodeFlags::DEPRECATED;
+        }
+        node
+    }
+
+    fn with_js_doc(&mut self, node: NodeId, has_js_doc: bool) -> NodeId {
+        if has_js_doc {
+            self.add_js_doc_comment(node)
+        } else {
+            node
+        }
+    }
+
+    fn create_source_file(
+        mut self,
+        language_version: ScriptTarget,
+        script_kind: ScriptKind,
+        is_declaration_file: bool,
+        statements: NodeArray,
+        end_of_file_token: NodeId,
+        flags: u32,
+    ) -> SourceFile {
+        // code from createNode is inlined here so createNode won't have to deal with special case of creating source files
+        // this is quite rare comparing to other nodes and createNode should be as fast as possible
+        let mut root = Node::new(
+            SyntaxKind::SourceFile,
+            NodeData::SourceFile {
+                statements,
+                end_of_file_token,
+            },
+        );
+        root.flags = flags;
+        root.range = TextRange {
+            pos: 0,
+            end: self.scanner.get_text_utf16().len(),
+        };
+        self.node_count += 1;
+        let root = self.push_node(root);
+
+        let mut external_module_indicator = self.get_external_module_indicator(root);
+
+        // If we parsed this as an external module, it may contain top-level await
+        if !is_declaration_file
+            && external_module_indicator.is_some()
+            && self.contains_possible_top_level_await(root)
+        {
+            self.reparse_top_level_await(root);
+            external_module_indicator = self.get_external_module_indicator(root);
+        }
+        self.fixup_parent_references(root);
+
+        let comment_directives = self
+            .scanner
+            .get_comment_directives()
+            .map(|directives| directives.to_vec());
+        let parse_diagnostics = std::mem::take(&mut *self.parse_diagnostics.borrow_mut());
+        SourceFile {
+            file_name: self.file_name,
+            text: self.source_text,
+            full_parse_nodes_length: self.nodes.len(),
+            atoms: self.atoms,
+            nodes: self.nodes,
+            root,
+            language_version,
+            language_variant: get_language_variant(script_kind),
+            script_kind,
+            is_declaration_file,
+            has_no_default_lib: false,
+            external_module_indicator,
+            node_count: self.node_count,
+            identifier_count: self.identifier_count,
+            identifiers: self.identifiers,
+            parse_diagnostics,
+            js_doc_diagnostics: self.js_doc_diagnostics.unwrap_or_default(),
+            comment_directives,
+            referenced_files: Vec::new(),
+            type_reference_directives: Vec::new(),
+            lib_reference_directives: Vec::new(),
+            amd_dependencies: Vec::new(),
+            module_name: None,
+            check_js_directive: None,
+            pragmas: Vec::new(),
+        }
+    }
+
+    // Points every node of the tree at its parent, walking down from the root with a stack
+    // rather than recursively, since deeply nested expressions could overflow the call stack.
+    // Nodes left out of the tree, by reparsing or by an incremental parse that didn't reuse
+    // them, are skipped: they may still list nodes of the tree as their children.
+    fn fixup_parent_references(&mut self, root: NodeId) {
+        let mut stack = vec![root];
+        while let Some(parent) = stack.pop() {
+            let start = stack.len();
+            let node = &self.nodes[parent.index()];
+            for_each_child(
+                node,
+                &mut |child| {
+                    stack.push(child);
+                    None::<()>
+                },
+                None,
+            );
+            stack.extend(node.js_doc.iter().flatten());
+            let mut index = start;
+            while index < stack.len() {
+                let child = &mut self.nodes[stack[index].index()];
+                if child.parent == Some(parent) {
+                    // A subtree an incremental parse reused already has its parent pointers set.
+                    stack.swap_remove(index);
+                } else {
+                    child.parent = Some(parent);
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    fn set_context_flag(&mut self, val: bool, flag: u32) {
+        if val {
+            self.context_flags |= flag;
+        } else {
+            self.context_flags &= !flag;
+        }
+    }
+
+    fn set_disallow_in_context(&mut self, val: bool) {
+        self.set_context_flag(val, NodeFlags::DISALLOW_IN_CONTEXT);
+    }
+
+    fn set_yield_context(&mut self, val: bool) {
+        self.set_context_flag(val, NodeFlags::YIELD_CONTEXT);
+    }
+
+    fn set_decorator_context(&mut self, val: bool) {
+        self.set_context_flag(val, NodeFlags::DECORATOR_CONTEXT);
+    }
+
+    fn set_await_context(&mut self, val: bool) {
+        self.set_context_flag(val, NodeFlags::AWAIT_CONTEXT);
+    }
+
+    fn do_outside_of_context<T>(&mut self, context: u32, func: impl FnOnce(&mut Self) -> T) -> T {
+        // contextFlagsToClear will contain only the context flags that are
+        // currently set that we need to temporarily clear
+        // We don't just blindly reset to the previous flags to ensure
+        // that we do not mutate cached flags for the incremental
+        // parser (ThisNodeHasError, ThisNodeOrAnySubNodesHasError, and
+        // HasAggregatedChildData).
+        let context_flags_to_clear = context & self.context_flags;
+        if context_flags_to_clear != 0 {
+            // clear the requested context flags
+            self.set_context_flag(false, context_flags_to_clear);
+            let result = func(self);
+            // restore the context flags we just cleared
+            self.set_context_flag(true, context_flags_to_clear);
+            return result;
+        }
+
+        // no need to do anything special as we are not in any of the requested contexts
+        func(self)
+    }
+
+    fn do_inside_of_context<T>(&mut self, context: u32, func: impl FnOnce(&mut Self) -> T) -> T {
+        // contextFlagsToSet will contain only the context flags that
+        // are not currently set that we need to temporarily enable.
+        // We don't just blindly reset to the previous flags to ensure
+        // that we do not mutate cached flags for the incremental
+        // parser (ThisNodeHasError, ThisNodeOrAnySubNodesHasError, and
+        // HasAggregatedChildData).
+        let context_flags_to_set = context & !self.context_flags;
+        if context_flags_to_set != 0 {
+            // set the requested context flags
+            self.set_context_flag(true, context_flags_to_set);
+            let result = func(self);
+            // reset the context flags we just set
+            self.set_context_flag(false, context_flags_to_set);
+            return result;
+        }
+
+        // no need to do anything special as we are already in all of the requested contexts
+        func(self)
+    }
+
+    fn allow_in_and<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_outside_of_context(NodeFlags::DISALLOW_IN_CONTEXT, func)
+    }
+
+    fn disallow_in_and<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::DISALLOW_IN_CONTEXT, func)
+    }
+
+    fn do_in_yield_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::YIELD_CONTEXT, func)
+    }
+
+    fn do_in_decorator_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::DECORATOR_CONTEXT, func)
+    }
+
+    fn do_in_await_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn do_outside_of_await_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_outside_of_context(NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn do_in_yield_and_await_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_inside_of_context(NodeFlags::YIELD_CONTEXT | NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn do_outside_of_yield_and_await_context<T>(&mut self, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.do_outside_of_context(NodeFlags::YIELD_CONTEXT | NodeFlags::AWAIT_CONTEXT, func)
+    }
+
+    fn in_context(&self, flags: u32) -> bool {
+        self.context_flags & flags != 0
+    }
+
+    fn in_yield_context(&self) -> bool {
+        self.in_context(NodeFlags::YIELD_CONTEXT)
+    }
+
+    fn in_disallow_in_context(&self) -> bool {
+        self.in_context(NodeFlags::DISALLOW_IN_CONTEXT)
+    }
+
+    fn in_decorator_context(&self) -> bool {
+        self.in_context(NodeFlags::DECORATOR_CONTEXT)
+    }
+
+    fn in_await_context(&self) -> bool {
+        self.in_context(NodeFlags::AWAIT_CONTEXT)
+    }
+
+    fn parse_error_at_current_token(
+        &mut self,
+        message: &IDiagnosticMessage<'static>,
+        args: &[&str],
+    ) {
+        let start = self.scanner.get_token_pos();
+        let end = self.scanner.get_text_pos();
+        self.parse_error_at(start, end, message, args);
+    }
+
+    fn parse_error_at_position(
+        &mut self,
+        start: usize,
+        length: usize,
+        message: &IDiagnosticMessage<'static>,
+        args: &[&str],
+    ) {
+        // Don't report another error if it would just be at the same position as the last error.
+        push_parse_error(
+            &self.parse_diagnostics,
+            create_file_diagnostic(&self.file_name, start, length, message, args),
+        );
+
+        // Mark that we've encountered an error.  We'll set an appropriate bit on the next
+        // node we finish so that it can't be reused incrementally.
+        self.parse_error_before_next_finished_node.set(true);
+    }
+
+    fn parse_error_at(
+        &mut self,
+        start: usize,
+        end: usize,
+        message: &IDiagnosticMessage<'static>,
+        args: &[&str],
+    ) {
+        self.parse_error_at_position(start, end.saturating_sub(start), message, args);
+    }
+
+    fn parse_error_at_range(
+        &mut self,
+        range: TextRange,
+        message: &IDiagnosticMessage<'static>,
+        args: &[&str],
+    ) {
+        self.parse_error_at(range.pos, range.end, message, args);
+    }
+
+    fn get_node_pos(&self) -> usize {
+        self.scanner.get_start_pos()
+    }
+
+    fn has_preceding_js_doc_comment(&self) -> bool {
+        self.scanner.has_preceding_jsdoc_comment()
+    }
+
+    fn token(&self) -> SyntaxKind {
+        self.current_token
+    }
+
+    fn next_token_without_check(&mut self) -> SyntaxKind {
+        if let Some(cancellation_token) = &self.cancellation_token {
+            if cancellation_token.is_cancellation_requested() {
+                // Skip to the end of the text: every list and construct stops at the end of the
+                // file, so the parse winds down right away.
+                let end = self.scanner.get_text_utf16().len();
+                self.scanner.set_text_pos(Some(end));
+            }
+        }
+        self.current_token = self.scanner.scan();
+        self.current_token
+    }
+
+    fn next_token(&mut self) -> SyntaxKind {
+        // if the keyword had an escape
+        if is_keyword(self.current_token)
+            && (self.scanner.has_unicode_escape() || self.scanner.has_extended_unicode_escape())
+        {
+            // issue a parse error for the escape
+            let start = self.scanner.get_token_pos();
+            let end = self.scanner.get_text_pos();
+            self.parse_error_at(
+                start,
+                end,
+                &Diagnostics::Keywords_cannot_contain_escape_characters,
+                &[],
+            );
+        }
+        self.next_token_without_check()
+    }
+
+    fn next_token_js_doc(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.scan_js_doc_token().into();
+        self.current_token
+    }
+
+    fn re_scan_greater_token(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.re_scan_greater_token();
+        self.current_token
+    }
+
+    fn re_scan_slash_token(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.re_scan_slash_token();
+        self.current_token
+    }
+
+    fn re_scan_template_token(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        self.current_token = self.scanner.re_scan_template_token(is_tagged_template);
+        self.current_token
+    }
+
+    fn re_scan_template_head_or_no_substitution_template(&mut self) -> SyntaxKind {
+        self.current_token = self
+            .scanner
+            .re_scan_template_head_or_no_substitution_template();
+        self.current_token
+    }
+
+    fn re_scan_less_than_token(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.re_scan_less_than_token();
+        self.current_token
+    }
+
+    fn re_scan_hash_token(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.re_scan_hash_token();
+        self.current_token
+    }
+
+    fn scan_jsx_identifier(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.scan_jsx_identifier();
+        self.current_token
+    }
+
+    fn scan_jsx_text(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.scan_jsx_token().into();
+        self.current_token
+    }
+
+    fn scan_jsx_attribute_value(&mut self) -> SyntaxKind {
+        self.current_token = self.scanner.scan_jsx_attribute_value();
+        self.current_token
+    }
+
+    fn save_speculation_state(&self) -> SpeculationState {
+        SpeculationState {
+            scanner: self.scanner.snapshot(),
+            current_token: self.current_token,
+            parse_diagnostics_length: self.parse_diagnostics.borrow().len(),
+            parse_error_before_next_finished_node: self.parse_error_before_next_finished_node.get(),
+            context_flags: self.context_flags,
+            nodes_length: self.nodes.len(),
+        }
+    }
+
+    fn speculation_helper<T>(
+        &mut self,
+        callback: impl FnOnce(&mut Self) -> T,
+        is_success: impl FnOnce(&T) -> bool,
+        speculation_kind: SpeculationKind,
+    ) -> T {
+        // Keep track of the state we'll need to rollback to if lookahead fails (or if the
+        // caller asked us to always reset our state).
+        //
+        // Note: it is not actually necessary to save/restore the context flags here.  That's
+        // because the saving/restoring of these flags happens naturally through the recursive
+        // descent nature of our parser.  However, we still store this here just so we can
+        // assert that invariant holds.
+        let saved = self.save_speculation_state();
+
+        let result = callback(self);
+
+        debug_assert_eq!(saved.context_flags, self.context_flags);
+
+        // If our callback returned nothing or we're just looking ahead, then unconditionally
+        // restore us to where we were. The scanner is always restored unless we're speculatively
+        // parsing, like `scanner.lookAhead` does.
+        if speculation_kind != SpeculationKind::TryParse || !is_success(&result) {
+            self.scanner.restore(saved.scanner);
+            self.current_token = saved.current_token;
+            if speculation_kind != SpeculationKind::Reparse {
+                self.parse_diagnostics
+                    .borrow_mut()
+                    .truncate(saved.parse_diagnostics_length);
+                // The nodes of the discarded parse are unreachable.
+                self.nodes.truncate(saved.nodes_length);
+            }
+            self.parse_error_before_next_finished_node
+                .set(saved.parse_error_before_next_finished_node);
+        }
+
+        result
+    }
+
+    // Invokes the provided callback then unconditionally restores the parser to the state it
+    // was in immediately prior to invoking the callback.  The result of invoking the callback
+    // is returned from this function. The callback must not return the nodes it creates, which
+    // are discarded.
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Self) -> T) -> T {
+        self.speculation_helper(callback, |_| true, SpeculationKind::Lookahead)
+    }
+
+    // Invokes the provided callback.  If the callback returns `None`, then it restores
+    // the parser to the state it was in immediately prior to invoking the callback.  If the
+    // callback returns something, then the parser state is not rolled back.  The result
+    // of invoking the callback is returned from this function.
+    fn try_parse<T>(&mut self, callback: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        self.speculation_helper(callback, Option::is_some, SpeculationKind::TryParse)
+    }
+
+    // `try_parse` for callbacks that only report whether they succeeded.
+    fn try_parse_bool(&mut self, callback: impl FnOnce(&mut Self) -> bool) -> bool {
+        self.speculation_helper(callback, |result| *result, SpeculationKind::TryParse)
+    }
+
+    // `token() > SyntaxKind.LastReservedWord` of the JS compiler: an identifier or a keyword
+    // that is not a reserved word.
+    fn is_token_after_last_reserved_word(&self) -> bool {
+        is_non_reserved_word_token(self.token())
+    }
+
+    fn is_binding_identifier(&self) -> bool {
+        if self.token() == SyntaxKind::Identifier {
+            return true;
+        }
+        self.is_token_after_last_reserved_word()
+    }
+
+    // Ignore strict mode flag because we will report an error in type checker instead.
+    fn is_identifier(&self) -> bool {
+        if self.token() == SyntaxKind::Identifier {
+            return true;
+        }
+
+        // If we have a 'yield' keyword, and we're in the [yield] context, then 'yield' is
+        // considered a keyword and is not an identifier.
+        if self.token() == SyntaxKind::YieldKeyword && self.in_yield_context() {
+            return false;
+        }
+
+        // If we have a 'await' keyword, and we're in the [Await] context, then 'await' is
+        // considered a keyword and is not an identifier.
+        if self.token() == SyntaxKind::AwaitKeyword && self.in_await_context() {
+            return false;
+        }
+
+        self.is_token_after_last_reserved_word()
+    }
+
+    fn parse_expected(&mut self, kind: SyntaxKind) -> bool {
+        self.parse_expected_with_message(kind, None, true)
+    }
+
+    fn parse_expected_with_message(
+        &mut self,
+        kind: SyntaxKind,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+        should_advance: bool,
+    ) -> bool {
+        if self.token() == kind {
+            if should_advance {
+                self.next_token();
+            }
+            return true;
+        }
+
+        // Report specific message if provided with one.  Otherwise, report generic fallback message.
+        match diagnostic_message {
+            Some(diagnostic_message) => self.parse_error_at_current_token(diagnostic_message, &[]),
+            None => self.parse_error_at_current_token(
+                &Diagnostics::_0_expected,
+                &[token_to_string(kind).unwrap_or_default()],
+            ),
+        }
+        false
+    }
+
+    fn parse_expected_js_doc(&mut self, kind: SyntaxKind) -> bool {
+        if self.token() == kind {
+            self.next_token_js_doc();
+            return true;
+        }
+        self.parse_error_at_current_token(
+            &Diagnostics::_0_expected,
+            &[token_to_string(kind).unwrap_or_default()],
+        );
+        false
+    }
+
+    fn parse_optional(&mut self, t: SyntaxKind) -> bool {
+        if self.token() == t {
+            self.next_token();
+            return true;
+        }
+        false
+    }
+
+    fn parse_optional_token(&mut self, t: SyntaxKind) -> Option<NodeId> {
+        if self.token() == t {
+            return Some(self.parse_token_node());
+        }
+        None
+    }
+
+    fn parse_optional_token_js_doc(&mut self, t: SyntaxKind) -> Option<NodeId> {
+        if self.token() == t {
+            return Some(self.parse_token_node_js_doc());
+        }
+        None
+    }
+
+    fn parse_expected_token(
+        &mut self,
+        t: SyntaxKind,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+        arg0: Option<&str>,
+    ) -> NodeId {
+        match self.parse_optional_token(t) {
+            Some(token) => token,
+            None => self.create_missing_node(
+                t,
+                false,
+                Some(diagnostic_message.unwrap_or(&Diagnostics::_0_expected)),
+                &[arg0.unwrap_or_else(|| token_to_string(t).unwrap_or_default())],
+            ),
+        }
+    }
+
+    fn parse_expected_token_js_doc(&mut self, t: SyntaxKind) -> NodeId {
+        match self.parse_optional_token_js_doc(t) {
+            Some(token) => token,
+            None => self.create_missing_node(
+                t,
+                false,
+                Some(&Diagnostics::_0_expected),
+                &[token_to_string(t).unwrap_or_default()],
+            ),
+        }
+    }
+
+    fn parse_token_node(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let kind = self.token();
+        self.next_token();
+        self.finish_node(Node::new(kind, NodeData::Token), pos)
+    }
+
+    fn parse_token_node_js_doc(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let kind = self.token();
+        self.next_token_js_doc();
+        self.finish_node(Node::new(kind, NodeData::Token), pos)
+    }
+
+    fn can_parse_semicolon(&self) -> bool {
+        // If there's a real semicolon, then we can always parse it out.
+        if self.token() == SyntaxKind::SemicolonToken {
+            return true;
+        }
+
+        // We can parse out an optional semicolon in ASI cases in the following cases.
+        self.token() == SyntaxKind::CloseBraceToken
+            || self.token() == SyntaxKind::EndOfFileToken
+            || self.scanner.has_preceding_line_break()
+    }
+
+    fn parse_semicolon(&mut self) -> bool {
+        if self.can_parse_semicolon() {
+            if self.token() == SyntaxKind::SemicolonToken {
+                // consume the semicolon if it was explicitly provided.
+                self.next_token();
+            }
+
+            true
+        } else {
+            self.parse_expected(SyntaxKind::SemicolonToken)
+        }
+    }
+
+    fn create_node_array(
+        &self,
+        elements: Vec<NodeId>,
+        pos: usize,
+        end: Option<usize>,
+        has_trailing_comma: bool,
+    ) -> NodeArray {
+        NodeArray {
+            range: TextRange {
+                pos,
+                end: end.unwrap_or_else(|| self.scanner.get_start_pos()),
+            },
+            has_trailing_comma,
+            is_missing_list: false,
+            nodes: elements,
+        }
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.index()]
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.index()]
+    }
+
+    fn kind(&self, id: NodeId) -> SyntaxKind {
+        self.node(id).kind
+    }
+
+    fn push_node(&mut self, node: Node) -> NodeId {
+        let id = NodeId(self.nodes.len() as u32);
+        self.nodes.push(node);
+        id
+    }
+
+    fn finish_node(&mut self, node: Node, pos: usize) -> NodeId {
+        let end = self.scanner.get_start_pos();
+        self.finish_node_at(node, pos, end)
+    }
+
+    fn finish_node_at(&mut self, mut node: Node, pos: usize, end: usize) -> NodeId {
+        node.range = TextRange { pos, end };
+        node.flags |= self.context_flags;
+
+        // Keep track on the node if we encountered an error while parsing it.  If we did, then
+        // we cannot reuse the node incrementally.  Once we've marked this node, clear out the
+        // flag so that we don't mark any subsequent nodes.
+        if self.parse_error_before_next_finished_node.get() {
+            self.parse_error_before_next_finished_node.set(false);
+            node.flags |= NodeFlags::THIS_NODE_HAS_ERROR;
+        }
+
+        self.node_count += 1;
+        self.push_node(node)
+    }
+
+    fn create_missing_node(
+        &mut self,
+        kind: SyntaxKind,
+        report_at_current_position: bool,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+        args: &[&str],
+    ) -> NodeId {
+        if let Some(diagnostic_message) = diagnostic_message {
+            if report_at_current_position {
+                let start = self.scanner.get_start_pos();
+                self.parse_error_at_position(start, 0, diagnostic_message, args);
+            } else {
+                self.parse_error_at_current_token(diagnostic_message, args);
+            }
+        }
+
+        let pos = self.get_node_pos();
+        let data = if kind == SyntaxKind::Identifier {
+            NodeData::Identifier {
+                text: self.atoms.intern(""),
+                original_keyword_kind: None,
+                is_in_jsdoc_namespace: false,
+                jsdoc_dot_pos: None,
+            }
+        } else if is_template_literal_kind(kind)
+            || kind == SyntaxKind::NumericLiteral
+            || kind == SyntaxKind::StringLiteral
+        {
+            NodeData::Literal {
+                text: String::new(),
+                raw_text: if is_template_literal_kind(kind) {
+                    Some(String::new())
+                } else {
+                    None
+                },
+                token_flags: 0,
+                is_unterminated: false,
+                has_extended_unicode_escape: false,
+            }
+        } else {
+            NodeData::Token
+        };
+        self.finish_node(Node::new(kind, data), pos)
+    }
+
+    // Interns the text of an identifier, and records it in the `identifiers` of the file.
+    fn intern_identifier(&mut self, text: &str) -> Atom {
+        if !self.identifiers.contains(text) {
+            self.identifiers.insert(text.to_owned());
+        }
+        self.atoms.intern(text)
+    }
+
+    // Records the text of a string or numeric literal in the `identifiers` of the file, for the
+    // literals `internIdentifier` is given: the ones naming properties and modules.
+    fn intern_literal_text(&mut self, node: NodeId) -> NodeId {
+        if let NodeData::Literal { text, .. } = &self.nodes[node.index()].data {
+            if !self.identifiers.contains(text) {
+                let text = text.clone();
+                self.identifiers.insert(text);
+            }
+        }
+        node
+    }
+
+    fn create_identifier(
+        &mut self,
+        is_identifier: bool,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+        private_identifier_diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        if is_identifier {
+            self.identifier_count += 1;
+            let pos = self.get_node_pos();
+            // Store original token kind if it is not just an Identifier so we can report appropriate error later in type checker
+            let original_keyword_kind = match self.token() {
+                SyntaxKind::Keywords(keyword) => Some(keyword),
+                _ => None,
+            };
+            let text = self.scanner.token_value().to_owned();
+            let text = self.intern_identifier(&text);
+            self.next_token_without_check();
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::Identifier,
+                    NodeData::Identifier {
+                        text,
+                        original_keyword_kind,
+                        is_in_jsdoc_namespace: false,
+                        jsdoc_dot_pos: None,
+                    },
+                ),
+                pos,
+            );
+        }
+
+        if self.token() == SyntaxKind::PrivateIdentifier {
+            self.parse_error_at_current_token(
+                private_identifier_diagnostic_message.unwrap_or(
+                    &Diagnostics::Private_identifiers_are_not_allowed_outside_class_bodies,
+                ),
+                &[],
+            );
+            return self.create_identifier(true, None, None);
+        }
+
+        if self.token() == SyntaxKind::Unknown
+            && self
+                .scanner
+                .try_scan(|scanner| {
+                    (scanner.re_scan_invalid_identifier() == SyntaxKind::Identifier).then_some(())
+                })
+                .is_some()
+        {
+            // Scanner has already recorded an 'Invalid character' error, so no need to add another from the parser.
+            return self.create_identifier(true, None, None);
+        }
+
+        self.identifier_count += 1;
+        // Only for end of file because the error gets reported incorrectly on embedded script tags.
+        let report_at_current_position = self.token() == SyntaxKind::EndOfFileToken;
+
+        let is_reserved_word = self.scanner.is_reserved_word();
+        let msg_arg = self.scanner.get_token_text();
+
+        let default_message = if is_reserved_word {
+            &Diagnostics::Identifier_expected_0_is_a_reserved_word_that_cannot_be_used_here
+        } else {
+            &Diagnostics::Identifier_expected
+        };
+
+        self.create_missing_node(
+            SyntaxKind::Identifier,
+            report_at_current_position,
+            Some(diagnostic_message.unwrap_or(default_message)),
+            &[&msg_arg],
+        )
+    }
+
+    fn parse_binding_identifier(
+        &mut self,
+        private_identifier_diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let is_binding_identifier = self.is_binding_identifier();
+        self.create_identifier(
+            is_binding_identifier,
+            None,
+            private_identifier_diagnostic_message,
+        )
+    }
+
+    fn parse_identifier(
+        &mut self,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+        private_identifier_diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let is_identifier = self.is_identifier();
+        self.create_identifier(
+            is_identifier,
+            diagnostic_message,
+            private_identifier_diagnostic_message,
+        )
+    }
+
+    fn parse_identifier_name(
+        &mut self,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let is_identifier = token_is_identifier_or_keyword(self.token());
+        self.create_identifier(is_identifier, diagnostic_message, None)
+    }
+
+    fn is_literal_property_name(&self) -> bool {
+        token_is_identifier_or_keyword(self.token())
+            || self.token() == SyntaxKind::StringLiteral
+            || self.token() == SyntaxKind::NumericLiteral
+    }
+
+    fn parse_property_name_worker(&mut self, allow_computed_property_names: bool) -> NodeId {
+        if self.token() == SyntaxKind::StringLiteral || self.token() == SyntaxKind::NumericLiteral {
+            let node = self.parse_literal_node();
+            return self.intern_literal_text(node);
+        }
+        if allow_computed_property_names && self.token() == SyntaxKind::OpenBracketToken {
+            return self.parse_computed_property_name();
+        }
+        if self.token() == SyntaxKind::PrivateIdentifier {
+            return self.parse_private_identifier();
+        }
+        self.parse_identifier_name(None)
+    }
+
+    fn parse_property_name(&mut self) -> NodeId {
+        self.parse_property_name_worker(true)
+    }
+
+    fn parse_computed_property_name(&mut self) -> NodeId {
+        // PropertyName [Yield]:
+        //      LiteralPropertyName
+        //      ComputedPropertyName[?Yield]
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken);
+        // We parse any expression (including a comma expression). But the grammar
+        // says that only an assignment expression is allowed, so the grammar checker
+        // will error if it sees a comma expression.
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseBracketToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ComputedPropertyName,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_private_identifier(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let text = self.atoms.intern(&self.scanner.get_token_text());
+        self.next_token();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::PrivateIdentifier,
+                NodeData::PrivateIdentifier { text },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_contextual_modifier(&mut self, t: SyntaxKind) -> bool {
+        self.token() == t && self.try_parse_bool(Self::next_token_can_follow_modifier)
+    }
+
+    fn next_token_is_on_same_line_and_can_follow_modifier(&mut self) -> bool {
+        self.next_token();
+        if self.scanner.has_preceding_line_break() {
+            return false;
+        }
+        self.can_follow_modifier()
+    }
+
+    fn next_token_can_follow_modifier(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::ConstKeyword => {
+                // 'const' is only a modifier if followed by 'enum'.
+                self.next_token() == SyntaxKind::EnumKeyword
+            }
+            SyntaxKind::ExportKeyword => {
+                self.next_token();
+                if self.token() == SyntaxKind::DefaultKeyword {
+                    return self.look_ahead(Self::next_token_can_follow_default_keyword);
+                }
+                if self.token() == SyntaxKind::TypeKeyword {
+                    return self.look_ahead(Self::next_token_can_follow_export_modifier);
+                }
+                self.can_follow_export_modifier()
+            }
+            SyntaxKind::DefaultKeyword => self.next_token_can_follow_default_keyword(),
+            SyntaxKind::StaticKeyword => self.next_token_is_on_same_line_and_can_follow_modifier(),
+            SyntaxKind::GetKeyword | SyntaxKind::SetKeyword => {
+                self.next_token();
+                self.can_follow_modifier()
+            }
+            _ => self.next_token_is_on_same_line_and_can_follow_modifier(),
+        }
+    }
+
+    fn can_follow_export_modifier(&self) -> bool {
+        self.token() != SyntaxKind::AsteriskToken
+            && self.token() != SyntaxKind::AsKeyword
+            && self.token() != SyntaxKind::OpenBraceToken
+            && self.can_follow_modifier()
+    }
+
+    fn next_token_can_follow_export_modifier(&mut self) -> bool {
+        self.next_token();
+        self.can_follow_export_modifier()
+    }
+
+    fn parse_any_contextual_modifier(&mut self) -> bool {
+        is_modifier_kind(self.token()) && self.try_parse_bool(Self::next_token_can_follow_modifier)
+    }
+
+    fn can_follow_modifier(&self) -> bool {
+        self.token() == SyntaxKind::OpenBracketToken
+            || self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::AsteriskToken
+            || self.token() == SyntaxKind::DotDotDotToken
+            || self.is_literal_property_name()
+    }
+
+    fn next_token_can_follow_default_keyword(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ClassKeyword
+            || self.token() == SyntaxKind::FunctionKeyword
+            || self.token() == SyntaxKind::InterfaceKeyword
+            || (self.token() == SyntaxKind::AbstractKeyword
+                && self.look_ahead(Self::next_token_is_class_keyword_on_same_line))
+            || (self.token() == SyntaxKind::AsyncKeyword
+                && self.look_ahead(Self::next_token_is_function_keyword_on_same_line))
+    }
+}
+
+// `token > SyntaxKind.LastReservedWord` of the JS compiler for a token: an identifier or a
+// keyword that is not a reserved word.
+fn is_non_reserved_word_token(token: SyntaxKind) -> bool {
+    match token {
+        SyntaxKind::Keywords(keyword) => !is_reserved_word(keyword),
+        _ => false,
+    }
+}
+
+#[allow(non_snake_case)]
+mod SignatureFlags {
+    pub const NONE: u32 = 0;
+    pub const YIELD: u32 = 1 << 0;
+    pub const AWAIT: u32 = 1 << 1;
+    pub const TYPE: u32 = 1 << 2;
+    pub const IGNORE_MISSING_OPEN_BRACE: u32 = 1 << 4;
+    pub const JSDOC: u32 = 1 << 5;
+}
+
+impl Parser {
+    // True if positioned at the start of a list element
+    fn is_list_element(
+        &mut self,
+        parsing_context: ParsingContext,
+        in_error_recovery: bool,
+    ) -> bool {
+        if self.current_node(parsing_context).is_some() {
+            return true;
+        }
+
+        match parsing_context {
+            ParsingContext::SourceElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauseStatements => {
+                // If we're in error recovery, then we don't want to treat ';' as an empty statement.
+                // The problem is that ';' can show up in far too many contexts, and if we see one
+                // and assume it's a statement, then we may bail out inappropriately from whatever
+                // we're parsing.  For example, if we have a semicolon in the middle of a class, then
+                // we really don't want to assume the class is over and we're on a statement in the
+                // outer module.  We just want to consume and move on.
+                !(self.token() == SyntaxKind::SemicolonToken && in_error_recovery)
+                    && self.is_start_of_statement()
+            }
+            ParsingContext::SwitchClauses => {
+                self.token() == SyntaxKind::CaseKeyword
+                    || self.token() == SyntaxKind::DefaultKeyword
+            }
+            ParsingContext::TypeMembers => self.look_ahead(Self::is_type_member_start),
+            ParsingContext::ClassMembers => {
+                // We allow semicolons as class elements (as specified by ES6) as long as we're
+                // not in error recovery.  If we're in error recovery, we don't want an errant
+                // semicolon to be treated as a class member (since they're almost always used
+                // for statements.
+                self.look_ahead(Self::is_class_member_start)
+                    || (self.token() == SyntaxKind::SemicolonToken && !in_error_recovery)
+            }
+            ParsingContext::EnumMembers => {
+                // Include open bracket computed properties. This technically also lets in indexers,
+                // which would be a candidate for improved error reporting.
+                self.token() == SyntaxKind::OpenBracketToken || self.is_literal_property_name()
+            }
+            ParsingContext::ObjectLiteralMembers => match self.token() {
+                SyntaxKind::OpenBracketToken
+                | SyntaxKind::AsteriskToken
+                | SyntaxKind::DotDotDotToken
+                // Not an object literal member, but don't want to close the object (see `tests/cases/fourslash/completionsDotInObjectLiteral.ts`)
+                | SyntaxKind::DotToken => true,
+                _ => self.is_literal_property_name(),
+            },
+            ParsingContext::RestProperties => self.is_literal_property_name(),
+            ParsingContext::ObjectBindingElements => {
+                self.token() == SyntaxKind::OpenBracketToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    || self.is_literal_property_name()
+            }
+            ParsingContext::HeritageClauseElement => {
+                // If we see `{ ... }` then only consume it as an expression if it is followed by `,` or `{`
+                // That way we won't consume the body of a class in its heritage clause.
+                if self.token() == SyntaxKind::OpenBraceToken {
+                    return self.look_ahead(Self::is_valid_heritage_clause_object_literal);
+                }
+
+                if !in_error_recovery {
+                    self.is_start_of_left_hand_side_expression()
+                        && !self.is_heritage_clause_extends_or_implements_keyword()
+                } else {
+                    // If we're in error recovery we tighten up what we're willing to match.
+                    // That way we don't treat something like "this" as a valid heritage clause
+                    // element during recovery.
+                    self.is_identifier() && !self.is_heritage_clause_extends_or_implements_keyword()
+                }
+            }
+            ParsingContext::VariableDeclarations => {
+                self.is_binding_identifier_or_private_identifier_or_pattern()
+            }
+            ParsingContext::ArrayBindingElements => {
+                self.token() == SyntaxKind::CommaToken
+                    || self.token() == SyntaxKind::DotDotDotToken
+                    || self.is_binding_identifier_or_private_identifier_or_pattern()
+            }
+            ParsingContext::TypeParameters => self.is_identifier(),
+            ParsingContext::ArrayLiteralMembers
+                if self.token() == SyntaxKind::CommaToken
+                    // Not an array literal member, but don't want to close the array (see `tests/cases/fourslash/completionsDotInArrayLiteralInObjectLiteral.ts`)
+                    || self.token() == SyntaxKind::DotToken =>
+            {
+                true
+            }
+            ParsingContext::ArrayLiteralMembers | ParsingContext::ArgumentExpressions => {
+                self.token() == SyntaxKind::DotDotDotToken || self.is_start_of_expression()
+            }
+            ParsingContext::Parameters => self.is_start_of_parameter(false),
+            ParsingContext::JSDocParameters => self.is_start_of_parameter(true),
+            ParsingContext::TypeArguments | ParsingContext::TupleElementTypes => {
+                self.token() == SyntaxKind::CommaToken || self.is_start_of_type(false)
+            }
+            ParsingContext::HeritageClauses => self.is_heritage_clause(),
+            ParsingContext::ImportOrExportSpecifiers => {
+                token_is_identifier_or_keyword(self.token())
+            }
+            ParsingContext::JsxAttributes => {
+                token_is_identifier_or_keyword(self.token())
+                    || self.token() == SyntaxKind::OpenBraceToken
+            }
+            ParsingContext::JsxChildren => true,
+        }
+    }
+
+    fn is_valid_heritage_clause_object_literal(&mut self) -> bool {
+        debug_assert_eq!(self.token(), SyntaxKind::OpenBraceToken);
+        if self.next_token() == SyntaxKind::CloseBraceToken {
+            // if we see "extends {}" then only treat the {} as what we're extending (and not
+            // the class body) if we have:
+            //
+            //      extends {} {
+            //      extends {},
+            //      extends {} extends
+            //      extends {} implements
+
+            let next = self.next_token();
+            return next == SyntaxKind::CommaToken
+                || next == SyntaxKind::OpenBraceToken
+                || next == SyntaxKind::ExtendsKeyword
+                || next == SyntaxKind::ImplementsKeyword;
+        }
+
+        true
+    }
+
+    fn next_token_is_identifier(&mut self) -> bool {
+        self.next_token();
+        self.is_identifier()
+    }
+
+    fn next_token_is_identifier_or_keyword(&mut self) -> bool {
+        self.next_token();
+        token_is_identifier_or_keyword(self.token())
+    }
+
+    fn next_token_is_identifier_or_keyword_or_greater_than(&mut self) -> bool {
+        self.next_token();
+        token_is_identifier_or_keyword_or_greater_than(self.token())
+    }
+
+    fn is_heritage_clause_extends_or_implements_keyword(&mut self) -> bool {
+        if self.token() == SyntaxKind::ImplementsKeyword
+            || self.token() == SyntaxKind::ExtendsKeyword
+        {
+            return self.look_ahead(Self::next_token_is_start_of_expression);
+        }
+
+        false
+    }
+
+    fn next_token_is_start_of_expression(&mut self) -> bool {
+        self.next_token();
+        self.is_start_of_expression()
+    }
+
+    fn next_token_is_start_of_type(&mut self) -> bool {
+        self.next_token();
+        self.is_start_of_type(false)
+    }
+
+    // True if positioned at a list terminator
+    fn is_list_terminator(&mut self, kind: ParsingContext) -> bool {
+        if self.token() == SyntaxKind::EndOfFileToken {
+            // Being at the end of the file ends all lists.
+            return true;
+        }
+
+        match kind {
+            ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauses
+            | ParsingContext::TypeMembers
+            | ParsingContext::ClassMembers
+            | ParsingContext::EnumMembers
+            | ParsingContext::ObjectLiteralMembers
+            | ParsingContext::ObjectBindingElements
+            | ParsingContext::ImportOrExportSpecifiers => {
+                self.token() == SyntaxKind::CloseBraceToken
+            }
+            ParsingContext::SwitchClauseStatements => {
+                self.token() == SyntaxKind::CloseBraceToken
+                    || self.token() == SyntaxKind::CaseKeyword
+                    || self.token() == SyntaxKind::DefaultKeyword
+            }
+            ParsingContext::HeritageClauseElement => {
+                self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::ExtendsKeyword
+                    || self.token() == SyntaxKind::ImplementsKeyword
+            }
+            ParsingContext::VariableDeclarations => self.is_variable_declarator_list_terminator(),
+            ParsingContext::TypeParameters => {
+                // Tokens other than '>' are here for better error recovery
+                self.token() == SyntaxKind::GreaterThanToken
+                    || self.token() == SyntaxKind::OpenParenToken
+                    || self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::ExtendsKeyword
+                    || self.token() == SyntaxKind::ImplementsKeyword
+            }
+            ParsingContext::ArgumentExpressions => {
+                // Tokens other than ')' are here for better error recovery
+                self.token() == SyntaxKind::CloseParenToken
+                    || self.token() == SyntaxKind::SemicolonToken
+            }
+            ParsingContext::ArrayLiteralMembers
+            | ParsingContext::TupleElementTypes
+            | ParsingContext::ArrayBindingElements => self.token() == SyntaxKind::CloseBracketToken,
+            ParsingContext::JSDocParameters
+            | ParsingContext::Parameters
+            | ParsingContext::RestProperties => {
+                // Tokens other than ')' and ']' (the latter for index signatures) are here for better error recovery
+                self.token() == SyntaxKind::CloseParenToken
+                    || self.token() == SyntaxKind::CloseBracketToken
+            }
+            ParsingContext::TypeArguments => {
+                // All other tokens should cause the type-argument to terminate except comma token
+                self.token() != SyntaxKind::CommaToken
+            }
+            ParsingContext::HeritageClauses => {
+                self.token() == SyntaxKind::OpenBraceToken
+                    || self.token() == SyntaxKind::CloseBraceToken
+            }
+            ParsingContext::JsxAttributes => {
+                self.token() == SyntaxKind::GreaterThanToken
+                    || self.token() == SyntaxKind::SlashToken
+            }
+            ParsingContext::JsxChildren => {
+                self.token() == SyntaxKind::LessThanToken
+                    && self.look_ahead(Self::next_token_is_slash)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_variable_declarator_list_terminator(&self) -> bool {
+        // If we can consume a semicolon (either explicitly, or with ASI), then consider us done
+        // with parsing the list of variable declarators.
+        if self.can_parse_semicolon() {
+            return true;
+        }
+
+        // in the case where we're parsing the variable declarator of a 'for-in' statement, we
+        // are done if we see an 'in' keyword in front of us. Same with for-of
+        if is_in_or_of_keyword(self.token()) {
+            return true;
+        }
+
+        // ERROR RECOVERY TWEAK:
+        // For better error recovery, if we see an '=>' then we just stop immediately.  We've got an
+        // arrow function here and it's going to be very unlikely that we'll resynchronize and get
+        // another variable declaration.
+        if self.token() == SyntaxKind::EqualsGreaterThanToken {
+            return true;
+        }
+
+        // Keep trying to parse out variable declarators.
+        false
+    }
+
+    // True if positioned at element or terminator of the current list or any enclosing list
+    fn is_in_some_parsing_context(&mut self) -> bool {
+        for kind in ParsingContext::ALL.iter() {
+            if self.parsing_context & kind.bit() != 0
+                && (self.is_list_element(*kind, true) || self.is_list_terminator(*kind))
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Parses a list of elements
+    fn parse_list(
+        &mut self,
+        kind: ParsingContext,
+        mut parse_element: impl FnMut(&mut Self) -> NodeId,
+    ) -> NodeArray {
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= kind.bit();
+        let mut list = Vec::new();
+        let list_pos = self.get_node_pos();
+
+        while !self.is_list_terminator(kind) {
+            if self.is_list_element(kind, false) {
+                list.push(self.parse_list_element(kind, &mut parse_element));
+
+                continue;
+            }
+
+            if self.abort_parsing_list_or_move_to_next_token(kind) {
+                break;
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        self.create_node_array(list, list_pos, None, false)
+    }
+
+    fn parse_list_element(
+        &mut self,
+        parsing_context: ParsingContext,
+        parse_element: &mut impl FnMut(&mut Self) -> NodeId,
+    ) -> NodeId {
+        if let Some(node) = self.current_node(parsing_context) {
+            return self.consume_node(node);
+        }
+
+        parse_element(self)
+    }
+
+    fn current_node(&mut self, parsing_context: ParsingContext) -> Option<NodeId> {
+        // If we don't have a cursor or the parsing context isn't reusable, there's nothing to reuse.
+        //
+        // If there is an outstanding parse error that we've encountered, but not attached to
+        // some node, then we cannot get a node from the old source tree.  This is because we
+        // want to mark the next node we encounter as being unusable.
+        //
+        // Note: This may be too conservative.  Perhaps we could reuse the node and set the bit
+        // on it (or its leftmost child) as having the error.  For now though, being conservative
+        // is nice and likely won't ever affect perf.
+        if self.syntax_cursor.is_none()
+            || !is_reusable_parsing_context(parsing_context)
+            || self.parse_error_before_next_finished_node.get()
+        {
+            return None;
+        }
+
+        let position = self.scanner.get_start_pos();
+        let syntax_cursor = self.syntax_cursor.as_mut()?;
+        let node = syntax_cursor.current_node(&self.nodes, position)?;
+        let intersects_change = syntax_cursor.intersects_change.contains(&node);
+
+        // Can't reuse a missing node.
+        // Can't reuse a node that intersected the change range.
+        // Can't reuse a node that contains a parse error.  This is necessary so that we
+        // produce the same set of errors again.
+        if node_is_missing(self.node(node)) || intersects_change || self.contains_parse_error(node)
+        {
+            return None;
+        }
+
+        // We can only reuse a node if it was parsed under the same strict mode that we're
+        // currently in.  i.e. if we originally parsed a node in non-strict mode, but then
+        // the user added 'using strict' at the top of the file, then we can't use that node
+        // again as the presence of strict mode may cause us to parse the tokens in the file
+        // differently.
+        //
+        // Note: we *can* reuse tokens when the strict mode changes.  That's because tokens
+        // are unaffected by strict mode.  It's just the parser will decide what to do with it
+        // differently depending on what mode it is in.
+        //
+        // This also applies to all our other context flags as well.
+        let node_context_flags = self.node(node).flags & NodeFlags::CONTEXT_FLAGS;
+        if node_context_flags != self.context_flags {
+            return None;
+        }
+
+        // Ok, we have a node that looks like it could be reused.  Now verify that it is valid
+        // in the current list parsing context that we're currently at.
+        if !self.can_reuse_node(node, parsing_context) {
+            return None;
+        }
+
+        Some(node)
+    }
+
+    fn consume_node(&mut self, node: NodeId) -> NodeId {
+        // Move the scanner so it is after the node we just consumed.
+        let end = self.node(node).end();
+        self.scanner.set_text_pos(Some(end));
+        self.next_token();
+        node
+    }
+
+    fn contains_parse_error(&mut self, node: NodeId) -> bool {
+        self.aggregate_child_data(node);
+        self.node(node).flags & NodeFlags::THIS_NODE_OR_ANY_SUB_NODES_HAS_ERROR != 0
+    }
+
+    fn aggregate_child_data(&mut self, node: NodeId) {
+        if self.node(node).flags & NodeFlags::HAS_AGGREGATED_CHILD_DATA == 0 {
+            // A node is considered to contain a parse error if:
+            //  a) the parser explicitly marked that it had an error
+            //  b) any of it's children reported that it had an error.
+            let this_node_or_any_sub_nodes_has_error =
+                self.node(node).flags & NodeFlags::THIS_NODE_HAS_ERROR != 0
+                    || self
+                        .children(node)
+                        .into_iter()
+                        .any(|child| self.contains_parse_error(child));
+
+            // If so, mark ourselves accordingly.
+            let node = self.node_mut(node);
+            if this_node_or_any_sub_nodes_has_error {
+                node.flags |= NodeFlags::THIS_NODE_OR_ANY_SUB_NODES_HAS_ERROR;
+            }
+
+            // Also mark that we've propagated the child information to this node.  This way we
+            // can always consult the bit directly on this node without needing to check its
+            // children again.
+            node.flags |= NodeFlags::HAS_AGGREGATED_CHILD_DATA;
+        }
+    }
+
+    fn children(&self, node: NodeId) -> Vec<NodeId> {
+        let mut children = Vec::new();
+        for_each_child(
+            self.node(node),
+            &mut |child| {
+                children.push(child);
+                None::<()>
+            },
+            None,
+        );
+        children
+    }
+
+    fn can_reuse_node(&self, node: NodeId, parsing_context: ParsingContext) -> bool {
+        match parsing_context {
+            ParsingContext::ClassMembers => self.is_reusable_class_member(node),
+            ParsingContext::SwitchClauses => is_reusable_switch_clause(self.node(node)),
+            ParsingContext::SourceElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauseStatements => is_reusable_statement(self.node(node)),
+            ParsingContext::EnumMembers => self.kind(node) == SyntaxKind::EnumMember,
+            ParsingContext::TypeMembers => is_reusable_type_member(self.node(node)),
+            ParsingContext::VariableDeclarations => {
+                is_reusable_variable_declaration(self.node(node))
+            }
+            ParsingContext::JSDocParameters | ParsingContext::Parameters => {
+                is_reusable_parameter(self.node(node))
+            }
+
+            // Any other lists we do not care about reusing nodes in.  But feel free to add if
+            // you can do so safely.  Danger areas involve nodes that may involve speculative
+            // parsing.  If speculative parsing is involved with the node, then the range the
+            // parser reached while looking ahead might be in the edited range (see the example
+            // in is_reusable_variable_declaration for a good case of this).
+
+            // ParsingContext::HeritageClauses would probably be safe to reuse.  There is no
+            // speculative parsing with heritage clauses.
+
+            // ParsingContext::TypeParameters would probably be safe to reuse.  There is no
+            // speculative parsing with type parameters.  Note that that's because type
+            // *parameters* only occur in unambiguous *type* contexts.  While type *arguments*
+            // occur in very ambiguous *expression* contexts.
+
+            // ParsingContext::TupleElementTypes would probably be safe to reuse.  There is no
+            // speculative parsing with tuple types.
+
+            // Technically, type argument list types are probably safe to reuse.  While
+            // speculative parsing is involved with them (since type argument lists are only
+            // produced from speculative parsing a < as a type argument list), we only have
+            // the types because speculative parsing succeeded.  Thus, the lookahead never
+            // went past the end of the list and rewound.
+
+            // ParsingContext::ArgumentExpressions is almost certainly not safe to ever reuse.
+            // Expressions commonly need a large amount of lookahead, and we should not reuse
+            // them as they may have actually intersected the edit.
+
+            // ParsingContext::ObjectLiteralMembers is not safe to reuse for the same reason.
+            // i.e. a property assignment may end with an expression, and thus might have
+            // lookahead far beyond it's old node.
+
+            // ParsingContext::HeritageClauseElement is probably not safe to reuse.  There can
+            // be speculative parsing with type names in a heritage clause.  There can be
+            // generic names in the type name list, and there can be left hand side expressions
+            // (which can have type arguments.)
+
+            // JSX attributes and children are perhaps safe to reuse, but it's unlikely we'd see
+            // more than a dozen attributes on any given element. Same for children.
+            _ => false,
+        }
+    }
+
+    fn is_reusable_class_member(&self, node: NodeId) -> bool {
+        match self.kind(node) {
+            SyntaxKind::Constructor
+            | SyntaxKind::IndexSignature
+            | SyntaxKind::GetAccessor
+            | SyntaxKind::SetAccessor
+            | SyntaxKind::PropertyDeclaration
+            | SyntaxKind::SemicolonClassElement => true,
+            SyntaxKind::MethodDeclaration => {
+                // Method declarations are not necessarily reusable.  An object-literal
+                // may have a method calls "constructor(...)" and we must reparse that
+                // into an actual .ConstructorDeclaration.
+                let name_is_constructor = self.node(node).name().is_some_and(|name| {
+                    matches!(
+                        self.node(name).data,
+                        NodeData::Identifier {
+                            original_keyword_kind: Some(KeywordSyntaxKind::ConstructorKeyword),
+                            ..
+                        }
+                    )
+                });
+                !name_is_constructor
+            }
+            _ => false,
+        }
+    }
+
+    // Returns true if we should abort parsing.
+    fn abort_parsing_list_or_move_to_next_token(&mut self, kind: ParsingContext) -> bool {
+        self.parsing_context_errors(kind);
+        if self.is_in_some_parsing_context() {
+            return true;
+        }
+
+        self.next_token();
+        false
+    }
+
+    fn parsing_context_errors(&mut self, context: ParsingContext) {
+        let message = match context {
+            ParsingContext::SourceElements => &Diagnostics::Declaration_or_statement_expected,
+            ParsingContext::BlockStatements => &Diagnostics::Declaration_or_statement_expected,
+            ParsingContext::SwitchClauses => &Diagnostics::case_or_default_expected,
+            ParsingContext::SwitchClauseStatements => &Diagnostics::Statement_expected,
+            ParsingContext::RestProperties | ParsingContext::TypeMembers => {
+                &Diagnostics::Property_or_signature_expected
+            }
+            ParsingContext::ClassMembers => {
+                &Diagnostics::Unexpected_token_A_constructor_method_accessor_or_property_was_expected
+            }
+            ParsingContext::EnumMembers => &Diagnostics::Enum_member_expected,
+            ParsingContext::HeritageClauseElement => &Diagnostics::Expression_expected,
+            ParsingContext::VariableDeclarations => {
+                if is_keyword(self.token()) {
+                    let token = token_to_string(self.token()).unwrap_or_default();
+                    return self.parse_error_at_current_token(
+                        &Diagnostics::_0_is_not_allowed_as_a_variable_declaration_name,
+                        &[token],
+                    );
+                }
+                &Diagnostics::Variable_declaration_expected
+            }
+            ParsingContext::ObjectBindingElements => {
+                &Diagnostics::Property_destructuring_pattern_expected
+            }
+            ParsingContext::ArrayBindingElements => {
+                &Diagnostics::Array_element_destructuring_pattern_expected
+            }
+            ParsingContext::ArgumentExpressions => &Diagnostics::Argument_expression_expected,
+            ParsingContext::ObjectLiteralMembers => &Diagnostics::Property_assignment_expected,
+            ParsingContext::ArrayLiteralMembers => &Diagnostics::Expression_or_comma_expected,
+            ParsingContext::JSDocParameters => &Diagnostics::Parameter_declaration_expected,
+            ParsingContext::Parameters => &Diagnostics::Parameter_declaration_expected,
+            ParsingContext::TypeParameters => &Diagnostics::Type_parameter_declaration_expected,
+            ParsingContext::TypeArguments => &Diagnostics::Type_argument_expected,
+            ParsingContext::TupleElementTypes => &Diagnostics::Type_expected,
+            ParsingContext::HeritageClauses => &Diagnostics::Unexpected_token_expected,
+            ParsingContext::ImportOrExportSpecifiers => &Diagnostics::Identifier_expected,
+            ParsingContext::JsxAttributes => &Diagnostics::Identifier_expected,
+            ParsingContext::JsxChildren => &Diagnostics::Identifier_expected,
+        };
+        self.parse_error_at_current_token(message, &[]);
+    }
+
+    // Parses a comma-delimited list of elements
+    fn parse_delimited_list(
+        &mut self,
+        kind: ParsingContext,
+        mut parse_element: impl FnMut(&mut Self) -> NodeId,
+        consider_semicolon_as_delimiter: bool,
+    ) -> NodeArray {
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= kind.bit();
+        let mut list = Vec::new();
+        let list_pos = self.get_node_pos();
+
+        // `None` meaning the previous token was not a comma
+        let mut comma_start = None;
+        loop {
+            if self.is_list_element(kind, false) {
+                let start_pos = self.scanner.get_start_pos();
+                list.push(self.parse_list_element(kind, &mut parse_element));
+                comma_start = Some(self.scanner.get_token_pos());
+
+                if self.parse_optional(SyntaxKind::CommaToken) {
+                    // No need to check for a zero length node since we know we parsed a comma
+                    continue;
+                }
+
+                comma_start = None; // Back to the state where the last token was not a comma
+                if self.is_list_terminator(kind) {
+                    break;
+                }
+
+                // We didn't get a comma, and the list wasn't terminated, explicitly parse
+                // out a comma so we give a good error message.
+                self.parse_expected_with_message(
+                    SyntaxKind::CommaToken,
+                    get_expected_comma_diagnostic(kind).as_ref(),
+                    true,
+                );
+
+                // If the token was a semicolon, and the caller allows that, then skip it and
+                // continue.  This ensures we get back on track and don't result in tons of
+                // parse errors.  For example, this can happen when people do things like use
+                // a semicolon to delimit object literal members.   Note: we'll have already
+                // reported an error when we called parseExpected above.
+                if consider_semicolon_as_delimiter
+                    && self.token() == SyntaxKind::SemicolonToken
+                    && !self.scanner.has_preceding_line_break()
+                {
+                    self.next_token();
+                }
+                if start_pos == self.scanner.get_start_pos() {
+                    // What we're parsing isn't actually remotely recognizable as a element and we've consumed no tokens whatsoever
+                    // Consume a token to advance the parser in some way and avoid an infinite loop
+                    // This can happen when we're speculatively parsing parenthesized expressions which we think may be arrow functions,
+                    // or when a modifier keyword which is disallowed as a parameter name (ie, `static` in strict mode) is supplied
+                    self.next_token();
+                }
+                continue;
+            }
+
+            if self.is_list_terminator(kind) {
+                break;
+            }
+
+            if self.abort_parsing_list_or_move_to_next_token(kind) {
+                break;
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        // Recording the trailing comma is deliberately done after the previous
+        // loop, and not just if we see a list terminator. This is because the list
+        // may have ended incorrectly, but it is still important to know if there
+        // was a trailing comma.
+        // Check if the last token was a comma.
+        // Always preserve a trailing comma by marking it on the NodeArray
+        self.create_node_array(list, list_pos, None, comma_start.is_some())
+    }
+
+    fn create_missing_list(&self) -> NodeArray {
+        let mut list = self.create_node_array(Vec::new(), self.get_node_pos(), None, false);
+        list.is_missing_list = true;
+        list
+    }
+
+    fn parse_bracketed_list(
+        &mut self,
+        kind: ParsingContext,
+        parse_element: impl FnMut(&mut Self) -> NodeId,
+        open: SyntaxKind,
+        close: SyntaxKind,
+    ) -> NodeArray {
+        if self.parse_expected(open) {
+            let result = self.parse_delimited_list(kind, parse_element, false);
+            self.parse_expected(close);
+            return result;
+        }
+
+        self.create_missing_list()
+    }
+
+    fn parse_entity_name(
+        &mut self,
+        allow_reserved_words: bool,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let pos = self.get_node_pos();
+        let mut entity = if allow_reserved_words {
+            self.parse_identifier_name(diagnostic_message)
+        } else {
+            self.parse_identifier(diagnostic_message, None)
+        };
+        let mut dot_pos = self.get_node_pos();
+        while self.parse_optional(SyntaxKind::DotToken) {
+            if self.token() == SyntaxKind::LessThanToken {
+                // the entity is part of a JSDoc-style generic, so record the trailing dot for later error reporting
+                set_jsdoc_dot_pos(self.node_mut(entity), dot_pos);
+                break;
+            }
+            dot_pos = self.get_node_pos();
+            let right = self.parse_right_side_of_dot(allow_reserved_words, false);
+            entity = self.finish_node(
+                Node::new(
+                    SyntaxKind::QualifiedName,
+                    NodeData::QualifiedName {
+                        left: entity,
+                        right,
+                        jsdoc_dot_pos: None,
+                    },
+                ),
+                pos,
+            );
+        }
+        entity
+    }
+
+    fn create_qualified_name(&mut self, entity: NodeId, name: NodeId) -> NodeId {
+        let pos = self.node(entity).pos();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::QualifiedName,
+                NodeData::QualifiedName {
+                    left: entity,
+                    right: name,
+                    jsdoc_dot_pos: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_right_side_of_dot(
+        &mut self,
+        allow_identifier_names: bool,
+        allow_private_identifiers: bool,
+    ) -> NodeId {
+        // Technically a keyword is valid here as all identifiers and keywords are identifier names.
+        // However, often we'll encounter this in error situations when the identifier or keyword
+        // is actually starting another valid construct.
+        //
+        // So, we check for the following specific case:
+        //
+        //      name.
+        //      identifierOrKeyword identifierNameOrKeyword
+        //
+        // Note: the newlines are important here.  For example, if that above code
+        // were rewritten into:
+        //
+        //      name.identifierOrKeyword
+        //      identifierNameOrKeyword
+        //
+        // Then we would consider it valid.  That's because ASI would take effect and
+        // the code would be implicitly: "name.identifierOrKeyword; identifierNameOrKeyword".
+        // In the first case though, ASI will not take effect because there is not a
+        // line terminator after the identifier or keyword.
+        if self.scanner.has_preceding_line_break() && token_is_identifier_or_keyword(self.token()) {
+            let matches_pattern =
+                self.look_ahead(Self::next_token_is_identifier_or_keyword_on_same_line);
+
+            if matches_pattern {
+                // Report that we need an identifier.  However, report it right after the dot,
+                // and not on the next token.  This is because the next token might actually
+                // be an identifier and the error would be quite confusing.
+                return self.create_missing_node(
+                    SyntaxKind::Identifier,
+                    true,
+                    Some(&Diagnostics::Identifier_expected),
+                    &[],
+                );
+            }
+        }
+
+        if self.token() == SyntaxKind::PrivateIdentifier {
+            let node = self.parse_private_identifier();
+            return if allow_private_identifiers {
+                node
+            } else {
+                self.create_missing_node(
+                    SyntaxKind::Identifier,
+                    true,
+                    Some(&Diagnostics::Identifier_expected),
+                    &[],
+                )
+            };
+        }
+
+        if allow_identifier_names {
+            self.parse_identifier_name(None)
+        } else {
+            self.parse_identifier(None, None)
+        }
+    }
+
+    fn parse_template_spans(&mut self, is_tagged_template: bool) -> NodeArray {
+        let pos = self.get_node_pos();
+        let mut list = Vec::new();
+        loop {
+            let node = self.parse_template_span(is_tagged_template);
+            list.push(node);
+            if !self.template_span_literal_is_middle(node) {
+                break;
+            }
+        }
+        self.create_node_array(list, pos, None, false)
+    }
+
+    fn template_span_literal_is_middle(&self, span: NodeId) -> bool {
+        match self.node(span).data {
+            NodeData::TemplateSpan { literal, .. }
+            | NodeData::TemplateLiteralTypeSpan { literal, .. } => {
+                self.kind(literal) == SyntaxKind::TemplateMiddle
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_template_expression(&mut self, is_tagged_template: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        let head = self.parse_template_head(is_tagged_template);
+        let template_spans = self.parse_template_spans(is_tagged_template);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TemplateExpression,
+                NodeData::TemplateExpression {
+                    head,
+                    template_spans,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_template_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let head = self.parse_template_head(false);
+        let template_spans = self.parse_template_type_spans();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TemplateLiteralType,
+                NodeData::TemplateExpression {
+                    head,
+                    template_spans,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_template_type_spans(&mut self) -> NodeArray {
+        let pos = self.get_node_pos();
+        let mut list = Vec::new();
+        loop {
+            let node = self.parse_template_type_span();
+            list.push(node);
+            if !self.template_span_literal_is_middle(node) {
+                break;
+            }
+        }
+        self.create_node_array(list, pos, None, false)
+    }
+
+    fn parse_template_type_span(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let _type = self.parse_type();
+        let literal = self.parse_literal_of_template_span(false);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TemplateLiteralTypeSpan,
+                NodeData::TemplateLiteralTypeSpan { _type, literal },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_literal_of_template_span(&mut self, is_tagged_template: bool) -> NodeId {
+        if self.token() == SyntaxKind::CloseBraceToken {
+            self.re_scan_template_token(is_tagged_template);
+            self.parse_template_middle_or_template_tail()
+        } else {
+            self.parse_expected_token(
+                SyntaxKind::TemplateTail,
+                Some(&Diagnostics::_0_expected),
+                token_to_string(SyntaxKind::CloseBraceToken),
+            )
+        }
+    }
+
+    fn parse_template_span(&mut self, is_tagged_template: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        let expression = self.allow_in_and(Self::parse_expression);
+        let literal = self.parse_literal_of_template_span(is_tagged_template);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TemplateSpan,
+                NodeData::TemplateSpan {
+                    expression,
+                    literal,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_literal_node(&mut self) -> NodeId {
+        let kind = self.token();
+        self.parse_literal_like_node(kind)
+    }
+
+    fn parse_template_head(&mut self, is_tagged_template: bool) -> NodeId {
+        if is_tagged_template {
+            self.re_scan_template_head_or_no_substitution_template();
+        }
+        let kind = self.token();
+        let fragment = self.parse_literal_like_node(kind);
+        debug_assert_eq!(
+            self.kind(fragment),
+            SyntaxKind::TemplateHead,
+            "Template head has wrong token kind"
+        );
+        fragment
+    }
+
+    fn parse_template_middle_or_template_tail(&mut self) -> NodeId {
+        let kind = self.token();
+        let fragment = self.parse_literal_like_node(kind);
+        debug_assert!(
+            self.kind(fragment) == SyntaxKind::TemplateMiddle
+                || self.kind(fragment) == SyntaxKind::TemplateTail,
+            "Template fragment has wrong token kind"
+        );
+        fragment
+    }
+
+    fn get_template_literal_raw_text(&self, kind: SyntaxKind) -> String {
+        let is_last =
+            kind == SyntaxKind::NoSubstitutionTemplateLiteral || kind == SyntaxKind::TemplateTail;
+        let token_text: Vec<u16> = self.scanner.get_token_text().encode_utf16().collect();
+        let end = token_text.len()
+            - if self.scanner.is_unterminated() {
+                0
+            } else if is_last {
+                1
+            } else {
+                2
+            };
+        String::from_utf16_lossy(&token_text[1.min(end)..end.max(1.min(end))])
+    }
+
+    fn parse_literal_like_node(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        let (raw_text, token_flags) = if is_template_literal_kind(kind) {
+            (
+                Some(self.get_template_literal_raw_text(kind)),
+                self.scanner.get_token_flags() & TokenFlags::ContainsInvalidEscape as u32,
+            )
+        } else if kind == SyntaxKind::NumericLiteral {
+            // Octal literals are not allowed in strict mode or ES5
+            // Note that theoretically the following condition would hold true literals like 009,
+            // which is not octal. But because of how the scanner separates the tokens, we would
+            // never get a token like this. Instead, we would get 00 and 9 as two separate tokens.
+            // We also do not need to check for negatives because any prefix operator would be part of a
+            // parent unary expression.
+            (None, self.scanner.get_numeric_literal_flags())
+        } else {
+            debug_assert!(is_literal_kind(kind));
+            (None, 0)
+        };
+        let node = Node::new(
+            kind,
+            NodeData::Literal {
+                text: self.scanner.get_token_value(),
+                raw_text,
+                token_flags,
+                is_unterminated: self.scanner.is_unterminated(),
+                has_extended_unicode_escape: self.scanner.has_extended_unicode_escape(),
+            },
+        );
+
+        self.next_token();
+        self.finish_node(node, pos)
+    }
+
+    // TYPES
+
+    fn parse_entity_name_of_type_reference(&mut self) -> NodeId {
+        self.parse_entity_name(true, Some(&Diagnostics::Type_expected))
+    }
+
+    fn parse_type_arguments_of_type_reference(&mut self) -> Option<NodeArray> {
+        if !self.scanner.has_preceding_line_break()
+            && self.re_scan_less_than_token() == SyntaxKind::LessThanToken
+        {
+            return Some(self.parse_bracketed_list(
+                ParsingContext::TypeArguments,
+                Self::parse_type,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ));
+        }
+        None
+    }
+
+    fn parse_type_reference(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let type_name = self.parse_entity_name_of_type_reference();
+        let type_arguments = self.parse_type_arguments_of_type_reference();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeReference,
+                NodeData::TypeReference {
+                    type_name,
+                    type_arguments,
+                },
+            ),
+            pos,
+        )
+    }
+
+    // If true, we should abort parsing an error function.
+    fn type_has_arrow_function_blocking_parse_error(&self, node: NodeId) -> bool {
+        let node = self.node(node);
+        match (&node.kind, &node.data) {
+            (&SyntaxKind::TypeReference, NodeData::TypeReference { type_name, .. }) => {
+                node_is_missing(self.node(*type_name))
+            }
+            (&SyntaxKind::FunctionType, NodeData::SignatureDeclaration(signature))
+            | (&SyntaxKind::ConstructorType, NodeData::SignatureDeclaration(signature)) => {
+                signature.parameters.is_missing_list
+                    || signature._type.is_some_and(|_type| {
+                        self.type_has_arrow_function_blocking_parse_error(_type)
+                    })
+            }
+            (&SyntaxKind::ParenthesizedType, NodeData::Type { _type: Some(_type) }) => {
+                self.type_has_arrow_function_blocking_parse_error(*_type)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_this_type_predicate(&mut self, lhs: NodeId) -> NodeId {
+        self.next_token();
+        let _type = self.parse_type();
+        let pos = self.node(lhs).pos();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypePredicate,
+                NodeData::TypePredicate {
+                    asserts_modifier: None,
+                    parameter_name: lhs,
+                    _type: Some(_type),
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_this_type_node(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.next_token();
+        self.finish_node(Node::new(SyntaxKind::ThisType, NodeData::Token), pos)
+    }
+
+    fn parse_js_doc_all_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.next_token();
+        self.finish_node(Node::new(SyntaxKind::JSDocAllType, NodeData::Token), pos)
+    }
+
+    fn parse_js_doc_non_nullable_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.next_token();
+        let _type = self.parse_non_array_type();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocNonNullableType,
+                NodeData::Type { _type: Some(_type) },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_unknown_or_nullable_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        // skip the ?
+        self.next_token();
+
+        // Need to lookahead to decide if this is a nullable or unknown type.
+
+        // Here are cases where we'll pick the unknown type:
+        //
+        //      Foo(?,
+        //      { a: ? }
+        //      Foo(?)
+        //      Foo<?>
+        //      Foo(?=
+        //      (?|
+        if self.token() == SyntaxKind::CommaToken
+            || self.token() == SyntaxKind::CloseBraceToken
+            || self.token() == SyntaxKind::CloseParenToken
+            || self.token() == SyntaxKind::GreaterThanToken
+            || self.token() == SyntaxKind::EqualsToken
+            || self.token() == SyntaxKind::BarToken
+        {
+            self.finish_node(
+                Node::new(SyntaxKind::JSDocUnknownType, NodeData::Token),
+                pos,
+            )
+        } else {
+            let _type = self.parse_type();
+            self.finish_node(
+                Node::new(
+                    SyntaxKind::JSDocNullableType,
+                    NodeData::Type { _type: Some(_type) },
+                ),
+                pos,
+            )
+        }
+    }
+
+    fn parse_js_doc_function_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        if self.look_ahead(Self::next_token_is_open_paren) {
+            self.next_token();
+            let parameters = self.parse_parameters(SignatureFlags::TYPE | SignatureFlags::JSDOC);
+            let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+            let node = self.finish_node(
+                Node::new(
+                    SyntaxKind::JSDocFunctionType,
+                    NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                        parameters,
+                        _type,
+                        ..SignatureDeclaration::default()
+                    })),
+                ),
+                pos,
+            );
+            return self.with_js_doc(node, has_js_doc);
+        }
+        let type_name = self.parse_identifier_name(None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeReference,
+                NodeData::TypeReference {
+                    type_name,
+                    type_arguments: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_parameter(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let mut name = None;
+        if self.token() == SyntaxKind::ThisKeyword || self.token() == SyntaxKind::NewKeyword {
+            name = Some(self.parse_identifier_name(None));
+            self.parse_expected(SyntaxKind::ColonToken);
+        }
+        let _type = self.parse_js_doc_type();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::Parameter,
+                NodeData::Parameter {
+                    dot_dot_dot_token: None,
+                    name,
+                    question_token: None,
+                    _type: Some(_type),
+                    initializer: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_type(&mut self) -> NodeId {
+        self.scanner.set_in_jsdoc_type(true);
+        let pos = self.get_node_pos();
+        if self.parse_optional(SyntaxKind::ModuleKeyword) {
+            // TODO(rbuckton): We never set the type for a JSDocNamepathType. What should we put here?
+            let module_tag = Node::new(
+                SyntaxKind::JSDocNamepathType,
+                NodeData::Type { _type: None },
+            );
+            loop {
+                match self.token() {
+                    SyntaxKind::CloseBraceToken
+                    | SyntaxKind::EndOfFileToken
+                    | SyntaxKind::CommaToken
+                    | SyntaxKind::WhitespaceTrivia => break,
+                    _ => {
+                        self.next_token_js_doc();
+                    }
+                }
+            }
+
+            self.scanner.set_in_jsdoc_type(false);
+            return self.finish_node(module_tag, pos);
+        }
+
+        let has_dot_dot_dot = self.parse_optional(SyntaxKind::DotDotDotToken);
+        let mut _type = self.parse_type_or_type_predicate();
+        self.scanner.set_in_jsdoc_type(false);
+        if has_dot_dot_dot {
+            _type = self.finish_node(
+                Node::new(
+                    SyntaxKind::JSDocVariadicType,
+                    NodeData::Type { _type: Some(_type) },
+                ),
+                pos,
+            );
+        }
+        if self.token() == SyntaxKind::EqualsToken {
+            self.next_token();
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::JSDocOptionalType,
+                    NodeData::Type { _type: Some(_type) },
+                ),
+                pos,
+            );
+        }
+        _type
+    }
+
+    fn parse_type_query(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::TypeOfKeyword);
+        let expr_name = self.parse_entity_name(true, None);
+        self.finish_node(
+            Node::new(SyntaxKind::TypeQuery, NodeData::TypeQuery { expr_name }),
+            pos,
+        )
+    }
+
+    fn parse_type_parameter(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let name = self.parse_identifier(None, None);
+        let mut constraint = None;
+        let mut expression = None;
+        if self.parse_optional(SyntaxKind::ExtendsKeyword) {
+            // It's not uncommon for people to write improper constraints to a generic.  If the
+            // user writes a constraint that is an expression and not an actual type, then parse
+            // it out as an expression (so we can recover well), but report that a type is needed
+            // instead.
+            if self.is_start_of_type(false) || !self.is_start_of_expression() {
+                constraint = Some(self.parse_type());
+            } else {
+                // It was not a type, and it looked like an expression.  Parse out an expression
+                // here so we recover well.  Note: it is important that we call parseUnaryExpression
+                // and not parseExpression here.  If the user has:
+                //
+                //      <T extends "">
+                //
+                // We do *not* want to consume the `>` as we're consuming the expression for "".
+                expression = Some(self.parse_unary_expression_or_higher());
+            }
+        }
+
+        let default = if self.parse_optional(SyntaxKind::EqualsToken) {
+            Some(self.parse_type())
+        } else {
+            None
+        };
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeParameter,
+                NodeData::TypeParameter {
+                    name,
+                    constraint,
+                    default,
+                    expression,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_type_parameters(&mut self) -> Option<NodeArray> {
+        if self.token() == SyntaxKind::LessThanToken {
+            return Some(self.parse_bracketed_list(
+                ParsingContext::TypeParameters,
+                Self::parse_type_parameter,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ));
+        }
+        None
+    }
+
+    fn is_start_of_parameter(&mut self, is_js_doc_parameter: bool) -> bool {
+        self.token() == SyntaxKind::DotDotDotToken
+            || self.is_binding_identifier_or_private_identifier_or_pattern()
+            || is_modifier_kind(self.token())
+            || self.token() == SyntaxKind::AtToken
+            || self.is_start_of_type(!is_js_doc_parameter)
+    }
+
+    fn parse_name_of_parameter(&mut self, modifiers: &Option<NodeArray>) -> NodeId {
+        // FormalParameter [Yield,Await]:
+        //      BindingElement[?Yield,?Await]
+        let name = self.parse_identifier_or_pattern(Some(
+            &Diagnostics::Private_identifiers_cannot_be_used_as_parameters,
+        ));
+        let name_range = self.node(name).range;
+        if name_range.end == name_range.pos
+            && modifiers
+                .as_ref()
+                .is_none_or(|modifiers| modifiers.is_empty())
+            && is_modifier_kind(self.token())
+        {
+            // in cases like
+            // 'use strict'
+            // function foo(static)
+            // isParameter('static') === true, because of isModifier('static')
+            // however 'static' is not a legal identifier in a strict mode.
+            // so result of this function will be ParameterDeclaration (flags = 0, name = missing, type = undefined, initializer = undefined)
+            // and current token will not change => parsing of the enclosing parameter list will last till the end of time (or OOM)
+            // to avoid this we'll advance cursor to the next token.
+            self.next_token();
+        }
+        name
+    }
+
+    fn parse_parameter_in_outer_await_context(&mut self) -> NodeId {
+        self.parse_parameter_worker(true)
+    }
+
+    fn parse_parameter(&mut self) -> NodeId {
+        self.parse_parameter_worker(false)
+    }
+
+    fn parse_parameter_worker(&mut self, in_outer_await_context: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+
+        // FormalParameter [Yield,Await]:
+        //      BindingElement[?Yield,?Await]
+
+        // Decorators are parsed in the outer [Await] context, the rest of the parameter is parsed in the function's [Await] context.
+        let decorators = if in_outer_await_context {
+            self.do_in_await_context(Self::parse_decorators)
+        } else {
+            self.parse_decorators()
+        };
+
+        if self.token() == SyntaxKind::ThisKeyword {
+            let name = self.create_identifier(true, None, None);
+            let _type = self.parse_type_annotation();
+
+            if let Some(decorators) = &decorators {
+                let range = self.node(decorators[0]).range;
+                self.parse_error_at_range(
+                    range,
+                    &Diagnostics::Decorators_may_not_be_applied_to_this_parameters,
+                    &[],
+                );
+            }
+
+            let mut node = Node::new(
+                SyntaxKind::Parameter,
+                NodeData::Parameter {
+                    dot_dot_dot_token: None,
+                    name: Some(name),
+                    question_token: None,
+                    _type,
+                    initializer: None,
+                },
+            );
+            node.decorators = decorators;
+            let node = self.finish_node(node, pos);
+            return self.with_js_doc(node, has_js_doc);
+        }
+
+        let saved_top_level = self.top_level;
+        self.top_level = false;
+        let modifiers = self.parse_modifiers(false);
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+        let name = self.parse_name_of_parameter(&modifiers);
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        let _type = self.parse_type_annotation();
+        let initializer = self.parse_initializer();
+        let mut node = Node::new(
+            SyntaxKind::Parameter,
+            NodeData::Parameter {
+                dot_dot_dot_token,
+                name: Some(name),
+                question_token,
+                _type,
+                initializer,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        let node = self.with_js_doc(node, has_js_doc);
+        self.top_level = saved_top_level;
+        node
+    }
+
+    fn parse_return_type(&mut self, return_token: SyntaxKind, is_type: bool) -> Option<NodeId> {
+        if self.should_parse_return_type(return_token, is_type) {
+            return Some(self.parse_type_or_type_predicate());
+        }
+        None
+    }
+
+    fn should_parse_return_type(&mut self, return_token: SyntaxKind, is_type: bool) -> bool {
+        if return_token == SyntaxKind::EqualsGreaterThanToken {
+            self.parse_expected(return_token);
+            return true;
+        } else if self.parse_optional(SyntaxKind::ColonToken) {
+            return true;
+        } else if is_type && self.token() == SyntaxKind::EqualsGreaterThanToken {
+            // This is easy to get backward, especially in type contexts, so parse the type anyway
+            self.parse_error_at_current_token(
+                &Diagnostics::_0_expected,
+                &[token_to_string(SyntaxKind::ColonToken).unwrap_or_default()],
+            );
+            self.next_token();
+            return true;
+        }
+        false
+    }
+
+    fn parse_parameters_worker(&mut self, flags: u32) -> NodeArray {
+        // FormalParameters [Yield,Await]: (modified)
+        //      [empty]
+        //      FormalParameterList[?Yield,Await]
+        //
+        // FormalParameter[Yield,Await]: (modified)
+        //      BindingElement[?Yield,Await]
+        //
+        // BindingElement [Yield,Await]: (modified)
+        //      SingleNameBinding[?Yield,?Await]
+        //      BindingPattern[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        //
+        // SingleNameBinding [Yield,Await]:
+        //      BindingIdentifier[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        let saved_yield_context = self.in_yield_context();
+        let saved_await_context = self.in_await_context();
+
+        self.set_yield_context(flags & SignatureFlags::YIELD != 0);
+        self.set_await_context(flags & SignatureFlags::AWAIT != 0);
+
+        let parameters = if flags & SignatureFlags::JSDOC != 0 {
+            self.parse_delimited_list(
+                ParsingContext::JSDocParameters,
+                Self::parse_js_doc_parameter,
+                false,
+            )
+        } else if saved_await_context {
+            self.parse_delimited_list(
+                ParsingContext::Parameters,
+                Self::parse_parameter_in_outer_await_context,
+                false,
+            )
+        } else {
+            self.parse_delimited_list(ParsingContext::Parameters, Self::parse_parameter, false)
+        };
+
+        self.set_yield_context(saved_yield_context);
+        self.set_await_context(saved_await_context);
+
+        parameters
+    }
+
+    fn parse_parameters(&mut self, flags: u32) -> NodeArray {
+        // FormalParameters [Yield,Await]: (modified)
+        //      [empty]
+        //      FormalParameterList[?Yield,Await]
+        //
+        // FormalParameter[Yield,Await]: (modified)
+        //      BindingElement[?Yield,Await]
+        //
+        // BindingElement [Yield,Await]: (modified)
+        //      SingleNameBinding[?Yield,?Await]
+        //      BindingPattern[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        //
+        // SingleNameBinding [Yield,Await]:
+        //      BindingIdentifier[?Yield,?Await]Initializer [In, ?Yield,?Await] opt
+        if !self.parse_expected(SyntaxKind::OpenParenToken) {
+            return self.create_missing_list();
+        }
+
+        let parameters = self.parse_parameters_worker(flags);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        parameters
+    }
+}
+
+fn get_expected_comma_diagnostic(kind: ParsingContext) -> Option<IDiagnosticMessage<'static>> {
+    if kind == ParsingContext::EnumMembers {
+        Some(Diagnostics::An_enum_member_name_must_be_followed_by_a_or)
+    } else {
+        None
+    }
+}
+
+fn set_jsdoc_dot_pos(node: &mut Node, dot_pos: usize) {
+    match &mut node.data {
+        NodeData::Identifier { jsdoc_dot_pos, .. }
+        | NodeData::QualifiedName { jsdoc_dot_pos, .. } => {
+            *jsdoc_dot_pos = Some(dot_pos);
+        }
+        _ => {}
+    }
+}
+
+fn is_in_or_of_keyword(t: SyntaxKind) -> bool {
+    t == SyntaxKind::InKeyword || t == SyntaxKind::OfKeyword
+}
+
+impl Parser {
+    fn parse_type_member_semicolon(&mut self) {
+        // We allow type members to be separated by commas or (possibly ASI) semicolons.
+        // First check if it was a comma.  If so, we're done with the member.
+        if self.parse_optional(SyntaxKind::CommaToken) {
+            return;
+        }
+
+        // Didn't have a comma.  We must have a (possible ASI) semicolon.
+        self.parse_semicolon();
+    }
+
+    fn parse_signature_member(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        if kind == SyntaxKind::ConstructSignature {
+            self.parse_expected(SyntaxKind::NewKeyword);
+        }
+
+        let type_parameters = self.parse_type_parameters();
+        let parameters = self.parse_parameters(SignatureFlags::TYPE);
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, true);
+        self.parse_type_member_semicolon();
+        let node = Node::new(
+            kind,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                type_parameters,
+                parameters,
+                _type,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn is_index_signature(&mut self) -> bool {
+        self.token() == SyntaxKind::OpenBracketToken
+            && self.look_ahead(Self::is_unambiguously_index_signature)
+    }
+
+    fn is_unambiguously_index_signature(&mut self) -> bool {
+        // The only allowed sequence is:
+        //
+        //   [id:
+        //
+        // However, for error recovery, we also check the following cases:
+        //
+        //   [...
+        //   [id,
+        //   [id?,
+        //   [id?:
+        //   [id?]
+        //   [public id
+        //   [private id
+        //   [protected id
+        //   []
+        //
+        self.next_token();
+        if self.token() == SyntaxKind::DotDotDotToken
+            || self.token() == SyntaxKind::CloseBracketToken
+        {
+            return true;
+        }
+
+        if is_modifier_kind(self.token()) {
+            self.next_token();
+            if self.is_identifier() {
+                return true;
+            }
+        } else if !self.is_identifier() {
+            return false;
+        } else {
+            // Skip the identifier
+            self.next_token();
+        }
+
+        // A colon signifies a well formed indexer
+        // A comma should be a badly formed indexer because comma expressions are not allowed
+        // in computed properties.
+        if self.token() == SyntaxKind::ColonToken || self.token() == SyntaxKind::CommaToken {
+            return true;
+        }
+
+        // Question mark could be an indexer with an optional property,
+        // or it could be a conditional expression in a computed property.
+        if self.token() != SyntaxKind::QuestionToken {
+            return false;
+        }
+
+        // If any of the following tokens are after the question mark, it cannot
+        // be a conditional expression, so treat it as an indexer.
+        self.next_token();
+        self.token() == SyntaxKind::ColonToken
+            || self.token() == SyntaxKind::CommaToken
+            || self.token() == SyntaxKind::CloseBracketToken
+    }
+
+    fn parse_index_signature_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let parameters = self.parse_bracketed_list(
+            ParsingContext::Parameters,
+            Self::parse_parameter,
+            SyntaxKind::OpenBracketToken,
+            SyntaxKind::CloseBracketToken,
+        );
+        let _type = self.parse_type_annotation();
+        self.parse_type_member_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::IndexSignature,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                parameters,
+                _type,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_property_or_method_signature(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let name = self.parse_property_name();
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        let mut node;
+        if self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken {
+            // Method signatures don't exist in expression contexts.  So they have neither
+            // [Yield] nor [Await]
+            let type_parameters = self.parse_type_parameters();
+            let parameters = self.parse_parameters(SignatureFlags::TYPE);
+            let _type = self.parse_return_type(SyntaxKind::ColonToken, true);
+            node = Node::new(
+                SyntaxKind::MethodSignature,
+                NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                    name: Some(name),
+                    question_token,
+                    type_parameters,
+                    parameters,
+                    _type,
+                    ..SignatureDeclaration::default()
+                })),
+            );
+        } else {
+            let _type = self.parse_type_annotation();
+            // Although type literal properties cannot not have initializers, we attempt
+            // to parse an initializer so we can report in the checker that an interface
+            // property or type literal property cannot have an initializer.
+            let initializer = if self.token() == SyntaxKind::EqualsToken {
+                self.parse_initializer()
+            } else {
+                None
+            };
+            node = Node::new(
+                SyntaxKind::PropertySignature,
+                NodeData::VariableLike {
+                    name,
+                    question_token,
+                    exclamation_token: None,
+                    _type,
+                    initializer,
+                },
+            );
+        }
+        node.modifiers = modifiers;
+        self.parse_type_member_semicolon();
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn is_type_member_start(&mut self) -> bool {
+        // Return true if we have the start of a signature member
+        if self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+            || self.token() == SyntaxKind::GetKeyword
+            || self.token() == SyntaxKind::SetKeyword
+        {
+            return true;
+        }
+        let mut id_token = false;
+        // Eat up all modifiers, but hold on to the last one in case it is actually an identifier
+        while is_modifier_kind(self.token()) {
+            id_token = true;
+            self.next_token();
+        }
+        // Index signatures and computed property names are type members
+        if self.token() == SyntaxKind::OpenBracketToken {
+            return true;
+        }
+        // Try to get the first property-like token following all modifiers
+        if self.is_literal_property_name() {
+            id_token = true;
+            self.next_token();
+        }
+        // If we were able to get any potential identifier, check that it is
+        // the start of a member declaration
+        if id_token {
+            return self.token() == SyntaxKind::OpenParenToken
+                || self.token() == SyntaxKind::LessThanToken
+                || self.token() == SyntaxKind::QuestionToken
+                || self.token() == SyntaxKind::ColonToken
+                || self.token() == SyntaxKind::CommaToken
+                || self.can_parse_semicolon();
+        }
+        false
+    }
+
+    fn parse_type_member(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken {
+            return self.parse_signature_member(SyntaxKind::CallSignature);
+        }
+        if self.token() == SyntaxKind::NewKeyword
+            && self.look_ahead(Self::next_token_is_open_paren_or_less_than)
+        {
+            return self.parse_signature_member(SyntaxKind::ConstructSignature);
+        }
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let modifiers = self.parse_modifiers(false);
+        if self.parse_contextual_modifier(SyntaxKind::GetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                None,
+                modifiers,
+                SyntaxKind::GetAccessor,
+            );
+        }
+
+        if self.parse_contextual_modifier(SyntaxKind::SetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                None,
+                modifiers,
+                SyntaxKind::SetAccessor,
+            );
+        }
+
+        if self.is_index_signature() {
+            return self.parse_index_signature_declaration(pos, has_js_doc, None, modifiers);
+        }
+        self.parse_property_or_method_signature(pos, has_js_doc, modifiers)
+    }
+
+    fn next_token_is_open_paren_or_less_than(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::OpenParenToken || self.token() == SyntaxKind::LessThanToken
+    }
+
+    fn next_token_is_dot(&mut self) -> bool {
+        self.next_token() == SyntaxKind::DotToken
+    }
+
+    fn next_token_is_open_paren_or_less_than_or_dot(&mut self) -> bool {
+        matches!(
+            self.next_token(),
+            SyntaxKind::OpenParenToken | SyntaxKind::LessThanToken | SyntaxKind::DotToken
+        )
+    }
+
+    fn parse_type_literal(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let members = self.parse_object_type_members();
+        self.finish_node(
+            Node::new(SyntaxKind::TypeLiteral, NodeData::TypeLiteral { members }),
+            pos,
+        )
+    }
+
+    fn parse_object_type_members(&mut self) -> NodeArray {
+        if self.parse_expected(SyntaxKind::OpenBraceToken) {
+            let members = self.parse_list(ParsingContext::TypeMembers, Self::parse_type_member);
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+            members
+        } else {
+            self.create_missing_list()
+        }
+    }
+
+    fn is_start_of_mapped_type(&mut self) -> bool {
+        self.next_token();
+        if self.token() == SyntaxKind::PlusToken || self.token() == SyntaxKind::MinusToken {
+            return self.next_token() == SyntaxKind::ReadonlyKeyword;
+        }
+        if self.token() == SyntaxKind::ReadonlyKeyword {
+            self.next_token();
+        }
+        self.token() == SyntaxKind::OpenBracketToken
+            && self.next_token_is_identifier()
+            && self.next_token() == SyntaxKind::InKeyword
+    }
+
+    fn parse_mapped_type_parameter(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let name = self.parse_identifier_name(None);
+        self.parse_expected(SyntaxKind::InKeyword);
+        let _type = self.parse_type();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeParameter,
+                NodeData::TypeParameter {
+                    name,
+                    constraint: Some(_type),
+                    default: None,
+                    expression: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_mapped_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken);
+        let mut readonly_token = None;
+        if self.token() == SyntaxKind::ReadonlyKeyword
+            || self.token() == SyntaxKind::PlusToken
+            || self.token() == SyntaxKind::MinusToken
+        {
+            let token = self.parse_token_node();
+            readonly_token = Some(token);
+            if self.kind(token) != SyntaxKind::ReadonlyKeyword {
+                self.parse_expected(SyntaxKind::ReadonlyKeyword);
+            }
+        }
+        self.parse_expected(SyntaxKind::OpenBracketToken);
+        let type_parameter = self.parse_mapped_type_parameter();
+        let name_type = if self.parse_optional(SyntaxKind::AsKeyword) {
+            Some(self.parse_type())
+        } else {
+            None
+        };
+        self.parse_expected(SyntaxKind::CloseBracketToken);
+        let mut question_token = None;
+        if self.token() == SyntaxKind::QuestionToken
+            || self.token() == SyntaxKind::PlusToken
+            || self.token() == SyntaxKind::MinusToken
+        {
+            let token = self.parse_token_node();
+            question_token = Some(token);
+            if self.kind(token) != SyntaxKind::QuestionToken {
+                self.parse_expected(SyntaxKind::QuestionToken);
+            }
+        }
+        let _type = self.parse_type_annotation();
+        self.parse_semicolon();
+        self.parse_expected(SyntaxKind::CloseBraceToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::MappedType,
+                NodeData::MappedType {
+                    readonly_token,
+                    type_parameter,
+                    name_type,
+                    question_token,
+                    _type,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_tuple_element_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        if self.parse_optional(SyntaxKind::DotDotDotToken) {
+            let _type = self.parse_type();
+            return self.finish_node(
+                Node::new(SyntaxKind::RestType, NodeData::Type { _type: Some(_type) }),
+                pos,
+            );
+        }
+        let _type = self.parse_type();
+        let node = self.node(_type);
+        let is_optional_type = match (node.kind, &node.data) {
+            (SyntaxKind::JSDocNullableType, NodeData::Type { _type: Some(inner) }) => {
+                node.pos() == self.node(*inner).pos()
+            }
+            _ => false,
+        };
+        if is_optional_type {
+            // `T?` in a tuple is an optional element, with the range and flags of the nullable
+            // type it was parsed as.
+            self.node_mut(_type).kind = SyntaxKind::OptionalType;
+        }
+        _type
+    }
+
+    fn is_next_token_colon_or_question_colon(&mut self) -> bool {
+        self.next_token() == SyntaxKind::ColonToken
+            || (self.token() == SyntaxKind::QuestionToken
+                && self.next_token() == SyntaxKind::ColonToken)
+    }
+
+    fn is_tuple_element_name(&mut self) -> bool {
+        if self.token() == SyntaxKind::DotDotDotToken {
+            return token_is_identifier_or_keyword(self.next_token())
+                && self.is_next_token_colon_or_question_colon();
+        }
+        token_is_identifier_or_keyword(self.token()) && self.is_next_token_colon_or_question_colon()
+    }
+
+    fn parse_tuple_element_name_or_tuple_element_type(&mut self) -> NodeId {
+        if self.look_ahead(Self::is_tuple_element_name) {
+            let pos = self.get_node_pos();
+            let has_js_doc = self.has_preceding_js_doc_comment();
+            let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+            let name = self.parse_identifier_name(None);
+            let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+            self.parse_expected(SyntaxKind::ColonToken);
+            let _type = self.parse_tuple_element_type();
+            let node = self.finish_node(
+                Node::new(
+                    SyntaxKind::NamedTupleMember,
+                    NodeData::NamedTupleMember {
+                        dot_dot_dot_token,
+                        name,
+                        question_token,
+                        _type,
+                    },
+                ),
+                pos,
+            );
+            return self.with_js_doc(node, has_js_doc);
+        }
+        self.parse_tuple_element_type()
+    }
+
+    fn parse_tuple_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let elements = self.parse_bracketed_list(
+            ParsingContext::TupleElementTypes,
+            Self::parse_tuple_element_name_or_tuple_element_type,
+            SyntaxKind::OpenBracketToken,
+            SyntaxKind::CloseBracketToken,
+        );
+        self.finish_node(
+            Node::new(SyntaxKind::TupleType, NodeData::TupleType { elements }),
+            pos,
+        )
+    }
+
+    fn parse_parenthesized_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let _type = self.parse_type();
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ParenthesizedType,
+                NodeData::Type { _type: Some(_type) },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_modifiers_for_constructor_type(&mut self) -> Option<NodeArray> {
+        if self.token() == SyntaxKind::AbstractKeyword {
+            let pos = self.get_node_pos();
+            self.next_token();
+            let modifier =
+                self.finish_node(Node::new(SyntaxKind::AbstractKeyword, NodeData::Token), pos);
+            return Some(self.create_node_array(vec![modifier], pos, None, false));
+        }
+        None
+    }
+
+    fn parse_function_or_constructor_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let modifiers = self.parse_modifiers_for_constructor_type();
+        let is_constructor_type = self.parse_optional(SyntaxKind::NewKeyword);
+        let type_parameters = self.parse_type_parameters();
+        let parameters = self.parse_parameters(SignatureFlags::TYPE);
+        let _type = self.parse_return_type(SyntaxKind::EqualsGreaterThanToken, false);
+        let kind = if is_constructor_type {
+            SyntaxKind::ConstructorType
+        } else {
+            SyntaxKind::FunctionType
+        };
+        // Function types may not have modifiers, they are kept for the grammar error.
+        let mut node = Node::new(
+            kind,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                type_parameters,
+                parameters,
+                _type,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_keyword_and_no_dot(&mut self) -> Option<NodeId> {
+        let node = self.parse_token_node();
+        if self.token() == SyntaxKind::DotToken {
+            None
+        } else {
+            Some(node)
+        }
+    }
+
+    fn parse_literal_type_node(&mut self, negative: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        if negative {
+            self.next_token();
+        }
+        let mut expression = if self.token() == SyntaxKind::TrueKeyword
+            || self.token() == SyntaxKind::FalseKeyword
+            || self.token() == SyntaxKind::NullKeyword
+        {
+            self.parse_token_node()
+        } else {
+            let kind = self.token();
+            self.parse_literal_like_node(kind)
+        };
+        if negative {
+            expression = self.finish_node(
+                Node::new(
+                    SyntaxKind::PrefixUnaryExpression,
+                    NodeData::UnaryExpression {
+                        operator: SyntaxKind::MinusToken,
+                        operand: expression,
+                    },
+                ),
+                pos,
+            );
+        }
+        self.finish_node(
+            Node::new(
+                SyntaxKind::LiteralType,
+                NodeData::LiteralType {
+                    literal: expression,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn is_start_of_type_of_import_type(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ImportKeyword
+    }
+
+    fn parse_import_type(&mut self) -> NodeId {
+        self.source_flags |= NodeFlags::POSSIBLY_CONTAINS_DYNAMIC_IMPORT;
+        let pos = self.get_node_pos();
+        let is_type_of = self.parse_optional(SyntaxKind::TypeOfKeyword);
+        self.parse_expected(SyntaxKind::ImportKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let argument = self.parse_type();
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let qualifier = if self.parse_optional(SyntaxKind::DotToken) {
+            Some(self.parse_entity_name_of_type_reference())
+        } else {
+            None
+        };
+        let type_arguments = self.parse_type_arguments_of_type_reference();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ImportType,
+                NodeData::ImportType {
+                    is_type_of,
+                    argument,
+                    qualifier,
+                    type_arguments,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn next_token_is_numeric_or_big_int_literal(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::NumericLiteral || self.token() == SyntaxKind::BigIntLiteral
+    }
+
+    fn parse_non_array_type(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::UnknownKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BigIntKeyword
+            | SyntaxKind::SymbolKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::UndefinedKeyword
+            | SyntaxKind::NeverKeyword
+            | SyntaxKind::ObjectKeyword => {
+                // If these are followed by a dot, then parse these out as a dotted type reference instead.
+                match self.try_parse(Self::parse_keyword_and_no_dot) {
+                    Some(node) => node,
+                    None => self.parse_type_reference(),
+                }
+            }
+            SyntaxKind::AsteriskEqualsToken => {
+                // If there is '*=', treat it as * followed by postfix =
+                self.current_token = self.scanner.re_scan_asterisk_equals_token();
+                self.parse_js_doc_all_type()
+            }
+            SyntaxKind::AsteriskToken => self.parse_js_doc_all_type(),
+            SyntaxKind::QuestionQuestionToken => {
+                // If there is '??', treat it as prefix-'?' in JSDoc type.
+                self.current_token = self.scanner.re_scan_question_token();
+                self.parse_js_doc_unknown_or_nullable_type()
+            }
+            SyntaxKind::QuestionToken => self.parse_js_doc_unknown_or_nullable_type(),
+            SyntaxKind::FunctionKeyword => self.parse_js_doc_function_type(),
+            SyntaxKind::ExclamationToken => self.parse_js_doc_non_nullable_type(),
+            SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NullKeyword => self.parse_literal_type_node(false),
+            SyntaxKind::MinusToken => {
+                if self.look_ahead(Self::next_token_is_numeric_or_big_int_literal) {
+                    self.parse_literal_type_node(true)
+                } else {
+                    self.parse_type_reference()
+                }
+            }
+            SyntaxKind::VoidKeyword => self.parse_token_node(),
+            SyntaxKind::ThisKeyword => {
+                let this_keyword = self.parse_this_type_node();
+                if self.token() == SyntaxKind::IsKeyword && !self.scanner.has_preceding_line_break()
+                {
+                    self.parse_this_type_predicate(this_keyword)
+                } else {
+                    this_keyword
+                }
+            }
+            SyntaxKind::TypeOfKeyword => {
+                if self.look_ahead(Self::is_start_of_type_of_import_type) {
+                    self.parse_import_type()
+                } else {
+                    self.parse_type_query()
+                }
+            }
+            SyntaxKind::OpenBraceToken => {
+                if self.look_ahead(Self::is_start_of_mapped_type) {
+                    self.parse_mapped_type()
+                } else {
+                    self.parse_type_literal()
+                }
+            }
+            SyntaxKind::OpenBracketToken => self.parse_tuple_type(),
+            SyntaxKind::OpenParenToken => self.parse_parenthesized_type(),
+            SyntaxKind::ImportKeyword => self.parse_import_type(),
+            SyntaxKind::AssertsKeyword => {
+                if self.look_ahead(Self::next_token_is_identifier_or_keyword_on_same_line) {
+                    self.parse_asserts_type_predicate()
+                } else {
+                    self.parse_type_reference()
+                }
+            }
+            SyntaxKind::TemplateHead => self.parse_template_type(),
+            _ => self.parse_type_reference(),
+        }
+    }
+
+    fn is_start_of_type(&mut self, in_start_of_parameter: bool) -> bool {
+        match self.token() {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::UnknownKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BigIntKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::ReadonlyKeyword
+            | SyntaxKind::SymbolKeyword
+            | SyntaxKind::UniqueKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::UndefinedKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::NeverKeyword
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::OpenBracketToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::BarToken
+            | SyntaxKind::AmpersandToken
+            | SyntaxKind::NewKeyword
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::ObjectKeyword
+            | SyntaxKind::AsteriskToken
+            | SyntaxKind::QuestionToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DotDotDotToken
+            | SyntaxKind::InferKeyword
+            | SyntaxKind::ImportKeyword
+            | SyntaxKind::AssertsKeyword
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::TemplateHead => true,
+            SyntaxKind::FunctionKeyword => !in_start_of_parameter,
+            SyntaxKind::MinusToken => {
+                !in_start_of_parameter
+                    && self.look_ahead(Self::next_token_is_numeric_or_big_int_literal)
+            }
+            SyntaxKind::OpenParenToken => {
+                // Only consider '(' the start of a type if followed by ')', '...', an identifier, a modifier,
+                // or something that starts a type. We don't want to consider things like '(1)' a type.
+                !in_start_of_parameter
+                    && self.look_ahead(Self::is_start_of_parenthesized_or_function_type)
+            }
+            _ => self.is_identifier(),
+        }
+    }
+
+    fn is_start_of_parenthesized_or_function_type(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::CloseParenToken
+            || self.is_start_of_parameter(false)
+            || self.is_start_of_type(false)
+    }
+
+    fn parse_postfix_type_or_higher(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let mut _type = self.parse_non_array_type();
+        while !self.scanner.has_preceding_line_break() {
+            match self.token() {
+                SyntaxKind::ExclamationToken => {
+                    self.next_token();
+                    _type = self.finish_node(
+                        Node::new(
+                            SyntaxKind::JSDocNonNullableType,
+                            NodeData::Type { _type: Some(_type) },
+                        ),
+                        pos,
+                    );
+                }
+                SyntaxKind::QuestionToken => {
+                    // If next token is start of a type we have a conditional type
+                    if self.look_ahead(Self::next_token_is_start_of_type) {
+                        return _type;
+                    }
+                    self.next_token();
+                    _type = self.finish_node(
+                        Node::new(
+                            SyntaxKind::JSDocNullableType,
+                            NodeData::Type { _type: Some(_type) },
+                        ),
+                        pos,
+                    );
+                }
+                SyntaxKind::OpenBracketToken => {
+                    self.parse_expected(SyntaxKind::OpenBracketToken);
+                    if self.is_start_of_type(false) {
+                        let index_type = self.parse_type();
+                        self.parse_expected(SyntaxKind::CloseBracketToken);
+                        _type = self.finish_node(
+                            Node::new(
+                                SyntaxKind::IndexedAccessType,
+                                NodeData::IndexedAccessType {
+                                    object_type: _type,
+                                    index_type,
+                                },
+                            ),
+                            pos,
+                        );
+                    } else {
+                        self.parse_expected(SyntaxKind::CloseBracketToken);
+                        _type = self.finish_node(
+                            Node::new(
+                                SyntaxKind::ArrayType,
+                                NodeData::ArrayType {
+                                    element_type: _type,
+                                },
+                            ),
+                            pos,
+                        );
+                    }
+                }
+                _ => return _type,
+            }
+        }
+        _type
+    }
+
+    fn parse_type_operator(&mut self, operator: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(operator);
+        let _type = self.parse_type_operator_or_higher();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeOperator,
+                NodeData::TypeOperator { operator, _type },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_type_parameter_of_infer_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let name = self.parse_identifier(None, None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeParameter,
+                NodeData::TypeParameter {
+                    name,
+                    constraint: None,
+                    default: None,
+                    expression: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_infer_type(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::InferKeyword);
+        let type_parameter = self.parse_type_parameter_of_infer_type();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::InferType,
+                NodeData::InferType { type_parameter },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_type_operator_or_higher(&mut self) -> NodeId {
+        let operator = self.token();
+        match operator {
+            SyntaxKind::KeyOfKeyword | SyntaxKind::UniqueKeyword | SyntaxKind::ReadonlyKeyword => {
+                self.parse_type_operator(operator)
+            }
+            SyntaxKind::InferKeyword => self.parse_infer_type(),
+            _ => self.parse_postfix_type_or_higher(),
+        }
+    }
+
+    fn parse_function_or_constructor_type_to_error(
+        &mut self,
+        is_in_union_type: bool,
+    ) -> Option<NodeId> {
+        // the function type and constructor type shorthand notation
+        // are not allowed directly in unions and intersections, but we'll
+        // try to parse them gracefully and issue a helpful message.
+        if self.is_start_of_function_type_or_constructor_type() {
+            let _type = self.parse_function_or_constructor_type();
+            let diagnostic = if self.kind(_type) == SyntaxKind::FunctionType {
+                if is_in_union_type {
+                    &Diagnostics::Function_type_notation_must_be_parenthesized_when_used_in_a_union_type
+                } else {
+                    &Diagnostics::Function_type_notation_must_be_parenthesized_when_used_in_an_intersection_type
+                }
+            } else if is_in_union_type {
+                &Diagnostics::Constructor_type_notation_must_be_parenthesized_when_used_in_a_union_type
+            } else {
+                &Diagnostics::Constructor_type_notation_must_be_parenthesized_when_used_in_an_intersection_type
+            };
+            let range = self.node(_type).range;
+            self.parse_error_at_range(range, diagnostic, &[]);
+            return Some(_type);
+        }
+        None
+    }
+
+    fn parse_union_or_intersection_type(
+        &mut self,
+        operator: SyntaxKind,
+        parse_constituent_type: fn(&mut Self) -> NodeId,
+    ) -> NodeId {
+        let pos = self.get_node_pos();
+        let is_union_type = operator == SyntaxKind::BarToken;
+        let has_leading_operator = self.parse_optional(operator);
+        let mut _type = match has_leading_operator
+            .then(|| self.parse_function_or_constructor_type_to_error(is_union_type))
+            .flatten()
+        {
+            Some(_type) => _type,
+            None => parse_constituent_type(self),
+        };
+        if self.token() == operator || has_leading_operator {
+            let mut types = vec![_type];
+            while self.parse_optional(operator) {
+                let constituent =
+                    match self.parse_function_or_constructor_type_to_error(is_union_type) {
+                        Some(constituent) => constituent,
+                        None => parse_constituent_type(self),
+                    };
+                types.push(constituent);
+            }
+            let types = self.create_node_array(types, pos, None, false);
+            let kind = if is_union_type {
+                SyntaxKind::UnionType
+            } else {
+                SyntaxKind::IntersectionType
+            };
+            _type = self.finish_node(
+                Node::new(kind, NodeData::UnionOrIntersectionType { types }),
+                pos,
+            );
+        }
+        _type
+    }
+
+    fn parse_intersection_type_or_higher(&mut self) -> NodeId {
+        self.parse_union_or_intersection_type(
+            SyntaxKind::AmpersandToken,
+            Self::parse_type_operator_or_higher,
+        )
+    }
+
+    fn parse_union_type_or_higher(&mut self) -> NodeId {
+        self.parse_union_or_intersection_type(
+            SyntaxKind::BarToken,
+            Self::parse_intersection_type_or_higher,
+        )
+    }
+
+    fn next_token_is_new_keyword(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::NewKeyword
+    }
+
+    fn is_start_of_function_type_or_constructor_type(&mut self) -> bool {
+        if self.token() == SyntaxKind::LessThanToken {
+            return true;
+        }
+        if self.token() == SyntaxKind::OpenParenToken
+            && self.look_ahead(Self::is_unambiguously_start_of_function_type)
+        {
+            return true;
+        }
+        self.token() == SyntaxKind::NewKeyword
+            || (self.token() == SyntaxKind::AbstractKeyword
+                && self.look_ahead(Self::next_token_is_new_keyword))
+    }
+
+    fn skip_parameter_start(&mut self) -> bool {
+        if is_modifier_kind(self.token()) {
+            // Skip modifiers
+            self.parse_modifiers(false);
+        }
+        if self.is_identifier() || self.token() == SyntaxKind::ThisKeyword {
+            self.next_token();
+            return true;
+        }
+        if self.token() == SyntaxKind::OpenBracketToken
+            || self.token() == SyntaxKind::OpenBraceToken
+        {
+            // Return true if we can parse an array or object binding pattern with no errors
+            let previous_error_count = self.parse_diagnostics.borrow().len();
+            self.parse_identifier_or_pattern(None);
+            return previous_error_count == self.parse_diagnostics.borrow().len();
+        }
+        false
+    }
+
+    fn is_unambiguously_start_of_function_type(&mut self) -> bool {
+        self.next_token();
+        if self.token() == SyntaxKind::CloseParenToken || self.token() == SyntaxKind::DotDotDotToken
+        {
+            // ( )
+            // ( ...
+            return true;
+        }
+        if self.skip_parameter_start() {
+            // We successfully skipped modifiers (if any) and an identifier or binding pattern,
+            // now see if we have something that indicates a parameter declaration
+            if self.token() == SyntaxKind::ColonToken
+                || self.token() == SyntaxKind::CommaToken
+                || self.token() == SyntaxKind::QuestionToken
+                || self.token() == SyntaxKind::EqualsToken
+            {
+                // ( xxx :
+                // ( xxx ,
+                // ( xxx ?
+                // ( xxx =
+                return true;
+            }
+            if self.token() == SyntaxKind::CloseParenToken {
+                self.next_token();
+                if self.token() == SyntaxKind::EqualsGreaterThanToken {
+                    // ( xxx ) =>
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn parse_type_or_type_predicate(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let type_predicate_variable = if self.is_identifier() {
+            self.try_parse(Self::parse_type_predicate_prefix)
+        } else {
+            None
+        };
+        let _type = self.parse_type();
+        match type_predicate_variable {
+            Some(parameter_name) => self.finish_node(
+                Node::new(
+                    SyntaxKind::TypePredicate,
+                    NodeData::TypePredicate {
+                        asserts_modifier: None,
+                        parameter_name,
+                        _type: Some(_type),
+                    },
+                ),
+                pos,
+            ),
+            None => _type,
+        }
+    }
+
+    fn parse_type_predicate_prefix(&mut self) -> Option<NodeId> {
+        let id = self.parse_identifier(None, None);
+        if self.token() == SyntaxKind::IsKeyword && !self.scanner.has_preceding_line_break() {
+            self.next_token();
+            return Some(id);
+        }
+        None
+    }
+
+    fn parse_asserts_type_predicate(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let asserts_modifier = self.parse_expected_token(SyntaxKind::AssertsKeyword, None, None);
+        let parameter_name = if self.token() == SyntaxKind::ThisKeyword {
+            self.parse_this_type_node()
+        } else {
+            self.parse_identifier(None, None)
+        };
+        let _type = if self.parse_optional(SyntaxKind::IsKeyword) {
+            Some(self.parse_type())
+        } else {
+            None
+        };
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypePredicate,
+                NodeData::TypePredicate {
+                    asserts_modifier: Some(asserts_modifier),
+                    parameter_name,
+                    _type,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_type(&mut self) -> NodeId {
+        // The rules about 'yield' only apply to actual code/expression contexts.  They don't
+        // apply to 'type' contexts.  So we disable these parameters here before moving on.
+        self.do_outside_of_context(NodeFlags::TYPE_EXCLUDES_FLAGS, |p| {
+            p.parse_type_worker(false)
+        })
+    }
+
+    fn parse_type_worker(&mut self, no_conditional_types: bool) -> NodeId {
+        if self.is_start_of_function_type_or_constructor_type() {
+            return self.parse_function_or_constructor_type();
+        }
+        let pos = self.get_node_pos();
+        let _type = self.parse_union_type_or_higher();
+        if !no_conditional_types
+            && !self.scanner.has_preceding_line_break()
+            && self.parse_optional(SyntaxKind::ExtendsKeyword)
+        {
+            // The type following 'extends' is not permitted to be another conditional type
+            let extends_type = self.parse_type_worker(true);
+            self.parse_expected(SyntaxKind::QuestionToken);
+            let true_type = self.parse_type_worker(false);
+            self.parse_expected(SyntaxKind::ColonToken);
+            let false_type = self.parse_type_worker(false);
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::ConditionalType,
+                    NodeData::ConditionalType {
+                        check_type: _type,
+                        extends_type,
+                        true_type,
+                        false_type,
+                    },
+                ),
+                pos,
+            );
+        }
+        _type
+    }
+
+    fn parse_type_annotation(&mut self) -> Option<NodeId> {
+        if self.parse_optional(SyntaxKind::ColonToken) {
+            Some(self.parse_type())
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tristate {
+    False,
+    True,
+    Unknown,
+}
+
+impl Parser {
+    // EXPRESSIONS
+    fn is_start_of_left_hand_side_expression(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::TemplateHead
+            | SyntaxKind::OpenParenToken
+            | SyntaxKind::OpenBracketToken
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::ClassKeyword
+            | SyntaxKind::NewKeyword
+            | SyntaxKind::SlashToken
+            | SyntaxKind::SlashEqualsToken
+            | SyntaxKind::Identifier => true,
+            SyntaxKind::ImportKeyword => {
+                self.look_ahead(Self::next_token_is_open_paren_or_less_than_or_dot)
+            }
+            _ => self.is_identifier(),
+        }
+    }
+
+    fn is_start_of_expression(&mut self) -> bool {
+        if self.is_start_of_left_hand_side_expression() {
+            return true;
+        }
+
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DeleteKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::PlusPlusToken
+            | SyntaxKind::MinusMinusToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::AwaitKeyword
+            | SyntaxKind::YieldKeyword
+            | SyntaxKind::PrivateIdentifier => {
+                // Yield/await always starts an expression.  Either it is an identifier (in which case
+                // it is definitely an expression).  Or it's a keyword (either because we're in
+                // a generator or async function, or in strict mode (or both)) and it started a yield or await expression.
+                true
+            }
+            _ => {
+                // Error tolerance.  If we see the start of some binary operator, we consider
+                // that the start of an expression.  That way we'll parse out a missing identifier,
+                // give a good message about an identifier being missing, and then consume the
+                // rest of the binary expression.
+                if self.is_binary_operator() {
+                    return true;
+                }
+
+                self.is_identifier()
+            }
+        }
+    }
+
+    fn is_start_of_expression_statement(&mut self) -> bool {
+        // As per the grammar, none of '{' or 'function' or 'class' can start an expression statement.
+        self.token() != SyntaxKind::OpenBraceToken
+            && self.token() != SyntaxKind::FunctionKeyword
+            && self.token() != SyntaxKind::ClassKeyword
+            && self.token() != SyntaxKind::AtToken
+            && self.is_start_of_expression()
+    }
+
+    fn parse_expression(&mut self) -> NodeId {
+        // Expression[in]:
+        //      AssignmentExpression[in]
+        //      Expression[in] , AssignmentExpression[in]
+
+        // clear the decorator context when parsing Expression, as it should be unambiguous when parsing a decorator
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_decorator_context(false);
+        }
+
+        let pos = self.get_node_pos();
+        let mut expr = self.parse_assignment_expression_or_higher();
+        while let Some(operator_token) = self.parse_optional_token(SyntaxKind::CommaToken) {
+            let right = self.parse_assignment_expression_or_higher();
+            expr = self.make_binary_expression(expr, operator_token, right, pos);
+        }
+
+        if save_decorator_context {
+            self.set_decorator_context(true);
+        }
+        expr
+    }
+
+    fn parse_initializer(&mut self) -> Option<NodeId> {
+        if self.parse_optional(SyntaxKind::EqualsToken) {
+            Some(self.parse_assignment_expression_or_higher())
+        } else {
+            None
+        }
+    }
+
+    fn parse_assignment_expression_or_higher(&mut self) -> NodeId {
+        //  AssignmentExpression[in,yield]:
+        //      1) ConditionalExpression[?in,?yield]
+        //      2) LeftHandSideExpression = AssignmentExpression[?in,?yield]
+        //      3) LeftHandSideExpression AssignmentOperator AssignmentExpression[?in,?yield]
+        //      4) ArrowFunctionExpression[?in,?yield]
+        //      5) AsyncArrowFunctionExpression[in,yield,await]
+        //      6) [+Yield] YieldExpression[?In]
+        //
+        // Note: for ease of implementation we treat productions '2' and '3' as the same thing.
+        // (i.e. they're both BinaryExpressions with an assignment operator in it).
+
+        // First, do the simple check if we have a YieldExpression (production '6').
+        if self.is_yield_expression() {
+            return self.parse_yield_expression();
+        }
+
+        // Then, check if we have an arrow function (production '4' and '5') that starts with a parenthesized
+        // parameter list or is an async arrow function.
+        // AsyncArrowFunctionExpression:
+        //      1) async[no LineTerminator here]AsyncArrowBindingIdentifier[?Yield][no LineTerminator here]=>AsyncConciseBody[?In]
+        //      2) CoverCallExpressionAndAsyncArrowHead[?Yield, ?Await][no LineTerminator here]=>AsyncConciseBody[?In]
+        // Production (1) of AsyncArrowFunctionExpression is parsed in "tryParseAsyncSimpleArrowFunctionExpression".
+        // And production (2) is parsed in "tryParseParenthesizedArrowFunctionExpression".
+        //
+        // If we do successfully parse arrow-function, we must *not* recurse for productions 1, 2 or 3. An ArrowFunction is
+        // not a LeftHandSideExpression, nor does it start a ConditionalExpression.  So we are done
+        // with AssignmentExpression if we see one.
+        if let Some(arrow_expression) = self
+            .try_parse_parenthesized_arrow_function_expression()
+            .or_else(|| self.try_parse_async_simple_arrow_function_expression())
+        {
+            return arrow_expression;
+        }
+
+        // Now try to see if we're in production '1', '2' or '3'.  A conditional expression can
+        // start with a LogicalOrExpression, while the assignment productions can only start with
+        // LeftHandSideExpressions.
+        //
+        // So, first, we try to just parse out a BinaryExpression.  If we get something that is a
+        // LeftHandSide or higher, then we can try to parse out the assignment expression part.
+        // Otherwise, we try to parse out the conditional expression bit.  We want to allow any
+        // binary expression here, so we pass in the 'lowest' precedence here so that it matches
+        // and consumes anything.
+        let pos = self.get_node_pos();
+        let expr = self.parse_binary_expression_or_higher(OperatorPrecedence::LOWEST);
+
+        // To avoid a look-ahead, we did not handle the case of an arrow function with a single un-parenthesized
+        // parameter ('x => ...') above. We handle it here by checking if the parsed expression was a single
+        // identifier and the current token is an arrow.
+        if self.kind(expr) == SyntaxKind::Identifier
+            && self.token() == SyntaxKind::EqualsGreaterThanToken
+        {
+            return self.parse_simple_arrow_function_expression(pos, expr, None);
+        }
+
+        // Now see if we might be in cases '2' or '3'.
+        // If the expression was a LHS expression, and we have an assignment operator, then
+        // we're in '2' or '3'. Consume the assignment and return.
+        //
+        // Note: we call reScanGreaterToken so that we get an appropriately merged token
+        // for cases like `> > =` becoming `>>=`
+        if is_left_hand_side_expression_kind(self.kind(expr))
+            && is_assignment_operator(self.re_scan_greater_token())
+        {
+            let operator_token = self.parse_token_node();
+            let right = self.parse_assignment_expression_or_higher();
+            return self.make_binary_expression(expr, operator_token, right, pos);
+        }
+
+        // It wasn't an assignment or a lambda.  This is a conditional expression:
+        self.parse_conditional_expression_rest(expr, pos)
+    }
+
+    fn is_yield_expression(&mut self) -> bool {
+        if self.token() == SyntaxKind::YieldKeyword {
+            // If we have a 'yield' keyword, and this is a context where yield expressions are
+            // allowed, then definitely parse out a yield expression.
+            if self.in_yield_context() {
+                return true;
+            }
+
+            // We're in a context where 'yield expr' is not allowed.  However, if we can
+            // definitely tell that the user was trying to parse a 'yield expr' and not
+            // just a normal expr that start with a 'yield' identifier, then parse out
+            // a 'yield expr'.  We can then report an error later that they are only
+            // allowed in generator expressions.
+            //
+            // for example, if we see 'yield(foo)', then we'll have to treat that as an
+            // invocation expression of something called 'yield'.  However, if we have
+            // 'yield foo' then that is not legal as a normal expression, so we can
+            // definitely recognize this as a yield expression.
+            //
+            // for now we just check if the next token is an identifier.  More heuristics
+            // can be added here later as necessary.  We just need to make sure that we
+            // don't accidentally consume something legal.
+            return self
+                .look_ahead(Self::next_token_is_identifier_or_keyword_or_literal_on_same_line);
+        }
+
+        false
+    }
+
+    fn next_token_is_identifier_on_same_line(&mut self) -> bool {
+        self.next_token();
+        !self.scanner.has_preceding_line_break() && self.is_identifier()
+    }
+
+    fn parse_yield_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+
+        // YieldExpression[In] :
+        //      yield
+        //      yield [no LineTerminator here] [Lexical goal InputElementRegExp]AssignmentExpression[?In, Yield]
+        //      yield [no LineTerminator here] * [Lexical goal InputElementRegExp]AssignmentExpression[?In, Yield]
+        self.next_token();
+
+        if !self.scanner.has_preceding_line_break()
+            && (self.token() == SyntaxKind::AsteriskToken || self.is_start_of_expression())
+        {
+            let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+            let expression = self.parse_assignment_expression_or_higher();
+            self.finish_node(
+                Node::new(
+                    SyntaxKind::YieldExpression,
+                    NodeData::YieldExpression {
+                        asterisk_token,
+                        expression: Some(expression),
+                    },
+                ),
+                pos,
+            )
+        } else {
+            // if the next token is not on the same line as yield.  or we don't have an '*' or
+            // the start of an expression, then this is just a simple "yield" expression.
+            self.finish_node(
+                Node::new(
+                    SyntaxKind::YieldExpression,
+                    NodeData::YieldExpression {
+                        asterisk_token: None,
+                        expression: None,
+                    },
+                ),
+                pos,
+            )
+        }
+    }
+
+    fn parse_simple_arrow_function_expression(
+        &mut self,
+        pos: usize,
+        identifier: NodeId,
+        async_modifier: Option<NodeArray>,
+    ) -> NodeId {
+        debug_assert_eq!(
+            self.token(),
+            SyntaxKind::EqualsGreaterThanToken,
+            "parseSimpleArrowFunctionExpression should only have been called if we had a =>"
+        );
+        let parameter = Node::new(
+            SyntaxKind::Parameter,
+            NodeData::Parameter {
+                dot_dot_dot_token: None,
+                name: Some(identifier),
+                question_token: None,
+                _type: None,
+                initializer: None,
+            },
+        );
+        let identifier_pos = self.node(identifier).pos();
+        let parameter = self.finish_node(parameter, identifier_pos);
+
+        let parameter_range = self.node(parameter).range;
+        let parameters = self.create_node_array(
+            vec![parameter],
+            parameter_range.pos,
+            Some(parameter_range.end),
+            false,
+        );
+        let equals_greater_than_token =
+            self.parse_expected_token(SyntaxKind::EqualsGreaterThanToken, None, None);
+        let body = self.parse_arrow_function_expression_body(async_modifier.is_some());
+        let mut node = Node::new(
+            SyntaxKind::ArrowFunction,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                parameters,
+                equals_greater_than_token: Some(equals_greater_than_token),
+                body: Some(body),
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.modifiers = async_modifier;
+        let node = self.finish_node(node, pos);
+        self.add_js_doc_comment(node)
+    }
+
+    fn try_parse_parenthesized_arrow_function_expression(&mut self) -> Option<NodeId> {
+        let tri_state = self.is_parenthesized_arrow_function_expression();
+        if tri_state == Tristate::False {
+            // It's definitely not a parenthesized arrow function expression.
+            return None;
+        }
+
+        // If we definitely have an arrow function, then we can just parse one, not requiring a
+        // following => or { token. Otherwise, we *might* have an arrow function.  Try to parse
+        // it out, but don't allow any ambiguity, and return 'undefined' if this could be an
+        // expression instead.
+        if tri_state == Tristate::True {
+            self.parse_parenthesized_arrow_function_expression(true)
+        } else {
+            self.try_parse(Self::parse_possible_parenthesized_arrow_function_expression)
+        }
+    }
+
+    //  True        -> We definitely expect a parenthesized arrow function here.
+    //  False       -> There *cannot* be a parenthesized arrow function here.
+    //  Unknown     -> There *might* be a parenthesized arrow function here.
+    //                 Speculatively look ahead to be sure, and rollback if not.
+    fn is_parenthesized_arrow_function_expression(&mut self) -> Tristate {
+        if self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+            || self.token() == SyntaxKind::AsyncKeyword
+        {
+            return self.look_ahead(Self::is_parenthesized_arrow_function_expression_worker);
+        }
+
+        if self.token() == SyntaxKind::EqualsGreaterThanToken {
+            // ERROR RECOVERY TWEAK:
+            // If we see a standalone => try to parse it as an arrow function expression as that's
+            // likely what the user intended to write.
+            return Tristate::True;
+        }
+        // Definitely not a parenthesized arrow function.
+        Tristate::False
+    }
+
+    fn is_parenthesized_arrow_function_expression_worker(&mut self) -> Tristate {
+        if self.token() == SyntaxKind::AsyncKeyword {
+            self.next_token();
+            if self.scanner.has_preceding_line_break() {
+                return Tristate::False;
+            }
+            if self.token() != SyntaxKind::OpenParenToken
+                && self.token() != SyntaxKind::LessThanToken
+            {
+                return Tristate::False;
+            }
+        }
+
+        let first = self.token();
+        let second = self.next_token();
+
+        if first == SyntaxKind::OpenParenToken {
+            if second == SyntaxKind::CloseParenToken {
+                // Simple cases: "() =>", "(): ", and "() {".
+                // This is an arrow function with no parameters.
+                // The last one is not actually an arrow function,
+                // but this is probably what the user intended.
+                let third = self.next_token();
+                return match third {
+                    SyntaxKind::EqualsGreaterThanToken
+                    | SyntaxKind::ColonToken
+                    | SyntaxKind::OpenBraceToken => Tristate::True,
+                    _ => Tristate::False,
+                };
+            }
+
+            // If encounter "([" or "({", this could be the start of a binding pattern.
+            // Examples:
+            //      ([ x ]) => { }
+            //      ({ x }) => { }
+            //      ([ x ])
+            //      ({ x })
+            if second == SyntaxKind::OpenBracketToken || second == SyntaxKind::OpenBraceToken {
+                return Tristate::Unknown;
+            }
+
+            // Simple case: "(..."
+            // This is an arrow function with a rest parameter.
+            if second == SyntaxKind::DotDotDotToken {
+                return Tristate::True;
+            }
+
+            // Check for "(xxx yyy", where xxx is a modifier and yyy is an identifier. This
+            // isn't actually allowed, but we want to treat it as a lambda so we can provide
+            // a good error message.
+            if is_modifier_kind(second)
+                && second != SyntaxKind::AsyncKeyword
+                && self.look_ahead(Self::next_token_is_identifier)
+            {
+                return Tristate::True;
+            }
+
+            // If we had "(" followed by something that's not an identifier,
+            // then this definitely doesn't look like a lambda.  "this" is not
+            // valid, but we want to parse it and then give a semantic error.
+            if !self.is_identifier() && second != SyntaxKind::ThisKeyword {
+                return Tristate::False;
+            }
+
+            match self.next_token() {
+                SyntaxKind::ColonToken => {
+                    // If we have something like "(a:", then we must have a
+                    // type-annotated parameter in an arrow function expression.
+                    Tristate::True
+                }
+                SyntaxKind::QuestionToken => {
+                    self.next_token();
+                    // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
+                    if self.token() == SyntaxKind::ColonToken
+                        || self.token() == SyntaxKind::CommaToken
+                        || self.token() == SyntaxKind::EqualsToken
+                        || self.token() == SyntaxKind::CloseParenToken
+                    {
+                        return Tristate::True;
+                    }
+                    // Otherwise it is definitely not a lambda.
+                    Tristate::False
+                }
+                SyntaxKind::CommaToken | SyntaxKind::EqualsToken | SyntaxKind::CloseParenToken => {
+                    // If we have "(a," or "(a=" or "(a)" this *could* be an arrow function
+                    Tristate::Unknown
+                }
+                // It is definitely not an arrow function
+                _ => Tristate::False,
+            }
+        } else {
+            debug_assert_eq!(first, SyntaxKind::LessThanToken);
+
+            // If we have "<" not followed by an identifier,
+            // then this definitely is not an arrow function.
+            if !self.is_identifier() {
+                return Tristate::False;
+            }
+
+            // JSX overrides
+            if self.language_variant == LanguageVariant::JSX {
+                let is_arrow_function_in_jsx = self.look_ahead(|p| {
+                    let third = p.next_token();
+                    if third == SyntaxKind::ExtendsKeyword {
+                        let fourth = p.next_token();
+                        !matches!(
+                            fourth,
+                            SyntaxKind::EqualsToken | SyntaxKind::GreaterThanToken
+                        )
+                    } else {
+                        third == SyntaxKind::CommaToken
+                    }
+                });
+
+                if is_arrow_function_in_jsx {
+                    return Tristate::True;
+                }
+
+                return Tristate::False;
+            }
+
+            // This *could* be a parenthesized arrow function.
+            Tristate::Unknown
+        }
+    }
+
+    fn parse_possible_parenthesized_arrow_function_expression(&mut self) -> Option<NodeId> {
+        let token_pos = self.scanner.get_token_pos();
+        if self.not_parenthesized_arrow.contains(&token_pos) {
+            return None;
+        }
+
+        let result = self.parse_parenthesized_arrow_function_expression(false);
+        if result.is_none() {
+            self.not_parenthesized_arrow.insert(token_pos);
+        }
+
+        result
+    }
+
+    fn try_parse_async_simple_arrow_function_expression(&mut self) -> Option<NodeId> {
+        // We do a check here so that we won't be doing unnecessarily call to "lookAhead"
+        if self.token() == SyntaxKind::AsyncKeyword
+            && self.look_ahead(Self::is_un_parenthesized_async_arrow_function_worker)
+                == Tristate::True
+        {
+            let pos = self.get_node_pos();
+            let async_modifier = self.parse_modifiers_for_arrow_function();
+            let expr = self.parse_binary_expression_or_higher(OperatorPrecedence::LOWEST);
+            return Some(self.parse_simple_arrow_function_expression(pos, expr, async_modifier));
+        }
+        None
+    }
+
+    fn is_un_parenthesized_async_arrow_function_worker(&mut self) -> Tristate {
+        // AsyncArrowFunctionExpression:
+        //      1) async[no LineTerminator here]AsyncArrowBindingIdentifier[?Yield][no LineTerminator here]=>AsyncConciseBody[?In]
+        //      2) CoverCallExpressionAndAsyncArrowHead[?Yield, ?Await][no LineTerminator here]=>AsyncConciseBody[?In]
+        if self.token() == SyntaxKind::AsyncKeyword {
+            self.next_token();
+            // If the "async" is followed by "=>" token then it is not a beginning of an async arrow-function
+            // but instead a simple arrow-function which will be parsed inside "parseAssignmentExpressionOrHigher"
+            if self.scanner.has_preceding_line_break()
+                || self.token() == SyntaxKind::EqualsGreaterThanToken
+            {
+                return Tristate::False;
+            }
+            // Check for un-parenthesized AsyncArrowFunction
+            let expr = self.parse_binary_expression_or_higher(OperatorPrecedence::LOWEST);
+            if !self.scanner.has_preceding_line_break()
+                && self.kind(expr) == SyntaxKind::Identifier
+                && self.token() == SyntaxKind::EqualsGreaterThanToken
+            {
+                return Tristate::True;
+            }
+        }
+
+        Tristate::False
+    }
+
+    fn parse_parenthesized_arrow_function_expression(
+        &mut self,
+        allow_ambiguity: bool,
+    ) -> Option<NodeId> {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let modifiers = self.parse_modifiers_for_arrow_function();
+        let is_async = self.has_async_modifier(&modifiers);
+        let signature_flags = if is_async {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        // Arrow functions are never generators.
+        //
+        // If we're speculatively parsing a signature for a parenthesized arrow function, then
+        // we have to have a complete parameter list.  Otherwise we might see something like
+        // a => (b => c)
+        // And think that "(b =>" was actually a parenthesized arrow function with a missing
+        // close paren.
+        let type_parameters = self.parse_type_parameters();
+
+        let parameters;
+        if !self.parse_expected(SyntaxKind::OpenParenToken) {
+            if !allow_ambiguity {
+                return None;
+            }
+            parameters = self.create_missing_list();
+        } else {
+            parameters = self.parse_parameters_worker(signature_flags);
+            if !self.parse_expected(SyntaxKind::CloseParenToken) && !allow_ambiguity {
+                return None;
+            }
+        }
+
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+        if let Some(_type) = _type {
+            if !allow_ambiguity && self.type_has_arrow_function_blocking_parse_error(_type) {
+                return None;
+            }
+        }
+
+        // Parsing a signature isn't enough.
+        // Parenthesized arrow signatures often look like other valid expressions.
+        // For instance:
+        //  - "(x = 10)" is an assignment expression parsed as a signature with a default parameter value.
+        //  - "(x,y)" is a comma expression parsed as a signature with two parameters.
+        //  - "a ? (b): c" will have "(b):" parsed as a signature with a return type annotation.
+        //  - "a ? (b): function() {}" will too, since function() is a valid JSDoc function type.
+        //
+        // So we need just a bit of lookahead to ensure that it can only be a signature.
+        let has_js_doc_function_type =
+            _type.is_some_and(|_type| self.kind(_type) == SyntaxKind::JSDocFunctionType);
+        if !allow_ambiguity
+            && self.token() != SyntaxKind::EqualsGreaterThanToken
+            && (has_js_doc_function_type || self.token() != SyntaxKind::OpenBraceToken)
+        {
+            // Returning undefined here will cause our caller to rewind to where we started from.
+            return None;
+        }
+
+        // If we have an arrow, then try to parse the body. Even if not, try to parse if we
+        // have an opening brace, just in case we're in an error state.
+        let last_token = self.token();
+        let equals_greater_than_token =
+            self.parse_expected_token(SyntaxKind::EqualsGreaterThanToken, None, None);
+        let body = if last_token == SyntaxKind::EqualsGreaterThanToken
+            || last_token == SyntaxKind::OpenBraceToken
+        {
+            self.parse_arrow_function_expression_body(is_async)
+        } else {
+            self.parse_identifier(None, None)
+        };
+
+        let mut node = Node::new(
+            SyntaxKind::ArrowFunction,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                type_parameters,
+                parameters,
+                _type,
+                equals_greater_than_token: Some(equals_greater_than_token),
+                body: Some(body),
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        Some(self.with_js_doc(node, has_js_doc))
+    }
+
+    fn parse_arrow_function_expression_body(&mut self, is_async: bool) -> NodeId {
+        let await_flag = if is_async {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        if self.token() == SyntaxKind::OpenBraceToken {
+            return self.parse_function_block(await_flag, None);
+        }
+
+        if self.token() != SyntaxKind::SemicolonToken
+            && self.token() != SyntaxKind::FunctionKeyword
+            && self.token() != SyntaxKind::ClassKeyword
+            && self.is_start_of_statement()
+            && !self.is_start_of_expression_statement()
+        {
+            // Check if we got a plain statement (i.e. no expression-statements, no function/class expressions/declarations)
+            //
+            // Here we try to recover from a potential error situation in the case where the
+            // user meant to supply a block. For example, if the user wrote:
+            //
+            //  a =>
+            //      let v = 0;
+            //  }
+            //
+            // they may be missing an open brace.  Check to see if that's the case so we can
+            // try to recover better.  If we don't do this, then the next close curly we see may end
+            // up preemptively closing the containing construct.
+            //
+            // Note: even when 'IgnoreMissingOpenBrace' is passed, parseBody will still error.
+            return self.parse_function_block(
+                SignatureFlags::IGNORE_MISSING_OPEN_BRACE | await_flag,
+                None,
+            );
+        }
+
+        let saved_top_level = self.top_level;
+        self.top_level = false;
+        let node = if is_async {
+            self.do_in_await_context(Self::parse_assignment_expression_or_higher)
+        } else {
+            self.do_outside_of_await_context(Self::parse_assignment_expression_or_higher)
+        };
+        self.top_level = saved_top_level;
+        node
+    }
+
+    fn parse_conditional_expression_rest(&mut self, left_operand: NodeId, pos: usize) -> NodeId {
+        // Note: we are passed in an expression which was produced from parseBinaryExpressionOrHigher.
+        let question_token = match self.parse_optional_token(SyntaxKind::QuestionToken) {
+            Some(question_token) => question_token,
+            None => return left_operand,
+        };
+
+        // Note: we explicitly 'allowIn' in the whenTrue part of the condition expression, and
+        // we do not that for the 'whenFalse' part.
+        let when_true = self.do_outside_of_context(
+            NodeFlags::DISALLOW_IN_CONTEXT | NodeFlags::DECORATOR_CONTEXT,
+            Self::parse_assignment_expression_or_higher,
+        );
+        let colon_token = self.parse_expected_token(SyntaxKind::ColonToken, None, None);
+        let when_false = if !node_is_missing(self.node(colon_token)) {
+            self.parse_assignment_expression_or_higher()
+        } else {
+            self.create_missing_node(
+                SyntaxKind::Identifier,
+                false,
+                Some(&Diagnostics::_0_expected),
+                &[token_to_string(SyntaxKind::ColonToken).unwrap_or_default()],
+            )
+        };
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ConditionalExpression,
+                NodeData::ConditionalExpression {
+                    condition: left_operand,
+                    question_token,
+                    when_true,
+                    colon_token,
+                    when_false,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_binary_expression_or_higher(&mut self, precedence: i32) -> NodeId {
+        let pos = self.get_node_pos();
+        let left_operand = self.parse_unary_expression_or_higher();
+        self.parse_binary_expression_rest(precedence, left_operand, pos)
+    }
+
+    fn parse_binary_expression_rest(
+        &mut self,
+        precedence: i32,
+        mut left_operand: NodeId,
+        pos: usize,
+    ) -> NodeId {
+        loop {
+            // We either have a binary operator here, or we're finished.  We call
+            // reScanGreaterToken so that we merge token sequences like > and = into >=
+
+            self.re_scan_greater_token();
+            let new_precedence = get_binary_operator_precedence(self.token());
+
+            // Check the precedence to see if we should "take" this operator
+            // - For left associative operator (all operator but **), consume the operator,
+            //   recursively call the function below, and parse binaryExpression as a rightOperand
+            //   of the caller if the new precedence of the operator is greater then or equal to the current precedence.
+            //   For example:
+            //      a - b - c;
+            //            ^token; leftOperand = b. Return b to the caller as a rightOperand
+            //      a * b - c
+            //            ^token; leftOperand = b. Return b to the caller as a rightOperand
+            //      a - b * c;
+            //            ^token; leftOperand = b. Return b * c to the caller as a rightOperand
+            // - For right associative operator (**), consume the operator, recursively call the function
+            //   and parse binaryExpression as a rightOperand of the caller if the new precedence of
+            //   the operator is strictly grater than the current precedence
+            //   For example:
+            //      a ** b ** c;
+            //             ^^token; leftOperand = b. Return b ** c to the caller as a rightOperand
+            //      a - b ** c;
+            //            ^^token; leftOperand = b. Return b ** c to the caller as a rightOperand
+            //      a ** b - c
+            //             ^token; leftOperand = b. Return b to the caller as a rightOperand
+            let consume_current_operator = if self.token() == SyntaxKind::AsteriskAsteriskToken {
+                new_precedence >= precedence
+            } else {
+                new_precedence > precedence
+            };
+
+            if !consume_current_operator {
+                break;
+            }
+
+            if self.token() == SyntaxKind::InKeyword && self.in_disallow_in_context() {
+                break;
+            }
+
+            if self.token() == SyntaxKind::AsKeyword {
+                // Make sure we *do* perform ASI for constructs like this:
+                //    var x = foo
+                //    as (Bar)
+                // This should be parsed as an initialized variable, followed
+                // by a function call to 'as' with the argument 'Bar'
+                if self.scanner.has_preceding_line_break() {
+                    break;
+                } else {
+                    self.next_token();
+                    let _type = self.parse_type();
+                    left_operand = self.make_as_expression(left_operand, _type);
+                }
+            } else {
+                let operator_token = self.parse_token_node();
+                let right = self.parse_binary_expression_or_higher(new_precedence);
+                left_operand =
+                    self.make_binary_expression(left_operand, operator_token, right, pos);
+            }
+        }
+
+        left_operand
+    }
+
+    fn is_binary_operator(&self) -> bool {
+        if self.in_disallow_in_context() && self.token() == SyntaxKind::InKeyword {
+            return false;
+        }
+
+        get_binary_operator_precedence(self.token()) > 0
+    }
+
+    fn make_binary_expression(
+        &mut self,
+        left: NodeId,
+        operator_token: NodeId,
+        right: NodeId,
+        pos: usize,
+    ) -> NodeId {
+        self.finish_node(
+            Node::new(
+                SyntaxKind::BinaryExpression,
+                NodeData::BinaryExpression {
+                    left,
+                    operator_token,
+                    right,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn make_as_expression(&mut self, left: NodeId, right: NodeId) -> NodeId {
+        let pos = self.node(left).pos();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::AsExpression,
+                NodeData::AsExpression {
+                    expression: left,
+                    _type: right,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_prefix_unary_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let operator = self.token();
+        self.next_token();
+        let operand = self.parse_simple_unary_expression();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::PrefixUnaryExpression,
+                NodeData::UnaryExpression { operator, operand },
+            ),
+            pos,
+        )
+    }
+
+    // `delete`, `typeof`, `void` and `await` expressions
+    fn parse_keyword_unary_expression(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        self.next_token();
+        let expression = self.parse_simple_unary_expression();
+        self.finish_node(Node::new(kind, NodeData::Expression { expression }), pos)
+    }
+
+    fn is_await_expression(&mut self) -> bool {
+        if self.token() == SyntaxKind::AwaitKeyword {
+            if self.in_await_context() {
+                return true;
+            }
+
+            // here we are using similar heuristics as 'isYieldExpression'
+            return self
+                .look_ahead(Self::next_token_is_identifier_or_keyword_or_literal_on_same_line);
+        }
+
+        false
+    }
+
+    /// Parse ES7 exponential expression and await expression
+    ///
+    /// ES7 ExponentiationExpression:
+    ///      1) UnaryExpression[?Yield]
+    ///      2) UpdateExpression[?Yield] ** ExponentiationExpression[?Yield]
+    fn parse_unary_expression_or_higher(&mut self) -> NodeId {
+        // ES7 UpdateExpression:
+        //      1) LeftHandSideExpression[?Yield]
+        //      2) LeftHandSideExpression[?Yield][no LineTerminator here]++
+        //      3) LeftHandSideExpression[?Yield][no LineTerminator here]--
+        //      4) ++UnaryExpression[?Yield]
+        //      5) --UnaryExpression[?Yield]
+        if self.is_update_expression() {
+            let pos = self.get_node_pos();
+            let update_expression = self.parse_update_expression();
+            return if self.token() == SyntaxKind::AsteriskAsteriskToken {
+                let precedence = get_binary_operator_precedence(self.token());
+                self.parse_binary_expression_rest(precedence, update_expression, pos)
+            } else {
+                update_expression
+            };
+        }
+
+        // ES7 UnaryExpression:
+        //      1) UpdateExpression[?yield]
+        //      2) delete UpdateExpression[?yield]
+        //      3) void UpdateExpression[?yield]
+        //      4) typeof UpdateExpression[?yield]
+        //      5) + UpdateExpression[?yield]
+        //      6) - UpdateExpression[?yield]
+        //      7) ~ UpdateExpression[?yield]
+        //      8) ! UpdateExpression[?yield]
+        let unary_operator = self.token();
+        let simple_unary_expression = self.parse_simple_unary_expression();
+        if self.token() == SyntaxKind::AsteriskAsteriskToken {
+            let range = self.node(simple_unary_expression).range;
+            let pos = skip_trivia(
+                self.scanner.get_text_utf16(),
+                range.pos,
+                false,
+                false,
+                false,
+            );
+            if self.kind(simple_unary_expression) == SyntaxKind::TypeAssertionExpression {
+                self.parse_error_at(
+                    pos,
+                    range.end,
+                    &Diagnostics::A_type_assertion_expression_is_not_allowed_in_the_left_hand_side_of_an_exponentiation_expression_Consider_enclosing_the_expression_in_parentheses,
+                    &[],
+                );
+            } else {
+                self.parse_error_at(
+                    pos,
+                    range.end,
+                    &Diagnostics::An_unary_expression_with_the_0_operator_is_not_allowed_in_the_left_hand_side_of_an_exponentiation_expression_Consider_enclosing_the_expression_in_parentheses,
+                    &[token_to_string(unary_operator).unwrap_or_default()],
+                );
+            }
+        }
+        simple_unary_expression
+    }
+
+    /// Parse ES7 simple-unary expression or higher:
+    ///
+    /// ES7 UnaryExpression:
+    ///      1) UpdateExpression[?yield]
+    ///      2) delete UnaryExpression[?yield]
+    ///      3) void UnaryExpression[?yield]
+    ///      4) typeof UnaryExpression[?yield]
+    ///      5) + UnaryExpression[?yield]
+    ///      6) - UnaryExpression[?yield]
+    ///      7) ~ UnaryExpression[?yield]
+    ///      8) ! UnaryExpression[?yield]
+    ///      9) [+Await] await UnaryExpression[?yield]
+    fn parse_simple_unary_expression(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken => self.parse_prefix_unary_expression(),
+            SyntaxKind::DeleteKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::DeleteExpression)
+            }
+            SyntaxKind::TypeOfKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::TypeOfExpression)
+            }
+            SyntaxKind::VoidKeyword => {
+                self.parse_keyword_unary_expression(SyntaxKind::VoidExpression)
+            }
+            SyntaxKind::LessThanToken => {
+                // This is modified UnaryExpression grammar in TypeScript
+                //  UnaryExpression (modified):
+                //      < type > UnaryExpression
+                self.parse_type_assertion()
+            }
+            SyntaxKind::AwaitKeyword if self.is_await_expression() => {
+                self.parse_keyword_unary_expression(SyntaxKind::AwaitExpression)
+            }
+            _ => self.parse_update_expression(),
+        }
+    }
+
+    /// Check if the current token can possibly be an ES7 increment expression.
+    ///
+    /// ES7 UpdateExpression:
+    ///      LeftHandSideExpression[?Yield]
+    ///      LeftHandSideExpression[?Yield][no LineTerminator here]++
+    ///      LeftHandSideExpression[?Yield][no LineTerminator here]--
+    ///      ++LeftHandSideExpression[?Yield]
+    ///      --LeftHandSideExpression[?Yield]
+    fn is_update_expression(&self) -> bool {
+        // This function is called inside parseUnaryExpression to decide
+        // whether to call parseSimpleUnaryExpression or call parseUpdateExpression directly
+        match self.token() {
+            SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DeleteKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::AwaitKeyword => false,
+            // If we are not in JSX context, we are parsing TypeAssertion which is an UnaryExpression
+            // In JSX context the token is part of JSXElement.
+            SyntaxKind::LessThanToken => self.language_variant == LanguageVariant::JSX,
+            _ => true,
+        }
+    }
+
+    /// Parse ES7 UpdateExpression. UpdateExpression is used instead of ES6's PostFixExpression.
+    ///
+    /// ES7 UpdateExpression[yield]:
+    ///      1) LeftHandSideExpression[?yield]
+    ///      2) LeftHandSideExpression[?yield] [[no LineTerminator here]]++
+    ///      3) LeftHandSideExpression[?yield] [[no LineTerminator here]]--
+    ///      4) ++LeftHandSideExpression[?yield]
+    ///      5) --LeftHandSideExpression[?yield]
+    /// In TypeScript (2), (3) are parsed as PostfixUnaryExpression. (4), (5) are parsed as PrefixUnaryExpression
+    fn parse_update_expression(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::PlusPlusToken || self.token() == SyntaxKind::MinusMinusToken
+        {
+            let pos = self.get_node_pos();
+            let operator = self.token();
+            self.next_token();
+            let operand = self.parse_left_hand_side_expression_or_higher();
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::PrefixUnaryExpression,
+                    NodeData::UnaryExpression { operator, operand },
+                ),
+                pos,
+            );
+        } else if self.language_variant == LanguageVariant::JSX
+            && self.token() == SyntaxKind::LessThanToken
+            && self.look_ahead(Self::next_token_is_identifier_or_keyword_or_greater_than)
+        {
+            // JSXElement is part of primaryExpression
+            return self.parse_jsx_element_or_self_closing_element_or_fragment(true, None);
+        }
+
+        let expression = self.parse_left_hand_side_expression_or_higher();
+
+        debug_assert!(is_left_hand_side_expression_kind(self.kind(expression)));
+        if (self.token() == SyntaxKind::PlusPlusToken
+            || self.token() == SyntaxKind::MinusMinusToken)
+            && !self.scanner.has_preceding_line_break()
+        {
+            let operator = self.token();
+            self.next_token();
+            let pos = self.node(expression).pos();
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::PostfixUnaryExpression,
+                    NodeData::UnaryExpression {
+                        operator,
+                        operand: expression,
+                    },
+                ),
+                pos,
+            );
+        }
+
+        expression
+    }
+
+    fn parse_left_hand_side_expression_or_higher(&mut self) -> NodeId {
+        // Original Ecma:
+        // LeftHandSideExpression: See 11.2
+        //      NewExpression
+        //      CallExpression
+        //
+        // Our simplification:
+        //
+        // LeftHandSideExpression: See 11.2
+        //      MemberExpression
+        //      CallExpression
+        //
+        // See comment in parseMemberExpressionOrHigher on how we replaced NewExpression with
+        // MemberExpression to make our lives easier.
+        //
+        // to best understand the below code, it's important to see how CallExpression expands
+        // out into its own productions:
+        //
+        // CallExpression:
+        //      MemberExpression Arguments
+        //      CallExpression Arguments
+        //      CallExpression[Expression]
+        //      CallExpression.IdentifierName
+        //      import (AssignmentExpression)
+        //      super Arguments
+        //      super.IdentifierName
+        //
+        // Because of the recursion in these calls, we need to bottom out first. There are three
+        // bottom out states we can run into: 1) We see 'super' which must start either of
+        // the last two CallExpression productions. 2) We see 'import' which must start import call.
+        // 3)we have a MemberExpression which either completes the LeftHandSideExpression,
+        // or starts the beginning of the first four CallExpression productions.
+        let pos = self.get_node_pos();
+        let expression;
+        if self.token() == SyntaxKind::ImportKeyword {
+            if self.look_ahead(Self::next_token_is_open_paren_or_less_than) {
+                // We don't want to eagerly consume all import keyword as import call expression so we look ahead to find "("
+                // For example:
+                //      var foo3 = require("subfolder
+                //      import * as foo1 from "module-from-node
+                // We want this import to be a statement rather than import call expression
+                self.source_flags |= NodeFlags::POSSIBLY_CONTAINS_DYNAMIC_IMPORT;
+                expression = self.parse_token_node();
+            } else if self.look_ahead(Self::next_token_is_dot) {
+                // This is an 'import.*' metaproperty (i.e. 'import.meta')
+                self.next_token(); // advance past the 'import'
+                self.next_token(); // advance past the dot
+                let name = self.parse_identifier_name(None);
+                expression = self.finish_node(
+                    Node::new(
+                        SyntaxKind::MetaProperty,
+                        NodeData::MetaProperty {
+                            keyword_token: SyntaxKind::ImportKeyword,
+                            name,
+                        },
+                    ),
+                    pos,
+                );
+                self.source_flags |= NodeFlags::POSSIBLY_CONTAINS_IMPORT_META;
+            } else {
+                expression = self.parse_member_expression_or_higher();
+            }
+        } else if self.token() == SyntaxKind::SuperKeyword {
+            expression = self.parse_super_expression();
+        } else {
+            expression = self.parse_member_expression_or_higher();
+        }
+
+        // Now, we *may* be complete.  However, we might have consumed the start of a
+        // CallExpression or OptionalExpression.  As such, we need to consume the rest
+        // of it here to be complete.
+        self.parse_call_expression_rest(pos, expression)
+    }
+}
+
+impl Parser {
+    fn parse_member_expression_or_higher(&mut self) -> NodeId {
+        // Note: to make our lives simpler, we decompose the NewExpression productions and
+        // place ObjectCreationExpression and FunctionExpression into PrimaryExpression.
+        // like so:
+        //
+        //   PrimaryExpression : See 11.1
+        //      this
+        //      Identifier
+        //      Literal
+        //      ArrayLiteral
+        //      ObjectLiteral
+        //      (Expression)
+        //      FunctionExpression
+        //      new MemberExpression Arguments?
+        //
+        //   MemberExpression : See 11.2
+        //      PrimaryExpression
+        //      MemberExpression[Expression]
+        //      MemberExpression.IdentifierName
+        //
+        //   CallExpression : See 11.2
+        //      MemberExpression
+        //      CallExpression Arguments
+        //      CallExpression[Expression]
+        //      CallExpression.IdentifierName
+        //
+        // Technically this is ambiguous.  i.e. CallExpression defines:
+        //
+        //   CallExpression:
+        //      CallExpression Arguments
+        //
+        // If you see: "new Foo()"
+        //
+        // Then that could be treated as a single ObjectCreationExpression, or it could be
+        // treated as the invocation of "new Foo".  We disambiguate that in code (to match
+        // the original grammar) by making sure that if we see an ObjectCreationExpression
+        // we always consume arguments if they are there. So we treat "new Foo()" as an
+        // object creation only, and not at all as an invocation.  Another way to think
+        // about this is that for every "new" that we see, we will consume an argument list if
+        // it is there as part of the *associated* object creation node.  Any additional
+        // argument lists we see, will become invocation expressions.
+        //
+        // Because there are no other places in the grammar now that refer to FunctionExpression
+        // or ObjectCreationExpression, it is safe to push down into the PrimaryExpression
+        // production.
+        //
+        // Because CallExpression and MemberExpression are left recursive, we need to bottom out
+        // of the recursion immediately.  So we parse out a primary expression to start with.
+        let pos = self.get_node_pos();
+        let expression = self.parse_primary_expression();
+        self.parse_member_expression_rest(pos, expression, true)
+    }
+
+    fn parse_super_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let expression = self.parse_token_node();
+        if self.token() == SyntaxKind::LessThanToken {
+            let start_pos = self.get_node_pos();
+            let type_arguments = self.try_parse(Self::parse_type_arguments_in_expression);
+            if type_arguments.is_some() {
+                let end = self.get_node_pos();
+                self.parse_error_at(
+                    start_pos,
+                    end,
+                    &Diagnostics::super_may_not_use_type_arguments,
+                    &[],
+                );
+            }
+        }
+
+        if self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::DotToken
+            || self.token() == SyntaxKind::OpenBracketToken
+        {
+            return expression;
+        }
+
+        // If we have seen "super" it must be followed by '(' or '.'.
+        // If it wasn't then just try to parse out a '.' and report an error.
+        self.parse_expected_token(
+            SyntaxKind::DotToken,
+            Some(&Diagnostics::super_must_be_followed_by_an_argument_list_or_member_access),
+            None,
+        );
+        // private names will never work with `super` (`super.#foo`), but that's a semantic error, not syntactic
+        let name = self.parse_right_side_of_dot(true, true);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::PropertyAccessExpression,
+                NodeData::PropertyAccessExpression {
+                    expression,
+                    question_dot_token: None,
+                    name,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn jsx_opening_tag_name(&self, opening: NodeId) -> Option<NodeId> {
+        match self.node(opening).data {
+            NodeData::JsxOpeningLikeElement { tag_name, .. }
+            | NodeData::JsxClosingElement { tag_name } => Some(tag_name),
+            _ => None,
+        }
+    }
+
+    // The opening and closing element of a `JsxElement` child whose tags don't match.
+    fn mismatched_jsx_element(&self, child: NodeId) -> Option<(NodeId, NodeId)> {
+        match self.node(child).data {
+            NodeData::JsxElement {
+                opening_element,
+                closing_element,
+                ..
+            } => {
+                let opening_tag_name = self.jsx_opening_tag_name(opening_element)?;
+                let closing_tag_name = self.jsx_opening_tag_name(closing_element)?;
+                if self.tag_names_are_equivalent(opening_tag_name, closing_tag_name) {
+                    None
+                } else {
+                    Some((opening_element, closing_element))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_jsx_element_or_self_closing_element_or_fragment(
+        &mut self,
+        in_expression_context: bool,
+        top_invalid_node_position: Option<usize>,
+    ) -> NodeId {
+        self.parse_jsx_element_or_self_closing_element_or_fragment_worker(
+            in_expression_context,
+            top_invalid_node_position,
+            None,
+        )
+    }
+
+    fn parse_jsx_element_or_self_closing_element_or_fragment_worker(
+        &mut self,
+        in_expression_context: bool,
+        top_invalid_node_position: Option<usize>,
+        opening_tag: Option<NodeId>,
+    ) -> NodeId {
+        let pos = self.get_node_pos();
+        let opening = self
+            .parse_jsx_opening_or_self_closing_element_or_opening_fragment(in_expression_context);
+        let result;
+        if self.kind(opening) == SyntaxKind::JsxOpeningElement {
+            let mut children = self.parse_jsx_children(opening);
+            let closing_element;
+            let opening_tag_name = self.jsx_opening_tag_name(opening).unwrap();
+
+            let last_child = children.last().copied();
+            let mismatched_last_child = last_child.and_then(|last_child| {
+                self.mismatched_jsx_element(last_child)
+                    .filter(|(_, last_closing)| {
+                        let last_closing_tag_name =
+                            self.jsx_opening_tag_name(*last_closing).unwrap();
+                        self.tag_names_are_equivalent(opening_tag_name, last_closing_tag_name)
+                    })
+            });
+            if let Some((last_opening, last_closing)) = mismatched_last_child {
+                // when an unclosed JsxOpeningElement incorrectly parses its parent's JsxClosingElement,
+                // restructure (<div>(...<span></div>)) --> (<div>(...<span></span>)</div>)
+                // (no need to error; the parent will error)
+                let last_opening_range = self.node(last_opening).range;
+                let end = last_opening_range.end; // newly-created children and closing are both zero-width end/end
+                let tag_name = self.finish_node_at(
+                    Node::new(
+                        SyntaxKind::Identifier,
+                        NodeData::Identifier {
+                            text: self.atoms.intern(""),
+                            original_keyword_kind: None,
+                            is_in_jsdoc_namespace: false,
+                            jsdoc_dot_pos: None,
+                        },
+                    ),
+                    end,
+                    end,
+                );
+                let new_closing = self.finish_node_at(
+                    Node::new(
+                        SyntaxKind::JsxClosingElement,
+                        NodeData::JsxClosingElement { tag_name },
+                    ),
+                    end,
+                    end,
+                );
+                let new_children = self.create_node_array(Vec::new(), end, Some(end), false);
+                let new_last = self.finish_node_at(
+                    Node::new(
+                        SyntaxKind::JsxElement,
+                        NodeData::JsxElement {
+                            opening_element: last_opening,
+                            children: new_children,
+                            closing_element: new_closing,
+                        },
+                    ),
+                    last_opening_range.pos,
+                    end,
+                );
+
+                let mut elements = children.nodes.clone();
+                *elements.last_mut().unwrap() = new_last;
+                children = self.create_node_array(elements, children.range.pos, Some(end), false);
+                closing_element = last_closing;
+            } else {
+                closing_element = self.parse_jsx_closing_element(opening, in_expression_context);
+                let closing_tag_name = self.jsx_opening_tag_name(closing_element).unwrap();
+                if !self.tag_names_are_equivalent(opening_tag_name, closing_tag_name) {
+                    let opening_tag_text = self.get_text_of_node(opening_tag_name);
+                    let parent_tag_name = opening_tag
+                        .filter(|opening_tag| {
+                            self.kind(*opening_tag) == SyntaxKind::JsxOpeningElement
+                        })
+                        .and_then(|opening_tag| self.jsx_opening_tag_name(opening_tag));
+                    if parent_tag_name.is_some_and(|parent_tag_name| {
+                        self.tag_names_are_equivalent(closing_tag_name, parent_tag_name)
+                    }) {
+                        // opening incorrectly matched with its parent's closing -- put error on opening
+                        let range = self.node(opening_tag_name).range;
+                        self.parse_error_at_range(
+                            range,
+                            &Diagnostics::JSX_element_0_has_no_corresponding_closing_tag,
+                            &[&opening_tag_text],
+                        );
+                    } else {
+                        // other opening/closing mismatches -- put error on closing
+                        let range = self.node(closing_tag_name).range;
+                        self.parse_error_at_range(
+                            range,
+                            &Diagnostics::Expected_corresponding_JSX_closing_tag_for_0,
+                            &[&opening_tag_text],
+                        );
+                    }
+                }
+            }
+            result = self.finish_node(
+                Node::new(
+                    SyntaxKind::JsxElement,
+                    NodeData::JsxElement {
+                        opening_element: opening,
+                        children,
+                        closing_element,
+                    },
+                ),
+                pos,
+            );
+        } else if self.kind(opening) == SyntaxKind::JsxOpeningFragment {
+            let children = self.parse_jsx_children(opening);
+            let closing_fragment = self.parse_jsx_closing_fragment(in_expression_context);
+            result = self.finish_node(
+                Node::new(
+                    SyntaxKind::JsxFragment,
+                    NodeData::JsxFragment {
+                        opening_fragment: opening,
+                        children,
+                        closing_fragment,
+                    },
+                ),
+                pos,
+            );
+        } else {
+            debug_assert_eq!(self.kind(opening), SyntaxKind::JsxSelfClosingElement);
+            // Nothing else to do for self-closing elements
+            result = opening;
+        }
+
+        // If the user writes the invalid code '<div></div><div></div>' in an expression context (i.e. not wrapped in
+        // an enclosing tag), we'll naively try to parse   ^ this as a 'less than' operator and the remainder of the tag
+        // as garbage, which will cause the formatter to badly mangle the JSX. Perform a speculative parse of a JSX
+        // element if we see a < token so that we can wrap it in a synthetic binary expression so the formatter
+        // does less damage and we can report a better error.
+        // Since JSX elements are invalid < operands anyway, this lookahead parse will only occur in error scenarios
+        // of one sort or another.
+        if in_expression_context && self.token() == SyntaxKind::LessThanToken {
+            let top_bad_pos = top_invalid_node_position.unwrap_or_else(|| self.node(result).pos());
+            let invalid_element =
+                self.try_parse(|p| {
+                    Some(p.parse_jsx_element_or_self_closing_element_or_fragment(
+                        true,
+                        Some(top_bad_pos),
+                    ))
+                });
+            if let Some(invalid_element) = invalid_element {
+                let operator_token =
+                    self.create_missing_node(SyntaxKind::CommaToken, false, None, &[]);
+                let invalid_element_range = self.node(invalid_element).range;
+                self.node_mut(operator_token).range = TextRange {
+                    pos: invalid_element_range.pos,
+                    end: invalid_element_range.pos,
+                };
+                let start = skip_trivia(
+                    self.scanner.get_text_utf16(),
+                    top_bad_pos,
+                    false,
+                    false,
+                    false,
+                );
+                self.parse_error_at(
+                    start,
+                    invalid_element_range.end,
+                    &Diagnostics::JSX_expressions_must_have_one_parent_element,
+                    &[],
+                );
+                return self.make_binary_expression(result, operator_token, invalid_element, pos);
+            }
+        }
+
+        result
+    }
+
+    fn parse_jsx_text(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let node = Node::new(
+            SyntaxKind::JsxText,
+            NodeData::JsxText {
+                text: self.scanner.get_token_value(),
+                contains_only_trivia_white_spaces: self.current_token
+                    == SyntaxKind::JsxTextAllWhiteSpaces,
+            },
+        );
+        self.current_token = self.scanner.scan_jsx_token().into();
+        self.finish_node(node, pos)
+    }
+
+    fn parse_jsx_child(
+        &mut self,
+        opening_tag: NodeId,
+        token: JsxTokenSyntaxKind,
+    ) -> Option<NodeId> {
+        match token {
+            JsxTokenSyntaxKind::EndOfFileToken => {
+                // If we hit EOF, issue the error at the tag that lacks the closing element
+                // rather than at the end of the file (which is useless)
+                if self.kind(opening_tag) == SyntaxKind::JsxOpeningFragment {
+                    let range = self.node(opening_tag).range;
+                    self.parse_error_at_range(
+                        range,
+                        &Diagnostics::JSX_fragment_has_no_corresponding_closing_tag,
+                        &[],
+                    );
+                } else {
+                    // We want the error span to cover only 'Foo.Bar' in < Foo.Bar >
+                    // or to cover only 'Foo' in < Foo >
+                    let tag = self.jsx_opening_tag_name(opening_tag).unwrap();
+                    let tag_range = self.node(tag).range;
+                    let start = skip_trivia(
+                        self.scanner.get_text_utf16(),
+                        tag_range.pos,
+                        false,
+                        false,
+                        false,
+                    );
+                    let tag_text = self.get_text_of_node(tag);
+                    self.parse_error_at(
+                        start,
+                        tag_range.end,
+                        &Diagnostics::JSX_element_0_has_no_corresponding_closing_tag,
+                        &[&tag_text],
+                    );
+                }
+                None
+            }
+            JsxTokenSyntaxKind::LessThanSlashToken | JsxTokenSyntaxKind::ConflictMarkerTrivia => {
+                None
+            }
+            JsxTokenSyntaxKind::JsxText | JsxTokenSyntaxKind::JsxTextAllWhiteSpaces => {
+                Some(self.parse_jsx_text())
+            }
+            JsxTokenSyntaxKind::OpenBraceToken => self.parse_jsx_expression(false),
+            JsxTokenSyntaxKind::LessThanToken => Some(
+                self.parse_jsx_element_or_self_closing_element_or_fragment_worker(
+                    false,
+                    None,
+                    Some(opening_tag),
+                ),
+            ),
+        }
+    }
+
+    fn parse_jsx_children(&mut self, opening_tag: NodeId) -> NodeArray {
+        let mut list = Vec::new();
+        let list_pos = self.get_node_pos();
+        let save_parsing_context = self.parsing_context;
+        self.parsing_context |= ParsingContext::JsxChildren.bit();
+
+        loop {
+            let token = self.scanner.re_scan_jsx_token(None);
+            self.current_token = token.into();
+            let child = match self.parse_jsx_child(opening_tag, token) {
+                Some(child) => child,
+                None => break,
+            };
+            list.push(child);
+            if self.kind(opening_tag) == SyntaxKind::JsxOpeningElement {
+                let opening_tag_name = self.jsx_opening_tag_name(opening_tag).unwrap();
+                let is_mismatched_parent_closing =
+                    self.mismatched_jsx_element(child)
+                        .is_some_and(|(_, child_closing)| {
+                            let child_closing_tag_name =
+                                self.jsx_opening_tag_name(child_closing).unwrap();
+                            self.tag_names_are_equivalent(opening_tag_name, child_closing_tag_name)
+                        });
+                if is_mismatched_parent_closing {
+                    // stop after parsing a mismatched child like <div>...(<span></div>) in order to reattach the </div> higher
+                    break;
+                }
+            }
+        }
+
+        self.parsing_context = save_parsing_context;
+        self.create_node_array(list, list_pos, None, false)
+    }
+
+    fn parse_jsx_attributes(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let properties = self.parse_list(ParsingContext::JsxAttributes, Self::parse_jsx_attribute);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JsxAttributes,
+                NodeData::JsxAttributes { properties },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_jsx_opening_or_self_closing_element_or_opening_fragment(
+        &mut self,
+        in_expression_context: bool,
+    ) -> NodeId {
+        let pos = self.get_node_pos();
+
+        self.parse_expected(SyntaxKind::LessThanToken);
+
+        if self.token() == SyntaxKind::GreaterThanToken {
+            // See below for explanation of scanJsxText
+            self.scan_jsx_text();
+            return self.finish_node(
+                Node::new(SyntaxKind::JsxOpeningFragment, NodeData::Token),
+                pos,
+            );
+        }
+        let tag_name = self.parse_jsx_element_name();
+        let type_arguments = if self.context_flags & NodeFlags::JAVA_SCRIPT_FILE == 0 {
+            self.try_parse_type_arguments()
+        } else {
+            None
+        };
+        let attributes = self.parse_jsx_attributes();
+
+        let kind = if self.token() == SyntaxKind::GreaterThanToken {
+            // Closing tag, so scan the immediately-following text with the JSX scanning instead
+            // of regular scanning to avoid treating illegal characters (e.g. '#') as immediate
+            // scanning errors
+            self.scan_jsx_text();
+            SyntaxKind::JsxOpeningElement
+        } else {
+            self.parse_expected(SyntaxKind::SlashToken);
+            if self.parse_expected_with_message(SyntaxKind::GreaterThanToken, None, false) {
+                // manually advance the scanner in order to look for jsx text inside jsx
+                if in_expression_context {
+                    self.next_token();
+                } else {
+                    self.scan_jsx_text();
+                }
+            }
+            SyntaxKind::JsxSelfClosingElement
+        };
+
+        self.finish_node(
+            Node::new(
+                kind,
+                NodeData::JsxOpeningLikeElement {
+                    tag_name,
+                    type_arguments,
+                    attributes,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_jsx_element_name(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.scan_jsx_identifier();
+        // JsxElement can have name in the form of
+        //      propertyAccessExpression
+        //      primaryExpression in the form of an identifier and "this" keyword
+        // We can't just simply use parseLeftHandSideExpressionOrHigher because then we will start consider class,function etc as a keyword
+        // We only want to consider "this" as a primaryExpression
+        let mut expression = if self.token() == SyntaxKind::ThisKeyword {
+            self.parse_token_node()
+        } else {
+            self.parse_identifier_name(None)
+        };
+        while self.parse_optional(SyntaxKind::DotToken) {
+            let name = self.parse_right_side_of_dot(true, false);
+            expression = self.finish_node(
+                Node::new(
+                    SyntaxKind::PropertyAccessExpression,
+                    NodeData::PropertyAccessExpression {
+                        expression,
+                        question_dot_token: None,
+                        name,
+                    },
+                ),
+                pos,
+            );
+        }
+        expression
+    }
+
+    fn parse_jsx_expression(&mut self, in_expression_context: bool) -> Option<NodeId> {
+        let pos = self.get_node_pos();
+        if !self.parse_expected(SyntaxKind::OpenBraceToken) {
+            return None;
+        }
+
+        let mut dot_dot_dot_token = None;
+        let mut expression = None;
+        if self.token() != SyntaxKind::CloseBraceToken {
+            dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+            // Only an AssignmentExpression is valid here per the JSX spec,
+            // but we can unambiguously parse a comma sequence and provide
+            // a better error message in grammar checking.
+            expression = Some(self.parse_expression());
+        }
+        if in_expression_context {
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+        } else if self.parse_expected_with_message(SyntaxKind::CloseBraceToken, None, false) {
+            self.scan_jsx_text();
+        }
+
+        Some(self.finish_node(
+            Node::new(
+                SyntaxKind::JsxExpression,
+                NodeData::JsxExpression {
+                    dot_dot_dot_token,
+                    expression,
+                },
+            ),
+            pos,
+        ))
+    }
+
+    fn parse_jsx_attribute(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::OpenBraceToken {
+            return self.parse_jsx_spread_attribute();
+        }
+
+        self.scan_jsx_identifier();
+        let pos = self.get_node_pos();
+        let name = self.parse_identifier_name(None);
+        let initializer = if self.token() != SyntaxKind::EqualsToken {
+            None
+        } else if self.scan_jsx_attribute_value() == SyntaxKind::StringLiteral {
+            Some(self.parse_literal_node())
+        } else {
+            self.parse_jsx_expression(true)
+        };
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JsxAttribute,
+                NodeData::JsxAttribute { name, initializer },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_jsx_spread_attribute(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken);
+        self.parse_expected(SyntaxKind::DotDotDotToken);
+        let expression = self.parse_expression();
+        self.parse_expected(SyntaxKind::CloseBraceToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JsxSpreadAttribute,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_jsx_closing_element(&mut self, open: NodeId, in_expression_context: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::LessThanSlashToken);
+        let tag_name = self.parse_jsx_element_name();
+        if self.parse_expected_with_message(SyntaxKind::GreaterThanToken, None, false) {
+            // manually advance the scanner in order to look for jsx text inside jsx
+            let open_tag_name = self.jsx_opening_tag_name(open).unwrap();
+            if in_expression_context || !self.tag_names_are_equivalent(open_tag_name, tag_name) {
+                self.next_token();
+            } else {
+                self.scan_jsx_text();
+            }
+        }
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JsxClosingElement,
+                NodeData::JsxClosingElement { tag_name },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_jsx_closing_fragment(&mut self, in_expression_context: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::LessThanSlashToken);
+        if token_is_identifier_or_keyword(self.token()) {
+            let name = self.parse_jsx_element_name();
+            let range = self.node(name).range;
+            self.parse_error_at_range(
+                range,
+                &Diagnostics::Expected_corresponding_closing_tag_for_JSX_fragment,
+                &[],
+            );
+        }
+        if self.parse_expected_with_message(SyntaxKind::GreaterThanToken, None, false) {
+            // manually advance the scanner in order to look for jsx text inside jsx
+            if in_expression_context {
+                self.next_token();
+            } else {
+                self.scan_jsx_text();
+            }
+        }
+        self.finish_node(
+            Node::new(SyntaxKind::JsxClosingFragment, NodeData::Token),
+            pos,
+        )
+    }
+
+    fn tag_names_are_equivalent(&self, lhs: NodeId, rhs: NodeId) -> bool {
+        let lhs = self.node(lhs);
+        let rhs = self.node(rhs);
+        if lhs.kind != rhs.kind {
+            return false;
+        }
+
+        match (&lhs.data, &rhs.data) {
+            (
+                NodeData::Identifier { text: lhs_text, .. },
+                NodeData::Identifier { text: rhs_text, .. },
+            ) => lhs_text == rhs_text,
+            (
+                NodeData::PropertyAccessExpression {
+                    expression: lhs_expression,
+                    name: lhs_name,
+                    ..
+                },
+                NodeData::PropertyAccessExpression {
+                    expression: rhs_expression,
+                    name: rhs_name,
+                    ..
+                },
+            ) => {
+                self.identifier_text(*lhs_name) == self.identifier_text(*rhs_name)
+                    && self.tag_names_are_equivalent(*lhs_expression, *rhs_expression)
+            }
+            // `this`
+            _ => true,
+        }
+    }
+
+    fn identifier_text(&self, node: NodeId) -> &str {
+        match &self.node(node).data {
+            NodeData::Identifier { text, .. } | NodeData::PrivateIdentifier { text } => text,
+            _ => "",
+        }
+    }
+
+    // The source text of a node, without its leading trivia.
+    fn get_text_of_node(&self, node: NodeId) -> String {
+        let node = self.node(node);
+        if node_is_missing(node) {
+            return String::new();
+        }
+        let text = self.scanner.get_text_utf16();
+        let start = skip_trivia(text, node.pos(), false, false, false);
+        String::from_utf16_lossy(&text[start..node.end()])
+    }
+
+    fn parse_type_assertion(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::LessThanToken);
+        let _type = self.parse_type();
+        self.parse_expected(SyntaxKind::GreaterThanToken);
+        let expression = self.parse_simple_unary_expression();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::TypeAssertionExpression,
+                NodeData::TypeAssertion { _type, expression },
+            ),
+            pos,
+        )
+    }
+
+    fn next_token_is_identifier_or_keyword_or_open_bracket_or_template(&mut self) -> bool {
+        self.next_token();
+        token_is_identifier_or_keyword(self.token())
+            || self.token() == SyntaxKind::OpenBracketToken
+            || self.is_template_start_of_tagged_template()
+    }
+
+    fn is_start_of_optional_property_or_element_access_chain(&mut self) -> bool {
+        self.token() == SyntaxKind::QuestionDotToken
+            && self
+                .look_ahead(Self::next_token_is_identifier_or_keyword_or_open_bracket_or_template)
+    }
+
+    fn try_reparse_optional_chain(&mut self, node: NodeId) -> bool {
+        if self.node(node).flags & NodeFlags::OPTIONAL_CHAIN != 0 {
+            return true;
+        }
+        // check for an optional chain in a non-null expression
+        if self.kind(node) == SyntaxKind::NonNullExpression {
+            let mut expr = self.non_null_expression_operand(node);
+            while self.kind(expr) == SyntaxKind::NonNullExpression
+                && self.node(expr).flags & NodeFlags::OPTIONAL_CHAIN == 0
+            {
+                expr = self.non_null_expression_operand(expr);
+            }
+            if self.node(expr).flags & NodeFlags::OPTIONAL_CHAIN != 0 {
+                // this is part of an optional chain. Walk down from `node` to `expression` and set the flag.
+                let mut node = node;
+                while self.kind(node) == SyntaxKind::NonNullExpression {
+                    self.node_mut(node).flags |= NodeFlags::OPTIONAL_CHAIN;
+                    node = self.non_null_expression_operand(node);
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    fn non_null_expression_operand(&self, node: NodeId) -> NodeId {
+        match self.node(node).data {
+            NodeData::Expression { expression } => expression,
+            _ => unreachable!("a non-null expression has an operand"),
+        }
+    }
+
+    fn parse_property_access_expression_rest(
+        &mut self,
+        pos: usize,
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+    ) -> NodeId {
+        let name = self.parse_right_side_of_dot(true, true);
+        let is_optional_chain =
+            question_dot_token.is_some() || self.try_reparse_optional_chain(expression);
+        if is_optional_chain && self.kind(name) == SyntaxKind::PrivateIdentifier {
+            let range = self.node(name).range;
+            self.parse_error_at_range(
+                range,
+                &Diagnostics::An_optional_chain_cannot_contain_private_identifiers,
+                &[],
+            );
+        }
+        let mut node = Node::new(
+            SyntaxKind::PropertyAccessExpression,
+            NodeData::PropertyAccessExpression {
+                expression,
+                question_dot_token,
+                name,
+            },
+        );
+        if is_optional_chain {
+            node.flags |= NodeFlags::OPTIONAL_CHAIN;
+        }
+        self.finish_node(node, pos)
+    }
+
+    fn parse_element_access_expression_rest(
+        &mut self,
+        pos: usize,
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+    ) -> NodeId {
+        let argument_expression = if self.token() == SyntaxKind::CloseBracketToken {
+            self.create_missing_node(
+                SyntaxKind::Identifier,
+                true,
+                Some(&Diagnostics::An_element_access_expression_should_take_an_argument),
+                &[],
+            )
+        } else {
+            let argument = self.allow_in_and(Self::parse_expression);
+            if is_string_or_numeric_literal_like(self.node(argument)) {
+                self.intern_literal_text(argument);
+            }
+            argument
+        };
+
+        self.parse_expected(SyntaxKind::CloseBracketToken);
+
+        let mut node = Node::new(
+            SyntaxKind::ElementAccessExpression,
+            NodeData::ElementAccessExpression {
+                expression,
+                question_dot_token,
+                argument_expression,
+            },
+        );
+        if question_dot_token.is_some() || self.try_reparse_optional_chain(expression) {
+            node.flags |= NodeFlags::OPTIONAL_CHAIN;
+        }
+        self.finish_node(node, pos)
+    }
+
+    fn parse_member_expression_rest(
+        &mut self,
+        pos: usize,
+        mut expression: NodeId,
+        allow_optional_chain: bool,
+    ) -> NodeId {
+        loop {
+            let mut question_dot_token = None;
+            let is_property_access;
+            if allow_optional_chain && self.is_start_of_optional_property_or_element_access_chain()
+            {
+                question_dot_token =
+                    Some(self.parse_expected_token(SyntaxKind::QuestionDotToken, None, None));
+                is_property_access = token_is_identifier_or_keyword(self.token());
+            } else {
+                is_property_access = self.parse_optional(SyntaxKind::DotToken);
+            }
+
+            if is_property_access {
+                expression =
+                    self.parse_property_access_expression_rest(pos, expression, question_dot_token);
+                continue;
+            }
+
+            if question_dot_token.is_none()
+                && self.token() == SyntaxKind::ExclamationToken
+                && !self.scanner.has_preceding_line_break()
+            {
+                self.next_token();
+                expression = self.finish_node(
+                    Node::new(
+                        SyntaxKind::NonNullExpression,
+                        NodeData::Expression { expression },
+                    ),
+                    pos,
+                );
+                continue;
+            }
+
+            // when in the [Decorator] context, we do not parse ElementAccess as it could be part of a ComputedPropertyName
+            if (question_dot_token.is_some() || !self.in_decorator_context())
+                && self.parse_optional(SyntaxKind::OpenBracketToken)
+            {
+                expression =
+                    self.parse_element_access_expression_rest(pos, expression, question_dot_token);
+                continue;
+            }
+
+            if self.is_template_start_of_tagged_template() {
+                expression =
+                    self.parse_tagged_template_rest(pos, expression, question_dot_token, None);
+                continue;
+            }
+
+            return expression;
+        }
+    }
+
+    fn is_template_start_of_tagged_template(&self) -> bool {
+        self.token() == SyntaxKind::NoSubstitutionTemplateLiteral
+            || self.token() == SyntaxKind::TemplateHead
+    }
+
+    fn parse_tagged_template_rest(
+        &mut self,
+        pos: usize,
+        tag: NodeId,
+        question_dot_token: Option<NodeId>,
+        type_arguments: Option<NodeArray>,
+    ) -> NodeId {
+        let template = if self.token() == SyntaxKind::NoSubstitutionTemplateLiteral {
+            self.re_scan_template_head_or_no_substitution_template();
+            self.parse_literal_node()
+        } else {
+            self.parse_template_expression(true)
+        };
+        let mut node = Node::new(
+            SyntaxKind::TaggedTemplateExpression,
+            NodeData::TaggedTemplateExpression {
+                tag,
+                question_dot_token,
+                type_arguments,
+                template,
+            },
+        );
+        if question_dot_token.is_some() || self.node(tag).flags & NodeFlags::OPTIONAL_CHAIN != 0 {
+            node.flags |= NodeFlags::OPTIONAL_CHAIN;
+        }
+        self.finish_node(node, pos)
+    }
+
+    fn make_call_expression(
+        &mut self,
+        pos: usize,
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+        type_arguments: Option<NodeArray>,
+        arguments: NodeArray,
+    ) -> NodeId {
+        let mut node = Node::new(
+            SyntaxKind::CallExpression,
+            NodeData::CallExpression {
+                expression,
+                question_dot_token,
+                type_arguments,
+                arguments: Some(arguments),
+            },
+        );
+        if question_dot_token.is_some() || self.try_reparse_optional_chain(expression) {
+            node.flags |= NodeFlags::OPTIONAL_CHAIN;
+        }
+        self.finish_node(node, pos)
+    }
+
+    fn parse_call_expression_rest(&mut self, pos: usize, mut expression: NodeId) -> NodeId {
+        loop {
+            expression = self.parse_member_expression_rest(pos, expression, true);
+            let question_dot_token = self.parse_optional_token(SyntaxKind::QuestionDotToken);
+            // handle 'foo<<T>()'
+            // parse template arguments only in TypeScript files (not in JavaScript files).
+            if self.context_flags & NodeFlags::JAVA_SCRIPT_FILE == 0
+                && (self.token() == SyntaxKind::LessThanToken
+                    || self.token() == SyntaxKind::LessThanLessThanToken)
+            {
+                // See if this is the start of a generic invocation.  If so, consume it and
+                // keep checking for postfix expressions.  Otherwise, it's just a '<' that's
+                // part of an arithmetic expression.  Break out so we consume it higher in the
+                // stack.
+                let type_arguments = self.try_parse(Self::parse_type_arguments_in_expression);
+                if let Some(type_arguments) = type_arguments {
+                    if self.is_template_start_of_tagged_template() {
+                        expression = self.parse_tagged_template_rest(
+                            pos,
+                            expression,
+                            question_dot_token,
+                            Some(type_arguments),
+                        );
+                        continue;
+                    }
+
+                    let argument_list = self.parse_argument_list();
+                    expression = self.make_call_expression(
+                        pos,
+                        expression,
+                        question_dot_token,
+                        Some(type_arguments),
+                        argument_list,
+                    );
+                    continue;
+                }
+            } else if self.token() == SyntaxKind::OpenParenToken {
+                let argument_list = self.parse_argument_list();
+                expression = self.make_call_expression(
+                    pos,
+                    expression,
+                    question_dot_token,
+                    None,
+                    argument_list,
+                );
+                continue;
+            }
+            if let Some(question_dot_token) = question_dot_token {
+                // We failed to parse anything, so report a missing identifier here.
+                let name = self.create_missing_node(
+                    SyntaxKind::Identifier,
+                    false,
+                    Some(&Diagnostics::Identifier_expected),
+                    &[],
+                );
+                let mut node = Node::new(
+                    SyntaxKind::PropertyAccessExpression,
+                    NodeData::PropertyAccessExpression {
+                        expression,
+                        question_dot_token: Some(question_dot_token),
+                        name,
+                    },
+                );
+                node.flags |= NodeFlags::OPTIONAL_CHAIN;
+                expression = self.finish_node(node, pos);
+            }
+            break;
+        }
+        expression
+    }
+
+    fn parse_argument_list(&mut self) -> NodeArray {
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let result = self.parse_delimited_list(
+            ParsingContext::ArgumentExpressions,
+            Self::parse_argument_expression,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        result
+    }
+
+    fn parse_type_arguments_in_expression(&mut self) -> Option<NodeArray> {
+        if self.context_flags & NodeFlags::JAVA_SCRIPT_FILE != 0 {
+            // TypeArguments must not be parsed in JavaScript files to avoid ambiguity with binary operators.
+            return None;
+        }
+
+        if self.re_scan_less_than_token() != SyntaxKind::LessThanToken {
+            return None;
+        }
+        self.next_token();
+
+        let type_arguments =
+            self.parse_delimited_list(ParsingContext::TypeArguments, Self::parse_type, false);
+        if !self.parse_expected(SyntaxKind::GreaterThanToken) {
+            // If it doesn't have the closing `>` then it's definitely not an type argument list.
+            return None;
+        }
+
+        // If we have a '<', then only parse this as a argument list if the type arguments
+        // are complete and we have an open paren.  if we don't, rewind and return nothing.
+        if self.can_follow_type_arguments_in_expression() {
+            Some(type_arguments)
+        } else {
+            None
+        }
+    }
+
+    fn can_follow_type_arguments_in_expression(&self) -> bool {
+        match self.token() {
+            // foo<x>(
+            SyntaxKind::OpenParenToken
+            // foo<T> `...`
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            // foo<T> `...${100}...`
+            | SyntaxKind::TemplateHead
+            // these are the only tokens can legally follow a type argument
+            // list. So we definitely want to treat them as type arg lists.
+            | SyntaxKind::DotToken                      // foo<x>.
+            | SyntaxKind::CloseParenToken               // foo<x>)
+            | SyntaxKind::CloseBracketToken             // foo<x>]
+            | SyntaxKind::ColonToken                    // foo<x>:
+            | SyntaxKind::SemicolonToken                // foo<x>;
+            | SyntaxKind::QuestionToken                 // foo<x>?
+            | SyntaxKind::EqualsEqualsToken             // foo<x> ==
+            | SyntaxKind::EqualsEqualsEqualsToken       // foo<x> ===
+            | SyntaxKind::ExclamationEqualsToken        // foo<x> !=
+            | SyntaxKind::ExclamationEqualsEqualsToken  // foo<x> !==
+            | SyntaxKind::AmpersandAmpersandToken       // foo<x> &&
+            | SyntaxKind::BarBarToken                   // foo<x> ||
+            | SyntaxKind::QuestionQuestionToken         // foo<x> ??
+            | SyntaxKind::CaretToken                    // foo<x> ^
+            | SyntaxKind::AmpersandToken                // foo<x> &
+            | SyntaxKind::BarToken                      // foo<x> |
+            | SyntaxKind::CloseBraceToken               // foo<x> }
+            | SyntaxKind::EndOfFileToken => {
+                // foo<x>
+                // these cases can't legally follow a type arg list.  However, they're not legal
+                // expressions either.  The user is probably in the middle of a generic type. So
+                // treat it as such.
+                true
+            }
+
+            // foo<x>,
+            // foo<x> {
+            // We don't want to treat these as type arguments.  Otherwise we'll parse this
+            // as an invocation expression.  Instead, we want to parse out the expression
+            // in isolation from the type arguments.
+            // Anything else treat as an expression.
+            _ => false,
+        }
+    }
+
+    fn parse_primary_expression(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral => return self.parse_literal_node(),
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword => return self.parse_token_node(),
+            SyntaxKind::OpenParenToken => return self.parse_parenthesized_expression(),
+            SyntaxKind::OpenBracketToken => return self.parse_array_literal_expression(),
+            SyntaxKind::OpenBraceToken => return self.parse_object_literal_expression(),
+            SyntaxKind::AsyncKeyword
+                // Async arrow functions are parsed earlier in parseAssignmentExpressionOrHigher.
+                // If we encounter `async [no LineTerminator here] function` then this is an async
+                // function; otherwise, its an identifier.
+                if self.look_ahead(Self::next_token_is_function_keyword_on_same_line) => {
+                    return self.parse_function_expression();
+                }
+            SyntaxKind::ClassKeyword => return self.parse_class_expression(),
+            SyntaxKind::FunctionKeyword => return self.parse_function_expression(),
+            SyntaxKind::NewKeyword => return self.parse_new_expression_or_new_dot_target(),
+            SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken
+                if self.re_scan_slash_token() == SyntaxKind::RegularExpressionLiteral => {
+                    return self.parse_literal_node();
+                }
+            SyntaxKind::TemplateHead => return self.parse_template_expression(false),
+            _ => {}
+        }
+
+        self.parse_identifier(Some(&Diagnostics::Expression_expected), None)
+    }
+
+    fn parse_parenthesized_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::ParenthesizedExpression,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_spread_element(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::DotDotDotToken);
+        let expression = self.parse_assignment_expression_or_higher();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::SpreadElement,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_argument_or_array_literal_element(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::DotDotDotToken {
+            self.parse_spread_element()
+        } else if self.token() == SyntaxKind::CommaToken {
+            let pos = self.get_node_pos();
+            self.finish_node(
+                Node::new(SyntaxKind::OmittedExpression, NodeData::Token),
+                pos,
+            )
+        } else {
+            self.parse_assignment_expression_or_higher()
+        }
+    }
+
+    fn parse_argument_expression(&mut self) -> NodeId {
+        self.do_outside_of_context(
+            NodeFlags::DISALLOW_IN_CONTEXT | NodeFlags::DECORATOR_CONTEXT,
+            Self::parse_argument_or_array_literal_element,
+        )
+    }
+
+    fn parse_array_literal_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken);
+        let multi_line = self.scanner.has_preceding_line_break();
+        let elements = self.parse_delimited_list(
+            ParsingContext::ArrayLiteralMembers,
+            Self::parse_argument_or_array_literal_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBracketToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ArrayLiteralExpression,
+                NodeData::ArrayLiteralExpression {
+                    elements,
+                    multi_line,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_object_literal_element(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+
+        if self
+            .parse_optional_token(SyntaxKind::DotDotDotToken)
+            .is_some()
+        {
+            let expression = self.parse_assignment_expression_or_higher();
+            let node = self.finish_node(
+                Node::new(
+                    SyntaxKind::SpreadAssignment,
+                    NodeData::Expression { expression },
+                ),
+                pos,
+            );
+            return self.with_js_doc(node, has_js_doc);
+        }
+
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(false);
+
+        if self.parse_contextual_modifier(SyntaxKind::GetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                SyntaxKind::GetAccessor,
+            );
+        }
+        if self.parse_contextual_modifier(SyntaxKind::SetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                SyntaxKind::SetAccessor,
+            );
+        }
+
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let token_is_identifier = self.is_identifier();
+        let name = self.parse_property_name();
+
+        // Disallowing of optional property assignments and definite assignment assertion happens in the grammar checker.
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        let exclamation_token = self.parse_optional_token(SyntaxKind::ExclamationToken);
+
+        if asterisk_token.is_some()
+            || self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+        {
+            return self.parse_method_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                asterisk_token,
+                name,
+                question_token,
+                exclamation_token,
+                None,
+            );
+        }
+
+        // check if it is short-hand property assignment or normal property assignment
+        // NOTE: if token is EqualsToken it is interpreted as CoverInitializedName production
+        // CoverInitializedName[Yield] :
+        //     IdentifierReference[?Yield] Initializer[In, ?Yield]
+        // this is necessary because ObjectLiteral productions are also used to cover grammar for ObjectAssignmentPattern
+        let is_shorthand_property_assignment =
+            token_is_identifier && self.token() != SyntaxKind::ColonToken;
+        let mut node = if is_shorthand_property_assignment {
+            let equals_token = self.parse_optional_token(SyntaxKind::EqualsToken);
+            let object_assignment_initializer = if equals_token.is_some() {
+                Some(self.allow_in_and(Self::parse_assignment_expression_or_higher))
+            } else {
+                None
+            };
+            // Save equals token for error reporting.
+            Node::new(
+                SyntaxKind::ShorthandPropertyAssignment,
+                NodeData::ShorthandPropertyAssignment {
+                    name,
+                    question_token,
+                    exclamation_token,
+                    equals_token,
+                    object_assignment_initializer,
+                },
+            )
+        } else {
+            self.parse_expected(SyntaxKind::ColonToken);
+            let initializer = self.allow_in_and(Self::parse_assignment_expression_or_higher);
+            Node::new(
+                SyntaxKind::PropertyAssignment,
+                NodeData::VariableLike {
+                    name,
+                    question_token,
+                    exclamation_token,
+                    _type: None,
+                    initializer: Some(initializer),
+                },
+            )
+        };
+        // Decorators, Modifiers, questionToken, and exclamationToken are not supported by property assignments and are reported in the grammar checker
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_object_literal_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken);
+        let multi_line = self.scanner.has_preceding_line_break();
+        let properties = self.parse_delimited_list(
+            ParsingContext::ObjectLiteralMembers,
+            Self::parse_object_literal_element,
+            true,
+        );
+        // The JS compiler relates a missing `}` error to the open brace, related information
+        // isn't kept by the native diagnostics.
+        self.parse_expected(SyntaxKind::CloseBraceToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ObjectLiteralExpression,
+                NodeData::ObjectLiteralExpression {
+                    properties,
+                    multi_line,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_function_expression(&mut self) -> NodeId {
+        // GeneratorExpression:
+        //      function* BindingIdentifier [Yield][opt](FormalParameters[Yield]){ GeneratorBody }
+        //
+        // FunctionExpression:
+        //      function BindingIdentifier[opt](FormalParameters){ FunctionBody }
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_decorator_context(false);
+        }
+
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let modifiers = self.parse_modifiers(false);
+        self.parse_expected(SyntaxKind::FunctionKeyword);
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_async_modifier(&modifiers) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let name = if is_generator != 0 && is_async != 0 {
+            self.do_in_yield_and_await_context(Self::parse_optional_binding_identifier)
+        } else if is_generator != 0 {
+            self.do_in_yield_context(Self::parse_optional_binding_identifier)
+        } else if is_async != 0 {
+            self.do_in_await_context(Self::parse_optional_binding_identifier)
+        } else {
+            self.parse_optional_binding_identifier()
+        };
+
+        let type_parameters = self.parse_type_parameters();
+        let parameters = self.parse_parameters(is_generator | is_async);
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+        let body = self.parse_function_block(is_generator | is_async, None);
+
+        if save_decorator_context {
+            self.set_decorator_context(true);
+        }
+
+        let mut node = Node::new(
+            SyntaxKind::FunctionExpression,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                asterisk_token,
+                name,
+                type_parameters,
+                parameters,
+                _type,
+                body: Some(body),
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_optional_binding_identifier(&mut self) -> Option<NodeId> {
+        if self.is_binding_identifier() {
+            Some(self.parse_binding_identifier(None))
+        } else {
+            None
+        }
+    }
+
+    fn parse_new_expression_or_new_dot_target(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::NewKeyword);
+        if self.parse_optional(SyntaxKind::DotToken) {
+            let name = self.parse_identifier_name(None);
+            return self.finish_node(
+                Node::new(
+                    SyntaxKind::MetaProperty,
+                    NodeData::MetaProperty {
+                        keyword_token: SyntaxKind::NewKeyword,
+                        name,
+                    },
+                ),
+                pos,
+            );
+        }
+
+        let expression_pos = self.get_node_pos();
+        let mut expression = self.parse_primary_expression();
+        expression = self.parse_member_expression_rest(expression_pos, expression, false);
+        let mut type_arguments = self.try_parse(Self::parse_type_arguments_in_expression);
+        if self.is_template_start_of_tagged_template() {
+            debug_assert!(
+                type_arguments.is_some(),
+                "Expected a type argument list; all plain tagged template starts should be consumed in 'parseMemberExpressionRest'"
+            );
+            expression = self.parse_tagged_template_rest(
+                expression_pos,
+                expression,
+                None,
+                type_arguments.take(),
+            );
+        }
+
+        let mut arguments = None;
+        if self.token() == SyntaxKind::OpenParenToken {
+            arguments = Some(self.parse_argument_list());
+        } else if type_arguments.is_some() {
+            let end = self.scanner.get_start_pos();
+            self.parse_error_at(
+                pos,
+                end,
+                &Diagnostics::A_new_expression_with_type_arguments_must_always_be_followed_by_a_parenthesized_argument_list,
+                &[],
+            );
+        }
+        self.finish_node(
+            Node::new(
+                SyntaxKind::NewExpression,
+                NodeData::CallExpression {
+                    expression,
+                    question_dot_token: None,
+                    type_arguments,
+                    arguments,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn has_async_modifier(&self, modifiers: &Option<NodeArray>) -> bool {
+        modifiers.as_ref().is_some_and(|modifiers| {
+            modifiers
+                .iter()
+                .any(|modifier| self.kind(*modifier) == SyntaxKind::AsyncKeyword)
+        })
+    }
+}
+
+impl Parser {
+    fn parse_block(
+        &mut self,
+        ignore_missing_open_brace: bool,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        if self.parse_expected_with_message(SyntaxKind::OpenBraceToken, diagnostic_message, true)
+            || ignore_missing_open_brace
+        {
+            let multi_line = self.scanner.has_preceding_line_break();
+            let statements =
+                self.parse_list(ParsingContext::BlockStatements, Self::parse_statement);
+            // The JS compiler relates a missing `}` error to the open brace, related information
+            // isn't kept by the native diagnostics.
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+            let result = self.finish_node(
+                Node::new(
+                    SyntaxKind::Block,
+                    NodeData::Block {
+                        statements,
+                        multi_line,
+                    },
+                ),
+                pos,
+            );
+            let result = self.with_js_doc(result, has_js_doc);
+            if self.token() == SyntaxKind::EqualsToken {
+                self.parse_error_at_current_token(&Diagnostics::Declaration_or_statement_expected_This_follows_a_block_of_statements_so_if_you_intended_to_write_a_destructuring_assignment_you_might_need_to_wrap_the_the_whole_assignment_in_parentheses, &[]);
+                self.next_token();
+            }
+
+            result
+        } else {
+            let statements = self.create_missing_list();
+            let result = self.finish_node(
+                Node::new(
+                    SyntaxKind::Block,
+                    NodeData::Block {
+                        statements,
+                        multi_line: false,
+                    },
+                ),
+                pos,
+            );
+            self.with_js_doc(result, has_js_doc)
+        }
+    }
+
+    fn parse_function_block(
+        &mut self,
+        flags: u32,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let saved_yield_context = self.in_yield_context();
+        self.set_yield_context(flags & SignatureFlags::YIELD != 0);
+
+        let saved_await_context = self.in_await_context();
+        self.set_await_context(flags & SignatureFlags::AWAIT != 0);
+
+        let saved_top_level = self.top_level;
+        self.top_level = false;
+
+        // We may be in a [Decorator] context when parsing a function expression or
+        // arrow function. The body of the function is not in [Decorator] context.
+        let save_decorator_context = self.in_decorator_context();
+        if save_decorator_context {
+            self.set_decorator_context(false);
+        }
+
+        let block = self.parse_block(
+            flags & SignatureFlags::IGNORE_MISSING_OPEN_BRACE != 0,
+            diagnostic_message,
+        );
+
+        if save_decorator_context {
+            self.set_decorator_context(true);
+        }
+
+        self.top_level = saved_top_level;
+        self.set_yield_context(saved_yield_context);
+        self.set_await_context(saved_await_context);
+
+        block
+    }
+
+    fn parse_empty_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::SemicolonToken);
+        let node = self.finish_node(Node::new(SyntaxKind::EmptyStatement, NodeData::Token), pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_if_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::IfKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let then_statement = self.parse_statement();
+        let else_statement = if self.parse_optional(SyntaxKind::ElseKeyword) {
+            Some(self.parse_statement())
+        } else {
+            None
+        };
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::IfStatement,
+                NodeData::IfStatement {
+                    expression,
+                    then_statement,
+                    else_statement,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_do_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::DoKeyword);
+        let statement = self.parse_statement();
+        self.parse_expected(SyntaxKind::WhileKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+
+        // From: https://mail.mozilla.org/pipermail/es-discuss/2011-August/016188.html
+        // 157 min --- All allen at wirfs-brock.com CONF --- "do{;}while(false)false" prohibited in
+        // spec but allowed in consensus reality. Approved -- this is the de-facto standard whereby
+        //  do;while(0)x will have a semicolon inserted before x.
+        self.parse_optional(SyntaxKind::SemicolonToken);
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::DoStatement,
+                NodeData::DoStatement {
+                    statement,
+                    expression,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_while_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::WhileKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let statement = self.parse_statement();
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::WhileStatement,
+                NodeData::WhileOrWithStatement {
+                    expression,
+                    statement,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_for_or_for_in_or_for_of_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::ForKeyword);
+        let await_token = self.parse_optional_token(SyntaxKind::AwaitKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+
+        let mut initializer = None;
+        if self.token() != SyntaxKind::SemicolonToken {
+            if self.token() == SyntaxKind::VarKeyword
+                || self.token() == SyntaxKind::LetKeyword
+                || self.token() == SyntaxKind::ConstKeyword
+            {
+                initializer = Some(self.parse_variable_declaration_list(true));
+            } else {
+                initializer = Some(self.disallow_in_and(Self::parse_expression));
+            }
+        }
+
+        let is_for_of = if await_token.is_some() {
+            self.parse_expected(SyntaxKind::OfKeyword)
+        } else {
+            self.parse_optional(SyntaxKind::OfKeyword)
+        };
+        let node = if is_for_of {
+            let expression = self.allow_in_and(Self::parse_assignment_expression_or_higher);
+            self.parse_expected(SyntaxKind::CloseParenToken);
+            let statement = self.parse_statement();
+            Node::new(
+                SyntaxKind::ForOfStatement,
+                NodeData::ForInOrOfStatement {
+                    await_modifier: await_token,
+                    initializer: initializer.unwrap(),
+                    expression,
+                    statement,
+                },
+            )
+        } else if self.parse_optional(SyntaxKind::InKeyword) {
+            let expression = self.allow_in_and(Self::parse_expression);
+            self.parse_expected(SyntaxKind::CloseParenToken);
+            let statement = self.parse_statement();
+            Node::new(
+                SyntaxKind::ForInStatement,
+                NodeData::ForInOrOfStatement {
+                    await_modifier: None,
+                    initializer: initializer.unwrap(),
+                    expression,
+                    statement,
+                },
+            )
+        } else {
+            self.parse_expected(SyntaxKind::SemicolonToken);
+            let condition = if self.token() != SyntaxKind::SemicolonToken
+                && self.token() != SyntaxKind::CloseParenToken
+            {
+                Some(self.allow_in_and(Self::parse_expression))
+            } else {
+                None
+            };
+            self.parse_expected(SyntaxKind::SemicolonToken);
+            let incrementor = if self.token() != SyntaxKind::CloseParenToken {
+                Some(self.allow_in_and(Self::parse_expression))
+            } else {
+                None
+            };
+            self.parse_expected(SyntaxKind::CloseParenToken);
+            let statement = self.parse_statement();
+            Node::new(
+                SyntaxKind::ForStatement,
+                NodeData::ForStatement {
+                    initializer,
+                    condition,
+                    incrementor,
+                    statement,
+                },
+            )
+        };
+
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_break_or_continue_statement(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+
+        self.parse_expected(if kind == SyntaxKind::BreakStatement {
+            SyntaxKind::BreakKeyword
+        } else {
+            SyntaxKind::ContinueKeyword
+        });
+        let label = if self.can_parse_semicolon() {
+            None
+        } else {
+            Some(self.parse_identifier(None, None))
+        };
+
+        self.parse_semicolon();
+        let node = self.finish_node(
+            Node::new(kind, NodeData::BreakOrContinueStatement { label }),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_return_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::ReturnKeyword);
+        let expression = if self.can_parse_semicolon() {
+            None
+        } else {
+            Some(self.allow_in_and(Self::parse_expression))
+        };
+        self.parse_semicolon();
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::ReturnStatement,
+                NodeData::ReturnStatement { expression },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_with_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::WithKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let statement =
+            self.do_inside_of_context(NodeFlags::IN_WITH_STATEMENT, Self::parse_statement);
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::WithStatement,
+                NodeData::WhileOrWithStatement {
+                    expression,
+                    statement,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_case_clause(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::CaseKeyword);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::ColonToken);
+        let statements = self.parse_list(
+            ParsingContext::SwitchClauseStatements,
+            Self::parse_statement,
+        );
+        self.finish_node(
+            Node::new(
+                SyntaxKind::CaseClause,
+                NodeData::CaseOrDefaultClause {
+                    expression: Some(expression),
+                    statements,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_default_clause(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::DefaultKeyword);
+        self.parse_expected(SyntaxKind::ColonToken);
+        let statements = self.parse_list(
+            ParsingContext::SwitchClauseStatements,
+            Self::parse_statement,
+        );
+        self.finish_node(
+            Node::new(
+                SyntaxKind::DefaultClause,
+                NodeData::CaseOrDefaultClause {
+                    expression: None,
+                    statements,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_case_or_default_clause(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::CaseKeyword {
+            self.parse_case_clause()
+        } else {
+            self.parse_default_clause()
+        }
+    }
+
+    fn parse_case_block(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken);
+        let clauses = self.parse_list(
+            ParsingContext::SwitchClauses,
+            Self::parse_case_or_default_clause,
+        );
+        self.parse_expected(SyntaxKind::CloseBraceToken);
+        self.finish_node(
+            Node::new(SyntaxKind::CaseBlock, NodeData::CaseBlock { clauses }),
+            pos,
+        )
+    }
+
+    fn parse_switch_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::SwitchKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.allow_in_and(Self::parse_expression);
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        let case_block = self.parse_case_block();
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::SwitchStatement,
+                NodeData::SwitchStatement {
+                    expression,
+                    case_block,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_throw_statement(&mut self) -> NodeId {
+        // ThrowStatement[Yield] :
+        //      throw [no LineTerminator here]Expression[In, ?Yield];
+
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::ThrowKeyword);
+
+        // Because of automatic semicolon insertion, we need to report error if this
+        // throw could be terminated with a semicolon.  Note: we can't call 'parseExpression'
+        // directly as that might consume an expression on the following line.
+        // Instead, we create a "missing" identifier, but don't report an error. The actual error
+        // will be reported in the grammar walker.
+        let expression = if self.scanner.has_preceding_line_break() {
+            self.identifier_count += 1;
+            let identifier_pos = self.get_node_pos();
+            self.finish_node(
+                Node::new(
+                    SyntaxKind::Identifier,
+                    NodeData::Identifier {
+                        text: self.atoms.intern(""),
+                        original_keyword_kind: None,
+                        is_in_jsdoc_namespace: false,
+                        jsdoc_dot_pos: None,
+                    },
+                ),
+                identifier_pos,
+            )
+        } else {
+            self.allow_in_and(Self::parse_expression)
+        };
+        self.parse_semicolon();
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::ThrowStatement,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    // TODO: Review for error recovery
+    fn parse_try_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+
+        self.parse_expected(SyntaxKind::TryKeyword);
+        let try_block = self.parse_block(false, None);
+        let catch_clause = if self.token() == SyntaxKind::CatchKeyword {
+            Some(self.parse_catch_clause())
+        } else {
+            None
+        };
+
+        // If we don't have a catch clause, then we must have a finally clause.  Try to parse
+        // one out no matter what.
+        let mut finally_block = None;
+        if catch_clause.is_none() || self.token() == SyntaxKind::FinallyKeyword {
+            self.parse_expected(SyntaxKind::FinallyKeyword);
+            finally_block = Some(self.parse_block(false, None));
+        }
+
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::TryStatement,
+                NodeData::TryStatement {
+                    try_block,
+                    catch_clause,
+                    finally_block,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_catch_clause(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::CatchKeyword);
+
+        let mut variable_declaration = None;
+        if self.parse_optional(SyntaxKind::OpenParenToken) {
+            variable_declaration = Some(self.parse_variable_declaration(false));
+            self.parse_expected(SyntaxKind::CloseParenToken);
+        }
+
+        let block = self.parse_block(false, None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::CatchClause,
+                NodeData::CatchClause {
+                    variable_declaration,
+                    block,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_debugger_statement(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_expected(SyntaxKind::DebuggerKeyword);
+        self.parse_semicolon();
+        let node = self.finish_node(
+            Node::new(SyntaxKind::DebuggerStatement, NodeData::Token),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_expression_or_labeled_statement(&mut self) -> NodeId {
+        // Avoiding having to do the lookahead for a labeled statement by just trying to parse
+        // out an expression, seeing if it is identifier and then seeing if it is followed by
+        // a colon.
+        let pos = self.get_node_pos();
+        let mut has_js_doc = self.has_preceding_js_doc_comment();
+        let has_paren = self.token() == SyntaxKind::OpenParenToken;
+        let expression = self.allow_in_and(Self::parse_expression);
+        let node = if self.kind(expression) == SyntaxKind::Identifier
+            && self.parse_optional(SyntaxKind::ColonToken)
+        {
+            let statement = self.parse_statement();
+            Node::new(
+                SyntaxKind::LabeledStatement,
+                NodeData::LabeledStatement {
+                    label: expression,
+                    statement,
+                },
+            )
+        } else {
+            self.parse_semicolon();
+            if has_paren {
+                // do not parse the same jsdoc twice
+                has_js_doc = false;
+            }
+            Node::new(
+                SyntaxKind::ExpressionStatement,
+                NodeData::Expression { expression },
+            )
+        };
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn next_token_is_identifier_or_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        token_is_identifier_or_keyword(self.token()) && !self.scanner.has_preceding_line_break()
+    }
+
+    fn next_token_is_class_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::ClassKeyword && !self.scanner.has_preceding_line_break()
+    }
+
+    fn next_token_is_function_keyword_on_same_line(&mut self) -> bool {
+        self.next_token();
+        self.token() == SyntaxKind::FunctionKeyword && !self.scanner.has_preceding_line_break()
+    }
+
+    fn next_token_is_identifier_or_keyword_or_literal_on_same_line(&mut self) -> bool {
+        self.next_token();
+        (token_is_identifier_or_keyword(self.token())
+            || self.token() == SyntaxKind::NumericLiteral
+            || self.token() == SyntaxKind::BigIntLiteral
+            || self.token() == SyntaxKind::StringLiteral)
+            && !self.scanner.has_preceding_line_break()
+    }
+
+    fn is_declaration(&mut self) -> bool {
+        loop {
+            match self.token() {
+                SyntaxKind::VarKeyword
+                | SyntaxKind::LetKeyword
+                | SyntaxKind::ConstKeyword
+                | SyntaxKind::FunctionKeyword
+                | SyntaxKind::ClassKeyword
+                | SyntaxKind::EnumKeyword => return true,
+
+                // 'declare', 'module', 'namespace', 'interface'* and 'type' are all legal JavaScript identifiers;
+                // however, an identifier cannot be followed by another identifier on the same line. This is what we
+                // count on to parse out the respective declarations. For instance, we exploit this to say that
+                //
+                //    namespace n
+                //
+                // can be none other than the beginning of a namespace declaration, but need to respect that JavaScript sees
+                //
+                //    namespace
+                //    n
+                //
+                // as the identifier 'namespace' on one line followed by the identifier 'n' on another.
+                // We need to look one token ahead to see if it permissible to try parsing a declaration.
+                //
+                // *Note*: 'interface' is actually a strict mode reserved word. So while
+                //
+                //   "use strict"
+                //   interface
+                //   I {}
+                //
+                // could be legal, it would add complexity for very little gain.
+                SyntaxKind::InterfaceKeyword | SyntaxKind::TypeKeyword => {
+                    return self.next_token_is_identifier_on_same_line();
+                }
+                SyntaxKind::ModuleKeyword | SyntaxKind::NamespaceKeyword => {
+                    return self.next_token_is_identifier_or_string_literal_on_same_line();
+                }
+                SyntaxKind::AbstractKeyword
+                | SyntaxKind::AsyncKeyword
+                | SyntaxKind::DeclareKeyword
+                | SyntaxKind::PrivateKeyword
+                | SyntaxKind::ProtectedKeyword
+                | SyntaxKind::PublicKeyword
+                | SyntaxKind::ReadonlyKeyword => {
+                    self.next_token();
+                    // ASI takes effect for this modifier.
+                    if self.scanner.has_preceding_line_break() {
+                        return false;
+                    }
+                    continue;
+                }
+
+                SyntaxKind::GlobalKeyword => {
+                    self.next_token();
+                    return self.token() == SyntaxKind::OpenBraceToken
+                        || self.token() == SyntaxKind::Identifier
+                        || self.token() == SyntaxKind::ExportKeyword;
+                }
+
+                SyntaxKind::ImportKeyword => {
+                    self.next_token();
+                    return self.token() == SyntaxKind::StringLiteral
+                        || self.token() == SyntaxKind::AsteriskToken
+                        || self.token() == SyntaxKind::OpenBraceToken
+                        || token_is_identifier_or_keyword(self.token());
+                }
+                SyntaxKind::ExportKeyword => {
+                    let mut current_token = self.next_token();
+                    if current_token == SyntaxKind::TypeKeyword {
+                        current_token = self.look_ahead(Self::next_token);
+                    }
+                    if current_token == SyntaxKind::EqualsToken
+                        || current_token == SyntaxKind::AsteriskToken
+                        || current_token == SyntaxKind::OpenBraceToken
+                        || current_token == SyntaxKind::DefaultKeyword
+                        || current_token == SyntaxKind::AsKeyword
+                    {
+                        return true;
+                    }
+                    continue;
+                }
+
+                SyntaxKind::StaticKeyword => {
+                    self.next_token();
+                    continue;
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    fn is_start_of_declaration(&mut self) -> bool {
+        self.look_ahead(Self::is_declaration)
+    }
+
+    fn is_start_of_statement(&mut self) -> bool {
+        match self.token() {
+            SyntaxKind::AtToken
+            | SyntaxKind::SemicolonToken
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::VarKeyword
+            | SyntaxKind::LetKeyword
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::ClassKeyword
+            | SyntaxKind::EnumKeyword
+            | SyntaxKind::IfKeyword
+            | SyntaxKind::DoKeyword
+            | SyntaxKind::WhileKeyword
+            | SyntaxKind::ForKeyword
+            | SyntaxKind::ContinueKeyword
+            | SyntaxKind::BreakKeyword
+            | SyntaxKind::ReturnKeyword
+            | SyntaxKind::WithKeyword
+            | SyntaxKind::SwitchKeyword
+            | SyntaxKind::ThrowKeyword
+            | SyntaxKind::TryKeyword
+            | SyntaxKind::DebuggerKeyword
+            // 'catch' and 'finally' do not actually indicate that the code is part of a statement,
+            // however, we say they are here so that we may gracefully parse them and error later.
+            | SyntaxKind::CatchKeyword
+            | SyntaxKind::FinallyKeyword => true,
+
+            SyntaxKind::ImportKeyword => {
+                self.is_start_of_declaration() || self.look_ahead(Self::next_token_is_open_paren_or_less_than_or_dot)
+            }
+
+            SyntaxKind::ConstKeyword | SyntaxKind::ExportKeyword => self.is_start_of_declaration(),
+
+            // When these don't start a declaration, they're an identifier in an expression statement
+            SyntaxKind::AsyncKeyword
+            | SyntaxKind::DeclareKeyword
+            | SyntaxKind::InterfaceKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword
+            | SyntaxKind::TypeKeyword
+            | SyntaxKind::GlobalKeyword => true,
+
+            // When these don't start a declaration, they may be the start of a class member if an identifier
+            // immediately follows. Otherwise they're an identifier in an expression statement.
+            SyntaxKind::PublicKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::StaticKeyword
+            | SyntaxKind::ReadonlyKeyword => {
+                self.is_start_of_declaration() || !self.look_ahead(Self::next_token_is_identifier_or_keyword_on_same_line)
+            }
+
+            _ => self.is_start_of_expression(),
+        }
+    }
+
+    fn next_token_is_identifier_or_start_of_destructuring(&mut self) -> bool {
+        self.next_token();
+        self.is_identifier()
+            || self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::OpenBracketToken
+    }
+
+    fn is_let_declaration(&mut self) -> bool {
+        // In ES6 'let' always starts a lexical declaration if followed by an identifier or {
+        // or [.
+        self.look_ahead(Self::next_token_is_identifier_or_start_of_destructuring)
+    }
+
+    fn parse_statement(&mut self) -> NodeId {
+        match self.token() {
+            SyntaxKind::SemicolonToken => return self.parse_empty_statement(),
+            SyntaxKind::OpenBraceToken => return self.parse_block(false, None),
+            SyntaxKind::VarKeyword => {
+                let pos = self.get_node_pos();
+                let has_js_doc = self.has_preceding_js_doc_comment();
+                return self.parse_variable_statement(pos, has_js_doc, None, None);
+            }
+            SyntaxKind::LetKeyword if self.is_let_declaration() => {
+                let pos = self.get_node_pos();
+                let has_js_doc = self.has_preceding_js_doc_comment();
+                return self.parse_variable_statement(pos, has_js_doc, None, None);
+            }
+            SyntaxKind::FunctionKeyword => {
+                let pos = self.get_node_pos();
+                let has_js_doc = self.has_preceding_js_doc_comment();
+                return self.parse_function_declaration(pos, has_js_doc, None, None);
+            }
+            SyntaxKind::ClassKeyword => {
+                let pos = self.get_node_pos();
+                let has_js_doc = self.has_preceding_js_doc_comment();
+                return self.parse_class_declaration(pos, has_js_doc, None, None);
+            }
+            SyntaxKind::IfKeyword => return self.parse_if_statement(),
+            SyntaxKind::DoKeyword => return self.parse_do_statement(),
+            SyntaxKind::WhileKeyword => return self.parse_while_statement(),
+            SyntaxKind::ForKeyword => return self.parse_for_or_for_in_or_for_of_statement(),
+            SyntaxKind::ContinueKeyword => {
+                return self.parse_break_or_continue_statement(SyntaxKind::ContinueStatement)
+            }
+            SyntaxKind::BreakKeyword => {
+                return self.parse_break_or_continue_statement(SyntaxKind::BreakStatement)
+            }
+            SyntaxKind::ReturnKeyword => return self.parse_return_statement(),
+            SyntaxKind::WithKeyword => return self.parse_with_statement(),
+            SyntaxKind::SwitchKeyword => return self.parse_switch_statement(),
+            SyntaxKind::ThrowKeyword => return self.parse_throw_statement(),
+            // Include 'catch' and 'finally' for error recovery.
+            SyntaxKind::TryKeyword | SyntaxKind::CatchKeyword | SyntaxKind::FinallyKeyword => {
+                return self.parse_try_statement()
+            }
+            SyntaxKind::DebuggerKeyword => return self.parse_debugger_statement(),
+            SyntaxKind::AtToken => return self.parse_declaration(),
+            SyntaxKind::AsyncKeyword
+            | SyntaxKind::InterfaceKeyword
+            | SyntaxKind::TypeKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword
+            | SyntaxKind::DeclareKeyword
+            | SyntaxKind::ConstKeyword
+            | SyntaxKind::EnumKeyword
+            | SyntaxKind::ExportKeyword
+            | SyntaxKind::ImportKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::PublicKeyword
+            | SyntaxKind::AbstractKeyword
+            | SyntaxKind::StaticKeyword
+            | SyntaxKind::ReadonlyKeyword
+            | SyntaxKind::GlobalKeyword
+                if self.is_start_of_declaration() =>
+            {
+                return self.parse_declaration();
+            }
+            _ => {}
+        }
+        self.parse_expression_or_labeled_statement()
+    }
+
+    fn parse_declaration(&mut self) -> NodeId {
+        let is_ambient = self.look_ahead(|p| {
+            p.parse_decorators();
+            let modifiers = p.parse_modifiers(false);
+            p.has_modifier_of_kind(&modifiers, SyntaxKind::DeclareKeyword)
+        });
+
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(false);
+        if is_ambient {
+            for &modifier in modifiers.iter().flat_map(|modifiers| modifiers.iter()) {
+                self.node_mut(modifier).flags |= NodeFlags::AMBIENT;
+            }
+            self.do_inside_of_context(NodeFlags::AMBIENT, |p| {
+                p.parse_declaration_worker(pos, has_js_doc, decorators, modifiers)
+            })
+        } else {
+            self.parse_declaration_worker(pos, has_js_doc, decorators, modifiers)
+        }
+    }
+
+    fn parse_declaration_worker(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        match self.token() {
+            SyntaxKind::VarKeyword | SyntaxKind::LetKeyword | SyntaxKind::ConstKeyword => {
+                self.parse_variable_statement(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::FunctionKeyword => {
+                self.parse_function_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::ClassKeyword => {
+                self.parse_class_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::InterfaceKeyword => {
+                self.parse_interface_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::TypeKeyword => {
+                self.parse_type_alias_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::EnumKeyword => {
+                self.parse_enum_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::GlobalKeyword
+            | SyntaxKind::ModuleKeyword
+            | SyntaxKind::NamespaceKeyword => {
+                self.parse_module_declaration(pos, has_js_doc, decorators, modifiers)
+            }
+            SyntaxKind::ImportKeyword => self
+                .parse_import_declaration_or_import_equals_declaration(
+                    pos, has_js_doc, decorators, modifiers,
+                ),
+            SyntaxKind::ExportKeyword => {
+                self.next_token();
+                match self.token() {
+                    SyntaxKind::DefaultKeyword | SyntaxKind::EqualsToken => {
+                        self.parse_export_assignment(pos, has_js_doc, decorators, modifiers)
+                    }
+                    SyntaxKind::AsKeyword => self
+                        .parse_namespace_export_declaration(pos, has_js_doc, decorators, modifiers),
+                    _ => self.parse_export_declaration(pos, has_js_doc, decorators, modifiers),
+                }
+            }
+            _ => {
+                // We reached this point because we encountered decorators and/or modifiers and assumed a declaration
+                // would follow. For recovery and error reporting purposes, return an incomplete declaration.
+                debug_assert!(decorators.is_some() || modifiers.is_some());
+                let missing = self.create_missing_node(
+                    SyntaxKind::MissingDeclaration,
+                    true,
+                    Some(&Diagnostics::Declaration_expected),
+                    &[],
+                );
+                let node = self.node_mut(missing);
+                node.range.pos = pos;
+                node.decorators = decorators;
+                node.modifiers = modifiers;
+                missing
+            }
+        }
+    }
+
+    fn next_token_is_identifier_or_string_literal_on_same_line(&mut self) -> bool {
+        self.next_token();
+        !self.scanner.has_preceding_line_break()
+            && (self.is_identifier() || self.token() == SyntaxKind::StringLiteral)
+    }
+
+    fn parse_function_block_or_semicolon(
+        &mut self,
+        flags: u32,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> Option<NodeId> {
+        if self.token() != SyntaxKind::OpenBraceToken && self.can_parse_semicolon() {
+            self.parse_semicolon();
+            return None;
+        }
+
+        Some(self.parse_function_block(flags, diagnostic_message))
+    }
+
+    // DECLARATIONS
+
+    fn parse_array_binding_element(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        if self.token() == SyntaxKind::CommaToken {
+            return self.finish_node(
+                Node::new(SyntaxKind::OmittedExpression, NodeData::Token),
+                pos,
+            );
+        }
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+        let name = self.parse_identifier_or_pattern(None);
+        let initializer = self.parse_initializer();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::BindingElement,
+                NodeData::BindingElement {
+                    dot_dot_dot_token,
+                    property_name: None,
+                    name,
+                    initializer,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_object_binding_element(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let dot_dot_dot_token = self.parse_optional_token(SyntaxKind::DotDotDotToken);
+        let token_is_identifier = self.is_binding_identifier();
+        let mut property_name = Some(self.parse_property_name());
+        let name = if token_is_identifier && self.token() != SyntaxKind::ColonToken {
+            property_name.take().unwrap()
+        } else {
+            self.parse_expected(SyntaxKind::ColonToken);
+            self.parse_identifier_or_pattern(None)
+        };
+        let initializer = self.parse_initializer();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::BindingElement,
+                NodeData::BindingElement {
+                    dot_dot_dot_token,
+                    property_name,
+                    name,
+                    initializer,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_object_binding_pattern(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBraceToken);
+        let elements = self.parse_delimited_list(
+            ParsingContext::ObjectBindingElements,
+            Self::parse_object_binding_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBraceToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ObjectBindingPattern,
+                NodeData::BindingPattern { elements },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_array_binding_pattern(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::OpenBracketToken);
+        let elements = self.parse_delimited_list(
+            ParsingContext::ArrayBindingElements,
+            Self::parse_array_binding_element,
+            false,
+        );
+        self.parse_expected(SyntaxKind::CloseBracketToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ArrayBindingPattern,
+                NodeData::BindingPattern { elements },
+            ),
+            pos,
+        )
+    }
+
+    fn is_binding_identifier_or_private_identifier_or_pattern(&self) -> bool {
+        self.token() == SyntaxKind::OpenBraceToken
+            || self.token() == SyntaxKind::OpenBracketToken
+            || self.token() == SyntaxKind::PrivateIdentifier
+            || self.is_binding_identifier()
+    }
+
+    fn parse_identifier_or_pattern(
+        &mut self,
+        private_identifier_diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        if self.token() == SyntaxKind::OpenBracketToken {
+            return self.parse_array_binding_pattern();
+        }
+        if self.token() == SyntaxKind::OpenBraceToken {
+            return self.parse_object_binding_pattern();
+        }
+        self.parse_binding_identifier(private_identifier_diagnostic_message)
+    }
+
+    fn parse_variable_declaration_allow_exclamation(&mut self) -> NodeId {
+        self.parse_variable_declaration(true)
+    }
+
+    fn parse_variable_declaration_disallow_exclamation(&mut self) -> NodeId {
+        self.parse_variable_declaration(false)
+    }
+
+    fn parse_variable_declaration(&mut self, allow_exclamation: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let name = self.parse_identifier_or_pattern(Some(
+            &Diagnostics::Private_identifiers_are_not_allowed_in_variable_declarations,
+        ));
+        let mut exclamation_token = None;
+        if allow_exclamation
+            && self.kind(name) == SyntaxKind::Identifier
+            && self.token() == SyntaxKind::ExclamationToken
+            && !self.scanner.has_preceding_line_break()
+        {
+            exclamation_token = Some(self.parse_token_node());
+        }
+        let _type = self.parse_type_annotation();
+        let initializer = if is_in_or_of_keyword(self.token()) {
+            None
+        } else {
+            self.parse_initializer()
+        };
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::VariableDeclaration,
+                NodeData::VariableLike {
+                    name,
+                    question_token: None,
+                    exclamation_token,
+                    _type,
+                    initializer,
+                },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_variable_declaration_list(&mut self, in_for_statement_initializer: bool) -> NodeId {
+        let pos = self.get_node_pos();
+
+        let flags = match self.token() {
+            SyntaxKind::VarKeyword => NodeFlags::NONE,
+            SyntaxKind::LetKeyword => NodeFlags::LET,
+            SyntaxKind::ConstKeyword => NodeFlags::CONST,
+            _ => unreachable!("a variable declaration list starts with var, let or const"),
+        };
+
+        self.next_token();
+
+        // The user may have written the following:
+        //
+        //    for (let of X) { }
+        //
+        // In this case, we want to parse an empty declaration list, and then parse 'of'
+        // as a keyword. The reason this is not automatic is that 'of' is a valid identifier.
+        // So we need to look ahead to determine if 'of' should be treated as a keyword in
+        // this context.
+        // The checker will then give an error that there is an empty declaration list.
+        let declarations;
+        if self.token() == SyntaxKind::OfKeyword
+            && self.look_ahead(Self::can_follow_contextual_of_keyword)
+        {
+            declarations = self.create_missing_list();
+        } else {
+            let saved_disallow_in = self.in_disallow_in_context();
+            self.set_disallow_in_context(in_for_statement_initializer);
+
+            declarations = if in_for_statement_initializer {
+                self.parse_delimited_list(
+                    ParsingContext::VariableDeclarations,
+                    Self::parse_variable_declaration_disallow_exclamation,
+                    false,
+                )
+            } else {
+                self.parse_delimited_list(
+                    ParsingContext::VariableDeclarations,
+                    Self::parse_variable_declaration_allow_exclamation,
+                    false,
+                )
+            };
+
+            self.set_disallow_in_context(saved_disallow_in);
+        }
+
+        let mut node = Node::new(
+            SyntaxKind::VariableDeclarationList,
+            NodeData::VariableDeclarationList { declarations },
+        );
+        node.flags |= flags;
+        self.finish_node(node, pos)
+    }
+
+    fn can_follow_contextual_of_keyword(&mut self) -> bool {
+        self.next_token_is_identifier() && self.next_token() == SyntaxKind::CloseParenToken
+    }
+
+    fn parse_variable_statement(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let declaration_list = self.parse_variable_declaration_list(false);
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::VariableStatement,
+            NodeData::VariableStatement { declaration_list },
+        );
+        node.modifiers = modifiers;
+        // Decorators are not allowed on a variable statement, so we keep track of them to report them in the grammar checker.
+        node.decorators = decorators;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_function_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let saved_await_context = self.in_await_context();
+        self.parse_expected(SyntaxKind::FunctionKeyword);
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        // We don't parse the name here in await context, instead we will report a grammar error in the checker.
+        let name = if self.has_modifier_of_kind(&modifiers, SyntaxKind::DefaultKeyword) {
+            self.parse_optional_binding_identifier()
+        } else {
+            Some(self.parse_binding_identifier(None))
+        };
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_async_modifier(&modifiers) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let type_parameters = self.parse_type_parameters();
+        if self.has_modifier_of_kind(&modifiers, SyntaxKind::ExportKeyword) {
+            self.set_await_context(true);
+        }
+        let parameters = self.parse_parameters(is_generator | is_async);
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+        let body = self.parse_function_block_or_semicolon(
+            is_generator | is_async,
+            Some(&Diagnostics::or_expected),
+        );
+        self.set_await_context(saved_await_context);
+        let mut node = Node::new(
+            SyntaxKind::FunctionDeclaration,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                asterisk_token,
+                name,
+                type_parameters,
+                parameters,
+                _type,
+                body,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_constructor_name(&mut self) -> bool {
+        if self.token() == SyntaxKind::ConstructorKeyword {
+            return self.parse_expected(SyntaxKind::ConstructorKeyword);
+        }
+        if self.token() == SyntaxKind::StringLiteral
+            && self.look_ahead(Self::next_token) == SyntaxKind::OpenParenToken
+        {
+            return self.try_parse_bool(|p| {
+                let literal_node = p.parse_literal_node();
+                matches!(&p.node(literal_node).data, NodeData::Literal { text, .. } if text == "constructor")
+            });
+        }
+        false
+    }
+
+    fn try_parse_constructor_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: &Option<NodeArray>,
+        modifiers: &Option<NodeArray>,
+    ) -> Option<NodeId> {
+        self.try_parse(|p| {
+            if !p.parse_constructor_name() {
+                return None;
+            }
+            let type_parameters = p.parse_type_parameters();
+            let parameters = p.parse_parameters(SignatureFlags::NONE);
+            let _type = p.parse_return_type(SyntaxKind::ColonToken, false);
+            let body = p.parse_function_block_or_semicolon(
+                SignatureFlags::NONE,
+                Some(&Diagnostics::or_expected),
+            );
+            // `typeParameters` and `type` are kept if they exist so that we can report them in the grammar checker.
+            let mut node = Node::new(
+                SyntaxKind::Constructor,
+                NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                    type_parameters,
+                    parameters,
+                    _type,
+                    body,
+                    ..SignatureDeclaration::default()
+                })),
+            );
+            node.decorators = decorators.clone();
+            node.modifiers = modifiers.clone();
+            let node = p.finish_node(node, pos);
+            Some(p.with_js_doc(node, has_js_doc))
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn parse_method_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        asterisk_token: Option<NodeId>,
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        diagnostic_message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        let is_generator = if asterisk_token.is_some() {
+            SignatureFlags::YIELD
+        } else {
+            SignatureFlags::NONE
+        };
+        let is_async = if self.has_async_modifier(&modifiers) {
+            SignatureFlags::AWAIT
+        } else {
+            SignatureFlags::NONE
+        };
+        let type_parameters = self.parse_type_parameters();
+        let parameters = self.parse_parameters(is_generator | is_async);
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+        let body =
+            self.parse_function_block_or_semicolon(is_generator | is_async, diagnostic_message);
+        // An exclamation token on a method is invalid syntax and will be handled by the grammar checker
+        let mut node = Node::new(
+            SyntaxKind::MethodDeclaration,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                asterisk_token,
+                name: Some(name),
+                question_token,
+                exclamation_token,
+                type_parameters,
+                parameters,
+                _type,
+                body,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_property_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        name: NodeId,
+        question_token: Option<NodeId>,
+    ) -> NodeId {
+        let exclamation_token =
+            if question_token.is_none() && !self.scanner.has_preceding_line_break() {
+                self.parse_optional_token(SyntaxKind::ExclamationToken)
+            } else {
+                None
+            };
+        let _type = self.parse_type_annotation();
+        let initializer = self.do_outside_of_context(
+            NodeFlags::YIELD_CONTEXT | NodeFlags::AWAIT_CONTEXT | NodeFlags::DISALLOW_IN_CONTEXT,
+            Self::parse_initializer,
+        );
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::PropertyDeclaration,
+            NodeData::VariableLike {
+                name,
+                question_token,
+                exclamation_token,
+                _type,
+                initializer,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_property_or_method_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let asterisk_token = self.parse_optional_token(SyntaxKind::AsteriskToken);
+        let name = self.parse_property_name();
+        // Note: this is not legal as per the grammar.  But we allow it in the parser and
+        // report an error in the grammar checker.
+        let question_token = self.parse_optional_token(SyntaxKind::QuestionToken);
+        if asterisk_token.is_some()
+            || self.token() == SyntaxKind::OpenParenToken
+            || self.token() == SyntaxKind::LessThanToken
+        {
+            return self.parse_method_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                asterisk_token,
+                name,
+                question_token,
+                None,
+                Some(&Diagnostics::or_expected),
+            );
+        }
+        self.parse_property_declaration(
+            pos,
+            has_js_doc,
+            decorators,
+            modifiers,
+            name,
+            question_token,
+        )
+    }
+
+    fn parse_accessor_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        kind: SyntaxKind,
+    ) -> NodeId {
+        let name = self.parse_property_name();
+        let type_parameters = self.parse_type_parameters();
+        let parameters = self.parse_parameters(SignatureFlags::NONE);
+        let _type = self.parse_return_type(SyntaxKind::ColonToken, false);
+        let body = self.parse_function_block_or_semicolon(SignatureFlags::NONE, None);
+        // `typeParameters` (for both) and `type` (for setters) are kept if they were parsed, those indicate grammar errors
+        let mut node = Node::new(
+            kind,
+            NodeData::SignatureDeclaration(Box::new(SignatureDeclaration {
+                name: Some(name),
+                type_parameters,
+                parameters,
+                _type,
+                body,
+                ..SignatureDeclaration::default()
+            })),
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn is_class_member_start(&mut self) -> bool {
+        let mut id_token = None;
+
+        if self.token() == SyntaxKind::AtToken {
+            return true;
+        }
+
+        // Eat up all modifiers, but hold on to the last one in case it is actually an identifier.
+        while is_modifier_kind(self.token()) {
+            id_token = Some(self.token());
+            // If the idToken is a class modifier (protected, private, public, and static), it is
+            // certain that we are starting to parse class member. This allows better error recovery
+            // Example:
+            //      public foo() ...     // true
+            //      public @dec blah ... // true; we will then report an error later
+            //      export public ...    // true; we will then report an error later
+            if is_class_member_modifier(self.token()) {
+                return true;
+            }
+
+            self.next_token();
+        }
+
+        if self.token() == SyntaxKind::AsteriskToken {
+            return true;
+        }
+
+        // Try to get the first property-like token following all modifiers.
+        // This can either be an identifier or the 'get' or 'set' keywords.
+        if self.is_literal_property_name() {
+            id_token = Some(self.token());
+            self.next_token();
+        }
+
+        // Index signatures and computed properties are class members; we can parse.
+        if self.token() == SyntaxKind::OpenBracketToken {
+            return true;
+        }
+
+        // If we were able to get any potential identifier...
+        if let Some(id_token) = id_token {
+            // If we have a non-keyword identifier, or if we have an accessor, then it's safe to parse.
+            if !is_keyword(id_token)
+                || id_token == SyntaxKind::SetKeyword
+                || id_token == SyntaxKind::GetKeyword
+            {
+                return true;
+            }
+
+            // If it *is* a keyword, but not an accessor, check a little farther along
+            // to see if it should actually be parsed as a class member.
+            return match self.token() {
+                SyntaxKind::OpenParenToken      // Method declaration
+                | SyntaxKind::LessThanToken     // Generic Method declaration
+                | SyntaxKind::ExclamationToken  // Non-null assertion on property name
+                | SyntaxKind::ColonToken        // Type Annotation for declaration
+                | SyntaxKind::EqualsToken       // Initializer for declaration
+                | SyntaxKind::QuestionToken => true, // Not valid, but permitted so that it gets caught later on.
+                // Covers
+                //  - Semicolons     (declaration termination)
+                //  - Closing braces (end-of-class, must be declaration)
+                //  - End-of-files   (not valid, but permitted so that it gets caught later on)
+                //  - Line-breaks    (enabling *automatic semicolon insertion*)
+                _ => self.can_parse_semicolon(),
+            };
+        }
+
+        false
+    }
+
+    fn parse_decorator_expression(&mut self) -> NodeId {
+        if self.in_await_context() && self.token() == SyntaxKind::AwaitKeyword {
+            // `@await` is is disallowed in an [Await] context, but can cause parsing to go off the rails
+            // This simply parses the missing identifier and moves on.
+            let pos = self.get_node_pos();
+            let await_expression =
+                self.parse_identifier(Some(&Diagnostics::Expression_expected), None);
+            self.next_token();
+            let member_expression = self.parse_member_expression_rest(pos, await_expression, true);
+            return self.parse_call_expression_rest(pos, member_expression);
+        }
+        self.parse_left_hand_side_expression_or_higher()
+    }
+
+    fn try_parse_decorator(&mut self) -> Option<NodeId> {
+        let pos = self.get_node_pos();
+        if !self.parse_optional(SyntaxKind::AtToken) {
+            return None;
+        }
+        let expression = self.do_in_decorator_context(Self::parse_decorator_expression);
+        Some(self.finish_node(
+            Node::new(SyntaxKind::Decorator, NodeData::Expression { expression }),
+            pos,
+        ))
+    }
+
+    fn parse_decorators(&mut self) -> Option<NodeArray> {
+        let pos = self.get_node_pos();
+        let mut list = Vec::new();
+        while let Some(decorator) = self.try_parse_decorator() {
+            list.push(decorator);
+        }
+        if list.is_empty() {
+            None
+        } else {
+            Some(self.create_node_array(list, pos, None, false))
+        }
+    }
+
+    fn try_parse_modifier(&mut self, permit_invalid_const_as_modifier: bool) -> Option<NodeId> {
+        let pos = self.get_node_pos();
+        let kind = self.token();
+
+        if self.token() == SyntaxKind::ConstKeyword && permit_invalid_const_as_modifier {
+            // We need to ensure that any subsequent modifiers appear on the same line
+            // so that when 'const' is a standalone declaration, we don't issue an error.
+            if !self.try_parse_bool(Self::next_token_is_on_same_line_and_can_follow_modifier) {
+                return None;
+            }
+        } else if !self.parse_any_contextual_modifier() {
+            return None;
+        }
+
+        Some(self.finish_node(Node::new(kind, NodeData::Token), pos))
+    }
+
+    /*
+     * There are situations in which a modifier like 'const' will appear unexpectedly, such as on a class member.
+     * In those situations, if we are entirely sure that 'const' is not valid on its own (such as when ASI takes effect
+     * and turns it into a standalone declaration), then it is better to parse it and report an error later.
+     *
+     * In such situations, 'permitInvalidConstAsModifier' should be set to true.
+     */
+    fn parse_modifiers(&mut self, permit_invalid_const_as_modifier: bool) -> Option<NodeArray> {
+        let pos = self.get_node_pos();
+        let mut list = Vec::new();
+        while let Some(modifier) = self.try_parse_modifier(permit_invalid_const_as_modifier) {
+            list.push(modifier);
+        }
+        if list.is_empty() {
+            None
+        } else {
+            Some(self.create_node_array(list, pos, None, false))
+        }
+    }
+
+    fn parse_modifiers_for_arrow_function(&mut self) -> Option<NodeArray> {
+        if self.token() != SyntaxKind::AsyncKeyword {
+            return None;
+        }
+        let pos = self.get_node_pos();
+        self.next_token();
+        let modifier = self.finish_node(Node::new(SyntaxKind::AsyncKeyword, NodeData::Token), pos);
+        Some(self.create_node_array(vec![modifier], pos, None, false))
+    }
+
+    /// Do not rely on parent pointers inside the parser; only the modifiers at hand are inspected.
+    fn has_modifier_of_kind(&self, modifiers: &Option<NodeArray>, kind: SyntaxKind) -> bool {
+        modifiers.as_ref().is_some_and(|modifiers| {
+            modifiers
+                .iter()
+                .any(|modifier| self.kind(*modifier) == kind)
+        })
+    }
+
+    fn parse_class_element(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        if self.token() == SyntaxKind::SemicolonToken {
+            self.next_token();
+            return self.finish_node(
+                Node::new(SyntaxKind::SemicolonClassElement, NodeData::Token),
+                pos,
+            );
+        }
+
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let decorators = self.parse_decorators();
+        let modifiers = self.parse_modifiers(true);
+
+        if self.parse_contextual_modifier(SyntaxKind::GetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                SyntaxKind::GetAccessor,
+            );
+        }
+
+        if self.parse_contextual_modifier(SyntaxKind::SetKeyword) {
+            return self.parse_accessor_declaration(
+                pos,
+                has_js_doc,
+                decorators,
+                modifiers,
+                SyntaxKind::SetAccessor,
+            );
+        }
+
+        if self.token() == SyntaxKind::ConstructorKeyword
+            || self.token() == SyntaxKind::StringLiteral
+        {
+            if let Some(constructor_declaration) =
+                self.try_parse_constructor_declaration(pos, has_js_doc, &decorators, &modifiers)
+            {
+                return constructor_declaration;
+            }
+        }
+
+        if self.is_index_signature() {
+            return self.parse_index_signature_declaration(pos, has_js_doc, decorators, modifiers);
+        }
+
+        // It is very important that we check this *after* checking indexers because
+        // the [ token can start an index signature or a computed property name
+        if token_is_identifier_or_keyword(self.token())
+            || self.token() == SyntaxKind::StringLiteral
+            || self.token() == SyntaxKind::NumericLiteral
+            || self.token() == SyntaxKind::AsteriskToken
+            || self.token() == SyntaxKind::OpenBracketToken
+        {
+            let is_ambient = self.has_modifier_of_kind(&modifiers, SyntaxKind::DeclareKeyword);
+            if is_ambient {
+                for &modifier in modifiers.iter().flat_map(|modifiers| modifiers.iter()) {
+                    self.node_mut(modifier).flags |= NodeFlags::AMBIENT;
+                }
+                return self.do_inside_of_context(NodeFlags::AMBIENT, |p| {
+                    p.parse_property_or_method_declaration(pos, has_js_doc, decorators, modifiers)
+                });
+            } else {
+                return self
+                    .parse_property_or_method_declaration(pos, has_js_doc, decorators, modifiers);
+            }
+        }
+
+        if decorators.is_some() || modifiers.is_some() {
+            // treat this as a property declaration with a missing name.
+            let name = self.create_missing_node(
+                SyntaxKind::Identifier,
+                true,
+                Some(&Diagnostics::Declaration_expected),
+                &[],
+            );
+            return self
+                .parse_property_declaration(pos, has_js_doc, decorators, modifiers, name, None);
+        }
+
+        // 'isClassMemberStart' should have hinted not to attempt parsing.
+        unreachable!("Should not have attempted to parse class member declaration.")
+    }
+
+    fn parse_class_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        self.parse_class_declaration_or_expression(
+            pos,
+            has_js_doc,
+            None,
+            None,
+            SyntaxKind::ClassExpression,
+        )
+    }
+
+    fn parse_class_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_class_declaration_or_expression(
+            pos,
+            has_js_doc,
+            decorators,
+            modifiers,
+            SyntaxKind::ClassDeclaration,
+        )
+    }
+
+    fn parse_class_declaration_or_expression(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        kind: SyntaxKind,
+    ) -> NodeId {
+        let saved_await_context = self.in_await_context();
+        self.parse_expected(SyntaxKind::ClassKeyword);
+        // We don't parse the name here in await context, instead we will report a grammar error in the checker.
+        let name = self.parse_name_of_class_declaration_or_expression();
+        let type_parameters = self.parse_type_parameters();
+        if self.has_modifier_of_kind(&modifiers, SyntaxKind::ExportKeyword) {
+            self.set_await_context(true);
+        }
+        let heritage_clauses = self.parse_heritage_clauses();
+
+        let members;
+        if self.parse_expected(SyntaxKind::OpenBraceToken) {
+            // ClassTail[Yield,Await] : (Modified) See 14.5
+            //      ClassHeritage[?Yield,?Await]opt { ClassBody[?Yield,?Await]opt }
+            members = self.parse_class_members();
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+        } else {
+            members = self.create_missing_list();
+        }
+        self.set_await_context(saved_await_context);
+        let mut node = Node::new(
+            kind,
+            NodeData::ClassLikeDeclaration {
+                name,
+                type_parameters,
+                heritage_clauses,
+                members,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_name_of_class_declaration_or_expression(&mut self) -> Option<NodeId> {
+        // implements is a future reserved word so
+        // 'class implements' might mean either
+        // - class expression with omitted name, 'implements' starts heritage clause
+        // - class with name 'implements'
+        // 'isImplementsClause' helps to disambiguate between these two cases
+        if self.is_binding_identifier() && !self.is_implements_clause() {
+            let is_binding_identifier = self.is_binding_identifier();
+            Some(self.create_identifier(is_binding_identifier, None, None))
+        } else {
+            None
+        }
+    }
+
+    fn is_implements_clause(&mut self) -> bool {
+        self.token() == SyntaxKind::ImplementsKeyword
+            && self.look_ahead(Self::next_token_is_identifier_or_keyword)
+    }
+
+    fn parse_heritage_clauses(&mut self) -> Option<NodeArray> {
+        // ClassTail[Yield,Await] : (Modified) See 14.5
+        //      ClassHeritage[?Yield,?Await]opt { ClassBody[?Yield,?Await]opt }
+
+        if self.is_heritage_clause() {
+            return Some(
+                self.parse_list(ParsingContext::HeritageClauses, Self::parse_heritage_clause),
+            );
+        }
+
+        None
+    }
+
+    fn parse_heritage_clause(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let tok = self.token();
+        debug_assert!(tok == SyntaxKind::ExtendsKeyword || tok == SyntaxKind::ImplementsKeyword); // isListElement() should ensure this.
+        self.next_token();
+        let types = self.parse_delimited_list(
+            ParsingContext::HeritageClauseElement,
+            Self::parse_expression_with_type_arguments,
+            false,
+        );
+        self.finish_node(
+            Node::new(
+                SyntaxKind::HeritageClause,
+                NodeData::HeritageClause { token: tok, types },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_expression_with_type_arguments(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let expression = self.parse_left_hand_side_expression_or_higher();
+        let type_arguments = self.try_parse_type_arguments();
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ExpressionWithTypeArguments,
+                NodeData::ExpressionWithTypeArguments {
+                    expression,
+                    type_arguments,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn try_parse_type_arguments(&mut self) -> Option<NodeArray> {
+        if self.token() == SyntaxKind::LessThanToken {
+            Some(self.parse_bracketed_list(
+                ParsingContext::TypeArguments,
+                Self::parse_type,
+                SyntaxKind::LessThanToken,
+                SyntaxKind::GreaterThanToken,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn is_heritage_clause(&self) -> bool {
+        self.token() == SyntaxKind::ExtendsKeyword || self.token() == SyntaxKind::ImplementsKeyword
+    }
+
+    fn parse_class_members(&mut self) -> NodeArray {
+        self.parse_list(ParsingContext::ClassMembers, Self::parse_class_element)
+    }
+
+    fn parse_interface_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::InterfaceKeyword);
+        let name = self.parse_identifier(None, None);
+        let type_parameters = self.parse_type_parameters();
+        let heritage_clauses = self.parse_heritage_clauses();
+        let members = self.parse_object_type_members();
+        let mut node = Node::new(
+            SyntaxKind::InterfaceDeclaration,
+            NodeData::InterfaceDeclaration {
+                name,
+                type_parameters,
+                heritage_clauses,
+                members,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_type_alias_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::TypeKeyword);
+        let name = self.parse_identifier(None, None);
+        let type_parameters = self.parse_type_parameters();
+        self.parse_expected(SyntaxKind::EqualsToken);
+        let intrinsic = if self.token() == SyntaxKind::IntrinsicKeyword {
+            self.try_parse(Self::parse_keyword_and_no_dot)
+        } else {
+            None
+        };
+        let _type = match intrinsic {
+            Some(intrinsic) => intrinsic,
+            None => self.parse_type(),
+        };
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::TypeAliasDeclaration,
+            NodeData::TypeAliasDeclaration {
+                name,
+                type_parameters,
+                _type,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    // In an ambient declaration, the grammar only allows integer literals as initializers.
+    // In a non-ambient declaration, the grammar allows uninitialized members only in a
+    // ConstantEnumMemberSection, which starts at the beginning of an enum declaration
+    // or any time an integer literal initializer is encountered.
+    fn parse_enum_member(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_js_doc = self.has_preceding_js_doc_comment();
+        let name = self.parse_property_name();
+        let initializer = self.allow_in_and(Self::parse_initializer);
+        let node = self.finish_node(
+            Node::new(
+                SyntaxKind::EnumMember,
+                NodeData::EnumMember { name, initializer },
+            ),
+            pos,
+        );
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_enum_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::EnumKeyword);
+        let name = self.parse_identifier(None, None);
+        let members;
+        if self.parse_expected(SyntaxKind::OpenBraceToken) {
+            members = self.do_outside_of_yield_and_await_context(|p| {
+                p.parse_delimited_list(ParsingContext::EnumMembers, Self::parse_enum_member, false)
+            });
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+        } else {
+            members = self.create_missing_list();
+        }
+        let mut node = Node::new(
+            SyntaxKind::EnumDeclaration,
+            NodeData::EnumDeclaration { name, members },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_module_block(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let statements;
+        if self.parse_expected(SyntaxKind::OpenBraceToken) {
+            statements = self.parse_list(ParsingContext::BlockStatements, Self::parse_statement);
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+        } else {
+            statements = self.create_missing_list();
+        }
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ModuleBlock,
+                NodeData::Block {
+                    statements,
+                    multi_line: false,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_module_or_namespace_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        flags: u32,
+    ) -> NodeId {
+        // If we are parsing a dotted namespace name, we want to
+        // propagate the 'Namespace' flag across the names if set.
+        let namespace_flag = flags & NodeFlags::NAMESPACE;
+        let name = self.parse_identifier(None, None);
+        let body = if self.parse_optional(SyntaxKind::DotToken) {
+            let body_pos = self.get_node_pos();
+            self.parse_module_or_namespace_declaration(
+                body_pos,
+                false,
+                None,
+                None,
+                NodeFlags::NESTED_NAMESPACE | namespace_flag,
+            )
+        } else {
+            self.parse_module_block()
+        };
+        let mut node = Node::new(
+            SyntaxKind::ModuleDeclaration,
+            NodeData::ModuleDeclaration {
+                name,
+                body: Some(body),
+            },
+        );
+        node.flags |= flags;
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_ambient_external_module_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let mut flags = NodeFlags::NONE;
+        let name;
+        if self.token() == SyntaxKind::GlobalKeyword {
+            // parse 'global' as name of global scope augmentation
+            name = self.parse_identifier(None, None);
+            flags |= NodeFlags::GLOBAL_AUGMENTATION;
+        } else {
+            name = self.parse_literal_node();
+            self.intern_literal_text(name);
+        }
+        let mut body = None;
+        if self.token() == SyntaxKind::OpenBraceToken {
+            body = Some(self.parse_module_block());
+        } else {
+            self.parse_semicolon();
+        }
+        let mut node = Node::new(
+            SyntaxKind::ModuleDeclaration,
+            NodeData::ModuleDeclaration { name, body },
+        );
+        node.flags |= flags;
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_module_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let mut flags = NodeFlags::NONE;
+        if self.token() == SyntaxKind::GlobalKeyword {
+            // global augmentation
+            return self
+                .parse_ambient_external_module_declaration(pos, has_js_doc, decorators, modifiers);
+        } else if self.parse_optional(SyntaxKind::NamespaceKeyword) {
+            flags |= NodeFlags::NAMESPACE;
+        } else {
+            self.parse_expected(SyntaxKind::ModuleKeyword);
+            if self.token() == SyntaxKind::StringLiteral {
+                return self.parse_ambient_external_module_declaration(
+                    pos, has_js_doc, decorators, modifiers,
+                );
+            }
+        }
+        self.parse_module_or_namespace_declaration(pos, has_js_doc, decorators, modifiers, flags)
+    }
+
+    fn is_external_module_reference(&mut self) -> bool {
+        self.token() == SyntaxKind::RequireKeyword
+            && self.look_ahead(Self::next_token_is_open_paren)
+    }
+
+    fn next_token_is_open_paren(&mut self) -> bool {
+        self.next_token() == SyntaxKind::OpenParenToken
+    }
+
+    fn next_token_is_slash(&mut self) -> bool {
+        self.next_token() == SyntaxKind::SlashToken
+    }
+
+    fn parse_namespace_export_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::AsKeyword);
+        self.parse_expected(SyntaxKind::NamespaceKeyword);
+        let name = self.parse_identifier(None, None);
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::NamespaceExportDeclaration,
+            NodeData::NamespaceImportOrExport { name },
+        );
+        // NamespaceExportDeclaration nodes cannot have decorators or modifiers, so we attach them here so we can report them in the grammar checker
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_import_declaration_or_import_equals_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::ImportKeyword);
+
+        let after_import_pos = self.scanner.get_start_pos();
+
+        // We don't parse the identifier here in await context, instead we will report a grammar error in the checker.
+        let mut identifier = None;
+        if self.is_identifier() {
+            identifier = Some(self.parse_identifier(None, None));
+        }
+
+        let mut is_type_only = false;
+        if self.token() != SyntaxKind::FromKeyword
+            && identifier.is_some_and(|identifier| self.identifier_text(identifier) == "type")
+            && (self.is_identifier()
+                || self.token_after_import_definitely_produces_import_declaration())
+        {
+            is_type_only = true;
+            identifier = if self.is_identifier() {
+                Some(self.parse_identifier(None, None))
+            } else {
+                None
+            };
+        }
+
+        if let Some(identifier) = identifier {
+            if !self.token_after_imported_identifier_definitely_produces_import_declaration() {
+                return self.parse_import_equals_declaration(
+                    pos,
+                    has_js_doc,
+                    decorators,
+                    modifiers,
+                    identifier,
+                    is_type_only,
+                );
+            }
+        }
+
+        // ImportDeclaration:
+        //  import ImportClause from ModuleSpecifier ;
+        //  import ModuleSpecifier;
+        let mut import_clause = None;
+        if identifier.is_some() // import id
+            || self.token() == SyntaxKind::AsteriskToken // import *
+            || self.token() == SyntaxKind::OpenBraceToken
+        // import {
+        {
+            import_clause =
+                Some(self.parse_import_clause(identifier, after_import_pos, is_type_only));
+            self.parse_expected(SyntaxKind::FromKeyword);
+        }
+
+        let module_specifier = self.parse_module_specifier();
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::ImportDeclaration,
+            NodeData::ImportDeclaration {
+                import_clause,
+                module_specifier,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn token_after_import_definitely_produces_import_declaration(&self) -> bool {
+        self.token() == SyntaxKind::AsteriskToken || self.token() == SyntaxKind::OpenBraceToken
+    }
+
+    fn token_after_imported_identifier_definitely_produces_import_declaration(&self) -> bool {
+        // In `import id ___`, the current token decides whether to produce
+        // an ImportDeclaration or ImportEqualsDeclaration.
+        self.token() == SyntaxKind::CommaToken || self.token() == SyntaxKind::FromKeyword
+    }
+
+    fn parse_import_equals_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+        identifier: NodeId,
+        is_type_only: bool,
+    ) -> NodeId {
+        self.parse_expected(SyntaxKind::EqualsToken);
+        let module_reference = self.parse_module_reference();
+        self.parse_semicolon();
+        let mut node = Node::new(
+            SyntaxKind::ImportEqualsDeclaration,
+            NodeData::ImportEqualsDeclaration {
+                is_type_only,
+                name: identifier,
+                module_reference,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_import_clause(
+        &mut self,
+        identifier: Option<NodeId>,
+        pos: usize,
+        is_type_only: bool,
+    ) -> NodeId {
+        // ImportClause:
+        //  ImportedDefaultBinding
+        //  NameSpaceImport
+        //  NamedImports
+        //  ImportedDefaultBinding, NameSpaceImport
+        //  ImportedDefaultBinding, NamedImports
+
+        // If there was no default import or if there is comma token after default import
+        // parse namespace or named imports
+        let mut named_bindings = None;
+        if identifier.is_none() || self.parse_optional(SyntaxKind::CommaToken) {
+            named_bindings = Some(if self.token() == SyntaxKind::AsteriskToken {
+                self.parse_namespace_import()
+            } else {
+                self.parse_named_imports_or_exports(SyntaxKind::NamedImports)
+            });
+        }
+
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ImportClause,
+                NodeData::ImportClause {
+                    is_type_only,
+                    name: identifier,
+                    named_bindings,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_module_reference(&mut self) -> NodeId {
+        if self.is_external_module_reference() {
+            self.parse_external_module_reference()
+        } else {
+            self.parse_entity_name(false, None)
+        }
+    }
+
+    fn parse_external_module_reference(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::RequireKeyword);
+        self.parse_expected(SyntaxKind::OpenParenToken);
+        let expression = self.parse_module_specifier();
+        self.parse_expected(SyntaxKind::CloseParenToken);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::ExternalModuleReference,
+                NodeData::Expression { expression },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_module_specifier(&mut self) -> NodeId {
+        if self.token() == SyntaxKind::StringLiteral {
+            let result = self.parse_literal_node();
+            self.intern_literal_text(result)
+        } else {
+            // We allow arbitrary expressions here, even though the grammar only allows string
+            // literals.  We check to ensure that it is only a string literal later in the grammar
+            // check pass.
+            self.parse_expression()
+        }
+    }
+
+    fn parse_namespace_import(&mut self) -> NodeId {
+        // NameSpaceImport:
+        //  * as ImportedBinding
+        let pos = self.get_node_pos();
+        self.parse_expected(SyntaxKind::AsteriskToken);
+        self.parse_expected(SyntaxKind::AsKeyword);
+        let name = self.parse_identifier(None, None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::NamespaceImport,
+                NodeData::NamespaceImportOrExport { name },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_named_imports_or_exports(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+
+        // NamedImports:
+        //  { }
+        //  { ImportsList }
+        //  { ImportsList, }
+
+        // ImportsList:
+        //  ImportSpecifier
+        //  ImportsList, ImportSpecifier
+        let elements = if kind == SyntaxKind::NamedImports {
+            self.parse_bracketed_list(
+                ParsingContext::ImportOrExportSpecifiers,
+                Self::parse_import_specifier,
+                SyntaxKind::OpenBraceToken,
+                SyntaxKind::CloseBraceToken,
+            )
+        } else {
+            self.parse_bracketed_list(
+                ParsingContext::ImportOrExportSpecifiers,
+                Self::parse_export_specifier,
+                SyntaxKind::OpenBraceToken,
+                SyntaxKind::CloseBraceToken,
+            )
+        };
+        self.finish_node(
+            Node::new(kind, NodeData::NamedImportsOrExports { elements }),
+            pos,
+        )
+    }
+
+    fn parse_export_specifier(&mut self) -> NodeId {
+        self.parse_import_or_export_specifier(SyntaxKind::ExportSpecifier)
+    }
+
+    fn parse_import_specifier(&mut self) -> NodeId {
+        self.parse_import_or_export_specifier(SyntaxKind::ImportSpecifier)
+    }
+
+    fn parse_import_or_export_specifier(&mut self, kind: SyntaxKind) -> NodeId {
+        let pos = self.get_node_pos();
+        // ImportSpecifier:
+        //   BindingIdentifier
+        //   IdentifierName as BindingIdentifier
+        // ExportSpecifier:
+        //   IdentifierName
+        //   IdentifierName as IdentifierName
+        let mut check_identifier_is_keyword = is_keyword(self.token()) && !self.is_identifier();
+        let mut check_identifier_start = self.scanner.get_token_pos();
+        let mut check_identifier_end = self.scanner.get_text_pos();
+        let identifier_name = self.parse_identifier_name(None);
+        let mut property_name = None;
+        let name;
+        if self.token() == SyntaxKind::AsKeyword {
+            property_name = Some(identifier_name);
+            self.parse_expected(SyntaxKind::AsKeyword);
+            check_identifier_is_keyword = is_keyword(self.token()) && !self.is_identifier();
+            check_identifier_start = self.scanner.get_token_pos();
+            check_identifier_end = self.scanner.get_text_pos();
+            name = self.parse_identifier_name(None);
+        } else {
+            name = identifier_name;
+        }
+        if kind == SyntaxKind::ImportSpecifier && check_identifier_is_keyword {
+            self.parse_error_at(
+                check_identifier_start,
+                check_identifier_end,
+                &Diagnostics::Identifier_expected,
+                &[],
+            );
+        }
+        self.finish_node(
+            Node::new(
+                kind,
+                NodeData::ImportOrExportSpecifier {
+                    property_name,
+                    name,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_namespace_export(&mut self, pos: usize) -> NodeId {
+        let name = self.parse_identifier_name(None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::NamespaceExport,
+                NodeData::NamespaceImportOrExport { name },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_export_declaration(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let saved_await_context = self.in_await_context();
+        self.set_await_context(true);
+        let mut export_clause = None;
+        let mut module_specifier = None;
+        let is_type_only = self.parse_optional(SyntaxKind::TypeKeyword);
+        let namespace_export_pos = self.get_node_pos();
+        if self.parse_optional(SyntaxKind::AsteriskToken) {
+            if self.parse_optional(SyntaxKind::AsKeyword) {
+                export_clause = Some(self.parse_namespace_export(namespace_export_pos));
+            }
+            self.parse_expected(SyntaxKind::FromKeyword);
+            module_specifier = Some(self.parse_module_specifier());
+        } else {
+            export_clause = Some(self.parse_named_imports_or_exports(SyntaxKind::NamedExports));
+            // It is not uncommon to accidentally omit the 'from' keyword. Additionally, in editing scenarios,
+            // the 'from' keyword can be parsed as a named export when the export clause is unterminated (i.e. `export { from "moduleName";`)
+            // If we don't have a 'from' keyword, see if we have a string literal such that ASI won't take effect.
+            if self.token() == SyntaxKind::FromKeyword
+                || (self.token() == SyntaxKind::StringLiteral
+                    && !self.scanner.has_preceding_line_break())
+            {
+                self.parse_expected(SyntaxKind::FromKeyword);
+                module_specifier = Some(self.parse_module_specifier());
+            }
+        }
+        self.parse_semicolon();
+        self.set_await_context(saved_await_context);
+        let mut node = Node::new(
+            SyntaxKind::ExportDeclaration,
+            NodeData::ExportDeclaration {
+                is_type_only,
+                export_clause,
+                module_specifier,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+
+    fn parse_export_assignment(
+        &mut self,
+        pos: usize,
+        has_js_doc: bool,
+        decorators: Option<NodeArray>,
+        modifiers: Option<NodeArray>,
+    ) -> NodeId {
+        let saved_await_context = self.in_await_context();
+        self.set_await_context(true);
+        let mut is_export_equals = false;
+        if self.parse_optional(SyntaxKind::EqualsToken) {
+            is_export_equals = true;
+        } else {
+            self.parse_expected(SyntaxKind::DefaultKeyword);
+        }
+        let expression = self.parse_assignment_expression_or_higher();
+        self.parse_semicolon();
+        self.set_await_context(saved_await_context);
+        let mut node = Node::new(
+            SyntaxKind::ExportAssignment,
+            NodeData::ExportAssignment {
+                is_export_equals,
+                expression,
+            },
+        );
+        node.decorators = decorators;
+        node.modifiers = modifiers;
+        let node = self.finish_node(node, pos);
+        self.with_js_doc(node, has_js_doc)
+    }
+}
+
+// The callback `for_each_child` calls with the arrays of children, like `cbNodes`.
+type NodeArrayCallback<'a, T> = &'a mut dyn FnMut(&NodeArray) -> Option<T>;
+
+struct ChildVisitor<'a, T> {
+    cb_node: &'a mut dyn FnMut(NodeId) -> Option<T>,
+    cb_nodes: Option<NodeArrayCallback<'a, T>>,
+}
+
+impl<'a, T> ChildVisitor<'a, T> {
+    fn node(&mut self, node: Option<NodeId>) -> Option<T> {
+        node.and_then(|node| (self.cb_node)(node))
+    }
+
+    fn nodes(&mut self, nodes: Option<&NodeArray>) -> Option<T> {
+        let nodes = nodes?;
+        if let Some(cb_nodes) = self.cb_nodes.as_mut() {
+            return cb_nodes(nodes);
+        }
+        nodes.iter().find_map(|node| (self.cb_node)(*node))
+    }
+
+    fn each(&mut self, nodes: Option<&Vec<NodeId>>) -> Option<T> {
+        nodes?.iter().find_map(|node| (self.cb_node)(*node))
+    }
+
+    fn comment(&mut self, comment: &Option<JSDocCommentText>) -> Option<T> {
+        match comment {
+            Some(JSDocCommentText::Nodes(nodes)) => self.nodes(Some(nodes)),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! visit {
+    ($e:expr) => {
+        if let Some(result) = $e {
+            return Some(result);
+        }
+    };
+}
+
+/// Invokes a callback for each child of the given node. The `cb_node` callback is invoked for all
+/// child nodes stored in properties. If a `cb_nodes` callback is specified, it is invoked for
+/// embedded arrays; otherwise, embedded arrays are flattened and the `cb_node` callback is invoked
+/// for each element. If a callback returns `Some`, iteration stops and that value is returned.
+pub fn for_each_child<'a, T>(
+    node: &Node,
+    cb_node: &'a mut dyn FnMut(NodeId) -> Option<T>,
+    cb_nodes: Option<NodeArrayCallback<'a, T>>,
+) -> Option<T> {
+    let mut v = ChildVisitor { cb_node, cb_nodes };
+    match node.kind {
+        // NamespaceExportDeclaration nodes only keep their decorators and modifiers for the grammar
+        // checker, and a MissingDeclaration only visits its decorators.
+        SyntaxKind::NamespaceExportDeclaration => {}
+        SyntaxKind::MissingDeclaration => return v.nodes(node.decorators.as_ref()),
+        _ => {
+            visit!(v.nodes(node.decorators.as_ref()));
+            visit!(v.nodes(node.modifiers.as_ref()));
+        }
+    }
+
+    match &node.data {
+        NodeData::Token
+        | NodeData::Identifier { .. }
+        | NodeData::PrivateIdentifier { .. }
+        | NodeData::Literal { .. }
+        | NodeData::JsxText { .. }
+        | NodeData::JSDocText { .. } => None,
+        NodeData::QualifiedName { left, right, .. } => {
+            visit!(v.node(Some(*left)));
+            v.node(Some(*right))
+        }
+        NodeData::Expression { expression } => v.node(Some(*expression)),
+        NodeData::TypeParameter {
+            name,
+            constraint,
+            default,
+            expression,
+        } => {
+            visit!(v.node(Some(*name)));
+            visit!(v.node(*constraint));
+            visit!(v.node(*default));
+            v.node(*expression)
+        }
+        NodeData::Parameter {
+            dot_dot_dot_token,
+            name,
+            question_token,
+            _type,
+            initializer,
+        } => {
+            visit!(v.node(*dot_dot_dot_token));
+            visit!(v.node(*name));
+            visit!(v.node(*question_token));
+            visit!(v.node(*_type));
+            v.node(*initializer)
+        }
+        NodeData::VariableLike {
+            name,
+            question_token,
+            exclamation_token,
+            _type,
+            initializer,
+        } => {
+            visit!(v.node(Some(*name)));
+            visit!(v.node(*question_token));
+            // The exclamation token of a property assignment is only kept for the grammar checker.
+            if node.kind != SyntaxKind::PropertyAssignment {
+                visit!(v.node(*exclamation_token));
+            }
+            visit!(v.node(*_type));
+            v.node(*initializer)
+        }
+        NodeData::ShorthandPropertyAssignment {
+            name,
+            question_token,
+            exclamation_token,
+            equals_token,
+            object_assignment_initializer,
+        } => {
+            visit!(v.node(Some(*name)));
+            visit!(v.node(*question_token));
+            visit!(v.node(*exclamation_token));
+            visit!(v.node(*equals_token));
+            v.node(*object_assignment_initializer)
+        }
+        NodeData::BindingElement {
+            dot_dot_dot_token,
+            property_name,
+            name,
+            initializer,
+        } => {
+            visit!(v.node(*dot_dot_dot_token));
+            visit!(v.node(*property_name));
+            visit!(v.node(Some(*name)));
+            v.node(*initializer)
+        }
+        NodeData::SignatureDeclaration(signature) => {
+            visit!(v.node(signature.asterisk_token));
+            visit!(v.node(signature.name));
+            visit!(v.node(signature.question_token));
+            visit!(v.node(signature.exclamation_token));
+            visit!(v.nodes(signature.type_parameters.as_ref()));
+            visit!(v.nodes(Some(&signature.parameters)));
+            visit!(v.node(signature._type));
+            visit!(v.node(signature.equals_greater_than_token));
+            v.node(signature.body)
+        }
+        NodeData::TypeReference {
+            type_name,
+            type_arguments,
+        } => {
+            visit!(v.node(Some(*type_name)));
+            v.nodes(type_arguments.as_ref())
+        }
+        NodeData::TypePredicate {
+            asserts_modifier,
+            parameter_name,
+            _type,
+        } => {
+            visit!(v.node(*asserts_modifier));
+            visit!(v.node(Some(*parameter_name)));
+            v.node(*_type)
+        }
+        NodeData::TypeQuery { expr_name } => v.node(Some(*expr_name)),
+        NodeData::TypeLiteral { members } => v.nodes(Some(members)),
+        NodeData::ArrayType { element_type } => v.node(Some(*element_type)),
+        NodeData::TupleType { elements } => v.nodes(Some(elements)),
+        NodeData::UnionOrIntersectionType { types } => v.nodes(Some(types)),
+        NodeData::ConditionalType {
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+        } => {
+            visit!(v.node(Some(*check_type)));
+            visit!(v.node(Some(*extends_type)));
+            visit!(v.node(Some(*true_type)));
+            v.node(Some(*false_type))
+        }
+        NodeData::InferType { type_parameter } => v.node(Some(*type_parameter)),
+        NodeData::ImportType {
+            argument,
+            qualifier,
+            type_arguments,
+            ..
+        } => {
+            visit!(v.node(Some(*argument)));
+            visit!(v.node(*qualifier));
+            v.nodes(type_arguments.as_ref())
+        }
+        NodeData::TypeOperator { _type, .. } => v.node(Some(*_type)),
+        NodeData::Type { _type } => v.node(*_type),
+        NodeData::IndexedAccessType {
+            object_type,
+            index_type,
+        } => {
+            visit!(v.node(Some(*object_type)));
+            v.node(Some(*index_type))
+        }
+        NodeData::MappedType {
+            readonly_token,
+            type_parameter,
+            name_type,
+            question_token,
+            _type,
+        } => {
+            visit!(v.node(*readonly_token));
+            visit!(v.node(Some(*type_parameter)));
+            visit!(v.node(*name_type));
+            visit!(v.node(*question_token));
+            v.node(*_type)
+        }
+        NodeData::LiteralType { literal } => v.node(Some(*literal)),
+        NodeData::NamedTupleMember {
+            dot_dot_dot_token,
+            name,
+            question_token,
+            _type,
+        } => {
+            visit!(v.node(*dot_dot_dot_token));
+            visit!(v.node(Some(*name)));
+            visit!(v.node(*question_token));
+            v.node(Some(*_type))
+        }
+        NodeData::BindingPattern { elements } => v.nodes(Some(elements)),
+        NodeData::ArrayLiteralExpression { elements, .. } => v.nodes(Some(elements)),
+        NodeData::ObjectLiteralExpression { properties, .. } => v.nodes(Some(properties)),
+        NodeData::PropertyAccessExpression {
+            expression,
+            question_dot_token,
+            name,
+        } => {
+            visit!(v.node(Some(*expression)));
+            visit!(v.node(*question_dot_token));
+            v.node(Some(*name))
+        }
+        NodeData::ElementAccessExpression {
+            expression,
+            question_dot_token,
+            argument_expression,
+        } => {
+            visit!(v.node(Some(*expression)));
+            visit!(v.node(*question_dot_token));
+            v.node(Some(*argument_expression))
+        }
+        NodeData::CallExpression {
+            expression,
+            question_dot_token,
+            type_arguments,
+            arguments,
+        } => {
+            visit!(v.node(Some(*expression)));
+            visit!(v.node(*question_dot_token));
+            visit!(v.nodes(type_arguments.as_ref()));
+            v.nodes(arguments.as_ref())
+        }
+        NodeData::TaggedTemplateExpression {
+            tag,
+            question_dot_token,
+            type_arguments,
+            template,
+        } => {
+            visit!(v.node(Some(*tag)));
+            visit!(v.node(*question_dot_token));
+            visit!(v.nodes(type_arguments.as_ref()));
+            v.node(Some(*template))
+        }
+        NodeData::TypeAssertion { _type, expression } => {
+            visit!(v.node(Some(*_type)));
+            v.node(Some(*expression))
+        }
+        NodeData::UnaryExpression { operand, .. } => v.node(Some(*operand)),
+        NodeData::YieldExpression {
+            asterisk_token,
+            expression,
+        } => {
+            visit!(v.node(*asterisk_token));
+            v.node(*expression)
+        }
+        NodeData::BinaryExpression {
+            left,
+            operator_token,
+            right,
+        } => {
+            visit!(v.node(Some(*left)));
+            visit!(v.node(Some(*operator_token)));
+            v.node(Some(*right))
+        }
+        NodeData::AsExpression { expression, _type } => {
+            visit!(v.node(Some(*expression)));
+            v.node(Some(*_type))
+        }
+        NodeData::MetaProperty { name, .. } => v.node(Some(*name)),
+        NodeData::ConditionalExpression {
+            condition,
+            question_token,
+            when_true,
+            colon_token,
+            when_false,
+        } => {
+            visit!(v.node(Some(*condition)));
+            visit!(v.node(Some(*question_token)));
+            visit!(v.node(Some(*when_true)));
+            visit!(v.node(Some(*colon_token)));
+            v.node(Some(*when_false))
+        }
+        NodeData::TemplateExpression {
+            head,
+            template_spans,
+        } => {
+            visit!(v.node(Some(*head)));
+            v.nodes(Some(template_spans))
+        }
+        NodeData::TemplateSpan {
+            expression,
+            literal,
+        } => {
+            visit!(v.node(Some(*expression)));
+            v.node(Some(*literal))
+        }
+        NodeData::TemplateLiteralTypeSpan { _type, literal } => {
+            visit!(v.node(Some(*_type)));
+            v.node(Some(*literal))
+        }
+        NodeData::Block { statements, .. } => v.nodes(Some(statements)),
+        NodeData::SourceFile {
+            statements,
+            end_of_file_token,
+        } => {
+            visit!(v.nodes(Some(statements)));
+            v.node(Some(*end_of_file_token))
+        }
+        NodeData::VariableStatement { declaration_list } => v.node(Some(*declaration_list)),
+        NodeData::VariableDeclarationList { declarations } => v.nodes(Some(declarations)),
+        NodeData::IfStatement {
+            expression,
+            then_statement,
+            else_statement,
+        } => {
+            visit!(v.node(Some(*expression)));
+            visit!(v.node(Some(*then_statement)));
+            v.node(*else_statement)
+        }
+        NodeData::DoStatement {
+            statement,
+            expression,
+        } => {
+            visit!(v.node(Some(*statement)));
+            v.node(Some(*expression))
+        }
+        NodeData::WhileOrWithStatement {
+            expression,
+            statement,
+        } => {
+            visit!(v.node(Some(*expression)));
+            v.node(Some(*statement))
+        }
+        NodeData::ForStatement {
+            initializer,
+            condition,
+            incrementor,
+            statement,
+        } => {
+            visit!(v.node(*initializer));
+            visit!(v.node(*condition));
+            visit!(v.node(*incrementor));
+            v.node(Some(*statement))
+        }
+        NodeData::ForInOrOfStatement {
+            await_modifier,
+            initializer,
+            expression,
+            statement,
+        } => {
+            visit!(v.node(*await_modifier));
+            visit!(v.node(Some(*initializer)));
+            visit!(v.node(Some(*expression)));
+            v.node(Some(*statement))
+        }
+        NodeData::BreakOrContinueStatement { label } => v.node(*label),
+        NodeData::ReturnStatement { expression } => v.node(*expression),
+        NodeData::SwitchStatement {
+            expression,
+            case_block,
+        } => {
+            visit!(v.node(Some(*expression)));
+            v.node(Some(*case_block))
+        }
+        NodeData::CaseBlock { clauses } => v.nodes(Some(clauses)),
+        NodeData::CaseOrDefaultClause {
+            expression,
+            statements,
+        } => {
+            visit!(v.node(*expression));
+            v.nodes(Some(statements))
+        }
+        NodeData::LabeledStatement { label, statement } => {
+            visit!(v.node(Some(*label)));
+            v.node(Some(*statement))
+        }
+        NodeData::TryStatement {
+            try_block,
+            catch_clause,
+            finally_block,
+        } => {
+            visit!(v.node(Some(*try_block)));
+            visit!(v.node(*catch_clause));
+            v.node(*finally_block)
+        }
+        NodeData::CatchClause {
+            variable_declaration,
+            block,
+        } => {
+            visit!(v.node(*variable_declaration));
+            v.node(Some(*block))
+        }
+        NodeData::ClassLikeDeclaration {
+            name,
+            type_parameters,
+            heritage_clauses,
+            members,
+        } => {
+            visit!(v.node(*name));
+            visit!(v.nodes(type_parameters.as_ref()));
+            visit!(v.nodes(heritage_clauses.as_ref()));
+            v.nodes(Some(members))
+        }
+        NodeData::InterfaceDeclaration {
+            name,
+            type_parameters,
+            heritage_clauses,
+            members,
+        } => {
+            visit!(v.node(Some(*name)));
+            visit!(v.nodes(type_parameters.as_ref()));
+            visit!(v.nodes(heritage_clauses.as_ref()));
+            v.nodes(Some(members))
+        }
+        NodeData::HeritageClause { types, .. } => v.nodes(Some(types)),
+        NodeData::ExpressionWithTypeArguments {
+            expression,
+            type_arguments,
+        } => {
+            visit!(v.node(Some(*expression)));
+            v.nodes(type_arguments.as_ref())
+        }
+        NodeData::TypeAliasDeclaration {
+            name,
+            type_parameters,
+            _type,
+        } => {
+            visit!(v.node(Some(*name)));
+            visit!(v.nodes(type_parameters.as_ref()));
+            v.node(Some(*_type))
+        }
+        NodeData::EnumDeclaration { name, members } => {
+            visit!(v.node(Some(*name)));
+            v.nodes(Some(members))
+        }
+        NodeData::EnumMember { name, initializer } => {
+            visit!(v.node(Some(*name)));
+            v.node(*initializer)
+        }
+        NodeData::ModuleDeclaration { name, body } => {
+            visit!(v.node(Some(*name)));
+            v.node(*body)
+        }
+        NodeData::ImportEqualsDeclaration {
+            name,
+            module_reference,
+            ..
+        } => {
+            visit!(v.node(Some(*name)));
+            v.node(Some(*module_reference))
+        }
+        NodeData::ImportDeclaration {
+            import_clause,
+            module_specifier,
+        } => {
+            visit!(v.node(*import_clause));
+            v.node(Some(*module_specifier))
+        }
+        NodeData::ImportClause {
+            name,
+            named_bindings,
+            ..
+        } => {
+            visit!(v.node(*name));
+            v.node(*named_bindings)
+        }
+        NodeData::NamespaceImportOrExport { name } => v.node(Some(*name)),
+        NodeData::NamedImportsOrExports { elements } => v.nodes(Some(elements)),
+        NodeData::ImportOrExportSpecifier {
+            property_name,
+            name,
+        } => {
+            visit!(v.node(*property_name));
+            v.node(Some(*name))
+        }
+        NodeData::ExportDeclaration {
+            export_clause,
+            module_specifier,
+            ..
+        } => {
+            visit!(v.node(*export_clause));
+            v.node(*module_specifier)
+        }
+        NodeData::ExportAssignment { expression, .. } => v.node(Some(*expression)),
+        NodeData::JsxElement {
+            opening_element,
+            children,
+            closing_element,
+        } => {
+            visit!(v.node(Some(*opening_element)));
+            visit!(v.nodes(Some(children)));
+            v.node(Some(*closing_element))
+        }
+        NodeData::JsxFragment {
+            opening_fragment,
+            children,
+            closing_fragment,
+        } => {
+            visit!(v.node(Some(*opening_fragment)));
+            visit!(v.nodes(Some(children)));
+            v.node(Some(*closing_fragment))
+        }
+        NodeData::JsxOpeningLikeElement {
+            tag_name,
+            type_arguments,
+            attributes,
+        } => {
+            visit!(v.node(Some(*tag_name)));
+            visit!(v.nodes(type_arguments.as_ref()));
+            v.node(Some(*attributes))
+        }
+        NodeData::JsxClosingElement { tag_name } => v.node(Some(*tag_name)),
+        NodeData::JsxAttributes { properties } => v.nodes(Some(properties)),
+        NodeData::JsxAttribute { name, initializer } => {
+            visit!(v.node(Some(*name)));
+            v.node(*initializer)
+        }
+        NodeData::JsxExpression {
+            dot_dot_dot_token,
+            expression,
+        } => {
+            visit!(v.node(*dot_dot_dot_token));
+            v.node(*expression)
+        }
+        NodeData::JSDoc { comment, tags } => {
+            visit!(v.comment(comment));
+            v.nodes(tags.as_ref())
+        }
+        NodeData::JSDocLink { name, .. } => v.node(*name),
+        NodeData::JSDocNameReference { name } => v.node(Some(*name)),
+        NodeData::JSDocTag(tag) => {
+            visit!(v.node(Some(tag.tag_name)));
+            match node.kind {
+                SyntaxKind::JSDocSeeTag => {
+                    visit!(v.node(tag.name));
+                }
+                SyntaxKind::JSDocParameterTag | SyntaxKind::JSDocPropertyTag => {
+                    if tag.is_name_first {
+                        visit!(v.node(tag.name));
+                        visit!(v.node(tag.type_expression));
+                    } else {
+                        visit!(v.node(tag.type_expression));
+                        visit!(v.node(tag.name));
+                    }
+                }
+                SyntaxKind::JSDocImplementsTag | SyntaxKind::JSDocAugmentsTag => {
+                    visit!(v.node(tag.class));
+                }
+                SyntaxKind::JSDocTemplateTag => {
+                    visit!(v.node(tag.constraint));
+                    visit!(v.nodes(tag.type_parameters.as_ref()));
+                }
+                SyntaxKind::JSDocTypedefTag => {
+                    if tag.is_name_first {
+                        visit!(v.node(tag.full_name));
+                        visit!(v.node(tag.type_expression));
+                    } else {
+                        visit!(v.node(tag.type_expression));
+                        visit!(v.node(tag.full_name));
+                    }
+                }
+                SyntaxKind::JSDocCallbackTag => {
+                    visit!(v.node(tag.full_name));
+                    visit!(v.node(tag.type_expression));
+                }
+                SyntaxKind::JSDocReturnTag
+                | SyntaxKind::JSDocTypeTag
+                | SyntaxKind::JSDocThisTag
+                | SyntaxKind::JSDocEnumTag => {
+                    visit!(v.node(tag.type_expression));
+                }
+                _ => {}
+            }
+            v.comment(&tag.comment)
+        }
+        NodeData::JSDocTypeLiteral {
+            js_doc_property_tags,
+            ..
+        } => v.each(js_doc_property_tags.as_ref()),
+        NodeData::JSDocSignature {
+            type_parameters,
+            parameters,
+            _type,
+        } => {
+            visit!(v.each(type_parameters.as_ref()));
+            visit!(v.each(Some(parameters)));
+            v.node(*_type)
+        }
+    }
+}
+
+/// A pass over a syntax tree. `visit_node` is called on the children of a node in the order of
+/// `for_each_child`; the default implementation walks into the children of the visited node, so
+/// an implementation that only overrides `visit_node` for some kinds should call
+/// `walk_children` for the others to visit the whole subtree.
+pub trait Visitor {
+    fn visit_node(&mut self, source_file: &SourceFile, node: NodeId) {
+        walk_children(self, source_file, node);
+    }
+}
+
+/// Calls `visitor.visit_node` on each child of `node`.
+pub fn walk_children<V: Visitor + ?Sized>(visitor: &mut V, source_file: &SourceFile, node: NodeId) {
+    for_each_child(
+        source_file.node(node),
+        &mut |child| {
+            visitor.visit_node(source_file, child);
+            None::<()>
+        },
+        None,
+    );
+}
+
+impl Parser {
+    fn get_external_module_indicator(&self, root: NodeId) -> Option<NodeId> {
+        // Try to use the first top-level import/export when available, then
+        // fall back to looking for an 'import.meta' somewhere in the tree if necessary.
+        let statements = match &self.node(root).data {
+            NodeData::SourceFile { statements, .. } => statements,
+            _ => unreachable!("the root of a source file is a SourceFile node"),
+        };
+        statements
+            .iter()
+            .copied()
+            .find(|&statement| self.is_an_external_module_indicator_node(statement))
+            .or_else(|| self.get_import_meta_if_necessary(root))
+    }
+
+    fn is_an_external_module_indicator_node(&self, node: NodeId) -> bool {
+        let node = self.node(node);
+        self.has_modifier_of_kind(&node.modifiers, SyntaxKind::ExportKeyword)
+            || match &node.data {
+                NodeData::ImportEqualsDeclaration {
+                    module_reference, ..
+                } => self.node(*module_reference).kind == SyntaxKind::ExternalModuleReference,
+                _ => matches!(
+                    node.kind,
+                    SyntaxKind::ImportDeclaration
+                        | SyntaxKind::ExportAssignment
+                        | SyntaxKind::ExportDeclaration
+                ),
+            }
+    }
+
+    fn get_import_meta_if_necessary(&self, root: NodeId) -> Option<NodeId> {
+        if self.node(root).flags & NodeFlags::POSSIBLY_CONTAINS_IMPORT_META != 0 {
+            self.walk_tree_for_external_module_indicators(root)
+        } else {
+            None
+        }
+    }
+
+    fn walk_tree_for_external_module_indicators(&self, node: NodeId) -> Option<NodeId> {
+        if self.is_import_meta(node) {
+            return Some(node);
+        }
+        for_each_child(
+            self.node(node),
+            &mut |child| self.walk_tree_for_external_module_indicators(child),
+            None,
+        )
+    }
+
+    fn is_import_meta(&self, node: NodeId) -> bool {
+        match &self.node(node).data {
+            NodeData::MetaProperty {
+                keyword_token: SyntaxKind::ImportKeyword,
+                name,
+            } => self.identifier_text(*name) == "meta",
+            _ => false,
+        }
+    }
+
+    // The JS compiler tracks possible top-level `await` identifiers with a transform flag that
+    // is aggregated while creating nodes. We compute the same thing on demand: an identifier
+    // that was scanned as the `await` keyword counts unless it is in a position where an
+    // IdentifierName is allowed, or below a node that excludes the flag from its subtree
+    // (functions, types, enums, namespaces and import/export declarations, which are always
+    // parsed in an `await` context).
+    fn contains_possible_top_level_await(&self, node: NodeId) -> bool {
+        let node = self.node(node);
+        let mut skipped_children = [None, None];
+        match &node.data {
+            NodeData::Identifier {
+                original_keyword_kind,
+                ..
+            } => return *original_keyword_kind == Some(KeywordSyntaxKind::AwaitKeyword),
+            NodeData::QualifiedName { right, .. } => skipped_children[0] = Some(*right),
+            NodeData::PropertyAccessExpression { name, .. } => skipped_children[0] = Some(*name),
+            NodeData::BindingElement { property_name, .. } => skipped_children[0] = *property_name,
+            NodeData::VariableLike { name, .. } if node.kind == SyntaxKind::PropertyDeclaration => {
+                skipped_children[0] = Some(*name).filter(|&name| self.is_identifier_node(name));
+            }
+            NodeData::SignatureDeclaration(signature) => match node.kind {
+                SyntaxKind::FunctionDeclaration
+                | SyntaxKind::FunctionExpression
+                | SyntaxKind::ArrowFunction
+                | SyntaxKind::Constructor => return false,
+                SyntaxKind::MethodDeclaration
+                | SyntaxKind::GetAccessor
+                | SyntaxKind::SetAccessor => {
+                    skipped_children[0] =
+                        signature.name.filter(|&name| self.is_identifier_node(name));
+                    skipped_children[1] = signature.body;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        if is_await_excluding_kind(node.kind) {
+            return false;
+        }
+        for_each_child(
+            node,
+            &mut |child| {
+                if !skipped_children.contains(&Some(child))
+                    && self.contains_possible_top_level_await(child)
+                {
+                    Some(())
+                } else {
+                    None
+                }
+            },
+            None,
+        )
+        .is_some()
+    }
+
+    fn statement_contains_possible_top_level_await(&self, node: NodeId) -> bool {
+        self.node(node).flags & NodeFlags::AWAIT_CONTEXT == 0
+            && self.contains_possible_top_level_await(node)
+    }
+
+    fn find_next_statement_with_await(&self, statements: &[NodeId], start: usize) -> Option<usize> {
+        (start..statements.len())
+            .find(|&i| self.statement_contains_possible_top_level_await(statements[i]))
+    }
+
+    fn find_next_statement_without_await(
+        &self,
+        statements: &[NodeId],
+        start: usize,
+    ) -> Option<usize> {
+        (start..statements.len())
+            .find(|&i| !self.statement_contains_possible_top_level_await(statements[i]))
+    }
+
+    fn reparse_top_level_await(&mut self, root: NodeId) {
+        // The statements are reparsed from the text, never taken from the old tree of an
+        // incremental parse.
+        self.syntax_cursor = None;
+        let source_statements = match &self.node(root).data {
+            NodeData::SourceFile { statements, .. } => statements.clone(),
+            _ => unreachable!("the root of a source file is a SourceFile node"),
+        };
+
+        let mut statements: Vec<NodeId> = Vec::new();
+        let saved_parse_diagnostics = std::mem::take(&mut *self.parse_diagnostics.borrow_mut());
+
+        let mut pos = Some(0);
+        let mut start = self.find_next_statement_with_await(&source_statements, 0);
+        while let Some(start_index) = start {
+            // append all statements between pos and start
+            let pos_index = pos.expect("a statement with `await` follows a statement without one");
+            let prev_statement = self.node(source_statements[pos_index]).pos();
+            let next_statement = self.node(source_statements[start_index]).pos();
+            statements.extend_from_slice(&source_statements[pos_index..start_index]);
+            pos = self.find_next_statement_without_await(&source_statements, start_index);
+
+            // append all diagnostics associated with the copied range
+            let diagnostic_start = saved_parse_diagnostics
+                .iter()
+                .position(|diagnostic| diagnostic.start >= prev_statement);
+            if let Some(diagnostic_start) = diagnostic_start {
+                let diagnostic_end = saved_parse_diagnostics[diagnostic_start..]
+                    .iter()
+                    .position(|diagnostic| diagnostic.start >= next_statement)
+                    .map_or(saved_parse_diagnostics.len(), |end| diagnostic_start + end);
+                self.parse_diagnostics
+                    .borrow_mut()
+                    .extend_from_slice(&saved_parse_diagnostics[diagnostic_start..diagnostic_end]);
+            }
+
+            // reparse all statements between start and pos. We skip existing diagnostics for the same range and allow the parser to generate new ones.
+            pos = self.speculation_helper(
+                |parser| {
+                    let mut pos = pos;
+                    let saved_context_flags = parser.context_flags;
+                    parser.context_flags |= NodeFlags::AWAIT_CONTEXT;
+                    parser.scanner.set_text_pos(Some(next_statement));
+                    parser.next_token();
+
+                    while parser.token() != SyntaxKind::EndOfFileToken {
+                        let start_pos = parser.scanner.get_start_pos();
+                        let statement = parser.parse_list_element(
+                            ParsingContext::SourceElements,
+                            &mut Self::parse_statement,
+                        );
+                        statements.push(statement);
+                        if start_pos == parser.scanner.get_start_pos() {
+                            parser.next_token();
+                        }
+
+                        if let Some(pos_index) = pos {
+                            let statement_end = parser.node(statement).end();
+                            let non_await_statement =
+                                parser.node(source_statements[pos_index]).pos();
+                            if statement_end == non_await_statement {
+                                // done reparsing this section
+                                break;
+                            }
+                            if statement_end > non_await_statement {
+                                // we ate into the next statement, so we must reparse it.
+                                pos = parser.find_next_statement_without_await(
+                                    &source_statements,
+                                    pos_index + 1,
+                                );
+                            }
+                        }
+                    }
+
+                    parser.context_flags = saved_context_flags;
+                    pos
+                },
+                |_| true,
+                SpeculationKind::Reparse,
+            );
+
+            // find the next statement containing an `await`
+            start =
+                pos.and_then(|pos| self.find_next_statement_with_await(&source_statements, pos));
+        }
+
+        // append all statements between pos and the end of the list
+        if let Some(pos_index) = pos {
+            let prev_statement = self.node(source_statements[pos_index]).pos();
+            statements.extend_from_slice(&source_statements[pos_index..]);
+
+            // append all diagnostics associated with the copied range
+            if let Some(diagnostic_start) = saved_parse_diagnostics
+                .iter()
+                .position(|diagnostic| diagnostic.start >= prev_statement)
+            {
+                self.parse_diagnostics
+                    .borrow_mut()
+                    .extend_from_slice(&saved_parse_diagnostics[diagnostic_start..]);
+            }
+        }
+
+        let statements = NodeArray {
+            nodes: statements,
+            ..source_statements
+        };
+        if let NodeData::SourceFile {
+            statements: root_statements,
+            ..
+        } = &mut self.node_mut(root).data
+        {
+            *root_statements = statements;
+        }
+    }
+
+    fn is_identifier_node(&self, node: NodeId) -> bool {
+        self.node(node).kind == SyntaxKind::Identifier
+    }
+}
+
+// Whether nodes of this kind never propagate a possible top-level `await` to their parent.
+fn is_await_excluding_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::TypeNodes(type_kind)
+            if type_kind != TypeNodeSyntaxKind::ExpressionWithTypeArguments
+    ) || matches!(
+        kind,
+        SyntaxKind::AnyKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BigIntKeyword
+            | SyntaxKind::NeverKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::ObjectKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::SymbolKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::TypeParameter
+            | SyntaxKind::PropertySignature
+            | SyntaxKind::MethodSignature
+            | SyntaxKind::CallSignature
+            | SyntaxKind::ConstructSignature
+            | SyntaxKind::IndexSignature
+            | SyntaxKind::InterfaceDeclaration
+            | SyntaxKind::TypeAliasDeclaration
+            | SyntaxKind::EnumDeclaration
+            | SyntaxKind::ModuleDeclaration
+            | SyntaxKind::ImportEqualsDeclaration
+            | SyntaxKind::ImportDeclaration
+            | SyntaxKind::ImportClause
+            | SyntaxKind::NamespaceImport
+            | SyntaxKind::NamespaceExport
+            | SyntaxKind::NamedImports
+            | SyntaxKind::ImportSpecifier
+            | SyntaxKind::ExportAssignment
+            | SyntaxKind::ExportDeclaration
+            | SyntaxKind::NamedExports
+            | SyntaxKind::ExportSpecifier
+            | SyntaxKind::ExternalModuleReference
+    )
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JSDocState {
+    BeginningOfLine,
+    SawAsterisk,
+    SavingComments,
+    SavingBackticks, // NOTE: Only used when parsing tag comments
+}
+
+#[allow(non_snake_case)]
+mod PropertyLikeParse {
+    pub const PROPERTY: u32 = 1 << 0;
+    pub const PARAMETER: u32 = 1 << 1;
+    pub const CALLBACK_PARAMETER: u32 = 1 << 2;
+}
+
+// The comment text collected while parsing a JSDoc comment or the comment of a tag, together
+// with the indentation used to strip the leading margin of continuation lines.
+struct JSDocCommentBuffer {
+    comments: Vec<String>,
+    margin: Option<usize>,
+    indent: usize,
+}
+
+impl JSDocCommentBuffer {
+    fn new(indent: usize) -> JSDocCommentBuffer {
+        JSDocCommentBuffer {
+            comments: Vec::new(),
+            margin: None,
+            indent,
+        }
+    }
+
+    fn push_comment(&mut self, text: String) {
+        if self.margin.is_none_or(|margin| margin == 0) {
+            self.margin = Some(self.indent);
+        }
+        self.indent += utf16_len(&text);
+        self.comments.push(text);
+    }
+
+    // Pushes the part of some whitespace that crosses the margin, if any.
+    fn push_whitespace_past_margin(&mut self, whitespace: String) {
+        if let Some(margin) = self.margin {
+            if self.indent + utf16_len(&whitespace) > margin {
+                self.comments.push(slice_utf16(
+                    &whitespace,
+                    margin as isize - self.indent as isize,
+                ));
+            }
+        }
+        self.indent += utf16_len(&whitespace);
+    }
+
+    fn remove_leading_newlines(&mut self) {
+        let leading_newlines = self
+            .comments
+            .iter()
+            .take_while(|comment| *comment == "\n" || *comment == "\r")
+            .count();
+        self.comments.drain(..leading_newlines);
+    }
+
+    fn remove_trailing_whitespace(&mut self) {
+        while self
+            .comments
+            .last()
+            .is_some_and(|comment| comment.trim().is_empty())
+        {
+            self.comments.pop();
+        }
+    }
+
+    fn take_text(&mut self) -> String {
+        std::mem::take(&mut self.comments).concat()
+    }
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+// Like `String.prototype.slice` with only a start index, in UTF-16 code units: a negative start
+// counts from the end of the text.
+fn slice_utf16(text: &str, start: isize) -> String {
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let start = if start < 0 {
+        units.len().saturating_sub(start.unsigned_abs())
+    } else {
+        (start as usize).min(units.len())
+    };
+    String::from_utf16_lossy(&units[start..])
+}
+
+fn create_js_doc_tag(tag_name: NodeId, comment: Option<JSDocCommentText>) -> JSDocTag {
+    JSDocTag {
+        tag_name,
+        comment,
+        type_expression: None,
+        name: None,
+        full_name: None,
+        class: None,
+        constraint: None,
+        type_parameters: None,
+        is_name_first: false,
+        is_bracketed: false,
+    }
+}
+
+impl Parser {
+    // Parses out a JSDoc type expression.
+    fn parse_js_doc_type_expression(&mut self, may_omit_braces: bool) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_brace = if may_omit_braces {
+            self.parse_optional(SyntaxKind::OpenBraceToken)
+        } else {
+            self.parse_expected(SyntaxKind::OpenBraceToken)
+        };
+        let _type = self.do_inside_of_context(NodeFlags::JSDOC, Self::parse_js_doc_type);
+        if !may_omit_braces || has_brace {
+            self.parse_expected_js_doc(SyntaxKind::CloseBraceToken);
+        }
+
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocTypeExpression,
+                NodeData::Type { _type: Some(_type) },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_name_reference(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let has_brace = self.parse_optional(SyntaxKind::OpenBraceToken);
+        let p2 = self.get_node_pos();
+        let mut entity_name = self.parse_entity_name(false, None);
+        while self.token() == SyntaxKind::PrivateIdentifier {
+            self.re_scan_hash_token(); // rescan #id as # id
+            self.next_token_js_doc(); // then skip the #
+            entity_name = self.parse_js_doc_member_name(entity_name, p2);
+        }
+        if has_brace {
+            self.parse_expected_js_doc(SyntaxKind::CloseBraceToken);
+        }
+
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocNameReference,
+                NodeData::JSDocNameReference { name: entity_name },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_member_name(&mut self, left: NodeId, pos: usize) -> NodeId {
+        let right = self.parse_identifier(None, None);
+        self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocMemberName,
+                NodeData::QualifiedName {
+                    left,
+                    right,
+                    jsdoc_dot_pos: None,
+                },
+            ),
+            pos,
+        )
+    }
+
+    fn parse_js_doc_comment(&mut self, start: usize, length: usize) -> Option<NodeId> {
+        let save_token = self.current_token;
+        let save_parse_diagnostics_length = self.parse_diagnostics.borrow().len();
+        let save_parse_error_before_next_finished_node =
+            self.parse_error_before_next_finished_node.get();
+
+        let comment = self.do_inside_of_context(NodeFlags::JSDOC, |parser| {
+            parser.parse_js_doc_comment_worker(start, length)
+        });
+
+        if self.context_flags & NodeFlags::JAVA_SCRIPT_FILE != 0 {
+            let parse_diagnostics = self.parse_diagnostics.borrow().clone();
+            self.js_doc_diagnostics
+                .get_or_insert_with(Vec::new)
+                .extend(parse_diagnostics);
+        }
+        self.current_token = save_token;
+        self.parse_diagnostics
+            .borrow_mut()
+            .truncate(save_parse_diagnostics_length);
+        self.parse_error_before_next_finished_node
+            .set(save_parse_error_before_next_finished_node);
+        comment
+    }
+
+    fn scan_range<T>(
+        &mut self,
+        start: usize,
+        length: usize,
+        callback: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let saved = self.scanner.enter_range(start, length);
+        let result = callback(self);
+        self.scanner.exit_range(saved);
+        result
+    }
+
+    fn parse_js_doc_comment_worker(&mut self, start: usize, length: usize) -> Option<NodeId> {
+        let content = self.scanner.get_text_utf16();
+        let end = start + length;
+
+        debug_assert!(end <= content.len());
+
+        // Check for /** (JSDoc opening part)
+        if !is_js_doc_like_text(content, start) {
+            return None;
+        }
+
+        // + 4 for leading '/** '
+        // + 1 because the last index of \n is always one index before the first character in the line and coincidentally, if there is no \n before start, it is -1, which is also one index before the first character
+        let line_start = content[..=start]
+            .iter()
+            .rposition(|&ch| u32::from(ch) == CharacterCodes::LINE_FEED)
+            .map_or(0, |index| index + 1);
+        let indent = start - line_start + 4;
+
+        let saved_tags = std::mem::take(&mut self.js_doc_tags);
+        // + 3 for leading /**, - 5 in total for /** */
+        let comment = self.scan_range(start + 3, length - 5, |parser| {
+            let mut tags_pos = 0;
+            let mut tags_end = 0;
+            let mut link_end: Option<usize> = None;
+            let mut comments_pos: Option<usize> = None;
+            let mut parts: Vec<NodeId> = Vec::new();
+
+            // Initially we can parse out a tag.  We also have seen a starting asterisk.
+            // This is so that /** * @type */ doesn't parse.
+            let mut state = JSDocState::SawAsterisk;
+            let mut buffer = JSDocCommentBuffer::new(indent);
+
+            parser.next_token_js_doc();
+            while parser.parse_optional_js_doc(SyntaxKind::WhitespaceTrivia) {}
+            if parser.parse_optional_js_doc(SyntaxKind::NewLineTrivia) {
+                state = JSDocState::BeginningOfLine;
+                buffer.indent = 0;
+            }
+            loop {
+                match parser.token() {
+                    SyntaxKind::AtToken
+                        if state == JSDocState::BeginningOfLine
+                            || state == JSDocState::SawAsterisk =>
+                    {
+                        buffer.remove_trailing_whitespace();
+                        if comments_pos.is_none() {
+                            comments_pos = Some(parser.get_node_pos());
+                        }
+                        let tag = parser.parse_tag(buffer.indent);
+                        if parser.js_doc_tags.is_empty() {
+                            tags_pos = parser.node(tag).pos();
+                        }
+                        parser.js_doc_tags.push(tag);
+                        tags_end = parser.node(tag).end();
+                        // NOTE: According to usejsdoc.org, a tag goes to end of line, except the last tag.
+                        // Real-world comments may break this rule, so "BeginningOfLine" will not be a real line beginning
+                        // for malformed examples like `/** @param {string} x @returns {number} the length */`
+                        state = JSDocState::BeginningOfLine;
+                        buffer.margin = None;
+                    }
+                    SyntaxKind::AtToken => {
+                        buffer.push_comment(parser.scanner.get_token_text());
+                    }
+                    SyntaxKind::NewLineTrivia => {
+                        buffer.comments.push(parser.scanner.get_token_text());
+                        state = JSDocState::BeginningOfLine;
+                        buffer.indent = 0;
+                    }
+                    SyntaxKind::AsteriskToken => {
+                        let asterisk = parser.scanner.get_token_text();
+                        if state == JSDocState::SawAsterisk || state == JSDocState::SavingComments {
+                            // If we've already seen an asterisk, then we can no longer parse a tag on this line
+                            state = JSDocState::SavingComments;
+                            buffer.push_comment(asterisk);
+                        } else {
+                            // Ignore the first asterisk on a line
+                            state = JSDocState::SawAsterisk;
+                            buffer.indent += utf16_len(&asterisk);
+                        }
+                    }
+                    SyntaxKind::WhitespaceTrivia => {
+                        // only collect whitespace if we're already saving comments or have just crossed the comment indent margin
+                        let whitespace = parser.scanner.get_token_text();
+                        if state == JSDocState::SavingComments {
+                            buffer.indent += utf16_len(&whitespace);
+                            buffer.comments.push(whitespace);
+                        } else {
+                            buffer.push_whitespace_past_margin(whitespace);
+                        }
+                    }
+                    SyntaxKind::EndOfFileToken => break,
+                    token => {
+                        // Anything else is doc comment text. We just save it. Because it
+                        // wasn't a tag, we can no longer parse a tag on this line until we hit the next
+                        // line break.
+                        state = JSDocState::SavingComments;
+                        let link = if token == SyntaxKind::OpenBraceToken {
+                            let comment_end = parser.scanner.get_start_pos();
+                            let link_start = parser.scanner.get_text_pos() - 1;
+                            parser
+                                .parse_js_doc_link(link_start)
+                                .map(|link| (link, comment_end))
+                        } else {
+                            None
+                        };
+                        match link {
+                            Some((link, comment_end)) => {
+                                if link_end.is_none() {
+                                    buffer.remove_leading_newlines();
+                                }
+                                let text = buffer.take_text();
+                                parts.push(parser.finish_node_at(
+                                    Node::new(SyntaxKind::JSDocText, NodeData::JSDocText { text }),
+                                    link_end.unwrap_or(start),
+                                    comment_end,
+                                ));
+                                parts.push(link);
+                                link_end = Some(parser.scanner.get_text_pos());
+                            }
+                            None => buffer.push_comment(parser.scanner.get_token_text()),
+                        }
+                    }
+                }
+                parser.next_token_js_doc();
+            }
+            buffer.remove_trailing_whitespace();
+            if !parts.is_empty() && !buffer.comments.is_empty() {
+                let text = buffer.take_text();
+                let text_end = comments_pos.unwrap_or_else(|| parser.scanner.get_start_pos());
+                parts.push(parser.finish_node_at(
+                    Node::new(SyntaxKind::JSDocText, NodeData::JSDocText { text }),
+                    link_end.unwrap_or(start),
+                    text_end,
+                ));
+            }
+            let tags = std::mem::take(&mut parser.js_doc_tags);
+            let tags_array = (!tags.is_empty())
+                .then(|| parser.create_node_array(tags, tags_pos, Some(tags_end), false));
+            let comment = if !parts.is_empty() {
+                Some(JSDocCommentText::Nodes(parser.create_node_array(
+                    parts,
+                    start,
+                    comments_pos,
+                    false,
+                )))
+            } else if !buffer.comments.is_empty() {
+                Some(JSDocCommentText::Text(buffer.take_text()))
+            } else {
+                None
+            };
+            parser.finish_node_at(
+                Node::new(
+                    SyntaxKind::JSDocComment,
+                    NodeData::JSDoc {
+                        comment,
+                        tags: tags_array,
+                    },
+                ),
+                start,
+                end,
+            )
+        });
+        self.js_doc_tags = saved_tags;
+        Some(comment)
+    }
+
+    fn is_next_nonwhitespace_token_end_of_file(&mut self) -> bool {
+        // We must use infinite lookahead, as there could be any number of newlines :(
+        loop {
+            self.next_token_js_doc();
+            if self.token() == SyntaxKind::EndOfFileToken {
+                return true;
+            }
+            if !(self.token() == SyntaxKind::WhitespaceTrivia
+                || self.token() == SyntaxKind::NewLineTrivia)
+            {
+                return false;
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        if (self.token() == SyntaxKind::WhitespaceTrivia
+            || self.token() == SyntaxKind::NewLineTrivia)
+            && self.look_ahead(Self::is_next_nonwhitespace_token_end_of_file)
+        {
+            return; // Don't skip whitespace prior to EoF (or end of comment) - that shouldn't be included in any node's range
+        }
+        while self.token() == SyntaxKind::WhitespaceTrivia
+            || self.token() == SyntaxKind::NewLineTrivia
+        {
+            self.next_token_js_doc();
+        }
+    }
+
+    fn skip_whitespace_or_asterisk(&mut self) -> String {
+        if (self.token() == SyntaxKind::WhitespaceTrivia
+            || self.token() == SyntaxKind::NewLineTrivia)
+            && self.look_ahead(Self::is_next_nonwhitespace_token_end_of_file)
+        {
+            return String::new(); // Don't skip whitespace prior to EoF (or end of comment) - that shouldn't be included in any node's range
+        }
+
+        let mut preceding_line_break = self.scanner.has_preceding_line_break();
+        let mut seen_line_break = false;
+        let mut indent_text = String::new();
+        while (preceding_line_break && self.token() == SyntaxKind::AsteriskToken)
+            || self.token() == SyntaxKind::WhitespaceTrivia
+            || self.token() == SyntaxKind::NewLineTrivia
+        {
+            indent_text.push_str(&self.scanner.get_token_text());
+            if self.token() == SyntaxKind::NewLineTrivia {
+                preceding_line_break = true;
+                seen_line_break = true;
+                indent_text.clear();
+            } else if self.token() == SyntaxKind::AsteriskToken {
+                preceding_line_break = false;
+            }
+            self.next_token_js_doc();
+        }
+        if seen_line_break {
+            indent_text
+        } else {
+            String::new()
+        }
+    }
+
+    fn parse_tag(&mut self, margin: usize) -> NodeId {
+        let start = self.scanner.get_token_pos();
+        self.next_token_js_doc();
+
+        let tag_name = self.parse_js_doc_identifier_name(None);
+        let indent_text = self.skip_whitespace_or_asterisk();
+
+        let tag_name_text = self.identifier_text(tag_name).to_owned();
+        match tag_name_text.as_str() {
+            "author" => self.parse_author_tag(start, tag_name, margin, &indent_text),
+            "implements" => self.parse_class_reference_tag(
+                start,
+                SyntaxKind::JSDocImplementsTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "augments" | "extends" => self.parse_class_reference_tag(
+                start,
+                SyntaxKind::JSDocAugmentsTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "class" | "constructor" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocClassTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "public" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocPublicTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "private" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocPrivateTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "protected" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocProtectedTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "readonly" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocReadonlyTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "override" => self.parse_simple_tag(
+                start,
+                SyntaxKind::JSDocOverrideTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "deprecated" => {
+                self.has_deprecated_tag = true;
+                self.parse_simple_tag(
+                    start,
+                    SyntaxKind::JSDocDeprecatedTag,
+                    tag_name,
+                    margin,
+                    &indent_text,
+                )
+            }
+            "this" => self.parse_type_expression_tag(
+                start,
+                SyntaxKind::JSDocThisTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "enum" => self.parse_type_expression_tag(
+                start,
+                SyntaxKind::JSDocEnumTag,
+                tag_name,
+                margin,
+                &indent_text,
+            ),
+            "arg" | "argument" | "param" => self.parse_parameter_or_property_tag(
+                start,
+                tag_name,
+                PropertyLikeParse::PARAMETER,
+                margin,
+            ),
+            "return" | "returns" => self.parse_return_tag(start, tag_name, margin, &indent_text),
+            "template" => self.parse_template_tag(start, tag_name, margin, &indent_text),
+            "type" => self.parse_type_tag(start, tag_name, Some((margin, &indent_text))),
+            "typedef" => self.parse_typedef_tag(start, tag_name, margin, &indent_text),
+            "callback" => self.parse_callback_tag(start, tag_name, margin, &indent_text),
+            "see" => self.parse_see_tag(start, tag_name, margin, &indent_text),
+            _ => self.parse_simple_tag(start, SyntaxKind::JSDocTag, tag_name, margin, &indent_text),
+        }
+    }
+
+    fn parse_trailing_tag_comments(
+        &mut self,
+        pos: usize,
+        end: usize,
+        margin: usize,
+        indent_text: &str,
+    ) -> Option<JSDocCommentText> {
+        let mut margin = margin;
+        // some tags, like typedef and callback, have already parsed their comments earlier
+        if indent_text.is_empty() {
+            margin += end - pos;
+        }
+        self.parse_tag_comments(margin, Some(slice_utf16(indent_text, margin as isize)))
+    }
+
+    fn parse_tag_comments(
+        &mut self,
+        indent: usize,
+        initial_margin: Option<String>,
+    ) -> Option<JSDocCommentText> {
+        let comments_pos = self.get_node_pos();
+        let mut buffer = JSDocCommentBuffer::new(indent);
+        let mut parts: Vec<NodeId> = Vec::new();
+        let mut link_end: Option<usize> = None;
+        let mut state = JSDocState::BeginningOfLine;
+        let mut previous_whitespace = true;
+        if let Some(initial_margin) = initial_margin {
+            // jump straight to saving comments if there is some initial indentation
+            if !initial_margin.is_empty() {
+                buffer.push_comment(initial_margin);
+            }
+            state = JSDocState::SawAsterisk;
+        }
+        let mut tok = self.token();
+        loop {
+            match tok {
+                SyntaxKind::NewLineTrivia => {
+                    state = JSDocState::BeginningOfLine;
+                    // don't use pushComment here because we want to keep the margin unchanged
+                    buffer.comments.push(self.scanner.get_token_text());
+                    buffer.indent = 0;
+                }
+                SyntaxKind::AtToken => {
+                    if state == JSDocState::SavingBackticks
+                        || state == JSDocState::SavingComments
+                            && (!previous_whitespace
+                                || self.look_ahead(Self::is_next_js_doc_token_whitespace))
+                    {
+                        // @ doesn't start a new tag inside ``, and inside a comment, only after whitespace or not before whitespace
+                        buffer.comments.push(self.scanner.get_token_text());
+                    } else {
+                        let text_pos = self.scanner.get_text_pos();
+                        self.scanner.set_text_pos(Some(text_pos - 1));
+                        break;
+                    }
+                }
+                SyntaxKind::EndOfFileToken => {
+                    // Done
+                    break;
+                }
+                SyntaxKind::WhitespaceTrivia => {
+                    let whitespace = self.scanner.get_token_text();
+                    if state == JSDocState::SavingComments || state == JSDocState::SavingBackticks {
+                        buffer.push_comment(whitespace);
+                    } else {
+                        // if the whitespace crosses the margin, take only the whitespace that passes the margin
+                        buffer.push_whitespace_past_margin(whitespace);
+                    }
+                }
+                SyntaxKind::OpenBraceToken => {
+                    state = JSDocState::SavingComments;
+                    let comment_end = self.scanner.get_start_pos();
+                    let link_start = self.scanner.get_text_pos() - 1;
+                    match self.parse_js_doc_link(link_start) {
+                        Some(link) => {
+                            let text = buffer.take_text();
+                            parts.push(self.finish_node_at(
+                                Node::new(SyntaxKind::JSDocText, NodeData::JSDocText { text }),
+                                link_end.unwrap_or(comments_pos),
+                                comment_end,
+                            ));
+                            parts.push(link);
+                            link_end = Some(self.scanner.get_text_pos());
+                        }
+                        None => buffer.push_comment(self.scanner.get_token_text()),
+                    }
+                }
+                SyntaxKind::BacktickToken => {
+                    if state == JSDocState::SavingBackticks {
+                        state = JSDocState::SavingComments;
+                    } else {
+                        state = JSDocState::SavingBackticks;
+                    }
+                    buffer.push_comment(self.scanner.get_token_text());
+                }
+                SyntaxKind::AsteriskToken if state == JSDocState::BeginningOfLine => {
+                    // leading asterisks start recording on the *next* (non-whitespace) token
+                    state = JSDocState::SawAsterisk;
+                    buffer.indent += 1;
+                }
+                _ => {
+                    if state != JSDocState::SavingBackticks {
+                        state = JSDocState::SavingComments; // leading identifiers start recording as well
+                    }
+                    buffer.push_comment(self.scanner.get_token_text());
+                }
+            }
+            previous_whitespace = self.token() == SyntaxKind::WhitespaceTrivia;
+            tok = self.next_token_js_doc();
+        }
+
+        buffer.remove_leading_newlines();
+        buffer.remove_trailing_whitespace();
+        if !parts.is_empty() {
+            if !buffer.comments.is_empty() {
+                let text = buffer.take_text();
+                parts.push(self.finish_node(
+                    Node::new(SyntaxKind::JSDocText, NodeData::JSDocText { text }),
+                    link_end.unwrap_or(comments_pos),
+                ));
+            }
+            let end = self.scanner.get_text_pos();
+            Some(JSDocCommentText::Nodes(self.create_node_array(
+                parts,
+                comments_pos,
+                Some(end),
+                false,
+            )))
+        } else if !buffer.comments.is_empty() {
+            Some(JSDocCommentText::Text(buffer.take_text()))
+        } else {
+            None
+        }
+    }
+
+    fn is_next_js_doc_token_whitespace(&mut self) -> bool {
+        let next = self.next_token_js_doc();
+        next == SyntaxKind::WhitespaceTrivia || next == SyntaxKind::NewLineTrivia
+    }
+
+    fn parse_js_doc_link(&mut self, start: usize) -> Option<NodeId> {
+        let link_type = self.try_parse(Self::parse_js_doc_link_prefix)?;
+        self.next_token_js_doc(); // start at token after link, then skip any whitespace
+        self.skip_whitespace();
+        // parseEntityName logs an error for non-identifier, so create a MissingNode ourselves to avoid the error
+        let p2 = self.get_node_pos();
+        let mut name = None;
+        if token_is_identifier_or_keyword(self.token()) {
+            let mut entity_name = self.parse_entity_name(true, None);
+            while self.token() == SyntaxKind::PrivateIdentifier {
+                self.re_scan_hash_token(); // rescan #id as # id
+                self.next_token_js_doc(); // then skip the #
+                entity_name = self.parse_js_doc_member_name(entity_name, p2);
+            }
+            name = Some(entity_name);
+        }
+        let mut text = String::new();
+        while self.token() != SyntaxKind::CloseBraceToken
+            && self.token() != SyntaxKind::NewLineTrivia
+            && self.token() != SyntaxKind::EndOfFileToken
+        {
+            text.push_str(&self.scanner.get_token_text());
+            self.next_token_js_doc();
+        }
+        let kind = match link_type.as_str() {
+            "link" => SyntaxKind::JSDocLink,
+            "linkcode" => SyntaxKind::JSDocLinkCode,
+            _ => SyntaxKind::JSDocLinkPlain,
+        };
+        let end = self.scanner.get_text_pos();
+        Some(self.finish_node_at(
+            Node::new(kind, NodeData::JSDocLink { name, text }),
+            start,
+            end,
+        ))
+    }
+
+    fn parse_js_doc_link_prefix(&mut self) -> Option<String> {
+        self.skip_whitespace_or_asterisk();
+        if self.token() == SyntaxKind::OpenBraceToken
+            && self.next_token_js_doc() == SyntaxKind::AtToken
+            && token_is_identifier_or_keyword(self.next_token_js_doc())
+        {
+            let kind = self.scanner.get_token_value();
+            if kind == "link" || kind == "linkcode" || kind == "linkplain" {
+                return Some(kind);
+            }
+        }
+        None
+    }
+
+    fn finish_js_doc_tag(&mut self, kind: SyntaxKind, tag: JSDocTag, start: usize) -> NodeId {
+        self.finish_node(Node::new(kind, NodeData::JSDocTag(Box::new(tag))), start)
+    }
+
+    fn js_doc_tag(&self, node: NodeId) -> &JSDocTag {
+        match &self.node(node).data {
+            NodeData::JSDocTag(tag) => tag,
+            _ => unreachable!("expected a JSDoc tag"),
+        }
+    }
+
+    // The type of a `JSDocTypeExpression`.
+    fn js_doc_type_expression_type(&self, node: NodeId) -> Option<NodeId> {
+        match &self.node(node).data {
+            NodeData::Type { _type } if self.kind(node) == SyntaxKind::JSDocTypeExpression => {
+                *_type
+            }
+            _ => None,
+        }
+    }
+
+    fn try_parse_type_expression(&mut self) -> Option<NodeId> {
+        self.skip_whitespace_or_asterisk();
+        if self.token() == SyntaxKind::OpenBraceToken {
+            Some(self.parse_js_doc_type_expression(false))
+        } else {
+            None
+        }
+    }
+
+    fn parse_bracket_name_in_property_and_param_tag(&mut self) -> (NodeId, bool) {
+        // Looking for something like '[foo]', 'foo', '[foo.bar]' or 'foo.bar'
+        let is_bracketed = self.parse_optional_js_doc(SyntaxKind::OpenBracketToken);
+        if is_bracketed {
+            self.skip_whitespace();
+        }
+        // a markdown-quoted name: `arg` is not legal jsdoc, but occurs in the wild
+        let is_backquoted = self.parse_optional_js_doc(SyntaxKind::BacktickToken);
+        let name = self.parse_js_doc_entity_name();
+        if is_backquoted {
+            self.parse_expected_token_js_doc(SyntaxKind::BacktickToken);
+        }
+        if is_bracketed {
+            self.skip_whitespace();
+            // May have an optional default, e.g. '[foo = 42]'
+            if self.parse_optional_token(SyntaxKind::EqualsToken).is_some() {
+                self.parse_expression();
+            }
+
+            self.parse_expected(SyntaxKind::CloseBracketToken);
+        }
+
+        (name, is_bracketed)
+    }
+
+    fn is_object_or_object_array_type_reference(&self, node: NodeId) -> bool {
+        match &self.node(node).data {
+            _ if self.kind(node) == SyntaxKind::ObjectKeyword => true,
+            NodeData::ArrayType { element_type } => {
+                self.is_object_or_object_array_type_reference(*element_type)
+            }
+            NodeData::TypeReference {
+                type_name,
+                type_arguments: None,
+            } => {
+                self.kind(*type_name) == SyntaxKind::Identifier
+                    && self.identifier_text(*type_name) == "Object"
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_parameter_or_property_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        target: u32,
+        indent: usize,
+    ) -> NodeId {
+        let mut type_expression = self.try_parse_type_expression();
+        let mut is_name_first = type_expression.is_none();
+        self.skip_whitespace_or_asterisk();
+
+        let (name, is_bracketed) = self.parse_bracket_name_in_property_and_param_tag();
+        let indent_text = self.skip_whitespace_or_asterisk();
+
+        if is_name_first && self.look_ahead(Self::parse_js_doc_link_prefix).is_none() {
+            type_expression = self.try_parse_type_expression();
+        }
+
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, indent, &indent_text);
+
+        if target != PropertyLikeParse::CALLBACK_PARAMETER {
+            if let Some(nested_type_literal) =
+                self.parse_nested_type_literal(type_expression, name, target, indent)
+            {
+                type_expression = Some(nested_type_literal);
+                is_name_first = true;
+            }
+        }
+        let kind = if target == PropertyLikeParse::PROPERTY {
+            SyntaxKind::JSDocPropertyTag
+        } else {
+            SyntaxKind::JSDocParameterTag
+        };
+        let tag = JSDocTag {
+            type_expression,
+            name: Some(name),
+            is_name_first,
+            is_bracketed,
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(kind, tag, start)
+    }
+
+    fn parse_nested_type_literal(
+        &mut self,
+        type_expression: Option<NodeId>,
+        name: NodeId,
+        target: u32,
+        indent: usize,
+    ) -> Option<NodeId> {
+        let _type = self.js_doc_type_expression_type(type_expression?)?;
+        if !self.is_object_or_object_array_type_reference(_type) {
+            return None;
+        }
+        let pos = self.get_node_pos();
+        let mut children = Vec::new();
+        while let Some(child) = self.try_parse(|parser| {
+            parser.parse_child_parameter_or_property_tag(target, indent, Some(name))
+        }) {
+            if self.kind(child) == SyntaxKind::JSDocParameterTag
+                || self.kind(child) == SyntaxKind::JSDocPropertyTag
+            {
+                children.push(child);
+            }
+        }
+        if children.is_empty() {
+            return None;
+        }
+        let is_array_type = self.kind(_type) == SyntaxKind::ArrayType;
+        let literal = self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocTypeLiteral,
+                NodeData::JSDocTypeLiteral {
+                    js_doc_property_tags: Some(children),
+                    is_array_type,
+                },
+            ),
+            pos,
+        );
+        Some(self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocTypeExpression,
+                NodeData::Type {
+                    _type: Some(literal),
+                },
+            ),
+            pos,
+        ))
+    }
+
+    fn report_duplicate_tag(&mut self, tag_name: NodeId, kind: SyntaxKind) {
+        if self.js_doc_tags.iter().any(|&tag| self.kind(tag) == kind) {
+            let tag_name_pos = self.node(tag_name).pos();
+            let tag_name_text = self.identifier_text(tag_name).to_owned();
+            let end = self.scanner.get_token_pos();
+            self.parse_error_at(
+                tag_name_pos,
+                end,
+                &Diagnostics::_0_tag_already_specified,
+                &[&tag_name_text],
+            );
+        }
+    }
+
+    fn parse_return_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        self.report_duplicate_tag(tag_name, SyntaxKind::JSDocReturnTag);
+
+        let type_expression = self.try_parse_type_expression();
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, indent, indent_text);
+        let tag = JSDocTag {
+            type_expression,
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(SyntaxKind::JSDocReturnTag, tag, start)
+    }
+
+    fn parse_type_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: Option<(usize, &str)>,
+    ) -> NodeId {
+        self.report_duplicate_tag(tag_name, SyntaxKind::JSDocTypeTag);
+
+        let type_expression = self.parse_js_doc_type_expression(true);
+        let comment = match indent {
+            Some((indent, indent_text)) => {
+                let end = self.get_node_pos();
+                self.parse_trailing_tag_comments(start, end, indent, indent_text)
+            }
+            None => None,
+        };
+        let tag = JSDocTag {
+            type_expression: Some(type_expression),
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(SyntaxKind::JSDocTypeTag, tag, start)
+    }
+
+    fn parse_see_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let is_link = self.look_ahead(|parser| {
+            parser.next_token_js_doc() == SyntaxKind::AtToken
+                && token_is_identifier_or_keyword(parser.next_token_js_doc())
+                && parser.scanner.get_token_value() == "link"
+        });
+        let name_expression = if is_link {
+            None
+        } else {
+            Some(self.parse_js_doc_name_reference())
+        };
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, indent, indent_text);
+        let tag = JSDocTag {
+            name: name_expression,
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(SyntaxKind::JSDocSeeTag, tag, start)
+    }
+
+    fn parse_author_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let comment_start = self.get_node_pos();
+        let text_only = self.parse_author_name_and_email();
+        let mut comment_end = self.scanner.get_start_pos();
+        let comments = self.parse_trailing_tag_comments(start, comment_end, indent, indent_text);
+        if comments.is_none() {
+            comment_end = self.scanner.get_start_pos();
+        }
+        let all_parts = match comments {
+            Some(JSDocCommentText::Text(comments)) => JSDocCommentText::Text(text_only + &comments),
+            comments => {
+                let text_only = self.finish_node_at(
+                    Node::new(
+                        SyntaxKind::JSDocText,
+                        NodeData::JSDocText { text: text_only },
+                    ),
+                    comment_start,
+                    comment_end,
+                );
+                let mut parts = vec![text_only];
+                if let Some(JSDocCommentText::Nodes(comments)) = comments {
+                    parts.extend(comments.nodes);
+                }
+                JSDocCommentText::Nodes(self.create_node_array(parts, comment_start, None, false))
+            }
+        };
+        let tag = create_js_doc_tag(tag_name, Some(all_parts));
+        self.finish_js_doc_tag(SyntaxKind::JSDocAuthorTag, tag, start)
+    }
+
+    fn parse_author_name_and_email(&mut self) -> String {
+        let mut comments = String::new();
+        let mut in_email = false;
+        let mut token = self.token();
+        while token != SyntaxKind::EndOfFileToken && token != SyntaxKind::NewLineTrivia {
+            if token == SyntaxKind::LessThanToken {
+                in_email = true;
+            } else if token == SyntaxKind::AtToken && !in_email {
+                break;
+            } else if token == SyntaxKind::GreaterThanToken && in_email {
+                comments.push_str(&self.scanner.get_token_text());
+                let token_pos = self.scanner.get_token_pos();
+                self.scanner.set_text_pos(Some(token_pos + 1));
+                break;
+            }
+            comments.push_str(&self.scanner.get_token_text());
+            token = self.next_token_js_doc();
+        }
+
+        comments
+    }
+
+    // `@implements` and `@augments` (or `@extends`)
+    fn parse_class_reference_tag(
+        &mut self,
+        start: usize,
+        kind: SyntaxKind,
+        tag_name: NodeId,
+        margin: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let class_name = self.parse_expression_with_type_arguments_for_augments();
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, margin, indent_text);
+        let tag = JSDocTag {
+            class: Some(class_name),
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(kind, tag, start)
+    }
+
+    fn parse_expression_with_type_arguments_for_augments(&mut self) -> NodeId {
+        let used_brace = self.parse_optional(SyntaxKind::OpenBraceToken);
+        let pos = self.get_node_pos();
+        let expression = self.parse_property_access_entity_name_expression();
+        let type_arguments = self.try_parse_type_arguments();
+        let res = self.finish_node(
+            Node::new(
+                SyntaxKind::ExpressionWithTypeArguments,
+                NodeData::ExpressionWithTypeArguments {
+                    expression,
+                    type_arguments,
+                },
+            ),
+            pos,
+        );
+        if used_brace {
+            self.parse_expected(SyntaxKind::CloseBraceToken);
+        }
+        res
+    }
+
+    fn parse_property_access_entity_name_expression(&mut self) -> NodeId {
+        let pos = self.get_node_pos();
+        let mut node = self.parse_js_doc_identifier_name(None);
+        while self.parse_optional(SyntaxKind::DotToken) {
+            let name = self.parse_js_doc_identifier_name(None);
+            node = self.finish_node(
+                Node::new(
+                    SyntaxKind::PropertyAccessExpression,
+                    NodeData::PropertyAccessExpression {
+                        expression: node,
+                        question_dot_token: None,
+                        name,
+                    },
+                ),
+                pos,
+            );
+        }
+        node
+    }
+
+    // Tags with nothing but a comment, like `@public` or an unknown tag.
+    fn parse_simple_tag(
+        &mut self,
+        start: usize,
+        kind: SyntaxKind,
+        tag_name: NodeId,
+        margin: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, margin, indent_text);
+        self.finish_js_doc_tag(kind, create_js_doc_tag(tag_name, comment), start)
+    }
+
+    // `@this` and `@enum`
+    fn parse_type_expression_tag(
+        &mut self,
+        start: usize,
+        kind: SyntaxKind,
+        tag_name: NodeId,
+        margin: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let type_expression = self.parse_js_doc_type_expression(true);
+        self.skip_whitespace();
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, margin, indent_text);
+        let tag = JSDocTag {
+            type_expression: Some(type_expression),
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(kind, tag, start)
+    }
+
+    fn parse_typedef_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let mut type_expression = self.try_parse_type_expression();
+        self.skip_whitespace_or_asterisk();
+
+        let full_name = self.parse_js_doc_type_name_with_namespace(false);
+        self.skip_whitespace();
+        let mut comment = self.parse_tag_comments(indent, None);
+
+        let mut end = None;
+        let type_expression_type =
+            type_expression.and_then(|node| self.js_doc_type_expression_type(node));
+        if type_expression.is_none()
+            || type_expression_type
+                .is_some_and(|node| self.is_object_or_object_array_type_reference(node))
+        {
+            let mut child_type_tag = None;
+            let mut js_doc_property_tags: Option<Vec<NodeId>> = None;
+            let mut has_children = false;
+            while let Some(child) = self.try_parse(|parser| parser.parse_child_property_tag(indent))
+            {
+                has_children = true;
+                if self.kind(child) == SyntaxKind::JSDocTypeTag {
+                    if child_type_tag.is_some() {
+                        // The JS compiler also relates "The tag was first specified here." to this
+                        // error; `DiagnosticWithLocation` has no related information to put it in.
+                        self.parse_error_at_current_token(
+                            &Diagnostics::A_JSDoc_typedef_comment_may_not_contain_multiple_type_tags,
+                            &[],
+                        );
+                        break;
+                    } else {
+                        child_type_tag = Some(child);
+                    }
+                } else {
+                    js_doc_property_tags
+                        .get_or_insert_with(Vec::new)
+                        .push(child);
+                }
+            }
+            if has_children {
+                let is_array_type = type_expression_type
+                    .is_some_and(|node| self.kind(node) == SyntaxKind::ArrayType);
+                let child_type_expression = child_type_tag
+                    .and_then(|tag| self.js_doc_tag(tag).type_expression)
+                    .filter(|&child_type_expression| {
+                        !self
+                            .js_doc_type_expression_type(child_type_expression)
+                            .is_some_and(|node| self.is_object_or_object_array_type_reference(node))
+                    });
+                let literal_or_type_expression = match child_type_expression {
+                    Some(child_type_expression) => child_type_expression,
+                    None => self.finish_node(
+                        Node::new(
+                            SyntaxKind::JSDocTypeLiteral,
+                            NodeData::JSDocTypeLiteral {
+                                js_doc_property_tags,
+                                is_array_type,
+                            },
+                        ),
+                        start,
+                    ),
+                };
+                type_expression = Some(literal_or_type_expression);
+                end = Some(self.node(literal_or_type_expression).end());
+            }
+        }
+
+        // Only include the characters between the name end and the next token if a comment was actually parsed out - otherwise it's just whitespace
+        let end = if end.is_some() || comment.is_some() {
+            self.get_node_pos()
+        } else {
+            self.node(full_name.or(type_expression).unwrap_or(tag_name))
+                .end()
+        };
+
+        if comment.is_none() {
+            comment = self.parse_trailing_tag_comments(start, end, indent, indent_text);
+        }
+
+        let tag = JSDocTag {
+            type_expression,
+            name: self.get_js_doc_type_alias_name(full_name),
+            full_name,
+            is_name_first: type_expression
+                .is_none_or(|node| self.kind(node) != SyntaxKind::JSDocTypeExpression),
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_node_at(
+            Node::new(
+                SyntaxKind::JSDocTypedefTag,
+                NodeData::JSDocTag(Box::new(tag)),
+            ),
+            start,
+            end,
+        )
+    }
+
+    // The rightmost name of the (possibly namespaced) name of a `@typedef` or `@callback`.
+    fn get_js_doc_type_alias_name(&self, full_name: Option<NodeId>) -> Option<NodeId> {
+        let mut right_node = full_name?;
+        loop {
+            match &self.node(right_node).data {
+                NodeData::ModuleDeclaration {
+                    body: Some(body), ..
+                } => right_node = *body,
+                NodeData::ModuleDeclaration { name, body: None } => return Some(*name),
+                _ => return Some(right_node),
+            }
+        }
+    }
+
+    fn parse_js_doc_type_name_with_namespace(&mut self, nested: bool) -> Option<NodeId> {
+        let pos = self.scanner.get_token_pos();
+        if !token_is_identifier_or_keyword(self.token()) {
+            return None;
+        }
+        let type_name_or_namespace_name = self.parse_js_doc_identifier_name(None);
+        if self.parse_optional(SyntaxKind::DotToken) {
+            let body = self.parse_js_doc_type_name_with_namespace(true);
+            let mut js_doc_namespace_node = Node::new(
+                SyntaxKind::ModuleDeclaration,
+                NodeData::ModuleDeclaration {
+                    name: type_name_or_namespace_name,
+                    body,
+                },
+            );
+            if nested {
+                js_doc_namespace_node.flags |= NodeFlags::NESTED_NAMESPACE;
+            }
+            return Some(self.finish_node(js_doc_namespace_node, pos));
+        }
+
+        if nested {
+            if let NodeData::Identifier {
+                is_in_jsdoc_namespace,
+                ..
+            } = &mut self.node_mut(type_name_or_namespace_name).data
+            {
+                *is_in_jsdoc_namespace = true;
+            }
+        }
+        Some(type_name_or_namespace_name)
+    }
+
+    fn parse_callback_tag_parameters(&mut self, indent: usize) -> Vec<NodeId> {
+        let mut parameters = Vec::new();
+        while let Some(child) = self.try_parse(|parser| {
+            parser.parse_child_parameter_or_property_tag(
+                PropertyLikeParse::CALLBACK_PARAMETER,
+                indent,
+                None,
+            )
+        }) {
+            parameters.push(child);
+        }
+        parameters
+    }
+
+    fn parse_callback_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        let full_name = self.parse_js_doc_type_name_with_namespace(false);
+        self.skip_whitespace();
+        let mut comment = self.parse_tag_comments(indent, None);
+        let parameters = self.parse_callback_tag_parameters(indent);
+        let return_tag = self.try_parse(|parser| {
+            if parser.parse_optional_js_doc(SyntaxKind::AtToken) {
+                let tag = parser.parse_tag(indent);
+                if parser.kind(tag) == SyntaxKind::JSDocReturnTag {
+                    return Some(tag);
+                }
+            }
+            None
+        });
+        let type_expression = self.finish_node(
+            Node::new(
+                SyntaxKind::JSDocSignature,
+                NodeData::JSDocSignature {
+                    type_parameters: None,
+                    parameters,
+                    _type: return_tag,
+                },
+            ),
+            start,
+        );
+        if comment.is_none() {
+            let end = self.get_node_pos();
+            comment = self.parse_trailing_tag_comments(start, end, indent, indent_text);
+        }
+        let tag = JSDocTag {
+            type_expression: Some(type_expression),
+            name: self.get_js_doc_type_alias_name(full_name),
+            full_name,
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(SyntaxKind::JSDocCallbackTag, tag, start)
+    }
+
+    fn escaped_texts_equal(&self, a: NodeId, b: NodeId) -> bool {
+        let (mut a, mut b) = (a, b);
+        loop {
+            match (&self.node(a).data, &self.node(b).data) {
+                (
+                    NodeData::Identifier { text: a_text, .. },
+                    NodeData::Identifier { text: b_text, .. },
+                ) => return a_text == b_text,
+                (
+                    NodeData::QualifiedName {
+                        left: a_left,
+                        right: a_right,
+                        ..
+                    },
+                    NodeData::QualifiedName {
+                        left: b_left,
+                        right: b_right,
+                        ..
+                    },
+                ) if self.identifier_text(*a_right) == self.identifier_text(*b_right) => {
+                    a = *a_left;
+                    b = *b_left;
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    fn parse_child_property_tag(&mut self, indent: usize) -> Option<NodeId> {
+        self.parse_child_parameter_or_property_tag(PropertyLikeParse::PROPERTY, indent, None)
+    }
+
+    fn parse_child_parameter_or_property_tag(
+        &mut self,
+        target: u32,
+        indent: usize,
+        name: Option<NodeId>,
+    ) -> Option<NodeId> {
+        let mut can_parse_tag = true;
+        let mut seen_asterisk = false;
+        loop {
+            match self.next_token_js_doc() {
+                SyntaxKind::AtToken => {
+                    if can_parse_tag {
+                        let child = self.try_parse_child_tag(target, indent)?;
+                        if let Some(name) = name {
+                            let child_kind = self.kind(child);
+                            if (child_kind == SyntaxKind::JSDocParameterTag
+                                || child_kind == SyntaxKind::JSDocPropertyTag)
+                                && target != PropertyLikeParse::CALLBACK_PARAMETER
+                            {
+                                let child_name = self
+                                    .js_doc_tag(child)
+                                    .name
+                                    .expect("property-like tags have a name");
+                                let is_nested_in_name = match &self.node(child_name).data {
+                                    NodeData::QualifiedName { left, .. } => {
+                                        self.escaped_texts_equal(name, *left)
+                                    }
+                                    _ => false,
+                                };
+                                if !is_nested_in_name {
+                                    return None;
+                                }
+                            }
+                        }
+                        return Some(child);
+                    }
+                    seen_asterisk = false;
+                }
+                SyntaxKind::NewLineTrivia => {
+                    can_parse_tag = true;
+                    seen_asterisk = false;
+                }
+                SyntaxKind::AsteriskToken => {
+                    if seen_asterisk {
+                        can_parse_tag = false;
+                    }
+                    seen_asterisk = true;
+                }
+                SyntaxKind::Identifier => can_parse_tag = false,
+                SyntaxKind::EndOfFileToken => return None,
+                _ => {}
+            }
+        }
+    }
+
+    fn try_parse_child_tag(&mut self, target: u32, indent: usize) -> Option<NodeId> {
+        let start = self.scanner.get_start_pos();
+        self.next_token_js_doc();
+
+        let tag_name = self.parse_js_doc_identifier_name(None);
+        self.skip_whitespace();
+        let tag_name_text = self.identifier_text(tag_name).to_owned();
+        let t = match tag_name_text.as_str() {
+            "type" => {
+                return if target == PropertyLikeParse::PROPERTY {
+                    Some(self.parse_type_tag(start, tag_name, None))
+                } else {
+                    None
+                };
+            }
+            "prop" | "property" => PropertyLikeParse::PROPERTY,
+            "arg" | "argument" | "param" => {
+                PropertyLikeParse::PARAMETER | PropertyLikeParse::CALLBACK_PARAMETER
+            }
+            _ => return None,
+        };
+        if target & t == 0 {
+            return None;
+        }
+        Some(self.parse_parameter_or_property_tag(start, tag_name, target, indent))
+    }
+
+    fn parse_template_tag_type_parameter(&mut self) -> Option<NodeId> {
+        let type_parameter_pos = self.get_node_pos();
+        let name = self.parse_js_doc_identifier_name(Some(
+            &Diagnostics::Unexpected_token_A_type_parameter_name_was_expected_without_curly_braces,
+        ));
+        if node_is_missing(self.node(name)) {
+            return None;
+        }
+        Some(self.finish_node(
+            Node::new(
+                SyntaxKind::TypeParameter,
+                NodeData::TypeParameter {
+                    name,
+                    constraint: None,
+                    default: None,
+                    expression: None,
+                },
+            ),
+            type_parameter_pos,
+        ))
+    }
+
+    fn parse_template_tag_type_parameters(&mut self) -> NodeArray {
+        let pos = self.get_node_pos();
+        let mut type_parameters = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if let Some(node) = self.parse_template_tag_type_parameter() {
+                type_parameters.push(node);
+            }
+            self.skip_whitespace_or_asterisk();
+            if !self.parse_optional_js_doc(SyntaxKind::CommaToken) {
+                break;
+            }
+        }
+        self.create_node_array(type_parameters, pos, None, false)
+    }
+
+    fn parse_template_tag(
+        &mut self,
+        start: usize,
+        tag_name: NodeId,
+        indent: usize,
+        indent_text: &str,
+    ) -> NodeId {
+        // The template tag looks like one of the following:
+        //   @template T,U,V
+        //   @template {Constraint} T
+        //
+        // According to the [closure docs](https://github.com/google/closure-compiler/wiki/Generic-Types#multiple-bounded-template-types):
+        //   > Multiple bounded generics cannot be declared on the same line. For the sake of clarity, if multiple templates share the same
+        //   > type bound they must be declared on separate lines.
+        //
+        // TODO: Determine whether we should enforce this in the checker.
+        // TODO: Consider moving the `constraint` to the first type parameter as we could then remove `getEffectiveConstraintOfTypeParameter`.
+        // TODO: Consider only parsing a single type parameter if there is a constraint.
+        let constraint = if self.token() == SyntaxKind::OpenBraceToken {
+            Some(self.parse_js_doc_type_expression(false))
+        } else {
+            None
+        };
+        let type_parameters = self.parse_template_tag_type_parameters();
+        let end = self.get_node_pos();
+        let comment = self.parse_trailing_tag_comments(start, end, indent, indent_text);
+        let tag = JSDocTag {
+            constraint,
+            type_parameters: Some(type_parameters),
+            ..create_js_doc_tag(tag_name, comment)
+        };
+        self.finish_js_doc_tag(SyntaxKind::JSDocTemplateTag, tag, start)
+    }
+
+    fn parse_optional_js_doc(&mut self, t: SyntaxKind) -> bool {
+        if self.token() == t {
+            self.next_token_js_doc();
+            return true;
+        }
+        false
+    }
+
+    fn parse_js_doc_entity_name(&mut self) -> NodeId {
+        let mut entity = self.parse_js_doc_identifier_name(None);
+        if self.parse_optional(SyntaxKind::OpenBracketToken) {
+            self.parse_expected(SyntaxKind::CloseBracketToken);
+            // Note that y[] is accepted as an entity name, but the postfix brackets are not saved for checking.
+            // Technically usejsdoc.org requires them for specifying a property of a type equivalent to Array<{ x: ...}>
+            // but it's not worth it to enforce that restriction.
+        }
+        while self.parse_optional(SyntaxKind::DotToken) {
+            let name = self.parse_js_doc_identifier_name(None);
+            if self.parse_optional(SyntaxKind::OpenBracketToken) {
+                self.parse_expected(SyntaxKind::CloseBracketToken);
+            }
+            entity = self.create_qualified_name(entity, name);
+        }
+        entity
+    }
+
+    fn parse_js_doc_identifier_name(
+        &mut self,
+        message: Option<&IDiagnosticMessage<'static>>,
+    ) -> NodeId {
+        if !token_is_identifier_or_keyword(self.token()) {
+            return self.create_missing_node(
+                SyntaxKind::Identifier,
+                message.is_none(),
+                Some(message.unwrap_or(&Diagnostics::Identifier_expected)),
+                &[],
+            );
+        }
+
+        self.identifier_count += 1;
+        let pos = self.scanner.get_token_pos();
+        let end = self.scanner.get_text_pos();
+        let original_keyword_kind = match self.token() {
+            SyntaxKind::Keywords(keyword) => Some(keyword),
+            _ => None,
+        };
+        let text = self.scanner.token_value().to_owned();
+        let text = self.intern_identifier(&text);
+        let result = self.finish_node_at(
+            Node::new(
+                SyntaxKind::Identifier,
+                NodeData::Identifier {
+                    text,
+                    original_keyword_kind,
+                    is_in_jsdoc_namespace: false,
+                    jsdoc_dot_pos: None,
+                },
+            ),
+            pos,
+            end,
+        );
+        self.next_token_js_doc();
+        result
+    }
+}
+
+fn is_reusable_parsing_context(parsing_context: ParsingContext) -> bool {
+    matches!(
+        parsing_context,
+        ParsingContext::ClassMembers
+            | ParsingContext::SwitchClauses
+            | ParsingContext::SourceElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauseStatements
+            | ParsingContext::EnumMembers
+            | ParsingContext::TypeMembers
+            | ParsingContext::VariableDeclarations
+            | ParsingContext::JSDocParameters
+            | ParsingContext::Parameters
+    )
+}
+
+fn is_reusable_switch_clause(node: &Node) -> bool {
+    matches!(
+        node.kind,
+        SyntaxKind::CaseClause | SyntaxKind::DefaultClause
+    )
+}
+
+fn is_reusable_statement(node: &Node) -> bool {
+    matches!(
+        node.kind,
+        SyntaxKind::FunctionDeclaration
+            | SyntaxKind::VariableStatement
+            | SyntaxKind::Block
+            | SyntaxKind::IfStatement
+            | SyntaxKind::ExpressionStatement
+            | SyntaxKind::ThrowStatement
+            | SyntaxKind::ReturnStatement
+            | SyntaxKind::SwitchStatement
+            | SyntaxKind::BreakStatement
+            | SyntaxKind::ContinueStatement
+            | SyntaxKind::ForInStatement
+            | SyntaxKind::ForOfStatement
+            | SyntaxKind::ForStatement
+            | SyntaxKind::WhileStatement
+            | SyntaxKind::WithStatement
+            | SyntaxKind::EmptyStatement
+            | SyntaxKind::TryStatement
+            | SyntaxKind::LabeledStatement
+            | SyntaxKind::DoStatement
+            | SyntaxKind::DebuggerStatement
+            | SyntaxKind::ImportDeclaration
+            | SyntaxKind::ImportEqualsDeclaration
+            | SyntaxKind::ExportDeclaration
+            | SyntaxKind::ExportAssignment
+            | SyntaxKind::ModuleDeclaration
+            | SyntaxKind::ClassDeclaration
+            | SyntaxKind::InterfaceDeclaration
+            | SyntaxKind::EnumDeclaration
+            | SyntaxKind::TypeAliasDeclaration
+    )
+}
+
+fn is_reusable_type_member(node: &Node) -> bool {
+    matches!(
+        node.kind,
+        SyntaxKind::ConstructSignature
+            | SyntaxKind::MethodSignature
+            | SyntaxKind::IndexSignature
+            | SyntaxKind::PropertySignature
+            | SyntaxKind::CallSignature
+    )
+}
+
+fn is_reusable_variable_declaration(node: &Node) -> bool {
+    if node.kind != SyntaxKind::VariableDeclaration {
+        return false;
+    }
+
+    // Very subtle incremental parsing bug.  Consider the following code:
+    //
+    //      let v = new List < A, B
+    //
+    // This is actually legal code.  It's a list of variable declarators "v = new List<A"
+    // on one side and "B" on the other. If you then change that to:
+    //
+    //      let v = new List < A, B >()
+    //
+    // then we have a problem.  "v = new List<A" doesn't intersect the change range, so we
+    // start reparsing at "B" and we completely fail to handle this properly.
+    //
+    // In order to prevent this, we do not allow a variable declarator to be reused if it
+    // has an initializer.
+    node.initializer().is_none()
+}
+
+fn is_reusable_parameter(node: &Node) -> bool {
+    if node.kind != SyntaxKind::Parameter {
+        return false;
+    }
+
+    // See the comment in is_reusable_variable_declaration for why we do this.
+    node.initializer().is_none()
+}
+
+// Allows finding nodes in the old tree of an incremental parse at a certain position in an
+// efficient manner. The implementation takes advantage of the calling pattern it knows the
+// parser will make in order to optimize finding nodes as quickly as possible.
+struct SyntaxCursor {
+    root: NodeId,
+    // The nodes that intersect the edit; we know, up front, that we cannot reuse these.
+    intersects_change: HashSet<NodeId>,
+    // The list the current node was found in, and its index there.
+    current_array: Vec<NodeId>,
+    current_array_index: usize,
+    current: Option<NodeId>,
+    last_queried_position: Option<usize>,
+}
+
+impl SyntaxCursor {
+    fn new(nodes: &[Node], root: NodeId, intersects_change: HashSet<NodeId>) -> SyntaxCursor {
+        let current_array = match &nodes[root.index()].data {
+            NodeData::SourceFile { statements, .. } => statements.to_vec(),
+            _ => unreachable!("the root of a source file is a SourceFile node"),
+        };
+        debug_assert!(!current_array.is_empty());
+        SyntaxCursor {
+            root,
+            intersects_change,
+            current: current_array.first().copied(),
+            current_array,
+            current_array_index: 0,
+            last_queried_position: None,
+        }
+    }
+
+    fn current_node(&mut self, nodes: &[Node], position: usize) -> Option<NodeId> {
+        // Only compute the current node if the position is different than the last time
+        // we were asked.  The parser commonly asks for the node at the same position
+        // twice.  Once to know if can read an appropriate list element at a certain point,
+        // and then to actually read and consume the node.
+        if self.last_queried_position != Some(position) {
+            // Much of the time the parser will need the very next node in the array that
+            // we just returned a node from. So just simply check for that case and move
+            // forward in the array instead of searching for the node again.
+            if let Some(current) = self.current {
+                if nodes[current.index()].end() == position
+                    && self.current_array_index + 1 < self.current_array.len()
+                {
+                    self.current_array_index += 1;
+                    self.current = Some(self.current_array[self.current_array_index]);
+                }
+            }
+
+            // If we don't have a node, or the node we have isn't in the right position,
+            // then try to find a viable node at the position requested.
+            if self
+                .current
+                .is_none_or(|current| nodes[current.index()].pos() != position)
+            {
+                self.find_highest_list_element_that_starts_at_position(nodes, position);
+            }
+        }
+
+        // Cache this query so that we don't do any extra work if the parser calls back
+        // into us.  Note: this is very common as the parser will make pairs of calls like
+        // 'is_list_element -> parse_list_element'.  If we were unable to find a node when
+        // called with 'is_list_element', we don't want to redo the work when
+        // parse_list_element is called immediately after.
+        self.last_queried_position = Some(position);
+
+        // Either we don't have a node, or we have a node at the position being asked for.
+        debug_assert!(self
+            .current
+            .is_none_or(|current| nodes[current.index()].pos() == position));
+        self.current
+    }
+
+    // Finds the highest element in the tree we can find that starts at the provided position.
+    // The element must be a direct child of some node list in the tree.  This way after we
+    // return it, we can easily return its next sibling in the list.
+    fn find_highest_list_element_that_starts_at_position(
+        &mut self,
+        nodes: &[Node],
+        position: usize,
+    ) {
+        match find_list_element_in_children(nodes, self.root, position) {
+            Some((array, index)) => {
+                self.current = Some(array[index]);
+                self.current_array = array;
+                self.current_array_index = index;
+            }
+            None => {
+                self.current = None;
+                self.current_array = Vec::new();
+                self.current_array_index = 0;
+            }
+        }
+    }
+}
+
+fn find_list_element_in_children(
+    nodes: &[Node],
+    node: NodeId,
+    position: usize,
+) -> Option<(Vec<NodeId>, usize)> {
+    for_each_child(
+        &nodes[node.index()],
+        &mut |child| {
+            let child_node = &nodes[child.index()];
+            if position >= child_node.pos() && position < child_node.end() {
+                // Position was within this node.  Keep searching deeper to find the node, and
+                // don't proceed any further in the search.
+                return Some(find_list_element_in_children(nodes, child, position));
+            }
+
+            // position wasn't in this node, have to keep searching.
+            None
+        },
+        Some(&mut |array: &NodeArray| {
+            if position >= array.range.pos && position < array.range.end {
+                // position was in this array.  Search through this array to see if we find a
+                // viable element.
+                for (index, &child) in array.iter().enumerate() {
+                    let child_node = &nodes[child.index()];
+                    if child_node.pos() == position {
+                        // Found the right node.  We're done.
+                        return Some(Some((array.to_vec(), index)));
+                    }
+                    if child_node.pos() < position && position < child_node.end() {
+                        // Position in somewhere within this child.  Search in it and
+                        // stop searching in this array.
+                        return Some(find_list_element_in_children(nodes, child, position));
+                    }
+                }
+            }
+
+            // position wasn't in this array, have to keep searching.
+            None
+        }),
+    )
+    .flatten()
+}
+
+// The positions of the edit an incremental parse moves the nodes of the old tree by.
+struct ChangeAdjustment {
+    change_start: usize,
+    change_range_old_end: usize,
+    change_range_new_end: usize,
+    // The amount the nodes after the edit range need to be adjusted.  It can be positive (if
+    // the edit added characters), negative (if the edit deleted characters) or zero (if this
+    // was a pure overwrite with nothing added/removed).
+    delta: isize,
+}
+
+impl ChangeAdjustment {
+    // Moves the nodes of the old tree to their positions in the new text, and returns the ones
+    // that intersect the edit. The position of a node alone decides what happens to it, so
+    // rather than walking down the tree this is a single pass over the arena; the nodes earlier
+    // updates left out of the tree get moved as well, which does no harm.
+    fn update_token_positions_and_mark_elements(&self, nodes: &mut [Node]) -> HashSet<NodeId> {
+        let mut intersects_change = HashSet::new();
+        for (index, node) in nodes.iter_mut().enumerate() {
+            debug_assert!(node.pos() <= node.end());
+            if node.pos() > self.change_range_old_end {
+                // Node is entirely past the change range.  We need to move both its pos and
+                // end, forward or backward appropriately, along with the lists it holds.
+                node.range = move_range(node.range, self.delta);
+                for_each_child_array_mut(node, &mut |array| {
+                    array.range = move_range(array.range, self.delta)
+                });
+                if let NodeData::Identifier {
+                    jsdoc_dot_pos: Some(dot_pos),
+                    ..
+                }
+                | NodeData::QualifiedName {
+                    jsdoc_dot_pos: Some(dot_pos),
+                    ..
+                } = &mut node.data
+                {
+                    *dot_pos = (*dot_pos as isize + self.delta) as usize;
+                }
+            } else if node.end() >= self.change_start {
+                // The element intersects the change range.  It is not reusable, and we'll need
+                // to adjust its pos or end (or both) accordingly, and those of the lists it holds.
+                intersects_change.insert(NodeId(index as u32));
+                node.range = self.adjust_intersecting_element(node.range);
+                for_each_child_array_mut(node, &mut |array| {
+                    if array.range.pos > self.change_range_old_end {
+                        array.range = move_range(array.range, self.delta);
+                    } else if array.range.end >= self.change_start {
+                        array.range = self.adjust_intersecting_element(array.range);
+                    }
+                });
+            }
+            // Otherwise, the node is entirely before the change range.  No need to do anything with it.
+        }
+        intersects_change
+    }
+
+    fn adjust_intersecting_element(&self, element: TextRange) -> TextRange {
+        debug_assert!(
+            element.end >= self.change_start,
+            "Adjusting an element that was entirely before the change range"
+        );
+        debug_assert!(
+            element.pos <= self.change_range_old_end,
+            "Adjusting an element that was entirely after the change range"
+        );
+        debug_assert!(element.pos <= element.end);
+
+        // We have an element that intersects the change range in some way.  It may have its
+        // start, or its end (or both) in the changed range.  We want to adjust any part
+        // that intersects such that the final tree is in a consistent state.  i.e. all
+        // children have spans within the span of their parent, and all siblings are ordered
+        // properly.
+
+        // We may need to update both the 'pos' and the 'end' of the element.
+
+        // If the 'pos' is before the start of the change, then we don't need to touch it.
+        // If it isn't, then the 'pos' must be inside the change.  How we update it will
+        // depend if delta is positive or negative. If delta is positive then we have
+        // something like:
+        //
+        //  -------------------AAA-----------------
+        //  -------------------BBBCCCCCCC-----------------
+        //
+        // In this case, we consider any node that started in the change range to still be
+        // starting at the same position.
+        //
+        // however, if the delta is negative, then we instead have something like this:
+        //
+        //  -------------------XXXYYYYYYY-----------------
+        //  -------------------ZZZ-----------------
+        //
+        // In this case, any element that started in the 'X' range will keep its position.
+        // However any element that started after that will have their pos adjusted to be
+        // at the end of the new range.  i.e. any node that started in the 'Y' range will
+        // be adjusted to have their start at the end of the 'Z' range.
+        //
+        // The element will keep its position if possible.  Or Move backward to the new-end
+        // if it's in the 'Y' range.
+        let pos = element.pos.min(self.change_range_new_end);
+
+        // If the 'end' is after the change range, then we always adjust it by the delta
+        // amount.  However, if the end is in the change range, then how we adjust it
+        // will depend on if delta is positive or negative.  If delta is positive then we
+        // have something like:
+        //
+        //  -------------------AAA-----------------
+        //  -------------------BBBCCCCCCC-----------------
+        //
+        // In this case, we consider any node that ended inside the change range to keep its
+        // end position.
+        //
+        // however, if the delta is negative, then we instead have something like this:
+        //
+        //  -------------------XXXYYYYYYY-----------------
+        //  -------------------ZZZ-----------------
+        //
+        // In this case, any element that ended in the 'X' range will keep its position.
+        // However any element that ended after that will have their pos adjusted to be
+        // at the end of the new range.  i.e. any node that ended in the 'Y' range will
+        // be adjusted to have their end at the end of the 'Z' range.
+        let end = if element.end >= self.change_range_old_end {
+            // Element ends after the change range.  Always adjust the end pos.
+            (element.end as isize + self.delta) as usize
+        } else {
+            // Element ends in the change range.  The element will keep its position if
+            // possible. Or Move backward to the new-end if it's in the 'Y' range.
+            element.end.min(self.change_range_new_end)
+        };
+
+        debug_assert!(pos <= end);
+        TextRange { pos, end }
+    }
+}
+
+fn move_range(range: TextRange, delta: isize) -> TextRange {
+    TextRange {
+        pos: (range.pos as isize + delta) as usize,
+        end: (range.end as isize + delta) as usize,
+    }
+}
+
+fn push_child_nodes_and_js_doc(stack: &mut Vec<NodeId>, node: &Node) {
+    for_each_child(
+        node,
+        &mut |child| {
+            stack.push(child);
+            None::<()>
+        },
+        None,
+    );
+    stack.extend(node.js_doc.iter().flatten());
+}
+
+// Invokes a callback for each node list a node holds, including its decorators and modifiers.
+fn for_each_child_array_mut(node: &mut Node, cb: &mut dyn FnMut(&mut NodeArray)) {
+    fn comment_nodes(comment: &mut Option<JSDocCommentText>) -> Option<&mut NodeArray> {
+        match comment {
+            Some(JSDocCommentText::Nodes(nodes)) => Some(nodes),
+            _ => None,
+        }
+    }
+
+    let arrays: Vec<&mut NodeArray> = match &mut node.data {
+        NodeData::SignatureDeclaration(signature) => {
+            let signature = &mut **signature;
+            signature
+                .type_parameters
+                .as_mut()
+                .into_iter()
+                .chain(Some(&mut signature.parameters))
+                .collect()
+        }
+        NodeData::TypeReference { type_arguments, .. }
+        | NodeData::ImportType { type_arguments, .. }
+        | NodeData::TaggedTemplateExpression { type_arguments, .. }
+        | NodeData::ExpressionWithTypeArguments { type_arguments, .. }
+        | NodeData::JsxOpeningLikeElement { type_arguments, .. } => {
+            type_arguments.as_mut().into_iter().collect()
+        }
+        NodeData::TypeLiteral { members } | NodeData::EnumDeclaration { members, .. } => {
+            vec![members]
+        }
+        NodeData::TupleType { elements }
+        | NodeData::BindingPattern { elements }
+        | NodeData::ArrayLiteralExpression { elements, .. }
+        | NodeData::NamedImportsOrExports { elements } => vec![elements],
+        NodeData::UnionOrIntersectionType { types } | NodeData::HeritageClause { types, .. } => {
+            vec![types]
+        }
+        NodeData::ObjectLiteralExpression { properties, .. }
+        | NodeData::JsxAttributes { properties } => vec![properties],
+        NodeData::CallExpression {
+            type_arguments,
+            arguments,
+            ..
+        } => type_arguments
+            .as_mut()
+            .into_iter()
+            .chain(arguments.as_mut())
+            .collect(),
+        NodeData::TemplateExpression { template_spans, .. } => vec![template_spans],
+        NodeData::Block { statements, .. }
+        | NodeData::SourceFile { statements, .. }
+        | NodeData::CaseOrDefaultClause { statements, .. } => vec![statements],
+        NodeData::VariableDeclarationList { declarations } => vec![declarations],
+        NodeData::CaseBlock { clauses } => vec![clauses],
+        NodeData::ClassLikeDeclaration {
+            type_parameters,
+            heritage_clauses,
+            members,
+            ..
+        }
+        | NodeData::InterfaceDeclaration {
+            type_parameters,
+            heritage_clauses,
+            members,
+            ..
+        } => type_parameters
+            .as_mut()
+            .into_iter()
+            .chain(heritage_clauses.as_mut())
+            .chain(Some(members))
+            .collect(),
+        NodeData::TypeAliasDeclaration {
+            type_parameters, ..
+        } => type_parameters.as_mut().into_iter().collect(),
+        NodeData::JsxElement { children, .. } | NodeData::JsxFragment { children, .. } => {
+            vec![children]
+        }
+        NodeData::JSDoc { comment, tags } => comment_nodes(comment)
+            .into_iter()
+            .chain(tags.as_mut())
+            .collect(),
+        NodeData::JSDocTag(tag) => {
+            let tag = &mut **tag;
+            comment_nodes(&mut tag.comment)
+                .into_iter()
+                .chain(tag.type_parameters.as_mut())
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+    for array in node
+        .decorators
+        .iter_mut()
+        .chain(node.modifiers.iter_mut())
+        .chain(arrays)
+    {
+        cb(array);
+    }
+}
+
+fn extend_to_affected_range(
+    nodes: &[Node],
+    root: NodeId,
+    change_range: TextChangeRange,
+) -> TextChangeRange {
+    // Consider the following code:
+    //      void foo() { /; }
+    //
+    // If the text changes with an insertion of / just before the semicolon then we end up with:
+    //      void foo() { //; }
+    //
+    // If we were to just use the changeRange a is, then we would not rescan the { token
+    // (as it does not intersect the actual original change range).  Because an edit may
+    // change the token touching it, we actually need to look back *at least* one token so
+    // that the prior token sees that change.
+    const MAX_LOOKAHEAD: usize = 1;
+
+    let mut start = change_range.span.pos;
+
+    // the first iteration aligns us with the change start. subsequent iteration move us to
+    // the left by MAX_LOOKAHEAD tokens.  We only need to do this as long as we're not at the
+    // start of the tree.
+    for _ in 0..=MAX_LOOKAHEAD {
+        if start == 0 {
+            break;
+        }
+        let nearest_node = find_nearest_node_starting_before_or_at_position(nodes, root, start);
+        let position = nodes[nearest_node.index()].pos();
+        debug_assert!(position <= start);
+
+        start = position.saturating_sub(1);
+    }
+
+    let final_span = TextRange {
+        pos: start,
+        end: change_range.span.end,
+    };
+    let final_length = change_range.new_length + (change_range.span.pos - start);
+
+    TextChangeRange::new(final_span, final_length)
+}
+
+fn find_nearest_node_starting_before_or_at_position(
+    nodes: &[Node],
+    root: NodeId,
+    position: usize,
+) -> NodeId {
+    fn visit(
+        nodes: &[Node],
+        node: NodeId,
+        position: usize,
+        best_result: &mut NodeId,
+        last_node_entirely_before_position: &mut Option<NodeId>,
+    ) {
+        for_each_child(
+            &nodes[node.index()],
+            &mut |child| {
+                let child_node = &nodes[child.index()];
+                if node_is_missing(child_node) {
+                    // Missing nodes are effectively invisible to us.  We never even consider them
+                    // When trying to find the nearest node before us.
+                    return None;
+                }
+
+                // If the child intersects this position, then this node is currently the nearest
+                // node that starts before the position.
+                if child_node.pos() <= position {
+                    if child_node.pos() >= nodes[best_result.index()].pos() {
+                        // This node starts before the position, and is closer to the position than
+                        // the previous best node we found.  It is now the new best node.
+                        *best_result = child;
+                    }
+
+                    // Now, the node may overlap the position, or it may end entirely before the
+                    // position.  If it overlaps with the position, then either it, or one of its
+                    // children must be the nearest node before the position.  So we can just
+                    // recurse into this child to see if we can find something better.
+                    if position < child_node.end() {
+                        // The nearest node is either this child, or one of the children inside
+                        // of it.  We've already marked this child as the best so far.  Recurse
+                        // in case one of the children is better.
+                        visit(
+                            nodes,
+                            child,
+                            position,
+                            best_result,
+                            last_node_entirely_before_position,
+                        );
+
+                        // Once we look at the children of this node, then there's no need to
+                        // continue any further.
+                        return Some(());
+                    }
+
+                    debug_assert!(child_node.end() <= position);
+                    // The child ends entirely before this position.  Say you have the following
+                    // (where $ is the position)
+                    //
+                    //      <complex expr 1> ? <complex expr 2> $ : <...> <...>
+                    //
+                    // We would want to find the nearest preceding node in "complex expr 2".
+                    // To support that, we keep track of this node, and once we're done searching
+                    // for a best node, we recurse down this node to see if we can find a good
+                    // result in it.
+                    //
+                    // This approach allows us to quickly skip over nodes that are entirely
+                    // before the position, while still allowing us to find any nodes in the
+                    // last one that might be what we want.
+                    *last_node_entirely_before_position = Some(child);
+                    None
+                } else {
+                    debug_assert!(child_node.pos() > position);
+                    // We're now at a node that is entirely past the position we're searching for.
+                    // This node (and all following nodes) could never contribute to the result,
+                    // so just skip them by returning `Some` here.
+                    Some(())
+                }
+            },
+            None,
+        );
+    }
+
+    let mut best_result = root;
+    let mut last_node_entirely_before_position = None;
+    visit(
+        nodes,
+        root,
+        position,
+        &mut best_result,
+        &mut last_node_entirely_before_position,
+    );
+
+    if let Some(last_node_entirely_before_position) = last_node_entirely_before_position {
+        let mut last_child_of_last_entire_node_before_position = last_node_entirely_before_position;
+        while let Some(last_child) =
+            get_last_child(nodes, last_child_of_last_entire_node_before_position)
+        {
+            last_child_of_last_entire_node_before_position = last_child;
+        }
+        if nodes[last_child_of_last_entire_node_before_position.index()].pos()
+            > nodes[best_result.index()].pos()
+        {
+            best_result = last_child_of_last_entire_node_before_position;
+        }
+    }
+
+    best_result
+}
+
+fn get_last_child(nodes: &[Node], node: NodeId) -> Option<NodeId> {
+    let last_child = Cell::new(None);
+    for_each_child(
+        &nodes[node.index()],
+        &mut |child| {
+            if !node_is_missing(&nodes[child.index()]) {
+                last_child.set(Some(child));
+            }
+            None::<()>
+        },
+        Some(&mut |children: &NodeArray| {
+            // As an optimization, jump straight to the end of the list.
+            if let Some(&child) = children
+                .iter()
+                .rev()
+                .find(|child| !node_is_missing(&nodes[child.index()]))
+            {
+                last_child.set(Some(child));
+            }
+            None
+        }),
+    );
+    last_child.get()
+}
+
+fn check_change_range(old_text: &str, new_text: &str, text_change_range: TextChangeRange) {
+    if cfg!(debug_assertions) {
+        let old_text: Vec<u16> = old_text.encode_utf16().collect();
+        let new_text: Vec<u16> = new_text.encode_utf16().collect();
+        let span = text_change_range.span;
+        debug_assert_eq!(
+            old_text.len() - (span.end - span.pos) + text_change_range.new_length,
+            new_text.len()
+        );
+        debug_assert_eq!(old_text[..span.pos], new_text[..span.pos]);
+        debug_assert_eq!(
+            old_text[span.end..],
+            new_text[text_change_range.new_span().end..]
+        );
+    }
+}
+
+// The scanner only sees the comment directives in the text the parser scans again, so those of
+// the old text that lie in reused nodes are kept (moved along with the nodes after the edit).
+// The ones in the leading trivia of a reused node are scanned again, and kept only once.
+fn get_new_comment_directives(
+    old_directives: Option<Vec<CommentDirective>>,
+    new_directives: Option<Vec<CommentDirective>>,
+    change_start: usize,
+    change_range_old_end: usize,
+    delta: isize,
+    reused_node_ranges: &[TextRange],
+) -> Option<Vec<CommentDirective>> {
+    let old_directives = match old_directives {
+        Some(old_directives) => old_directives,
+        None => return new_directives,
+    };
+    let is_in_reused_node = |range: TextRange| {
+        let index = reused_node_ranges.partition_point(|node| node.pos <= range.pos);
+        index > 0 && reused_node_ranges[index - 1].end >= range.end
+    };
+    let mut comment_directives: Vec<CommentDirective> = old_directives
+        .into_iter()
+        .filter_map(|directive| {
+            let range = if directive.range.end < change_start {
+                // Range before the change
+                directive.range
+            } else if directive.range.pos > change_range_old_end {
+                // Range entirely past the change range.  We need to move both its pos and
+                // end, forward or backward appropriately.
+                move_range(directive.range, delta)
+            } else {
+                // Ignore ranges that fall in change range
+                return None;
+            };
+            Some(CommentDirective {
+                range,
+                _type: directive._type,
+            })
+            .filter(|directive| is_in_reused_node(directive.range))
+        })
+        .chain(new_directives.into_iter().flatten())
+        .collect();
+    comment_directives.sort_by_key(|directive| directive.range.pos);
+    comment_directives.dedup_by_key(|directive| directive.range);
+    if comment_directives.is_empty() {
+        None
+    } else {
+        Some(comment_directives)
+    }
+}
+
+// Finds the outermost nodes of the new tree that were taken from the old one, in order.
+fn get_reused_node_ranges(source_file: &SourceFile, old_nodes_length: usize) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    let mut stack = vec![source_file.root];
+    while let Some(node) = stack.pop() {
+        if node.index() < old_nodes_length {
+            ranges.push(source_file.node(node).range);
+        } else {
+            push_child_nodes_and_js_doc(&mut stack, source_file.node(node));
+        }
+    }
+    ranges.sort_by_key(|range| range.pos);
+    ranges
+}
+
+#[allow(non_snake_case)]
+mod PragmaKindFlags {
+    pub const TRIPLE_SLASH_XML: u32 = 1 << 0;
+    pub const SINGLE_LINE: u32 = 1 << 1;
+    pub const MULTI_LINE: u32 = 1 << 2;
+}
+
+struct PragmaArgumentSpecification {
+    name: &'static str,
+    optional: bool,
+    capture_span: bool,
+}
+
+struct PragmaDefinition {
+    args: &'static [PragmaArgumentSpecification],
+    kind: u32,
+}
+
+const fn pragma_arg(
+    name: &'static str,
+    optional: bool,
+    capture_span: bool,
+) -> PragmaArgumentSpecification {
+    PragmaArgumentSpecification {
+        name,
+        optional,
+        capture_span,
+    }
+}
+
+fn comment_pragma(name: &str) -> Option<PragmaDefinition> {
+    const REFERENCE_ARGS: &[PragmaArgumentSpecification] = &[
+        pragma_arg("types", true, true),
+        pragma_arg("lib", true, true),
+        pragma_arg("path", true, true),
+        pragma_arg("no-default-lib", true, false),
+    ];
+    const AMD_DEPENDENCY_ARGS: &[PragmaArgumentSpecification] = &[
+        pragma_arg("path", false, false),
+        pragma_arg("name", true, false),
+    ];
+    const AMD_MODULE_ARGS: &[PragmaArgumentSpecification] = &[pragma_arg("name", false, false)];
+    const FACTORY_ARGS: &[PragmaArgumentSpecification] = &[pragma_arg("factory", false, false)];
+
+    let (args, kind) = match name {
+        "reference" => (REFERENCE_ARGS, PragmaKindFlags::TRIPLE_SLASH_XML),
+        "amd-dependency" => (AMD_DEPENDENCY_ARGS, PragmaKindFlags::TRIPLE_SLASH_XML),
+        "amd-module" => (AMD_MODULE_ARGS, PragmaKindFlags::TRIPLE_SLASH_XML),
+        "ts-check" | "ts-nocheck" => (&[][..], PragmaKindFlags::SINGLE_LINE),
+        "jsx" | "jsxfrag" | "jsximportsource" | "jsxruntime" => {
+            (FACTORY_ARGS, PragmaKindFlags::MULTI_LINE)
+        }
+        _ => return None,
+    };
+    Some(PragmaDefinition { args, kind })
+}
+
+// Whether the name is the name of a pragma, like `name in commentPragmas` in the JS compiler.
+pub(crate) fn is_comment_pragma(name: &str) -> bool {
+    comment_pragma(name).is_some()
+}
+
+// Reads the pragmas in the leading comments of the file (`/// <reference ... />`, `// @ts-check`
+// and friends) into the fields of the source file.
+fn process_comment_pragmas(source_file: &mut SourceFile) {
+    let source_text: Vec<u16> = source_file.text.encode_utf16().collect();
+    let mut pragmas = Vec::new();
+
+    for range in get_leading_comment_ranges(&source_text, 0) {
+        let comment = &source_text[range.range.pos..range.range.end];
+        extract_pragmas(&mut pragmas, &range, comment);
+    }
+
+    process_pragmas_into_fields(source_file, &pragmas);
+    source_file.pragmas = pragmas;
+}
+
+fn process_pragmas_into_fields(source_file: &mut SourceFile, pragmas: &[Pragma]) {
+    source_file.check_js_directive = None;
+    source_file.referenced_files = Vec::new();
+    source_file.type_reference_directives = Vec::new();
+    source_file.lib_reference_directives = Vec::new();
+    source_file.amd_dependencies = Vec::new();
+    source_file.has_no_default_lib = false;
+
+    // Entries are handled grouped by name, in the order each name first appears.
+    let mut names: Vec<&str> = Vec::new();
+    for pragma in pragmas {
+        if !names.contains(&pragma.name.as_str()) {
+            names.push(&pragma.name);
+        }
+    }
+
+    for name in names {
+        let entries = pragmas.iter().filter(|pragma| pragma.name == name);
+        match name {
+            "reference" => {
+                for entry in entries {
+                    let file_reference = |argument: &PragmaArgument| FileReference {
+                        range: argument
+                            .range
+                            .expect("`reference` arguments capture their span"),
+                        file_name: argument.value.clone(),
+                    };
+                    if entry
+                        .argument("no-default-lib")
+                        .is_some_and(|argument| !argument.value.is_empty())
+                    {
+                        source_file.has_no_default_lib = true;
+                    } else if let Some(types) = entry.argument("types") {
+                        source_file
+                            .type_reference_directives
+                            .push(file_reference(types));
+                    } else if let Some(lib) = entry.argument("lib") {
+                        source_file
+                            .lib_reference_directives
+                            .push(file_reference(lib));
+                    } else if let Some(path) = entry.argument("path") {
+                        source_file.referenced_files.push(file_reference(path));
+                    } else {
+                        report_pragma_diagnostic(
+                            source_file,
+                            entry.range.range,
+                            &Diagnostics::Invalid_reference_directive_syntax,
+                        );
+                    }
+                }
+            }
+            "amd-dependency" => {
+                source_file.amd_dependencies = entries
+                    .map(|entry| AmdDependency {
+                        path: entry
+                            .argument("path")
+                            .map(|path| path.value.clone())
+                            .unwrap_or_default(),
+                        name: entry.argument("name").map(|name| name.value.clone()),
+                    })
+                    .collect();
+            }
+            "amd-module" => {
+                for entry in entries {
+                    if source_file
+                        .module_name
+                        .as_ref()
+                        .is_some_and(|name| !name.is_empty())
+                    {
+                        // TODO: It's probably fine to issue this diagnostic on all instances of the pragma
+                        report_pragma_diagnostic(
+                            source_file,
+                            entry.range.range,
+                            &Diagnostics::An_AMD_module_cannot_have_multiple_name_assignments,
+                        );
+                    }
+                    source_file.module_name = entry.argument("name").map(|name| name.value.clone());
+                }
+            }
+            "ts-nocheck" | "ts-check" => {
+                // _last_ of either nocheck or check in a file is the "winner"
+                for entry in entries {
+                    if source_file
+                        .check_js_directive
+                        .is_none_or(|directive| entry.range.range.pos > directive.range.pos)
+                    {
+                        source_file.check_js_directive = Some(CheckJsDirective {
+                            range: entry.range.range,
+                            enabled: name == "ts-check",
+                        });
+                    }
+                }
+            }
+            // The jsx pragmas are read directly from the comments by their consumers.
+            "jsx" | "jsxfrag" | "jsximportsource" | "jsxruntime" => {}
+            _ => unreachable!("Unhandled pragma kind"),
+        }
+    }
+}
+
+fn report_pragma_diagnostic(
+    source_file: &mut SourceFile,
+    range: TextRange,
+    message: &IDiagnosticMessage,
+) {
+    let diagnostic = create_file_diagnostic(
+        &source_file.file_name,
+        range.pos,
+        range.end - range.pos,
+        message,
+        &[],
+    );
+    source_file.parse_diagnostics.push(diagnostic);
+}
+
+// `\s` in a JS regular expression.
+pub(crate) fn is_pragma_whitespace(ch: u16) -> bool {
+    matches!(
+        ch,
+        0x09..=0x0D | 0x20 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x2028 | 0x2029 | 0x202F | 0x205F | 0x3000 | 0xFEFF
+    )
+}
+
+// A line terminator, which `.` does not match and before which `$` matches in a multiline
+// JS regular expression.
+fn is_pragma_line_terminator(ch: u16) -> bool {
+    matches!(ch, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+fn skip_pragma_whitespace(text: &[u16], pos: usize) -> usize {
+    pos + text[pos..]
+        .iter()
+        .take_while(|&&ch| is_pragma_whitespace(ch))
+        .count()
+}
+
+fn skip_pragma_non_whitespace(text: &[u16], pos: usize) -> usize {
+    pos + text[pos..]
+        .iter()
+        .take_while(|&&ch| !is_pragma_whitespace(ch))
+        .count()
+}
+
+fn starts_with_ascii(text: &[u16], pos: usize, prefix: &str) -> bool {
+    text.len() >= pos + prefix.len()
+        && prefix
+            .bytes()
+            .zip(&text[pos..])
+            .all(|(a, &b)| u16::from(a) == b)
+}
+
+fn starts_with_ascii_ignore_case(text: &[u16], pos: usize, prefix: &str) -> bool {
+    text.len() >= pos + prefix.len()
+        && prefix
+            .bytes()
+            .zip(&text[pos..])
+            .all(|(a, &b)| b < 0x80 && (b as u8).eq_ignore_ascii_case(&a))
+}
+
+// Matches `^\/\/\/\s*<(\S+)\s.*?\/>` and returns the tag name.
+fn match_triple_slash_xml_comment_start(text: &[u16]) -> Option<String> {
+    if !starts_with_ascii(text, 0, "///") {
+        return None;
+    }
+    let name_start = skip_pragma_whitespace(text, 3);
+    if text.get(name_start) != Some(&u16::from(b'<')) {
+        return None;
+    }
+    let name_start = name_start + 1;
+    let name_end = skip_pragma_non_whitespace(text, name_start);
+    if name_end == name_start || name_end == text.len() {
+        return None;
+    }
+    let rest = &text[name_end + 1..];
+    let closes = rest
+        .windows(2)
+        .take_while(|pair| !is_pragma_line_terminator(pair[0]))
+        .any(|pair| pair == [u16::from(b'/'), u16::from(b'>')]);
+    if is_pragma_line_terminator(text[name_end]) || !closes {
+        return None;
+    }
+    Some(String::from_utf16_lossy(&text[name_start..name_end]))
+}
+
+// Matches `(\s${name}\s*=\s*)(?:(?:'([^']*)')|(?:"([^"]*)"))` case-insensitively, returning the
+// offset of the value and the value.
+fn match_named_arg(text: &[u16], name: &str) -> Option<(usize, String)> {
+    (0..text.len())
+        .filter(|&start| is_pragma_whitespace(text[start]))
+        .find_map(|start| {
+            if !starts_with_ascii_ignore_case(text, start + 1, name) {
+                return None;
+            }
+            let equals = skip_pragma_whitespace(text, start + 1 + name.len());
+            if text.get(equals) != Some(&u16::from(b'=')) {
+                return None;
+            }
+            let quote_pos = skip_pragma_whitespace(text, equals + 1);
+            let quote = *text.get(quote_pos)?;
+            if quote != u16::from(b'\'') && quote != u16::from(b'"') {
+                return None;
+            }
+            let value_start = quote_pos + 1;
+            let value_length = text[value_start..].iter().position(|&ch| ch == quote)?;
+            Some((
+                value_start,
+                String::from_utf16_lossy(&text[value_start..value_start + value_length]),
+            ))
+        })
+}
+
+// Matches `^\/\/\/?\s*@(\S+)` and returns the pragma name.
+fn match_single_line_pragma(text: &[u16]) -> Option<String> {
+    if !starts_with_ascii(text, 0, "//") {
+        return None;
+    }
+    let pos = if starts_with_ascii(text, 2, "/") {
+        3
+    } else {
+        2
+    };
+    let name_start = skip_pragma_whitespace(text, pos);
+    if text.get(name_start) != Some(&u16::from(b'@')) {
+        return None;
+    }
+    let name_end = skip_pragma_non_whitespace(text, name_start + 1);
+    if name_end == name_start + 1 {
+        return None;
+    }
+    Some(String::from_utf16_lossy(&text[name_start + 1..name_end]))
+}
+
+fn extract_pragmas(pragmas: &mut Vec<Pragma>, range: &CommentRange, text: &[u16]) {
+    let is_single_line = range.kind == TriviaSyntaxKind::SingleLineCommentTrivia;
+    if let Some(name) = is_single_line
+        .then(|| match_triple_slash_xml_comment_start(text))
+        .flatten()
+    {
+        let name = name.to_lowercase();
+        let pragma = match comment_pragma(&name) {
+            Some(pragma) if pragma.kind & PragmaKindFlags::TRIPLE_SLASH_XML != 0 => pragma,
+            _ => return,
+        };
+        let mut arguments = BTreeMap::new();
+        for arg in pragma.args {
+            match match_named_arg(text, arg.name) {
+                None if !arg.optional => {
+                    return; // Missing required argument, don't parse
+                }
+                None => {}
+                Some((value_start, value)) => {
+                    let range = if arg.capture_span {
+                        let pos = range.range.pos + value_start;
+                        Some(TextRange {
+                            pos,
+                            end: pos + value.encode_utf16().count(),
+                        })
+                    } else {
+                        None
+                    };
+                    arguments.insert(arg.name, PragmaArgument { value, range });
+                }
+            }
+        }
+        pragmas.push(Pragma {
+            name,
+            arguments,
+            range: *range,
+        });
+        return;
+    }
+
+    if let Some(name) = is_single_line
+        .then(|| match_single_line_pragma(text))
+        .flatten()
+    {
+        let name = name.to_lowercase();
+        if let Some(pragma) = comment_pragma(&name) {
+            // None of the single-line pragmas take arguments.
+            if pragma.kind & PragmaKindFlags::SINGLE_LINE != 0 && pragma.args.is_empty() {
+                pragmas.push(Pragma {
+                    name,
+                    arguments: BTreeMap::new(),
+                    range: *range,
+                });
+            }
+        }
+        return;
+    }
+
+    if range.kind == TriviaSyntaxKind::MultiLineCommentTrivia {
+        // Matches of `@(\S+)(\s+.*)?$`, one after the other.
+        let mut pos = 0;
+        while let Some(at) = text[pos..].iter().position(|&ch| ch == u16::from(b'@')) {
+            let name_start = pos + at + 1;
+            let name_end = skip_pragma_non_whitespace(text, name_start);
+            if name_end == name_start {
+                pos = name_start;
+                continue;
+            }
+            let args_end = if name_end < text.len() {
+                let rest_start = skip_pragma_whitespace(text, name_end);
+                rest_start
+                    + text[rest_start..]
+                        .iter()
+                        .take_while(|&&ch| !is_pragma_line_terminator(ch))
+                        .count()
+            } else {
+                name_end
+            };
+            let name = String::from_utf16_lossy(&text[name_start..name_end]);
+            let args = String::from_utf16_lossy(&text[name_end..args_end]);
+            add_pragma_for_match(pragmas, range, PragmaKindFlags::MULTI_LINE, name, &args);
+            pos = args_end.max(name_end);
+        }
+    }
+}
+
+fn add_pragma_for_match(
+    pragmas: &mut Vec<Pragma>,
+    range: &CommentRange,
+    kind: u32,
+    name: String,
+    args: &str,
+) {
+    let name = name.to_lowercase();
+    let pragma = match comment_pragma(&name) {
+        Some(pragma) if pragma.kind & kind != 0 => pragma,
+        _ => return,
+    };
+    // Split on spaces and match up positionally with definition
+    let arguments = match get_named_pragma_arguments(&pragma, args) {
+        Some(arguments) => arguments,
+        None => return, // Missing required argument, fail to parse it
+    };
+    pragmas.push(Pragma {
+        name,
+        arguments,
+        range: *range,
+    });
+}
+
+fn get_named_pragma_arguments(
+    pragma: &PragmaDefinition,
+    text: &str,
+) -> Option<BTreeMap<&'static str, PragmaArgument>> {
+    let mut arg_map = BTreeMap::new();
+    if text.is_empty() {
+        return Some(arg_map);
+    }
+    let args: Vec<&str> = text.split_whitespace().collect();
+    for (i, argument) in pragma.args.iter().enumerate() {
+        match args.get(i) {
+            None if !argument.optional => return None,
+            None => {}
+            Some(value) => {
+                debug_assert!(
+                    !argument.capture_span,
+                    "Capture spans not yet implemented for non-xml pragmas"
+                );
+                arg_map.insert(
+                    argument.name,
+                    PragmaArgument {
+                        value: (*value).to_owned(),
+                        range: None,
+                    },
+                );
+            }
+        }
+    }
+    Some(arg_map)
+}
+
+// Matches the numeric values of `ts.ScriptTarget`; anything unknown is treated as `Latest`.
+pub fn script_target_from_number(value: u32) -> ScriptTarget {
+    match value {
+        0 => ScriptTarget::ES3,
+        1 => ScriptTarget::ES5,
+        2 => ScriptTarget::ES2015,
+        3 => ScriptTarget::ES2016,
+        4 => ScriptTarget::ES2017,
+        5 => ScriptTarget::ES2018,
+        6 => ScriptTarget::ES2019,
+        7 => ScriptTarget::ES2020,
+        8 => ScriptTarget::ES2021,
+        100 => ScriptTarget::JSON,
+        _ => ScriptTarget::ESNext,
+    }
+}
+
+// Matches the numeric values of `ts.ScriptKind`; `Unknown` lets the file extension decide.
+pub fn script_kind_from_number(value: u32) -> Option<ScriptKind> {
+    match value {
+        1 => Some(ScriptKind::JS),
+        2 => Some(ScriptKind::JSX),
+        3 => Some(ScriptKind::TS),
+        4 => Some(ScriptKind::TSX),
+        5 => Some(ScriptKind::External),
+        6 => Some(ScriptKind::JSON),
+        7 => Some(ScriptKind::Deferred),
+        _ => None,
+    }
+}
+
+/// The number of `u32`s each node takes in the table of `getTreeNodeTable`: its kind (the value
+/// of `ts.SyntaxKind`), `pos`, `end`, flags, and the id of its parent, or `NO_PARENT`.
+pub const NODE_TABLE_STRIDE: usize = 5;
+
+pub const NO_PARENT: u32 = u32::MAX;
+
+/// The kind, range, flags and parent of every node of a tree, by id, in the layout of
+/// `NODE_TABLE_STRIDE`.
+pub fn create_node_table(source_file: &SourceFile) -> Vec<u32> {
+    let mut table = Vec::with_capacity(source_file.nodes.len() * NODE_TABLE_STRIDE);
+    for node in &source_file.nodes {
+        table.extend_from_slice(&[
+            node.kind.js_value(),
+            node.range.pos as u32,
+            node.range.end as u32,
+            node.flags,
+            node.parent.map_or(NO_PARENT, |parent| parent.0),
+        ]);
+    }
+    table
+}
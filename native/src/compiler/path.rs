@@ -0,0 +1,293 @@
+// Path helpers ported from `path.ts`. Paths are normalized to use `/` as the directory separator
+// and a "root" is one of `/`, `//server/`, `c:` or `c:/`.
+
+pub const DIRECTORY_SEPARATOR: char = '/';
+const ALT_DIRECTORY_SEPARATOR: char = '\\';
+
+pub fn is_any_directory_separator(ch: char) -> bool {
+    ch == DIRECTORY_SEPARATOR || ch == ALT_DIRECTORY_SEPARATOR
+}
+
+pub fn normalize_slashes(path: &str) -> String {
+    path.replace(ALT_DIRECTORY_SEPARATOR, "/")
+}
+
+fn is_volume_character(ch: u8) -> bool {
+    ch.is_ascii_alphabetic()
+}
+
+/// Returns the length of the root of a path, or 0 for a relative path:
+///
+/// ```text
+/// get_root_length("a")          == 0  // ""
+/// get_root_length("/")          == 1  // "/"
+/// get_root_length("c:")         == 2  // "c:"
+/// get_root_length("c:/")        == 3  // "c:/"
+/// get_root_length("//server/")  == 9  // "//server/"
+/// ```
+pub fn get_root_length(path: &str) -> usize {
+    let bytes = path.as_bytes();
+    if bytes.is_empty() {
+        return 0;
+    }
+    let ch0 = bytes[0] as char;
+
+    // POSIX or UNC
+    if is_any_directory_separator(ch0) {
+        if bytes.len() < 2 || bytes[1] as char != ch0 {
+            return 1; // POSIX: "/" (or non-normalized "\")
+        }
+        return match path[2..].find(ch0) {
+            Some(index) => index + 3, // UNC: "//server/" or "\\server\"
+            None => path.len(),       // UNC: "//server" or "\\server"
+        };
+    }
+
+    // DOS
+    if bytes.len() >= 2 && is_volume_character(bytes[0]) && bytes[1] == b':' {
+        if bytes.len() >= 3 && is_any_directory_separator(bytes[2] as char) {
+            return 3; // DOS: "c:/" or "c:\"
+        }
+        if bytes.len() == 2 {
+            return 2; // DOS: "c:" (but not "c:d")
+        }
+    }
+
+    0
+}
+
+/// Determines whether a path starts with an absolute path component (i.e. `/`, `c:/`, or
+/// `//server/`).
+pub fn is_rooted_disk_path(path: &str) -> bool {
+    get_root_length(path) > 0
+}
+
+pub fn has_trailing_directory_separator(path: &str) -> bool {
+    path.chars().last().is_some_and(is_any_directory_separator)
+}
+
+pub fn remove_trailing_directory_separator(path: &str) -> &str {
+    if path.len() > 1 && has_trailing_directory_separator(path) {
+        &path[..path.len() - 1]
+    } else {
+        path
+    }
+}
+
+pub fn ensure_trailing_directory_separator(path: &str) -> String {
+    if has_trailing_directory_separator(path) {
+        path.to_string()
+    } else {
+        format!("{}{}", path, DIRECTORY_SEPARATOR)
+    }
+}
+
+/// Returns the path except for its basename. Semantics align with NodeJS's `path.dirname`
+/// but for normalized slashes.
+pub fn get_directory_path(path: &str) -> String {
+    let path = normalize_slashes(path);
+
+    // If the path provided is itself the root, then return it.
+    let root_length = get_root_length(&path);
+    if root_length == path.len() {
+        return path;
+    }
+
+    // Return the leading portion of the path up to the last (non-terminal) directory separator
+    // but not including any trailing directory separator.
+    let path = remove_trailing_directory_separator(&path);
+    let last_separator = path.rfind(DIRECTORY_SEPARATOR).unwrap_or(0);
+    path[..root_length.max(last_separator)].to_string()
+}
+
+/// Returns the path except for its containing directory name. Semantics align with NodeJS's
+/// `path.basename` but for normalized slashes.
+pub fn get_base_file_name(path: &str) -> String {
+    let path = normalize_slashes(path);
+
+    // If the path provided is itself the root, then it has no file name.
+    let root_length = get_root_length(&path);
+    if root_length == path.len() {
+        return String::new();
+    }
+
+    let path = remove_trailing_directory_separator(&path);
+    let start = root_length.max(path.rfind(DIRECTORY_SEPARATOR).map_or(0, |index| index + 1));
+    path[start..].to_string()
+}
+
+/// Combines paths. If a path is absolute, it replaces any previous path. Relative paths are not
+/// simplified.
+pub fn combine_paths(path: &str, paths: &[&str]) -> String {
+    let mut path = if path.is_empty() {
+        String::new()
+    } else {
+        normalize_slashes(path)
+    };
+    for relative_path in paths {
+        if relative_path.is_empty() {
+            continue;
+        }
+        let relative_path = normalize_slashes(relative_path);
+        if path.is_empty() || get_root_length(&relative_path) != 0 {
+            path = relative_path;
+        } else {
+            path = ensure_trailing_directory_separator(&path) + &relative_path;
+        }
+    }
+    path
+}
+
+/// Parse a path into an array containing a root component (at index 0) and zero or more path
+/// components (at indices > 0). The result is not normalized.
+pub fn get_path_components(path: &str, current_directory: &str) -> Vec<String> {
+    let path = combine_paths(current_directory, &[path]);
+    let root_length = get_root_length(&path);
+    let mut components = vec![path[..root_length].to_string()];
+    components.extend(
+        path[root_length..]
+            .split(DIRECTORY_SEPARATOR)
+            .filter(|component| !component.is_empty())
+            .map(str::to_string),
+    );
+    components
+}
+
+/// Reduce an array of path components to a more simplified path by navigating any `"."` or
+/// `".."` entries in the path.
+pub fn reduce_path_components(components: Vec<String>) -> Vec<String> {
+    let mut components = components.into_iter();
+    let mut reduced = match components.next() {
+        Some(root) => vec![root],
+        None => return Vec::new(),
+    };
+    for component in components {
+        if component.is_empty() || component == "." {
+            continue;
+        }
+        if component == ".." {
+            if reduced.len() > 1 {
+                if reduced[reduced.len() - 1] != ".." {
+                    reduced.pop();
+                    continue;
+                }
+            } else if !reduced[0].is_empty() {
+                continue;
+            }
+        }
+        reduced.push(component);
+    }
+    reduced
+}
+
+/// Formats a parsed path consisting of a root component (at index 0) and zero or more path
+/// segments (at indices > 0).
+pub fn get_path_from_path_components(path_components: &[String]) -> String {
+    match path_components.split_first() {
+        Some((root, rest)) => {
+            let root = if root.is_empty() {
+                String::new()
+            } else {
+                ensure_trailing_directory_separator(root)
+            };
+            root + &rest.join("/")
+        }
+        None => String::new(),
+    }
+}
+
+pub fn get_normalized_path_components(path: &str, current_directory: &str) -> Vec<String> {
+    reduce_path_components(get_path_components(path, current_directory))
+}
+
+pub fn get_normalized_absolute_path(file_name: &str, current_directory: &str) -> String {
+    get_path_from_path_components(&get_normalized_path_components(
+        file_name,
+        current_directory,
+    ))
+}
+
+pub fn normalize_path(path: &str) -> String {
+    let path = normalize_slashes(path);
+    let normalized =
+        get_path_from_path_components(&reduce_path_components(get_path_components(&path, "")));
+    if !normalized.is_empty() && has_trailing_directory_separator(&path) {
+        ensure_trailing_directory_separator(&normalized)
+    } else {
+        normalized
+    }
+}
+
+pub fn to_file_name_lower_case(file_name: &str) -> String {
+    file_name.to_lowercase()
+}
+
+fn get_path_components_relative_to(
+    from: &str,
+    to: &str,
+    get_canonical_file_name: impl Fn(&str) -> String,
+) -> Vec<String> {
+    let from_components = reduce_path_components(get_path_components(from, ""));
+    let to_components = reduce_path_components(get_path_components(to, ""));
+
+    let mut start = 0;
+    while start < from_components.len() && start < to_components.len() {
+        let from_component = get_canonical_file_name(&from_components[start]);
+        let to_component = get_canonical_file_name(&to_components[start]);
+        // The roots are always compared case-insensitively.
+        let is_same = if start == 0 {
+            from_component.eq_ignore_ascii_case(&to_component)
+        } else {
+            from_component == to_component
+        };
+        if !is_same {
+            break;
+        }
+        start += 1;
+    }
+
+    if start == 0 {
+        return to_components;
+    }
+
+    let mut relative = vec![String::new()];
+    relative.extend((start..from_components.len()).map(|_| "..".to_string()));
+    relative.extend(to_components[start..].iter().cloned());
+    relative
+}
+
+/// Gets a relative path that can be used to traverse between `from` and `to`.
+pub fn get_relative_path_from_directory(
+    from_directory: &str,
+    to: &str,
+    get_canonical_file_name: impl Fn(&str) -> String,
+) -> String {
+    let path_components =
+        get_path_components_relative_to(from_directory, to, get_canonical_file_name);
+    get_path_from_path_components(&path_components)
+}
+
+pub fn convert_to_relative_path(
+    absolute_or_relative_path: &str,
+    base_path: &str,
+    get_canonical_file_name: impl Fn(&str) -> String,
+) -> String {
+    if !is_rooted_disk_path(absolute_or_relative_path) {
+        absolute_or_relative_path.to_string()
+    } else {
+        get_relative_path_from_directory(
+            base_path,
+            absolute_or_relative_path,
+            get_canonical_file_name,
+        )
+    }
+}
+
+/// Returns the directory of `file_name` once it is resolved against `base_path`.
+pub fn directory_of_combined_path(file_name: &str, base_path: &str) -> String {
+    get_directory_path(&get_normalized_absolute_path(file_name, base_path))
+}
+
+pub fn file_extension_is(path: &str, extension: &str) -> bool {
+    path.len() > extension.len() && path.ends_with(extension)
+}
@@ -1,191 +1,3196 @@
-use std::convert::TryInto;
-
-use napi::{CallContext, JsBoolean, JsFunction, JsNumber, JsObject, JsString, Result};
-use napi_derive::js_function;
+use std::cell::RefCell;
+use std::rc::Rc;
 
+use super::diagnostic_information_map::Diagnostics;
 use super::types::{
-    CharacterCodes, CommentDirective, IDiagnosticMessage, JSDocSyntaxKind, JsxTokenSyntaxKind,
-    LanguageVariant, PunctuationSyntaxKind, ScriptTarget, SyntaxKind, TokenFlags, TokenSyntaxKind,
+    CancellationToken, CharacterCodes, CommentDirective, CommentDirectiveType, CommentRange,
+    Diagnostic, IDiagnosticMessage, JSDocSyntaxKind, JsxTokenSyntaxKind, KeywordSyntaxKind,
+    KeywordTypeSyntaxKind, LanguageVariant, LiteralSyntaxKind, ModifierSyntaxKind,
+    OperationCanceled, PseudoLiteralSyntaxKind, PunctuationSyntaxKind, ScriptTarget, SyntaxKind,
+    TextRange, TokenFlags, TokenSyntaxKind, TriviaSyntaxKind,
+};
+use super::unicode::{
+    self, UNICODE_ES3_IDENTIFIER_PART, UNICODE_ES3_IDENTIFIER_START, UNICODE_ES5_IDENTIFIER_PART,
+    UNICODE_ES5_IDENTIFIER_START, UNICODE_ESNEXT_IDENTIFIER_PART, UNICODE_ESNEXT_IDENTIFIER_START,
 };
+use super::utilities::create_compiler_diagnostic;
 
-pub type ErrorCallback<'a> = dyn Fn(IDiagnosticMessage<'a>) -> &'a str;
+/// Receives every diagnostic the scanner reports together with its start position and length
+/// (both in UTF-16 code units, like every other position the scanner hands out).
+pub type ErrorCallback = dyn FnMut(&IDiagnosticMessage<'static>, usize, usize);
 
-pub fn tokenIsIdentifierOrKeyword(token: SyntaxKind) -> bool {
-    match token {
-        SyntaxKind::Tokens(TokenSyntaxKind::Identifier) => true,
-        SyntaxKind::Keywords(_) => true,
-        _ => false,
-    }
+/// Returned by `char_code_at` and `code_point_at` for positions outside of the text, the
+/// equivalent of the `NaN`/`undefined` the JS scanner compares against. It is never a valid
+/// character, digit, line break or identifier character.
+const INVALID_CHARACTER: u32 = u32::MAX;
+
+// Like `token >= SyntaxKind.Identifier` in the JS scanner, this includes `PrivateIdentifier`.
+pub fn token_is_identifier_or_keyword(token: SyntaxKind) -> bool {
+    matches!(
+        token,
+        SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+            | SyntaxKind::PrivateIdentifier
+            | SyntaxKind::Keywords(_)
+    )
 }
 
 pub fn token_is_identifier_or_keyword_or_greater_than(token: SyntaxKind) -> bool {
     if let SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanToken) = token {
         return true;
     }
-    tokenIsIdentifierOrKeyword(token)
+    token_is_identifier_or_keyword(token)
 }
 
 pub trait Scanner {
-    fn get_start_pos() -> usize;
-    fn get_token() -> SyntaxKind;
-    fn get_text_pos() -> usize;
-    fn get_token_pos() -> usize;
-    fn get_token_text() -> String;
-    fn get_token_value() -> String;
-    fn has_unicode_escape() -> bool;
-    fn has_extended_unicode_escape() -> bool;
-    fn has_preceding_line_break() -> bool;
+    fn get_start_pos(&self) -> usize;
+    fn get_token(&self) -> SyntaxKind;
+    fn get_text_pos(&self) -> usize;
+    fn get_token_pos(&self) -> usize;
+    fn get_token_text(&self) -> String;
+    fn get_token_value(&self) -> String;
+    fn has_unicode_escape(&self) -> bool;
+    fn has_extended_unicode_escape(&self) -> bool;
+    fn has_preceding_line_break(&self) -> bool;
     /* @internal */
-    fn has_preceding_jsdoc_comment() -> bool;
-    fn is_identifier() -> bool;
-    fn is_reserved_word() -> bool;
-    fn is_unterminated() -> bool;
+    fn has_preceding_jsdoc_comment(&self) -> bool;
+    fn is_identifier(&self) -> bool;
+    fn is_reserved_word(&self) -> bool;
+    fn is_unterminated(&self) -> bool;
     /* @internal */
-    fn get_numeric_literal_flags() -> TokenFlags;
+    fn get_numeric_literal_flags(&self) -> u32;
     /* @internal */
-    fn get_comment_directives() -> Option<CommentDirective>;
+    fn get_comment_directives(&self) -> Option<&[CommentDirective]>;
     /* @internal */
-    fn get_token_flags() -> TokenFlags;
-    fn re_scan_greater_token() -> SyntaxKind;
-    fn re_scan_slash_token() -> SyntaxKind;
-    fn re_scan_asterisk_equals_token() -> SyntaxKind;
-    fn re_scan_template_token(is_tagged_template: bool) -> SyntaxKind;
-    fn re_scan_template_head_or_no_substitution_template() -> SyntaxKind;
-    fn scan_jsx_identifier() -> SyntaxKind;
-    fn scan_jsx_attribute_value() -> SyntaxKind;
-    fn re_scan_jsx_attribute_value() -> SyntaxKind;
-    fn re_scan_jsx_token(allow_multiline_jsx_text: Option<bool>) -> JsxTokenSyntaxKind;
-    fn re_scan_less_than_token() -> SyntaxKind;
-    fn re_scan_hash_tokenken() -> SyntaxKind;
-    fn re_scan_question_token() -> SyntaxKind;
-    fn re_scan_invalid_identifier() -> SyntaxKind;
-    fn scan_jsx_token() -> JsxTokenSyntaxKind;
-    fn scan_js_doc_token() -> JSDocSyntaxKind;
-    fn scan() -> SyntaxKind;
-
-    fn get_text() -> String;
+    fn get_token_flags(&self) -> u32;
+    fn re_scan_greater_token(&mut self) -> SyntaxKind;
+    fn re_scan_slash_token(&mut self) -> SyntaxKind;
+    fn re_scan_asterisk_equals_token(&mut self) -> SyntaxKind;
+    fn re_scan_template_token(&mut self, is_tagged_template: bool) -> SyntaxKind;
+    fn re_scan_template_head_or_no_substitution_template(&mut self) -> SyntaxKind;
+    fn scan_jsx_identifier(&mut self) -> SyntaxKind;
+    fn scan_jsx_attribute_value(&mut self) -> SyntaxKind;
+    fn re_scan_jsx_attribute_value(&mut self) -> SyntaxKind;
+    fn re_scan_jsx_token(&mut self, allow_multiline_jsx_text: Option<bool>) -> JsxTokenSyntaxKind;
+    fn re_scan_less_than_token(&mut self) -> SyntaxKind;
+    fn re_scan_hash_token(&mut self) -> SyntaxKind;
+    fn re_scan_question_token(&mut self) -> SyntaxKind;
+    fn re_scan_invalid_identifier(&mut self) -> SyntaxKind;
+    fn scan_jsx_token(&mut self) -> JsxTokenSyntaxKind;
+    fn scan_js_doc_token(&mut self) -> JSDocSyntaxKind;
+    fn scan(&mut self) -> SyntaxKind;
+
+    fn get_text(&self) -> String;
     /* @internal */
-    fn clear_comment_directives();
-    // Sets the text for the scanner to scan.  An optional subrange
-    // can be provided to have the scanner only scan a portion of the
-    fn set_text(text: Option<String>, start: Option<usize>, length: Option<usize>);
-    fn set_on_error(on_error: Option<Box<ErrorCallback>>);
-    fn set_script_target(script_target: ScriptTarget);
-    fn set_language_variant(variant: LanguageVariant);
-    fn set_text_pos(text_pos: Option<usize>);
+    fn clear_comment_directives(&mut self);
+    // Sets the text for the scanner to scan.  An optional subrange starting point and length
+    // can be provided to have the scanner only scan a portion of the text.
+    fn set_text(&mut self, text: Option<String>, start: Option<usize>, length: Option<usize>);
+    fn set_on_error(&mut self, on_error: Option<Box<ErrorCallback>>);
+    // The target decides which unicode tables classify identifier characters and whether
+    // `\u{...}` escapes may appear in identifiers. Like in JS, the errors for bigint literals and
+    // private identifiers below their targets are left to the checker, which knows whether they
+    // appear in a literal type or an ambient context.
+    fn set_script_target(&mut self, script_target: ScriptTarget);
+    fn set_language_variant(&mut self, variant: LanguageVariant);
+    fn set_text_pos(&mut self, text_pos: Option<usize>);
     /* @internal */
-    fn set_in_jsdoc_type(in_type: bool);
-    // Invokes the provided callback then unconditionally restores the
-    // was in immediately prior to invoking the callback.  The result
+    fn set_in_jsdoc_type(&mut self, in_type: bool);
+    // Invokes the provided callback then unconditionally restores the scanner to the state it
+    // was in immediately prior to invoking the callback.  The result of invoking the callback
     // is returned from this function.
-    fn look_ahead<T>(callback: dyn Fn() -> T) -> T;
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Self) -> T) -> T;
 
-    // Invokes the callback with the scanner set to scan the specified
-    // returns, the scanner is restored to the state it was in before
-    fn scan_range<T>(start: usize, length: usize, callback: dyn Fn() -> T) -> T;
+    // Invokes the callback with the scanner set to scan the specified range. When the callback
+    // returns, the scanner is restored to the state it was in before scanRange was called.
+    fn scan_range<T>(
+        &mut self,
+        start: usize,
+        length: usize,
+        callback: impl FnOnce(&mut Self) -> T,
+    ) -> T;
 
-    // Invokes the provided callback.  If the callback returns
-    // the scanner to the state it was in immediately prior to
-    // callback returns something truthy, then the scanner state is
+    // Invokes the provided callback.  If the callback returns `None`, then it restores
+    // the scanner to the state it was in immediately prior to invoking the callback.  If the
+    // callback returns something, then the scanner state is not rolled back.  The result
     // of invoking the callback is returned from this function.
-    fn try_scan<T>(callback: dyn Fn() -> T) -> T;
-}
-
-const COMMENT_DIRECTIVE_REG_EX_SINGLE_LINE: &str = "^///?\\s*@(ts-expect-error|ts-ignore)";
-
-const COMMENT_DIRECTIVE_REG_EX_MULTI_LINE: &str = "^(?:/|*)*\\s*@(ts-expect-error|ts-ignore)";
-
-#[js_function(2)]
-pub fn lookup_in_unicode_map(cx: CallContext) -> Result<JsBoolean> {
-    let code: u32 = cx
-        .get::<JsNumber>(0)?
-        .try_into()
-        .expect("argument of type number expected");
-    let array_of_number_err = "expected an array";
-    let map_raw = cx.get::<JsObject>(1).expect(array_of_number_err);
-    let mut map: Vec<u32> = Vec::new();
-    let validating_err = "error on array validating";
-    if map_raw.is_array().expect(validating_err) {
-        for index in 0..map_raw.get_array_length().expect(validating_err) {
-            map.push(
-                map_raw
-                    .get_element::<JsNumber>(index)
-                    .expect("element in has correct index but doesn't exist")
-                    .try_into()
-                    .expect(array_of_number_err),
-            );
+    fn try_scan<T>(&mut self, callback: impl FnOnce(&mut Self) -> Option<T>) -> Option<T>;
+}
+
+pub fn text_to_keyword(text: &str) -> Option<KeywordSyntaxKind> {
+    let keyword = match text {
+        "abstract" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::AbstractKeyword),
+        "any" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::AnyKeyword),
+        "as" => KeywordSyntaxKind::AsKeyword,
+        "asserts" => KeywordSyntaxKind::AssertsKeyword,
+        "bigint" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::BigIntKeyword),
+        "boolean" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::BooleanKeyword),
+        "break" => KeywordSyntaxKind::BreakKeyword,
+        "case" => KeywordSyntaxKind::CaseKeyword,
+        "catch" => KeywordSyntaxKind::CatchKeyword,
+        "class" => KeywordSyntaxKind::ClassKeyword,
+        "continue" => KeywordSyntaxKind::ContinueKeyword,
+        "const" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ConstKeyword),
+        "constructor" => KeywordSyntaxKind::ConstructorKeyword,
+        "debugger" => KeywordSyntaxKind::DebuggerKeyword,
+        "declare" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::DeclareKeyword),
+        "default" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::DefaultKeyword),
+        "delete" => KeywordSyntaxKind::DeleteKeyword,
+        "do" => KeywordSyntaxKind::DoKeyword,
+        "else" => KeywordSyntaxKind::ElseKeyword,
+        "enum" => KeywordSyntaxKind::EnumKeyword,
+        "export" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ExportKeyword),
+        "extends" => KeywordSyntaxKind::ExtendsKeyword,
+        "false" => KeywordSyntaxKind::FalseKeyword,
+        "finally" => KeywordSyntaxKind::FinallyKeyword,
+        "for" => KeywordSyntaxKind::ForKeyword,
+        "from" => KeywordSyntaxKind::FromKeyword,
+        "function" => KeywordSyntaxKind::FunctionKeyword,
+        "get" => KeywordSyntaxKind::GetKeyword,
+        "if" => KeywordSyntaxKind::IfKeyword,
+        "implements" => KeywordSyntaxKind::ImplementsKeyword,
+        "import" => KeywordSyntaxKind::ImportKeyword,
+        "in" => KeywordSyntaxKind::InKeyword,
+        "infer" => KeywordSyntaxKind::InferKeyword,
+        "instanceof" => KeywordSyntaxKind::InstanceOfKeyword,
+        "interface" => KeywordSyntaxKind::InterfaceKeyword,
+        "intrinsic" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::IntrinsicKeyword),
+        "is" => KeywordSyntaxKind::IsKeyword,
+        "keyof" => KeywordSyntaxKind::KeyOfKeyword,
+        "let" => KeywordSyntaxKind::LetKeyword,
+        "module" => KeywordSyntaxKind::ModuleKeyword,
+        "namespace" => KeywordSyntaxKind::NamespaceKeyword,
+        "never" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::NeverKeyword),
+        "new" => KeywordSyntaxKind::NewKeyword,
+        "null" => KeywordSyntaxKind::NullKeyword,
+        "number" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::NumberKeyword),
+        "object" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::ObjectKeyword),
+        "package" => KeywordSyntaxKind::PackageKeyword,
+        "private" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::PrivateKeyword),
+        "protected" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ProtectedKeyword),
+        "public" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::PublicKeyword),
+        "override" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::OverrideKeyword),
+        "readonly" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ReadonlyKeyword),
+        "require" => KeywordSyntaxKind::RequireKeyword,
+        "global" => KeywordSyntaxKind::GlobalKeyword,
+        "return" => KeywordSyntaxKind::ReturnKeyword,
+        "set" => KeywordSyntaxKind::SetKeyword,
+        "static" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::StaticKeyword),
+        "string" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::StringKeyword),
+        "super" => KeywordSyntaxKind::SuperKeyword,
+        "switch" => KeywordSyntaxKind::SwitchKeyword,
+        "symbol" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::SymbolKeyword),
+        "this" => KeywordSyntaxKind::ThisKeyword,
+        "throw" => KeywordSyntaxKind::ThrowKeyword,
+        "true" => KeywordSyntaxKind::TrueKeyword,
+        "try" => KeywordSyntaxKind::TryKeyword,
+        "type" => KeywordSyntaxKind::TypeKeyword,
+        "typeof" => KeywordSyntaxKind::TypeOfKeyword,
+        "undefined" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::UndefinedKeyword),
+        "unique" => KeywordSyntaxKind::UniqueKeyword,
+        "unknown" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::UnknownKeyword),
+        "var" => KeywordSyntaxKind::VarKeyword,
+        "void" => KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::VoidKeyword),
+        "while" => KeywordSyntaxKind::WhileKeyword,
+        "with" => KeywordSyntaxKind::WithKeyword,
+        "yield" => KeywordSyntaxKind::YieldKeyword,
+        "async" => KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::AsyncKeyword),
+        "await" => KeywordSyntaxKind::AwaitKeyword,
+        "of" => KeywordSyntaxKind::OfKeyword,
+        _ => return None,
+    };
+    Some(keyword)
+}
+
+// The reserved words are `SyntaxKind.FirstReservedWord` to `SyntaxKind.LastReservedWord` in the
+// JS compiler; some of them live in the modifier and keyword type groups here.
+pub fn is_reserved_word(keyword: KeywordSyntaxKind) -> bool {
+    matches!(
+        keyword,
+        KeywordSyntaxKind::BreakKeyword
+            | KeywordSyntaxKind::CaseKeyword
+            | KeywordSyntaxKind::CatchKeyword
+            | KeywordSyntaxKind::ClassKeyword
+            | KeywordSyntaxKind::ContinueKeyword
+            | KeywordSyntaxKind::DebuggerKeyword
+            | KeywordSyntaxKind::DeleteKeyword
+            | KeywordSyntaxKind::DoKeyword
+            | KeywordSyntaxKind::ElseKeyword
+            | KeywordSyntaxKind::EnumKeyword
+            | KeywordSyntaxKind::ExtendsKeyword
+            | KeywordSyntaxKind::FalseKeyword
+            | KeywordSyntaxKind::FinallyKeyword
+            | KeywordSyntaxKind::ForKeyword
+            | KeywordSyntaxKind::FunctionKeyword
+            | KeywordSyntaxKind::IfKeyword
+            | KeywordSyntaxKind::ImportKeyword
+            | KeywordSyntaxKind::InKeyword
+            | KeywordSyntaxKind::InstanceOfKeyword
+            | KeywordSyntaxKind::NewKeyword
+            | KeywordSyntaxKind::NullKeyword
+            | KeywordSyntaxKind::ReturnKeyword
+            | KeywordSyntaxKind::SuperKeyword
+            | KeywordSyntaxKind::SwitchKeyword
+            | KeywordSyntaxKind::ThisKeyword
+            | KeywordSyntaxKind::ThrowKeyword
+            | KeywordSyntaxKind::TrueKeyword
+            | KeywordSyntaxKind::TryKeyword
+            | KeywordSyntaxKind::TypeOfKeyword
+            | KeywordSyntaxKind::VarKeyword
+            | KeywordSyntaxKind::WhileKeyword
+            | KeywordSyntaxKind::WithKeyword
+            | KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ConstKeyword)
+            | KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::DefaultKeyword)
+            | KeywordSyntaxKind::Modifiers(ModifierSyntaxKind::ExportKeyword)
+            | KeywordSyntaxKind::KeywordType(KeywordTypeSyntaxKind::VoidKeyword)
+    )
+}
+
+pub fn token_to_string(token: SyntaxKind) -> Option<&'static str> {
+    let text = match token {
+        SyntaxKind::Keywords(keyword) => return Some(keyword_to_string(keyword)),
+        SyntaxKind::Tokens(TokenSyntaxKind::Keywords(keyword)) => {
+            return Some(keyword_to_string(keyword))
         }
+        SyntaxKind::Punctuations(punctuation)
+        | SyntaxKind::Tokens(TokenSyntaxKind::Punctuations(punctuation)) => match punctuation {
+            PunctuationSyntaxKind::OpenBraceToken => "{",
+            PunctuationSyntaxKind::CloseBraceToken => "}",
+            PunctuationSyntaxKind::OpenParenToken => "(",
+            PunctuationSyntaxKind::CloseParenToken => ")",
+            PunctuationSyntaxKind::OpenBracketToken => "[",
+            PunctuationSyntaxKind::CloseBracketToken => "]",
+            PunctuationSyntaxKind::DotToken => ".",
+            PunctuationSyntaxKind::DotDotDotToken => "...",
+            PunctuationSyntaxKind::SemicolonToken => ";",
+            PunctuationSyntaxKind::CommaToken => ",",
+            PunctuationSyntaxKind::LessThanToken => "<",
+            PunctuationSyntaxKind::GreaterThanToken => ">",
+            PunctuationSyntaxKind::LessThanEqualsToken => "<=",
+            PunctuationSyntaxKind::GreaterThanEqualsToken => ">=",
+            PunctuationSyntaxKind::EqualsEqualsToken => "==",
+            PunctuationSyntaxKind::ExclamationEqualsToken => "!=",
+            PunctuationSyntaxKind::EqualsEqualsEqualsToken => "===",
+            PunctuationSyntaxKind::ExclamationEqualsEqualsToken => "!==",
+            PunctuationSyntaxKind::EqualsGreaterThanToken => "=>",
+            PunctuationSyntaxKind::PlusToken => "+",
+            PunctuationSyntaxKind::MinusToken => "-",
+            PunctuationSyntaxKind::AsteriskAsteriskToken => "**",
+            PunctuationSyntaxKind::AsteriskToken => "*",
+            PunctuationSyntaxKind::SlashToken => "/",
+            PunctuationSyntaxKind::PercentToken => "%",
+            PunctuationSyntaxKind::PlusPlusToken => "++",
+            PunctuationSyntaxKind::MinusMinusToken => "--",
+            PunctuationSyntaxKind::LessThanLessThanToken => "<<",
+            PunctuationSyntaxKind::LessThanSlashToken => "</",
+            PunctuationSyntaxKind::GreaterThanGreaterThanToken => ">>",
+            PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanToken => ">>>",
+            PunctuationSyntaxKind::AmpersandToken => "&",
+            PunctuationSyntaxKind::BarToken => "|",
+            PunctuationSyntaxKind::CaretToken => "^",
+            PunctuationSyntaxKind::ExclamationToken => "!",
+            PunctuationSyntaxKind::TildeToken => "~",
+            PunctuationSyntaxKind::AmpersandAmpersandToken => "&&",
+            PunctuationSyntaxKind::BarBarToken => "||",
+            PunctuationSyntaxKind::QuestionToken => "?",
+            PunctuationSyntaxKind::QuestionQuestionToken => "??",
+            PunctuationSyntaxKind::QuestionDotToken => "?.",
+            PunctuationSyntaxKind::ColonToken => ":",
+            PunctuationSyntaxKind::EqualsToken => "=",
+            PunctuationSyntaxKind::PlusEqualsToken => "+=",
+            PunctuationSyntaxKind::MinusEqualsToken => "-=",
+            PunctuationSyntaxKind::AsteriskEqualsToken => "*=",
+            PunctuationSyntaxKind::AsteriskAsteriskEqualsToken => "**=",
+            PunctuationSyntaxKind::SlashEqualsToken => "/=",
+            PunctuationSyntaxKind::PercentEqualsToken => "%=",
+            PunctuationSyntaxKind::LessThanLessThanEqualsToken => "<<=",
+            PunctuationSyntaxKind::GreaterThanGreaterThanEqualsToken => ">>=",
+            PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken => ">>>=",
+            PunctuationSyntaxKind::AmpersandEqualsToken => "&=",
+            PunctuationSyntaxKind::BarEqualsToken => "|=",
+            PunctuationSyntaxKind::CaretEqualsToken => "^=",
+            PunctuationSyntaxKind::BarBarEqualsToken => "||=",
+            PunctuationSyntaxKind::AmpersandAmpersandEqualsToken => "&&=",
+            PunctuationSyntaxKind::QuestionQuestionEqualsToken => "??=",
+            PunctuationSyntaxKind::AtToken => "@",
+            PunctuationSyntaxKind::HashToken => "#",
+            PunctuationSyntaxKind::BacktickToken => "`",
+        },
+        _ => return None,
+    };
+    Some(text)
+}
+
+// The reverse of `text_to_keyword`.
+fn keyword_to_string(keyword: KeywordSyntaxKind) -> &'static str {
+    match keyword {
+        KeywordSyntaxKind::BreakKeyword => "break",
+        KeywordSyntaxKind::CaseKeyword => "case",
+        KeywordSyntaxKind::CatchKeyword => "catch",
+        KeywordSyntaxKind::ClassKeyword => "class",
+        KeywordSyntaxKind::ContinueKeyword => "continue",
+        KeywordSyntaxKind::DebuggerKeyword => "debugger",
+        KeywordSyntaxKind::DeleteKeyword => "delete",
+        KeywordSyntaxKind::DoKeyword => "do",
+        KeywordSyntaxKind::ElseKeyword => "else",
+        KeywordSyntaxKind::EnumKeyword => "enum",
+        KeywordSyntaxKind::ExtendsKeyword => "extends",
+        KeywordSyntaxKind::FalseKeyword => "false",
+        KeywordSyntaxKind::FinallyKeyword => "finally",
+        KeywordSyntaxKind::ForKeyword => "for",
+        KeywordSyntaxKind::FunctionKeyword => "function",
+        KeywordSyntaxKind::IfKeyword => "if",
+        KeywordSyntaxKind::ImportKeyword => "import",
+        KeywordSyntaxKind::InKeyword => "in",
+        KeywordSyntaxKind::InstanceOfKeyword => "instanceof",
+        KeywordSyntaxKind::NewKeyword => "new",
+        KeywordSyntaxKind::NullKeyword => "null",
+        KeywordSyntaxKind::ReturnKeyword => "return",
+        KeywordSyntaxKind::SuperKeyword => "super",
+        KeywordSyntaxKind::SwitchKeyword => "switch",
+        KeywordSyntaxKind::ThisKeyword => "this",
+        KeywordSyntaxKind::ThrowKeyword => "throw",
+        KeywordSyntaxKind::TrueKeyword => "true",
+        KeywordSyntaxKind::TryKeyword => "try",
+        KeywordSyntaxKind::TypeOfKeyword => "typeof",
+        KeywordSyntaxKind::VarKeyword => "var",
+        KeywordSyntaxKind::WhileKeyword => "while",
+        KeywordSyntaxKind::WithKeyword => "with",
+        KeywordSyntaxKind::Modifiers(modifier) => match modifier {
+            ModifierSyntaxKind::AbstractKeyword => "abstract",
+            ModifierSyntaxKind::AsyncKeyword => "async",
+            ModifierSyntaxKind::ConstKeyword => "const",
+            ModifierSyntaxKind::DeclareKeyword => "declare",
+            ModifierSyntaxKind::DefaultKeyword => "default",
+            ModifierSyntaxKind::ExportKeyword => "export",
+            ModifierSyntaxKind::PrivateKeyword => "private",
+            ModifierSyntaxKind::ProtectedKeyword => "protected",
+            ModifierSyntaxKind::PublicKeyword => "public",
+            ModifierSyntaxKind::ReadonlyKeyword => "readonly",
+            ModifierSyntaxKind::OverrideKeyword => "override",
+            ModifierSyntaxKind::StaticKeyword => "static",
+        },
+        KeywordSyntaxKind::KeywordType(keyword_type) => match keyword_type {
+            KeywordTypeSyntaxKind::AnyKeyword => "any",
+            KeywordTypeSyntaxKind::BigIntKeyword => "bigint",
+            KeywordTypeSyntaxKind::VoidKeyword => "void",
+            KeywordTypeSyntaxKind::BooleanKeyword => "boolean",
+            KeywordTypeSyntaxKind::IntrinsicKeyword => "intrinsic",
+            KeywordTypeSyntaxKind::NeverKeyword => "never",
+            KeywordTypeSyntaxKind::NumberKeyword => "number",
+            KeywordTypeSyntaxKind::ObjectKeyword => "object",
+            KeywordTypeSyntaxKind::StringKeyword => "string",
+            KeywordTypeSyntaxKind::SymbolKeyword => "symbol",
+            KeywordTypeSyntaxKind::UndefinedKeyword => "undefined",
+            KeywordTypeSyntaxKind::UnknownKeyword => "unknown",
+        },
+        KeywordSyntaxKind::ImplementsKeyword => "implements",
+        KeywordSyntaxKind::InterfaceKeyword => "interface",
+        KeywordSyntaxKind::LetKeyword => "let",
+        KeywordSyntaxKind::PackageKeyword => "package",
+        KeywordSyntaxKind::YieldKeyword => "yield",
+        KeywordSyntaxKind::AsKeyword => "as",
+        KeywordSyntaxKind::AssertsKeyword => "asserts",
+        KeywordSyntaxKind::AwaitKeyword => "await",
+        KeywordSyntaxKind::ConstructorKeyword => "constructor",
+        KeywordSyntaxKind::GetKeyword => "get",
+        KeywordSyntaxKind::InferKeyword => "infer",
+        KeywordSyntaxKind::IsKeyword => "is",
+        KeywordSyntaxKind::KeyOfKeyword => "keyof",
+        KeywordSyntaxKind::ModuleKeyword => "module",
+        KeywordSyntaxKind::NamespaceKeyword => "namespace",
+        KeywordSyntaxKind::RequireKeyword => "require",
+        KeywordSyntaxKind::SetKeyword => "set",
+        KeywordSyntaxKind::TypeKeyword => "type",
+        KeywordSyntaxKind::UniqueKeyword => "unique",
+        KeywordSyntaxKind::FromKeyword => "from",
+        KeywordSyntaxKind::GlobalKeyword => "global",
+        KeywordSyntaxKind::OfKeyword => "of",
     }
-    if code < map[0] {
-        return cx.env.get_boolean(false);
-    }
+}
 
-    let mut lo = 0;
-    let mut hi = map.len();
-    let mut mid: usize;
+/// Returns the name a `ScriptTarget` has in the `target` compiler option.
+pub fn get_name_of_script_target(target: ScriptTarget) -> &'static str {
+    match target {
+        ScriptTarget::ES3 => "es3",
+        ScriptTarget::ES5 => "es5",
+        ScriptTarget::ES2015 => "es2015",
+        ScriptTarget::ES2016 => "es2016",
+        ScriptTarget::ES2017 => "es2017",
+        ScriptTarget::ES2018 => "es2018",
+        ScriptTarget::ES2019 => "es2019",
+        ScriptTarget::ES2020 => "es2020",
+        ScriptTarget::ES2021 => "es2021",
+        ScriptTarget::ESNext => "esnext",
+        ScriptTarget::JSON => "json",
+    }
+}
 
-    while lo + 1 < hi {
-        mid = lo + (hi - lo) / 2;
-        // mid has to be even to catch a range's beginning
-        mid -= mid % 2;
-        if map[mid] <= code && code <= map[mid + 1] {
-            return cx.env.get_boolean(true);
-        }
+pub fn is_unicode_identifier_start(code: u32, language_version: ScriptTarget) -> bool {
+    if language_version >= ScriptTarget::ES2015 {
+        unicode::lookup_in_unicode_map(code, UNICODE_ESNEXT_IDENTIFIER_START)
+    } else if language_version == ScriptTarget::ES5 {
+        unicode::lookup_in_unicode_map(code, UNICODE_ES5_IDENTIFIER_START)
+    } else {
+        unicode::lookup_in_unicode_map(code, UNICODE_ES3_IDENTIFIER_START)
+    }
+}
 
-        if code < map[mid] {
-            hi = mid;
-        } else {
-            lo = mid + 2;
-        }
+fn is_unicode_identifier_part(code: u32, language_version: ScriptTarget) -> bool {
+    if language_version >= ScriptTarget::ES2015 {
+        unicode::lookup_in_unicode_map(code, UNICODE_ESNEXT_IDENTIFIER_PART)
+    } else if language_version == ScriptTarget::ES5 {
+        unicode::lookup_in_unicode_map(code, UNICODE_ES5_IDENTIFIER_PART)
+    } else {
+        unicode::lookup_in_unicode_map(code, UNICODE_ES3_IDENTIFIER_PART)
     }
+}
+
+pub fn is_white_space_like(ch: u32) -> bool {
+    is_white_space_single_line(ch) || is_line_break(ch)
+}
 
-    cx.env.get_boolean(true)
+/// Does not include line breaks. For that, see is_white_space_like.
+pub fn is_white_space_single_line(ch: u32) -> bool {
+    // Note: nextLine is in the Zs space, and should be considered to be a whitespace.
+    // It is explicitly not a line-break as it isn't in the exact set specified by EcmaScript.
+    ch == CharacterCodes::SPACE
+        || ch == CharacterCodes::TAB
+        || ch == CharacterCodes::VERTICAL_TAB
+        || ch == CharacterCodes::FORM_FEED
+        || ch == CharacterCodes::NON_BREAKING_SPACE
+        || ch == CharacterCodes::NEXT_LINE
+        || ch == CharacterCodes::OGHAM
+        || (CharacterCodes::EN_QUAD..=CharacterCodes::ZERO_WIDTH_SPACE).contains(&ch)
+        || ch == CharacterCodes::NARROW_NO_BREAK_SPACE
+        || ch == CharacterCodes::MATHEMATICAL_SPACE
+        || ch == CharacterCodes::IDEOGRAPHIC_SPACE
+        || ch == CharacterCodes::BYTE_ORDER_MARK
 }
 
-fn is_line_break(ch: u32) -> bool {
+pub fn is_line_break(ch: u32) -> bool {
     ch == CharacterCodes::LINE_FEED
         || ch == CharacterCodes::CARRIAGE_RETURN
         || ch == CharacterCodes::LINE_SEPARATOR
         || ch == CharacterCodes::PARAGRAPH_SEPARATOR
 }
 
-#[js_function(2)]
-pub fn compute_line_starts(cx: CallContext) -> Result<JsObject> {
-    let str_err = "expected a string";
-    let text: String = cx
-        .get::<JsString>(0)
-        .expect(str_err)
-        .into_utf8()
-        .expect(str_err)
-        .as_str()
-        .expect(str_err)
-        .to_string();
-    let u32_err = "failure at converting u32 to JsNumber";
+fn is_digit(ch: u32) -> bool {
+    (CharacterCodes::_0..=CharacterCodes::_9).contains(&ch)
+}
 
-    let mut result = cx.env.create_array()?;
-    let mut res_index = 0;
+fn is_hex_digit(ch: u32) -> bool {
+    is_digit(ch)
+        || (CharacterCodes::A..=CharacterCodes::F).contains(&ch)
+        || (CharacterCodes::AA..=CharacterCodes::FF).contains(&ch)
+}
 
-    for (index, arg) in text.chars().enumerate() {
-        let ch = arg as u32;
-        if index == 0 {
+fn is_code_point(code: u32) -> bool {
+    code <= 0x10FFFF
+}
+
+/* @internal */
+pub fn is_octal_digit(ch: u32) -> bool {
+    (CharacterCodes::_0..=CharacterCodes::_7).contains(&ch)
+}
+
+pub fn is_identifier_start(ch: u32, language_version: ScriptTarget) -> bool {
+    (CharacterCodes::A..=CharacterCodes::Z).contains(&ch)
+        || (CharacterCodes::AA..=CharacterCodes::ZZ).contains(&ch)
+        || ch == CharacterCodes::DOLLAR
+        || ch == CharacterCodes::LODASH
+        || ch > CharacterCodes::MAX_ASCII_CHARACTER
+            && is_unicode_identifier_start(ch, language_version)
+}
+
+pub fn is_identifier_part(
+    ch: u32,
+    language_version: ScriptTarget,
+    identifier_variant: Option<LanguageVariant>,
+) -> bool {
+    (CharacterCodes::A..=CharacterCodes::Z).contains(&ch)
+        || (CharacterCodes::AA..=CharacterCodes::ZZ).contains(&ch)
+        || (CharacterCodes::_0..=CharacterCodes::_9).contains(&ch)
+        || ch == CharacterCodes::DOLLAR
+        || ch == CharacterCodes::LODASH
+        // "-" and ":" are valid in JSX Identifiers
+        || identifier_variant == Some(LanguageVariant::JSX)
+            && (ch == CharacterCodes::MINUS || ch == CharacterCodes::COLON)
+        || ch > CharacterCodes::MAX_ASCII_CHARACTER
+            && is_unicode_identifier_part(ch, language_version)
+}
+
+/* @internal */
+pub fn is_identifier_text(
+    name: &str,
+    language_version: ScriptTarget,
+    identifier_variant: Option<LanguageVariant>,
+) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(ch) if is_identifier_start(ch as u32, language_version) => {}
+        _ => return false,
+    }
+    chars.all(|ch| is_identifier_part(ch as u32, language_version, identifier_variant))
+}
+
+fn char_size(ch: u32) -> usize {
+    if ch >= 0x10000 && ch != INVALID_CHARACTER {
+        return 2;
+    }
+    1
+}
+
+// Derived from the 10.1.1 UTF16Encoding of the ES6 Spec.
+fn utf16_encode_as_string(code_point: u32) -> String {
+    match std::char::from_u32(code_point) {
+        Some(ch) => ch.to_string(),
+        // Lone surrogates cannot be represented in a Rust string.
+        None => char::REPLACEMENT_CHARACTER.to_string(),
+    }
+}
+
+/// Converts a number to a string the way JavaScript's `Number.prototype.toString` does, which
+/// is how the JS scanner normalizes the value of numeric literals.
+pub fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+
+    // `{:e}` produces the shortest digits that round-trip, which is what the JS algorithm
+    // is specified in terms of.
+    let scientific = format!("{:e}", value.abs());
+    let exponent_start = scientific.find('e').unwrap();
+    let digits: String = scientific[..exponent_start]
+        .chars()
+        .filter(|ch| *ch != '.')
+        .collect();
+    let exponent: i32 = scientific[exponent_start + 1..].parse().unwrap();
+    let k = digits.len() as i32;
+    let n = exponent + 1;
+
+    let mut result = String::new();
+    if value < 0.0 {
+        result.push('-');
+    }
+    if k <= n && n <= 21 {
+        result.push_str(&digits);
+        result.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        result.push_str(&digits[..n as usize]);
+        result.push('.');
+        result.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        result.push_str("0.");
+        result.push_str(&"0".repeat((-n) as usize));
+        result.push_str(&digits);
+    } else {
+        result.push_str(&digits[..1]);
+        if k > 1 {
+            result.push('.');
+            result.push_str(&digits[1..]);
+        }
+        result.push('e');
+        result.push(if n > 0 { '+' } else { '-' });
+        result.push_str(&(n - 1).abs().to_string());
+    }
+    result
+}
+
+fn parse_integer(digits: &str, radix: u32) -> f64 {
+    let mut value = 0.0;
+    for ch in digits.chars() {
+        match ch.to_digit(radix) {
+            Some(digit) => value = value * radix as f64 + digit as f64,
+            None => break,
+        }
+    }
+    value
+}
+
+/// Converts a bigint literal (including its `n` suffix) to its base 10 digits, without the
+/// suffix.
+pub fn parse_pseudo_big_int(string_value: &str) -> String {
+    let bytes = string_value.as_bytes();
+    let radix = match bytes.get(1) {
+        Some(b'b') | Some(b'B') => 2,
+        Some(b'o') | Some(b'O') => 8,
+        Some(b'x') | Some(b'X') => 16,
+        _ => {
+            // already in decimal; omit trailing "n" and skip leading 0s
+            let digits = string_value[..string_value.len() - 1].trim_start_matches('0');
+            return if digits.is_empty() {
+                "0".to_string()
+            } else {
+                digits.to_string()
+            };
+        }
+    };
+
+    // Little endian base 10^9 segments of the value.
+    const SEGMENT_BASE: u64 = 1_000_000_000;
+    let mut segments: Vec<u64> = vec![0];
+    for ch in string_value[2..string_value.len() - 1].chars() {
+        let mut carry = ch.to_digit(radix).unwrap_or(0) as u64;
+        for segment in segments.iter_mut() {
+            let value = *segment * radix as u64 + carry;
+            *segment = value % SEGMENT_BASE;
+            carry = value / SEGMENT_BASE;
+        }
+        if carry > 0 {
+            segments.push(carry);
+        }
+    }
+
+    let mut result = segments.last().unwrap().to_string();
+    for segment in segments.iter().rev().skip(1) {
+        result.push_str(&format!("{:09}", segment));
+    }
+    result
+}
+
+fn get_directive_from_comment(text: &str, multi_line: bool) -> Option<CommentDirectiveType> {
+    // Matches `^\/\/\/?\s*@(ts-expect-error|ts-ignore)` for single line comments and
+    // `^(?:\/|\*)*\s*@(ts-expect-error|ts-ignore)` for the last line of multi-line comments.
+    let mut rest = text.trim_start();
+    if multi_line {
+        rest = rest.trim_start_matches(['/', '*']);
+    } else {
+        rest = rest.strip_prefix("//")?;
+        rest = rest.strip_prefix('/').unwrap_or(rest);
+    }
+    rest = rest.trim_start().strip_prefix('@')?;
+
+    if rest.starts_with("ts-expect-error") {
+        Some(CommentDirectiveType::ExpectError)
+    } else if rest.starts_with("ts-ignore") {
+        Some(CommentDirectiveType::Ignore)
+    } else {
+        None
+    }
+}
+
+fn char_code_at(text: &[u16], pos: usize) -> u32 {
+    match text.get(pos) {
+        Some(ch) => *ch as u32,
+        None => INVALID_CHARACTER,
+    }
+}
+
+fn code_point_at(text: &[u16], pos: usize) -> u32 {
+    let first = char_code_at(text, pos);
+    // check if it’s the start of a surrogate pair
+    if (0xD800..=0xDBFF).contains(&first) {
+        let second = char_code_at(text, pos + 1);
+        if (0xDC00..=0xDFFF).contains(&second) {
+            return (first - 0xD800) * 0x400 + second - 0xDC00 + 0x10000;
+        }
+    }
+    first
+}
+
+// All conflict markers consist of the same character repeated seven times.  If it is
+// a <<<<<<< or >>>>>>> marker then it is also followed by a space.
+const MERGE_CONFLICT_MARKER_LENGTH: usize = 7;
+
+fn is_conflict_marker_trivia(text: &[u16], pos: usize) -> bool {
+    // Conflict markers must be at the start of a line.
+    if pos == 0 || is_line_break(char_code_at(text, pos - 1)) {
+        let ch = char_code_at(text, pos);
+
+        if pos + MERGE_CONFLICT_MARKER_LENGTH < text.len() {
+            for i in 0..MERGE_CONFLICT_MARKER_LENGTH {
+                if char_code_at(text, pos + i) != ch {
+                    return false;
+                }
+            }
+
+            return ch == CharacterCodes::EQUALS
+                || char_code_at(text, pos + MERGE_CONFLICT_MARKER_LENGTH) == CharacterCodes::SPACE;
+        }
+    }
+
+    false
+}
+
+fn scan_conflict_marker_trivia(text: &[u16], mut pos: usize) -> usize {
+    let ch = char_code_at(text, pos);
+    let len = text.len();
+
+    if ch == CharacterCodes::LESS_THAN || ch == CharacterCodes::GREATER_THAN {
+        while pos < len && !is_line_break(char_code_at(text, pos)) {
+            pos += 1;
+        }
+    } else {
+        // Consume everything from the start of a ||||||| or ======= marker to the start
+        // of the next ======= or >>>>>>> marker.
+        while pos < len {
+            let current_char = char_code_at(text, pos);
+            if (current_char == CharacterCodes::EQUALS
+                || current_char == CharacterCodes::GREATER_THAN)
+                && current_char != ch
+                && is_conflict_marker_trivia(text, pos)
+            {
+                break;
+            }
+
+            pos += 1;
+        }
+    }
+
+    pos
+}
+
+/*@internal*/
+pub fn is_shebang_trivia(text: &[u16], pos: usize) -> bool {
+    // Shebangs check must only be done at the start of the file
+    pos == 0
+        && char_code_at(text, 0) == CharacterCodes::HASH
+        && char_code_at(text, 1) == CharacterCodes::EXCLAMATION
+}
+
+/*@internal*/
+pub fn scan_shebang_trivia(text: &[u16], mut pos: usize) -> usize {
+    // `#!.*` stops at the first line terminator
+    while pos < text.len() && !is_line_break(char_code_at(text, pos)) {
+        pos += 1;
+    }
+    pos
+}
+
+// Collects the comments that start at `pos`. Leading comments are the ones before the next token
+// (all of them at the start of the file, otherwise only those after the first line break);
+// trailing comments are the ones on the same line as `pos`. At the start of the file the shebang
+// is skipped.
+fn iterate_comment_ranges(text: &[u16], mut pos: usize, trailing: bool) -> Vec<CommentRange> {
+    let mut comments = Vec::new();
+    let mut pending: Option<CommentRange> = None;
+    let mut collecting = trailing;
+    if pos == 0 {
+        collecting = true;
+        if is_shebang_trivia(text, pos) {
+            pos = scan_shebang_trivia(text, pos);
+        }
+    }
+    while pos < text.len() {
+        let ch = char_code_at(text, pos);
+        match ch {
+            CharacterCodes::CARRIAGE_RETURN | CharacterCodes::LINE_FEED => {
+                if ch == CharacterCodes::CARRIAGE_RETURN
+                    && char_code_at(text, pos + 1) == CharacterCodes::LINE_FEED
+                {
+                    pos += 1;
+                }
+                pos += 1;
+                if trailing {
+                    break;
+                }
+
+                collecting = true;
+                if let Some(pending) = pending.as_mut() {
+                    pending.has_trailing_new_line = true;
+                }
+                continue;
+            }
+            CharacterCodes::TAB
+            | CharacterCodes::VERTICAL_TAB
+            | CharacterCodes::FORM_FEED
+            | CharacterCodes::SPACE => {
+                pos += 1;
+                continue;
+            }
+            CharacterCodes::SLASH => {
+                let next_char = char_code_at(text, pos + 1);
+                let mut has_trailing_new_line = false;
+                if next_char == CharacterCodes::SLASH || next_char == CharacterCodes::ASTERISK {
+                    let kind = if next_char == CharacterCodes::SLASH {
+                        TriviaSyntaxKind::SingleLineCommentTrivia
+                    } else {
+                        TriviaSyntaxKind::MultiLineCommentTrivia
+                    };
+                    let start_pos = pos;
+                    pos += 2;
+                    if next_char == CharacterCodes::SLASH {
+                        while pos < text.len() {
+                            if is_line_break(char_code_at(text, pos)) {
+                                has_trailing_new_line = true;
+                                break;
+                            }
+                            pos += 1;
+                        }
+                    } else {
+                        while pos < text.len() {
+                            if char_code_at(text, pos) == CharacterCodes::ASTERISK
+                                && char_code_at(text, pos + 1) == CharacterCodes::SLASH
+                            {
+                                pos += 2;
+                                break;
+                            }
+                            pos += 1;
+                        }
+                    }
+
+                    if collecting {
+                        comments.extend(pending.take());
+                        pending = Some(CommentRange {
+                            range: TextRange {
+                                pos: start_pos,
+                                end: pos,
+                            },
+                            kind,
+                            has_trailing_new_line,
+                        });
+                    }
+                    continue;
+                }
+                break;
+            }
+            _ => {
+                if ch > CharacterCodes::MAX_ASCII_CHARACTER && is_white_space_like(ch) {
+                    if is_line_break(ch) {
+                        if let Some(pending) = pending.as_mut() {
+                            pending.has_trailing_new_line = true;
+                        }
+                    }
+                    pos += 1;
+                    continue;
+                }
+                break;
+            }
+        }
+    }
+
+    comments.extend(pending);
+    comments
+}
+
+pub fn get_leading_comment_ranges(text: &[u16], pos: usize) -> Vec<CommentRange> {
+    iterate_comment_ranges(text, pos, false)
+}
+
+pub fn get_trailing_comment_ranges(text: &[u16], pos: usize) -> Vec<CommentRange> {
+    iterate_comment_ranges(text, pos, true)
+}
+
+pub fn could_start_trivia(text: &[u16], pos: usize) -> bool {
+    // Keep in sync with skip_trivia
+    match text.get(pos).map(|&ch| ch as u32) {
+        Some(
+            CharacterCodes::CARRIAGE_RETURN
+            | CharacterCodes::LINE_FEED
+            | CharacterCodes::TAB
+            | CharacterCodes::VERTICAL_TAB
+            | CharacterCodes::FORM_FEED
+            | CharacterCodes::SPACE
+            | CharacterCodes::SLASH
+            // starts of normal trivia
+            | CharacterCodes::LESS_THAN
+            | CharacterCodes::BAR
+            | CharacterCodes::EQUALS
+            | CharacterCodes::GREATER_THAN,
+        ) => {
+            // Starts of conflict marker trivia
+            true
+        }
+        // Only if its the beginning can we have #! trivia
+        Some(CharacterCodes::HASH) => pos == 0,
+        Some(ch) => ch > CharacterCodes::MAX_ASCII_CHARACTER,
+        None => false,
+    }
+}
+
+/* @internal */
+pub fn skip_trivia(
+    text: &[u16],
+    mut pos: usize,
+    stop_after_line_break: bool,
+    stop_at_comments: bool,
+    in_jsdoc: bool,
+) -> usize {
+    let mut can_consume_star = false;
+    // Keep in sync with could_start_trivia
+    loop {
+        let ch = char_code_at(text, pos);
+        match ch {
+            CharacterCodes::CARRIAGE_RETURN | CharacterCodes::LINE_FEED => {
+                if ch == CharacterCodes::CARRIAGE_RETURN
+                    && char_code_at(text, pos + 1) == CharacterCodes::LINE_FEED
+                {
+                    pos += 1;
+                }
+                pos += 1;
+                if stop_after_line_break {
+                    return pos;
+                }
+                can_consume_star = in_jsdoc;
+                continue;
+            }
+            CharacterCodes::TAB
+            | CharacterCodes::VERTICAL_TAB
+            | CharacterCodes::FORM_FEED
+            | CharacterCodes::SPACE => {
+                pos += 1;
+                continue;
+            }
+            CharacterCodes::SLASH => {
+                if stop_at_comments {
+                    return pos;
+                }
+                if char_code_at(text, pos + 1) == CharacterCodes::SLASH {
+                    pos += 2;
+                    while pos < text.len() {
+                        if is_line_break(char_code_at(text, pos)) {
+                            break;
+                        }
+                        pos += 1;
+                    }
+                    can_consume_star = false;
+                    continue;
+                }
+                if char_code_at(text, pos + 1) == CharacterCodes::ASTERISK {
+                    pos += 2;
+                    while pos < text.len() {
+                        if char_code_at(text, pos) == CharacterCodes::ASTERISK
+                            && char_code_at(text, pos + 1) == CharacterCodes::SLASH
+                        {
+                            pos += 2;
+                            break;
+                        }
+                        pos += 1;
+                    }
+                    can_consume_star = false;
+                    continue;
+                }
+            }
+            CharacterCodes::LESS_THAN
+            | CharacterCodes::BAR
+            | CharacterCodes::EQUALS
+            | CharacterCodes::GREATER_THAN => {
+                if is_conflict_marker_trivia(text, pos) {
+                    pos = scan_conflict_marker_trivia(text, pos);
+                    can_consume_star = false;
+                    continue;
+                }
+            }
+            CharacterCodes::HASH => {
+                if pos == 0 && is_shebang_trivia(text, pos) {
+                    pos = scan_shebang_trivia(text, pos);
+                    can_consume_star = false;
+                    continue;
+                }
+            }
+            CharacterCodes::ASTERISK => {
+                if can_consume_star {
+                    pos += 1;
+                    can_consume_star = false;
+                    continue;
+                }
+            }
+            _ => {
+                if ch > CharacterCodes::MAX_ASCII_CHARACTER && is_white_space_like(ch) {
+                    pos += 1;
+                    continue;
+                }
+            }
+        }
+        return pos;
+    }
+}
+
+// Creates a scanner over a (possibly unspecified) range of a piece of text.
+pub fn create_scanner(
+    language_version: ScriptTarget,
+    skip_trivia: bool,
+    language_variant: Option<LanguageVariant>,
+    text_initial: Option<String>,
+    on_error: Option<Box<ErrorCallback>>,
+    start: Option<usize>,
+    length: Option<usize>,
+) -> TextScanner {
+    let mut scanner = TextScanner {
+        text: Vec::new(),
+        pos: 0,
+        end: 0,
+        start_pos: 0,
+        token_pos: 0,
+        token: SyntaxKind::Tokens(TokenSyntaxKind::Unknown),
+        token_value: String::new(),
+        token_flags: TokenFlags::None as u32,
+        comment_directives: None,
+        in_jsdoc_type: 0,
+        language_version,
+        language_variant: language_variant.unwrap_or(LanguageVariant::Standard),
+        skip_trivia,
+        on_error,
+    };
+    scanner.set_text(text_initial, start, length);
+    scanner
+}
+
+pub struct TextScanner {
+    // The text being scanned as UTF-16 code units, so that every position matches the JS scanner
+    text: Vec<u16>,
+
+    // Current position (end position of text of current token)
+    pos: usize,
+
+    // end of text
+    end: usize,
+
+    // Start position of whitespace before current token
+    start_pos: usize,
+
+    // Start position of text of current token
+    token_pos: usize,
+
+    token: SyntaxKind,
+    token_value: String,
+    token_flags: u32,
+
+    comment_directives: Option<Vec<CommentDirective>>,
+    in_jsdoc_type: i32,
+
+    language_version: ScriptTarget,
+    language_variant: LanguageVariant,
+    skip_trivia: bool,
+    on_error: Option<Box<ErrorCallback>>,
+}
+
+pub(crate) struct ScannerStateSnapshot {
+    pos: usize,
+    start_pos: usize,
+    token_pos: usize,
+    token: SyntaxKind,
+    token_value: String,
+    token_flags: u32,
+}
+
+pub(crate) struct ScanRangeSnapshot {
+    end: usize,
+    state: ScannerStateSnapshot,
+    comment_directives: Option<Vec<CommentDirective>>,
+}
+
+impl TextScanner {
+    /// The scanned text as UTF-16 code units.
+    pub fn get_text_utf16(&self) -> &[u16] {
+        &self.text
+    }
+
+    /// The value of the current token, without the copy `get_token_value` makes.
+    pub fn token_value(&self) -> &str {
+        &self.token_value
+    }
+
+    fn char_code_at(&self, pos: usize) -> u32 {
+        char_code_at(&self.text, pos)
+    }
+
+    fn code_point_at(&self, pos: usize) -> u32 {
+        code_point_at(&self.text, pos)
+    }
+
+    fn substring(&self, start: usize, end: usize) -> String {
+        let end = end.min(self.text.len());
+        let start = start.min(end);
+        String::from_utf16_lossy(&self.text[start..end])
+    }
+
+    fn error(&mut self, message: &IDiagnosticMessage<'static>, err_pos: usize, length: usize) {
+        if let Some(on_error) = self.on_error.as_mut() {
+            on_error(message, err_pos, length);
+        }
+    }
+
+    fn error_at_pos(&mut self, message: &IDiagnosticMessage<'static>) {
+        self.error(message, self.pos, 0);
+    }
+
+    fn set_token(&mut self, token: SyntaxKind) -> SyntaxKind {
+        self.token = token;
+        token
+    }
+
+    fn punctuation(&mut self, kind: PunctuationSyntaxKind, length: usize) -> SyntaxKind {
+        self.pos += length;
+        self.set_token(SyntaxKind::Punctuations(kind))
+    }
+
+    fn scan_number_fragment(&mut self) -> String {
+        let mut start = self.pos;
+        let mut allow_separator = false;
+        let mut is_previous_token_separator = false;
+        let mut result = String::new();
+        loop {
+            let ch = self.char_code_at(self.pos);
+            if ch == CharacterCodes::LODASH {
+                self.token_flags |= TokenFlags::ContainsSeparator as u32;
+                if allow_separator {
+                    allow_separator = false;
+                    is_previous_token_separator = true;
+                    result.push_str(&self.substring(start, self.pos));
+                } else if is_previous_token_separator {
+                    self.error(
+                        &Diagnostics::Multiple_consecutive_numeric_separators_are_not_permitted,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error(
+                        &Diagnostics::Numeric_separators_are_not_allowed_here,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                start = self.pos;
+                continue;
+            }
+            if is_digit(ch) {
+                allow_separator = true;
+                is_previous_token_separator = false;
+                self.pos += 1;
+                continue;
+            }
+            break;
+        }
+        if self.pos > 0 && self.char_code_at(self.pos - 1) == CharacterCodes::LODASH {
+            self.error(
+                &Diagnostics::Numeric_separators_are_not_allowed_here,
+                self.pos - 1,
+                1,
+            );
+        }
+        result.push_str(&self.substring(start, self.pos));
+        result
+    }
+
+    fn scan_number(&mut self) -> (SyntaxKind, String) {
+        let start = self.pos;
+        let main_fragment = self.scan_number_fragment();
+        let mut decimal_fragment: Option<String> = None;
+        let mut scientific_fragment: Option<String> = None;
+        if self.char_code_at(self.pos) == CharacterCodes::DOT {
+            self.pos += 1;
+            decimal_fragment = Some(self.scan_number_fragment());
+        }
+        let mut end = self.pos;
+        if self.char_code_at(self.pos) == CharacterCodes::E
+            || self.char_code_at(self.pos) == CharacterCodes::EE
+        {
+            self.pos += 1;
+            self.token_flags |= TokenFlags::Scientific as u32;
+            if self.char_code_at(self.pos) == CharacterCodes::PLUS
+                || self.char_code_at(self.pos) == CharacterCodes::MINUS
+            {
+                self.pos += 1;
+            }
+            let pre_numeric_part = self.pos;
+            let final_fragment = self.scan_number_fragment();
+            if final_fragment.is_empty() {
+                self.error_at_pos(&Diagnostics::Digit_expected);
+            } else {
+                scientific_fragment = Some(self.substring(end, pre_numeric_part) + &final_fragment);
+                end = self.pos;
+            }
+        }
+        let result = if self.token_flags & TokenFlags::ContainsSeparator as u32 != 0 {
+            let mut result = main_fragment;
+            if let Some(decimal_fragment) = decimal_fragment.as_ref() {
+                if !decimal_fragment.is_empty() {
+                    result.push('.');
+                    result.push_str(decimal_fragment);
+                }
+            }
+            if let Some(scientific_fragment) = scientific_fragment.as_ref() {
+                result.push_str(scientific_fragment);
+            }
             result
-                .set_element(res_index, cx.env.create_uint32(0).expect(u32_err))
-                .expect(u32_err);
-            res_index += 1;
+        } else {
+            self.substring(start, end) // No need to use all the fragments; no _ removal needed
+        };
+
+        let is_scientific = self.token_flags & TokenFlags::Scientific as u32 != 0;
+        if decimal_fragment.is_some() || is_scientific {
+            self.check_for_identifier_start_after_numeric_literal(
+                start,
+                decimal_fragment.is_none() && is_scientific,
+            );
+            // if value is not an integer, it can be safely coerced to a number
+            let value = result.parse::<f64>().unwrap_or(f64::NAN);
+            (
+                SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral),
+                number_to_string(value),
+            )
+        } else {
+            self.token_value = result;
+            let kind = self.check_big_int_suffix(); // if value is an integer, check whether it is a bigint
+            self.check_for_identifier_start_after_numeric_literal(start, false);
+            (kind, self.token_value.clone())
         }
-        if (ch == CharacterCodes::LINE_FEED)
+    }
+
+    fn check_for_identifier_start_after_numeric_literal(
+        &mut self,
+        numeric_start: usize,
+        is_scientific: bool,
+    ) {
+        if !is_identifier_start(self.code_point_at(self.pos), self.language_version) {
+            return;
+        }
+
+        let identifier_start = self.pos;
+        let length = self.scan_identifier_parts().encode_utf16().count();
+
+        if length == 1 && self.char_code_at(identifier_start) == CharacterCodes::NN {
+            if is_scientific {
+                self.error(
+                    &Diagnostics::A_bigint_literal_cannot_use_exponential_notation,
+                    numeric_start,
+                    identifier_start - numeric_start + 1,
+                );
+            } else {
+                self.error(
+                    &Diagnostics::A_bigint_literal_must_be_an_integer,
+                    numeric_start,
+                    identifier_start - numeric_start + 1,
+                );
+            }
+        } else {
+            self.error(
+                &Diagnostics::An_identifier_or_keyword_cannot_immediately_follow_a_numeric_literal,
+                identifier_start,
+                length,
+            );
+            self.pos = identifier_start;
+        }
+    }
+
+    fn scan_octal_digits(&mut self) -> f64 {
+        let start = self.pos;
+        while is_octal_digit(self.char_code_at(self.pos)) {
+            self.pos += 1;
+        }
+        self.substring(start, self.pos)
+            .parse::<f64>()
+            .unwrap_or(f64::NAN)
+    }
+
+    /**
+     * Scans the given number of hexadecimal digits in the text,
+     * returning None if the given number is unavailable.
+     */
+    fn scan_exact_number_of_hex_digits(
+        &mut self,
+        count: usize,
+        can_have_separators: bool,
+    ) -> Option<u32> {
+        let value_string = self.scan_hex_digits(count, false, can_have_separators);
+        if value_string.is_empty() {
+            None
+        } else {
+            u32::from_str_radix(&value_string, 16).ok()
+        }
+    }
+
+    /**
+     * Scans as many hexadecimal digits as are available in the text,
+     * returning "" if the given number of digits was unavailable.
+     */
+    fn scan_minimum_number_of_hex_digits(
+        &mut self,
+        count: usize,
+        can_have_separators: bool,
+    ) -> String {
+        self.scan_hex_digits(count, true, can_have_separators)
+    }
+
+    fn scan_hex_digits(
+        &mut self,
+        min_count: usize,
+        scan_as_many_as_possible: bool,
+        can_have_separators: bool,
+    ) -> String {
+        let mut value_chars: Vec<u8> = Vec::new();
+        let mut allow_separator = false;
+        let mut is_previous_token_separator = false;
+        while value_chars.len() < min_count || scan_as_many_as_possible {
+            let mut ch = self.char_code_at(self.pos);
+            if can_have_separators && ch == CharacterCodes::LODASH {
+                self.token_flags |= TokenFlags::ContainsSeparator as u32;
+                if allow_separator {
+                    allow_separator = false;
+                    is_previous_token_separator = true;
+                } else if is_previous_token_separator {
+                    self.error(
+                        &Diagnostics::Multiple_consecutive_numeric_separators_are_not_permitted,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error(
+                        &Diagnostics::Numeric_separators_are_not_allowed_here,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                continue;
+            }
+            allow_separator = can_have_separators;
+            if (CharacterCodes::A..=CharacterCodes::F).contains(&ch) {
+                ch += CharacterCodes::AA - CharacterCodes::A; // standardize hex literals to lowercase
+            } else if !((CharacterCodes::_0..=CharacterCodes::_9).contains(&ch)
+                || (CharacterCodes::AA..=CharacterCodes::FF).contains(&ch))
+            {
+                break;
+            }
+            value_chars.push(ch as u8);
+            self.pos += 1;
+            is_previous_token_separator = false;
+        }
+        if value_chars.len() < min_count {
+            value_chars.clear();
+        }
+        if self.pos > 0 && self.char_code_at(self.pos - 1) == CharacterCodes::LODASH {
+            self.error(
+                &Diagnostics::Numeric_separators_are_not_allowed_here,
+                self.pos - 1,
+                1,
+            );
+        }
+        String::from_utf8(value_chars).unwrap()
+    }
+
+    fn scan_string(&mut self, jsx_attribute_string: bool) -> String {
+        let quote = self.char_code_at(self.pos);
+        self.pos += 1;
+        let mut result = String::new();
+        let mut start = self.pos;
+        loop {
+            if self.pos >= self.end {
+                result.push_str(&self.substring(start, self.pos));
+                self.token_flags |= TokenFlags::Unterminated as u32;
+                self.error_at_pos(&Diagnostics::Unterminated_string_literal);
+                break;
+            }
+            let ch = self.char_code_at(self.pos);
+            if ch == quote {
+                result.push_str(&self.substring(start, self.pos));
+                self.pos += 1;
+                break;
+            }
+            if ch == CharacterCodes::BACKSLASH && !jsx_attribute_string {
+                result.push_str(&self.substring(start, self.pos));
+                result.push_str(&self.scan_escape_sequence(false));
+                start = self.pos;
+                continue;
+            }
+            if is_line_break(ch) && !jsx_attribute_string {
+                result.push_str(&self.substring(start, self.pos));
+                self.token_flags |= TokenFlags::Unterminated as u32;
+                self.error_at_pos(&Diagnostics::Unterminated_string_literal);
+                break;
+            }
+            self.pos += 1;
+        }
+        result
+    }
+
+    /**
+     * Sets the current 'token_value' and returns a NoSubstitutionTemplateLiteral or
+     * a literal component of a TemplateExpression.
+     */
+    fn scan_template_and_set_token_value(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        let started_with_backtick = self.char_code_at(self.pos) == CharacterCodes::BACKTICK;
+
+        self.pos += 1;
+        let mut start = self.pos;
+        let mut contents = String::new();
+        let resulting_token: SyntaxKind;
+
+        loop {
+            if self.pos >= self.end {
+                contents.push_str(&self.substring(start, self.pos));
+                self.token_flags |= TokenFlags::Unterminated as u32;
+                self.error_at_pos(&Diagnostics::Unterminated_template_literal);
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral)
+                } else {
+                    SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail)
+                };
+                break;
+            }
+
+            let curr_char = self.char_code_at(self.pos);
+
+            // '`'
+            if curr_char == CharacterCodes::BACKTICK {
+                contents.push_str(&self.substring(start, self.pos));
+                self.pos += 1;
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral)
+                } else {
+                    SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail)
+                };
+                break;
+            }
+
+            // '${'
+            if curr_char == CharacterCodes::DOLLAR
+                && self.pos + 1 < self.end
+                && self.char_code_at(self.pos + 1) == CharacterCodes::OPEN_BRACE
+            {
+                contents.push_str(&self.substring(start, self.pos));
+                self.pos += 2;
+                resulting_token = if started_with_backtick {
+                    SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateHead)
+                } else {
+                    SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateMiddle)
+                };
+                break;
+            }
+
+            // Escape character
+            if curr_char == CharacterCodes::BACKSLASH {
+                contents.push_str(&self.substring(start, self.pos));
+                contents.push_str(&self.scan_escape_sequence(is_tagged_template));
+                start = self.pos;
+                continue;
+            }
+
+            // Speculated ECMAScript 6 Spec 11.8.6.1:
+            // <CR><LF> and <CR> LineTerminatorSequences are normalized to <LF> for Template Values
+            if curr_char == CharacterCodes::CARRIAGE_RETURN {
+                contents.push_str(&self.substring(start, self.pos));
+                self.pos += 1;
+
+                if self.pos < self.end && self.char_code_at(self.pos) == CharacterCodes::LINE_FEED {
+                    self.pos += 1;
+                }
+
+                contents.push('\n');
+                start = self.pos;
+                continue;
+            }
+
+            self.pos += 1;
+        }
+
+        self.token_value = contents;
+        resulting_token
+    }
+
+    fn scan_escape_sequence(&mut self, is_tagged_template: bool) -> String {
+        let start = self.pos;
+        self.pos += 1;
+        if self.pos >= self.end {
+            self.error_at_pos(&Diagnostics::Unexpected_end_of_text);
+            return String::new();
+        }
+        let ch = self.char_code_at(self.pos);
+        self.pos += 1;
+        match ch {
+            CharacterCodes::_0 => {
+                // '\01'
+                if is_tagged_template
+                    && self.pos < self.end
+                    && is_digit(self.char_code_at(self.pos))
+                {
+                    self.pos += 1;
+                    self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                    return self.substring(start, self.pos);
+                }
+                "\0".to_string()
+            }
+            CharacterCodes::BB => "\u{8}".to_string(),
+            CharacterCodes::TT => "\t".to_string(),
+            CharacterCodes::NN => "\n".to_string(),
+            CharacterCodes::VV => "\u{b}".to_string(),
+            CharacterCodes::FF => "\u{c}".to_string(),
+            CharacterCodes::RR => "\r".to_string(),
+            CharacterCodes::SINGLE_QUOTE => "'".to_string(),
+            CharacterCodes::DOUBLE_QUOTE => "\"".to_string(),
+            CharacterCodes::UU => {
+                if is_tagged_template {
+                    // '\u' or '\u0' or '\u00' or '\u000'
+                    for escape_pos in self.pos..self.pos + 4 {
+                        if escape_pos < self.end
+                            && !is_hex_digit(self.char_code_at(escape_pos))
+                            && self.char_code_at(escape_pos) != CharacterCodes::OPEN_BRACE
+                        {
+                            self.pos = escape_pos;
+                            self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                            return self.substring(start, self.pos);
+                        }
+                    }
+                }
+                // '\u{DDDDDDDD}'
+                if self.pos < self.end && self.char_code_at(self.pos) == CharacterCodes::OPEN_BRACE
+                {
+                    self.pos += 1;
+
+                    // '\u{'
+                    if is_tagged_template && !is_hex_digit(self.char_code_at(self.pos)) {
+                        self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                        return self.substring(start, self.pos);
+                    }
+
+                    if is_tagged_template {
+                        let save_pos = self.pos;
+                        let escaped_value_string = self.scan_minimum_number_of_hex_digits(1, false);
+                        let escaped_value = u32::from_str_radix(&escaped_value_string, 16).ok();
+
+                        // '\u{Not Code Point' or '\u{CodePoint'
+                        if !escaped_value.is_some_and(is_code_point)
+                            || self.char_code_at(self.pos) != CharacterCodes::CLOSE_BRACE
+                        {
+                            self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                            return self.substring(start, self.pos);
+                        } else {
+                            self.pos = save_pos;
+                        }
+                    }
+                    self.token_flags |= TokenFlags::ExtendedUnicodeEscape as u32;
+                    return self.scan_extended_unicode_escape();
+                }
+
+                self.token_flags |= TokenFlags::UnicodeEscape as u32;
+                // '\uDDDD'
+                self.scan_hexadecimal_escape(4)
+            }
+            CharacterCodes::XX => {
+                if is_tagged_template {
+                    if !is_hex_digit(self.char_code_at(self.pos)) {
+                        self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                        return self.substring(start, self.pos);
+                    } else if !is_hex_digit(self.char_code_at(self.pos + 1)) {
+                        self.pos += 1;
+                        self.token_flags |= TokenFlags::ContainsInvalidEscape as u32;
+                        return self.substring(start, self.pos);
+                    }
+                }
+                // '\xDD'
+                self.scan_hexadecimal_escape(2)
+            }
+            // when encountering a LineContinuation (i.e. a backslash and a line terminator sequence),
+            // the line terminator is interpreted to be "the empty code unit sequence".
+            CharacterCodes::CARRIAGE_RETURN => {
+                if self.pos < self.end && self.char_code_at(self.pos) == CharacterCodes::LINE_FEED {
+                    self.pos += 1;
+                }
+                String::new()
+            }
+            CharacterCodes::LINE_FEED
+            | CharacterCodes::LINE_SEPARATOR
+            | CharacterCodes::PARAGRAPH_SEPARATOR => String::new(),
+            _ => utf16_encode_as_string(ch),
+        }
+    }
+
+    fn scan_hexadecimal_escape(&mut self, num_digits: usize) -> String {
+        match self.scan_exact_number_of_hex_digits(num_digits, false) {
+            Some(escaped_value) => utf16_encode_as_string(escaped_value),
+            None => {
+                self.error_at_pos(&Diagnostics::Hexadecimal_digit_expected);
+                String::new()
+            }
+        }
+    }
+
+    fn scan_extended_unicode_escape(&mut self) -> String {
+        let escaped_value_string = self.scan_minimum_number_of_hex_digits(1, false);
+        let escaped_value = if escaped_value_string.is_empty() {
+            None
+        } else {
+            // Anything that does not fit is certainly out of range.
+            Some(u32::from_str_radix(&escaped_value_string, 16).unwrap_or(u32::MAX))
+        };
+        let mut is_invalid_extended_escape = false;
+
+        // Validate the value of the digit
+        match escaped_value {
+            None => {
+                self.error_at_pos(&Diagnostics::Hexadecimal_digit_expected);
+                is_invalid_extended_escape = true;
+            }
+            Some(value) if value > 0x10FFFF => {
+                self.error_at_pos(
+                    &Diagnostics::An_extended_Unicode_escape_value_must_be_between_0x0_and_0x10FFFF_inclusive,
+                );
+                is_invalid_extended_escape = true;
+            }
+            _ => {}
+        }
+
+        if self.pos >= self.end {
+            self.error_at_pos(&Diagnostics::Unexpected_end_of_text);
+            is_invalid_extended_escape = true;
+        } else if self.char_code_at(self.pos) == CharacterCodes::CLOSE_BRACE {
+            // Only swallow the following character up if it's a '}'.
+            self.pos += 1;
+        } else {
+            self.error_at_pos(&Diagnostics::Unterminated_Unicode_escape_sequence);
+            is_invalid_extended_escape = true;
+        }
+
+        if is_invalid_extended_escape {
+            return String::new();
+        }
+
+        utf16_encode_as_string(escaped_value.unwrap())
+    }
+
+    // Current character is known to be a backslash. Check for Unicode escape of the form '\uXXXX'
+    // and return code point value if valid Unicode escape is found.
+    fn peek_unicode_escape(&mut self) -> Option<u32> {
+        if self.pos + 5 < self.end && self.char_code_at(self.pos + 1) == CharacterCodes::UU {
+            let start = self.pos;
+            self.pos += 2;
+            let value = self.scan_exact_number_of_hex_digits(4, false);
+            self.pos = start;
+            return value;
+        }
+        None
+    }
+
+    // `\u{...}` escapes in identifiers only exist since ES2015; for older targets the backslash
+    // is reported as an invalid character, like the JS scanner does.
+    fn peek_extended_unicode_escape(&mut self) -> Option<u32> {
+        if self.language_version >= ScriptTarget::ES2015
+            && self.code_point_at(self.pos + 1) == CharacterCodes::UU
+            && self.code_point_at(self.pos + 2) == CharacterCodes::OPEN_BRACE
+        {
+            let start = self.pos;
+            self.pos += 3;
+            let escaped_value_string = self.scan_minimum_number_of_hex_digits(1, false);
+            self.pos = start;
+            if escaped_value_string.is_empty() {
+                return None;
+            }
+            return Some(u32::from_str_radix(&escaped_value_string, 16).unwrap_or(u32::MAX));
+        }
+        None
+    }
+
+    fn scan_identifier_parts(&mut self) -> String {
+        let mut result = String::new();
+        let mut start = self.pos;
+        while self.pos < self.end {
+            let ch = self.code_point_at(self.pos);
+            if is_identifier_part(ch, self.language_version, None) {
+                self.pos += char_size(ch);
+            } else if ch == CharacterCodes::BACKSLASH {
+                if let Some(ch) = self.peek_extended_unicode_escape() {
+                    if is_identifier_part(ch, self.language_version, None) {
+                        self.pos += 3;
+                        self.token_flags |= TokenFlags::ExtendedUnicodeEscape as u32;
+                        result.push_str(&self.scan_extended_unicode_escape());
+                        start = self.pos;
+                        continue;
+                    }
+                }
+                let ch = match self.peek_unicode_escape() {
+                    Some(ch) if is_identifier_part(ch, self.language_version, None) => ch,
+                    _ => break,
+                };
+                self.token_flags |= TokenFlags::UnicodeEscape as u32;
+                result.push_str(&self.substring(start, self.pos));
+                result.push_str(&utf16_encode_as_string(ch));
+                // Valid Unicode escape is always six characters
+                self.pos += 6;
+                start = self.pos;
+            } else {
+                break;
+            }
+        }
+        result.push_str(&self.substring(start, self.pos));
+        result
+    }
+
+    fn get_identifier_token(&mut self) -> SyntaxKind {
+        // Reserved words are between 2 and 12 characters long and start with a lowercase letter
+        let len = self.token_value.len();
+        if (2..=12).contains(&len) {
+            let ch = self.token_value.as_bytes()[0] as u32;
+            if (CharacterCodes::AA..=CharacterCodes::ZZ).contains(&ch) {
+                if let Some(keyword) = text_to_keyword(&self.token_value) {
+                    return self.set_token(SyntaxKind::Keywords(keyword));
+                }
+            }
+        }
+        self.set_token(SyntaxKind::Tokens(TokenSyntaxKind::Identifier))
+    }
+
+    fn scan_binary_or_octal_digits(&mut self, base: u32) -> String {
+        let mut value = String::new();
+        // For counting number of digits; Valid binaryIntegerLiteral must have at least one binary digit following B or b.
+        // Similarly valid octalIntegerLiteral must have at least one octal digit following o or O.
+        let mut separator_allowed = false;
+        let mut is_previous_token_separator = false;
+        loop {
+            let ch = self.char_code_at(self.pos);
+            // Numeric separators are allowed anywhere within a numeric literal, except not at the beginning, or following another separator
+            if ch == CharacterCodes::LODASH {
+                self.token_flags |= TokenFlags::ContainsSeparator as u32;
+                if separator_allowed {
+                    separator_allowed = false;
+                    is_previous_token_separator = true;
+                } else if is_previous_token_separator {
+                    self.error(
+                        &Diagnostics::Multiple_consecutive_numeric_separators_are_not_permitted,
+                        self.pos,
+                        1,
+                    );
+                } else {
+                    self.error(
+                        &Diagnostics::Numeric_separators_are_not_allowed_here,
+                        self.pos,
+                        1,
+                    );
+                }
+                self.pos += 1;
+                continue;
+            }
+            separator_allowed = true;
+            if !is_digit(ch) || ch - CharacterCodes::_0 >= base {
+                break;
+            }
+            value.push(std::char::from_u32(ch).unwrap());
+            self.pos += 1;
+            is_previous_token_separator = false;
+        }
+        if self.pos > 0 && self.char_code_at(self.pos - 1) == CharacterCodes::LODASH {
+            // Literal ends with underscore - not allowed
+            self.error(
+                &Diagnostics::Numeric_separators_are_not_allowed_here,
+                self.pos - 1,
+                1,
+            );
+        }
+        value
+    }
+
+    fn check_big_int_suffix(&mut self) -> SyntaxKind {
+        if self.char_code_at(self.pos) == CharacterCodes::NN {
+            self.token_value.push('n');
+            // Use base 10 instead of base 2 or base 8 for shorter literals
+            if self.token_flags & TokenFlags::BinaryOrOctalSpecifier as u32 != 0 {
+                self.token_value = parse_pseudo_big_int(&self.token_value) + "n";
+            }
+            self.pos += 1;
+            SyntaxKind::Literals(LiteralSyntaxKind::BigIntLiteral)
+        } else {
+            // not a bigint, so can convert to number in simplified form
+            let numeric_value = if self.token_flags & TokenFlags::BinarySpecifier as u32 != 0 {
+                parse_integer(&self.token_value[2..], 2) // skip "0b"
+            } else if self.token_flags & TokenFlags::OctalSpecifier as u32 != 0 {
+                parse_integer(&self.token_value[2..], 8) // skip "0o"
+            } else if self.token_flags & TokenFlags::HexSpecifier as u32 != 0 {
+                parse_integer(&self.token_value[2..], 16) // skip "0x"
+            } else {
+                self.token_value.parse::<f64>().unwrap_or(f64::NAN)
+            };
+            self.token_value = number_to_string(numeric_value);
+            SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral)
+        }
+    }
+
+    fn scan_identifier(
+        &mut self,
+        start_character: u32,
+        language_version: ScriptTarget,
+    ) -> Option<SyntaxKind> {
+        let mut ch = start_character;
+        if is_identifier_start(ch, language_version) {
+            self.pos += char_size(ch);
+            while self.pos < self.end {
+                ch = self.code_point_at(self.pos);
+                if !is_identifier_part(ch, language_version, None) {
+                    break;
+                }
+                self.pos += char_size(ch);
+            }
+            self.token_value = self.substring(self.token_pos, self.pos);
+            if ch == CharacterCodes::BACKSLASH {
+                let parts = self.scan_identifier_parts();
+                self.token_value.push_str(&parts);
+            }
+            return Some(self.get_identifier_token());
+        }
+        None
+    }
+
+    fn append_if_comment_directive(&mut self, line_start: usize, multi_line: bool) {
+        let text = self.substring(line_start, self.pos);
+        if let Some(directive_type) = get_directive_from_comment(&text, multi_line) {
+            self.comment_directives
+                .get_or_insert_with(Vec::new)
+                .push(CommentDirective {
+                    range: TextRange {
+                        pos: line_start,
+                        end: self.pos,
+                    },
+                    _type: directive_type,
+                });
+        }
+    }
+
+    fn scan_jsx_token_worker(&mut self, allow_multiline_jsx_text: bool) -> JsxTokenSyntaxKind {
+        self.start_pos = self.pos;
+        self.token_pos = self.pos;
+
+        if self.pos >= self.end {
+            self.token = SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken);
+            return JsxTokenSyntaxKind::EndOfFileToken;
+        }
+
+        let mut char = self.char_code_at(self.pos);
+        if char == CharacterCodes::LESS_THAN {
+            if self.char_code_at(self.pos + 1) == CharacterCodes::SLASH {
+                self.pos += 2;
+                self.token = JsxTokenSyntaxKind::LessThanSlashToken.into();
+                return JsxTokenSyntaxKind::LessThanSlashToken;
+            }
+            self.pos += 1;
+            self.token = JsxTokenSyntaxKind::LessThanToken.into();
+            return JsxTokenSyntaxKind::LessThanToken;
+        }
+
+        if char == CharacterCodes::OPEN_BRACE {
+            self.pos += 1;
+            self.token = JsxTokenSyntaxKind::OpenBraceToken.into();
+            return JsxTokenSyntaxKind::OpenBraceToken;
+        }
+
+        // First non-whitespace character on this line.
+        // 0 means that we only saw whitespace so far, -1 that it was followed by a line break.
+        let mut first_non_whitespace: isize = 0;
+
+        while self.pos < self.end {
+            char = self.char_code_at(self.pos);
+            if char == CharacterCodes::OPEN_BRACE {
+                break;
+            }
+            if char == CharacterCodes::LESS_THAN {
+                if is_conflict_marker_trivia(&self.text, self.pos) {
+                    self.error(
+                        &Diagnostics::Merge_conflict_marker_encountered,
+                        self.pos,
+                        MERGE_CONFLICT_MARKER_LENGTH,
+                    );
+                    self.pos = scan_conflict_marker_trivia(&self.text, self.pos);
+                    self.token = JsxTokenSyntaxKind::ConflictMarkerTrivia.into();
+                    return JsxTokenSyntaxKind::ConflictMarkerTrivia;
+                }
+                break;
+            }
+            if char == CharacterCodes::GREATER_THAN {
+                self.error(
+                    &Diagnostics::Unexpected_token_Did_you_mean_or_gt,
+                    self.pos,
+                    1,
+                );
+            }
+            if char == CharacterCodes::CLOSE_BRACE {
+                self.error(
+                    &Diagnostics::Unexpected_token_Did_you_mean_or_rbrace,
+                    self.pos,
+                    1,
+                );
+            }
+
+            // FirstNonWhitespace is 0, then we only see whitespaces so far. If we see a linebreak, we want to ignore that whitespaces.
+            // i.e (- : whitespace)
+            //      <div>----
+            //      </div> becomes <div></div>
+            //
+            //      <div>----</div> becomes <div>----</div>
+            if is_line_break(char) && first_non_whitespace == 0 {
+                first_non_whitespace = -1;
+            } else if !allow_multiline_jsx_text && is_line_break(char) && first_non_whitespace > 0 {
+                // Stop JsxText on each line during formatting. This allows the formatter to
+                // indent each line correctly.
+                break;
+            } else if !is_white_space_like(char) {
+                first_non_whitespace = self.pos as isize;
+            }
+
+            self.pos += 1;
+        }
+
+        self.token_value = self.substring(self.start_pos, self.pos);
+
+        if first_non_whitespace == -1 {
+            JsxTokenSyntaxKind::JsxTextAllWhiteSpaces
+        } else {
+            JsxTokenSyntaxKind::JsxText
+        }
+    }
+
+    fn speculation_helper<T>(
+        &mut self,
+        callback: impl FnOnce(&mut Self) -> T,
+        should_restore: impl FnOnce(&T) -> bool,
+    ) -> T {
+        let saved = self.snapshot();
+        let result = callback(self);
+
+        // If our callback returned nothing or we're just looking ahead,
+        // then unconditionally restore us to where we were.
+        if should_restore(&result) {
+            self.restore(saved);
+        }
+        result
+    }
+
+    pub(crate) fn snapshot(&self) -> ScannerStateSnapshot {
+        ScannerStateSnapshot {
+            pos: self.pos,
+            start_pos: self.start_pos,
+            token_pos: self.token_pos,
+            token: self.token,
+            token_value: self.token_value.clone(),
+            token_flags: self.token_flags,
+        }
+    }
+
+    pub(crate) fn restore(&mut self, saved: ScannerStateSnapshot) {
+        self.pos = saved.pos;
+        self.start_pos = saved.start_pos;
+        self.token_pos = saved.token_pos;
+        self.token = saved.token;
+        self.token_value = saved.token_value;
+        self.token_flags = saved.token_flags;
+    }
+
+    // The two halves of `scan_range`, for callers that cannot hand the scanner to a callback
+    // because they scan the range with their own state, like the JSDoc parser.
+    pub(crate) fn enter_range(&mut self, start: usize, length: usize) -> ScanRangeSnapshot {
+        let saved = ScanRangeSnapshot {
+            end: self.end,
+            state: self.snapshot(),
+            comment_directives: self.comment_directives.take(),
+        };
+        self.end = start + length;
+        self.set_text_pos(Some(start));
+        saved
+    }
+
+    pub(crate) fn exit_range(&mut self, saved: ScanRangeSnapshot) {
+        self.end = saved.end;
+        self.restore(saved.state);
+        self.comment_directives = saved.comment_directives;
+    }
+}
+
+impl Scanner for TextScanner {
+    fn get_start_pos(&self) -> usize {
+        self.start_pos
+    }
+
+    fn get_token(&self) -> SyntaxKind {
+        self.token
+    }
+
+    fn get_text_pos(&self) -> usize {
+        self.pos
+    }
+
+    fn get_token_pos(&self) -> usize {
+        self.token_pos
+    }
+
+    fn get_token_text(&self) -> String {
+        self.substring(self.token_pos, self.pos)
+    }
+
+    fn get_token_value(&self) -> String {
+        self.token_value.clone()
+    }
+
+    fn has_unicode_escape(&self) -> bool {
+        self.token_flags & TokenFlags::UnicodeEscape as u32 != 0
+    }
+
+    fn has_extended_unicode_escape(&self) -> bool {
+        self.token_flags & TokenFlags::ExtendedUnicodeEscape as u32 != 0
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.token_flags & TokenFlags::PrecedingLineBreak as u32 != 0
+    }
+
+    fn has_preceding_jsdoc_comment(&self) -> bool {
+        self.token_flags & TokenFlags::PrecedingJSDocComment as u32 != 0
+    }
+
+    fn is_identifier(&self) -> bool {
+        match self.token {
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier) => true,
+            SyntaxKind::Keywords(keyword) => !is_reserved_word(keyword),
+            _ => false,
+        }
+    }
+
+    fn is_reserved_word(&self) -> bool {
+        match self.token {
+            SyntaxKind::Keywords(keyword) => is_reserved_word(keyword),
+            _ => false,
+        }
+    }
+
+    fn is_unterminated(&self) -> bool {
+        self.token_flags & TokenFlags::Unterminated as u32 != 0
+    }
+
+    fn get_numeric_literal_flags(&self) -> u32 {
+        self.token_flags & TokenFlags::NumericLiteralFlags as u32
+    }
+
+    fn get_comment_directives(&self) -> Option<&[CommentDirective]> {
+        self.comment_directives.as_deref()
+    }
+
+    fn get_token_flags(&self) -> u32 {
+        self.token_flags
+    }
+
+    fn re_scan_greater_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanToken) {
+            if self.char_code_at(self.pos) == CharacterCodes::GREATER_THAN {
+                if self.char_code_at(self.pos + 1) == CharacterCodes::GREATER_THAN {
+                    if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                        return self.punctuation(
+                            PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken,
+                            3,
+                        );
+                    }
+                    return self.punctuation(
+                        PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanToken,
+                        2,
+                    );
+                }
+                if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                    return self
+                        .punctuation(PunctuationSyntaxKind::GreaterThanGreaterThanEqualsToken, 2);
+                }
+                return self.punctuation(PunctuationSyntaxKind::GreaterThanGreaterThanToken, 1);
+            }
+            if self.char_code_at(self.pos) == CharacterCodes::EQUALS {
+                return self.punctuation(PunctuationSyntaxKind::GreaterThanEqualsToken, 1);
+            }
+        }
+        self.token
+    }
+
+    fn re_scan_slash_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashToken)
+            || self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashEqualsToken)
+        {
+            let mut p = self.token_pos + 1;
+            let mut in_escape = false;
+            let mut in_character_class = false;
+            loop {
+                // If we reach the end of a file, or hit a newline, then this is an unterminated
+                // regex.  Report error and return what we have so far.
+                if p >= self.end {
+                    self.token_flags |= TokenFlags::Unterminated as u32;
+                    self.error_at_pos(&Diagnostics::Unterminated_regular_expression_literal);
+                    break;
+                }
+
+                let ch = self.char_code_at(p);
+                if is_line_break(ch) {
+                    self.token_flags |= TokenFlags::Unterminated as u32;
+                    self.error_at_pos(&Diagnostics::Unterminated_regular_expression_literal);
+                    break;
+                }
+
+                if in_escape {
+                    // Parsing an escape character;
+                    // reset the flag and just advance to the next char.
+                    in_escape = false;
+                } else if ch == CharacterCodes::SLASH && !in_character_class {
+                    // A slash within a character class is permissible,
+                    // but in general it signals the end of the regexp literal.
+                    p += 1;
+                    break;
+                } else if ch == CharacterCodes::OPEN_BRACKET {
+                    in_character_class = true;
+                } else if ch == CharacterCodes::BACKSLASH {
+                    in_escape = true;
+                } else if ch == CharacterCodes::CLOSE_BRACKET {
+                    in_character_class = false;
+                }
+                p += 1;
+            }
+
+            while p < self.end
+                && is_identifier_part(self.char_code_at(p), self.language_version, None)
+            {
+                p += 1;
+            }
+            self.pos = p;
+            self.token_value = self.substring(self.token_pos, self.pos);
+            self.token = SyntaxKind::Literals(LiteralSyntaxKind::RegularExpressionLiteral);
+        }
+        self.token
+    }
+
+    fn re_scan_asterisk_equals_token(&mut self) -> SyntaxKind {
+        debug_assert!(
+            self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskEqualsToken),
+            "'re_scan_asterisk_equals_token' should only be called on a '*='"
+        );
+        self.pos = self.token_pos + 1;
+        self.set_token(SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsToken))
+    }
+
+    /**
+     * Unconditionally back up and scan a template expression portion.
+     */
+    fn re_scan_template_token(&mut self, is_tagged_template: bool) -> SyntaxKind {
+        debug_assert!(
+            self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken),
+            "'re_scan_template_token' should only be called on a '{}'",
+            "}"
+        );
+        self.pos = self.token_pos;
+        let token = self.scan_template_and_set_token_value(is_tagged_template);
+        self.set_token(token)
+    }
+
+    fn re_scan_template_head_or_no_substitution_template(&mut self) -> SyntaxKind {
+        self.pos = self.token_pos;
+        let token = self.scan_template_and_set_token_value(true);
+        self.set_token(token)
+    }
+
+    // Scans a JSX identifier; these differ from normal identifiers in that
+    // they allow dashes
+    fn scan_jsx_identifier(&mut self) -> SyntaxKind {
+        if token_is_identifier_or_keyword(self.token) {
+            // An identifier or keyword has already been parsed - check for a `-` or a single instance of `:` and then append it and
+            // everything after it to the token
+            // Do note that this means that `scan_jsx_identifier` effectively _mutates_ the visible token without advancing to a new token
+            // Any caller should be expecting this behavior and should only read the pos or token value after calling it.
+            let mut namespace_separator = false;
+            while self.pos < self.end {
+                let ch = self.char_code_at(self.pos);
+                if ch == CharacterCodes::MINUS {
+                    self.token_value.push('-');
+                    self.pos += 1;
+                    continue;
+                } else if ch == CharacterCodes::COLON && !namespace_separator {
+                    self.token_value.push(':');
+                    self.pos += 1;
+                    namespace_separator = true;
+                    self.token = SyntaxKind::Tokens(TokenSyntaxKind::Identifier); // swap from keyword kind to identifier kind
+                    continue;
+                }
+                let old_pos = self.pos;
+                let parts = self.scan_identifier_parts(); // reuse `scan_identifier_parts` so unicode escapes are handled
+                self.token_value.push_str(&parts);
+                if self.pos == old_pos {
+                    break;
+                }
+            }
+            // Do not include a trailing namespace separator in the token, since this is against the spec.
+            if self.token_value.ends_with(':') {
+                self.token_value.pop();
+                self.pos -= 1;
+            }
+        }
+        self.token
+    }
+
+    fn scan_jsx_attribute_value(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+
+        match self.char_code_at(self.pos) {
+            CharacterCodes::DOUBLE_QUOTE | CharacterCodes::SINGLE_QUOTE => {
+                self.token_value = self.scan_string(true);
+                self.set_token(SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral))
+            }
+            // If this scans anything other than `{`, it's a parse error.
+            _ => self.scan(),
+        }
+    }
+
+    fn re_scan_jsx_attribute_value(&mut self) -> SyntaxKind {
+        self.pos = self.start_pos;
+        self.token_pos = self.start_pos;
+        self.scan_jsx_attribute_value()
+    }
+
+    fn re_scan_jsx_token(&mut self, allow_multiline_jsx_text: Option<bool>) -> JsxTokenSyntaxKind {
+        self.pos = self.start_pos;
+        self.token_pos = self.start_pos;
+        let token = self.scan_jsx_token_worker(allow_multiline_jsx_text.unwrap_or(true));
+        self.token = token.into();
+        token
+    }
+
+    fn re_scan_less_than_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanLessThanToken) {
+            self.pos = self.token_pos + 1;
+            return self.set_token(SyntaxKind::Punctuations(
+                PunctuationSyntaxKind::LessThanToken,
+            ));
+        }
+        self.token
+    }
+
+    fn re_scan_hash_token(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::PrivateIdentifier {
+            self.pos = self.token_pos + 1;
+            return self.set_token(SyntaxKind::Punctuations(PunctuationSyntaxKind::HashToken));
+        }
+        self.token
+    }
+
+    fn re_scan_question_token(&mut self) -> SyntaxKind {
+        debug_assert!(
+            self.token == SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionQuestionToken),
+            "'re_scan_question_token' should only be called on a '??'"
+        );
+        self.pos = self.token_pos + 1;
+        self.set_token(SyntaxKind::Punctuations(
+            PunctuationSyntaxKind::QuestionToken,
+        ))
+    }
+
+    fn re_scan_invalid_identifier(&mut self) -> SyntaxKind {
+        debug_assert!(
+            self.token == SyntaxKind::Tokens(TokenSyntaxKind::Unknown),
+            "'re_scan_invalid_identifier' should only be called when the current token is 'SyntaxKind::Unknown'."
+        );
+        self.pos = self.start_pos;
+        self.token_pos = self.start_pos;
+        self.token_flags = TokenFlags::None as u32;
+        let ch = self.code_point_at(self.pos);
+        if let Some(identifier_kind) = self.scan_identifier(ch, ScriptTarget::ESNext) {
+            return self.set_token(identifier_kind);
+        }
+        self.pos += char_size(ch);
+        self.token // Still `SyntaxKind::Unknown`
+    }
+
+    fn scan_jsx_token(&mut self) -> JsxTokenSyntaxKind {
+        self.scan_jsx_token_worker(true)
+    }
+
+    fn scan_js_doc_token(&mut self) -> JSDocSyntaxKind {
+        self.start_pos = self.pos;
+        self.token_pos = self.pos;
+        self.token_flags = TokenFlags::None as u32;
+        if self.pos >= self.end {
+            self.token = JSDocSyntaxKind::EndOfFileToken.into();
+            return JSDocSyntaxKind::EndOfFileToken;
+        }
+
+        let ch = self.code_point_at(self.pos);
+        self.pos += char_size(ch);
+        let token = match ch {
+            CharacterCodes::TAB
+            | CharacterCodes::VERTICAL_TAB
+            | CharacterCodes::FORM_FEED
+            | CharacterCodes::SPACE => {
+                while self.pos < self.end && is_white_space_single_line(self.char_code_at(self.pos))
+                {
+                    self.pos += 1;
+                }
+                Some(JSDocSyntaxKind::WhitespaceTrivia)
+            }
+            CharacterCodes::AT => Some(JSDocSyntaxKind::AtToken),
+            CharacterCodes::CARRIAGE_RETURN | CharacterCodes::LINE_FEED => {
+                if ch == CharacterCodes::CARRIAGE_RETURN
+                    && self.char_code_at(self.pos) == CharacterCodes::LINE_FEED
+                {
+                    self.pos += 1;
+                }
+                self.token_flags |= TokenFlags::PrecedingLineBreak as u32;
+                Some(JSDocSyntaxKind::NewLineTrivia)
+            }
+            CharacterCodes::ASTERISK => Some(JSDocSyntaxKind::AsteriskToken),
+            CharacterCodes::OPEN_BRACE => Some(JSDocSyntaxKind::OpenBraceToken),
+            CharacterCodes::CLOSE_BRACE => Some(JSDocSyntaxKind::CloseBraceToken),
+            CharacterCodes::OPEN_BRACKET => Some(JSDocSyntaxKind::OpenBracketToken),
+            CharacterCodes::CLOSE_BRACKET => Some(JSDocSyntaxKind::CloseBracketToken),
+            CharacterCodes::LESS_THAN => Some(JSDocSyntaxKind::LessThanToken),
+            CharacterCodes::GREATER_THAN => Some(JSDocSyntaxKind::GreaterThanToken),
+            CharacterCodes::EQUALS => Some(JSDocSyntaxKind::EqualsToken),
+            CharacterCodes::COMMA => Some(JSDocSyntaxKind::CommaToken),
+            CharacterCodes::DOT => Some(JSDocSyntaxKind::DotToken),
+            CharacterCodes::BACKTICK => Some(JSDocSyntaxKind::BacktickToken),
+            CharacterCodes::HASH => Some(JSDocSyntaxKind::HashToken),
+            CharacterCodes::BACKSLASH => {
+                self.pos -= 1;
+                if let Some(extended_cooked_char) = self.peek_extended_unicode_escape() {
+                    if is_identifier_start(extended_cooked_char, self.language_version) {
+                        self.pos += 3;
+                        self.token_flags |= TokenFlags::ExtendedUnicodeEscape as u32;
+                        self.token_value =
+                            self.scan_extended_unicode_escape() + &self.scan_identifier_parts();
+                        return self.get_js_doc_identifier_token();
+                    }
+                }
+
+                if let Some(cooked_char) = self.peek_unicode_escape() {
+                    if is_identifier_start(cooked_char, self.language_version) {
+                        self.pos += 6;
+                        self.token_flags |= TokenFlags::UnicodeEscape as u32;
+                        self.token_value =
+                            utf16_encode_as_string(cooked_char) + &self.scan_identifier_parts();
+                        return self.get_js_doc_identifier_token();
+                    }
+                }
+                self.pos += 1;
+                Some(JSDocSyntaxKind::Unknown)
+            }
+            _ => None,
+        };
+        if let Some(token) = token {
+            self.token = token.into();
+            return token;
+        }
+
+        if is_identifier_start(ch, self.language_version) {
+            let mut char = ch;
+            while self.pos < self.end {
+                char = self.code_point_at(self.pos);
+                if !(is_identifier_part(char, self.language_version, None)
+                    || self.char_code_at(self.pos) == CharacterCodes::MINUS)
+                {
+                    break;
+                }
+                self.pos += char_size(char);
+            }
+            self.token_value = self.substring(self.token_pos, self.pos);
+            if char == CharacterCodes::BACKSLASH {
+                let parts = self.scan_identifier_parts();
+                self.token_value.push_str(&parts);
+            }
+            self.get_js_doc_identifier_token()
+        } else {
+            self.token = JSDocSyntaxKind::Unknown.into();
+            JSDocSyntaxKind::Unknown
+        }
+    }
+
+    fn scan(&mut self) -> SyntaxKind {
+        self.start_pos = self.pos;
+        self.token_flags = TokenFlags::None as u32;
+        let mut asterisk_seen = false;
+        loop {
+            self.token_pos = self.pos;
+            if self.pos >= self.end {
+                return self.set_token(SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken));
+            }
+            let ch = self.code_point_at(self.pos);
+
+            // Special handling for shebang
+            if ch == CharacterCodes::HASH
+                && self.pos == 0
+                && is_shebang_trivia(&self.text, self.pos)
+            {
+                self.pos = scan_shebang_trivia(&self.text, self.pos);
+                if self.skip_trivia {
+                    continue;
+                } else {
+                    return self.set_token(SyntaxKind::Trivias(TriviaSyntaxKind::ShebangTrivia));
+                }
+            }
+
+            match ch {
+                CharacterCodes::LINE_FEED | CharacterCodes::CARRIAGE_RETURN => {
+                    self.token_flags |= TokenFlags::PrecedingLineBreak as u32;
+                    if self.skip_trivia {
+                        self.pos += 1;
+                        continue;
+                    } else {
+                        if ch == CharacterCodes::CARRIAGE_RETURN
+                            && self.pos + 1 < self.end
+                            && self.char_code_at(self.pos + 1) == CharacterCodes::LINE_FEED
+                        {
+                            // consume both CR and LF
+                            self.pos += 2;
+                        } else {
+                            self.pos += 1;
+                        }
+                        return self
+                            .set_token(SyntaxKind::Trivias(TriviaSyntaxKind::NewLineTrivia));
+                    }
+                }
+                CharacterCodes::TAB
+                | CharacterCodes::VERTICAL_TAB
+                | CharacterCodes::FORM_FEED
+                | CharacterCodes::SPACE
+                | CharacterCodes::NON_BREAKING_SPACE
+                | CharacterCodes::OGHAM
+                | CharacterCodes::EN_QUAD
+                | CharacterCodes::EM_QUAD
+                | CharacterCodes::EN_SPACE
+                | CharacterCodes::EM_SPACE
+                | CharacterCodes::THREE_PER_EM_SPACE
+                | CharacterCodes::FOUR_PER_EM_SPACE
+                | CharacterCodes::SIX_PER_EM_SPACE
+                | CharacterCodes::FIGURE_SPACE
+                | CharacterCodes::PUNCTUATION_SPACE
+                | CharacterCodes::THIN_SPACE
+                | CharacterCodes::HAIR_SPACE
+                | CharacterCodes::ZERO_WIDTH_SPACE
+                | CharacterCodes::NARROW_NO_BREAK_SPACE
+                | CharacterCodes::MATHEMATICAL_SPACE
+                | CharacterCodes::IDEOGRAPHIC_SPACE
+                | CharacterCodes::BYTE_ORDER_MARK => {
+                    if self.skip_trivia {
+                        self.pos += 1;
+                        continue;
+                    } else {
+                        while self.pos < self.end
+                            && is_white_space_single_line(self.char_code_at(self.pos))
+                        {
+                            self.pos += 1;
+                        }
+                        return self
+                            .set_token(SyntaxKind::Trivias(TriviaSyntaxKind::WhitespaceTrivia));
+                    }
+                }
+                CharacterCodes::EXCLAMATION => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(
+                                PunctuationSyntaxKind::ExclamationEqualsEqualsToken,
+                                3,
+                            );
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::ExclamationEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::ExclamationToken, 1);
+                }
+                CharacterCodes::DOUBLE_QUOTE | CharacterCodes::SINGLE_QUOTE => {
+                    self.token_value = self.scan_string(false);
+                    return self.set_token(SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral));
+                }
+                CharacterCodes::BACKTICK => {
+                    let token = self.scan_template_and_set_token_value(false);
+                    return self.set_token(token);
+                }
+                CharacterCodes::PERCENT => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::PercentEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::PercentToken, 1);
+                }
+                CharacterCodes::AMPERSAND => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::AMPERSAND {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(
+                                PunctuationSyntaxKind::AmpersandAmpersandEqualsToken,
+                                3,
+                            );
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::AmpersandAmpersandToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::AmpersandEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::AmpersandToken, 1);
+                }
+                CharacterCodes::OPEN_PAREN => {
+                    return self.punctuation(PunctuationSyntaxKind::OpenParenToken, 1);
+                }
+                CharacterCodes::CLOSE_PAREN => {
+                    return self.punctuation(PunctuationSyntaxKind::CloseParenToken, 1);
+                }
+                CharacterCodes::ASTERISK => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::AsteriskEqualsToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::ASTERISK {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(
+                                PunctuationSyntaxKind::AsteriskAsteriskEqualsToken,
+                                3,
+                            );
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::AsteriskAsteriskToken, 2);
+                    }
+                    self.pos += 1;
+                    if self.in_jsdoc_type > 0
+                        && !asterisk_seen
+                        && self.token_flags & TokenFlags::PrecedingLineBreak as u32 != 0
+                    {
+                        // decoration at the start of a JSDoc comment line
+                        asterisk_seen = true;
+                        continue;
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::AsteriskToken, 0);
+                }
+                CharacterCodes::PLUS => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::PLUS {
+                        return self.punctuation(PunctuationSyntaxKind::PlusPlusToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::PlusEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::PlusToken, 1);
+                }
+                CharacterCodes::COMMA => {
+                    return self.punctuation(PunctuationSyntaxKind::CommaToken, 1);
+                }
+                CharacterCodes::MINUS => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::MINUS {
+                        return self.punctuation(PunctuationSyntaxKind::MinusMinusToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::MinusEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::MinusToken, 1);
+                }
+                CharacterCodes::DOT => {
+                    if is_digit(self.char_code_at(self.pos + 1)) {
+                        self.token_value = self.scan_number().1;
+                        return self
+                            .set_token(SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral));
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::DOT
+                        && self.char_code_at(self.pos + 2) == CharacterCodes::DOT
+                    {
+                        return self.punctuation(PunctuationSyntaxKind::DotDotDotToken, 3);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::DotToken, 1);
+                }
+                CharacterCodes::SLASH => {
+                    // Single-line comment
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::SLASH {
+                        self.pos += 2;
+
+                        while self.pos < self.end {
+                            if is_line_break(self.char_code_at(self.pos)) {
+                                break;
+                            }
+                            self.pos += 1;
+                        }
+
+                        self.append_if_comment_directive(self.token_pos, false);
+
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::SingleLineCommentTrivia,
+                            ));
+                        }
+                    }
+                    // Multi-line comment
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::ASTERISK {
+                        self.pos += 2;
+                        if self.char_code_at(self.pos) == CharacterCodes::ASTERISK
+                            && self.char_code_at(self.pos + 1) != CharacterCodes::SLASH
+                        {
+                            self.token_flags |= TokenFlags::PrecedingJSDocComment as u32;
+                        }
+
+                        let mut comment_closed = false;
+                        let mut last_line_start = self.token_pos;
+                        while self.pos < self.end {
+                            let ch = self.char_code_at(self.pos);
+
+                            if ch == CharacterCodes::ASTERISK
+                                && self.char_code_at(self.pos + 1) == CharacterCodes::SLASH
+                            {
+                                self.pos += 2;
+                                comment_closed = true;
+                                break;
+                            }
+
+                            self.pos += 1;
+
+                            if is_line_break(ch) {
+                                last_line_start = self.pos;
+                                self.token_flags |= TokenFlags::PrecedingLineBreak as u32;
+                            }
+                        }
+
+                        self.append_if_comment_directive(last_line_start, true);
+
+                        if !comment_closed {
+                            self.error_at_pos(&Diagnostics::Asterisk_Slash_expected);
+                        }
+
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            if !comment_closed {
+                                self.token_flags |= TokenFlags::Unterminated as u32;
+                            }
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::MultiLineCommentTrivia,
+                            ));
+                        }
+                    }
+
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::SlashEqualsToken, 2);
+                    }
+
+                    return self.punctuation(PunctuationSyntaxKind::SlashToken, 1);
+                }
+                CharacterCodes::_0
+                | CharacterCodes::_1
+                | CharacterCodes::_2
+                | CharacterCodes::_3
+                | CharacterCodes::_4
+                | CharacterCodes::_5
+                | CharacterCodes::_6
+                | CharacterCodes::_7
+                | CharacterCodes::_8
+                | CharacterCodes::_9 => {
+                    if ch == CharacterCodes::_0 {
+                        let next = self.char_code_at(self.pos + 1);
+                        if self.pos + 2 < self.end
+                            && (next == CharacterCodes::X || next == CharacterCodes::XX)
+                        {
+                            self.pos += 2;
+                            self.token_value = self.scan_minimum_number_of_hex_digits(1, true);
+                            if self.token_value.is_empty() {
+                                self.error_at_pos(&Diagnostics::Hexadecimal_digit_expected);
+                                self.token_value = "0".to_string();
+                            }
+                            self.token_value = format!("0x{}", self.token_value);
+                            self.token_flags |= TokenFlags::HexSpecifier as u32;
+                            let token = self.check_big_int_suffix();
+                            return self.set_token(token);
+                        } else if self.pos + 2 < self.end
+                            && (next == CharacterCodes::B || next == CharacterCodes::BB)
+                        {
+                            self.pos += 2;
+                            self.token_value = self.scan_binary_or_octal_digits(2);
+                            if self.token_value.is_empty() {
+                                self.error_at_pos(&Diagnostics::Binary_digit_expected);
+                                self.token_value = "0".to_string();
+                            }
+                            self.token_value = format!("0b{}", self.token_value);
+                            self.token_flags |= TokenFlags::BinarySpecifier as u32;
+                            let token = self.check_big_int_suffix();
+                            return self.set_token(token);
+                        } else if self.pos + 2 < self.end
+                            && (next == CharacterCodes::O || next == CharacterCodes::OO)
+                        {
+                            self.pos += 2;
+                            self.token_value = self.scan_binary_or_octal_digits(8);
+                            if self.token_value.is_empty() {
+                                self.error_at_pos(&Diagnostics::Octal_digit_expected);
+                                self.token_value = "0".to_string();
+                            }
+                            self.token_value = format!("0o{}", self.token_value);
+                            self.token_flags |= TokenFlags::OctalSpecifier as u32;
+                            let token = self.check_big_int_suffix();
+                            return self.set_token(token);
+                        }
+                        // Try to parse as an octal
+                        if self.pos + 1 < self.end && is_octal_digit(next) {
+                            self.token_value = number_to_string(self.scan_octal_digits());
+                            self.token_flags |= TokenFlags::Octal as u32;
+                            return self.set_token(SyntaxKind::Literals(
+                                LiteralSyntaxKind::NumericLiteral,
+                            ));
+                        }
+                    }
+                    // This fall-through is a deviation from the EcmaScript grammar. The grammar says that a leading zero
+                    // can only be followed by an octal digit, a dot, or the end of the number literal. However, we are being
+                    // permissive and allowing decimal digits of the form 08* and 09* (which many browsers also do).
+                    let (token, value) = self.scan_number();
+                    self.token_value = value;
+                    return self.set_token(token);
+                }
+                CharacterCodes::COLON => {
+                    return self.punctuation(PunctuationSyntaxKind::ColonToken, 1);
+                }
+                CharacterCodes::SEMICOLON => {
+                    return self.punctuation(PunctuationSyntaxKind::SemicolonToken, 1);
+                }
+                CharacterCodes::LESS_THAN => {
+                    if is_conflict_marker_trivia(&self.text, self.pos) {
+                        self.error(
+                            &Diagnostics::Merge_conflict_marker_encountered,
+                            self.pos,
+                            MERGE_CONFLICT_MARKER_LENGTH,
+                        );
+                        self.pos = scan_conflict_marker_trivia(&self.text, self.pos);
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::ConflictMarkerTrivia,
+                            ));
+                        }
+                    }
+
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::LESS_THAN {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(
+                                PunctuationSyntaxKind::LessThanLessThanEqualsToken,
+                                3,
+                            );
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::LessThanLessThanToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::LessThanEqualsToken, 2);
+                    }
+                    if self.language_variant == LanguageVariant::JSX
+                        && self.char_code_at(self.pos + 1) == CharacterCodes::SLASH
+                        && self.char_code_at(self.pos + 2) != CharacterCodes::ASTERISK
+                    {
+                        return self.punctuation(PunctuationSyntaxKind::LessThanSlashToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::LessThanToken, 1);
+                }
+                CharacterCodes::EQUALS => {
+                    if is_conflict_marker_trivia(&self.text, self.pos) {
+                        self.error(
+                            &Diagnostics::Merge_conflict_marker_encountered,
+                            self.pos,
+                            MERGE_CONFLICT_MARKER_LENGTH,
+                        );
+                        self.pos = scan_conflict_marker_trivia(&self.text, self.pos);
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::ConflictMarkerTrivia,
+                            ));
+                        }
+                    }
+
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self
+                                .punctuation(PunctuationSyntaxKind::EqualsEqualsEqualsToken, 3);
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::EqualsEqualsToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::GREATER_THAN {
+                        return self.punctuation(PunctuationSyntaxKind::EqualsGreaterThanToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::EqualsToken, 1);
+                }
+                CharacterCodes::GREATER_THAN => {
+                    if is_conflict_marker_trivia(&self.text, self.pos) {
+                        self.error(
+                            &Diagnostics::Merge_conflict_marker_encountered,
+                            self.pos,
+                            MERGE_CONFLICT_MARKER_LENGTH,
+                        );
+                        self.pos = scan_conflict_marker_trivia(&self.text, self.pos);
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::ConflictMarkerTrivia,
+                            ));
+                        }
+                    }
+
+                    return self.punctuation(PunctuationSyntaxKind::GreaterThanToken, 1);
+                }
+                CharacterCodes::QUESTION => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::DOT
+                        && !is_digit(self.char_code_at(self.pos + 2))
+                    {
+                        return self.punctuation(PunctuationSyntaxKind::QuestionDotToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::QUESTION {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(
+                                PunctuationSyntaxKind::QuestionQuestionEqualsToken,
+                                3,
+                            );
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::QuestionQuestionToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::QuestionToken, 1);
+                }
+                CharacterCodes::OPEN_BRACKET => {
+                    return self.punctuation(PunctuationSyntaxKind::OpenBracketToken, 1);
+                }
+                CharacterCodes::CLOSE_BRACKET => {
+                    return self.punctuation(PunctuationSyntaxKind::CloseBracketToken, 1);
+                }
+                CharacterCodes::CARET => {
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::CaretEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::CaretToken, 1);
+                }
+                CharacterCodes::OPEN_BRACE => {
+                    return self.punctuation(PunctuationSyntaxKind::OpenBraceToken, 1);
+                }
+                CharacterCodes::BAR => {
+                    if is_conflict_marker_trivia(&self.text, self.pos) {
+                        self.error(
+                            &Diagnostics::Merge_conflict_marker_encountered,
+                            self.pos,
+                            MERGE_CONFLICT_MARKER_LENGTH,
+                        );
+                        self.pos = scan_conflict_marker_trivia(&self.text, self.pos);
+                        if self.skip_trivia {
+                            continue;
+                        } else {
+                            return self.set_token(SyntaxKind::Trivias(
+                                TriviaSyntaxKind::ConflictMarkerTrivia,
+                            ));
+                        }
+                    }
+
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::BAR {
+                        if self.char_code_at(self.pos + 2) == CharacterCodes::EQUALS {
+                            return self.punctuation(PunctuationSyntaxKind::BarBarEqualsToken, 3);
+                        }
+                        return self.punctuation(PunctuationSyntaxKind::BarBarToken, 2);
+                    }
+                    if self.char_code_at(self.pos + 1) == CharacterCodes::EQUALS {
+                        return self.punctuation(PunctuationSyntaxKind::BarEqualsToken, 2);
+                    }
+                    return self.punctuation(PunctuationSyntaxKind::BarToken, 1);
+                }
+                CharacterCodes::CLOSE_BRACE => {
+                    return self.punctuation(PunctuationSyntaxKind::CloseBraceToken, 1);
+                }
+                CharacterCodes::TILDE => {
+                    return self.punctuation(PunctuationSyntaxKind::TildeToken, 1);
+                }
+                CharacterCodes::AT => {
+                    return self.punctuation(PunctuationSyntaxKind::AtToken, 1);
+                }
+                CharacterCodes::BACKSLASH => {
+                    if let Some(extended_cooked_char) = self.peek_extended_unicode_escape() {
+                        if is_identifier_start(extended_cooked_char, self.language_version) {
+                            self.pos += 3;
+                            self.token_flags |= TokenFlags::ExtendedUnicodeEscape as u32;
+                            self.token_value =
+                                self.scan_extended_unicode_escape() + &self.scan_identifier_parts();
+                            return self.get_identifier_token();
+                        }
+                    }
+
+                    if let Some(cooked_char) = self.peek_unicode_escape() {
+                        if is_identifier_start(cooked_char, self.language_version) {
+                            self.pos += 6;
+                            self.token_flags |= TokenFlags::UnicodeEscape as u32;
+                            self.token_value =
+                                utf16_encode_as_string(cooked_char) + &self.scan_identifier_parts();
+                            return self.get_identifier_token();
+                        }
+                    }
+
+                    self.error_at_pos(&Diagnostics::Invalid_character);
+                    self.pos += 1;
+                    return self.set_token(SyntaxKind::Tokens(TokenSyntaxKind::Unknown));
+                }
+                CharacterCodes::HASH => {
+                    if self.pos != 0
+                        && self.char_code_at(self.pos + 1) == CharacterCodes::EXCLAMATION
+                    {
+                        self.error_at_pos(&Diagnostics::can_only_be_used_at_the_start_of_a_file);
+                        self.pos += 1;
+                        return self.set_token(SyntaxKind::Tokens(TokenSyntaxKind::Unknown));
+                    }
+                    self.pos += 1;
+                    let ch = self.code_point_at(self.pos);
+                    self.scan_identifier(ch, self.language_version);
+                    return self.set_token(SyntaxKind::PrivateIdentifier);
+                }
+                _ => {
+                    if let Some(identifier_kind) = self.scan_identifier(ch, self.language_version) {
+                        return self.set_token(identifier_kind);
+                    } else if is_white_space_single_line(ch) {
+                        self.pos += char_size(ch);
+                        continue;
+                    } else if is_line_break(ch) {
+                        self.token_flags |= TokenFlags::PrecedingLineBreak as u32;
+                        self.pos += char_size(ch);
+                        continue;
+                    }
+                    self.error_at_pos(&Diagnostics::Invalid_character);
+                    self.pos += char_size(ch);
+                    return self.set_token(SyntaxKind::Tokens(TokenSyntaxKind::Unknown));
+                }
+            }
+        }
+    }
+
+    fn get_text(&self) -> String {
+        String::from_utf16_lossy(&self.text)
+    }
+
+    fn clear_comment_directives(&mut self) {
+        self.comment_directives = None;
+    }
+
+    fn set_text(&mut self, text: Option<String>, start: Option<usize>, length: Option<usize>) {
+        self.text = text.unwrap_or_default().encode_utf16().collect();
+        self.end = match length {
+            Some(length) => start.unwrap_or(0) + length,
+            None => self.text.len(),
+        };
+        self.set_text_pos(start);
+    }
+
+    fn set_on_error(&mut self, on_error: Option<Box<ErrorCallback>>) {
+        self.on_error = on_error;
+    }
+
+    fn set_script_target(&mut self, script_target: ScriptTarget) {
+        self.language_version = script_target;
+    }
+
+    fn set_language_variant(&mut self, variant: LanguageVariant) {
+        self.language_variant = variant;
+    }
+
+    fn set_text_pos(&mut self, text_pos: Option<usize>) {
+        let text_pos = text_pos.unwrap_or(0);
+        self.pos = text_pos;
+        self.start_pos = text_pos;
+        self.token_pos = text_pos;
+        self.token = SyntaxKind::Tokens(TokenSyntaxKind::Unknown);
+        self.token_value = String::new();
+        self.token_flags = TokenFlags::None as u32;
+    }
+
+    fn set_in_jsdoc_type(&mut self, in_type: bool) {
+        self.in_jsdoc_type += if in_type { 1 } else { -1 };
+    }
+
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Self) -> T) -> T {
+        self.speculation_helper(callback, |_| true)
+    }
+
+    fn scan_range<T>(
+        &mut self,
+        start: usize,
+        length: usize,
+        callback: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let saved = self.enter_range(start, length);
+        let result = callback(self);
+        self.exit_range(saved);
+        result
+    }
+
+    fn try_scan<T>(&mut self, callback: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        self.speculation_helper(callback, |result| result.is_none())
+    }
+}
+
+impl TextScanner {
+    fn get_js_doc_identifier_token(&mut self) -> JSDocSyntaxKind {
+        match self.get_identifier_token() {
+            SyntaxKind::Keywords(keyword) => JSDocSyntaxKind::Keywords(keyword),
+            _ => JSDocSyntaxKind::Identifier,
+        }
+    }
+}
+
+// Line starts as `computeLineStarts` returns them: a line starts after every line break, a CRLF
+// counting as one, at an offset in UTF-16 code units like the positions of the scanner.
+pub fn compute_line_starts(text: &str) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut pos = 0;
+    let mut line_start = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(arg) = chars.next() {
+        let ch = arg as u32;
+        pos += arg.len_utf16();
+        if ch == CharacterCodes::CARRIAGE_RETURN {
+            if chars.next_if_eq(&'\n').is_some() {
+                pos += 1;
+            }
+            result.push(line_start);
+            line_start = pos;
+        } else if ch == CharacterCodes::LINE_FEED
             || ch > CharacterCodes::MAX_ASCII_CHARACTER && is_line_break(ch)
         {
-            result
-                .set_element(
-                    res_index,
-                    cx.env.create_uint32((index + 1) as u32).expect(u32_err),
-                )
-                .expect(u32_err);
-            res_index += 1;
-            continue;
+            result.push(line_start);
+            line_start = pos;
+        }
+    }
+    result.push(line_start);
+    result
+}
+
+// The line of the position, given the starts of the lines like `compute_line_starts` returns them.
+pub fn compute_line_of_position(line_starts: &[usize], position: usize) -> usize {
+    match line_starts.binary_search(&position) {
+        Ok(line) => line,
+        // The position is in the line before the first line that starts after it.
+        Err(next_line) => next_line - 1,
+    }
+}
+
+/// A token as `tokenize` reports it. `pos` is the start of the trivia before the token, like
+/// `get_start_pos`, and `start` the start of the token itself, like `get_token_pos`.
+pub struct Token {
+    pub kind: SyntaxKind,
+    pub pos: usize,
+    pub start: usize,
+    pub end: usize,
+    pub flags: u32,
+    /// The value of identifiers, keywords and literals
+    pub value: Option<String>,
+}
+
+pub struct Tokenization {
+    pub tokens: Vec<Token>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The name of the `ts.SyntaxKind` member of a kind, like `Debug.formatSyntaxKind`. The kinds of
+/// each group are named after the members of `ts.SyntaxKind`.
+pub fn format_syntax_kind(kind: SyntaxKind) -> String {
+    let debug = format!("{:?}", kind);
+    debug
+        .trim_end_matches(')')
+        .rsplit('(')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+// The flags a token can have, without the internal groups of them.
+const TOKEN_FLAG_NAMES: &[(TokenFlags, &str)] = &[
+    (TokenFlags::PrecedingLineBreak, "PrecedingLineBreak"),
+    (TokenFlags::PrecedingJSDocComment, "PrecedingJSDocComment"),
+    (TokenFlags::Unterminated, "Unterminated"),
+    (TokenFlags::ExtendedUnicodeEscape, "ExtendedUnicodeEscape"),
+    (TokenFlags::Scientific, "Scientific"),
+    (TokenFlags::Octal, "Octal"),
+    (TokenFlags::HexSpecifier, "HexSpecifier"),
+    (TokenFlags::BinarySpecifier, "BinarySpecifier"),
+    (TokenFlags::OctalSpecifier, "OctalSpecifier"),
+    (TokenFlags::ContainsSeparator, "ContainsSeparator"),
+    (TokenFlags::UnicodeEscape, "UnicodeEscape"),
+    (TokenFlags::ContainsInvalidEscape, "ContainsInvalidEscape"),
+];
+
+/// The names of the `ts.TokenFlags` set in the flags of a token, like `Debug.formatTokenFlags`.
+pub fn format_token_flags(flags: u32) -> Vec<&'static str> {
+    TOKEN_FLAG_NAMES
+        .iter()
+        .filter(|&&(flag, _)| flags & flag as u32 != 0)
+        .map(|&(_, name)| name)
+        .collect()
+}
+
+/// Scans the whole text with `scan`, up to and including the `EndOfFileToken`, the way a
+/// classifier or a syntax highlighter does without the rescans the parser would drive. Trivia
+/// tokens are only reported when `skip_trivia` is false. With a cancellation token, scanning
+/// stops at the first token after the token is canceled.
+pub fn tokenize(
+    text: String,
+    language_version: ScriptTarget,
+    language_variant: LanguageVariant,
+    skip_trivia: bool,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Tokenization, OperationCanceled> {
+    let diagnostics: Rc<RefCell<Vec<Diagnostic>>> = Rc::default();
+    let scan_error = {
+        let diagnostics = diagnostics.clone();
+        move |message: &IDiagnosticMessage<'static>, pos: usize, length: usize| {
+            let mut diagnostic = create_compiler_diagnostic(message, &[]);
+            diagnostic.start = Some(pos);
+            diagnostic.length = Some(length);
+            diagnostics.borrow_mut().push(diagnostic);
+        }
+    };
+    let mut scanner = create_scanner(
+        language_version,
+        skip_trivia,
+        Some(language_variant),
+        Some(text),
+        Some(Box::new(scan_error)),
+        None,
+        None,
+    );
+    let mut tokens = Vec::new();
+    loop {
+        if let Some(cancellation_token) = cancellation_token {
+            cancellation_token.throw_if_cancellation_requested()?;
+        }
+        let kind = scanner.scan();
+        let value = match kind {
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+            | SyntaxKind::PrivateIdentifier
+            | SyntaxKind::Keywords(_)
+            | SyntaxKind::Literals(_)
+            | SyntaxKind::PseudoLiterals(_) => Some(scanner.get_token_value()),
+            _ => None,
+        };
+        tokens.push(Token {
+            kind,
+            pos: scanner.get_start_pos(),
+            start: scanner.get_token_pos(),
+            end: scanner.get_text_pos(),
+            flags: scanner.get_token_flags(),
+            value,
+        });
+        if let SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken) = kind {
+            break;
         }
     }
+    drop(scanner);
+    let diagnostics = Rc::try_unwrap(diagnostics)
+        .map(RefCell::into_inner)
+        .unwrap_or_default();
+    Ok(Tokenization {
+        tokens,
+        diagnostics,
+    })
+}
+
+/// The version of the layout of the `tokens` of `tokenize`. Changing the layout means bumping it,
+/// so that JS can tell which layout an addon uses.
+pub const TOKEN_ENCODING_VERSION: u32 = 1;
+
+/// The number of `u32`s each token takes in the `tokens` of `tokenize`: its kind (the value of
+/// `ts.SyntaxKind`), `pos`, `start`, `end`, flags, and the index of its value in `values`, or
+/// `NO_TOKEN_VALUE` for a token without one.
+pub const TOKEN_ENCODING_STRIDE: usize = 6;
 
-    Ok(result)
+pub const NO_TOKEN_VALUE: u32 = u32::MAX;
+
+impl Tokenization {
+    /// The tokens in the layout of `TOKEN_ENCODING_STRIDE`, along with the values they index.
+    pub fn encode(&self) -> (Vec<u32>, Vec<&str>) {
+        let mut encoded = Vec::with_capacity(self.tokens.len() * TOKEN_ENCODING_STRIDE);
+        let mut values = Vec::new();
+        for token in &self.tokens {
+            let value_index = match &token.value {
+                Some(value) => {
+                    values.push(value.as_str());
+                    (values.len() - 1) as u32
+                }
+                None => NO_TOKEN_VALUE,
+            };
+            encoded.extend_from_slice(&[
+                token.kind.js_value(),
+                token.pos as u32,
+                token.start as u32,
+                token.end as u32,
+                token.flags,
+                value_index,
+            ]);
+        }
+        (encoded, values)
+    }
 }
@@ -0,0 +1,59 @@
+use std::fs;
+use std::io;
+
+use super::path::normalize_slashes;
+use super::types::ParseConfigHost;
+
+/// The local file system, like `sys` in the JS compiler running on node.
+pub struct LocalFileSystem;
+
+impl ParseConfigHost for LocalFileSystem {
+    fn use_case_sensitive_file_names(&self) -> bool {
+        // Windows and macOS file systems are case insensitive by default.
+        !cfg!(any(windows, target_os = "macos"))
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        fs::metadata(path).is_ok_and(|metadata| metadata.is_file())
+    }
+
+    fn directory_exists(&self, path: &str) -> bool {
+        fs::metadata(path).is_ok_and(|metadata| metadata.is_dir())
+    }
+
+    fn read_file(&self, path: &str) -> io::Result<String> {
+        let buffer = fs::read(path)?;
+        if buffer.len() >= 2 && buffer[0] == 0xFE && buffer[1] == 0xFF {
+            // Big endian UTF-16 byte order mark detected
+            let units: Vec<u16> = buffer[2..]
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            return Ok(String::from_utf16_lossy(&units));
+        }
+        if buffer.len() >= 2 && buffer[0] == 0xFF && buffer[1] == 0xFE {
+            // Little endian UTF-16 byte order mark detected
+            let units: Vec<u16> = buffer[2..]
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect();
+            return Ok(String::from_utf16_lossy(&units));
+        }
+        if buffer.len() >= 3 && buffer[0] == 0xEF && buffer[1] == 0xBB && buffer[2] == 0xBF {
+            // UTF-8 byte order mark detected
+            return Ok(String::from_utf8_lossy(&buffer[3..]).into_owned());
+        }
+        // Default is UTF-8 with no byte order mark
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    fn realpath(&self, path: &str) -> String {
+        match fs::canonicalize(path) {
+            // Drop the verbatim prefix Windows puts on canonical paths (`\\?\C:\...`).
+            Ok(real_path) => normalize_slashes(&real_path.to_string_lossy())
+                .trim_start_matches("//?/")
+                .to_string(),
+            Err(_) => path.to_string(),
+        }
+    }
+}
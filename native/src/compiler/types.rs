@@ -1,5 +1,12 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
+use super::atom::{Atom, AtomTable};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TriviaSyntaxKind {
     SingleLineCommentTrivia,
     MultiLineCommentTrivia,
@@ -12,6 +19,7 @@ pub enum TriviaSyntaxKind {
     ConflictMarkerTrivia,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum LiteralSyntaxKind {
     NumericLiteral,
     BigIntLiteral,
@@ -22,12 +30,14 @@ pub enum LiteralSyntaxKind {
     NoSubstitutionTemplateLiteral,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PseudoLiteralSyntaxKind {
     TemplateHead,
     TemplateMiddle,
     TemplateTail,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PunctuationSyntaxKind {
     OpenBraceToken,
     CloseBraceToken,
@@ -95,6 +105,7 @@ pub enum PunctuationSyntaxKind {
     CaretEqualsToken,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ModifierSyntaxKind {
     AbstractKeyword,
     AsyncKeyword,
@@ -110,6 +121,7 @@ pub enum ModifierSyntaxKind {
     StaticKeyword,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum KeywordTypeSyntaxKind {
     AnyKeyword,
     BigIntKeyword,
@@ -125,6 +137,7 @@ pub enum KeywordTypeSyntaxKind {
     UnknownKeyword,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum KeywordSyntaxKind {
     // Reserved words
     BreakKeyword,
@@ -200,6 +213,7 @@ pub enum KeywordSyntaxKind {
     OfKeyword, // LastKeyword and LastToken and LastContextualKeyword
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TypeNodeSyntaxKind {
     TypePredicate,
     TypeReference,
@@ -239,6 +253,7 @@ pub enum TypeNodeSyntaxKind {
     JSDocSignature,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TokenSyntaxKind {
     Unknown,
     EndOfFileToken,
@@ -250,6 +265,7 @@ pub enum TokenSyntaxKind {
     Keywords(KeywordSyntaxKind),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum JsxTokenSyntaxKind {
     LessThanSlashToken,
     EndOfFileToken,
@@ -260,6 +276,7 @@ pub enum JsxTokenSyntaxKind {
     LessThanToken,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum JSDocSyntaxKind {
     EndOfFileToken,
     WhitespaceTrivia,
@@ -282,6 +299,7 @@ pub enum JSDocSyntaxKind {
     Keywords(KeywordSyntaxKind),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SyntaxKind {
     Trivias(TriviaSyntaxKind),
 
@@ -526,6 +544,779 @@ pub enum SyntaxKind {
     // /* @internal */ LastContextualKeyword = SyntaxKind::OfKeyword,
 }
 
+// The nested kinds under the flat names of the JS compiler, so that `SyntaxKind::OpenBraceToken`
+// can be compared and matched on like any other kind.
+#[allow(non_upper_case_globals)]
+impl SyntaxKind {
+    pub const SingleLineCommentTrivia: SyntaxKind =
+        SyntaxKind::Trivias(TriviaSyntaxKind::SingleLineCommentTrivia);
+    pub const MultiLineCommentTrivia: SyntaxKind =
+        SyntaxKind::Trivias(TriviaSyntaxKind::MultiLineCommentTrivia);
+    pub const NewLineTrivia: SyntaxKind = SyntaxKind::Trivias(TriviaSyntaxKind::NewLineTrivia);
+    pub const WhitespaceTrivia: SyntaxKind =
+        SyntaxKind::Trivias(TriviaSyntaxKind::WhitespaceTrivia);
+    pub const ShebangTrivia: SyntaxKind = SyntaxKind::Trivias(TriviaSyntaxKind::ShebangTrivia);
+    pub const ConflictMarkerTrivia: SyntaxKind =
+        SyntaxKind::Trivias(TriviaSyntaxKind::ConflictMarkerTrivia);
+
+    pub const NumericLiteral: SyntaxKind = SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral);
+    pub const BigIntLiteral: SyntaxKind = SyntaxKind::Literals(LiteralSyntaxKind::BigIntLiteral);
+    pub const StringLiteral: SyntaxKind = SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral);
+    pub const JsxText: SyntaxKind = SyntaxKind::Literals(LiteralSyntaxKind::JsxText);
+    pub const JsxTextAllWhiteSpaces: SyntaxKind =
+        SyntaxKind::Literals(LiteralSyntaxKind::JsxTextAllWhiteSpaces);
+    pub const RegularExpressionLiteral: SyntaxKind =
+        SyntaxKind::Literals(LiteralSyntaxKind::RegularExpressionLiteral);
+    pub const NoSubstitutionTemplateLiteral: SyntaxKind =
+        SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral);
+    pub const TemplateHead: SyntaxKind =
+        SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateHead);
+    pub const TemplateMiddle: SyntaxKind =
+        SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateMiddle);
+    pub const TemplateTail: SyntaxKind =
+        SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail);
+
+    pub const OpenBraceToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken);
+    pub const CloseBraceToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken);
+    pub const OpenParenToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenParenToken);
+    pub const CloseParenToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseParenToken);
+    pub const OpenBracketToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBracketToken);
+    pub const CloseBracketToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBracketToken);
+    pub const DotToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::DotToken);
+    pub const DotDotDotToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::DotDotDotToken);
+    pub const SemicolonToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::SemicolonToken);
+    pub const CommaToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::CommaToken);
+    pub const QuestionDotToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionDotToken);
+    pub const LessThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanToken);
+    pub const LessThanSlashToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanSlashToken);
+    pub const GreaterThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanToken);
+    pub const LessThanEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanEqualsToken);
+    pub const GreaterThanEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanEqualsToken);
+    pub const EqualsEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsEqualsToken);
+    pub const ExclamationEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::ExclamationEqualsToken);
+    pub const EqualsEqualsEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsEqualsEqualsToken);
+    pub const ExclamationEqualsEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::ExclamationEqualsEqualsToken);
+    pub const EqualsGreaterThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsGreaterThanToken);
+    pub const PlusToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::PlusToken);
+    pub const MinusToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusToken);
+    pub const AsteriskToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskToken);
+    pub const AsteriskAsteriskToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskAsteriskToken);
+    pub const SlashToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashToken);
+    pub const PercentToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::PercentToken);
+    pub const PlusPlusToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::PlusPlusToken);
+    pub const MinusMinusToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusMinusToken);
+    pub const LessThanLessThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanLessThanToken);
+    pub const GreaterThanGreaterThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanGreaterThanToken);
+    pub const GreaterThanGreaterThanGreaterThanToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanToken);
+    pub const AmpersandToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AmpersandToken);
+    pub const BarToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::BarToken);
+    pub const CaretToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::CaretToken);
+    pub const ExclamationToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::ExclamationToken);
+    pub const TildeToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::TildeToken);
+    pub const AmpersandAmpersandToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AmpersandAmpersandToken);
+    pub const BarBarToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::BarBarToken);
+    pub const QuestionToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionToken);
+    pub const ColonToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::ColonToken);
+    pub const AtToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::AtToken);
+    pub const QuestionQuestionToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionQuestionToken);
+    pub const BacktickToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::BacktickToken);
+    pub const HashToken: SyntaxKind = SyntaxKind::Punctuations(PunctuationSyntaxKind::HashToken);
+    pub const EqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsToken);
+    pub const PlusEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::PlusEqualsToken);
+    pub const MinusEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::MinusEqualsToken);
+    pub const AsteriskEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskEqualsToken);
+    pub const AsteriskAsteriskEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskAsteriskEqualsToken);
+    pub const SlashEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::SlashEqualsToken);
+    pub const PercentEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::PercentEqualsToken);
+    pub const LessThanLessThanEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanLessThanEqualsToken);
+    pub const GreaterThanGreaterThanEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanGreaterThanEqualsToken);
+    pub const GreaterThanGreaterThanGreaterThanEqualsToken: SyntaxKind = SyntaxKind::Punctuations(
+        PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken,
+    );
+    pub const AmpersandEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AmpersandEqualsToken);
+    pub const BarEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::BarEqualsToken);
+    pub const BarBarEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::BarBarEqualsToken);
+    pub const AmpersandAmpersandEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::AmpersandAmpersandEqualsToken);
+    pub const QuestionQuestionEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::QuestionQuestionEqualsToken);
+    pub const CaretEqualsToken: SyntaxKind =
+        SyntaxKind::Punctuations(PunctuationSyntaxKind::CaretEqualsToken);
+
+    pub const BreakKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::BreakKeyword);
+    pub const CaseKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::CaseKeyword);
+    pub const CatchKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::CatchKeyword);
+    pub const ClassKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ClassKeyword);
+    pub const ContinueKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::ContinueKeyword);
+    pub const DebuggerKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::DebuggerKeyword);
+    pub const DeleteKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::DeleteKeyword);
+    pub const DoKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::DoKeyword);
+    pub const ElseKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ElseKeyword);
+    pub const EnumKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::EnumKeyword);
+    pub const ExtendsKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ExtendsKeyword);
+    pub const FalseKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::FalseKeyword);
+    pub const FinallyKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::FinallyKeyword);
+    pub const ForKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ForKeyword);
+    pub const FunctionKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::FunctionKeyword);
+    pub const IfKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::IfKeyword);
+    pub const ImportKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ImportKeyword);
+    pub const InKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::InKeyword);
+    pub const InstanceOfKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::InstanceOfKeyword);
+    pub const NewKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::NewKeyword);
+    pub const NullKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::NullKeyword);
+    pub const ReturnKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ReturnKeyword);
+    pub const SuperKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::SuperKeyword);
+    pub const SwitchKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::SwitchKeyword);
+    pub const ThisKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ThisKeyword);
+    pub const ThrowKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ThrowKeyword);
+    pub const TrueKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::TrueKeyword);
+    pub const TryKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::TryKeyword);
+    pub const TypeOfKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::TypeOfKeyword);
+    pub const VarKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::VarKeyword);
+    pub const WhileKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::WhileKeyword);
+    pub const WithKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::WithKeyword);
+    pub const ImplementsKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::ImplementsKeyword);
+    pub const InterfaceKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::InterfaceKeyword);
+    pub const LetKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::LetKeyword);
+    pub const PackageKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::PackageKeyword);
+    pub const YieldKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::YieldKeyword);
+    pub const AsKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::AsKeyword);
+    pub const AssertsKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::AssertsKeyword);
+    pub const AwaitKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::AwaitKeyword);
+    pub const ConstructorKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::ConstructorKeyword);
+    pub const GetKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::GetKeyword);
+    pub const InferKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::InferKeyword);
+    pub const IsKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::IsKeyword);
+    pub const KeyOfKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeyOfKeyword);
+    pub const ModuleKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::ModuleKeyword);
+    pub const NamespaceKeyword: SyntaxKind =
+        SyntaxKind::Keywords(KeywordSyntaxKind::NamespaceKeyword);
+    pub const RequireKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::RequireKeyword);
+    pub const SetKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::SetKeyword);
+    pub const TypeKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::TypeKeyword);
+    pub const UniqueKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::UniqueKeyword);
+    pub const FromKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::FromKeyword);
+    pub const GlobalKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::GlobalKeyword);
+    pub const OfKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::OfKeyword);
+    pub const AbstractKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::AbstractKeyword,
+    ));
+    pub const AsyncKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::AsyncKeyword,
+    ));
+    pub const ConstKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::ConstKeyword,
+    ));
+    pub const DeclareKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::DeclareKeyword,
+    ));
+    pub const DefaultKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::DefaultKeyword,
+    ));
+    pub const ExportKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::ExportKeyword,
+    ));
+    pub const PrivateKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::PrivateKeyword,
+    ));
+    pub const ProtectedKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::ProtectedKeyword,
+    ));
+    pub const PublicKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::PublicKeyword,
+    ));
+    pub const ReadonlyKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::ReadonlyKeyword,
+    ));
+    pub const OverrideKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::OverrideKeyword,
+    ));
+    pub const StaticKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+        ModifierSyntaxKind::StaticKeyword,
+    ));
+    pub const AnyKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::AnyKeyword,
+    ));
+    pub const BigIntKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::BigIntKeyword,
+    ));
+    pub const VoidKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::VoidKeyword,
+    ));
+    pub const BooleanKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::BooleanKeyword,
+    ));
+    pub const IntrinsicKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::IntrinsicKeyword,
+    ));
+    pub const NeverKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::NeverKeyword,
+    ));
+    pub const NumberKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::NumberKeyword,
+    ));
+    pub const ObjectKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::ObjectKeyword,
+    ));
+    pub const StringKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::StringKeyword,
+    ));
+    pub const SymbolKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::SymbolKeyword,
+    ));
+    pub const UndefinedKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::UndefinedKeyword,
+    ));
+    pub const UnknownKeyword: SyntaxKind = SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+        KeywordTypeSyntaxKind::UnknownKeyword,
+    ));
+
+    pub const TypePredicate: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TypePredicate);
+    pub const TypeReference: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TypeReference);
+    pub const FunctionType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::FunctionType);
+    pub const ConstructorType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ConstructorType);
+    pub const TypeQuery: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TypeQuery);
+    pub const TypeLiteral: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TypeLiteral);
+    pub const ArrayType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ArrayType);
+    pub const TupleType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TupleType);
+    pub const OptionalType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::OptionalType);
+    pub const RestType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::RestType);
+    pub const UnionType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::UnionType);
+    pub const IntersectionType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::IntersectionType);
+    pub const ConditionalType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ConditionalType);
+    pub const InferType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::InferType);
+    pub const ParenthesizedType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ParenthesizedType);
+    pub const ThisType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ThisType);
+    pub const TypeOperator: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TypeOperator);
+    pub const IndexedAccessType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::IndexedAccessType);
+    pub const MappedType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::MappedType);
+    pub const LiteralType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::LiteralType);
+    pub const NamedTupleMember: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::NamedTupleMember);
+    pub const TemplateLiteralType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TemplateLiteralType);
+    pub const TemplateLiteralTypeSpan: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::TemplateLiteralTypeSpan);
+    pub const ImportType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ImportType);
+    pub const ExpressionWithTypeArguments: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::ExpressionWithTypeArguments);
+    pub const JSDocTypeExpression: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocTypeExpression);
+    pub const JSDocAllType: SyntaxKind = SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocAllType);
+    pub const JSDocUnknownType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocUnknownType);
+    pub const JSDocNullableType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocNullableType);
+    pub const JSDocNonNullableType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocNonNullableType);
+    pub const JSDocOptionalType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocOptionalType);
+    pub const JSDocFunctionType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocFunctionType);
+    pub const JSDocVariadicType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocVariadicType);
+    pub const JSDocNamepathType: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocNamepathType);
+    pub const JSDocTypeLiteral: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocTypeLiteral);
+    pub const JSDocSignature: SyntaxKind =
+        SyntaxKind::TypeNodes(TypeNodeSyntaxKind::JSDocSignature);
+
+    pub const Unknown: SyntaxKind = SyntaxKind::Tokens(TokenSyntaxKind::Unknown);
+    pub const EndOfFileToken: SyntaxKind = SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken);
+    pub const Identifier: SyntaxKind = SyntaxKind::Tokens(TokenSyntaxKind::Identifier);
+}
+
+// The numeric values of `ts.SyntaxKind`, for handing kinds to JS. The kinds of each group below
+// that are contiguous there are declared in the same order, so their values are offsets.
+impl SyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            SyntaxKind::Trivias(kind) => kind.js_value(),
+            SyntaxKind::Literals(kind) => kind.js_value(),
+            SyntaxKind::PseudoLiterals(kind) => kind.js_value(),
+            SyntaxKind::Punctuations(kind) => kind.js_value(),
+            SyntaxKind::PrivateIdentifier => 80,
+            SyntaxKind::Keywords(kind) => kind.js_value(),
+            SyntaxKind::QualifiedName => 159,
+            SyntaxKind::ComputedPropertyName => 160,
+            SyntaxKind::TypeParameter => 161,
+            SyntaxKind::Parameter => 162,
+            SyntaxKind::Decorator => 163,
+            SyntaxKind::PropertySignature => 164,
+            SyntaxKind::PropertyDeclaration => 165,
+            SyntaxKind::MethodSignature => 166,
+            SyntaxKind::MethodDeclaration => 167,
+            SyntaxKind::Constructor => 168,
+            SyntaxKind::GetAccessor => 169,
+            SyntaxKind::SetAccessor => 170,
+            SyntaxKind::CallSignature => 171,
+            SyntaxKind::ConstructSignature => 172,
+            SyntaxKind::IndexSignature => 173,
+            SyntaxKind::TypeNodes(kind) => kind.js_value(),
+            SyntaxKind::Tokens(kind) => kind.js_value(),
+            SyntaxKind::ObjectBindingPattern => 198,
+            SyntaxKind::ArrayBindingPattern => 199,
+            SyntaxKind::BindingElement => 200,
+            SyntaxKind::ArrayLiteralExpression => 201,
+            SyntaxKind::ObjectLiteralExpression => 202,
+            SyntaxKind::PropertyAccessExpression => 203,
+            SyntaxKind::ElementAccessExpression => 204,
+            SyntaxKind::CallExpression => 205,
+            SyntaxKind::NewExpression => 206,
+            SyntaxKind::TaggedTemplateExpression => 207,
+            SyntaxKind::TypeAssertionExpression => 208,
+            SyntaxKind::ParenthesizedExpression => 209,
+            SyntaxKind::FunctionExpression => 210,
+            SyntaxKind::ArrowFunction => 211,
+            SyntaxKind::DeleteExpression => 212,
+            SyntaxKind::TypeOfExpression => 213,
+            SyntaxKind::VoidExpression => 214,
+            SyntaxKind::AwaitExpression => 215,
+            SyntaxKind::PrefixUnaryExpression => 216,
+            SyntaxKind::PostfixUnaryExpression => 217,
+            SyntaxKind::BinaryExpression => 218,
+            SyntaxKind::ConditionalExpression => 219,
+            SyntaxKind::TemplateExpression => 220,
+            SyntaxKind::YieldExpression => 221,
+            SyntaxKind::SpreadElement => 222,
+            SyntaxKind::ClassExpression => 223,
+            SyntaxKind::OmittedExpression => 224,
+            SyntaxKind::AsExpression => 226,
+            SyntaxKind::NonNullExpression => 227,
+            SyntaxKind::MetaProperty => 228,
+            SyntaxKind::SyntheticExpression => 229,
+            SyntaxKind::TemplateSpan => 230,
+            SyntaxKind::SemicolonClassElement => 231,
+            SyntaxKind::Block => 232,
+            SyntaxKind::EmptyStatement => 233,
+            SyntaxKind::VariableStatement => 234,
+            SyntaxKind::ExpressionStatement => 235,
+            SyntaxKind::IfStatement => 236,
+            SyntaxKind::DoStatement => 237,
+            SyntaxKind::WhileStatement => 238,
+            SyntaxKind::ForStatement => 239,
+            SyntaxKind::ForInStatement => 240,
+            SyntaxKind::ForOfStatement => 241,
+            SyntaxKind::ContinueStatement => 242,
+            SyntaxKind::BreakStatement => 243,
+            SyntaxKind::ReturnStatement => 244,
+            SyntaxKind::WithStatement => 245,
+            SyntaxKind::SwitchStatement => 246,
+            SyntaxKind::LabeledStatement => 247,
+            SyntaxKind::ThrowStatement => 248,
+            SyntaxKind::TryStatement => 249,
+            SyntaxKind::DebuggerStatement => 250,
+            SyntaxKind::VariableDeclaration => 251,
+            SyntaxKind::VariableDeclarationList => 252,
+            SyntaxKind::FunctionDeclaration => 253,
+            SyntaxKind::ClassDeclaration => 254,
+            SyntaxKind::InterfaceDeclaration => 255,
+            SyntaxKind::TypeAliasDeclaration => 256,
+            SyntaxKind::EnumDeclaration => 257,
+            SyntaxKind::ModuleDeclaration => 258,
+            SyntaxKind::ModuleBlock => 259,
+            SyntaxKind::CaseBlock => 260,
+            SyntaxKind::NamespaceExportDeclaration => 261,
+            SyntaxKind::ImportEqualsDeclaration => 262,
+            SyntaxKind::ImportDeclaration => 263,
+            SyntaxKind::ImportClause => 264,
+            SyntaxKind::NamespaceImport => 265,
+            SyntaxKind::NamedImports => 266,
+            SyntaxKind::ImportSpecifier => 267,
+            SyntaxKind::ExportAssignment => 268,
+            SyntaxKind::ExportDeclaration => 269,
+            SyntaxKind::NamedExports => 270,
+            SyntaxKind::NamespaceExport => 271,
+            SyntaxKind::ExportSpecifier => 272,
+            SyntaxKind::MissingDeclaration => 273,
+            SyntaxKind::ExternalModuleReference => 274,
+            SyntaxKind::JsxElement => 275,
+            SyntaxKind::JsxSelfClosingElement => 276,
+            SyntaxKind::JsxOpeningElement => 277,
+            SyntaxKind::JsxClosingElement => 278,
+            SyntaxKind::JsxFragment => 279,
+            SyntaxKind::JsxOpeningFragment => 280,
+            SyntaxKind::JsxClosingFragment => 281,
+            SyntaxKind::JsxAttribute => 282,
+            SyntaxKind::JsxAttributes => 283,
+            SyntaxKind::JsxSpreadAttribute => 284,
+            SyntaxKind::JsxExpression => 285,
+            SyntaxKind::CaseClause => 286,
+            SyntaxKind::DefaultClause => 287,
+            SyntaxKind::HeritageClause => 288,
+            SyntaxKind::CatchClause => 289,
+            SyntaxKind::PropertyAssignment => 290,
+            SyntaxKind::ShorthandPropertyAssignment => 291,
+            SyntaxKind::SpreadAssignment => 292,
+            SyntaxKind::EnumMember => 293,
+            SyntaxKind::UnparsedPrologue => 294,
+            SyntaxKind::UnparsedPrepend => 295,
+            SyntaxKind::UnparsedText => 296,
+            SyntaxKind::UnparsedInternalText => 297,
+            SyntaxKind::UnparsedSyntheticReference => 298,
+            SyntaxKind::SourceFile => 299,
+            SyntaxKind::Bundle => 300,
+            SyntaxKind::UnparsedSource => 301,
+            SyntaxKind::InputFiles => 302,
+            SyntaxKind::JSDocNameReference => 304,
+            SyntaxKind::JSDocMemberName => 305,
+            SyntaxKind::JSDocComment => 314,
+            SyntaxKind::JSDocText => 315,
+            SyntaxKind::JSDocLink => 318,
+            SyntaxKind::JSDocLinkCode => 319,
+            SyntaxKind::JSDocLinkPlain => 320,
+            SyntaxKind::JSDocTag => 321,
+            SyntaxKind::JSDocAugmentsTag => 322,
+            SyntaxKind::JSDocImplementsTag => 323,
+            SyntaxKind::JSDocAuthorTag => 324,
+            SyntaxKind::JSDocDeprecatedTag => 325,
+            SyntaxKind::JSDocClassTag => 326,
+            SyntaxKind::JSDocPublicTag => 327,
+            SyntaxKind::JSDocPrivateTag => 328,
+            SyntaxKind::JSDocProtectedTag => 329,
+            SyntaxKind::JSDocReadonlyTag => 330,
+            SyntaxKind::JSDocOverrideTag => 331,
+            SyntaxKind::JSDocCallbackTag => 332,
+            SyntaxKind::JSDocEnumTag => 333,
+            SyntaxKind::JSDocParameterTag => 334,
+            SyntaxKind::JSDocReturnTag => 335,
+            SyntaxKind::JSDocThisTag => 336,
+            SyntaxKind::JSDocTypeTag => 337,
+            SyntaxKind::JSDocTemplateTag => 338,
+            SyntaxKind::JSDocTypedefTag => 339,
+            SyntaxKind::JSDocSeeTag => 340,
+            SyntaxKind::JSDocPropertyTag => 341,
+            SyntaxKind::SyntaxList => 342,
+            SyntaxKind::NotEmittedStatement => 343,
+            SyntaxKind::PartiallyEmittedExpression => 344,
+            SyntaxKind::CommaListExpression => 345,
+            SyntaxKind::MergeDeclarationMarker => 346,
+            SyntaxKind::EndOfDeclarationMarker => 347,
+            SyntaxKind::SyntheticReferenceExpression => 348,
+            SyntaxKind::Count => 349,
+        }
+    }
+}
+
+impl TokenSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            TokenSyntaxKind::Unknown => 0,
+            TokenSyntaxKind::EndOfFileToken => 1,
+            TokenSyntaxKind::Identifier => 79,
+            TokenSyntaxKind::Trivias(kind) => kind.js_value(),
+            TokenSyntaxKind::Literals(kind) => kind.js_value(),
+            TokenSyntaxKind::PseudoLiterals(kind) => kind.js_value(),
+            TokenSyntaxKind::Punctuations(kind) => kind.js_value(),
+            TokenSyntaxKind::Keywords(kind) => kind.js_value(),
+        }
+    }
+}
+
+impl KeywordSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            KeywordSyntaxKind::BreakKeyword => 81,
+            KeywordSyntaxKind::CaseKeyword => 82,
+            KeywordSyntaxKind::CatchKeyword => 83,
+            KeywordSyntaxKind::ClassKeyword => 84,
+            KeywordSyntaxKind::ContinueKeyword => 86,
+            KeywordSyntaxKind::DebuggerKeyword => 87,
+            KeywordSyntaxKind::DeleteKeyword => 89,
+            KeywordSyntaxKind::DoKeyword => 90,
+            KeywordSyntaxKind::ElseKeyword => 91,
+            KeywordSyntaxKind::EnumKeyword => 92,
+            KeywordSyntaxKind::ExtendsKeyword => 94,
+            KeywordSyntaxKind::FalseKeyword => 95,
+            KeywordSyntaxKind::FinallyKeyword => 96,
+            KeywordSyntaxKind::ForKeyword => 97,
+            KeywordSyntaxKind::FunctionKeyword => 98,
+            KeywordSyntaxKind::IfKeyword => 99,
+            KeywordSyntaxKind::ImportKeyword => 100,
+            KeywordSyntaxKind::InKeyword => 101,
+            KeywordSyntaxKind::InstanceOfKeyword => 102,
+            KeywordSyntaxKind::NewKeyword => 103,
+            KeywordSyntaxKind::NullKeyword => 104,
+            KeywordSyntaxKind::ReturnKeyword => 105,
+            KeywordSyntaxKind::SuperKeyword => 106,
+            KeywordSyntaxKind::SwitchKeyword => 107,
+            KeywordSyntaxKind::ThisKeyword => 108,
+            KeywordSyntaxKind::ThrowKeyword => 109,
+            KeywordSyntaxKind::TrueKeyword => 110,
+            KeywordSyntaxKind::TryKeyword => 111,
+            KeywordSyntaxKind::TypeOfKeyword => 112,
+            KeywordSyntaxKind::VarKeyword => 113,
+            KeywordSyntaxKind::WhileKeyword => 115,
+            KeywordSyntaxKind::WithKeyword => 116,
+            KeywordSyntaxKind::Modifiers(kind) => kind.js_value(),
+            KeywordSyntaxKind::KeywordType(kind) => kind.js_value(),
+            KeywordSyntaxKind::ImplementsKeyword => 117,
+            KeywordSyntaxKind::InterfaceKeyword => 118,
+            KeywordSyntaxKind::LetKeyword => 119,
+            KeywordSyntaxKind::PackageKeyword => 120,
+            KeywordSyntaxKind::YieldKeyword => 125,
+            KeywordSyntaxKind::AsKeyword => 127,
+            KeywordSyntaxKind::AssertsKeyword => 128,
+            KeywordSyntaxKind::AwaitKeyword => 131,
+            KeywordSyntaxKind::ConstructorKeyword => 133,
+            KeywordSyntaxKind::GetKeyword => 135,
+            KeywordSyntaxKind::InferKeyword => 136,
+            KeywordSyntaxKind::IsKeyword => 138,
+            KeywordSyntaxKind::KeyOfKeyword => 139,
+            KeywordSyntaxKind::ModuleKeyword => 140,
+            KeywordSyntaxKind::NamespaceKeyword => 141,
+            KeywordSyntaxKind::RequireKeyword => 144,
+            KeywordSyntaxKind::SetKeyword => 147,
+            KeywordSyntaxKind::TypeKeyword => 150,
+            KeywordSyntaxKind::UniqueKeyword => 152,
+            KeywordSyntaxKind::FromKeyword => 154,
+            KeywordSyntaxKind::GlobalKeyword => 155,
+            KeywordSyntaxKind::OfKeyword => 158,
+        }
+    }
+}
+
+impl ModifierSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            ModifierSyntaxKind::AbstractKeyword => 126,
+            ModifierSyntaxKind::AsyncKeyword => 130,
+            ModifierSyntaxKind::ConstKeyword => 85,
+            ModifierSyntaxKind::DeclareKeyword => 134,
+            ModifierSyntaxKind::DefaultKeyword => 88,
+            ModifierSyntaxKind::ExportKeyword => 93,
+            ModifierSyntaxKind::PrivateKeyword => 121,
+            ModifierSyntaxKind::ProtectedKeyword => 122,
+            ModifierSyntaxKind::PublicKeyword => 123,
+            ModifierSyntaxKind::ReadonlyKeyword => 143,
+            ModifierSyntaxKind::OverrideKeyword => 157,
+            ModifierSyntaxKind::StaticKeyword => 124,
+        }
+    }
+}
+
+impl KeywordTypeSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            KeywordTypeSyntaxKind::AnyKeyword => 129,
+            KeywordTypeSyntaxKind::BigIntKeyword => 156,
+            KeywordTypeSyntaxKind::VoidKeyword => 114,
+            KeywordTypeSyntaxKind::BooleanKeyword => 132,
+            KeywordTypeSyntaxKind::IntrinsicKeyword => 137,
+            KeywordTypeSyntaxKind::NeverKeyword => 142,
+            KeywordTypeSyntaxKind::NumberKeyword => 145,
+            KeywordTypeSyntaxKind::ObjectKeyword => 146,
+            KeywordTypeSyntaxKind::StringKeyword => 148,
+            KeywordTypeSyntaxKind::SymbolKeyword => 149,
+            KeywordTypeSyntaxKind::UndefinedKeyword => 151,
+            KeywordTypeSyntaxKind::UnknownKeyword => 153,
+        }
+    }
+}
+
+impl TypeNodeSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        match self {
+            TypeNodeSyntaxKind::TypePredicate => 174,
+            TypeNodeSyntaxKind::TypeReference => 175,
+            TypeNodeSyntaxKind::FunctionType => 176,
+            TypeNodeSyntaxKind::ConstructorType => 177,
+            TypeNodeSyntaxKind::TypeQuery => 178,
+            TypeNodeSyntaxKind::TypeLiteral => 179,
+            TypeNodeSyntaxKind::ArrayType => 180,
+            TypeNodeSyntaxKind::TupleType => 181,
+            TypeNodeSyntaxKind::OptionalType => 182,
+            TypeNodeSyntaxKind::RestType => 183,
+            TypeNodeSyntaxKind::UnionType => 184,
+            TypeNodeSyntaxKind::IntersectionType => 185,
+            TypeNodeSyntaxKind::ConditionalType => 186,
+            TypeNodeSyntaxKind::InferType => 187,
+            TypeNodeSyntaxKind::ParenthesizedType => 188,
+            TypeNodeSyntaxKind::ThisType => 189,
+            TypeNodeSyntaxKind::TypeOperator => 190,
+            TypeNodeSyntaxKind::IndexedAccessType => 191,
+            TypeNodeSyntaxKind::MappedType => 192,
+            TypeNodeSyntaxKind::LiteralType => 193,
+            TypeNodeSyntaxKind::NamedTupleMember => 194,
+            TypeNodeSyntaxKind::TemplateLiteralType => 195,
+            TypeNodeSyntaxKind::TemplateLiteralTypeSpan => 196,
+            TypeNodeSyntaxKind::ImportType => 197,
+            TypeNodeSyntaxKind::ExpressionWithTypeArguments => 225,
+            TypeNodeSyntaxKind::JSDocTypeExpression => 303,
+            TypeNodeSyntaxKind::JSDocAllType => 306,
+            TypeNodeSyntaxKind::JSDocUnknownType => 307,
+            TypeNodeSyntaxKind::JSDocNullableType => 308,
+            TypeNodeSyntaxKind::JSDocNonNullableType => 309,
+            TypeNodeSyntaxKind::JSDocOptionalType => 310,
+            TypeNodeSyntaxKind::JSDocFunctionType => 311,
+            TypeNodeSyntaxKind::JSDocVariadicType => 312,
+            TypeNodeSyntaxKind::JSDocNamepathType => 313,
+            TypeNodeSyntaxKind::JSDocTypeLiteral => 316,
+            TypeNodeSyntaxKind::JSDocSignature => 317,
+        }
+    }
+}
+
+impl TriviaSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        self as u32 + 2
+    }
+}
+
+impl LiteralSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        self as u32 + 8
+    }
+}
+
+impl PseudoLiteralSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        self as u32 + 15
+    }
+}
+
+impl PunctuationSyntaxKind {
+    pub fn js_value(self) -> u32 {
+        self as u32 + 18
+    }
+}
+
+impl From<JsxTokenSyntaxKind> for SyntaxKind {
+    fn from(kind: JsxTokenSyntaxKind) -> SyntaxKind {
+        match kind {
+            JsxTokenSyntaxKind::LessThanSlashToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanSlashToken)
+            }
+            JsxTokenSyntaxKind::EndOfFileToken => {
+                SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken)
+            }
+            JsxTokenSyntaxKind::ConflictMarkerTrivia => {
+                SyntaxKind::Trivias(TriviaSyntaxKind::ConflictMarkerTrivia)
+            }
+            JsxTokenSyntaxKind::JsxText => SyntaxKind::Literals(LiteralSyntaxKind::JsxText),
+            JsxTokenSyntaxKind::JsxTextAllWhiteSpaces => {
+                SyntaxKind::Literals(LiteralSyntaxKind::JsxTextAllWhiteSpaces)
+            }
+            JsxTokenSyntaxKind::OpenBraceToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken)
+            }
+            JsxTokenSyntaxKind::LessThanToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanToken)
+            }
+        }
+    }
+}
+
+impl From<JSDocSyntaxKind> for SyntaxKind {
+    fn from(kind: JSDocSyntaxKind) -> SyntaxKind {
+        match kind {
+            JSDocSyntaxKind::EndOfFileToken => SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+            JSDocSyntaxKind::WhitespaceTrivia => {
+                SyntaxKind::Trivias(TriviaSyntaxKind::WhitespaceTrivia)
+            }
+            JSDocSyntaxKind::AtToken => SyntaxKind::Punctuations(PunctuationSyntaxKind::AtToken),
+            JSDocSyntaxKind::NewLineTrivia => SyntaxKind::Trivias(TriviaSyntaxKind::NewLineTrivia),
+            JSDocSyntaxKind::AsteriskToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::AsteriskToken)
+            }
+            JSDocSyntaxKind::OpenBraceToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBraceToken)
+            }
+            JSDocSyntaxKind::CloseBraceToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBraceToken)
+            }
+            JSDocSyntaxKind::LessThanToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::LessThanToken)
+            }
+            JSDocSyntaxKind::GreaterThanToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::GreaterThanToken)
+            }
+            JSDocSyntaxKind::OpenBracketToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::OpenBracketToken)
+            }
+            JSDocSyntaxKind::CloseBracketToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::CloseBracketToken)
+            }
+            JSDocSyntaxKind::EqualsToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsToken)
+            }
+            JSDocSyntaxKind::CommaToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::CommaToken)
+            }
+            JSDocSyntaxKind::DotToken => SyntaxKind::Punctuations(PunctuationSyntaxKind::DotToken),
+            JSDocSyntaxKind::Identifier => SyntaxKind::Tokens(TokenSyntaxKind::Identifier),
+            JSDocSyntaxKind::BacktickToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::BacktickToken)
+            }
+            JSDocSyntaxKind::HashToken => {
+                SyntaxKind::Punctuations(PunctuationSyntaxKind::HashToken)
+            }
+            JSDocSyntaxKind::Unknown => SyntaxKind::Tokens(TokenSyntaxKind::Unknown),
+            JSDocSyntaxKind::Keywords(keyword) => SyntaxKind::Keywords(keyword),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TokenFlags {
     None = 0,
     /* @internal */
@@ -555,6 +1346,7 @@ pub enum TokenFlags {
     // TemplateLiteralLikeFlags = 1 << 11,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DiagnosticCategory {
     Warning,
     Error,
@@ -562,32 +1354,271 @@ pub enum DiagnosticCategory {
     Message,
 }
 
+impl DiagnosticCategory {
+    /// The numeric value of `ts.DiagnosticCategory`.
+    pub fn js_value(self) -> u32 {
+        match self {
+            DiagnosticCategory::Warning => 0,
+            DiagnosticCategory::Error => 1,
+            DiagnosticCategory::Suggestion => 2,
+            DiagnosticCategory::Message => 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct IDiagnosticMessage<'a> {
-    key: &'a str,
-    category: DiagnosticCategory,
-    code: u16,
-    message: String,
-    reports_unnecessary: Option<HashMap<&'a str, &'a str>>,
-    reports_deprecated: Option<HashMap<&'a str, &'a str>>,
+    pub key: &'a str,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+    pub message: Cow<'a, str>,
+    pub reports_unnecessary: bool,
+    pub reports_deprecated: bool,
     /* @internal */
-    elided_in_compatibility_pyramid: Option<bool>,
+    pub elided_in_compatibility_pyramid: bool,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct TextRange {
     pub pos: usize,
     pub end: usize,
 }
 
+// An edit of a text: the range of the old text that was replaced and the length of the text
+// that replaced it, both in UTF-16 code units. The JS compiler describes the replaced range as
+// a `TextSpan` (a start and a length) instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextChangeRange {
+    pub span: TextRange,
+    pub new_length: usize,
+}
+
+impl TextChangeRange {
+    pub fn new(span: TextRange, new_length: usize) -> TextChangeRange {
+        TextChangeRange { span, new_length }
+    }
+
+    // The range of the new text that took the place of `span`.
+    pub fn new_span(&self) -> TextRange {
+        TextRange {
+            pos: self.span.pos,
+            end: self.span.pos + self.new_length,
+        }
+    }
+
+    pub fn is_unchanged(&self) -> bool {
+        self.span.pos == self.span.end && self.new_length == 0
+    }
+}
+
+// Lets the thread that started a long-running operation cancel it from another thread, like the
+// `CancellationToken` tsserver passes to the language service. Clones share the same state, and
+// the operation polls it as it goes.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancellation_requested(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn throw_if_cancellation_requested(&self) -> Result<(), OperationCanceled> {
+        if self.is_cancellation_requested() {
+            Err(OperationCanceled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// The error of an operation that stopped because its `CancellationToken` was canceled, like the
+// `OperationCanceledException` of the JS compiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationCanceled;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticWithLocation {
+    pub file_name: String,
+    pub start: usize,
+    pub length: usize,
+    pub message_text: String,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+    pub reports_unnecessary: bool,
+    pub reports_deprecated: bool,
+}
+
+// A diagnostic that may not be attached to a file, like the circularity error for `extends`
+// chains. `start` and `length` are only set together with `file_name`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file_name: Option<String>,
+    pub start: Option<usize>,
+    pub length: Option<usize>,
+    pub message_text: String,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+    pub reports_unnecessary: bool,
+    pub reports_deprecated: bool,
+}
+
+impl From<DiagnosticWithLocation> for Diagnostic {
+    fn from(diagnostic: DiagnosticWithLocation) -> Diagnostic {
+        Diagnostic {
+            file_name: Some(diagnostic.file_name),
+            start: Some(diagnostic.start),
+            length: Some(diagnostic.length),
+            message_text: diagnostic.message_text,
+            category: diagnostic.category,
+            code: diagnostic.code,
+            reports_unnecessary: diagnostic.reports_unnecessary,
+            reports_deprecated: diagnostic.reports_deprecated,
+        }
+    }
+}
+
+// The value tree of a JSON (or JSONC) document. Every range follows the `Node` convention of the
+// JS compiler: `pos` is the full start including leading trivia and `end` is the end of the
+// last token, both in UTF-16 code units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonSourceFile {
+    pub file_name: String,
+    // `None` when the file does not contain any value.
+    pub value: Option<JsonNode>,
+    pub end_of_file_token: TextRange,
+    pub parse_diagnostics: Vec<DiagnosticWithLocation>,
+    // Errors for syntax that parses but is not valid JSON, like single quoted strings or
+    // identifiers as values (`convertToObject` in the JS compiler).
+    pub json_diagnostics: Vec<DiagnosticWithLocation>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonNode {
+    pub range: TextRange,
+    pub value: JsonValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Object(Vec<JsonProperty>),
+    Array(Vec<JsonNode>),
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Null,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonProperty {
+    pub range: TextRange,
+    pub name: String,
+    pub name_range: TextRange,
+    pub initializer: JsonNode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptionMapValue {
+    Number(i32),
+    String(&'static str),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CommandLineOptionType {
+    String,
+    Number,
+    Boolean,
+    Object,
+    List(&'static CommandLineOption),
+    // An option whose string value is one of the keys of the map, like `target` or `lib`.
+    Map(&'static [(&'static str, OptionMapValue)]),
+}
+
+// Returns the diagnostic (and its argument) for a value that is well typed but still invalid.
+pub type ExtraValidation = fn(&JsonValue) -> Option<(IDiagnosticMessage<'static>, String)>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CommandLineOption {
+    pub name: &'static str,
+    pub option_type: CommandLineOptionType,
+    // True if option value is a path or fileName
+    pub is_file_path: bool,
+    pub extra_validation: Option<ExtraValidation>,
+}
+
+// Option values keyed by option name. `None` is an option that was set to `null` or to an invalid
+// value, which still overrides the value inherited from an extended config.
+pub type CompilerOptions = BTreeMap<String, Option<JsonValue>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectReference {
+    // A normalized path on disk
+    pub path: String,
+    // The path as the user originally wrote it
+    pub original_path: String,
+    // True if the output of this reference should be prepended to the output of this project.
+    // Only valid for --outFile compilations
+    pub prepend: Option<bool>,
+    // True if it is intended that this reference form a circularity
+    pub circular: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigFileSpecs {
+    pub files_specs: Option<Vec<String>>,
+    // Present to report errors (user specified specs), validated_include_specs are used for
+    // file name matching
+    pub include_specs: Option<Vec<String>>,
+    // Present to report errors (user specified specs), validated_exclude_specs are used for
+    // file name matching
+    pub exclude_specs: Option<Vec<String>>,
+    pub validated_include_specs: Option<Vec<String>>,
+    pub validated_exclude_specs: Option<Vec<String>>,
+}
+
+// The result of reading a config file and the configs it extends. Unlike `ParsedCommandLine` in
+// the JS compiler there are no `fileNames`: the `config_file_specs` are not matched against the
+// file system.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedCommandLine {
+    pub options: CompilerOptions,
+    pub watch_options: Option<CompilerOptions>,
+    pub project_references: Option<Vec<ProjectReference>>,
+    pub config_file_specs: ConfigFileSpecs,
+    pub compile_on_save: bool,
+    // Every config file in the `extends` chain, nearest first.
+    pub extended_source_files: Vec<String>,
+    pub errors: Vec<Diagnostic>,
+}
+
+pub trait ParseConfigHost {
+    fn use_case_sensitive_file_names(&self) -> bool;
+    fn file_exists(&self, path: &str) -> bool;
+    fn directory_exists(&self, path: &str) -> bool;
+    fn read_file(&self, path: &str) -> io::Result<String>;
+    fn realpath(&self, path: &str) -> String;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CommentDirectiveType {
     ExpectError,
     Ignore,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CommentDirective {
     pub range: TextRange,
     pub _type: CommentDirectiveType,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ScriptTarget {
     ES3 = 0,
     ES5 = 1,
@@ -602,10 +1633,1040 @@ pub enum ScriptTarget {
     JSON = 100,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum LanguageVariant {
     Standard,
     JSX,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptKind {
+    Unknown,
+    JS,
+    JSX,
+    TS,
+    TSX,
+    External,
+    JSON,
+    // Used on extensions that doesn't define the ScriptKind but the content defines it.
+    // Deferred extensions are going to be included in all project contexts.
+    Deferred,
+}
+
+#[allow(non_snake_case)]
+pub mod NodeFlags {
+    pub const NONE: u32 = 0;
+    pub const LET: u32 = 1 << 0; // Variable declaration
+    pub const CONST: u32 = 1 << 1; // Variable declaration
+    pub const NESTED_NAMESPACE: u32 = 1 << 2; // Namespace declaration
+    pub const SYNTHESIZED: u32 = 1 << 3; // Node was synthesized during transformation
+    pub const NAMESPACE: u32 = 1 << 4; // Namespace declaration
+    pub const OPTIONAL_CHAIN: u32 = 1 << 5; // Chained MemberExpression rooted to a pseudo-OptionalExpression
+    pub const EXPORT_CONTEXT: u32 = 1 << 6; // Export context (initialized by binding)
+    pub const CONTAINS_THIS: u32 = 1 << 7; // Interface contains references to "this"
+    pub const HAS_IMPLICIT_RETURN: u32 = 1 << 8; // If function implicitly returns on one of codepaths (initialized by binding)
+    pub const HAS_EXPLICIT_RETURN: u32 = 1 << 9; // If function has explicit reachable return on one of codepaths (initialized by binding)
+    pub const GLOBAL_AUGMENTATION: u32 = 1 << 10; // Set if module declaration is an augmentation for the global scope
+    pub const HAS_ASYNC_FUNCTIONS: u32 = 1 << 11; // If the file has async functions (initialized by binding)
+    pub const DISALLOW_IN_CONTEXT: u32 = 1 << 12; // If node was parsed in a context where 'in-expressions' are not allowed
+    pub const YIELD_CONTEXT: u32 = 1 << 13; // If node was parsed in the 'yield' context created when parsing a generator
+    pub const DECORATOR_CONTEXT: u32 = 1 << 14; // If node was parsed as part of a decorator
+    pub const AWAIT_CONTEXT: u32 = 1 << 15; // If node was parsed in the 'await' context created when parsing an async function
+    pub const THIS_NODE_HAS_ERROR: u32 = 1 << 16; // If the parser encountered an error when parsing the code that created this node
+    pub const JAVA_SCRIPT_FILE: u32 = 1 << 17; // If node was parsed in a JavaScript
+    pub const THIS_NODE_OR_ANY_SUB_NODES_HAS_ERROR: u32 = 1 << 18; // If this node or any of its children had an error
+    pub const HAS_AGGREGATED_CHILD_DATA: u32 = 1 << 19; // If we've computed data from children and cached it in this node
+
+    // These flags will be set when the parser encounters a dynamic import expression or
+    // 'import.meta' to avoid walking the tree if the flags are not set. They are an
+    // approximation: once set, the flags never get cleared.
+    pub const POSSIBLY_CONTAINS_DYNAMIC_IMPORT: u32 = 1 << 20;
+    pub const POSSIBLY_CONTAINS_IMPORT_META: u32 = 1 << 21;
+
+    pub const JSDOC: u32 = 1 << 22; // If node was parsed inside jsdoc
+    pub const AMBIENT: u32 = 1 << 23; // If node was inside an ambient context -- a declaration file, or inside something with the `declare` modifier.
+    pub const IN_WITH_STATEMENT: u32 = 1 << 24; // If any ancestor of node was the `statement` of a WithStatement (not the `expression`)
+    pub const JSON_FILE: u32 = 1 << 25; // If node was parsed in a Json
+    pub const TYPE_CACHED: u32 = 1 << 26; // If a type was cached for node at any point
+    pub const DEPRECATED: u32 = 1 << 27; // If has '@deprecated' JSDoc tag
+
+    pub const BLOCK_SCOPED: u32 = LET | CONST;
+
+    pub const REACHABILITY_CHECK_FLAGS: u32 = HAS_IMPLICIT_RETURN | HAS_EXPLICIT_RETURN;
+    pub const REACHABILITY_AND_EMIT_FLAGS: u32 = REACHABILITY_CHECK_FLAGS | HAS_ASYNC_FUNCTIONS;
+
+    // Parsing context flags
+    pub const CONTEXT_FLAGS: u32 = DISALLOW_IN_CONTEXT
+        | YIELD_CONTEXT
+        | DECORATOR_CONTEXT
+        | AWAIT_CONTEXT
+        | JAVA_SCRIPT_FILE
+        | IN_WITH_STATEMENT
+        | AMBIENT;
+
+    // Exclude these flags when parsing a Type
+    pub const TYPE_EXCLUDES_FLAGS: u32 = YIELD_CONTEXT | AWAIT_CONTEXT;
+
+    // Represents all flags that are potentially set once and
+    // never cleared on SourceFiles which get re-used in between incremental parses.
+    pub const PERMANENTLY_SET_INCREMENTAL_FLAGS: u32 =
+        POSSIBLY_CONTAINS_DYNAMIC_IMPORT | POSSIBLY_CONTAINS_IMPORT_META;
+}
+
+#[allow(non_snake_case)]
+pub mod ModifierFlags {
+    pub const NONE: u32 = 0;
+    pub const EXPORT: u32 = 1 << 0; // Declarations
+    pub const AMBIENT: u32 = 1 << 1; // Declarations
+    pub const PUBLIC: u32 = 1 << 2; // Property/Method
+    pub const PRIVATE: u32 = 1 << 3; // Property/Method
+    pub const PROTECTED: u32 = 1 << 4; // Property/Method
+    pub const STATIC: u32 = 1 << 5; // Property/Method
+    pub const READONLY: u32 = 1 << 6; // Property/Method
+    pub const ABSTRACT: u32 = 1 << 7; // Class/Method/ConstructSignature
+    pub const ASYNC: u32 = 1 << 8; // Property/Method/Function
+    pub const DEFAULT: u32 = 1 << 9; // Function/Class (export default declaration)
+    pub const CONST: u32 = 1 << 11; // Const enum
+
+    pub const DEPRECATED: u32 = 1 << 13; // Deprecated tag.
+    pub const OVERRIDE: u32 = 1 << 14; // Override method.
+
+    pub const ACCESSIBILITY_MODIFIER: u32 = PUBLIC | PRIVATE | PROTECTED;
+    // Accessibility modifiers and 'readonly' can be attached to a parameter in a constructor to make it a property.
+    pub const PARAMETER_PROPERTY_MODIFIER: u32 = ACCESSIBILITY_MODIFIER | READONLY | OVERRIDE;
+    pub const NON_PUBLIC_ACCESSIBILITY_MODIFIER: u32 = PRIVATE | PROTECTED;
+
+    pub const TYPE_SCRIPT_MODIFIER: u32 =
+        AMBIENT | PUBLIC | PRIVATE | PROTECTED | READONLY | ABSTRACT | CONST | OVERRIDE;
+    pub const EXPORT_DEFAULT: u32 = EXPORT | DEFAULT;
+    pub const ALL: u32 = EXPORT
+        | AMBIENT
+        | PUBLIC
+        | PRIVATE
+        | PROTECTED
+        | STATIC
+        | READONLY
+        | ABSTRACT
+        | ASYNC
+        | DEFAULT
+        | CONST
+        | DEPRECATED
+        | OVERRIDE;
+}
+
+// The kinds of assignments and `Object.defineProperty` calls that declare something in a JS file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AssignmentDeclarationKind {
+    None,
+    /// exports.name = expr
+    /// module.exports.name = expr
+    ExportsProperty,
+    /// module.exports = expr
+    ModuleExports,
+    /// className.prototype.name = expr
+    PrototypeProperty,
+    /// this.name = expr
+    ThisProperty,
+    // F.name = expr
+    Property,
+    // F.prototype = { ... }
+    Prototype,
+    // Object.defineProperty(x, 'name', { value: any, writable?: boolean (false by default) });
+    // Object.defineProperty(x, 'name', { get: Function, set: Function });
+    // Object.defineProperty(x, 'name', { get: Function });
+    // Object.defineProperty(x, 'name', { set: Function });
+    ObjectDefinePropertyValue,
+    // Object.defineProperty(exports || module.exports, 'name', ...);
+    ObjectDefinePropertyExports,
+    // Object.defineProperty(Foo.prototype, 'name', ...);
+    ObjectDefinePrototypeProperty,
+}
+
+/// A handle to a node of a `SourceFile`, the index of the node in `SourceFile::nodes`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub u32);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+// A list of child nodes together with the range it covers, like `NodeArray` in the JS compiler.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeArray {
+    pub range: TextRange,
+    pub has_trailing_comma: bool,
+    // Set on lists whose opening token is missing, like the parameters of `function f {}`.
+    pub is_missing_list: bool,
+    pub nodes: Vec<NodeId>,
+}
+
+impl std::ops::Deref for NodeArray {
+    type Target = [NodeId];
+
+    fn deref(&self) -> &[NodeId] {
+        &self.nodes
+    }
+}
+
+// A node of the syntax tree. As in the JS compiler, `range.pos` is the full start of the node
+// including its leading trivia and `range.end` is the end of its last token, both in UTF-16
+// code units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub kind: SyntaxKind,
+    pub range: TextRange,
+    pub flags: u32,
+    pub decorators: Option<NodeArray>,
+    pub modifiers: Option<NodeArray>,
+    // The JSDoc comments attached to the node, each one a `JSDocComment` node.
+    pub js_doc: Option<Vec<NodeId>>,
+    // The node this node is a child (or JSDoc comment) of; `None` for the `SourceFile` node.
+    pub parent: Option<NodeId>,
+    pub data: NodeData,
+}
+
+impl Node {
+    pub fn new(kind: SyntaxKind, data: NodeData) -> Node {
+        Node {
+            kind,
+            range: TextRange::default(),
+            flags: NodeFlags::NONE,
+            decorators: None,
+            modifiers: None,
+            js_doc: None,
+            parent: None,
+            data,
+        }
+    }
+
+    pub fn pos(&self) -> usize {
+        self.range.pos
+    }
+
+    pub fn end(&self) -> usize {
+        self.range.end
+    }
+
+    // The accessors below return the child stored under the same property name in the JS
+    // compiler, whichever variant of `NodeData` holds it, and `None` for kinds without one.
+
+    pub fn name(&self) -> Option<NodeId> {
+        match &self.data {
+            NodeData::TypeParameter { name, .. }
+            | NodeData::VariableLike { name, .. }
+            | NodeData::ShorthandPropertyAssignment { name, .. }
+            | NodeData::BindingElement { name, .. }
+            | NodeData::NamedTupleMember { name, .. }
+            | NodeData::PropertyAccessExpression { name, .. }
+            | NodeData::MetaProperty { name, .. }
+            | NodeData::InterfaceDeclaration { name, .. }
+            | NodeData::TypeAliasDeclaration { name, .. }
+            | NodeData::EnumDeclaration { name, .. }
+            | NodeData::EnumMember { name, .. }
+            | NodeData::ModuleDeclaration { name, .. }
+            | NodeData::ImportEqualsDeclaration { name, .. }
+            | NodeData::NamespaceImportOrExport { name }
+            | NodeData::ImportOrExportSpecifier { name, .. }
+            | NodeData::JsxAttribute { name, .. }
+            | NodeData::JSDocNameReference { name } => Some(*name),
+            NodeData::Parameter { name, .. }
+            | NodeData::ClassLikeDeclaration { name, .. }
+            | NodeData::ImportClause { name, .. }
+            | NodeData::JSDocLink { name, .. } => *name,
+            NodeData::SignatureDeclaration(signature) => signature.name,
+            NodeData::JSDocTag(tag) => tag.name,
+            _ => None,
+        }
+    }
+
+    pub fn expression(&self) -> Option<NodeId> {
+        match &self.data {
+            NodeData::Expression { expression }
+            | NodeData::PropertyAccessExpression { expression, .. }
+            | NodeData::ElementAccessExpression { expression, .. }
+            | NodeData::CallExpression { expression, .. }
+            | NodeData::TypeAssertion { expression, .. }
+            | NodeData::AsExpression { expression, .. }
+            | NodeData::TemplateSpan { expression, .. }
+            | NodeData::IfStatement { expression, .. }
+            | NodeData::DoStatement { expression, .. }
+            | NodeData::WhileOrWithStatement { expression, .. }
+            | NodeData::ForInOrOfStatement { expression, .. }
+            | NodeData::SwitchStatement { expression, .. }
+            | NodeData::ExpressionWithTypeArguments { expression, .. }
+            | NodeData::ExportAssignment { expression, .. } => Some(*expression),
+            NodeData::TypeParameter { expression, .. }
+            | NodeData::YieldExpression { expression, .. }
+            | NodeData::ReturnStatement { expression }
+            | NodeData::CaseOrDefaultClause { expression, .. }
+            | NodeData::JsxExpression { expression, .. } => *expression,
+            _ => None,
+        }
+    }
+
+    // The `type` property: the annotated, asserted or aliased type.
+    pub fn type_node(&self) -> Option<NodeId> {
+        match &self.data {
+            NodeData::TypeOperator { _type, .. }
+            | NodeData::NamedTupleMember { _type, .. }
+            | NodeData::TypeAssertion { _type, .. }
+            | NodeData::AsExpression { _type, .. }
+            | NodeData::TemplateLiteralTypeSpan { _type, .. }
+            | NodeData::TypeAliasDeclaration { _type, .. } => Some(*_type),
+            NodeData::Parameter { _type, .. }
+            | NodeData::VariableLike { _type, .. }
+            | NodeData::TypePredicate { _type, .. }
+            | NodeData::Type { _type }
+            | NodeData::MappedType { _type, .. }
+            | NodeData::JSDocSignature { _type, .. } => *_type,
+            NodeData::SignatureDeclaration(signature) => signature._type,
+            _ => None,
+        }
+    }
+
+    pub fn initializer(&self) -> Option<NodeId> {
+        match &self.data {
+            NodeData::ForInOrOfStatement { initializer, .. } => Some(*initializer),
+            NodeData::Parameter { initializer, .. }
+            | NodeData::VariableLike { initializer, .. }
+            | NodeData::BindingElement { initializer, .. }
+            | NodeData::ForStatement { initializer, .. }
+            | NodeData::EnumMember { initializer, .. }
+            | NodeData::JsxAttribute { initializer, .. } => *initializer,
+            _ => None,
+        }
+    }
+
+    pub fn body(&self) -> Option<NodeId> {
+        match &self.data {
+            NodeData::SignatureDeclaration(signature) => signature.body,
+            NodeData::ModuleDeclaration { body, .. } => *body,
+            _ => None,
+        }
+    }
+
+    pub fn type_parameters(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::SignatureDeclaration(signature) => signature.type_parameters.as_ref(),
+            NodeData::ClassLikeDeclaration {
+                type_parameters, ..
+            }
+            | NodeData::InterfaceDeclaration {
+                type_parameters, ..
+            }
+            | NodeData::TypeAliasDeclaration {
+                type_parameters, ..
+            } => type_parameters.as_ref(),
+            NodeData::JSDocTag(tag) => tag.type_parameters.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn type_arguments(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::TypeReference { type_arguments, .. }
+            | NodeData::ImportType { type_arguments, .. }
+            | NodeData::CallExpression { type_arguments, .. }
+            | NodeData::TaggedTemplateExpression { type_arguments, .. }
+            | NodeData::ExpressionWithTypeArguments { type_arguments, .. }
+            | NodeData::JsxOpeningLikeElement { type_arguments, .. } => type_arguments.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn parameters(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::SignatureDeclaration(signature) => Some(&signature.parameters),
+            _ => None,
+        }
+    }
+
+    pub fn arguments(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::CallExpression { arguments, .. } => arguments.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn heritage_clauses(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::ClassLikeDeclaration {
+                heritage_clauses, ..
+            }
+            | NodeData::InterfaceDeclaration {
+                heritage_clauses, ..
+            } => heritage_clauses.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn members(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::TypeLiteral { members }
+            | NodeData::ClassLikeDeclaration { members, .. }
+            | NodeData::InterfaceDeclaration { members, .. }
+            | NodeData::EnumDeclaration { members, .. } => Some(members),
+            _ => None,
+        }
+    }
+
+    pub fn elements(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::TupleType { elements }
+            | NodeData::BindingPattern { elements }
+            | NodeData::ArrayLiteralExpression { elements, .. }
+            | NodeData::NamedImportsOrExports { elements } => Some(elements),
+            _ => None,
+        }
+    }
+
+    pub fn statements(&self) -> Option<&NodeArray> {
+        match &self.data {
+            NodeData::Block { statements, .. }
+            | NodeData::SourceFile { statements, .. }
+            | NodeData::CaseOrDefaultClause { statements, .. } => Some(statements),
+            _ => None,
+        }
+    }
+
+    // The text of identifiers, literals, JSX text and JSDoc text.
+    pub fn text(&self) -> Option<&str> {
+        match &self.data {
+            NodeData::Identifier { text, .. } | NodeData::PrivateIdentifier { text } => Some(text),
+            NodeData::Literal { text, .. }
+            | NodeData::JsxText { text, .. }
+            | NodeData::JSDocText { text } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+// The text of a JSDoc comment or tag: plain text, or text interleaved with `{@link}` nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JSDocCommentText {
+    Text(String),
+    Nodes(NodeArray),
+}
+
+// The children and properties of a node. Several kinds share a variant when they have the same
+// shape; the doc comment of each variant lists the kinds that use it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeData {
+    /// Tokens, keywords and the nodes without children, like `EmptyStatement` or `ThisType`.
+    Token,
+    Identifier {
+        text: Atom,
+        // The keyword the identifier was scanned as, so that we can report appropriate errors
+        // later in the type checker.
+        original_keyword_kind: Option<KeywordSyntaxKind>,
+        // If the node is a member in a JSDoc namespace
+        is_in_jsdoc_namespace: bool,
+        // Set when the identifier occurs in a JSDoc-style generic: `Id.<T>`
+        jsdoc_dot_pos: Option<usize>,
+    },
+    PrivateIdentifier {
+        text: Atom,
+    },
+    /// Numeric, bigint, string and regular expression literals and the template literal
+    /// pieces (`NoSubstitutionTemplateLiteral`, `TemplateHead`, `TemplateMiddle`, `TemplateTail`).
+    Literal {
+        text: String,
+        // The raw text of template pieces, between the delimiters.
+        raw_text: Option<String>,
+        // The numeric literal flags of numbers and `ContainsInvalidEscape` for templates.
+        token_flags: u32,
+        is_unterminated: bool,
+        has_extended_unicode_escape: bool,
+    },
+    JsxText {
+        text: String,
+        contains_only_trivia_white_spaces: bool,
+    },
+    /// `QualifiedName` and `JSDocMemberName`
+    QualifiedName {
+        left: NodeId,
+        right: NodeId,
+        jsdoc_dot_pos: Option<usize>,
+    },
+    /// `ComputedPropertyName`, `Decorator`, `SpreadAssignment`, `SpreadElement`,
+    /// `ParenthesizedExpression`, `DeleteExpression`, `TypeOfExpression`, `VoidExpression`,
+    /// `AwaitExpression`, `NonNullExpression`, `ExpressionStatement`, `ThrowStatement`,
+    /// `ExternalModuleReference` and `JsxSpreadAttribute`
+    Expression {
+        expression: NodeId,
+    },
+    TypeParameter {
+        name: NodeId,
+        constraint: Option<NodeId>,
+        default: Option<NodeId>,
+        // Used for error recovery, when the constraint is an expression instead of a type.
+        expression: Option<NodeId>,
+    },
+    Parameter {
+        dot_dot_dot_token: Option<NodeId>,
+        // Only parameters of JSDoc function types may have no name.
+        name: Option<NodeId>,
+        question_token: Option<NodeId>,
+        _type: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    /// `PropertySignature`, `PropertyDeclaration`, `PropertyAssignment` and
+    /// `VariableDeclaration`
+    VariableLike {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        _type: Option<NodeId>,
+        initializer: Option<NodeId>,
+    },
+    ShorthandPropertyAssignment {
+        name: NodeId,
+        question_token: Option<NodeId>,
+        exclamation_token: Option<NodeId>,
+        // used when ObjectLiteralExpression is used in ObjectAssignmentPattern
+        // it is a grammar error to appear in actual object initializer:
+        equals_token: Option<NodeId>,
+        object_assignment_initializer: Option<NodeId>,
+    },
+    BindingElement {
+        dot_dot_dot_token: Option<NodeId>,
+        property_name: Option<NodeId>,
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+    /// Every signature and function-like declaration: `FunctionType`, `ConstructorType`,
+    /// `CallSignature`, `ConstructSignature`, `IndexSignature`, `MethodSignature`,
+    /// `MethodDeclaration`, `Constructor`, `GetAccessor`, `SetAccessor`, `FunctionExpression`,
+    /// `FunctionDeclaration`, `ArrowFunction` and `JSDocFunctionType`
+    SignatureDeclaration(Box<SignatureDeclaration>),
+    TypeReference {
+        type_name: NodeId,
+        type_arguments: Option<NodeArray>,
+    },
+    TypePredicate {
+        asserts_modifier: Option<NodeId>,
+        parameter_name: NodeId,
+        _type: Option<NodeId>,
+    },
+    TypeQuery {
+        expr_name: NodeId,
+    },
+    TypeLiteral {
+        members: NodeArray,
+    },
+    ArrayType {
+        element_type: NodeId,
+    },
+    TupleType {
+        elements: NodeArray,
+    },
+    /// `UnionType` and `IntersectionType`
+    UnionOrIntersectionType {
+        types: NodeArray,
+    },
+    ConditionalType {
+        check_type: NodeId,
+        extends_type: NodeId,
+        true_type: NodeId,
+        false_type: NodeId,
+    },
+    InferType {
+        type_parameter: NodeId,
+    },
+    ImportType {
+        is_type_of: bool,
+        argument: NodeId,
+        qualifier: Option<NodeId>,
+        type_arguments: Option<NodeArray>,
+    },
+    TypeOperator {
+        operator: SyntaxKind,
+        _type: NodeId,
+    },
+    /// `ParenthesizedType`, `OptionalType`, `RestType`, `JSDocTypeExpression`,
+    /// `JSDocNullableType`, `JSDocNonNullableType`, `JSDocOptionalType`, `JSDocVariadicType`
+    /// and `JSDocNamepathType` (the latter never has a type)
+    Type {
+        _type: Option<NodeId>,
+    },
+    IndexedAccessType {
+        object_type: NodeId,
+        index_type: NodeId,
+    },
+    MappedType {
+        readonly_token: Option<NodeId>,
+        type_parameter: NodeId,
+        name_type: Option<NodeId>,
+        question_token: Option<NodeId>,
+        _type: Option<NodeId>,
+    },
+    LiteralType {
+        literal: NodeId,
+    },
+    NamedTupleMember {
+        dot_dot_dot_token: Option<NodeId>,
+        name: NodeId,
+        question_token: Option<NodeId>,
+        _type: NodeId,
+    },
+    /// `ObjectBindingPattern` and `ArrayBindingPattern`
+    BindingPattern {
+        elements: NodeArray,
+    },
+    ArrayLiteralExpression {
+        elements: NodeArray,
+        multi_line: bool,
+    },
+    ObjectLiteralExpression {
+        properties: NodeArray,
+        multi_line: bool,
+    },
+    PropertyAccessExpression {
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+        name: NodeId,
+    },
+    ElementAccessExpression {
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+        argument_expression: NodeId,
+    },
+    /// `CallExpression` and `NewExpression`
+    CallExpression {
+        expression: NodeId,
+        question_dot_token: Option<NodeId>,
+        type_arguments: Option<NodeArray>,
+        // `None` for `new` expressions without an argument list.
+        arguments: Option<NodeArray>,
+    },
+    TaggedTemplateExpression {
+        tag: NodeId,
+        question_dot_token: Option<NodeId>,
+        type_arguments: Option<NodeArray>,
+        template: NodeId,
+    },
+    TypeAssertion {
+        _type: NodeId,
+        expression: NodeId,
+    },
+    /// `PrefixUnaryExpression` and `PostfixUnaryExpression`
+    UnaryExpression {
+        operator: SyntaxKind,
+        operand: NodeId,
+    },
+    YieldExpression {
+        asterisk_token: Option<NodeId>,
+        expression: Option<NodeId>,
+    },
+    BinaryExpression {
+        left: NodeId,
+        operator_token: NodeId,
+        right: NodeId,
+    },
+    AsExpression {
+        expression: NodeId,
+        _type: NodeId,
+    },
+    MetaProperty {
+        keyword_token: SyntaxKind,
+        name: NodeId,
+    },
+    ConditionalExpression {
+        condition: NodeId,
+        question_token: NodeId,
+        when_true: NodeId,
+        colon_token: NodeId,
+        when_false: NodeId,
+    },
+    /// `TemplateExpression` and `TemplateLiteralType`
+    TemplateExpression {
+        head: NodeId,
+        template_spans: NodeArray,
+    },
+    TemplateSpan {
+        expression: NodeId,
+        literal: NodeId,
+    },
+    TemplateLiteralTypeSpan {
+        _type: NodeId,
+        literal: NodeId,
+    },
+    /// `Block` and `ModuleBlock`
+    Block {
+        statements: NodeArray,
+        multi_line: bool,
+    },
+    SourceFile {
+        statements: NodeArray,
+        end_of_file_token: NodeId,
+    },
+    VariableStatement {
+        declaration_list: NodeId,
+    },
+    VariableDeclarationList {
+        declarations: NodeArray,
+    },
+    IfStatement {
+        expression: NodeId,
+        then_statement: NodeId,
+        else_statement: Option<NodeId>,
+    },
+    DoStatement {
+        statement: NodeId,
+        expression: NodeId,
+    },
+    /// `WhileStatement` and `WithStatement`
+    WhileOrWithStatement {
+        expression: NodeId,
+        statement: NodeId,
+    },
+    ForStatement {
+        initializer: Option<NodeId>,
+        condition: Option<NodeId>,
+        incrementor: Option<NodeId>,
+        statement: NodeId,
+    },
+    /// `ForInStatement` and `ForOfStatement`
+    ForInOrOfStatement {
+        await_modifier: Option<NodeId>,
+        initializer: NodeId,
+        expression: NodeId,
+        statement: NodeId,
+    },
+    /// `BreakStatement` and `ContinueStatement`
+    BreakOrContinueStatement {
+        label: Option<NodeId>,
+    },
+    ReturnStatement {
+        expression: Option<NodeId>,
+    },
+    SwitchStatement {
+        expression: NodeId,
+        case_block: NodeId,
+    },
+    CaseBlock {
+        clauses: NodeArray,
+    },
+    /// `CaseClause` and `DefaultClause`
+    CaseOrDefaultClause {
+        expression: Option<NodeId>,
+        statements: NodeArray,
+    },
+    LabeledStatement {
+        label: NodeId,
+        statement: NodeId,
+    },
+    TryStatement {
+        try_block: NodeId,
+        catch_clause: Option<NodeId>,
+        finally_block: Option<NodeId>,
+    },
+    CatchClause {
+        variable_declaration: Option<NodeId>,
+        block: NodeId,
+    },
+    /// `ClassDeclaration` and `ClassExpression`
+    ClassLikeDeclaration {
+        name: Option<NodeId>,
+        type_parameters: Option<NodeArray>,
+        heritage_clauses: Option<NodeArray>,
+        members: NodeArray,
+    },
+    InterfaceDeclaration {
+        name: NodeId,
+        type_parameters: Option<NodeArray>,
+        heritage_clauses: Option<NodeArray>,
+        members: NodeArray,
+    },
+    HeritageClause {
+        token: SyntaxKind,
+        types: NodeArray,
+    },
+    ExpressionWithTypeArguments {
+        expression: NodeId,
+        type_arguments: Option<NodeArray>,
+    },
+    TypeAliasDeclaration {
+        name: NodeId,
+        type_parameters: Option<NodeArray>,
+        _type: NodeId,
+    },
+    EnumDeclaration {
+        name: NodeId,
+        members: NodeArray,
+    },
+    EnumMember {
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+    ModuleDeclaration {
+        name: NodeId,
+        body: Option<NodeId>,
+    },
+    ImportEqualsDeclaration {
+        is_type_only: bool,
+        name: NodeId,
+        module_reference: NodeId,
+    },
+    ImportDeclaration {
+        import_clause: Option<NodeId>,
+        module_specifier: NodeId,
+    },
+    ImportClause {
+        is_type_only: bool,
+        name: Option<NodeId>,
+        named_bindings: Option<NodeId>,
+    },
+    /// `NamespaceImport`, `NamespaceExport` and `NamespaceExportDeclaration`
+    NamespaceImportOrExport {
+        name: NodeId,
+    },
+    /// `NamedImports` and `NamedExports`
+    NamedImportsOrExports {
+        elements: NodeArray,
+    },
+    /// `ImportSpecifier` and `ExportSpecifier`
+    ImportOrExportSpecifier {
+        property_name: Option<NodeId>,
+        name: NodeId,
+    },
+    ExportDeclaration {
+        is_type_only: bool,
+        export_clause: Option<NodeId>,
+        module_specifier: Option<NodeId>,
+    },
+    ExportAssignment {
+        is_export_equals: bool,
+        expression: NodeId,
+    },
+    JsxElement {
+        opening_element: NodeId,
+        children: NodeArray,
+        closing_element: NodeId,
+    },
+    JsxFragment {
+        opening_fragment: NodeId,
+        children: NodeArray,
+        closing_fragment: NodeId,
+    },
+    /// `JsxSelfClosingElement` and `JsxOpeningElement`
+    JsxOpeningLikeElement {
+        tag_name: NodeId,
+        type_arguments: Option<NodeArray>,
+        attributes: NodeId,
+    },
+    JsxClosingElement {
+        tag_name: NodeId,
+    },
+    JsxAttributes {
+        properties: NodeArray,
+    },
+    JsxAttribute {
+        name: NodeId,
+        initializer: Option<NodeId>,
+    },
+    JsxExpression {
+        dot_dot_dot_token: Option<NodeId>,
+        expression: Option<NodeId>,
+    },
+    JSDoc {
+        comment: Option<JSDocCommentText>,
+        tags: Option<NodeArray>,
+    },
+    JSDocText {
+        text: String,
+    },
+    /// `JSDocLink`, `JSDocLinkCode` and `JSDocLinkPlain`
+    JSDocLink {
+        name: Option<NodeId>,
+        text: String,
+    },
+    JSDocNameReference {
+        name: NodeId,
+    },
+    /// Every JSDoc tag kind, from the plain `JSDocTag` to `JSDocPropertyTag`
+    JSDocTag(Box<JSDocTag>),
+    JSDocTypeLiteral {
+        js_doc_property_tags: Option<Vec<NodeId>>,
+        // If true, then this type literal represents an *array* of its type.
+        is_array_type: bool,
+    },
+    JSDocSignature {
+        type_parameters: Option<Vec<NodeId>>,
+        parameters: Vec<NodeId>,
+        _type: Option<NodeId>,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SignatureDeclaration {
+    pub asterisk_token: Option<NodeId>,
+    pub name: Option<NodeId>,
+    pub question_token: Option<NodeId>,
+    pub exclamation_token: Option<NodeId>,
+    pub type_parameters: Option<NodeArray>,
+    pub parameters: NodeArray,
+    pub _type: Option<NodeId>,
+    pub equals_greater_than_token: Option<NodeId>,
+    pub body: Option<NodeId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JSDocTag {
+    pub tag_name: NodeId,
+    pub comment: Option<JSDocCommentText>,
+    // The type of `@param`, `@property`, `@returns`, `@type`, `@this`, `@enum`, `@typedef` and
+    // `@callback` tags.
+    pub type_expression: Option<NodeId>,
+    // The parameter or property name of `@param` and `@property`, the reference of `@see` and
+    // the declared name of `@typedef` and `@callback`.
+    pub name: Option<NodeId>,
+    // The full (possibly namespaced) name of `@typedef` and `@callback`.
+    pub full_name: Option<NodeId>,
+    // The class of `@augments` and `@implements`.
+    pub class: Option<NodeId>,
+    pub constraint: Option<NodeId>,
+    pub type_parameters: Option<NodeArray>,
+    // Whether the property name came before the type -- non-standard for JSDoc, but
+    // Typescript-like. Also set on a `@typedef` whose type is a `JSDocTypeLiteral` (or missing),
+    // since the name then precedes the type in the source.
+    pub is_name_first: bool,
+    pub is_bracketed: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommentRange {
+    pub range: TextRange,
+    // `SingleLineCommentTrivia` or `MultiLineCommentTrivia`
+    pub kind: TriviaSyntaxKind,
+    pub has_trailing_new_line: bool,
+}
+
+// An argument of a pragma, with the span of its value for the arguments that capture one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PragmaArgument {
+    pub value: String,
+    pub range: Option<TextRange>,
+}
+
+// A pragma of the leading comments of a file, like the entries of the `pragmas` map of the JS
+// parser: `/// <reference path="..." />`, `// @ts-check`, `/** @jsx h */` and the like.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pragma {
+    pub name: String,
+    pub arguments: BTreeMap<&'static str, PragmaArgument>,
+    pub range: CommentRange,
+}
+
+impl Pragma {
+    pub fn argument(&self, name: &str) -> Option<&PragmaArgument> {
+        self.arguments.get(name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileReference {
+    pub range: TextRange,
+    pub file_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmdDependency {
+    pub path: String,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckJsDirective {
+    pub range: TextRange,
+    pub enabled: bool,
+}
+
+// A parsed source file. The nodes live in `nodes` and refer to each other by `NodeId`; `root`
+// is the `SourceFile` node itself.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceFile {
+    pub file_name: String,
+    pub text: String,
+    pub nodes: Vec<Node>,
+    pub root: NodeId,
+    // The length of `nodes` after the last full parse. Incremental parses append to `nodes` and
+    // leave the old nodes they didn't reuse behind, so this bounds how much of it is garbage.
+    pub full_parse_nodes_length: usize,
+    // The table the texts of the identifiers are interned in, which may be shared with other
+    // files. An incremental parse keeps interning in it.
+    pub atoms: Arc<AtomTable>,
+    pub language_version: ScriptTarget,
+    pub language_variant: LanguageVariant,
+    pub script_kind: ScriptKind,
+    pub is_declaration_file: bool,
+    pub has_no_default_lib: bool,
+    // The first `import`/`export` (or `import.meta`) that makes the file a module.
+    pub external_module_indicator: Option<NodeId>,
+    pub node_count: usize,
+    pub identifier_count: usize,
+    // The texts of the identifiers, and of the string and numeric literals naming properties and
+    // modules, like the `identifiers` map of the JS parser. An incremental parse only has those
+    // of the nodes it parsed again.
+    pub identifiers: BTreeSet<String>,
+    pub parse_diagnostics: Vec<DiagnosticWithLocation>,
+    pub js_doc_diagnostics: Vec<DiagnosticWithLocation>,
+    pub comment_directives: Option<Vec<CommentDirective>>,
+    pub referenced_files: Vec<FileReference>,
+    pub type_reference_directives: Vec<FileReference>,
+    pub lib_reference_directives: Vec<FileReference>,
+    pub amd_dependencies: Vec<AmdDependency>,
+    pub module_name: Option<String>,
+    pub check_js_directive: Option<CheckJsDirective>,
+    pub pragmas: Vec<Pragma>,
+}
+
+impl SourceFile {
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.index()]
+    }
+
+    pub fn statements(&self) -> &NodeArray {
+        match &self.node(self.root).data {
+            NodeData::SourceFile { statements, .. } => statements,
+            _ => unreachable!("the root of a source file is a SourceFile node"),
+        }
+    }
+
+    pub fn end_of_file_token(&self) -> NodeId {
+        match &self.node(self.root).data {
+            NodeData::SourceFile {
+                end_of_file_token, ..
+            } => *end_of_file_token,
+            _ => unreachable!("the root of a source file is a SourceFile node"),
+        }
+    }
+
+    // See also `isExternalOrCommonJsModule` in the JS compiler
+    pub fn is_external_module(&self) -> bool {
+        self.external_module_indicator.is_some()
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    // The parent of the node, its parent and so on up to the `SourceFile` node.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.parent(id), move |&parent| self.parent(parent))
+    }
+}
+
+// The tree only refers to nodes by index, so a parsed file can be handed to another thread or
+// shared between threads as is.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SourceFile>();
+};
+
+#[allow(non_snake_case)]
 pub mod CharacterCodes {
     pub const NULL_CHARACTER: u32 = 0;
     pub const MAX_ASCII_CHARACTER: u32 = 0x7F;
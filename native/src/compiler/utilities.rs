@@ -0,0 +1,1470 @@
+use std::borrow::Cow;
+
+use super::atom::escape_leading_underscores;
+use super::diagnostic_information_map::Diagnostics;
+use super::path::file_extension_is;
+use super::scanner::{get_leading_comment_ranges, get_trailing_comment_ranges, skip_trivia};
+use super::types::{
+    AssignmentDeclarationKind, CharacterCodes, CommentRange, Diagnostic, DiagnosticWithLocation,
+    IDiagnosticMessage, KeywordSyntaxKind, LanguageVariant, LiteralSyntaxKind, ModifierFlags,
+    ModifierSyntaxKind, Node, NodeData, NodeFlags, NodeId, PunctuationSyntaxKind, ScriptKind,
+    SourceFile, SyntaxKind,
+};
+
+pub fn format_string_from_args(text: &str, args: &[&str]) -> String {
+    let mut result = text.to_string();
+    for (index, arg) in args.iter().enumerate() {
+        result = result.replace(&format!("{{{}}}", index), arg);
+    }
+    result
+}
+
+pub fn create_file_diagnostic(
+    file_name: &str,
+    start: usize,
+    length: usize,
+    message: &IDiagnosticMessage,
+    args: &[&str],
+) -> DiagnosticWithLocation {
+    DiagnosticWithLocation {
+        file_name: file_name.to_string(),
+        start,
+        length,
+        message_text: format_string_from_args(&message.message, args),
+        category: message.category,
+        code: message.code,
+        reports_unnecessary: message.reports_unnecessary,
+        reports_deprecated: message.reports_deprecated,
+    }
+}
+
+pub fn create_compiler_diagnostic(message: &IDiagnosticMessage, args: &[&str]) -> Diagnostic {
+    Diagnostic {
+        file_name: None,
+        start: None,
+        length: None,
+        message_text: format_string_from_args(&message.message, args),
+        category: message.category,
+        code: message.code,
+        reports_unnecessary: message.reports_unnecessary,
+        reports_deprecated: message.reports_deprecated,
+    }
+}
+
+pub fn create_cannot_read_file_diagnostic(file_name: &str, error: &std::io::Error) -> Diagnostic {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        create_compiler_diagnostic(&Diagnostics::Cannot_read_file_0, &[file_name])
+    } else {
+        create_compiler_diagnostic(
+            &Diagnostics::Cannot_read_file_0_Colon_1,
+            &[file_name, &error.to_string()],
+        )
+    }
+}
+
+// True for the kinds of nodes, from `QualifiedName` on, as opposed to tokens.
+pub fn is_node_kind(kind: SyntaxKind) -> bool {
+    kind.js_value() >= SyntaxKind::QualifiedName.js_value()
+}
+
+/// True if kind is of some token syntax kind.
+/// For example, this is true for an IfKeyword but not for an IfStatement.
+/// Literals are considered tokens, except TemplateLiteral, but does include TemplateHead/Middle/Tail.
+pub fn is_token_kind(kind: SyntaxKind) -> bool {
+    !is_node_kind(kind)
+}
+
+pub fn is_this_identifier(node: &Node) -> bool {
+    matches!(
+        node.data,
+        NodeData::Identifier {
+            original_keyword_kind: Some(KeywordSyntaxKind::ThisKeyword),
+            ..
+        }
+    )
+}
+
+pub fn is_keyword(token: SyntaxKind) -> bool {
+    matches!(token, SyntaxKind::Keywords(_))
+}
+
+pub fn is_modifier_kind(token: SyntaxKind) -> bool {
+    matches!(token, SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(_)))
+}
+
+pub fn is_parameter_property_modifier(kind: SyntaxKind) -> bool {
+    match kind {
+        SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(modifier)) => matches!(
+            modifier,
+            ModifierSyntaxKind::PublicKeyword
+                | ModifierSyntaxKind::PrivateKeyword
+                | ModifierSyntaxKind::ProtectedKeyword
+                | ModifierSyntaxKind::ReadonlyKeyword
+                | ModifierSyntaxKind::OverrideKeyword
+        ),
+        _ => false,
+    }
+}
+
+pub fn is_class_member_modifier(kind: SyntaxKind) -> bool {
+    is_parameter_property_modifier(kind)
+        || kind == SyntaxKind::StaticKeyword
+        || kind == SyntaxKind::OverrideKeyword
+}
+
+pub fn is_literal_kind(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::Literals(_))
+}
+
+pub fn is_template_literal_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral)
+            | SyntaxKind::PseudoLiterals(_)
+    )
+}
+
+pub fn is_assignment_operator(token: SyntaxKind) -> bool {
+    match token {
+        SyntaxKind::Punctuations(punctuation) => matches!(
+            punctuation,
+            PunctuationSyntaxKind::EqualsToken
+                | PunctuationSyntaxKind::PlusEqualsToken
+                | PunctuationSyntaxKind::MinusEqualsToken
+                | PunctuationSyntaxKind::AsteriskEqualsToken
+                | PunctuationSyntaxKind::AsteriskAsteriskEqualsToken
+                | PunctuationSyntaxKind::SlashEqualsToken
+                | PunctuationSyntaxKind::PercentEqualsToken
+                | PunctuationSyntaxKind::LessThanLessThanEqualsToken
+                | PunctuationSyntaxKind::GreaterThanGreaterThanEqualsToken
+                | PunctuationSyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken
+                | PunctuationSyntaxKind::AmpersandEqualsToken
+                | PunctuationSyntaxKind::BarEqualsToken
+                | PunctuationSyntaxKind::BarBarEqualsToken
+                | PunctuationSyntaxKind::AmpersandAmpersandEqualsToken
+                | PunctuationSyntaxKind::QuestionQuestionEqualsToken
+                | PunctuationSyntaxKind::CaretEqualsToken
+        ),
+        _ => false,
+    }
+}
+
+pub fn is_left_hand_side_expression_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PropertyAccessExpression
+            | SyntaxKind::ElementAccessExpression
+            | SyntaxKind::NewExpression
+            | SyntaxKind::CallExpression
+            | SyntaxKind::JsxElement
+            | SyntaxKind::JsxSelfClosingElement
+            | SyntaxKind::JsxFragment
+            | SyntaxKind::TaggedTemplateExpression
+            | SyntaxKind::ArrayLiteralExpression
+            | SyntaxKind::ParenthesizedExpression
+            | SyntaxKind::ObjectLiteralExpression
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::Identifier
+            | SyntaxKind::RegularExpressionLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::BigIntLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::TemplateExpression
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NonNullExpression
+            | SyntaxKind::MetaProperty
+            // technically this is only an Expression if it's in a CallExpression
+            | SyntaxKind::ImportKeyword
+    )
+}
+
+#[allow(non_snake_case)]
+pub mod OperatorPrecedence {
+    // Expression:
+    //     AssignmentExpression
+    //     Expression `,` AssignmentExpression
+    pub const COMMA: i32 = 0;
+    // NOTE: `Spread` is higher than `Comma` due to how it is parsed in |ElementList|
+    pub const SPREAD: i32 = 1;
+    pub const YIELD: i32 = 2;
+    pub const ASSIGNMENT: i32 = 3;
+    pub const CONDITIONAL: i32 = 4;
+    // NOTE: `Conditional` and `Coalesce` share a precedence, `??` only mixes with `||` and `&&`
+    // in parentheses
+    pub const COALESCE: i32 = CONDITIONAL;
+    pub const LOGICAL_OR: i32 = 5;
+    pub const LOGICAL_AND: i32 = 6;
+    pub const BITWISE_OR: i32 = 7;
+    pub const BITWISE_XOR: i32 = 8;
+    pub const BITWISE_AND: i32 = 9;
+    pub const EQUALITY: i32 = 10;
+    pub const RELATIONAL: i32 = 11;
+    pub const SHIFT: i32 = 12;
+    pub const ADDITIVE: i32 = 13;
+    pub const MULTIPLICATIVE: i32 = 14;
+    pub const EXPONENTIATION: i32 = 15;
+    pub const UNARY: i32 = 16;
+    pub const UPDATE: i32 = 17;
+    pub const LEFT_HAND_SIDE: i32 = 18;
+    pub const MEMBER: i32 = 19;
+    pub const PRIMARY: i32 = 20;
+    pub const HIGHEST: i32 = PRIMARY;
+    pub const LOWEST: i32 = COMMA;
+    // -1 is lower than all other precedences. Returning it will cause binary expression
+    // parsing to stop.
+    pub const INVALID: i32 = -1;
+}
+
+pub fn get_binary_operator_precedence(kind: SyntaxKind) -> i32 {
+    match kind {
+        SyntaxKind::QuestionQuestionToken => OperatorPrecedence::COALESCE,
+        SyntaxKind::BarBarToken => OperatorPrecedence::LOGICAL_OR,
+        SyntaxKind::AmpersandAmpersandToken => OperatorPrecedence::LOGICAL_AND,
+        SyntaxKind::BarToken => OperatorPrecedence::BITWISE_OR,
+        SyntaxKind::CaretToken => OperatorPrecedence::BITWISE_XOR,
+        SyntaxKind::AmpersandToken => OperatorPrecedence::BITWISE_AND,
+        SyntaxKind::EqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsToken
+        | SyntaxKind::EqualsEqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsEqualsToken => OperatorPrecedence::EQUALITY,
+        SyntaxKind::LessThanToken
+        | SyntaxKind::GreaterThanToken
+        | SyntaxKind::LessThanEqualsToken
+        | SyntaxKind::GreaterThanEqualsToken
+        | SyntaxKind::InstanceOfKeyword
+        | SyntaxKind::InKeyword
+        | SyntaxKind::AsKeyword => OperatorPrecedence::RELATIONAL,
+        SyntaxKind::LessThanLessThanToken
+        | SyntaxKind::GreaterThanGreaterThanToken
+        | SyntaxKind::GreaterThanGreaterThanGreaterThanToken => OperatorPrecedence::SHIFT,
+        SyntaxKind::PlusToken | SyntaxKind::MinusToken => OperatorPrecedence::ADDITIVE,
+        SyntaxKind::AsteriskToken | SyntaxKind::SlashToken | SyntaxKind::PercentToken => {
+            OperatorPrecedence::MULTIPLICATIVE
+        }
+        SyntaxKind::AsteriskAsteriskToken => OperatorPrecedence::EXPONENTIATION,
+        // -1 is lower than all other precedences.  Returning it will cause binary expression
+        // parsing to stop.
+        _ => OperatorPrecedence::INVALID,
+    }
+}
+
+pub fn get_language_variant(script_kind: ScriptKind) -> LanguageVariant {
+    // .tsx and .jsx files are treated as jsx language variant.
+    match script_kind {
+        ScriptKind::TSX | ScriptKind::JSX | ScriptKind::JS | ScriptKind::JSON => {
+            LanguageVariant::JSX
+        }
+        _ => LanguageVariant::Standard,
+    }
+}
+
+pub fn ensure_script_kind(file_name: &str, script_kind: Option<ScriptKind>) -> ScriptKind {
+    // If the 'scriptKind' is unspecified or `Unknown` then we attempt to get the ScriptKind from
+    // the file name. If it cannot be resolved from the file name then the default 'TS' script
+    // kind is returned.
+    match script_kind {
+        Some(script_kind) if script_kind != ScriptKind::Unknown => script_kind,
+        _ => match get_script_kind_from_file_name(file_name) {
+            ScriptKind::Unknown => ScriptKind::TS,
+            script_kind => script_kind,
+        },
+    }
+}
+
+pub fn get_script_kind_from_file_name(file_name: &str) -> ScriptKind {
+    let extension = file_name.rfind('.').map_or("", |index| &file_name[index..]);
+    match extension.to_lowercase().as_str() {
+        ".js" => ScriptKind::JS,
+        ".jsx" => ScriptKind::JSX,
+        ".ts" => ScriptKind::TS,
+        ".tsx" => ScriptKind::TSX,
+        ".json" => ScriptKind::JSON,
+        _ => ScriptKind::Unknown,
+    }
+}
+
+pub fn is_declaration_file_name(file_name: &str) -> bool {
+    file_extension_is(file_name, ".d.ts")
+}
+
+pub fn get_js_doc_comment_ranges(node: &Node, text: &[u16]) -> Vec<CommentRange> {
+    let comment_ranges = match node.kind {
+        SyntaxKind::Parameter
+        | SyntaxKind::TypeParameter
+        | SyntaxKind::FunctionExpression
+        | SyntaxKind::ArrowFunction
+        | SyntaxKind::ParenthesizedExpression
+        | SyntaxKind::VariableDeclaration => {
+            let mut ranges = get_trailing_comment_ranges(text, node.pos());
+            ranges.extend(get_leading_comment_ranges(text, node.pos()));
+            ranges
+        }
+        _ => get_leading_comment_ranges(text, node.pos()),
+    };
+    comment_ranges
+        .into_iter()
+        .filter(|comment| is_js_doc_like_text(text, comment.range.pos))
+        .collect()
+}
+
+// True if the comment starts with '/**' but not if it is '/**/'
+pub fn is_js_doc_like_text(text: &[u16], start: usize) -> bool {
+    let char_at = |index: usize| text.get(index).map(|&ch| u32::from(ch));
+    char_at(start + 1) == Some(CharacterCodes::ASTERISK)
+        && char_at(start + 2) == Some(CharacterCodes::ASTERISK)
+        && char_at(start + 3) != Some(CharacterCodes::SLASH)
+}
+
+// A node is missing when it has no text, like the identifiers the parser creates for error
+// recovery; the end of file token is the exception.
+pub fn node_is_missing(node: &Node) -> bool {
+    node.pos() == node.end() && node.kind != SyntaxKind::EndOfFileToken
+}
+
+// The start of the node after its leading trivia, like `getTokenPosOfNode` without `includeJsDoc`.
+pub fn get_token_pos_of_node(node: &Node, text: &[u16]) -> usize {
+    // With nodes that have no underlying tokens (ie. missing nodes), we just return the pos
+    // of the node.
+    if node_is_missing(node) {
+        return node.pos();
+    }
+
+    if is_js_doc_node_kind(node.kind) || node.kind == SyntaxKind::JsxText {
+        // JsxText cannot actually contain comments, even though the scanner will think it sees comments
+        return skip_trivia(text, node.pos(), false, true, false);
+    }
+
+    skip_trivia(
+        text,
+        node.pos(),
+        false,
+        false,
+        node.flags & NodeFlags::JSDOC != 0,
+    )
+}
+
+// True for the kinds from `FirstJSDocNode` to `LastJSDocNode`.
+pub fn is_js_doc_node_kind(kind: SyntaxKind) -> bool {
+    kind.js_value() >= SyntaxKind::JSDocTypeExpression.js_value()
+        && kind.js_value() <= SyntaxKind::JSDocPropertyTag.js_value()
+}
+
+pub fn is_declaration(source_file: &SourceFile, node: NodeId) -> bool {
+    let data = source_file.node(node);
+    if data.kind == SyntaxKind::TypeParameter {
+        let parent_kind = source_file
+            .parent(node)
+            .map(|parent| source_file.node(parent).kind);
+        return matches!(parent_kind, Some(kind) if kind != SyntaxKind::JSDocTemplateTag)
+            || data.flags & NodeFlags::JAVA_SCRIPT_FILE != 0;
+    }
+
+    is_declaration_kind(data.kind)
+}
+
+fn is_declaration_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ArrowFunction
+            | SyntaxKind::BindingElement
+            | SyntaxKind::ClassDeclaration
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::Constructor
+            | SyntaxKind::EnumDeclaration
+            | SyntaxKind::EnumMember
+            | SyntaxKind::ExportSpecifier
+            | SyntaxKind::FunctionDeclaration
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::GetAccessor
+            | SyntaxKind::ImportClause
+            | SyntaxKind::ImportEqualsDeclaration
+            | SyntaxKind::ImportSpecifier
+            | SyntaxKind::InterfaceDeclaration
+            | SyntaxKind::JsxAttribute
+            | SyntaxKind::MethodDeclaration
+            | SyntaxKind::MethodSignature
+            | SyntaxKind::ModuleDeclaration
+            | SyntaxKind::NamespaceExportDeclaration
+            | SyntaxKind::NamespaceImport
+            | SyntaxKind::NamespaceExport
+            | SyntaxKind::Parameter
+            | SyntaxKind::PropertyAssignment
+            | SyntaxKind::PropertyDeclaration
+            | SyntaxKind::PropertySignature
+            | SyntaxKind::SetAccessor
+            | SyntaxKind::ShorthandPropertyAssignment
+            | SyntaxKind::TypeAliasDeclaration
+            | SyntaxKind::TypeParameter
+            | SyntaxKind::VariableDeclaration
+            | SyntaxKind::JSDocTypedefTag
+            | SyntaxKind::JSDocCallbackTag
+            | SyntaxKind::JSDocPropertyTag
+    )
+}
+
+pub fn is_string_literal_like(node: &Node) -> bool {
+    node.kind == SyntaxKind::StringLiteral || node.kind == SyntaxKind::NoSubstitutionTemplateLiteral
+}
+
+pub fn is_string_or_numeric_literal_like(node: &Node) -> bool {
+    is_string_literal_like(node) || node.kind == SyntaxKind::NumericLiteral
+}
+
+pub fn is_signed_numeric_literal(source_file: &SourceFile, node: NodeId) -> bool {
+    let node = source_file.node(node);
+    node.kind == SyntaxKind::PrefixUnaryExpression
+        && matches!(
+            node.data,
+            NodeData::UnaryExpression { operator, operand }
+                if (operator == SyntaxKind::PlusToken || operator == SyntaxKind::MinusToken)
+                    && source_file.node(operand).kind == SyntaxKind::NumericLiteral
+        )
+}
+
+pub fn skip_parentheses(source_file: &SourceFile, node: NodeId) -> NodeId {
+    let mut node = node;
+    while source_file.node(node).kind == SyntaxKind::ParenthesizedExpression {
+        match source_file.node(node).expression() {
+            Some(expression) => node = expression,
+            None => break,
+        }
+    }
+    node
+}
+
+pub fn is_access_expression(node: &Node) -> bool {
+    node.kind == SyntaxKind::PropertyAccessExpression
+        || node.kind == SyntaxKind::ElementAccessExpression
+}
+
+pub fn is_binding_pattern(node: &Node) -> bool {
+    node.kind == SyntaxKind::ObjectBindingPattern || node.kind == SyntaxKind::ArrayBindingPattern
+}
+
+fn is_unary_expression_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PrefixUnaryExpression
+            | SyntaxKind::PostfixUnaryExpression
+            | SyntaxKind::DeleteExpression
+            | SyntaxKind::TypeOfExpression
+            | SyntaxKind::VoidExpression
+            | SyntaxKind::AwaitExpression
+            | SyntaxKind::TypeAssertionExpression
+    ) || is_left_hand_side_expression_kind(kind)
+}
+
+pub fn is_expression_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ConditionalExpression
+            | SyntaxKind::YieldExpression
+            | SyntaxKind::ArrowFunction
+            | SyntaxKind::BinaryExpression
+            | SyntaxKind::SpreadElement
+            | SyntaxKind::AsExpression
+            | SyntaxKind::OmittedExpression
+            | SyntaxKind::CommaListExpression
+            | SyntaxKind::PartiallyEmittedExpression
+    ) || is_unary_expression_kind(kind)
+}
+
+pub fn is_property_name_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Identifier
+            | SyntaxKind::PrivateIdentifier
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::ComputedPropertyName
+    )
+}
+
+// The escaped name a property name declares, if it is known without the checker.
+pub fn get_property_name_for_property_name_node(
+    source_file: &SourceFile,
+    name: NodeId,
+) -> Option<Cow<'_, str>> {
+    let node = source_file.node(name);
+    match &node.data {
+        NodeData::Identifier { text, .. } | NodeData::PrivateIdentifier { text } => {
+            Some(text.escaped_text())
+        }
+        NodeData::Literal { text, .. }
+            if node.kind == SyntaxKind::StringLiteral
+                || node.kind == SyntaxKind::NumericLiteral =>
+        {
+            Some(escape_leading_underscores(text))
+        }
+        NodeData::Expression { expression } if node.kind == SyntaxKind::ComputedPropertyName => {
+            let name_expression = source_file.node(*expression);
+            match &name_expression.data {
+                NodeData::Literal { text, .. }
+                    if is_string_or_numeric_literal_like(name_expression) =>
+                {
+                    Some(escape_leading_underscores(text))
+                }
+                NodeData::UnaryExpression { operator, operand }
+                    if is_signed_numeric_literal(source_file, *expression) =>
+                {
+                    let operand = source_file.node(*operand).text().unwrap_or_default();
+                    if *operator == SyntaxKind::MinusToken {
+                        Some(Cow::Owned(format!("-{}", operand)))
+                    } else {
+                        Some(Cow::Borrowed(operand))
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn is_dynamic_name(source_file: &SourceFile, name: NodeId) -> bool {
+    let node = source_file.node(name);
+    let expression = match &node.data {
+        NodeData::ElementAccessExpression {
+            argument_expression,
+            ..
+        } => skip_parentheses(source_file, *argument_expression),
+        NodeData::Expression { expression } if node.kind == SyntaxKind::ComputedPropertyName => {
+            *expression
+        }
+        _ => return false,
+    };
+    !is_string_or_numeric_literal_like(source_file.node(expression))
+        && !is_signed_numeric_literal(source_file, expression)
+}
+
+/// A declaration has a dynamic name if all of the following are true:
+///   1. The declaration has a computed property name.
+///   2. The computed name is *not* expressed as a StringLiteral.
+///   3. The computed name is *not* expressed as a NumericLiteral.
+///   4. The computed name is *not* expressed as a PlusToken or MinusToken
+///      immediately followed by a NumericLiteral.
+pub fn has_dynamic_name(source_file: &SourceFile, declaration: NodeId) -> bool {
+    matches!(get_name_of_declaration(source_file, declaration), Some(name) if is_dynamic_name(source_file, name))
+}
+
+pub fn get_text_of_identifier_or_literal(node: &Node) -> &str {
+    node.text().unwrap_or_default()
+}
+
+pub fn is_ambient_module(source_file: &SourceFile, node: NodeId) -> bool {
+    let node = source_file.node(node);
+    match &node.data {
+        NodeData::ModuleDeclaration { name, .. } => {
+            source_file.node(*name).kind == SyntaxKind::StringLiteral
+                || node.flags & NodeFlags::GLOBAL_AUGMENTATION != 0
+        }
+        _ => false,
+    }
+}
+
+pub fn is_entity_name_expression(source_file: &SourceFile, node: NodeId) -> bool {
+    let node = source_file.node(node);
+    match &node.data {
+        NodeData::Identifier { .. } => true,
+        NodeData::PropertyAccessExpression {
+            expression, name, ..
+        } => {
+            source_file.node(*name).kind == SyntaxKind::Identifier
+                && is_entity_name_expression(source_file, *expression)
+        }
+        _ => false,
+    }
+}
+
+fn is_identifier_with_text(node: &Node, text: &str) -> bool {
+    node.kind == SyntaxKind::Identifier && node.text() == Some(text)
+}
+
+pub fn is_literal_like_element_access(source_file: &SourceFile, node: NodeId) -> bool {
+    matches!(
+        &source_file.node(node).data,
+        NodeData::ElementAccessExpression { argument_expression, .. }
+            if is_string_or_numeric_literal_like(source_file.node(*argument_expression))
+    )
+}
+
+/// Any series of property and element accesses.
+pub fn is_bindable_static_access_expression(
+    source_file: &SourceFile,
+    node: NodeId,
+    exclude_this_keyword: bool,
+) -> bool {
+    match &source_file.node(node).data {
+        NodeData::PropertyAccessExpression {
+            expression, name, ..
+        } => {
+            (!exclude_this_keyword && source_file.node(*expression).kind == SyntaxKind::ThisKeyword)
+                || (source_file.node(*name).kind == SyntaxKind::Identifier
+                    && is_bindable_static_name_expression(source_file, *expression, true))
+        }
+        _ => is_bindable_static_element_access_expression(source_file, node, exclude_this_keyword),
+    }
+}
+
+/// Any series of property and element accesses, ending in a literal element access
+pub fn is_bindable_static_element_access_expression(
+    source_file: &SourceFile,
+    node: NodeId,
+    exclude_this_keyword: bool,
+) -> bool {
+    if !is_literal_like_element_access(source_file, node) {
+        return false;
+    }
+    let expression = match source_file.node(node).expression() {
+        Some(expression) => expression,
+        None => return false,
+    };
+    (!exclude_this_keyword && source_file.node(expression).kind == SyntaxKind::ThisKeyword)
+        || is_entity_name_expression(source_file, expression)
+        || is_bindable_static_access_expression(source_file, expression, true)
+}
+
+pub fn is_bindable_static_name_expression(
+    source_file: &SourceFile,
+    node: NodeId,
+    exclude_this_keyword: bool,
+) -> bool {
+    is_entity_name_expression(source_file, node)
+        || is_bindable_static_access_expression(source_file, node, exclude_this_keyword)
+}
+
+// `Object.defineProperty(x, "name", ...)` with a bindable `x`.
+pub fn is_bindable_object_define_property_call(source_file: &SourceFile, node: NodeId) -> bool {
+    let node = source_file.node(node);
+    let (expression, arguments) = match &node.data {
+        NodeData::CallExpression {
+            expression,
+            arguments: Some(arguments),
+            ..
+        } if node.kind == SyntaxKind::CallExpression => (*expression, arguments),
+        _ => return false,
+    };
+    arguments.len() == 3
+        && matches!(
+            &source_file.node(expression).data,
+            NodeData::PropertyAccessExpression { expression, name, .. }
+                if is_identifier_with_text(source_file.node(*expression), "Object")
+                    && source_file.node(*name).text() == Some("defineProperty")
+        )
+        && is_string_or_numeric_literal_like(source_file.node(arguments[1]))
+        && is_bindable_static_name_expression(source_file, arguments[0], true)
+}
+
+// The name of a property access, or the argument of an element access. An element access with
+// an argument that isn't a literal is returned itself.
+pub fn get_element_or_property_access_argument_expression_or_name(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Option<NodeId> {
+    match &source_file.node(node).data {
+        NodeData::PropertyAccessExpression { name, .. } => Some(*name),
+        NodeData::ElementAccessExpression {
+            argument_expression,
+            ..
+        } => {
+            let argument = skip_parentheses(source_file, *argument_expression);
+            if is_string_or_numeric_literal_like(source_file.node(argument)) {
+                Some(argument)
+            } else {
+                Some(node)
+            }
+        }
+        _ => None,
+    }
+}
+
+// The escaped name a property or literal element access accesses.
+pub fn get_element_or_property_access_name(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Option<Cow<'_, str>> {
+    let name = get_element_or_property_access_argument_expression_or_name(source_file, node)?;
+    let name = source_file.node(name);
+    match &name.data {
+        NodeData::Identifier { text, .. } => Some(text.escaped_text()),
+        NodeData::Literal { text, .. } if is_string_or_numeric_literal_like(name) => {
+            Some(escape_leading_underscores(text))
+        }
+        _ => None,
+    }
+}
+
+// The name of a property access, or the argument of a literal element access.
+pub fn get_name_or_argument(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    match &source_file.node(node).data {
+        NodeData::PropertyAccessExpression { name, .. } => Some(*name),
+        NodeData::ElementAccessExpression {
+            argument_expression,
+            ..
+        } => Some(*argument_expression),
+        _ => None,
+    }
+}
+
+fn is_exports_identifier(node: &Node) -> bool {
+    is_identifier_with_text(node, "exports")
+}
+
+fn is_module_identifier(node: &Node) -> bool {
+    is_identifier_with_text(node, "module")
+}
+
+pub fn is_module_exports_access_expression(source_file: &SourceFile, node: NodeId) -> bool {
+    let data = source_file.node(node);
+    (data.kind == SyntaxKind::PropertyAccessExpression
+        || is_literal_like_element_access(source_file, node))
+        && matches!(data.expression(), Some(expression) if is_module_identifier(source_file.node(expression)))
+        && get_element_or_property_access_name(source_file, node).as_deref() == Some("exports")
+}
+
+pub fn is_prototype_access(source_file: &SourceFile, node: NodeId) -> bool {
+    is_bindable_static_access_expression(source_file, node, false)
+        && get_element_or_property_access_name(source_file, node).as_deref() == Some("prototype")
+}
+
+fn is_equals_binary_expression(source_file: &SourceFile, node: NodeId) -> bool {
+    matches!(
+        &source_file.node(node).data,
+        NodeData::BinaryExpression { operator_token, .. }
+            if source_file.node(*operator_token).kind == SyntaxKind::EqualsToken
+    )
+}
+
+// `a = b = c` and so on, without compound assignments.
+fn is_simple_assignment_expression(source_file: &SourceFile, node: NodeId) -> bool {
+    matches!(
+        &source_file.node(node).data,
+        NodeData::BinaryExpression { left, .. }
+            if is_equals_binary_expression(source_file, node)
+                && is_left_hand_side_expression_kind(source_file.node(*left).kind)
+    )
+}
+
+pub fn get_right_most_assigned_expression(source_file: &SourceFile, node: NodeId) -> NodeId {
+    let mut node = node;
+    while is_simple_assignment_expression(source_file, node) {
+        match &source_file.node(node).data {
+            NodeData::BinaryExpression { right, .. } => node = *right,
+            _ => break,
+        }
+    }
+    node
+}
+
+pub fn get_initializer_of_binary_expression(source_file: &SourceFile, node: NodeId) -> NodeId {
+    let mut node = node;
+    loop {
+        match &source_file.node(node).data {
+            NodeData::BinaryExpression { right, .. }
+                if source_file.node(*right).kind == SyntaxKind::BinaryExpression =>
+            {
+                node = *right
+            }
+            NodeData::BinaryExpression { right, .. } => return *right,
+            _ => return node,
+        }
+    }
+}
+
+pub fn is_void_zero(source_file: &SourceFile, node: NodeId) -> bool {
+    let node = source_file.node(node);
+    node.kind == SyntaxKind::VoidExpression
+        && matches!(
+            node.expression().map(|expression| source_file.node(expression)),
+            Some(expression) if expression.kind == SyntaxKind::NumericLiteral && expression.text() == Some("0")
+        )
+}
+
+/// Given a BinaryExpression or a CallExpression, returns a special assignment declaration kind
+/// if it declares something. Only simple property assignments count outside of JS files.
+pub fn get_assignment_declaration_kind(
+    source_file: &SourceFile,
+    expression: NodeId,
+) -> AssignmentDeclarationKind {
+    let special = get_assignment_declaration_kind_worker(source_file, expression);
+    if special == AssignmentDeclarationKind::Property
+        || source_file.node(expression).flags & NodeFlags::JAVA_SCRIPT_FILE != 0
+    {
+        special
+    } else {
+        AssignmentDeclarationKind::None
+    }
+}
+
+fn get_assignment_declaration_kind_worker(
+    source_file: &SourceFile,
+    expression: NodeId,
+) -> AssignmentDeclarationKind {
+    let node = source_file.node(expression);
+    match &node.data {
+        NodeData::CallExpression { arguments, .. } if node.kind == SyntaxKind::CallExpression => {
+            if !is_bindable_object_define_property_call(source_file, expression) {
+                return AssignmentDeclarationKind::None;
+            }
+            let entity_name = arguments
+                .as_ref()
+                .map_or(expression, |arguments| arguments[0]);
+            if is_exports_identifier(source_file.node(entity_name))
+                || is_module_exports_access_expression(source_file, entity_name)
+            {
+                return AssignmentDeclarationKind::ObjectDefinePropertyExports;
+            }
+            if is_bindable_static_access_expression(source_file, entity_name, false)
+                && get_element_or_property_access_name(source_file, entity_name).as_deref()
+                    == Some("prototype")
+            {
+                return AssignmentDeclarationKind::ObjectDefinePrototypeProperty;
+            }
+            AssignmentDeclarationKind::ObjectDefinePropertyValue
+        }
+        NodeData::BinaryExpression {
+            left,
+            operator_token,
+            ..
+        } => {
+            if source_file.node(*operator_token).kind != SyntaxKind::EqualsToken
+                || !is_access_expression(source_file.node(*left))
+                || is_void_zero(
+                    source_file,
+                    get_right_most_assigned_expression(source_file, expression),
+                )
+            {
+                return AssignmentDeclarationKind::None;
+            }
+            if let Some(left_expression) = source_file.node(*left).expression() {
+                if is_bindable_static_name_expression(source_file, left_expression, true)
+                    && get_element_or_property_access_name(source_file, *left).as_deref()
+                        == Some("prototype")
+                    && source_file
+                        .node(get_initializer_of_binary_expression(
+                            source_file,
+                            expression,
+                        ))
+                        .kind
+                        == SyntaxKind::ObjectLiteralExpression
+                {
+                    // F.prototype = { ... }
+                    return AssignmentDeclarationKind::Prototype;
+                }
+            }
+            get_assignment_declaration_property_access_kind(source_file, *left)
+        }
+        _ => AssignmentDeclarationKind::None,
+    }
+}
+
+pub fn get_assignment_declaration_property_access_kind(
+    source_file: &SourceFile,
+    lhs: NodeId,
+) -> AssignmentDeclarationKind {
+    let expression = match source_file.node(lhs).expression() {
+        Some(expression) => expression,
+        None => return AssignmentDeclarationKind::None,
+    };
+    if source_file.node(expression).kind == SyntaxKind::ThisKeyword {
+        return AssignmentDeclarationKind::ThisProperty;
+    }
+    if is_module_exports_access_expression(source_file, lhs) {
+        // module.exports = expr
+        return AssignmentDeclarationKind::ModuleExports;
+    }
+    if is_bindable_static_name_expression(source_file, expression, true) {
+        if is_prototype_access(source_file, expression) {
+            // F.G....prototype.x = expr
+            return AssignmentDeclarationKind::PrototypeProperty;
+        }
+
+        let mut next_to_last = lhs;
+        let id = loop {
+            match source_file.node(next_to_last).expression() {
+                Some(expression) if source_file.node(expression).kind == SyntaxKind::Identifier => {
+                    break expression
+                }
+                Some(expression) => next_to_last = expression,
+                None => return AssignmentDeclarationKind::None,
+            }
+        };
+        let id = source_file.node(id);
+        if (is_exports_identifier(id)
+            // exports.name = expr OR module.exports.name = expr OR exports["name"] = expr ...
+            || is_module_identifier(id)
+                && get_element_or_property_access_name(source_file, next_to_last).as_deref() == Some("exports"))
+            // ...but for maximum compatibility, *only* for static names.
+            && is_bindable_static_access_expression(source_file, lhs, false)
+        {
+            return AssignmentDeclarationKind::ExportsProperty;
+        }
+        if is_bindable_static_name_expression(source_file, lhs, true)
+            || (source_file.node(lhs).kind == SyntaxKind::ElementAccessExpression
+                && is_dynamic_name(source_file, lhs))
+        {
+            // F.G...x = expr
+            return AssignmentDeclarationKind::Property;
+        }
+    }
+
+    AssignmentDeclarationKind::None
+}
+
+/// The name of a declaration, like `getNameOfDeclaration`: its `name`, the target of an
+/// assignment declaration, the name of a nameless `@typedef` from its host, or the name a
+/// function or class expression is assigned to.
+pub fn get_name_of_declaration(source_file: &SourceFile, declaration: NodeId) -> Option<NodeId> {
+    get_non_assigned_name_of_declaration(source_file, declaration).or_else(|| {
+        match source_file.node(declaration).kind {
+            SyntaxKind::FunctionExpression
+            | SyntaxKind::ArrowFunction
+            | SyntaxKind::ClassExpression => get_assigned_name(source_file, declaration),
+            _ => None,
+        }
+    })
+}
+
+pub fn get_non_assigned_name_of_declaration(
+    source_file: &SourceFile,
+    declaration: NodeId,
+) -> Option<NodeId> {
+    let node = source_file.node(declaration);
+    match node.kind {
+        SyntaxKind::Identifier => return Some(declaration),
+        SyntaxKind::JSDocPropertyTag | SyntaxKind::JSDocParameterTag => {
+            if let Some(name) = node.name() {
+                let name = source_file.node(name);
+                if let NodeData::QualifiedName { right, .. } = &name.data {
+                    if name.kind == SyntaxKind::QualifiedName {
+                        return Some(*right);
+                    }
+                }
+            }
+        }
+        SyntaxKind::CallExpression | SyntaxKind::BinaryExpression => {
+            return match get_assignment_declaration_kind(source_file, declaration) {
+                AssignmentDeclarationKind::ExportsProperty
+                | AssignmentDeclarationKind::ThisProperty
+                | AssignmentDeclarationKind::Property
+                | AssignmentDeclarationKind::PrototypeProperty => match &node.data {
+                    NodeData::BinaryExpression { left, .. } => {
+                        get_element_or_property_access_argument_expression_or_name(
+                            source_file,
+                            *left,
+                        )
+                    }
+                    _ => None,
+                },
+                AssignmentDeclarationKind::ObjectDefinePropertyValue
+                | AssignmentDeclarationKind::ObjectDefinePropertyExports
+                | AssignmentDeclarationKind::ObjectDefinePrototypeProperty => node
+                    .arguments()
+                    .and_then(|arguments| arguments.get(1).copied()),
+                _ => None,
+            };
+        }
+        SyntaxKind::JSDocTypedefTag => {
+            return node
+                .name()
+                .or_else(|| name_for_nameless_js_doc_typedef(source_file, declaration));
+        }
+        SyntaxKind::JSDocEnumTag => {
+            return name_for_nameless_js_doc_typedef(source_file, declaration)
+        }
+        SyntaxKind::ExportAssignment => {
+            return node
+                .expression()
+                .filter(|&expression| source_file.node(expression).kind == SyntaxKind::Identifier);
+        }
+        SyntaxKind::ElementAccessExpression
+            if is_bindable_static_element_access_expression(source_file, declaration, false) =>
+        {
+            if let NodeData::ElementAccessExpression {
+                argument_expression,
+                ..
+            } = &node.data
+            {
+                return Some(*argument_expression);
+            }
+        }
+        _ => {}
+    }
+    node.name()
+}
+
+fn get_assigned_name(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    let parent = source_file.parent(node)?;
+    let parent_node = source_file.node(parent);
+    match &parent_node.data {
+        _ if parent_node.kind == SyntaxKind::PropertyAssignment
+            || parent_node.kind == SyntaxKind::BindingElement =>
+        {
+            parent_node.name()
+        }
+        NodeData::BinaryExpression { left, right, .. } if *right == node => {
+            let left_node = source_file.node(*left);
+            if left_node.kind == SyntaxKind::Identifier {
+                Some(*left)
+            } else if is_access_expression(left_node) {
+                get_element_or_property_access_argument_expression_or_name(source_file, *left)
+            } else {
+                None
+            }
+        }
+        _ if parent_node.kind == SyntaxKind::VariableDeclaration => parent_node
+            .name()
+            .filter(|&name| source_file.node(name).kind == SyntaxKind::Identifier),
+        _ => None,
+    }
+}
+
+fn get_declaration_identifier(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    get_name_of_declaration(source_file, node)
+        .filter(|&name| source_file.node(name).kind == SyntaxKind::Identifier)
+}
+
+// The name of a `@typedef` or `@enum` tag without one: the name of the declaration the JSDoc
+// comment is on, or of the target of the assignment it is on.
+pub fn name_for_nameless_js_doc_typedef(
+    source_file: &SourceFile,
+    declaration: NodeId,
+) -> Option<NodeId> {
+    let host = source_file.parent(source_file.parent(declaration)?)?;
+    if is_declaration(source_file, host) {
+        return get_declaration_identifier(source_file, host);
+    }
+    let host_node = source_file.node(host);
+    match &host_node.data {
+        NodeData::VariableStatement { declaration_list } => {
+            match &source_file.node(*declaration_list).data {
+                NodeData::VariableDeclarationList { declarations } => {
+                    get_declaration_identifier(source_file, *declarations.first()?)
+                }
+                _ => None,
+            }
+        }
+        NodeData::Expression { expression }
+            if host_node.kind == SyntaxKind::ExpressionStatement =>
+        {
+            let mut expression = *expression;
+            if is_equals_binary_expression(source_file, expression) {
+                if let NodeData::BinaryExpression { left, .. } = &source_file.node(expression).data
+                {
+                    expression = *left;
+                }
+            }
+            match &source_file.node(expression).data {
+                NodeData::PropertyAccessExpression { name, .. } => Some(*name),
+                NodeData::ElementAccessExpression {
+                    argument_expression,
+                    ..
+                } if source_file.node(*argument_expression).kind == SyntaxKind::Identifier => {
+                    Some(*argument_expression)
+                }
+                _ => None,
+            }
+        }
+        NodeData::Expression { expression }
+            if host_node.kind == SyntaxKind::ParenthesizedExpression =>
+        {
+            get_declaration_identifier(source_file, *expression)
+        }
+        NodeData::LabeledStatement { statement, .. }
+            if is_declaration(source_file, *statement)
+                || is_expression_kind(source_file.node(*statement).kind) =>
+        {
+            get_declaration_identifier(source_file, *statement)
+        }
+        _ => None,
+    }
+}
+
+pub fn modifier_to_flag(token: SyntaxKind) -> u32 {
+    match token {
+        SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(modifier)) => match modifier {
+            ModifierSyntaxKind::StaticKeyword => ModifierFlags::STATIC,
+            ModifierSyntaxKind::PublicKeyword => ModifierFlags::PUBLIC,
+            ModifierSyntaxKind::ProtectedKeyword => ModifierFlags::PROTECTED,
+            ModifierSyntaxKind::PrivateKeyword => ModifierFlags::PRIVATE,
+            ModifierSyntaxKind::AbstractKeyword => ModifierFlags::ABSTRACT,
+            ModifierSyntaxKind::ExportKeyword => ModifierFlags::EXPORT,
+            ModifierSyntaxKind::DeclareKeyword => ModifierFlags::AMBIENT,
+            ModifierSyntaxKind::ConstKeyword => ModifierFlags::CONST,
+            ModifierSyntaxKind::DefaultKeyword => ModifierFlags::DEFAULT,
+            ModifierSyntaxKind::AsyncKeyword => ModifierFlags::ASYNC,
+            ModifierSyntaxKind::ReadonlyKeyword => ModifierFlags::READONLY,
+            ModifierSyntaxKind::OverrideKeyword => ModifierFlags::OVERRIDE,
+        },
+        _ => ModifierFlags::NONE,
+    }
+}
+
+/// The ModifierFlags for the syntactic modifiers on the node, which don't include the modifiers
+/// from JSDoc.
+pub fn get_syntactic_modifier_flags(source_file: &SourceFile, node: NodeId) -> u32 {
+    let data = source_file.node(node);
+    if is_token_kind(data.kind) {
+        return ModifierFlags::NONE;
+    }
+
+    let mut flags = data
+        .modifiers
+        .iter()
+        .flat_map(|modifiers| modifiers.iter())
+        .fold(ModifierFlags::NONE, |flags, &modifier| {
+            flags | modifier_to_flag(source_file.node(modifier).kind)
+        });
+    if data.flags & NodeFlags::NESTED_NAMESPACE != 0 {
+        flags |= ModifierFlags::EXPORT;
+    }
+    flags
+}
+
+pub fn has_syntactic_modifier(source_file: &SourceFile, node: NodeId, flags: u32) -> bool {
+    get_syntactic_modifier_flags(source_file, node) & flags != 0
+}
+
+/// The ModifierFlags for the node including the ones of its JSDoc tags, like `@deprecated`, even
+/// outside of JS files.
+pub fn get_effective_modifier_flags_always_include_js_doc(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> u32 {
+    let data = source_file.node(node);
+    if is_token_kind(data.kind) {
+        return ModifierFlags::NONE;
+    }
+
+    let mut flags = get_syntactic_modifier_flags(source_file, node);
+    if data.parent.is_some() {
+        flags |= get_js_doc_modifier_flags(source_file, node);
+    }
+    flags
+}
+
+fn get_js_doc_modifier_flags(source_file: &SourceFile, node: NodeId) -> u32 {
+    let data = source_file.node(node);
+    if data.parent.is_none() || data.kind == SyntaxKind::Parameter {
+        return ModifierFlags::NONE;
+    }
+
+    let is_in_js_file = data.flags & NodeFlags::JAVA_SCRIPT_FILE != 0;
+    get_js_doc_tags(source_file, node)
+        .into_iter()
+        .fold(ModifierFlags::NONE, |flags, tag| {
+            flags
+                | match source_file.node(tag).kind {
+                    SyntaxKind::JSDocPublicTag if is_in_js_file => ModifierFlags::PUBLIC,
+                    SyntaxKind::JSDocPrivateTag if is_in_js_file => ModifierFlags::PRIVATE,
+                    SyntaxKind::JSDocProtectedTag if is_in_js_file => ModifierFlags::PROTECTED,
+                    SyntaxKind::JSDocReadonlyTag if is_in_js_file => ModifierFlags::READONLY,
+                    SyntaxKind::JSDocOverrideTag if is_in_js_file => ModifierFlags::OVERRIDE,
+                    SyntaxKind::JSDocDeprecatedTag => ModifierFlags::DEPRECATED,
+                    _ => ModifierFlags::NONE,
+                }
+        })
+}
+
+/// The tags of the JSDoc comments that apply to the node. Unlike `getJSDocTags`, the `@param`
+/// and `@template` tags of a parameter or type parameter in the comment of its function are left
+/// out.
+pub fn get_js_doc_tags(source_file: &SourceFile, node: NodeId) -> Vec<NodeId> {
+    get_js_doc_comments(source_file, node)
+        .into_iter()
+        .filter_map(|js_doc| match &source_file.node(js_doc).data {
+            NodeData::JSDoc { tags, .. } => tags.as_ref(),
+            _ => None,
+        })
+        .flat_map(|tags| tags.iter().copied())
+        .collect()
+}
+
+// The last JSDoc comment of the host and of each node that passes its comments on to the next,
+// like the variable statement of a variable declaration, as `getJSDocCommentsAndTags` finds them.
+fn get_js_doc_comments(source_file: &SourceFile, host: NodeId) -> Vec<NodeId> {
+    let last_js_doc = |node: NodeId| {
+        source_file
+            .node(node)
+            .js_doc
+            .as_ref()
+            .and_then(|js_doc| js_doc.last().copied())
+    };
+    let mut result = Vec::new();
+    let host_node = source_file.node(host);
+    // Pull parameter comments from declaring function as well
+    if is_variable_like_kind(host_node.kind) {
+        if let Some(js_doc) = host_node.initializer().and_then(last_js_doc) {
+            result.push(js_doc);
+        }
+    }
+
+    let mut node = Some(host);
+    while let Some(current) = node.filter(|&node| source_file.parent(node).is_some()) {
+        if let Some(js_doc) = last_js_doc(current) {
+            result.push(js_doc);
+        }
+        let kind = source_file.node(current).kind;
+        if kind == SyntaxKind::Parameter || kind == SyntaxKind::TypeParameter {
+            break;
+        }
+        node = get_next_js_doc_comment_location(source_file, current);
+    }
+    result
+}
+
+fn is_variable_like_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::BindingElement
+            | SyntaxKind::EnumMember
+            | SyntaxKind::Parameter
+            | SyntaxKind::PropertyAssignment
+            | SyntaxKind::PropertyDeclaration
+            | SyntaxKind::PropertySignature
+            | SyntaxKind::ShorthandPropertyAssignment
+            | SyntaxKind::VariableDeclaration
+    )
+}
+
+pub fn get_next_js_doc_comment_location(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    let parent = source_file.parent(node)?;
+    let parent_kind = source_file.node(parent).kind;
+    if parent_kind == SyntaxKind::PropertyAssignment
+        || parent_kind == SyntaxKind::ExportAssignment
+        || parent_kind == SyntaxKind::PropertyDeclaration
+        || parent_kind == SyntaxKind::ExpressionStatement
+            && source_file.node(node).kind == SyntaxKind::PropertyAccessExpression
+        || parent_kind == SyntaxKind::ReturnStatement
+        || get_nested_module_declaration(source_file, parent).is_some()
+        || is_equals_binary_expression(source_file, node)
+    {
+        return Some(parent);
+    }
+    // Try to recognize this pattern when node is initializer of variable declaration and JSDoc comments are on containing variable statement.
+    // /**
+    //   * @param {number} name
+    //   * @returns {number}
+    //   */
+    // var x = function(name) { return name.length; }
+    let grandparent = source_file.parent(parent)?;
+    if get_single_variable_of_variable_statement(source_file, grandparent) == Some(node)
+        || is_equals_binary_expression(source_file, parent)
+    {
+        return Some(grandparent);
+    }
+    let great_grandparent = source_file.parent(grandparent)?;
+    if get_single_variable_of_variable_statement(source_file, great_grandparent).is_some()
+        || get_single_initializer_of_variable_statement_or_property_declaration(
+            source_file,
+            great_grandparent,
+        ) == Some(node)
+        || get_source_of_defaulted_assignment(source_file, great_grandparent).is_some()
+    {
+        return Some(great_grandparent);
+    }
+    None
+}
+
+fn get_source_of_defaulted_assignment(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    let node = source_file.node(node);
+    if node.kind != SyntaxKind::ExpressionStatement {
+        return None;
+    }
+    let expression = node.expression()?;
+    match &source_file.node(expression).data {
+        NodeData::BinaryExpression { right, .. }
+            if get_assignment_declaration_kind(source_file, expression)
+                != AssignmentDeclarationKind::None =>
+        {
+            match &source_file.node(*right).data {
+                NodeData::BinaryExpression {
+                    operator_token,
+                    right,
+                    ..
+                } if matches!(
+                    source_file.node(*operator_token).kind,
+                    SyntaxKind::BarBarToken | SyntaxKind::QuestionQuestionToken
+                ) =>
+                {
+                    Some(*right)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn get_single_initializer_of_variable_statement_or_property_declaration(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Option<NodeId> {
+    match source_file.node(node).kind {
+        SyntaxKind::VariableStatement => {
+            get_single_variable_of_variable_statement(source_file, node)
+                .and_then(|declaration| source_file.node(declaration).initializer())
+        }
+        SyntaxKind::PropertyDeclaration | SyntaxKind::PropertyAssignment => {
+            source_file.node(node).initializer()
+        }
+        _ => None,
+    }
+}
+
+fn get_single_variable_of_variable_statement(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Option<NodeId> {
+    match &source_file.node(node).data {
+        NodeData::VariableStatement { declaration_list } => {
+            match &source_file.node(*declaration_list).data {
+                NodeData::VariableDeclarationList { declarations } => declarations.first().copied(),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn get_nested_module_declaration(source_file: &SourceFile, node: NodeId) -> Option<NodeId> {
+    match &source_file.node(node).data {
+        NodeData::ModuleDeclaration {
+            body: Some(body), ..
+        } if source_file.node(*body).kind == SyntaxKind::ModuleDeclaration => Some(*body),
+        _ => None,
+    }
+}
+
+pub fn walk_up_binding_elements_and_patterns(source_file: &SourceFile, binding: NodeId) -> NodeId {
+    let mut node = source_file.parent(binding).unwrap_or(binding);
+    while let Some(parent) = source_file
+        .parent(node)
+        .filter(|&parent| source_file.node(parent).kind == SyntaxKind::BindingElement)
+    {
+        node = source_file.parent(parent).unwrap_or(parent);
+    }
+    source_file.parent(node).unwrap_or(node)
+}
+
+pub fn get_root_declaration(source_file: &SourceFile, node: NodeId) -> NodeId {
+    let mut node = node;
+    while source_file.node(node).kind == SyntaxKind::BindingElement {
+        match source_file
+            .parent(node)
+            .and_then(|pattern| source_file.parent(pattern))
+        {
+            Some(declaration) => node = declaration,
+            None => break,
+        }
+    }
+    node
+}
+
+// The flags of the node together with the ones of its variable declaration list and statement,
+// for a variable declaration or a binding element in one.
+fn get_combined_flags(
+    source_file: &SourceFile,
+    node: NodeId,
+    get_flags: impl Fn(NodeId) -> u32,
+) -> u32 {
+    let mut node = node;
+    if source_file.node(node).kind == SyntaxKind::BindingElement {
+        node = walk_up_binding_elements_and_patterns(source_file, node);
+    }
+    let mut flags = get_flags(node);
+    let mut current = Some(node);
+    if source_file.node(node).kind == SyntaxKind::VariableDeclaration {
+        current = source_file.parent(node);
+    }
+    if let Some(list) =
+        current.filter(|&node| source_file.node(node).kind == SyntaxKind::VariableDeclarationList)
+    {
+        flags |= get_flags(list);
+        current = source_file.parent(list);
+    }
+    if let Some(statement) =
+        current.filter(|&node| source_file.node(node).kind == SyntaxKind::VariableStatement)
+    {
+        flags |= get_flags(statement);
+    }
+    flags
+}
+
+pub fn get_combined_node_flags(source_file: &SourceFile, node: NodeId) -> u32 {
+    get_combined_flags(source_file, node, |node| source_file.node(node).flags)
+}
+
+// The ModifierFlags (not NodeFlags, despite the name) of the node and its variable statement.
+pub fn get_combined_node_flags_always_include_js_doc(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> u32 {
+    get_combined_flags(source_file, node, |node| {
+        get_effective_modifier_flags_always_include_js_doc(source_file, node)
+    })
+}
+
+pub fn is_var_const(source_file: &SourceFile, node: NodeId) -> bool {
+    get_combined_node_flags(source_file, node) & NodeFlags::CONST != 0
+}
+
+pub fn is_let(source_file: &SourceFile, node: NodeId) -> bool {
+    get_combined_node_flags(source_file, node) & NodeFlags::LET != 0
+}
+
+pub fn is_parameter_property_declaration(
+    source_file: &SourceFile,
+    node: NodeId,
+    parent: NodeId,
+) -> bool {
+    has_syntactic_modifier(
+        source_file,
+        node,
+        ModifierFlags::PARAMETER_PROPERTY_MODIFIER,
+    ) && source_file.node(parent).kind == SyntaxKind::Constructor
+}
+
+// Escapes the characters of the text that can't appear as they are between double quotes.
+pub fn escape_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\t' => result.push_str("\\t"),
+            '\u{000B}' => result.push_str("\\v"),
+            '\u{000C}' => result.push_str("\\f"),
+            '\u{0008}' => result.push_str("\\b"),
+            '\r' => result.push_str("\\r"),
+            '\n' => result.push_str("\\n"),
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\u{2028}' => result.push_str("\\u2028"),
+            '\u{2029}' => result.push_str("\\u2029"),
+            '\u{0085}' => result.push_str("\\u0085"),
+            // If the null character is followed by digits, print as a hex escape to prevent the
+            // result from parsing as an octal (which is forbidden in strict mode)
+            '\0' if matches!(chars.peek(), Some('0'..='9')) => result.push_str("\\x00"),
+            // Otherwise, keep printing a literal \0 for the null character
+            '\0' => result.push_str("\\0"),
+            '\u{0001}'..='\u{001F}' => result.push_str(&format!("\\u{:04X}", c as u32)),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+pub fn remove_file_extension(path: &str) -> &str {
+    for extension in &[".d.ts", ".ts", ".js", ".tsx", ".jsx", ".json"] {
+        if file_extension_is(path, extension) {
+            return &path[..path.len() - extension.len()];
+        }
+    }
+    path
+}
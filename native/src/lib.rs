@@ -1,12 +1,8 @@
-mod compiler;
+pub mod compiler;
+pub mod services;
 
-use compiler::scanner::{compute_line_starts, lookup_in_unicode_map};
-use napi::{JsObject, Result};
-use napi_derive::module_exports;
+#[cfg(feature = "napi")]
+mod bindings;
 
-#[module_exports]
-fn init(mut exports: JsObject) -> Result<()> {
-    exports.create_named_method("lookupInUnicodeMap", lookup_in_unicode_map)?;
-    exports.create_named_method("computeLineStarts", compute_line_starts)?;
-    Ok(())
-}
+#[cfg(feature = "wasm")]
+mod wasm;
@@ -0,0 +1,1402 @@
+use std::ops::Range;
+
+use crate::compiler::parser::{
+    is_comment_pragma, is_pragma_whitespace, parse_isolated_js_doc_comment,
+};
+use crate::compiler::scanner::{
+    could_start_trivia, create_scanner, is_line_break, Scanner, TextScanner,
+};
+use crate::compiler::types::{
+    CancellationToken, CharacterCodes, JSDocCommentText, JSDocTag, KeywordSyntaxKind,
+    KeywordTypeSyntaxKind, LiteralSyntaxKind, ModifierSyntaxKind, NodeData, NodeId,
+    OperationCanceled, PseudoLiteralSyntaxKind, ScriptTarget, SourceFile, SyntaxKind, TextRange,
+    TriviaSyntaxKind,
+};
+use crate::compiler::utilities::{
+    is_js_doc_like_text, is_keyword, is_template_literal_kind, is_this_identifier, is_token_kind,
+};
+
+use super::types::{
+    ClassificationInfo, ClassificationResult, ClassificationType, Classifications, EndOfLineState,
+    TokenClass,
+};
+use super::utilities::{
+    get_children, get_type_argument_or_type_parameter_list, is_punctuation, Child,
+};
+
+/// The classifier is used for syntactic highlighting in editors via the TSServer. It classifies a
+/// line at a time, from the state the previous line ended in, without parsing.
+pub struct Classifier {
+    scanner: TextScanner,
+}
+
+pub fn create_classifier() -> Classifier {
+    Classifier {
+        scanner: create_scanner(ScriptTarget::ESNext, false, None, None, None, None, None),
+    }
+}
+
+impl Classifier {
+    /// Gives lexical classifications of tokens on a line without any syntactic context. If there
+    /// is a syntactic classifier (`syntactic_classifier_absent` is false), the tokens it could
+    /// classify differently, like a type keyword that may be a type argument, are classified as
+    /// identifiers, to let it decide.
+    ///
+    /// Unlike the JS classifier, which has no `TokenClass` for them, regular expressions are
+    /// classified as `TokenClass::RegExpLiteral`.
+    pub fn get_classifications_for_line(
+        &mut self,
+        text: &str,
+        lex_state: EndOfLineState,
+        syntactic_classifier_absent: bool,
+    ) -> ClassificationResult {
+        let classifications =
+            self.get_encoded_lexical_classifications(text, lex_state, syntactic_classifier_absent);
+        convert_classifications_to_result(&classifications, text)
+    }
+
+    // If there is a syntactic classifier ('syntacticClassifierAbsent' is false),
+    // we will be more conservative in order to avoid conflicting with the syntactic classifier.
+    pub fn get_encoded_lexical_classifications(
+        &mut self,
+        text: &str,
+        lex_state: EndOfLineState,
+        syntactic_classifier_absent: bool,
+    ) -> Classifications {
+        let mut last_non_trivia_token = SyntaxKind::Unknown;
+
+        // Just a stack of TemplateHeads and OpenCurlyBraces, used to perform rudimentary (inexact)
+        // classification on template strings. Because of the context free nature of templates,
+        // the only precise way to classify a template portion would be by propagating the stack across
+        // lines, just as we do with the end-of-line state. However, this is a burden for implementers,
+        // and the behavior is entirely subsumed by the syntactic classifier anyway, so we instead
+        // flatten any nesting when the template stack is non-empty and encode it in the end-of-line state.
+        // Situations in which this fails are
+        //  1) When template strings are nested across different lines:
+        //          `hello ${ `world
+        //          ` }`
+        //
+        //     Where on the second line, you will get the closing of a template,
+        //     a closing curly, and a new template.
+        //
+        //  2) When substitution expressions have curly braces and the curly brace falls on the next line:
+        //          `hello ${ () => {
+        //          return "world" } } `
+        //
+        //     Where on the second line, you will get the 'return' keyword,
+        //     a string literal, and a template end consisting of '} } `'.
+        let mut template_stack: Vec<SyntaxKind> = Vec::new();
+
+        let (prefix, push_template) = get_prefix_from_lex_state(lex_state);
+        let text = format!("{}{}", prefix, text);
+        let text_length = text.encode_utf16().count();
+        let offset = prefix.len();
+        if push_template {
+            template_stack.push(SyntaxKind::TemplateHead);
+        }
+
+        self.scanner.set_text(Some(text), None, None);
+
+        let mut end_of_line_state = EndOfLineState::None;
+        let mut spans = Vec::new();
+
+        // We can run into an unfortunate interaction between the lexical and syntactic classifier
+        // when the user is typing something generic.  Consider the case where the user types:
+        //
+        //      Foo<number
+        //
+        // From the lexical classifier's perspective, 'number' is a keyword, and so the word will
+        // be classified as such.  However, from the syntactic classifier's tree-based perspective
+        // this is simply an expression with the identifier 'number' on the RHS of the less than
+        // token.  So the classification will go back to being an identifier.  The moment the user
+        // types again, number will become a keyword, then an identifier, etc. etc.
+        //
+        // To try to avoid this problem, we avoid classifying contextual keywords as keywords
+        // when the user is potentially typing something generic.  We just can't do a good enough
+        // job at the lexical level, and so well leave it up to the syntactic classifier to make
+        // the determination.
+        //
+        // In order to determine if the user is potentially typing something generic, we use a
+        // weak heuristic where we track < and > tokens.  It's a weak heuristic, but should
+        // work well enough in practice.
+        let mut angle_bracket_stack = 0;
+
+        loop {
+            let mut token = self.scanner.scan();
+            if !matches!(token, SyntaxKind::Trivias(_)) {
+                token = self.handle_token(
+                    token,
+                    last_non_trivia_token,
+                    &mut template_stack,
+                    &mut angle_bracket_stack,
+                    syntactic_classifier_absent,
+                );
+                last_non_trivia_token = token;
+            }
+            let end = self.scanner.get_text_pos();
+            push_encoded_classification(
+                self.scanner.get_token_pos(),
+                end,
+                offset,
+                class_from_kind(token),
+                &mut spans,
+            );
+            if end >= text_length {
+                if let Some(end) =
+                    get_new_end_of_line_state(&self.scanner, token, template_stack.last().copied())
+                {
+                    end_of_line_state = end;
+                }
+            }
+            if token == SyntaxKind::EndOfFileToken {
+                break;
+            }
+        }
+
+        Classifications {
+            spans,
+            end_of_line_state,
+        }
+    }
+
+    fn handle_token(
+        &mut self,
+        token: SyntaxKind,
+        last_non_trivia_token: SyntaxKind,
+        template_stack: &mut Vec<SyntaxKind>,
+        angle_bracket_stack: &mut usize,
+        syntactic_classifier_absent: bool,
+    ) -> SyntaxKind {
+        match token {
+            SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken => {
+                if !is_in_no_regex_table(last_non_trivia_token)
+                    && self.scanner.re_scan_slash_token() == SyntaxKind::RegularExpressionLiteral
+                {
+                    return SyntaxKind::RegularExpressionLiteral;
+                }
+            }
+            SyntaxKind::LessThanToken => {
+                if last_non_trivia_token == SyntaxKind::Identifier {
+                    // Could be the start of something generic.  Keep track of that by bumping
+                    // up the current count of generic contexts we may be in.
+                    *angle_bracket_stack += 1;
+                }
+            }
+            SyntaxKind::GreaterThanToken => {
+                if *angle_bracket_stack > 0 {
+                    // If we think we're currently in something generic, then mark that that
+                    // generic entity is complete.
+                    *angle_bracket_stack -= 1;
+                }
+            }
+            SyntaxKind::Keywords(KeywordSyntaxKind::KeywordType(
+                KeywordTypeSyntaxKind::AnyKeyword
+                | KeywordTypeSyntaxKind::StringKeyword
+                | KeywordTypeSyntaxKind::NumberKeyword
+                | KeywordTypeSyntaxKind::BooleanKeyword
+                | KeywordTypeSyntaxKind::SymbolKeyword,
+            )) => {
+                if *angle_bracket_stack > 0 && !syntactic_classifier_absent {
+                    // If it looks like we're could be in something generic, don't classify this
+                    // as a keyword.  We may just get overwritten by the syntactic classifier,
+                    // causing a noisy experience for the user.
+                    return SyntaxKind::Identifier;
+                }
+            }
+            SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateHead) => {
+                template_stack.push(token);
+            }
+            SyntaxKind::OpenBraceToken => {
+                // If we don't have anything on the template stack,
+                // then we aren't trying to keep track of a previously scanned template head.
+                if !template_stack.is_empty() {
+                    template_stack.push(token);
+                }
+            }
+            SyntaxKind::CloseBraceToken => {
+                // If we don't have anything on the template stack,
+                // then we aren't trying to keep track of a previously scanned template head.
+                if let Some(&last_template_stack_token) = template_stack.last() {
+                    if last_template_stack_token == SyntaxKind::TemplateHead {
+                        let token = self.scanner.re_scan_template_token(false);
+
+                        // Only pop on a TemplateTail; a TemplateMiddle indicates there is more for us.
+                        if token == SyntaxKind::TemplateTail {
+                            template_stack.pop();
+                        } else {
+                            debug_assert_eq!(
+                                token,
+                                SyntaxKind::TemplateMiddle,
+                                "Should have been a template middle."
+                            );
+                        }
+                        return token;
+                    }
+                    debug_assert_eq!(
+                        last_template_stack_token,
+                        SyntaxKind::OpenBraceToken,
+                        "Should have been an open brace"
+                    );
+                    template_stack.pop();
+                }
+            }
+            _ => {
+                if !is_keyword(token) {
+                    return token;
+                }
+
+                if last_non_trivia_token == SyntaxKind::DotToken {
+                    return SyntaxKind::Identifier;
+                }
+                if is_keyword(last_non_trivia_token) && !can_follow(last_non_trivia_token, token) {
+                    // We have two keywords in a row.  Only treat the second as a keyword if
+                    // it's a sequence that could legally occur in the language.  Otherwise
+                    // treat it as an identifier.  This way, if someone writes "private var"
+                    // we recognize that 'var' is actually an identifier here.
+                    return SyntaxKind::Identifier;
+                }
+            }
+        }
+        token
+    }
+}
+
+// We do not have a full parser support to know when we should parse a regex or not
+// If we consider every slash token to be a regex, we could be missing cases like "1/2/3", where
+// we have a series of divide operator. this list allows us to be more accurate by ruling out
+// locations where a regexp cannot exist.
+fn is_in_no_regex_table(token: SyntaxKind) -> bool {
+    matches!(
+        token,
+        SyntaxKind::Identifier
+            | SyntaxKind::Literals(
+                LiteralSyntaxKind::StringLiteral
+                    | LiteralSyntaxKind::NumericLiteral
+                    | LiteralSyntaxKind::BigIntLiteral
+                    | LiteralSyntaxKind::RegularExpressionLiteral
+            )
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::PlusPlusToken
+            | SyntaxKind::MinusMinusToken
+            | SyntaxKind::CloseParenToken
+            | SyntaxKind::CloseBracketToken
+            | SyntaxKind::CloseBraceToken
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+    )
+}
+
+fn get_new_end_of_line_state(
+    scanner: &TextScanner,
+    token: SyntaxKind,
+    last_on_template_stack: Option<SyntaxKind>,
+) -> Option<EndOfLineState> {
+    match token {
+        SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral) => {
+            // Check to see if we finished up on a multiline string literal.
+            if !scanner.is_unterminated() {
+                return None;
+            }
+
+            let token_text = scanner.get_token_text();
+            let num_backslashes = token_text
+                .chars()
+                .rev()
+                .take_while(|&ch| ch == '\\')
+                .count();
+
+            // If we have an odd number of backslashes, then the multiline string is unclosed
+            if num_backslashes & 1 == 0 {
+                return None;
+            }
+            Some(if token_text.starts_with('"') {
+                EndOfLineState::InDoubleQuoteStringLiteral
+            } else {
+                EndOfLineState::InSingleQuoteStringLiteral
+            })
+        }
+        SyntaxKind::Trivias(TriviaSyntaxKind::MultiLineCommentTrivia) => {
+            // Check to see if the multiline comment was unclosed.
+            if scanner.is_unterminated() {
+                Some(EndOfLineState::InMultiLineCommentTrivia)
+            } else {
+                None
+            }
+        }
+        _ if is_template_literal_kind(token) => {
+            if !scanner.is_unterminated() {
+                return None;
+            }
+            match token {
+                SyntaxKind::PseudoLiterals(PseudoLiteralSyntaxKind::TemplateTail) => {
+                    Some(EndOfLineState::InTemplateMiddleOrTail)
+                }
+                SyntaxKind::Literals(LiteralSyntaxKind::NoSubstitutionTemplateLiteral) => {
+                    Some(EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate)
+                }
+                _ => panic!(
+                    "Only 'NoSubstitutionTemplateLiteral's and 'TemplateTail's can be unterminated; got SyntaxKind #{}",
+                    token.js_value()
+                ),
+            }
+        }
+        _ if last_on_template_stack == Some(SyntaxKind::TemplateHead) => {
+            Some(EndOfLineState::InTemplateSubstitutionPosition)
+        }
+        _ => None,
+    }
+}
+
+fn push_encoded_classification(
+    start: usize,
+    end: usize,
+    offset: usize,
+    classification: ClassificationType,
+    result: &mut Vec<u32>,
+) {
+    if classification == ClassificationType::WhiteSpace {
+        // Don't bother with whitespace classifications.  They're not needed.
+        return;
+    }
+
+    let mut start = start;
+    if start == 0 && offset > 0 {
+        // We're classifying the first token, and this was a case where we prepended text.
+        // We should consider the start of this token to be at the start of the original text.
+        start += offset;
+    }
+
+    if end > start {
+        // All our tokens are in relation to the augmented text.  Move them back to be
+        // relative to the original text.
+        result.extend_from_slice(&[
+            (start - offset) as u32,
+            (end - start) as u32,
+            classification as u32,
+        ]);
+    }
+}
+
+fn convert_classifications_to_result(
+    classifications: &Classifications,
+    text: &str,
+) -> ClassificationResult {
+    let mut entries = Vec::new();
+    let mut last_end = 0;
+
+    for span in classifications.spans.chunks_exact(3) {
+        let start = span[0] as usize;
+        let length = span[1] as usize;
+
+        // Make a whitespace entry between the last item and this one.
+        if start > last_end {
+            entries.push(ClassificationInfo {
+                length: start - last_end,
+                classification: TokenClass::Whitespace,
+            });
+        }
+
+        entries.push(ClassificationInfo {
+            length,
+            classification: convert_classification(span[2]),
+        });
+        last_end = start + length;
+    }
+
+    let text_length = text.encode_utf16().count();
+    if text_length > last_end {
+        entries.push(ClassificationInfo {
+            length: text_length - last_end,
+            classification: TokenClass::Whitespace,
+        });
+    }
+
+    ClassificationResult {
+        final_lex_state: classifications.end_of_line_state,
+        entries,
+    }
+}
+
+// The lexical classifier only reports the classifications of `class_from_kind`.
+fn convert_classification(classification: u32) -> TokenClass {
+    match classification {
+        c if c == ClassificationType::Comment as u32 => TokenClass::Comment,
+        c if c == ClassificationType::Keyword as u32 => TokenClass::Keyword,
+        c if c == ClassificationType::NumericLiteral as u32 => TokenClass::NumberLiteral,
+        c if c == ClassificationType::BigintLiteral as u32 => TokenClass::BigIntLiteral,
+        c if c == ClassificationType::Operator as u32 => TokenClass::Operator,
+        c if c == ClassificationType::StringLiteral as u32 => TokenClass::StringLiteral,
+        c if c == ClassificationType::RegularExpressionLiteral as u32 => TokenClass::RegExpLiteral,
+        c if c == ClassificationType::WhiteSpace as u32 => TokenClass::Whitespace,
+        c if c == ClassificationType::Punctuation as u32 => TokenClass::Punctuation,
+        _ => TokenClass::Identifier,
+    }
+}
+
+fn is_accessibility_modifier(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Keywords(KeywordSyntaxKind::Modifiers(
+            ModifierSyntaxKind::PublicKeyword
+                | ModifierSyntaxKind::PrivateKeyword
+                | ModifierSyntaxKind::ProtectedKeyword
+        ))
+    )
+}
+
+/// Returns true if 'keyword2' can legally follow 'keyword1' in any language construct.
+fn can_follow(keyword1: SyntaxKind, keyword2: SyntaxKind) -> bool {
+    if !is_accessibility_modifier(keyword1) {
+        // Assume any other keyword combination is legal.
+        // This can be refined in the future if there are more cases we want the classifier to be better at.
+        return true;
+    }
+    // Allow things like "public get", "public constructor" and "public static". Any other keyword
+    // following "public" is actually an identifier, not a real keyword.
+    matches!(
+        keyword2,
+        SyntaxKind::GetKeyword
+            | SyntaxKind::SetKeyword
+            | SyntaxKind::ConstructorKeyword
+            | SyntaxKind::StaticKeyword
+    )
+}
+
+// If we're in a string literal, then prepend: "\
+// (and a newline).  That way when we lex we'll think we're still in a string literal.
+//
+// If we're in a multiline comment, then prepend: /*
+// (and a newline).  That way when we lex we'll think we're still in a multiline comment.
+fn get_prefix_from_lex_state(lex_state: EndOfLineState) -> (&'static str, bool) {
+    match lex_state {
+        EndOfLineState::InDoubleQuoteStringLiteral => ("\"\\\n", false),
+        EndOfLineState::InSingleQuoteStringLiteral => ("'\\\n", false),
+        EndOfLineState::InMultiLineCommentTrivia => ("/*\n", false),
+        EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate => ("`\n", false),
+        EndOfLineState::InTemplateMiddleOrTail => ("}\n", true),
+        EndOfLineState::InTemplateSubstitutionPosition => ("", true),
+        EndOfLineState::None => ("", false),
+    }
+}
+
+fn is_binary_expression_operator_token(token: SyntaxKind) -> bool {
+    matches!(
+        token,
+        SyntaxKind::AsteriskToken
+            | SyntaxKind::SlashToken
+            | SyntaxKind::PercentToken
+            | SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::LessThanLessThanToken
+            | SyntaxKind::GreaterThanGreaterThanToken
+            | SyntaxKind::GreaterThanGreaterThanGreaterThanToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::GreaterThanToken
+            | SyntaxKind::LessThanEqualsToken
+            | SyntaxKind::GreaterThanEqualsToken
+            | SyntaxKind::InstanceOfKeyword
+            | SyntaxKind::InKeyword
+            | SyntaxKind::AsKeyword
+            | SyntaxKind::EqualsEqualsToken
+            | SyntaxKind::ExclamationEqualsToken
+            | SyntaxKind::EqualsEqualsEqualsToken
+            | SyntaxKind::ExclamationEqualsEqualsToken
+            | SyntaxKind::AmpersandToken
+            | SyntaxKind::CaretToken
+            | SyntaxKind::BarToken
+            | SyntaxKind::AmpersandAmpersandToken
+            | SyntaxKind::BarBarToken
+            | SyntaxKind::BarEqualsToken
+            | SyntaxKind::AmpersandEqualsToken
+            | SyntaxKind::CaretEqualsToken
+            | SyntaxKind::LessThanLessThanEqualsToken
+            | SyntaxKind::GreaterThanGreaterThanEqualsToken
+            | SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken
+            | SyntaxKind::PlusEqualsToken
+            | SyntaxKind::MinusEqualsToken
+            | SyntaxKind::AsteriskEqualsToken
+            | SyntaxKind::SlashEqualsToken
+            | SyntaxKind::PercentEqualsToken
+            | SyntaxKind::EqualsToken
+            | SyntaxKind::CommaToken
+            | SyntaxKind::QuestionQuestionToken
+            | SyntaxKind::BarBarEqualsToken
+            | SyntaxKind::AmpersandAmpersandEqualsToken
+            | SyntaxKind::QuestionQuestionEqualsToken
+    )
+}
+
+fn is_prefix_unary_expression_operator_token(token: SyntaxKind) -> bool {
+    matches!(
+        token,
+        SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::PlusPlusToken
+            | SyntaxKind::MinusMinusToken
+    )
+}
+
+fn class_from_kind(token: SyntaxKind) -> ClassificationType {
+    if is_keyword(token) {
+        return ClassificationType::Keyword;
+    } else if is_binary_expression_operator_token(token)
+        || is_prefix_unary_expression_operator_token(token)
+    {
+        return ClassificationType::Operator;
+    } else if matches!(token, SyntaxKind::Punctuations(_)) {
+        return ClassificationType::Punctuation;
+    }
+
+    match token {
+        SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral) => {
+            ClassificationType::NumericLiteral
+        }
+        SyntaxKind::Literals(LiteralSyntaxKind::BigIntLiteral) => ClassificationType::BigintLiteral,
+        SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral) => ClassificationType::StringLiteral,
+        SyntaxKind::Literals(LiteralSyntaxKind::RegularExpressionLiteral) => {
+            ClassificationType::RegularExpressionLiteral
+        }
+        SyntaxKind::Trivias(
+            TriviaSyntaxKind::ConflictMarkerTrivia
+            | TriviaSyntaxKind::MultiLineCommentTrivia
+            | TriviaSyntaxKind::SingleLineCommentTrivia,
+        ) => ClassificationType::Comment,
+        SyntaxKind::Trivias(
+            TriviaSyntaxKind::WhitespaceTrivia | TriviaSyntaxKind::NewLineTrivia,
+        ) => ClassificationType::WhiteSpace,
+        _ if is_template_literal_kind(token) => ClassificationType::StringLiteral,
+        _ => ClassificationType::Identifier,
+    }
+}
+
+// We don't want to check the cancellation token on every node. So instead we pick a few
+// reasonable node kinds to bother checking on. These node kinds represent high level
+// constructs that we would expect to see commonly, but just at a far less frequent interval.
+//
+// For example, in checker.ts (around 750k) we only have around 600 of these constructs.
+fn check_for_classification_cancellation(
+    cancellation_token: Option<&CancellationToken>,
+    kind: SyntaxKind,
+) -> Result<(), OperationCanceled> {
+    match (cancellation_token, kind) {
+        (
+            Some(cancellation_token),
+            SyntaxKind::ModuleDeclaration
+            | SyntaxKind::ClassDeclaration
+            | SyntaxKind::InterfaceDeclaration
+            | SyntaxKind::FunctionDeclaration
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::ArrowFunction,
+        ) => cancellation_token.throw_if_cancellation_requested(),
+        _ => Ok(()),
+    }
+}
+
+/// Classifies the tokens and comments of the file that intersect the span from its syntax tree,
+/// like `getEncodedSyntacticClassifications` of the JS services: names get the classification of
+/// what they declare, like `ClassName` or `ParameterName`, the tags and names of JSX elements and
+/// the tags of JSDoc comments are classified on their own, and whitespace isn't classified.
+/// Positions are in UTF-16 code units. With a cancellation token, classifying stops at the next
+/// declaration after the token is canceled.
+pub fn get_encoded_syntactic_classifications(
+    source_file: &SourceFile,
+    span: TextRange,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Classifications, OperationCanceled> {
+    let text: Vec<u16> = source_file.text.encode_utf16().collect();
+    let mut classifier = SyntacticClassifier {
+        text: &text,
+        span,
+        cancellation_token,
+        // Make a scanner we can get trivia from.
+        trivia_scanner: create_scanner(
+            ScriptTarget::ESNext,
+            false,
+            Some(source_file.language_variant),
+            Some(source_file.text.clone()),
+            None,
+            None,
+            None,
+        ),
+        merge_conflict_scanner: create_scanner(
+            ScriptTarget::ESNext,
+            false,
+            Some(source_file.language_variant),
+            Some(source_file.text.clone()),
+            None,
+            None,
+            None,
+        ),
+        children_scanner: create_scanner(
+            ScriptTarget::ESNext,
+            true,
+            None,
+            Some(source_file.text.clone()),
+            None,
+            None,
+            None,
+        ),
+        result: Vec::new(),
+    };
+    classifier.process_element(source_file, Child::Node(source_file.root))?;
+
+    Ok(Classifications {
+        spans: classifier.result,
+        end_of_line_state: EndOfLineState::None,
+    })
+}
+
+struct SyntacticClassifier<'a> {
+    text: &'a [u16],
+    span: TextRange,
+    cancellation_token: Option<&'a CancellationToken>,
+    trivia_scanner: TextScanner,
+    merge_conflict_scanner: TextScanner,
+    // Reads the tokens between the child nodes for `get_children`.
+    children_scanner: TextScanner,
+    result: Vec<u32>,
+}
+
+impl SyntacticClassifier<'_> {
+    fn push_classification(
+        &mut self,
+        start: usize,
+        length: usize,
+        classification: ClassificationType,
+    ) {
+        self.result
+            .extend_from_slice(&[start as u32, length as u32, classification as u32]);
+    }
+
+    fn push_comment_range(&mut self, start: usize, width: usize) {
+        self.push_classification(start, width, ClassificationType::Comment);
+    }
+
+    fn classify_leading_trivia_and_get_token_start(
+        &mut self,
+        source_file: &SourceFile,
+        token: &Child,
+    ) -> Result<usize, OperationCanceled> {
+        self.trivia_scanner
+            .set_text_pos(Some(token.range(source_file).pos));
+        loop {
+            let start = self.trivia_scanner.get_text_pos();
+            // only bother scanning if we have something that could be trivia.
+            if !could_start_trivia(self.text, start) {
+                return Ok(start);
+            }
+
+            let kind = self.trivia_scanner.scan();
+            let end = self.trivia_scanner.get_text_pos();
+            let width = end - start;
+
+            // The moment we get something that isn't trivia, then stop processing.
+            let kind = match kind {
+                SyntaxKind::Trivias(kind) => kind,
+                _ => return Ok(start),
+            };
+
+            match kind {
+                TriviaSyntaxKind::NewLineTrivia | TriviaSyntaxKind::WhitespaceTrivia => {
+                    // Don't bother with newlines/whitespace.
+                    continue;
+                }
+                TriviaSyntaxKind::SingleLineCommentTrivia
+                | TriviaSyntaxKind::MultiLineCommentTrivia => {
+                    // Only bother with the trivia if it at least intersects the span of interest.
+                    self.classify_comment(source_file, token, kind, start, width)?;
+
+                    // Classifying a comment might cause us to reuse the trivia scanner
+                    // (because of jsdoc comments).  So after we classify the comment make
+                    // sure we set the scanner position back to where it needs to be.
+                    self.trivia_scanner.set_text_pos(Some(end));
+                    continue;
+                }
+                TriviaSyntaxKind::ConflictMarkerTrivia => {
+                    let ch = self.text[start] as u32;
+
+                    // for the <<<<<<< and >>>>>>> markers, we just add them in as comments
+                    // in the classification stream.
+                    if ch == CharacterCodes::LESS_THAN || ch == CharacterCodes::GREATER_THAN {
+                        self.push_classification(start, width, ClassificationType::Comment);
+                        continue;
+                    }
+
+                    // for the ||||||| and ======== markers, add a comment for the first line,
+                    // and then lex all subsequent lines up until the end of the conflict marker.
+                    debug_assert!(ch == CharacterCodes::BAR || ch == CharacterCodes::EQUALS);
+                    self.classify_disabled_merge_code(start, end);
+                }
+                TriviaSyntaxKind::ShebangTrivia => {
+                    // TODO: Maybe we should classify these.
+                }
+            }
+        }
+    }
+
+    fn classify_comment(
+        &mut self,
+        source_file: &SourceFile,
+        token: &Child,
+        kind: TriviaSyntaxKind,
+        start: usize,
+        width: usize,
+    ) -> Result<(), OperationCanceled> {
+        if kind == TriviaSyntaxKind::MultiLineCommentTrivia {
+            // See if this is a doc comment.  If so, we'll classify certain portions of it
+            // specially. The comments the parser attached to a node are already parsed; the
+            // others are parsed on their own, as the JS services parse every doc comment.
+            if let Some(js_doc) = find_attached_js_doc(source_file, token, start) {
+                return self.classify_js_doc_comment(source_file, js_doc);
+            }
+            if is_js_doc_like_text(self.text, start) {
+                let doc_comment =
+                    parse_isolated_js_doc_comment(source_file.text.clone(), start, width);
+                if let Some((doc_comment_file, js_doc)) = doc_comment {
+                    return self.classify_js_doc_comment(&doc_comment_file, js_doc);
+                }
+            }
+        } else if kind == TriviaSyntaxKind::SingleLineCommentTrivia
+            && self.try_classify_triple_slash_comment(start, width)
+        {
+            return Ok(());
+        }
+
+        // Simple comment.  Just add as is.
+        self.push_comment_range(start, width);
+        Ok(())
+    }
+
+    fn classify_js_doc_comment(
+        &mut self,
+        source_file: &SourceFile,
+        doc_comment: NodeId,
+    ) -> Result<(), OperationCanceled> {
+        let doc_comment = source_file.node(doc_comment);
+        let mut pos = doc_comment.pos();
+
+        if let NodeData::JSDoc {
+            tags: Some(tags), ..
+        } = &doc_comment.data
+        {
+            for &tag_id in tags.iter() {
+                let tag_node = source_file.node(tag_id);
+                let tag = match &tag_node.data {
+                    NodeData::JSDocTag(tag) => tag,
+                    _ => continue,
+                };
+                let end_of = |node: Option<NodeId>| node.map(|node| source_file.node(node).end());
+
+                // As we walk through each tag, classify the portion of text from the end of
+                // the last tag (or the start of the entire doc comment) as 'comment'.
+                if tag_node.pos() != pos {
+                    self.push_comment_range(pos, tag_node.pos() - pos);
+                }
+
+                self.push_classification(tag_node.pos(), 1, ClassificationType::Punctuation); // "@"
+                let tag_name = source_file.node(tag.tag_name).range;
+                self.push_classification(
+                    tag_name.pos,
+                    tag_name.end - tag_name.pos,
+                    ClassificationType::DocCommentTagName,
+                ); // e.g. "param"
+
+                pos = tag_name.end;
+                let mut comment_start = tag_name.end;
+
+                match tag_node.kind {
+                    SyntaxKind::JSDocParameterTag => {
+                        self.process_js_doc_parameter_tag(source_file, tag, &mut pos)?;
+                        comment_start = tag
+                            .is_name_first
+                            .then(|| end_of(tag.type_expression))
+                            .flatten()
+                            .or_else(|| end_of(tag.name))
+                            .unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocPropertyTag => {
+                        comment_start = tag
+                            .is_name_first
+                            .then(|| end_of(tag.type_expression))
+                            .flatten()
+                            .or_else(|| end_of(tag.name))
+                            .unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocTemplateTag => {
+                        self.process_js_doc_template_tag(source_file, tag_id)?;
+                        pos = tag_node.end();
+                        comment_start = tag
+                            .type_parameters
+                            .as_ref()
+                            .map_or(comment_start, |list| list.range.end);
+                    }
+                    SyntaxKind::JSDocTypedefTag => {
+                        let type_expression_kind =
+                            tag.type_expression.map(|node| source_file.node(node).kind);
+                        comment_start = (type_expression_kind
+                            == Some(SyntaxKind::JSDocTypeExpression))
+                        .then(|| end_of(tag.full_name))
+                        .flatten()
+                        .or_else(|| end_of(tag.type_expression))
+                        .unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocCallbackTag
+                    | SyntaxKind::JSDocThisTag
+                    | SyntaxKind::JSDocEnumTag => {
+                        comment_start = end_of(tag.type_expression).unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocTypeTag | SyntaxKind::JSDocReturnTag => {
+                        if let Some(type_expression) = tag.type_expression {
+                            self.process_element(source_file, Child::Node(type_expression))?;
+                        }
+                        pos = tag_node.end();
+                        comment_start = end_of(tag.type_expression).unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocSeeTag => {
+                        comment_start = end_of(tag.name).unwrap_or(comment_start);
+                    }
+                    SyntaxKind::JSDocAugmentsTag | SyntaxKind::JSDocImplementsTag => {
+                        comment_start = end_of(tag.class).unwrap_or(comment_start);
+                    }
+                    _ => {}
+                }
+                match &tag.comment {
+                    Some(JSDocCommentText::Nodes(comment)) => {
+                        self.push_comment_range(
+                            comment.range.pos,
+                            comment.range.end - comment.range.pos,
+                        );
+                    }
+                    Some(JSDocCommentText::Text(_)) => {
+                        self.push_comment_range(comment_start, tag_node.end() - comment_start);
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if pos != doc_comment.end() {
+            self.push_comment_range(pos, doc_comment.end() - pos);
+        }
+        Ok(())
+    }
+
+    fn process_js_doc_parameter_tag(
+        &mut self,
+        source_file: &SourceFile,
+        tag: &JSDocTag,
+        pos: &mut usize,
+    ) -> Result<(), OperationCanceled> {
+        let name = tag.name.map(|name| source_file.node(name).range);
+        if tag.is_name_first {
+            if let Some(name) = name {
+                self.push_comment_range(*pos, name.pos - *pos);
+                self.push_classification(
+                    name.pos,
+                    name.end - name.pos,
+                    ClassificationType::ParameterName,
+                );
+                *pos = name.end;
+            }
+        }
+
+        if let Some(type_expression) = tag.type_expression {
+            let range = source_file.node(type_expression).range;
+            self.push_comment_range(*pos, range.pos - *pos);
+            self.process_element(source_file, Child::Node(type_expression))?;
+            *pos = range.end;
+        }
+
+        if !tag.is_name_first {
+            if let Some(name) = name {
+                self.push_comment_range(*pos, name.pos - *pos);
+                self.push_classification(
+                    name.pos,
+                    name.end - name.pos,
+                    ClassificationType::ParameterName,
+                );
+                *pos = name.end;
+            }
+        }
+        Ok(())
+    }
+
+    fn try_classify_triple_slash_comment(&mut self, start: usize, width: usize) -> bool {
+        let text = &self.text[start..start + width];
+        let r#match = match match_triple_slash_xml_comment(text) {
+            Some(r#match) => r#match,
+            None => return false,
+        };
+
+        // Limiting classification to exactly the elements and attributes
+        // defined in `ts.commentPragmas` would be excessive, but we can avoid
+        // some obvious false positives (e.g. in XML-like doc comments) by
+        // checking the element name.
+        if !is_comment_pragma(&String::from_utf16_lossy(&text[r#match.name.clone()])) {
+            return false;
+        }
+
+        let mut pos = start;
+
+        self.push_comment_range(pos, r#match.name.start - 1); // ///
+        pos += r#match.name.start - 1;
+
+        self.push_classification(pos, 1, ClassificationType::Punctuation); // <
+        pos += 1;
+
+        self.push_classification(
+            pos,
+            r#match.name.len(),
+            ClassificationType::JsxSelfClosingTagName,
+        ); // element name
+        pos += r#match.name.len();
+
+        let attr_text = &text[r#match.attributes.clone()];
+        let mut attr_pos = pos;
+        let mut last_index = 0;
+        while let Some(attr_match) = match_triple_slash_attribute(attr_text, last_index) {
+            last_index = attr_match.end;
+
+            let new_attr_pos = pos + attr_match.index + 1; // whitespace
+            if new_attr_pos > attr_pos {
+                self.push_comment_range(attr_pos, new_attr_pos - attr_pos);
+                attr_pos = new_attr_pos;
+            }
+
+            self.push_classification(
+                attr_pos,
+                attr_match.name_length,
+                ClassificationType::JsxAttribute,
+            ); // attribute name
+            attr_pos += attr_match.name_length;
+
+            if attr_match.whitespace_before_equals > 0 {
+                self.push_comment_range(attr_pos, attr_match.whitespace_before_equals); // whitespace
+                attr_pos += attr_match.whitespace_before_equals;
+            }
+
+            self.push_classification(attr_pos, 1, ClassificationType::Operator); // =
+            attr_pos += 1;
+
+            if attr_match.whitespace_after_equals > 0 {
+                self.push_comment_range(attr_pos, attr_match.whitespace_after_equals); // whitespace
+                attr_pos += attr_match.whitespace_after_equals;
+            }
+
+            self.push_classification(
+                attr_pos,
+                attr_match.value_length,
+                ClassificationType::JsxAttributeStringLiteralValue,
+            ); // attribute value
+            attr_pos += attr_match.value_length;
+        }
+
+        pos += r#match.attributes.len();
+
+        if pos > attr_pos {
+            self.push_comment_range(attr_pos, pos - attr_pos);
+        }
+
+        if r#match.is_self_closing {
+            self.push_classification(pos, 2, ClassificationType::Punctuation); // />
+            pos += 2;
+        }
+
+        let end = start + width;
+        if pos < end {
+            self.push_comment_range(pos, end - pos);
+        }
+
+        true
+    }
+
+    fn process_js_doc_template_tag(
+        &mut self,
+        source_file: &SourceFile,
+        tag: NodeId,
+    ) -> Result<(), OperationCanceled> {
+        for child in get_children(source_file, tag, &mut self.children_scanner) {
+            self.process_element(source_file, child)?;
+        }
+        Ok(())
+    }
+
+    fn classify_disabled_merge_code(&mut self, start: usize, end: usize) {
+        // Classify the line that the ||||||| or ======= marker is on as a comment.
+        // Then just lex all further tokens and add them to the result.
+        let line_end = (start..end)
+            .find(|&i| is_line_break(self.text[i] as u32))
+            .unwrap_or(end);
+        self.push_classification(start, line_end - start, ClassificationType::Comment);
+        self.merge_conflict_scanner.set_text_pos(Some(line_end));
+
+        while self.merge_conflict_scanner.get_text_pos() < end {
+            self.classify_disabled_code_token();
+        }
+    }
+
+    fn classify_disabled_code_token(&mut self) {
+        let start = self.merge_conflict_scanner.get_text_pos();
+        let token_kind = self.merge_conflict_scanner.scan();
+        let end = self.merge_conflict_scanner.get_text_pos();
+
+        if let Some(classification) = classify_token_type(None, token_kind, None) {
+            self.push_classification(start, end - start, classification);
+        }
+    }
+
+    /// Returns true if node should be treated as classified and no further processing is required.
+    /// False will mean that node is not classified and traverse routine should recurse into node contents.
+    fn try_classify_node(
+        &mut self,
+        source_file: &SourceFile,
+        node: &Child,
+    ) -> Result<bool, OperationCanceled> {
+        let kind = node.kind(source_file);
+        if kind == SyntaxKind::JSDocComment {
+            return Ok(true);
+        }
+
+        let range = node.range(source_file);
+        if range.pos == range.end && kind != SyntaxKind::EndOfFileToken {
+            return Ok(true);
+        }
+
+        let classified_element_name = try_classify_jsx_element_name(source_file, node);
+        if !is_token_kind(kind) && kind != SyntaxKind::JsxText && classified_element_name.is_none()
+        {
+            return Ok(false);
+        }
+
+        let token_start = if kind == SyntaxKind::JsxText {
+            range.pos
+        } else {
+            self.classify_leading_trivia_and_get_token_start(source_file, node)?
+        };
+
+        debug_assert!(range.end >= token_start);
+        let token_width = range.end.saturating_sub(token_start);
+        if token_width > 0 {
+            let classification = classified_element_name
+                .or_else(|| classify_token_type(Some(source_file), kind, Some(node)));
+            if let Some(classification) = classification {
+                self.push_classification(token_start, token_width, classification);
+            }
+        }
+
+        Ok(true)
+    }
+
+    // Walks down with a stack of the children left to visit rather than recursively, since deeply
+    // nested expressions could overflow the call stack.
+    fn process_element(
+        &mut self,
+        source_file: &SourceFile,
+        element: Child,
+    ) -> Result<(), OperationCanceled> {
+        let mut stack = Vec::new();
+        self.push_children(source_file, &element, &mut stack)?;
+        while let Some(children) = stack.last_mut() {
+            match children.next() {
+                Some(child) => {
+                    if !self.try_classify_node(source_file, &child)? {
+                        // Recurse into our child nodes.
+                        self.push_children(source_file, &child, &mut stack)?;
+                    }
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_children(
+        &mut self,
+        source_file: &SourceFile,
+        element: &Child,
+        stack: &mut Vec<std::vec::IntoIter<Child>>,
+    ) -> Result<(), OperationCanceled> {
+        // Ignore nodes that don't intersect the original span to classify.
+        let range = element.range(source_file);
+        if range.pos <= self.span.end && range.end >= self.span.pos {
+            check_for_classification_cancellation(
+                self.cancellation_token,
+                element.kind(source_file),
+            )?;
+            stack.push(
+                element
+                    .children(source_file, &mut self.children_scanner)
+                    .into_iter(),
+            );
+        }
+        Ok(())
+    }
+}
+
+// The JSDoc comment starting at `start` that the parser attached to a node that starts with the
+// token, that is, to the token or one of its ancestors that begin where it does.
+fn find_attached_js_doc(source_file: &SourceFile, token: &Child, start: usize) -> Option<NodeId> {
+    let pos = token.range(source_file).pos;
+    let node = match token {
+        Child::Node(node) => *node,
+        Child::Token { parent, .. } | Child::SyntaxList { parent, .. } => *parent,
+    };
+    std::iter::once(node)
+        .chain(source_file.ancestors(node))
+        .take_while(|&node| source_file.node(node).pos() == pos)
+        .find_map(|node| {
+            source_file
+                .node(node)
+                .js_doc
+                .iter()
+                .flatten()
+                .copied()
+                .find(|&js_doc| source_file.node(js_doc).pos() == start)
+        })
+}
+
+fn try_classify_jsx_element_name(
+    source_file: &SourceFile,
+    token: &Child,
+) -> Option<ClassificationType> {
+    let token = match token {
+        Child::Node(token) => *token,
+        _ => return None,
+    };
+    let parent = source_file.node(source_file.parent(token)?);
+    match (parent.kind, &parent.data) {
+        (SyntaxKind::JsxOpeningElement, NodeData::JsxOpeningLikeElement { tag_name, .. })
+            if *tag_name == token =>
+        {
+            Some(ClassificationType::JsxOpenTagName)
+        }
+        (SyntaxKind::JsxClosingElement, NodeData::JsxClosingElement { tag_name })
+            if *tag_name == token =>
+        {
+            Some(ClassificationType::JsxCloseTagName)
+        }
+        (SyntaxKind::JsxSelfClosingElement, NodeData::JsxOpeningLikeElement { tag_name, .. })
+            if *tag_name == token =>
+        {
+            Some(ClassificationType::JsxSelfClosingTagName)
+        }
+        (SyntaxKind::JsxAttribute, NodeData::JsxAttribute { name, .. }) if *name == token => {
+            Some(ClassificationType::JsxAttribute)
+        }
+        _ => None,
+    }
+}
+
+// for accurate classification, the actual token should be passed in.  however, for
+// cases like 'disabled merge code' classification, we just get the token kind and
+// classify based on that instead.
+fn classify_token_type(
+    source_file: Option<&SourceFile>,
+    token_kind: SyntaxKind,
+    token: Option<&Child>,
+) -> Option<ClassificationType> {
+    let parent = source_file
+        .zip(token)
+        .and_then(|(source_file, token)| Some((source_file, token.parent(source_file)?)));
+    let parent_kind = parent.map(|(source_file, parent)| source_file.node(parent).kind);
+
+    if is_keyword(token_kind) {
+        return Some(ClassificationType::Keyword);
+    }
+
+    // Special case `<` and `>`: If they appear in a generic context they are punctuation,
+    // not operators.
+    if token_kind == SyntaxKind::LessThanToken || token_kind == SyntaxKind::GreaterThanToken {
+        // If the node owning the token has a type argument list or type parameter list, then
+        // we can effectively assume that a '<' and '>' belong to those lists.
+        if let Some((source_file, parent)) = parent {
+            if get_type_argument_or_type_parameter_list(source_file, parent).is_some() {
+                return Some(ClassificationType::Punctuation);
+            }
+        }
+    }
+
+    if is_punctuation(token_kind) {
+        if let Some(parent_kind) = parent_kind {
+            if token_kind == SyntaxKind::EqualsToken {
+                // the '=' in a variable declaration is special cased here.
+                if matches!(
+                    parent_kind,
+                    SyntaxKind::VariableDeclaration
+                        | SyntaxKind::PropertyDeclaration
+                        | SyntaxKind::Parameter
+                        | SyntaxKind::JsxAttribute
+                ) {
+                    return Some(ClassificationType::Operator);
+                }
+            }
+
+            if matches!(
+                parent_kind,
+                SyntaxKind::BinaryExpression
+                    | SyntaxKind::PrefixUnaryExpression
+                    | SyntaxKind::PostfixUnaryExpression
+                    | SyntaxKind::ConditionalExpression
+            ) {
+                return Some(ClassificationType::Operator);
+            }
+        }
+
+        return Some(ClassificationType::Punctuation);
+    }
+
+    match token_kind {
+        SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral) => {
+            Some(ClassificationType::NumericLiteral)
+        }
+        SyntaxKind::Literals(LiteralSyntaxKind::BigIntLiteral) => {
+            Some(ClassificationType::BigintLiteral)
+        }
+        SyntaxKind::Literals(LiteralSyntaxKind::StringLiteral) => {
+            Some(if parent_kind == Some(SyntaxKind::JsxAttribute) {
+                ClassificationType::JsxAttributeStringLiteralValue
+            } else {
+                ClassificationType::StringLiteral
+            })
+        }
+        SyntaxKind::Literals(LiteralSyntaxKind::RegularExpressionLiteral) => {
+            // TODO: we should get another classification type for these literals.
+            Some(ClassificationType::StringLiteral)
+        }
+        // TODO (drosen): we should *also* get another classification type for these literals.
+        _ if is_template_literal_kind(token_kind) => Some(ClassificationType::StringLiteral),
+        SyntaxKind::Literals(LiteralSyntaxKind::JsxText) => Some(ClassificationType::JsxText),
+        SyntaxKind::Identifier => {
+            if let (Some((source_file, parent)), Some(Child::Node(token))) = (parent, token) {
+                let parent = source_file.node(parent);
+                let is_name = parent.name() == Some(*token);
+                let classification = match parent.kind {
+                    SyntaxKind::ClassDeclaration => ClassificationType::ClassName,
+                    SyntaxKind::TypeParameter => ClassificationType::TypeParameterName,
+                    SyntaxKind::InterfaceDeclaration => ClassificationType::InterfaceName,
+                    SyntaxKind::EnumDeclaration => ClassificationType::EnumName,
+                    SyntaxKind::ModuleDeclaration => ClassificationType::ModuleName,
+                    SyntaxKind::Parameter if is_this_identifier(source_file.node(*token)) => {
+                        ClassificationType::Keyword
+                    }
+                    SyntaxKind::Parameter => ClassificationType::ParameterName,
+                    _ => return Some(ClassificationType::Identifier),
+                };
+                return if is_name { Some(classification) } else { None };
+            }
+            Some(ClassificationType::Identifier)
+        }
+        _ => None,
+    }
+}
+
+// The parts of a match of `^(\/\/\/\s*)(<)(?:(\S+)((?:[^/]|\/[^>])*)(\/>)?)?` with a tag name.
+struct TripleSlashXmlCommentMatch {
+    name: Range<usize>,
+    attributes: Range<usize>,
+    is_self_closing: bool,
+}
+
+fn match_triple_slash_xml_comment(text: &[u16]) -> Option<TripleSlashXmlCommentMatch> {
+    let slashes = u16::from(b'/');
+    if text.len() < 3 || text[..3] != [slashes; 3] {
+        return None;
+    }
+    let open = 3 + text[3..]
+        .iter()
+        .take_while(|&&ch| is_pragma_whitespace(ch))
+        .count();
+    if text.get(open) != Some(&u16::from(b'<')) {
+        return None;
+    }
+    let name_start = open + 1;
+    let name_end = name_start
+        + text[name_start..]
+            .iter()
+            .take_while(|&&ch| !is_pragma_whitespace(ch))
+            .count();
+    if name_end == name_start {
+        return None;
+    }
+    let mut attributes_end = name_end;
+    while let Some(&ch) = text.get(attributes_end) {
+        if ch != slashes {
+            attributes_end += 1;
+        } else if matches!(text.get(attributes_end + 1), Some(&next) if next != u16::from(b'>')) {
+            attributes_end += 2;
+        } else {
+            break;
+        }
+    }
+    Some(TripleSlashXmlCommentMatch {
+        name: name_start..name_end,
+        attributes: name_end..attributes_end,
+        is_self_closing: text[attributes_end..].starts_with(&[slashes, u16::from(b'>')]),
+    })
+}
+
+// A match of `(\s)(\S+)(\s*)(=)(\s*)('[^']+'|"[^"]+")`: where it starts, where it ends and the
+// lengths of its groups.
+struct TripleSlashAttributeMatch {
+    index: usize,
+    end: usize,
+    name_length: usize,
+    whitespace_before_equals: usize,
+    whitespace_after_equals: usize,
+    value_length: usize,
+}
+
+// Finds the first match at or after `last_index`, like `exec` of a global regular expression.
+fn match_triple_slash_attribute(
+    text: &[u16],
+    last_index: usize,
+) -> Option<TripleSlashAttributeMatch> {
+    let skip_whitespace = |pos: usize| {
+        pos + text[pos..]
+            .iter()
+            .take_while(|&&ch| is_pragma_whitespace(ch))
+            .count()
+    };
+    (last_index..text.len())
+        .filter(|&index| is_pragma_whitespace(text[index]))
+        .find_map(|index| {
+            let name_start = index + 1;
+            let longest_name_end = name_start
+                + text[name_start..]
+                    .iter()
+                    .take_while(|&&ch| !is_pragma_whitespace(ch))
+                    .count();
+            // The name backtracks from the longest run of non-whitespace.
+            (name_start + 1..=longest_name_end)
+                .rev()
+                .find_map(|name_end| {
+                    let equals = skip_whitespace(name_end);
+                    if text.get(equals) != Some(&u16::from(b'=')) {
+                        return None;
+                    }
+                    let quote_pos = skip_whitespace(equals + 1);
+                    let quote = *text.get(quote_pos)?;
+                    if quote != u16::from(b'\'') && quote != u16::from(b'"') {
+                        return None;
+                    }
+                    let value_length = text[quote_pos + 1..].iter().position(|&ch| ch == quote)?;
+                    if value_length == 0 {
+                        return None;
+                    }
+                    let end = quote_pos + value_length + 2;
+                    Some(TripleSlashAttributeMatch {
+                        index,
+                        end,
+                        name_length: name_end - name_start,
+                        whitespace_before_equals: equals - name_end,
+                        whitespace_after_equals: quote_pos - equals - 1,
+                        value_length: end - quote_pos,
+                    })
+                })
+        })
+}
@@ -0,0 +1,5 @@
+pub mod classifier;
+pub mod navigation_bar;
+pub mod outlining_elements_collector;
+pub mod types;
+pub mod utilities;
@@ -0,0 +1,1509 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::compiler::atom::unescape_leading_underscores;
+use crate::compiler::core::compare_strings_case_sensitive_ui;
+use crate::compiler::parser::for_each_child;
+use crate::compiler::path::{get_base_file_name, normalize_path};
+use crate::compiler::scanner::skip_trivia;
+use crate::compiler::types::{
+    AssignmentDeclarationKind, CancellationToken, ModifierFlags, NodeData, NodeId,
+    OperationCanceled, SourceFile, SyntaxKind, TextRange,
+};
+use crate::compiler::utilities::{
+    escape_string, get_assignment_declaration_kind, get_element_or_property_access_name,
+    get_name_of_declaration, get_name_or_argument, get_property_name_for_property_name_node,
+    get_syntactic_modifier_flags, get_text_of_identifier_or_literal, get_token_pos_of_node,
+    has_dynamic_name, has_syntactic_modifier, is_ambient_module,
+    is_bindable_static_access_expression, is_binding_pattern, is_declaration, is_expression_kind,
+    is_parameter_property_declaration, is_property_name_kind, is_string_literal_like,
+    is_token_kind, remove_file_extension,
+};
+
+use super::types::{NavigationTree, ScriptElementKind, ScriptElementKindModifier};
+use super::utilities::{get_node_kind, get_node_modifiers};
+
+/// The declarations of the file as a tree, like `getNavigationTree` of the JS services: the
+/// classes, interfaces, enums, namespaces, functions, variables and members under the item of
+/// the file, with the declarations of the same name merged into one item and the children of
+/// each item sorted by name. Only the syntax tree is needed. Positions are in UTF-16 code units.
+pub fn get_navigation_tree(
+    source_file: &SourceFile,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<NavigationTree, OperationCanceled> {
+    let text: Vec<u16> = source_file.text.encode_utf16().collect();
+    let mut navigation_bar = NavigationBar {
+        source_file,
+        text: &text,
+        cancellation_token,
+        nodes: vec![NavigationBarNode {
+            node: Declaration::Node(source_file.root),
+            name: None,
+            additional_nodes: None,
+            children: None,
+        }],
+        synthesized: Vec::new(),
+        ends: HashMap::new(),
+        parent: ROOT,
+        parents_stack: Vec::new(),
+        tracked_es5_classes: None,
+        tracked_es5_classes_stack: Vec::new(),
+    };
+    for &statement in source_file.statements().iter() {
+        navigation_bar.add_children_recursively(statement)?;
+    }
+    navigation_bar.merge_and_sort_children(ROOT);
+    Ok(navigation_bar.convert_to_tree(ROOT))
+}
+
+/// Maximum amount of characters to return
+/// The amount was chosen arbitrarily.
+const MAX_LENGTH: usize = 150;
+
+const ROOT: usize = 0;
+
+// A node an item is made from: a node of the tree, or one of the class and constructor
+// declarations made up when the members of an ES5 class are merged into one item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Declaration {
+    Node(NodeId),
+    Synthesized(usize),
+}
+
+struct SynthesizedDeclaration {
+    // `ClassDeclaration` or `Constructor`
+    kind: SyntaxKind,
+    range: TextRange,
+    name: Option<Name>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Name {
+    Node(NodeId),
+    // An identifier with the text and range of a string or numeric literal, which names the
+    // property an `Object.defineProperty` call defines.
+    Identifier(NodeId),
+    // The `__class__` identifier of an ES5 class made up without a name, which has no range.
+    Class,
+}
+
+/// Represents a navigation bar item and its children.
+/// The returned NavigationTree is more complicated, so we use these to do work before converting.
+#[derive(Clone)]
+struct NavigationBarNode {
+    node: Declaration,
+    name: Option<Name>,
+    additional_nodes: Option<Vec<Declaration>>,
+    children: Option<Vec<usize>>,
+}
+
+// The work of `addChildrenRecursively`, which is kept on a stack rather than done recursively,
+// since the nesting of the expressions of a file can be deep enough to overflow the stack.
+enum Task {
+    Visit(NodeId),
+    Start(NodeId, Option<Name>),
+    End,
+    Leaf(NodeId, Option<Name>),
+}
+
+struct NavigationBar<'a> {
+    source_file: &'a SourceFile,
+    text: &'a [u16],
+    cancellation_token: Option<&'a CancellationToken>,
+    nodes: Vec<NavigationBarNode>,
+    synthesized: Vec<SynthesizedDeclaration>,
+    // The ends of the nodes an ES5 class item grew over, which later merges compare.
+    ends: HashMap<NodeId, usize>,
+    // For performance, we keep navigation bar parents on a stack rather than passing them through
+    // each recursion. `parent` is the current parent and is *not* stored in `parents_stack`.
+    parent: usize,
+    parents_stack: Vec<usize>,
+    tracked_es5_classes: Option<HashSet<String>>,
+    tracked_es5_classes_stack: Vec<Option<HashSet<String>>>,
+}
+
+impl NavigationBar<'_> {
+    fn throw_if_cancellation_requested(&self) -> Result<(), OperationCanceled> {
+        match self.cancellation_token {
+            Some(cancellation_token) => cancellation_token.throw_if_cancellation_requested(),
+            None => Ok(()),
+        }
+    }
+
+    fn kind(&self, declaration: Declaration) -> SyntaxKind {
+        match declaration {
+            Declaration::Node(node) => self.source_file.node(node).kind,
+            Declaration::Synthesized(index) => self.synthesized[index].kind,
+        }
+    }
+
+    fn range(&self, declaration: Declaration) -> TextRange {
+        match declaration {
+            Declaration::Node(node) => {
+                let range = self.source_file.node(node).range;
+                TextRange {
+                    pos: range.pos,
+                    end: self.ends.get(&node).copied().unwrap_or(range.end),
+                }
+            }
+            Declaration::Synthesized(index) => self.synthesized[index].range,
+        }
+    }
+
+    fn set_end(&mut self, declaration: Declaration, end: usize) {
+        match declaration {
+            Declaration::Node(node) => {
+                self.ends.insert(node, end);
+            }
+            Declaration::Synthesized(index) => self.synthesized[index].range.end = end,
+        }
+    }
+
+    fn synthesize(
+        &mut self,
+        kind: SyntaxKind,
+        range: TextRange,
+        name: Option<Name>,
+    ) -> Declaration {
+        self.synthesized
+            .push(SynthesizedDeclaration { kind, range, name });
+        Declaration::Synthesized(self.synthesized.len() - 1)
+    }
+
+    fn name_of_declaration(&self, declaration: Declaration) -> Option<Name> {
+        match declaration {
+            Declaration::Node(node) => {
+                get_name_of_declaration(self.source_file, node).map(Name::Node)
+            }
+            Declaration::Synthesized(index) => self.synthesized[index].name,
+        }
+    }
+
+    // The text of the node without its leading trivia, like `getText`.
+    fn get_text(&self, node: NodeId) -> String {
+        let data = self.source_file.node(node);
+        let start = get_token_pos_of_node(data, self.text);
+        String::from_utf16_lossy(&self.text[start..data.end().max(start)])
+    }
+
+    fn node_text(&self, node: NodeId) -> String {
+        clean_text(&self.get_text(node))
+    }
+
+    fn name_text(&self, name: Name) -> String {
+        match name {
+            Name::Node(node) | Name::Identifier(node) => self.node_text(node),
+            Name::Class => String::new(),
+        }
+    }
+
+    fn push_child(&mut self, parent: usize, child: usize) {
+        self.nodes[parent]
+            .children
+            .get_or_insert_with(Vec::new)
+            .push(child);
+    }
+
+    fn empty_navigation_bar_node(&mut self, node: Declaration, name: Option<Name>) -> usize {
+        let name = name.or_else(|| {
+            let has_name = match node {
+                Declaration::Node(node) => {
+                    is_declaration(self.source_file, node)
+                        || is_expression_kind(self.source_file.node(node).kind)
+                }
+                Declaration::Synthesized(_) => true,
+            };
+            if has_name {
+                self.name_of_declaration(node)
+            } else {
+                None
+            }
+        });
+        self.nodes.push(NavigationBarNode {
+            node,
+            name,
+            additional_nodes: None,
+            children: None,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn add_leaf_node(&mut self, node: NodeId, name: Option<Name>) {
+        let child = self.empty_navigation_bar_node(Declaration::Node(node), name);
+        self.push_child(self.parent, child);
+    }
+
+    fn add_tracked_es5_class(&mut self, name: &str) {
+        self.tracked_es5_classes
+            .get_or_insert_with(HashSet::new)
+            .insert(name.to_string());
+    }
+
+    /// Add a new level of NavigationBarNodes.
+    /// This pushes to the stack, so you must call `end_node` when you are done adding to this node.
+    fn start_node(&mut self, node: NodeId, name: Option<Name>) {
+        let navigation_node = self.empty_navigation_bar_node(Declaration::Node(node), name);
+        self.push_child(self.parent, navigation_node);
+
+        // Save the old parent
+        self.parents_stack.push(self.parent);
+        self.tracked_es5_classes_stack
+            .push(self.tracked_es5_classes.take());
+        self.parent = navigation_node;
+    }
+
+    /// Call after calling `start_node` and adding children to it.
+    fn end_node(&mut self) {
+        self.merge_and_sort_children(self.parent);
+        if let Some(parent) = self.parents_stack.pop() {
+            self.parent = parent;
+            self.tracked_es5_classes = self.tracked_es5_classes_stack.pop().flatten();
+        }
+    }
+
+    fn merge_and_sort_children(&mut self, node: usize) {
+        if self.nodes[node].children.is_some() {
+            self.merge_children(node);
+            self.sort_children(node);
+        }
+    }
+
+    /// Look for navigation bar items in node's subtree, adding them to the current `parent`.
+    fn add_children_recursively(&mut self, node: NodeId) -> Result<(), OperationCanceled> {
+        let mut stack = vec![Task::Visit(node)];
+        while let Some(task) = stack.pop() {
+            match task {
+                Task::Visit(node) => {
+                    self.throw_if_cancellation_requested()?;
+                    let mut tasks = Vec::new();
+                    self.visit(node, &mut tasks);
+                    stack.extend(tasks.into_iter().rev());
+                }
+                Task::Start(node, name) => self.start_node(node, name),
+                Task::End => self.end_node(),
+                Task::Leaf(node, name) => self.add_leaf_node(node, name),
+            }
+        }
+        Ok(())
+    }
+
+    // The tasks of one step of `addChildrenRecursively`, in the order they are to be done in.
+    fn visit(&mut self, node: NodeId, tasks: &mut Vec<Task>) {
+        let source_file = self.source_file;
+        let data = source_file.node(node);
+        if is_token_kind(data.kind) {
+            return;
+        }
+
+        match data.kind {
+            SyntaxKind::Constructor => {
+                // Get parameter properties, and treat them as being on the *same* level as the constructor, not under it.
+                add_node_with_recursive_child(tasks, node, data.body(), None);
+
+                // Parameter properties are children of the class, not the constructor.
+                for &parameter in data
+                    .parameters()
+                    .into_iter()
+                    .flat_map(|parameters| parameters.iter())
+                {
+                    if is_parameter_property_declaration(source_file, parameter, node) {
+                        tasks.push(Task::Leaf(parameter, None));
+                    }
+                }
+                return;
+            }
+            SyntaxKind::MethodDeclaration
+            | SyntaxKind::GetAccessor
+            | SyntaxKind::SetAccessor
+            | SyntaxKind::MethodSignature => {
+                if has_navigation_bar_name(source_file, node) {
+                    add_node_with_recursive_child(tasks, node, data.body(), None);
+                }
+                return;
+            }
+            SyntaxKind::PropertyDeclaration => {
+                if has_navigation_bar_name(source_file, node) {
+                    add_node_with_recursive_initializer(source_file, tasks, node);
+                }
+                return;
+            }
+            SyntaxKind::PropertySignature => {
+                if has_navigation_bar_name(source_file, node) {
+                    tasks.push(Task::Leaf(node, None));
+                }
+                return;
+            }
+            SyntaxKind::ImportClause => {
+                if let NodeData::ImportClause {
+                    name,
+                    named_bindings,
+                    ..
+                } = &data.data
+                {
+                    // Handle default import case e.g.:
+                    //    import d from "mod";
+                    if let Some(name) = *name {
+                        tasks.push(Task::Leaf(name, None));
+                    }
+
+                    // Handle named bindings in imports e.g.:
+                    //    import * as NS from "mod";
+                    //    import {a, b as B} from "mod";
+                    if let Some(named_bindings) = *named_bindings {
+                        let named_bindings_node = source_file.node(named_bindings);
+                        if named_bindings_node.kind == SyntaxKind::NamespaceImport {
+                            tasks.push(Task::Leaf(named_bindings, None));
+                        } else {
+                            for &element in named_bindings_node
+                                .elements()
+                                .into_iter()
+                                .flat_map(|elements| elements.iter())
+                            {
+                                tasks.push(Task::Leaf(element, None));
+                            }
+                        }
+                    }
+                }
+                return;
+            }
+            SyntaxKind::ShorthandPropertyAssignment => {
+                add_node_with_recursive_child(tasks, node, data.name(), None);
+                return;
+            }
+            SyntaxKind::SpreadAssignment => {
+                // Use the expression as the name of the SpreadAssignment, otherwise show as <unknown>.
+                let name = data
+                    .expression()
+                    .filter(|&expression| {
+                        source_file.node(expression).kind == SyntaxKind::Identifier
+                    })
+                    .map(Name::Node);
+                tasks.push(Task::Leaf(node, name));
+                return;
+            }
+            SyntaxKind::BindingElement
+            | SyntaxKind::PropertyAssignment
+            | SyntaxKind::VariableDeclaration => {
+                match data.name() {
+                    Some(name) if is_binding_pattern(source_file.node(name)) => {
+                        tasks.push(Task::Visit(name))
+                    }
+                    _ => add_node_with_recursive_initializer(source_file, tasks, node),
+                }
+                return;
+            }
+            SyntaxKind::FunctionDeclaration => {
+                // If we see a function declaration track as a possible ES5 class
+                if let Some(name) = data.name() {
+                    let name = source_file.node(name);
+                    if name.kind == SyntaxKind::Identifier {
+                        self.add_tracked_es5_class(name.text().unwrap_or_default());
+                    }
+                }
+                add_node_with_recursive_child(tasks, node, data.body(), None);
+                return;
+            }
+            SyntaxKind::ArrowFunction | SyntaxKind::FunctionExpression => {
+                add_node_with_recursive_child(tasks, node, data.body(), None);
+                return;
+            }
+            SyntaxKind::EnumDeclaration => {
+                tasks.push(Task::Start(node, None));
+                for &member in data
+                    .members()
+                    .into_iter()
+                    .flat_map(|members| members.iter())
+                {
+                    if !is_computed_property(source_file, member) {
+                        tasks.push(Task::Leaf(member, None));
+                    }
+                }
+                tasks.push(Task::End);
+                return;
+            }
+            SyntaxKind::ClassDeclaration
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::InterfaceDeclaration => {
+                tasks.push(Task::Start(node, None));
+                tasks.extend(
+                    data.members()
+                        .into_iter()
+                        .flat_map(|members| members.iter())
+                        .map(|&member| Task::Visit(member)),
+                );
+                tasks.push(Task::End);
+                return;
+            }
+            SyntaxKind::ModuleDeclaration => {
+                let body = source_file
+                    .node(get_interior_module(source_file, node))
+                    .body();
+                add_node_with_recursive_child(tasks, node, body, None);
+                return;
+            }
+            SyntaxKind::ExportAssignment => {
+                let child = data.expression().and_then(|expression| {
+                    let expression_node = source_file.node(expression);
+                    match expression_node.kind {
+                        SyntaxKind::ObjectLiteralExpression | SyntaxKind::CallExpression => {
+                            Some(expression)
+                        }
+                        SyntaxKind::ArrowFunction | SyntaxKind::FunctionExpression => {
+                            expression_node.body()
+                        }
+                        _ => None,
+                    }
+                });
+                match child {
+                    Some(child) => add_node_with_recursive_child(tasks, node, Some(child), None),
+                    None => tasks.push(Task::Leaf(node, None)),
+                }
+                return;
+            }
+            SyntaxKind::ExportSpecifier
+            | SyntaxKind::ImportEqualsDeclaration
+            | SyntaxKind::IndexSignature
+            | SyntaxKind::CallSignature
+            | SyntaxKind::ConstructSignature
+            | SyntaxKind::TypeAliasDeclaration => {
+                tasks.push(Task::Leaf(node, None));
+                return;
+            }
+            SyntaxKind::CallExpression | SyntaxKind::BinaryExpression
+                if self.visit_assignment_declaration(node, tasks) =>
+            {
+                return;
+            }
+            _ => {}
+        }
+
+        for &js_doc in data.js_doc.iter().flatten() {
+            if let NodeData::JSDoc {
+                tags: Some(tags), ..
+            } = &source_file.node(js_doc).data
+            {
+                for &tag in tags.iter() {
+                    if matches!(
+                        source_file.node(tag).kind,
+                        SyntaxKind::JSDocTypedefTag
+                            | SyntaxKind::JSDocCallbackTag
+                            | SyntaxKind::JSDocEnumTag
+                    ) {
+                        tasks.push(Task::Leaf(tag, None));
+                    }
+                }
+            }
+        }
+
+        add_each_child(source_file, tasks, node);
+    }
+
+    // The items of the assignments and `Object.defineProperty` calls that declare something, or
+    // `false` if the node is to be treated as any other.
+    fn visit_assignment_declaration(&mut self, node: NodeId, tasks: &mut Vec<Task>) -> bool {
+        let source_file = self.source_file;
+        let data = source_file.node(node);
+        let special = get_assignment_declaration_kind(source_file, node);
+        match special {
+            AssignmentDeclarationKind::ExportsProperty
+            | AssignmentDeclarationKind::ModuleExports => {
+                if let NodeData::BinaryExpression { right, .. } = &data.data {
+                    add_node_with_recursive_child(tasks, node, Some(*right), None);
+                }
+                true
+            }
+            AssignmentDeclarationKind::Prototype | AssignmentDeclarationKind::PrototypeProperty => {
+                let (assignment_target, right) = match &data.data {
+                    NodeData::BinaryExpression { left, right, .. } => (*left, *right),
+                    _ => return true,
+                };
+                let prototype_access = if special == AssignmentDeclarationKind::PrototypeProperty {
+                    source_file.node(assignment_target).expression()
+                } else {
+                    Some(assignment_target)
+                };
+                let class_expression = match prototype_access
+                    .and_then(|access| source_file.node(access).expression())
+                {
+                    Some(expression) => expression,
+                    None => return true,
+                };
+
+                // If we see a prototype assignment, start tracking the target as a class
+                // This is only done for simple classes not nested assignments.
+                let class_name_node = source_file.node(class_expression);
+                let (depth, class_name) = if class_name_node.kind == SyntaxKind::Identifier {
+                    self.add_tracked_es5_class(class_name_node.text().unwrap_or_default());
+                    (0, class_expression)
+                } else {
+                    start_nested_nodes(source_file, tasks, node, class_expression)
+                };
+                let right_node = source_file.node(right);
+                if special == AssignmentDeclarationKind::Prototype {
+                    if let NodeData::ObjectLiteralExpression { properties, .. } = &right_node.data {
+                        if !properties.is_empty() {
+                            tasks.push(Task::Start(node, Some(Name::Node(class_name))));
+                            add_each_child(source_file, tasks, right);
+                            tasks.push(Task::End);
+                        }
+                    }
+                } else if matches!(
+                    right_node.kind,
+                    SyntaxKind::FunctionExpression | SyntaxKind::ArrowFunction
+                ) {
+                    add_node_with_recursive_child(
+                        tasks,
+                        node,
+                        Some(right),
+                        Some(Name::Node(class_name)),
+                    );
+                } else {
+                    let member_name = match &source_file.node(assignment_target).data {
+                        NodeData::PropertyAccessExpression { name, .. } => Some(Name::Node(*name)),
+                        _ => None,
+                    };
+                    tasks.push(Task::Start(node, Some(Name::Node(class_name))));
+                    add_node_with_recursive_child(tasks, node, Some(right), member_name);
+                    tasks.push(Task::End);
+                }
+                tasks.extend((0..depth).map(|_| Task::End));
+                true
+            }
+            AssignmentDeclarationKind::ObjectDefinePropertyValue
+            | AssignmentDeclarationKind::ObjectDefinePrototypeProperty => {
+                let arguments = match data.arguments() {
+                    Some(arguments) if arguments.len() == 3 => arguments,
+                    _ => return true,
+                };
+                let class_name = if special == AssignmentDeclarationKind::ObjectDefinePropertyValue
+                {
+                    Some(arguments[0])
+                } else {
+                    source_file.node(arguments[0]).expression()
+                };
+                let class_name = match class_name {
+                    Some(class_name) => class_name,
+                    None => return true,
+                };
+                let member_name = arguments[1];
+                let (depth, class_name_identifier) =
+                    start_nested_nodes(source_file, tasks, node, class_name);
+                tasks.push(Task::Start(node, Some(Name::Node(class_name_identifier))));
+                tasks.push(Task::Start(node, Some(Name::Identifier(member_name))));
+                tasks.push(Task::Visit(arguments[2]));
+                tasks.push(Task::End);
+                tasks.push(Task::End);
+                tasks.extend((0..depth).map(|_| Task::End));
+                true
+            }
+            AssignmentDeclarationKind::Property => {
+                let (assignment_target, right) = match &data.data {
+                    NodeData::BinaryExpression { left, right, .. } => (*left, *right),
+                    _ => return false,
+                };
+                let target_function = match source_file.node(assignment_target).expression() {
+                    Some(target_function) => target_function,
+                    None => return false,
+                };
+                let target_function_node = source_file.node(target_function);
+                let is_tracked_es5_class = target_function_node.kind == SyntaxKind::Identifier
+                    && get_element_or_property_access_name(source_file, assignment_target)
+                        .as_deref()
+                        != Some("prototype")
+                    && matches!(
+                        (&self.tracked_es5_classes, target_function_node.text()),
+                        (Some(classes), Some(text)) if classes.contains(text)
+                    );
+                if !is_tracked_es5_class {
+                    return false;
+                }
+                if matches!(
+                    source_file.node(right).kind,
+                    SyntaxKind::FunctionExpression | SyntaxKind::ArrowFunction
+                ) {
+                    add_node_with_recursive_child(
+                        tasks,
+                        node,
+                        Some(right),
+                        Some(Name::Node(target_function)),
+                    );
+                } else if is_bindable_static_access_expression(
+                    source_file,
+                    assignment_target,
+                    false,
+                ) {
+                    tasks.push(Task::Start(node, Some(Name::Node(target_function))));
+                    let member_name =
+                        get_name_or_argument(source_file, assignment_target).map(Name::Node);
+                    add_node_with_recursive_child(
+                        tasks,
+                        assignment_target,
+                        Some(right),
+                        member_name,
+                    );
+                    tasks.push(Task::End);
+                }
+                true
+            }
+            AssignmentDeclarationKind::ThisProperty
+            | AssignmentDeclarationKind::None
+            | AssignmentDeclarationKind::ObjectDefinePropertyExports => false,
+        }
+    }
+
+    /// Merge declarations of the same kind.
+    fn merge_children(&mut self, node: usize) {
+        let children = match self.nodes[node].children.clone() {
+            Some(children) => children,
+            None => return,
+        };
+        let mut name_to_items: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut kept = Vec::with_capacity(children.len());
+        'children: for (index, &child) in children.iter().enumerate() {
+            let declaration_name = self.nodes[child]
+                .name
+                .or_else(|| self.name_of_declaration(self.nodes[child].node));
+            let name = declaration_name
+                .map(|name| self.name_text(name))
+                .unwrap_or_default();
+            if name.is_empty() {
+                // Anonymous items are never merged.
+                kept.push(child);
+                continue;
+            }
+
+            let items_with_same_name = name_to_items.get(&name).cloned().unwrap_or_default();
+            for item_with_same_name in items_with_same_name {
+                if self.try_merge(item_with_same_name, child, index, &children, node) {
+                    continue 'children;
+                }
+            }
+            name_to_items.entry(name).or_default().push(child);
+            kept.push(child);
+        }
+        self.nodes[node].children = Some(kept);
+    }
+
+    fn try_merge(
+        &mut self,
+        a: usize,
+        b: usize,
+        b_index: usize,
+        siblings: &[usize],
+        parent: usize,
+    ) -> bool {
+        if self.try_merge_es5_class(a, b, b_index, siblings) {
+            return true;
+        }
+        if self.should_really_merge(self.nodes[a].node, self.nodes[b].node, parent) {
+            self.merge(a, b);
+            return true;
+        }
+        false
+    }
+
+    fn assignment_declaration_kind(&self, declaration: Declaration) -> AssignmentDeclarationKind {
+        match declaration {
+            Declaration::Node(node)
+                if matches!(
+                    self.source_file.node(node).kind,
+                    SyntaxKind::BinaryExpression | SyntaxKind::CallExpression
+                ) =>
+            {
+                get_assignment_declaration_kind(self.source_file, node)
+            }
+            _ => AssignmentDeclarationKind::None,
+        }
+    }
+
+    fn is_possible_constructor(&self, declaration: Declaration) -> bool {
+        matches!(
+            declaration,
+            Declaration::Node(node) if matches!(
+                self.source_file.node(node).kind,
+                SyntaxKind::FunctionExpression | SyntaxKind::FunctionDeclaration | SyntaxKind::VariableDeclaration
+            )
+        )
+    }
+
+    fn is_class_declaration(&self, declaration: Declaration) -> bool {
+        self.kind(declaration) == SyntaxKind::ClassDeclaration
+    }
+
+    // The siblings are the children of the parent of `b` as they were before merging, for the one
+    // before `b`. The cases are kept the way the services spell them.
+    #[allow(clippy::nonminimal_bool)]
+    fn try_merge_es5_class(
+        &mut self,
+        a: usize,
+        b: usize,
+        b_index: usize,
+        siblings: &[usize],
+    ) -> bool {
+        let a_node = self.nodes[a].node;
+        let b_node = self.nodes[b].node;
+        let a_assignment_declaration_kind = self.assignment_declaration_kind(a_node);
+        let b_assignment_declaration_kind = self.assignment_declaration_kind(b_node);
+        let is_a_member = is_es5_class_member(a_assignment_declaration_kind);
+        let is_b_member = is_es5_class_member(b_assignment_declaration_kind);
+        let is_a_synthesized_class =
+            self.is_class_declaration(a_node) && matches!(a_node, Declaration::Synthesized(_));
+
+        // We treat this as an es5 class and merge the nodes in in one of several cases
+        if !((is_b_member && is_a_member) // merge two class elements
+            || (self.is_possible_constructor(a_node) && is_b_member) // ctor function & member
+            || (self.is_possible_constructor(b_node) && is_a_member) // member & ctor function
+            || (is_a_synthesized_class && is_b_member) // class (generated) & member
+            || (self.is_class_declaration(b_node) && is_a_member) // member & class (generated)
+            || (is_a_synthesized_class && self.is_possible_constructor(b_node)) // class (generated) & ctor
+            || (self.is_class_declaration(b_node) && self.is_possible_constructor(a_node) && matches!(a_node, Declaration::Synthesized(_))))
+        // ctor & class (generated)
+        {
+            return b_assignment_declaration_kind != AssignmentDeclarationKind::None;
+        }
+
+        let mut last_a_node = self.nodes[a]
+            .additional_nodes
+            .as_ref()
+            .and_then(|nodes| nodes.last().copied())
+            .unwrap_or(a_node);
+        let a_name = self.nodes[a].name;
+
+        if (!self.is_class_declaration(a_node) && !self.is_class_declaration(b_node)) // If neither outline node is a class
+            || self.is_possible_constructor(a_node) || self.is_possible_constructor(b_node)
+        // If either function is a constructor function
+        {
+            let ctor_function = if self.is_possible_constructor(a_node) {
+                Some(a_node)
+            } else if self.is_possible_constructor(b_node) {
+                Some(b_node)
+            } else {
+                None
+            };
+
+            if let Some(ctor_function) = ctor_function {
+                let ctor_node =
+                    self.synthesize(SyntaxKind::Constructor, self.range(ctor_function), None);
+                let ctor = self.empty_navigation_bar_node(ctor_node, None);
+                self.nodes[ctor].children = if a_node == ctor_function {
+                    self.nodes[a].children.clone()
+                } else {
+                    self.nodes[b].children.clone()
+                };
+                self.nodes[a].children = Some(if a_node == ctor_function {
+                    let mut children = vec![ctor];
+                    children.extend(self.nodes[b].children.clone().unwrap_or_else(|| vec![b]));
+                    children
+                } else {
+                    let mut children = self.a_children_or_copy(a);
+                    children.push(ctor);
+                    children
+                });
+            } else if self.nodes[a].children.is_some() || self.nodes[b].children.is_some() {
+                let mut children = self.a_children_or_copy(a);
+                children.extend(self.nodes[b].children.clone().unwrap_or_else(|| vec![b]));
+                self.nodes[a].children = Some(children);
+                self.merge_and_sort_children(a);
+            }
+
+            let range = self.range(a_node);
+            let class_node = self.synthesize(
+                SyntaxKind::ClassDeclaration,
+                range,
+                Some(a_name.unwrap_or(Name::Class)),
+            );
+            self.nodes[a].node = class_node;
+            last_a_node = class_node;
+        } else {
+            let b_children = self.nodes[b].children.clone();
+            self.nodes[a].children = concatenate(self.nodes[a].children.take(), b_children);
+            if self.nodes[a].children.is_some() {
+                self.merge_children(a);
+            }
+        }
+
+        // We merge if the outline node previous to b (b_index - 1) is already part of the current class
+        // We do this so that statements between class members that do not generate outline nodes do not split up the class outline:
+        // Ex This should produce one outline node C:
+        //    function C() {}; a = 1; C.prototype.m = function () {}
+        // Ex This will produce 3 outline nodes: C, a, C
+        //    function C() {}; let a = 1; C.prototype.m = function () {}
+        let b_range = self.range(b_node);
+        let previous = b_index
+            .checked_sub(1)
+            .map(|index| self.nodes[siblings[index]].node);
+        if matches!(previous, Some(previous) if self.range(previous).end == self.range(last_a_node).end)
+        {
+            self.set_end(last_a_node, b_range.end);
+        } else {
+            let class_node = self.synthesize(
+                SyntaxKind::ClassDeclaration,
+                b_range,
+                Some(a_name.unwrap_or(Name::Class)),
+            );
+            self.nodes[a]
+                .additional_nodes
+                .get_or_insert_with(Vec::new)
+                .push(class_node);
+        }
+        true
+    }
+
+    // The children of `a`, or a copy of `a` in their place if it has none.
+    fn a_children_or_copy(&mut self, a: usize) -> Vec<usize> {
+        match self.nodes[a].children.clone() {
+            Some(children) => children,
+            None => {
+                self.nodes.push(self.nodes[a].clone());
+                vec![self.nodes.len() - 1]
+            }
+        }
+    }
+
+    /// a and b have the same name, but they may not be mergeable.
+    fn should_really_merge(&self, a: Declaration, b: Declaration, parent: usize) -> bool {
+        let source_file = self.source_file;
+        let parent_of = |declaration| match declaration {
+            Declaration::Node(node) => source_file.parent(node),
+            Declaration::Synthesized(_) => None,
+        };
+        if self.kind(a) != self.kind(b)
+            || parent_of(a) != parent_of(b)
+                && !(self.is_own_child(a, parent) && self.is_own_child(b, parent))
+        {
+            return false;
+        }
+        let (a, b) = match (a, b) {
+            (Declaration::Node(a), Declaration::Node(b)) => (a, b),
+            _ => return true,
+        };
+        match source_file.node(a).kind {
+            SyntaxKind::PropertyDeclaration
+            | SyntaxKind::MethodDeclaration
+            | SyntaxKind::GetAccessor
+            | SyntaxKind::SetAccessor => {
+                has_syntactic_modifier(source_file, a, ModifierFlags::STATIC)
+                    == has_syntactic_modifier(source_file, b, ModifierFlags::STATIC)
+            }
+            SyntaxKind::ModuleDeclaration => {
+                are_same_module(source_file, a, b)
+                    && get_fully_qualified_module_name(source_file, a)
+                        == get_fully_qualified_module_name(source_file, b)
+            }
+            _ => true,
+        }
+    }
+
+    // We want to merge own children like `I` in in `module A { interface I {} } module A { interface I {} }`
+    // We don't want to merge unrelated children like `m` in `const o = { a: { m() {} }, b: { m() {} } };`
+    fn is_own_child(&self, declaration: Declaration, parent: usize) -> bool {
+        let source_file = self.source_file;
+        let parent_node = match declaration {
+            Declaration::Node(node) => source_file.parent(node),
+            Declaration::Synthesized(_) => None,
+        };
+        let parent_node = match parent_node {
+            Some(parent_node) if source_file.node(parent_node).kind == SyntaxKind::ModuleBlock => {
+                source_file.parent(parent_node)
+            }
+            parent_node => parent_node,
+        };
+        let parent_node = match parent_node {
+            Some(parent_node) => Declaration::Node(parent_node),
+            None => return false,
+        };
+        let parent = &self.nodes[parent];
+        parent.node == parent_node
+            || matches!(&parent.additional_nodes, Some(nodes) if nodes.contains(&parent_node))
+    }
+
+    /// Merge source into target. Source should be thrown away after this is called.
+    fn merge(&mut self, target: usize, source: usize) {
+        let source_node = self.nodes[source].node;
+        let source_additional_nodes = self.nodes[source].additional_nodes.clone();
+        let additional_nodes = self.nodes[target]
+            .additional_nodes
+            .get_or_insert_with(Vec::new);
+        additional_nodes.push(source_node);
+        additional_nodes.extend(source_additional_nodes.into_iter().flatten());
+
+        let source_children = self.nodes[source].children.clone();
+        self.nodes[target].children =
+            concatenate(self.nodes[target].children.take(), source_children);
+        self.merge_and_sort_children(target);
+    }
+
+    fn sort_children(&mut self, node: usize) {
+        let mut children: Vec<(Option<String>, SyntaxKind, usize)> =
+            match &self.nodes[node].children {
+                Some(children) => children
+                    .iter()
+                    .map(|&child| {
+                        let declaration = self.nodes[child].node;
+                        (
+                            self.try_get_name(declaration),
+                            self.kind(declaration),
+                            child,
+                        )
+                    })
+                    .collect(),
+                None => return,
+            };
+        // A stable sort, like `Array.prototype.sort`.
+        children.sort_by(|(name1, kind1, _), (name2, kind2, _)| {
+            compare_strings_case_sensitive_ui(name1.as_deref(), name2.as_deref())
+                .then_with(|| kind1.js_value().cmp(&kind2.js_value()))
+        });
+        self.nodes[node].children = Some(children.into_iter().map(|(_, _, child)| child).collect());
+    }
+
+    /// This differs from get_item_name because this is just used for sorting.
+    /// We only sort nodes by name that have a more-or-less "direct" name, as opposed to `new()` and the like.
+    /// So `new()` can still come before an `aardvark` method.
+    fn try_get_name(&self, declaration: Declaration) -> Option<String> {
+        let source_file = self.source_file;
+        if let Declaration::Node(node) = declaration {
+            if source_file.node(node).kind == SyntaxKind::ModuleDeclaration {
+                return Some(get_module_name(source_file, node));
+            }
+        }
+
+        match self.name_of_declaration(declaration) {
+            Some(Name::Node(name)) if is_property_name_kind(source_file.node(name).kind) => {
+                return get_property_name_for_property_name_node(source_file, name)
+                    .map(|property_name| unescape_leading_underscores(&property_name).to_string());
+            }
+            Some(Name::Identifier(literal)) => {
+                return Some(
+                    get_text_of_identifier_or_literal(source_file.node(literal)).to_string(),
+                )
+            }
+            Some(Name::Class) => return Some("__class__".to_string()),
+            _ => {}
+        }
+        match declaration {
+            Declaration::Node(node)
+                if matches!(
+                    source_file.node(node).kind,
+                    SyntaxKind::FunctionExpression
+                        | SyntaxKind::ArrowFunction
+                        | SyntaxKind::ClassExpression
+                ) =>
+            {
+                Some(self.get_function_or_class_name(node))
+            }
+            _ => None,
+        }
+    }
+
+    fn get_item_name(&self, declaration: Declaration, name: Option<Name>) -> String {
+        let source_file = self.source_file;
+        let kind = self.kind(declaration);
+        if let Declaration::Node(node) = declaration {
+            if kind == SyntaxKind::ModuleDeclaration {
+                return clean_text(&get_module_name(source_file, node));
+            }
+        }
+
+        if let Some(name) = name {
+            let text = match name {
+                Name::Node(name) => {
+                    let name_node = source_file.node(name);
+                    match &name_node.data {
+                        NodeData::Identifier { text, .. } => text.to_string(),
+                        NodeData::ElementAccessExpression {
+                            argument_expression,
+                            ..
+                        } => {
+                            format!("[{}]", self.node_text(*argument_expression))
+                        }
+                        _ => self.node_text(name),
+                    }
+                }
+                Name::Identifier(literal) => {
+                    get_text_of_identifier_or_literal(source_file.node(literal)).to_string()
+                }
+                Name::Class => "__class__".to_string(),
+            };
+            if !text.is_empty() {
+                return clean_text(&text);
+            }
+        }
+
+        let node = match declaration {
+            Declaration::Node(node) => node,
+            Declaration::Synthesized(_) if kind == SyntaxKind::Constructor => {
+                return "constructor".to_string()
+            }
+            Declaration::Synthesized(_) => return "<class>".to_string(),
+        };
+        match kind {
+            SyntaxKind::SourceFile => {
+                if source_file.is_external_module() {
+                    format!(
+                        "\"{}\"",
+                        escape_string(&get_base_file_name(remove_file_extension(&normalize_path(
+                            &source_file.file_name
+                        ))))
+                    )
+                } else {
+                    "<global>".to_string()
+                }
+            }
+            SyntaxKind::ExportAssignment => match source_file.node(node).data {
+                NodeData::ExportAssignment {
+                    is_export_equals: true,
+                    ..
+                } => "export=".to_string(),
+                _ => "default".to_string(),
+            },
+            SyntaxKind::ArrowFunction
+            | SyntaxKind::FunctionDeclaration
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::ClassDeclaration
+            | SyntaxKind::ClassExpression => {
+                if get_syntactic_modifier_flags(source_file, node) & ModifierFlags::DEFAULT != 0 {
+                    return "default".to_string();
+                }
+                // We may get a string with newlines or other whitespace in the case of an object dereference
+                // (eg: "app\n.onactivated"), so we should remove the whitespace for readability in the
+                // navigation bar.
+                self.get_function_or_class_name(node)
+            }
+            SyntaxKind::Constructor => "constructor".to_string(),
+            SyntaxKind::ConstructSignature => "new()".to_string(),
+            SyntaxKind::CallSignature => "()".to_string(),
+            SyntaxKind::IndexSignature => "[]".to_string(),
+            _ => "<unknown>".to_string(),
+        }
+    }
+
+    fn get_function_or_class_name(&self, node: NodeId) -> String {
+        let source_file = self.source_file;
+        let data = source_file.node(node);
+        if let Some(name) = data
+            .name()
+            .filter(|&name| get_full_width(source_file, name) > 0)
+        {
+            return clean_text(&self.declaration_name_to_string(Some(name)));
+        }
+        let parent = source_file
+            .parent(node)
+            .map(|parent| (parent, source_file.node(parent)));
+        match parent {
+            // See if it is a var initializer. If so, use the var name.
+            Some((_, parent)) if parent.kind == SyntaxKind::VariableDeclaration => {
+                return clean_text(&self.declaration_name_to_string(parent.name()))
+            }
+            // See if it is of the form "<expr> = function(){...}". If so, use the text from the left-hand side.
+            Some((parent, parent_node)) if is_equals_assignment(source_file, parent) => {
+                if let NodeData::BinaryExpression { left, .. } = &parent_node.data {
+                    return self
+                        .node_text(*left)
+                        .chars()
+                        .filter(|&c| !is_js_white_space(c))
+                        .collect();
+                }
+            }
+            // See if it is a property assignment, and if so use the property name
+            Some((_, parent)) if parent.kind == SyntaxKind::PropertyAssignment => {
+                if let Some(name) = parent.name() {
+                    return self.node_text(name);
+                }
+            }
+            _ => {}
+        }
+        // Default exports are named "default"
+        if get_syntactic_modifier_flags(source_file, node) & ModifierFlags::DEFAULT != 0 {
+            return "default".to_string();
+        }
+        if matches!(
+            data.kind,
+            SyntaxKind::ClassDeclaration | SyntaxKind::ClassExpression
+        ) {
+            return "<class>".to_string();
+        }
+        if let Some((_, parent)) = parent {
+            if let NodeData::CallExpression {
+                expression,
+                arguments,
+                ..
+            } = &parent.data
+            {
+                if parent.kind == SyntaxKind::CallExpression {
+                    if let Some(name) = get_called_expression_name(source_file, *expression) {
+                        let name = clean_text(&name);
+                        if name.encode_utf16().count() > MAX_LENGTH {
+                            return format!("{} callback", name);
+                        }
+
+                        let args = arguments
+                            .iter()
+                            .flat_map(|arguments| arguments.iter())
+                            .filter(|&&argument| is_string_literal_like(source_file.node(argument)))
+                            .map(|&argument| self.get_text(argument))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        return format!("{}({}) callback", name, clean_text(&args));
+                    }
+                }
+            }
+        }
+        "<function>".to_string()
+    }
+
+    fn declaration_name_to_string(&self, name: Option<NodeId>) -> String {
+        match name {
+            Some(name) if get_full_width(self.source_file, name) > 0 => self.get_text(name),
+            _ => "(Missing)".to_string(),
+        }
+    }
+
+    fn get_node_span(&self, declaration: Declaration) -> TextRange {
+        let range = self.range(declaration);
+        let pos = match declaration {
+            Declaration::Node(node)
+                if self.source_file.node(node).kind == SyntaxKind::SourceFile =>
+            {
+                range.pos
+            }
+            Declaration::Node(node) => {
+                get_token_pos_of_node(self.source_file.node(node), self.text)
+            }
+            Declaration::Synthesized(_) if range.pos == range.end => range.pos,
+            Declaration::Synthesized(_) => skip_trivia(self.text, range.pos, false, false, false),
+        };
+        TextRange {
+            pos,
+            end: range.end,
+        }
+    }
+
+    fn get_spans(&self, navigation_node: &NavigationBarNode) -> Vec<TextRange> {
+        std::iter::once(navigation_node.node)
+            .chain(navigation_node.additional_nodes.iter().flatten().copied())
+            .map(|declaration| self.get_node_span(declaration))
+            .collect()
+    }
+
+    fn get_modifiers(&self, declaration: Declaration) -> Vec<ScriptElementKindModifier> {
+        let source_file = self.source_file;
+        match declaration {
+            Declaration::Node(node) => {
+                let node = match source_file.parent(node) {
+                    Some(parent)
+                        if source_file.node(parent).kind == SyntaxKind::VariableDeclaration =>
+                    {
+                        parent
+                    }
+                    _ => node,
+                };
+                get_node_modifiers(source_file, node)
+            }
+            Declaration::Synthesized(_) => Vec::new(),
+        }
+    }
+
+    fn convert_to_tree(&self, navigation_node: usize) -> NavigationTree {
+        let n = &self.nodes[navigation_node];
+        NavigationTree {
+            text: self.get_item_name(n.node, n.name),
+            kind: match n.node {
+                Declaration::Node(node) => get_node_kind(self.source_file, node),
+                Declaration::Synthesized(index)
+                    if self.synthesized[index].kind == SyntaxKind::Constructor =>
+                {
+                    ScriptElementKind::ConstructorImplementationElement
+                }
+                Declaration::Synthesized(_) => ScriptElementKind::ClassElement,
+            },
+            kind_modifiers: self.get_modifiers(n.node),
+            spans: self.get_spans(n),
+            name_span: n.name.and_then(|name| match name {
+                Name::Node(node) | Name::Identifier(node) => {
+                    Some(self.get_node_span(Declaration::Node(node)))
+                }
+                Name::Class => None,
+            }),
+            child_items: n
+                .children
+                .iter()
+                .flatten()
+                .map(|&child| self.convert_to_tree(child))
+                .collect(),
+        }
+    }
+}
+
+fn add_node_with_recursive_child(
+    tasks: &mut Vec<Task>,
+    node: NodeId,
+    child: Option<NodeId>,
+    name: Option<Name>,
+) {
+    tasks.push(Task::Start(node, name));
+    if let Some(child) = child {
+        tasks.push(Task::Visit(child));
+    }
+    tasks.push(Task::End);
+}
+
+fn add_node_with_recursive_initializer(
+    source_file: &SourceFile,
+    tasks: &mut Vec<Task>,
+    node: NodeId,
+) {
+    let initializer = source_file.node(node).initializer();
+    match initializer {
+        Some(initializer)
+            if is_function_or_class_expression(source_file.node(initializer).kind) =>
+        {
+            tasks.push(Task::Start(node, None));
+            add_each_child(source_file, tasks, initializer);
+            tasks.push(Task::End);
+        }
+        _ => add_node_with_recursive_child(tasks, node, initializer, None),
+    }
+}
+
+fn add_each_child(source_file: &SourceFile, tasks: &mut Vec<Task>, node: NodeId) {
+    for_each_child(
+        source_file.node(node),
+        &mut |child| {
+            tasks.push(Task::Visit(child));
+            None::<()>
+        },
+        None,
+    );
+}
+
+// Starts the items of the namespaces an assignment to a nested property is in, like `A` and `B`
+// for `A.B.C.prototype.m = ...`. Returns how many were started and the name of the last one.
+fn start_nested_nodes(
+    source_file: &SourceFile,
+    tasks: &mut Vec<Task>,
+    target_node: NodeId,
+    entity_name: NodeId,
+) -> (usize, NodeId) {
+    let mut names = Vec::new();
+    let mut entity_name = entity_name;
+    while !is_property_name_literal(source_file.node(entity_name).kind) {
+        let (name, expression) = match (
+            get_name_or_argument(source_file, entity_name),
+            source_file.node(entity_name).expression(),
+        ) {
+            (Some(name), Some(expression)) => (name, expression),
+            _ => break,
+        };
+        let is_prototype = get_element_or_property_access_name(source_file, entity_name).as_deref()
+            == Some("prototype");
+        entity_name = expression;
+        if is_prototype || source_file.node(name).kind == SyntaxKind::PrivateIdentifier {
+            continue;
+        }
+        names.push(name);
+    }
+    names.push(entity_name);
+    for &name in names[1..].iter().rev() {
+        tasks.push(Task::Start(target_node, Some(Name::Node(name))));
+    }
+    (names.len() - 1, names[0])
+}
+
+/// Historically, we've elided dynamic names from the nav tree (including late bound names),
+/// but included certain "well known" symbol names. While we no longer distinguish those well-known
+/// symbols from other unique symbols, we do the below to retain those members in the nav tree.
+fn has_navigation_bar_name(source_file: &SourceFile, node: NodeId) -> bool {
+    if !has_dynamic_name(source_file, node) {
+        return true;
+    }
+    let data = source_file.node(node);
+    data.kind != SyntaxKind::BinaryExpression
+        && matches!(
+            data.name().and_then(|name| source_file.node(name).expression()).map(|expression| &source_file.node(expression).data),
+            Some(NodeData::PropertyAccessExpression { expression, .. })
+                if source_file.node(*expression).kind == SyntaxKind::Identifier
+                    && source_file.node(*expression).text() == Some("Symbol")
+        )
+}
+
+fn is_es5_class_member(kind: AssignmentDeclarationKind) -> bool {
+    matches!(
+        kind,
+        AssignmentDeclarationKind::Property
+            | AssignmentDeclarationKind::PrototypeProperty
+            | AssignmentDeclarationKind::ObjectDefinePropertyValue
+            | AssignmentDeclarationKind::ObjectDefinePrototypeProperty
+            | AssignmentDeclarationKind::Prototype
+    )
+}
+
+fn concatenate(a: Option<Vec<usize>>, b: Option<Vec<usize>>) -> Option<Vec<usize>> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            a.extend(b);
+            Some(a)
+        }
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+// We use 1 NavNode to represent 'A.B.C', but there are multiple source nodes.
+// Only merge module nodes that have the same chain. Don't merge 'A.B.C' with 'A'!
+fn are_same_module(source_file: &SourceFile, a: NodeId, b: NodeId) -> bool {
+    let (a, b) = (source_file.node(a).body(), source_file.node(b).body());
+    let kind = |body: Option<NodeId>| body.map(|body| source_file.node(body).kind);
+    match (a, b) {
+        (Some(a), Some(b)) if kind(Some(a)) == kind(Some(b)) => {
+            source_file.node(a).kind != SyntaxKind::ModuleDeclaration
+                || are_same_module(source_file, a, b)
+        }
+        _ => kind(a) == kind(b) && a.is_none(),
+    }
+}
+
+fn get_module_name(source_file: &SourceFile, module_declaration: NodeId) -> String {
+    // We want to maintain quotation marks.
+    if is_ambient_module(source_file, module_declaration) {
+        if let Some(name) = source_file.node(module_declaration).name() {
+            let text: Vec<u16> = source_file.text.encode_utf16().collect();
+            let name = source_file.node(name);
+            let start = get_token_pos_of_node(name, &text);
+            return String::from_utf16_lossy(&text[start..name.end().max(start)]);
+        }
+    }
+
+    get_fully_qualified_module_name(source_file, module_declaration)
+}
+
+fn get_fully_qualified_module_name(source_file: &SourceFile, module_declaration: NodeId) -> String {
+    // Otherwise, we need to aggregate each identifier to build up the qualified name.
+    let mut result = Vec::new();
+    let mut module_declaration = Some(module_declaration);
+    while let Some(declaration) = module_declaration {
+        let data = source_file.node(declaration);
+        result.push(data.name().map_or("", |name| {
+            get_text_of_identifier_or_literal(source_file.node(name))
+        }));
+        module_declaration = data
+            .body()
+            .filter(|&body| source_file.node(body).kind == SyntaxKind::ModuleDeclaration);
+    }
+    result.join(".")
+}
+
+/// For 'module A.B.C', we want to get the node for 'C'.
+/// We store 'A' as associated with a NavNode, and use get_module_name to traverse down again.
+fn get_interior_module(source_file: &SourceFile, declaration: NodeId) -> NodeId {
+    let mut declaration = declaration;
+    while let Some(body) = source_file.node(declaration).body() {
+        if source_file.node(body).kind != SyntaxKind::ModuleDeclaration {
+            break;
+        }
+        declaration = body;
+    }
+    declaration
+}
+
+fn is_computed_property(source_file: &SourceFile, member: NodeId) -> bool {
+    match source_file.node(member).name() {
+        Some(name) => source_file.node(name).kind == SyntaxKind::ComputedPropertyName,
+        None => true,
+    }
+}
+
+fn is_function_or_class_expression(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ArrowFunction | SyntaxKind::FunctionExpression | SyntaxKind::ClassExpression
+    )
+}
+
+fn is_property_name_literal(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Identifier
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::NoSubstitutionTemplateLiteral
+            | SyntaxKind::NumericLiteral
+    )
+}
+
+fn is_equals_assignment(source_file: &SourceFile, node: NodeId) -> bool {
+    matches!(
+        &source_file.node(node).data,
+        NodeData::BinaryExpression { operator_token, .. }
+            if source_file.node(*operator_token).kind == SyntaxKind::EqualsToken
+    )
+}
+
+fn get_full_width(source_file: &SourceFile, node: NodeId) -> usize {
+    let range = source_file.node(node).range;
+    range.end.saturating_sub(range.pos)
+}
+
+// See also 'tryGetPropertyAccessOrIdentifierToString'
+fn get_called_expression_name(source_file: &SourceFile, expression: NodeId) -> Option<String> {
+    let mut names = Vec::new();
+    let mut expression = expression;
+    loop {
+        let data = source_file.node(expression);
+        match &data.data {
+            NodeData::Identifier { text, .. } => {
+                names.push(text.to_string());
+                break;
+            }
+            NodeData::PropertyAccessExpression {
+                expression: object,
+                name,
+                ..
+            } => {
+                names.push(
+                    source_file
+                        .node(*name)
+                        .text()
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                expression = *object;
+            }
+            _ if names.is_empty() => return None,
+            _ => break,
+        }
+    }
+    names.reverse();
+    Some(names.join("."))
+}
+
+// The characters of `\s` in a regular expression.
+fn is_js_white_space(c: char) -> bool {
+    (c.is_whitespace() && c != '\u{0085}') || c == '\u{FEFF}'
+}
+
+fn clean_text(text: &str) -> String {
+    // Truncate to maximum amount of characters as we don't want to do a big replace operation.
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let text = if units.len() > MAX_LENGTH {
+        String::from_utf16_lossy(&units[..MAX_LENGTH]) + "..."
+    } else {
+        text.to_string()
+    };
+
+    // Replaces ECMAScript line terminators and removes the trailing `\` from each line:
+    // \n - Line Feed
+    // \r - Carriage Return
+    //   - Line separator
+    //   - Paragraph separator
+    let is_line_terminator = |c: char| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}');
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_line_terminator(c)
+            || c == '\\' && matches!(chars.peek(), Some(&next) if is_line_terminator(next))
+        {
+            continue;
+        }
+        result.push(c);
+    }
+    result
+}
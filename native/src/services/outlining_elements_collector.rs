@@ -0,0 +1,801 @@
+use std::iter;
+
+use crate::compiler::parser::{for_each_child, is_pragma_whitespace};
+use crate::compiler::scanner::{
+    compute_line_of_position, compute_line_starts, create_scanner, get_leading_comment_ranges,
+    TextScanner,
+};
+use crate::compiler::types::{
+    CancellationToken, CharacterCodes, NodeData, NodeId, OperationCanceled, ScriptTarget,
+    SourceFile, SyntaxKind, TextRange, TriviaSyntaxKind,
+};
+use crate::compiler::utilities::{get_token_pos_of_node, is_declaration};
+
+use super::types::{OutliningSpan, OutliningSpanKind};
+use super::utilities::{
+    find_child_of_kind, get_children, is_function_like_kind, is_in_comment, Child,
+};
+
+/// The spans of the file an editor can fold, like `getOutliningSpans` of the JS services: runs of
+/// comments, `// #region` sections, groups of imports, and the blocks, literals, JSX elements
+/// and other constructs that span lines, sorted by their start. Only the syntax tree is needed.
+/// Positions are in UTF-16 code units.
+pub fn collect_elements(
+    source_file: &SourceFile,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Vec<OutliningSpan>, OperationCanceled> {
+    let text: Vec<u16> = source_file.text.encode_utf16().collect();
+    let mut collector = OutliningElementsCollector {
+        source_file,
+        text: &text,
+        line_starts: compute_line_starts(&source_file.text),
+        scanner: create_scanner(
+            ScriptTarget::ESNext,
+            true,
+            None,
+            Some(source_file.text.clone()),
+            None,
+            None,
+            None,
+        ),
+        cancellation_token,
+        out: Vec::new(),
+    };
+    collector.add_node_outlining_spans()?;
+    collector.add_region_outlining_spans();
+    let mut res = collector.out;
+    // A stable sort, like `Array.prototype.sort`, keeps the spans that start together in the
+    // order they were found in.
+    res.sort_by_key(|span| span.text_span.pos);
+    Ok(res)
+}
+
+// How deep in the statements the nodes with spans are looked for. The arguments of a call and
+// the `else if`s of an `if` don't count as nested.
+const MAX_DEPTH: usize = 40;
+
+struct OutliningElementsCollector<'a> {
+    source_file: &'a SourceFile,
+    text: &'a [u16],
+    line_starts: Vec<usize>,
+    // Reads the tokens of nodes, like the `{` and `}` around a block.
+    scanner: TextScanner,
+    cancellation_token: Option<&'a CancellationToken>,
+    out: Vec<OutliningSpan>,
+}
+
+impl OutliningElementsCollector<'_> {
+    fn throw_if_cancellation_requested(&self) -> Result<(), OperationCanceled> {
+        match self.cancellation_token {
+            Some(cancellation_token) => cancellation_token.throw_if_cancellation_requested(),
+            None => Ok(()),
+        }
+    }
+
+    fn add_node_outlining_spans(&mut self) -> Result<(), OperationCanceled> {
+        let source_file = self.source_file;
+        // Includes the EOF Token so that comments which aren't attached to statements are included
+        let statements: Vec<NodeId> = source_file
+            .statements()
+            .iter()
+            .copied()
+            .chain(iter::once(source_file.end_of_file_token()))
+            .collect();
+        let is_import = |node: NodeId| is_any_import_syntax(source_file.node(node).kind);
+        let n = statements.len();
+        let mut current = 0;
+        while current < n {
+            while current < n && !is_import(statements[current]) {
+                self.visit_non_import_node(statements[current])?;
+                current += 1;
+            }
+            if current == n {
+                break;
+            }
+            let first_import = current;
+            while current < n && is_import(statements[current]) {
+                self.add_outlining_for_leading_comments_for_node(statements[current])?;
+                current += 1;
+            }
+            let last_import = current - 1;
+            if last_import != first_import {
+                let import_keyword = find_child_of_kind(
+                    source_file,
+                    statements[first_import],
+                    SyntaxKind::ImportKeyword,
+                    &mut self.scanner,
+                );
+                if let Some(import_keyword) = import_keyword {
+                    self.out.push(create_outlining_span_from_bounds(
+                        import_keyword.start(source_file, self.text),
+                        source_file.node(statements[last_import]).end(),
+                        OutliningSpanKind::Imports,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Visits the node and its descendants in the order the JS collector recurses in. The nodes
+    // wait on a stack with the depth they have left instead, since a long `else if` chain or
+    // call chain would nest the recursion deep enough to overflow the stack.
+    fn visit_non_import_node(&mut self, node: NodeId) -> Result<(), OperationCanceled> {
+        let source_file = self.source_file;
+        let mut stack = vec![(node, MAX_DEPTH)];
+        while let Some((n, depth_remaining)) = stack.pop() {
+            if depth_remaining == 0 {
+                continue;
+            }
+            self.throw_if_cancellation_requested()?;
+
+            let data = source_file.node(n);
+            if is_declaration(source_file, n)
+                || data.kind == SyntaxKind::VariableStatement
+                || data.kind == SyntaxKind::EndOfFileToken
+            {
+                self.add_outlining_for_leading_comments_for_node(n)?;
+            }
+
+            if is_function_like_kind(data.kind) {
+                let parent = data.parent.map(|parent| &source_file.node(parent).data);
+                if let Some(NodeData::BinaryExpression { left, .. }) = parent {
+                    if source_file.node(*left).kind == SyntaxKind::PropertyAccessExpression {
+                        self.add_outlining_for_leading_comments_for_node(*left)?;
+                    }
+                }
+            }
+
+            if let Some(span) = self.get_outlining_span_for_node(n) {
+                self.out.push(span);
+            }
+
+            let depth_remaining = depth_remaining - 1;
+            let mut children = Vec::new();
+            match &data.data {
+                NodeData::CallExpression {
+                    expression,
+                    type_arguments,
+                    arguments,
+                    ..
+                } if data.kind == SyntaxKind::CallExpression => {
+                    children.push((*expression, depth_remaining + 1));
+                    children.extend(
+                        arguments
+                            .iter()
+                            .chain(type_arguments)
+                            .flat_map(|list| list.iter())
+                            .map(|&child| (child, depth_remaining)),
+                    );
+                }
+                NodeData::IfStatement {
+                    expression,
+                    then_statement,
+                    else_statement: Some(else_statement),
+                } if source_file.node(*else_statement).kind == SyntaxKind::IfStatement => {
+                    // Consider an 'else if' to be on the same depth as the 'if'.
+                    children.push((*expression, depth_remaining));
+                    children.push((*then_statement, depth_remaining));
+                    children.push((*else_statement, depth_remaining + 1));
+                }
+                _ => {
+                    for_each_child(
+                        data,
+                        &mut |child| {
+                            children.push((child, depth_remaining));
+                            None::<()>
+                        },
+                        None,
+                    );
+                }
+            }
+            stack.extend(children.into_iter().rev());
+        }
+        Ok(())
+    }
+
+    fn add_region_outlining_spans(&mut self) {
+        let source_file = self.source_file;
+        let text = self.text;
+        let mut regions: Vec<OutliningSpan> = Vec::new();
+        for line in 0..self.line_starts.len() {
+            let current_line_start = self.line_starts[line];
+            let line_end = self.get_line_end(line);
+            let result = match is_region_delimiter(&text[current_line_start..line_end]) {
+                Some(result) => result,
+                None => continue,
+            };
+            if is_in_comment(source_file, text, current_line_start, &mut self.scanner) {
+                continue;
+            }
+
+            match result {
+                RegionDelimiter::Region(label) => {
+                    let start = current_line_start
+                        + text[current_line_start..]
+                            .windows(2)
+                            .position(|slashes| slashes == [CharacterCodes::SLASH as u16; 2])
+                            .expect("a region delimiter starts with //");
+                    let span = TextRange {
+                        pos: start,
+                        end: line_end,
+                    };
+                    regions.push(OutliningSpan {
+                        banner_text: label
+                            .map_or_else(|| "#region".to_string(), String::from_utf16_lossy),
+                        ..create_outlining_span(span, OutliningSpanKind::Region)
+                    });
+                }
+                RegionDelimiter::EndRegion => {
+                    if let Some(mut region) = regions.pop() {
+                        region.text_span.end = line_end;
+                        region.hint_span.end = line_end;
+                        self.out.push(region);
+                    }
+                }
+            }
+        }
+    }
+
+    // The end of the line, before its line break, like `getLineEndOfPosition` of the start of
+    // the line.
+    fn get_line_end(&self, line: usize) -> usize {
+        let last_char_pos = match self.line_starts.get(line + 1) {
+            Some(&next_line_start) => next_line_start - 1,
+            None => self.text.len(),
+        };
+        // if the new line is "\r\n", we should return the last non-new-line-character position
+        if last_char_pos > 0
+            && self.text.get(last_char_pos) == Some(&(CharacterCodes::LINE_FEED as u16))
+            && self.text[last_char_pos - 1] == CharacterCodes::CARRIAGE_RETURN as u16
+        {
+            last_char_pos - 1
+        } else {
+            last_char_pos
+        }
+    }
+
+    fn add_outlining_for_leading_comments_for_node(
+        &mut self,
+        n: NodeId,
+    ) -> Result<(), OperationCanceled> {
+        let node = self.source_file.node(n);
+        if node.kind == SyntaxKind::JsxText {
+            return Ok(());
+        }
+        let comments = get_leading_comment_ranges(self.text, node.pos());
+        let mut first_single_line_comment_start = 0;
+        let mut last_single_line_comment_end = 0;
+        let mut single_line_comment_count = 0;
+        for comment in comments {
+            self.throw_if_cancellation_requested()?;
+            let TextRange { pos, end } = comment.range;
+            match comment.kind {
+                TriviaSyntaxKind::SingleLineCommentTrivia => {
+                    // never fold region delimiters into single-line comment regions
+                    if is_region_delimiter(&self.text[pos..end]).is_some() {
+                        combine_and_add_multiple_single_line_comments(
+                            &mut self.out,
+                            single_line_comment_count,
+                            first_single_line_comment_start,
+                            last_single_line_comment_end,
+                        );
+                        single_line_comment_count = 0;
+                        continue;
+                    }
+
+                    // For single line comments, combine consecutive ones (2 or more) into
+                    // a single span from the start of the first till the end of the last
+                    if single_line_comment_count == 0 {
+                        first_single_line_comment_start = pos;
+                    }
+                    last_single_line_comment_end = end;
+                    single_line_comment_count += 1;
+                }
+                TriviaSyntaxKind::MultiLineCommentTrivia => {
+                    combine_and_add_multiple_single_line_comments(
+                        &mut self.out,
+                        single_line_comment_count,
+                        first_single_line_comment_start,
+                        last_single_line_comment_end,
+                    );
+                    self.out.push(create_outlining_span_from_bounds(
+                        pos,
+                        end,
+                        OutliningSpanKind::Comment,
+                    ));
+                    single_line_comment_count = 0;
+                }
+                _ => unreachable!("a comment range is a single-line or multi-line comment"),
+            }
+        }
+        combine_and_add_multiple_single_line_comments(
+            &mut self.out,
+            single_line_comment_count,
+            first_single_line_comment_start,
+            last_single_line_comment_end,
+        );
+        Ok(())
+    }
+
+    fn get_outlining_span_for_node(&mut self, n: NodeId) -> Option<OutliningSpan> {
+        let source_file = self.source_file;
+        let node = source_file.node(n);
+        let parent_kind = node.parent.map(|parent| source_file.node(parent).kind);
+        match node.kind {
+            SyntaxKind::Block => {
+                let parent = node.parent?;
+                if is_function_like_kind(source_file.node(parent).kind) {
+                    return self.function_span(parent, n);
+                }
+                // Check if the block is standalone, or 'attached' to some parent statement.
+                // If the latter, we want to collapse the block, but consider its hint span
+                // to be the entire span of the parent.
+                match &source_file.node(parent).data {
+                    _ if matches!(
+                        parent_kind,
+                        Some(
+                            SyntaxKind::DoStatement
+                                | SyntaxKind::ForInStatement
+                                | SyntaxKind::ForOfStatement
+                                | SyntaxKind::ForStatement
+                                | SyntaxKind::IfStatement
+                                | SyntaxKind::WhileStatement
+                                | SyntaxKind::WithStatement
+                                | SyntaxKind::CatchClause
+                        )
+                    ) =>
+                    {
+                        return self.span_for_node(
+                            n,
+                            &Child::Node(parent),
+                            false,
+                            true,
+                            SyntaxKind::OpenBraceToken,
+                        );
+                    }
+                    // Could be the try-block, or the finally-block.
+                    NodeData::TryStatement { try_block, .. } if *try_block == n => {
+                        return self.span_for_node(
+                            n,
+                            &Child::Node(parent),
+                            false,
+                            true,
+                            SyntaxKind::OpenBraceToken,
+                        );
+                    }
+                    NodeData::TryStatement {
+                        finally_block: Some(finally_block),
+                        ..
+                    } if *finally_block == n => {
+                        let finally_keyword = find_child_of_kind(
+                            source_file,
+                            parent,
+                            SyntaxKind::FinallyKeyword,
+                            &mut self.scanner,
+                        );
+                        if let Some(finally_keyword) = finally_keyword {
+                            return self.span_for_node(
+                                n,
+                                &finally_keyword,
+                                false,
+                                true,
+                                SyntaxKind::OpenBraceToken,
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+                // Block was a standalone block.  In this case we want to only collapse
+                // the span of the block, independent of any parent span.
+                Some(create_outlining_span(
+                    self.create_text_span_from_node(&Child::Node(n)),
+                    OutliningSpanKind::Code,
+                ))
+            }
+            SyntaxKind::ModuleBlock => self.span_for_node(
+                n,
+                &Child::Node(node.parent?),
+                false,
+                true,
+                SyntaxKind::OpenBraceToken,
+            ),
+            SyntaxKind::ClassDeclaration
+            | SyntaxKind::ClassExpression
+            | SyntaxKind::InterfaceDeclaration
+            | SyntaxKind::EnumDeclaration
+            | SyntaxKind::CaseBlock
+            | SyntaxKind::TypeLiteral
+            | SyntaxKind::ObjectBindingPattern => {
+                self.span_for_node(n, &Child::Node(n), false, true, SyntaxKind::OpenBraceToken)
+            }
+            SyntaxKind::TupleType => {
+                let use_full_start = parent_kind != Some(SyntaxKind::TupleType);
+                self.span_for_node(
+                    n,
+                    &Child::Node(n),
+                    false,
+                    use_full_start,
+                    SyntaxKind::OpenBracketToken,
+                )
+            }
+            SyntaxKind::CaseClause | SyntaxKind::DefaultClause => {
+                let statements = node.statements()?;
+                if statements.is_empty() {
+                    None
+                } else {
+                    Some(create_outlining_span(
+                        statements.range,
+                        OutliningSpanKind::Code,
+                    ))
+                }
+            }
+            SyntaxKind::ObjectLiteralExpression => {
+                self.span_for_object_or_array_literal(n, parent_kind, SyntaxKind::OpenBraceToken)
+            }
+            SyntaxKind::ArrayLiteralExpression => {
+                self.span_for_object_or_array_literal(n, parent_kind, SyntaxKind::OpenBracketToken)
+            }
+            SyntaxKind::JsxElement => self.span_for_jsx_element(n),
+            SyntaxKind::JsxFragment => self.span_for_jsx_fragment(n),
+            SyntaxKind::JsxSelfClosingElement | SyntaxKind::JsxOpeningElement => match &node.data {
+                NodeData::JsxOpeningLikeElement { attributes, .. } => {
+                    self.span_for_jsx_attributes(*attributes)
+                }
+                _ => None,
+            },
+            SyntaxKind::TemplateExpression | SyntaxKind::NoSubstitutionTemplateLiteral => {
+                self.span_for_template_literal(n)
+            }
+            SyntaxKind::ArrayBindingPattern => {
+                let use_full_start = parent_kind != Some(SyntaxKind::BindingElement);
+                self.span_for_node(
+                    n,
+                    &Child::Node(n),
+                    false,
+                    use_full_start,
+                    SyntaxKind::OpenBracketToken,
+                )
+            }
+            SyntaxKind::ArrowFunction => self.span_for_arrow_function(n),
+            SyntaxKind::CallExpression => self.span_for_call_expression(n),
+            _ => None,
+        }
+    }
+
+    fn span_for_call_expression(&mut self, n: NodeId) -> Option<OutliningSpan> {
+        match self.source_file.node(n).arguments() {
+            Some(arguments) if !arguments.is_empty() => {}
+            _ => return None,
+        }
+        let children = get_children(self.source_file, n, &mut self.scanner);
+        let open_token = self.find_token(&children, SyntaxKind::OpenParenToken)?;
+        let close_token = self.find_token(&children, SyntaxKind::CloseParenToken)?;
+        if self.positions_are_on_same_line(
+            open_token.range(self.source_file).pos,
+            close_token.range(self.source_file).pos,
+        ) {
+            return None;
+        }
+
+        Some(self.span_between_tokens(open_token, close_token, &Child::Node(n), false, true))
+    }
+
+    fn span_for_arrow_function(&self, n: NodeId) -> Option<OutliningSpan> {
+        let body = self.source_file.node(self.source_file.node(n).body()?);
+        if body.kind == SyntaxKind::Block || self.positions_are_on_same_line(body.pos(), body.end())
+        {
+            return None;
+        }
+        Some(OutliningSpan {
+            hint_span: self.create_text_span_from_node(&Child::Node(n)),
+            ..create_outlining_span(body.range, OutliningSpanKind::Code)
+        })
+    }
+
+    fn span_for_jsx_element(&self, n: NodeId) -> Option<OutliningSpan> {
+        let (opening_element, closing_element) = match &self.source_file.node(n).data {
+            NodeData::JsxElement {
+                opening_element,
+                closing_element,
+                ..
+            } => (
+                self.source_file.node(*opening_element),
+                self.source_file.node(*closing_element),
+            ),
+            _ => return None,
+        };
+        let text_span = TextRange {
+            pos: get_token_pos_of_node(opening_element, self.text),
+            end: closing_element.end(),
+        };
+        let tag_name = match &opening_element.data {
+            NodeData::JsxOpeningLikeElement { tag_name, .. } => self.source_file.node(*tag_name),
+            _ => return None,
+        };
+        let tag_name = String::from_utf16_lossy(
+            &self.text[get_token_pos_of_node(tag_name, self.text)..tag_name.end()],
+        );
+        Some(OutliningSpan {
+            banner_text: format!("<{}>...</{}>", tag_name, tag_name),
+            ..create_outlining_span(text_span, OutliningSpanKind::Code)
+        })
+    }
+
+    fn span_for_jsx_fragment(&self, n: NodeId) -> Option<OutliningSpan> {
+        let (opening_fragment, closing_fragment) = match &self.source_file.node(n).data {
+            NodeData::JsxFragment {
+                opening_fragment,
+                closing_fragment,
+                ..
+            } => (
+                self.source_file.node(*opening_fragment),
+                self.source_file.node(*closing_fragment),
+            ),
+            _ => return None,
+        };
+        let text_span = TextRange {
+            pos: get_token_pos_of_node(opening_fragment, self.text),
+            end: closing_fragment.end(),
+        };
+        Some(OutliningSpan {
+            banner_text: "<>...</>".to_string(),
+            ..create_outlining_span(text_span, OutliningSpanKind::Code)
+        })
+    }
+
+    fn span_for_jsx_attributes(&self, n: NodeId) -> Option<OutliningSpan> {
+        let node = self.source_file.node(n);
+        match &node.data {
+            NodeData::JsxAttributes { properties } if !properties.is_empty() => {}
+            _ => return None,
+        }
+
+        Some(create_outlining_span_from_bounds(
+            get_token_pos_of_node(node, self.text),
+            node.end(),
+            OutliningSpanKind::Code,
+        ))
+    }
+
+    fn span_for_template_literal(&self, n: NodeId) -> Option<OutliningSpan> {
+        let node = self.source_file.node(n);
+        if node.kind == SyntaxKind::NoSubstitutionTemplateLiteral && node.text() == Some("") {
+            return None;
+        }
+        Some(create_outlining_span_from_bounds(
+            get_token_pos_of_node(node, self.text),
+            node.end(),
+            OutliningSpanKind::Code,
+        ))
+    }
+
+    fn span_for_object_or_array_literal(
+        &mut self,
+        n: NodeId,
+        parent_kind: Option<SyntaxKind>,
+        open: SyntaxKind,
+    ) -> Option<OutliningSpan> {
+        // If the block has no leading keywords and is inside an array literal or call expression,
+        // we only want to collapse the span of the block.
+        // Otherwise, the collapsed section will include the end of the previous line.
+        let use_full_start = !matches!(
+            parent_kind,
+            Some(SyntaxKind::ArrayLiteralExpression | SyntaxKind::CallExpression)
+        );
+        self.span_for_node(n, &Child::Node(n), false, use_full_start, open)
+    }
+
+    // The span from the `open` token of the node to its closing token, `}` or `]`, with the
+    // hint span of `hint_span_node`.
+    fn span_for_node(
+        &mut self,
+        n: NodeId,
+        hint_span_node: &Child,
+        auto_collapse: bool,
+        use_full_start: bool,
+        open: SyntaxKind,
+    ) -> Option<OutliningSpan> {
+        let close = if open == SyntaxKind::OpenBraceToken {
+            SyntaxKind::CloseBraceToken
+        } else {
+            SyntaxKind::CloseBracketToken
+        };
+        let children = get_children(self.source_file, n, &mut self.scanner);
+        let open_token = self.find_token(&children, open)?;
+        let close_token = self.find_token(&children, close)?;
+        Some(self.span_between_tokens(
+            open_token,
+            close_token,
+            hint_span_node,
+            auto_collapse,
+            use_full_start,
+        ))
+    }
+
+    fn function_span(&mut self, node: NodeId, body: NodeId) -> Option<OutliningSpan> {
+        let open_token = self.try_get_function_open_token(node, body)?;
+        let close_token = find_child_of_kind(
+            self.source_file,
+            body,
+            SyntaxKind::CloseBraceToken,
+            &mut self.scanner,
+        )?;
+        let auto_collapse = self.source_file.node(node).kind != SyntaxKind::ArrowFunction;
+        Some(self.span_between_tokens(
+            &open_token,
+            &close_token,
+            &Child::Node(node),
+            auto_collapse,
+            true,
+        ))
+    }
+
+    fn try_get_function_open_token(&mut self, node: NodeId, body: NodeId) -> Option<Child> {
+        if let Some(parameters) = self.source_file.node(node).parameters() {
+            if !self.positions_are_on_same_line(parameters.range.pos, parameters.range.end) {
+                let open_paren_token = find_child_of_kind(
+                    self.source_file,
+                    node,
+                    SyntaxKind::OpenParenToken,
+                    &mut self.scanner,
+                );
+                if open_paren_token.is_some() {
+                    return open_paren_token;
+                }
+            }
+        }
+        find_child_of_kind(
+            self.source_file,
+            body,
+            SyntaxKind::OpenBraceToken,
+            &mut self.scanner,
+        )
+    }
+
+    fn span_between_tokens(
+        &self,
+        open_token: &Child,
+        close_token: &Child,
+        hint_span_node: &Child,
+        auto_collapse: bool,
+        use_full_start: bool,
+    ) -> OutliningSpan {
+        let start = if use_full_start {
+            open_token.range(self.source_file).pos
+        } else {
+            open_token.start(self.source_file, self.text)
+        };
+        let text_span = TextRange {
+            pos: start,
+            end: close_token.range(self.source_file).end,
+        };
+        OutliningSpan {
+            hint_span: self.create_text_span_from_node(hint_span_node),
+            auto_collapse,
+            ..create_outlining_span(text_span, OutliningSpanKind::Code)
+        }
+    }
+
+    fn find_token<'c>(&self, children: &'c [Child], kind: SyntaxKind) -> Option<&'c Child> {
+        children
+            .iter()
+            .find(|child| child.kind(self.source_file) == kind)
+    }
+
+    fn create_text_span_from_node(&self, node: &Child) -> TextRange {
+        TextRange {
+            pos: node.start(self.source_file, self.text),
+            end: node.range(self.source_file).end,
+        }
+    }
+
+    fn positions_are_on_same_line(&self, pos1: usize, pos2: usize) -> bool {
+        compute_line_of_position(&self.line_starts, pos1)
+            == compute_line_of_position(&self.line_starts, pos2)
+    }
+}
+
+fn is_any_import_syntax(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::ImportDeclaration || kind == SyntaxKind::ImportEqualsDeclaration
+}
+
+// Only outline spans of two or more consecutive single line comments
+fn combine_and_add_multiple_single_line_comments(
+    out: &mut Vec<OutliningSpan>,
+    single_line_comment_count: usize,
+    first_single_line_comment_start: usize,
+    last_single_line_comment_end: usize,
+) {
+    if single_line_comment_count > 1 {
+        out.push(create_outlining_span_from_bounds(
+            first_single_line_comment_start,
+            last_single_line_comment_end,
+            OutliningSpanKind::Comment,
+        ));
+    }
+}
+
+fn create_outlining_span_from_bounds(
+    pos: usize,
+    end: usize,
+    kind: OutliningSpanKind,
+) -> OutliningSpan {
+    create_outlining_span(TextRange { pos, end }, kind)
+}
+
+// A span with the defaults of `createOutliningSpan`: the hint span is the span itself, it isn't
+// collapsed automatically, and its banner is "...".
+fn create_outlining_span(text_span: TextRange, kind: OutliningSpanKind) -> OutliningSpan {
+    OutliningSpan {
+        text_span,
+        hint_span: text_span,
+        banner_text: "...".to_string(),
+        auto_collapse: false,
+        kind,
+    }
+}
+
+// A `#region` comment, with the label after it if it has one, or an `#endregion` comment.
+enum RegionDelimiter<'a> {
+    Region(Option<&'a [u16]>),
+    EndRegion,
+}
+
+// Matches `^#(end)?region(?:\s+(.*))?(?:\r)?$` on the text after the `//` of a line or a
+// single-line comment, trimmed.
+fn is_region_delimiter(line_text: &[u16]) -> Option<RegionDelimiter<'_>> {
+    // We trim the leading whitespace and // without the regex since the
+    // multiple potential whitespace matches can make for some gnarly backtracking behavior
+    let line_text = strip_ascii_prefix(trim_start(line_text), "//")?;
+    let line_text = trim_end(trim_start(line_text));
+    let line_text = strip_ascii_prefix(line_text, "#")?;
+    let (is_end, line_text) = match strip_ascii_prefix(line_text, "end") {
+        Some(rest) => (true, rest),
+        None => (false, line_text),
+    };
+    let label = strip_ascii_prefix(line_text, "region")?;
+    // The text is trimmed, so all that can follow `region` is whitespace and the label. The
+    // text has no line breaks for `.` not to match, as it is within a line.
+    if !label.is_empty() && !is_pragma_whitespace(label[0]) {
+        return None;
+    }
+    Some(if is_end {
+        RegionDelimiter::EndRegion
+    } else if label.is_empty() {
+        RegionDelimiter::Region(None)
+    } else {
+        RegionDelimiter::Region(Some(trim_start(label)))
+    })
+}
+
+fn strip_ascii_prefix<'a>(text: &'a [u16], prefix: &str) -> Option<&'a [u16]> {
+    if text.len() >= prefix.len()
+        && text
+            .iter()
+            .zip(prefix.bytes())
+            .all(|(&ch, byte)| ch == u16::from(byte))
+    {
+        Some(&text[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn trim_start(text: &[u16]) -> &[u16] {
+    let start = text
+        .iter()
+        .take_while(|&&ch| is_pragma_whitespace(ch))
+        .count();
+    &text[start..]
+}
+
+fn trim_end(text: &[u16]) -> &[u16] {
+    let end = text.len()
+        - text
+            .iter()
+            .rev()
+            .take_while(|&&ch| is_pragma_whitespace(ch))
+            .count();
+    &text[..end]
+}
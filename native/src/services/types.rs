@@ -0,0 +1,305 @@
+use crate::compiler::types::TextRange;
+
+// The state of the lexical classifier at the end of a line, which the next line starts in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndOfLineState {
+    None = 0,
+    InMultiLineCommentTrivia = 1,
+    InSingleQuoteStringLiteral = 2,
+    InDoubleQuoteStringLiteral = 3,
+    InTemplateHeadOrNoSubstitutionTemplate = 4,
+    InTemplateMiddleOrTail = 5,
+    InTemplateSubstitutionPosition = 6,
+}
+
+impl EndOfLineState {
+    /// The state of a value of `ts.EndOfLineState`.
+    pub fn from_js_value(value: u32) -> Option<EndOfLineState> {
+        match value {
+            0 => Some(EndOfLineState::None),
+            1 => Some(EndOfLineState::InMultiLineCommentTrivia),
+            2 => Some(EndOfLineState::InSingleQuoteStringLiteral),
+            3 => Some(EndOfLineState::InDoubleQuoteStringLiteral),
+            4 => Some(EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate),
+            5 => Some(EndOfLineState::InTemplateMiddleOrTail),
+            6 => Some(EndOfLineState::InTemplateSubstitutionPosition),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+    Punctuation = 0,
+    Keyword = 1,
+    Operator = 2,
+    Comment = 3,
+    Whitespace = 4,
+    Identifier = 5,
+    NumberLiteral = 6,
+    BigIntLiteral = 7,
+    StringLiteral = 8,
+    RegExpLiteral = 9,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClassificationType {
+    Comment = 1,
+    Identifier = 2,
+    Keyword = 3,
+    NumericLiteral = 4,
+    Operator = 5,
+    StringLiteral = 6,
+    RegularExpressionLiteral = 7,
+    WhiteSpace = 8,
+    Text = 9,
+    Punctuation = 10,
+    ClassName = 11,
+    EnumName = 12,
+    InterfaceName = 13,
+    ModuleName = 14,
+    TypeParameterName = 15,
+    TypeAliasName = 16,
+    ParameterName = 17,
+    DocCommentTagName = 18,
+    JsxOpenTagName = 19,
+    JsxCloseTagName = 20,
+    JsxSelfClosingTagName = 21,
+    JsxAttribute = 22,
+    JsxText = 23,
+    JsxAttributeStringLiteralValue = 24,
+    BigintLiteral = 25,
+}
+
+/// Classifications encoded like tsserver sends them: a start, a length and a
+/// `ClassificationType` per span, one after the other.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Classifications {
+    pub spans: Vec<u32>,
+    pub end_of_line_state: EndOfLineState,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClassificationInfo {
+    pub length: usize,
+    pub classification: TokenClass,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassificationResult {
+    pub final_lex_state: EndOfLineState,
+    pub entries: Vec<ClassificationInfo>,
+}
+
+/// A span of the file an editor can fold, like `OutliningSpan` in the JS services. The spans are
+/// ranges rather than `TextSpan`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutliningSpan {
+    /// The span of the document to actually collapse.
+    pub text_span: TextRange,
+    /// The span of the document to display when the user hovers over the collapsed span.
+    pub hint_span: TextRange,
+    /// The text to display in the editor for the collapsed region.
+    pub banner_text: String,
+    /// Whether or not this region should be automatically collapsed when the 'Collapse to
+    /// Definitions' command is invoked.
+    pub auto_collapse: bool,
+    /// Classification of the contents of the span
+    pub kind: OutliningSpanKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutliningSpanKind {
+    /// Single or multi-line comments
+    Comment,
+    /// Sections marked by '// #region' and '// #endregion' comments
+    Region,
+    /// Declarations and expressions
+    Code,
+    /// Contiguous blocks of import declarations
+    Imports,
+}
+
+impl OutliningSpanKind {
+    /// The value of the kind in `ts.OutliningSpanKind`.
+    pub fn js_value(self) -> &'static str {
+        match self {
+            OutliningSpanKind::Comment => "comment",
+            OutliningSpanKind::Region => "region",
+            OutliningSpanKind::Code => "code",
+            OutliningSpanKind::Imports => "imports",
+        }
+    }
+}
+
+/// An item of the navigation tree of a file, like `NavigationTree` in the JS services. The spans
+/// are ranges rather than `TextSpan`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavigationTree {
+    /// Name of the declaration, or a short description, e.g. "<class>".
+    pub text: String,
+    pub kind: ScriptElementKind,
+    pub kind_modifiers: Vec<ScriptElementKindModifier>,
+    /// Spans of the nodes that generated this declaration.
+    /// There will be more than one if this is the result of merging.
+    pub spans: Vec<TextRange>,
+    pub name_span: Option<TextRange>,
+    /// Empty if the item has no children
+    pub child_items: Vec<NavigationTree>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptElementKind {
+    Unknown,
+    Warning,
+    /// predefined type (void) or keyword (class)
+    Keyword,
+    /// top level script node
+    ScriptElement,
+    /// module foo {}
+    ModuleElement,
+    /// class X {}
+    ClassElement,
+    /// var x = class X {}
+    LocalClassElement,
+    /// interface Y {}
+    InterfaceElement,
+    /// type T = ...
+    TypeElement,
+    /// enum E
+    EnumElement,
+    EnumMemberElement,
+    /// Inside module and script only
+    /// const v = ..
+    VariableElement,
+    /// Inside function
+    LocalVariableElement,
+    /// Inside module and script only
+    /// function f() { }
+    FunctionElement,
+    /// Inside function
+    LocalFunctionElement,
+    /// class X { [public|private]* foo() {} }
+    MemberFunctionElement,
+    /// class X { [public|private]* [get|set] foo:number; }
+    MemberGetAccessorElement,
+    MemberSetAccessorElement,
+    /// class X { [public|private]* foo:number; }
+    /// interface Y { foo:number; }
+    MemberVariableElement,
+    /// class X { constructor() { } }
+    ConstructorImplementationElement,
+    /// interface Y { ():number; }
+    CallSignatureElement,
+    /// interface Y { []:number; }
+    IndexSignatureElement,
+    /// interface Y { new():Y; }
+    ConstructSignatureElement,
+    /// function foo(*Y*: string)
+    ParameterElement,
+    TypeParameterElement,
+    PrimitiveType,
+    Label,
+    Alias,
+    ConstElement,
+    LetElement,
+    Directory,
+    ExternalModuleName,
+    /// <JsxTagName attribute1 attribute2={0} />
+    JsxAttribute,
+    /// String literal
+    String,
+    /// Jsdoc @link: in `{@link C link text}`, the before and after text "{@link " and "}"
+    Link,
+    /// Jsdoc @link: in `{@link C link text}`, the entity name "C"
+    LinkName,
+    /// Jsdoc @link: in `{@link C link text}`, the link text "link text"
+    LinkText,
+}
+
+impl ScriptElementKind {
+    /// The value of the kind in `ts.ScriptElementKind`.
+    pub fn js_value(self) -> &'static str {
+        match self {
+            ScriptElementKind::Unknown => "",
+            ScriptElementKind::Warning => "warning",
+            ScriptElementKind::Keyword => "keyword",
+            ScriptElementKind::ScriptElement => "script",
+            ScriptElementKind::ModuleElement => "module",
+            ScriptElementKind::ClassElement => "class",
+            ScriptElementKind::LocalClassElement => "local class",
+            ScriptElementKind::InterfaceElement => "interface",
+            ScriptElementKind::TypeElement => "type",
+            ScriptElementKind::EnumElement => "enum",
+            ScriptElementKind::EnumMemberElement => "enum member",
+            ScriptElementKind::VariableElement => "var",
+            ScriptElementKind::LocalVariableElement => "local var",
+            ScriptElementKind::FunctionElement => "function",
+            ScriptElementKind::LocalFunctionElement => "local function",
+            ScriptElementKind::MemberFunctionElement => "method",
+            ScriptElementKind::MemberGetAccessorElement => "getter",
+            ScriptElementKind::MemberSetAccessorElement => "setter",
+            ScriptElementKind::MemberVariableElement => "property",
+            ScriptElementKind::ConstructorImplementationElement => "constructor",
+            ScriptElementKind::CallSignatureElement => "call",
+            ScriptElementKind::IndexSignatureElement => "index",
+            ScriptElementKind::ConstructSignatureElement => "construct",
+            ScriptElementKind::ParameterElement => "parameter",
+            ScriptElementKind::TypeParameterElement => "type parameter",
+            ScriptElementKind::PrimitiveType => "primitive type",
+            ScriptElementKind::Label => "label",
+            ScriptElementKind::Alias => "alias",
+            ScriptElementKind::ConstElement => "const",
+            ScriptElementKind::LetElement => "let",
+            ScriptElementKind::Directory => "directory",
+            ScriptElementKind::ExternalModuleName => "external module name",
+            ScriptElementKind::JsxAttribute => "JSX attribute",
+            ScriptElementKind::String => "string",
+            ScriptElementKind::Link => "link",
+            ScriptElementKind::LinkName => "link name",
+            ScriptElementKind::LinkText => "link text",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptElementKindModifier {
+    PublicMemberModifier,
+    PrivateMemberModifier,
+    ProtectedMemberModifier,
+    ExportedModifier,
+    AmbientModifier,
+    StaticModifier,
+    AbstractModifier,
+    OptionalModifier,
+    DeprecatedModifier,
+    DtsModifier,
+    TsModifier,
+    TsxModifier,
+    JsModifier,
+    JsxModifier,
+    JsonModifier,
+}
+
+impl ScriptElementKindModifier {
+    /// The value of the modifier in `ts.ScriptElementKindModifier`.
+    pub fn js_value(self) -> &'static str {
+        match self {
+            ScriptElementKindModifier::PublicMemberModifier => "public",
+            ScriptElementKindModifier::PrivateMemberModifier => "private",
+            ScriptElementKindModifier::ProtectedMemberModifier => "protected",
+            ScriptElementKindModifier::ExportedModifier => "export",
+            ScriptElementKindModifier::AmbientModifier => "declare",
+            ScriptElementKindModifier::StaticModifier => "static",
+            ScriptElementKindModifier::AbstractModifier => "abstract",
+            ScriptElementKindModifier::OptionalModifier => "optional",
+            ScriptElementKindModifier::DeprecatedModifier => "deprecated",
+            ScriptElementKindModifier::DtsModifier => ".d.ts",
+            ScriptElementKindModifier::TsModifier => ".ts",
+            ScriptElementKindModifier::TsxModifier => ".tsx",
+            ScriptElementKindModifier::JsModifier => ".js",
+            ScriptElementKindModifier::JsxModifier => ".jsx",
+            ScriptElementKindModifier::JsonModifier => ".json",
+        }
+    }
+}
@@ -0,0 +1,534 @@
+use std::cell::RefCell;
+use std::iter;
+
+use crate::compiler::parser::for_each_child;
+use crate::compiler::scanner::{
+    get_leading_comment_ranges, get_trailing_comment_ranges, skip_trivia, Scanner, TextScanner,
+};
+use crate::compiler::types::{
+    AssignmentDeclarationKind, ModifierFlags, NodeArray, NodeData, NodeFlags, NodeId, SourceFile,
+    SyntaxKind, TextRange, TriviaSyntaxKind,
+};
+use crate::compiler::utilities::{
+    get_assignment_declaration_kind, get_combined_node_flags_always_include_js_doc,
+    get_root_declaration, get_token_pos_of_node, has_syntactic_modifier, is_declaration, is_let,
+    is_node_kind, is_var_const,
+};
+
+use super::types::{ScriptElementKind, ScriptElementKindModifier};
+
+/// A child of a node as `getChildren` of the JS services has it. Besides the nodes of the tree,
+/// the children include the tokens the tree keeps no node for, like the punctuation between the
+/// child nodes, and each list of child nodes is a single `SyntaxList` child.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Child {
+    Node(NodeId),
+    Token {
+        kind: SyntaxKind,
+        range: TextRange,
+        parent: NodeId,
+    },
+    SyntaxList {
+        list: NodeArray,
+        parent: NodeId,
+    },
+}
+
+impl Child {
+    pub fn kind(&self, source_file: &SourceFile) -> SyntaxKind {
+        match self {
+            Child::Node(node) => source_file.node(*node).kind,
+            Child::Token { kind, .. } => *kind,
+            Child::SyntaxList { .. } => SyntaxKind::SyntaxList,
+        }
+    }
+
+    pub fn range(&self, source_file: &SourceFile) -> TextRange {
+        match self {
+            Child::Node(node) => source_file.node(*node).range,
+            Child::Token { range, .. } => *range,
+            Child::SyntaxList { list, .. } => list.range,
+        }
+    }
+
+    // The node the child belongs to. The tokens of a list belong to the node of the list, like
+    // the nodes in it.
+    pub fn parent(&self, source_file: &SourceFile) -> Option<NodeId> {
+        match self {
+            Child::Node(node) => source_file.parent(*node),
+            Child::Token { parent, .. } | Child::SyntaxList { parent, .. } => Some(*parent),
+        }
+    }
+
+    // The start of the child after its leading trivia, like `getStart`.
+    pub fn start(&self, source_file: &SourceFile, text: &[u16]) -> usize {
+        match self {
+            Child::Node(node) => get_token_pos_of_node(source_file.node(*node), text),
+            Child::Token { range, .. } => skip_trivia(text, range.pos, false, false, false),
+            Child::SyntaxList { list, .. } => match list.first() {
+                Some(&node) => get_token_pos_of_node(source_file.node(node), text),
+                None => skip_trivia(text, list.range.pos, false, false, false),
+            },
+        }
+    }
+
+    pub fn children(&self, source_file: &SourceFile, scanner: &mut TextScanner) -> Vec<Child> {
+        match self {
+            Child::Node(node) => get_children(source_file, *node, scanner),
+            Child::Token { .. } => Vec::new(),
+            Child::SyntaxList { list, parent } => {
+                get_syntax_list_children(list, *parent, source_file, scanner)
+            }
+        }
+    }
+}
+
+/// The children of the node, like `node.getChildren()` in the JS services. `scanner` is a
+/// scanner over the text of the file that skips trivia, which reads the tokens between the
+/// child nodes.
+pub fn get_children(
+    source_file: &SourceFile,
+    node: NodeId,
+    scanner: &mut TextScanner,
+) -> Vec<Child> {
+    let data = source_file.node(node);
+    if !is_node_kind(data.kind) {
+        return Vec::new();
+    }
+
+    let mut children = Vec::new();
+    if is_js_doc_comment_containing_node(data.kind) {
+        // Don't add trivia for "tokens" since this is in a comment.
+        for_each_child(
+            data,
+            &mut |child| {
+                children.push(Child::Node(child));
+                None::<()>
+            },
+            None,
+        );
+        return children;
+    }
+
+    // jsDocComments need to be the first children
+    children.extend(
+        data.js_doc
+            .iter()
+            .flatten()
+            .map(|&js_doc| Child::Node(js_doc)),
+    );
+
+    let nodes = RefCell::new(Vec::new());
+    for_each_child(
+        data,
+        &mut |child| {
+            nodes.borrow_mut().push(Child::Node(child));
+            None::<()>
+        },
+        Some(&mut |list| {
+            nodes.borrow_mut().push(Child::SyntaxList {
+                list: list.clone(),
+                parent: node,
+            });
+            None
+        }),
+    );
+
+    // For syntactic classifications, all trivia are classified together, including jsdoc comments.
+    // For that to work, the jsdoc comments should still be the leading trivia of the first child.
+    // Starting from the start of the node ensures that.
+    let mut pos = data.pos();
+    for child in nodes.into_inner() {
+        let range = child.range(source_file);
+        add_synthetic_nodes(&mut children, pos, range.pos, node, scanner);
+        children.push(child);
+        pos = range.end;
+    }
+    add_synthetic_nodes(&mut children, pos, data.end(), node, scanner);
+    children
+}
+
+fn get_syntax_list_children(
+    list: &NodeArray,
+    parent: NodeId,
+    source_file: &SourceFile,
+    scanner: &mut TextScanner,
+) -> Vec<Child> {
+    let mut children = Vec::new();
+    let mut pos = list.range.pos;
+    for &node in list.iter() {
+        let range = source_file.node(node).range;
+        add_synthetic_nodes(&mut children, pos, range.pos, parent, scanner);
+        children.push(Child::Node(node));
+        pos = range.end;
+    }
+    add_synthetic_nodes(&mut children, pos, list.range.end, parent, scanner);
+    children
+}
+
+fn add_synthetic_nodes(
+    children: &mut Vec<Child>,
+    mut pos: usize,
+    end: usize,
+    parent: NodeId,
+    scanner: &mut TextScanner,
+) {
+    scanner.set_text_pos(Some(pos));
+    while pos < end {
+        let token = scanner.scan();
+        let text_pos = scanner.get_text_pos();
+        if text_pos <= end {
+            children.push(Child::Token {
+                kind: token,
+                range: TextRange { pos, end: text_pos },
+                parent,
+            });
+        }
+        pos = text_pos;
+        if token == SyntaxKind::EndOfFileToken {
+            break;
+        }
+    }
+}
+
+pub fn find_child_of_kind(
+    source_file: &SourceFile,
+    node: NodeId,
+    kind: SyntaxKind,
+    scanner: &mut TextScanner,
+) -> Option<Child> {
+    get_children(source_file, node, scanner)
+        .into_iter()
+        .find(|child| child.kind(source_file) == kind)
+}
+
+/// The token the position is in, or in the leading trivia of, like `getTokenAtPosition` of the
+/// JS services. Returns a node rather than a token where no token of the node holds the
+/// position, like a position before the JSDoc comment of a node.
+pub fn get_token_at_position(
+    source_file: &SourceFile,
+    position: usize,
+    scanner: &mut TextScanner,
+) -> Child {
+    let mut current = Child::Node(source_file.root);
+    'outer: loop {
+        // find the child that contains 'position'
+        for child in current.children(source_file, scanner) {
+            let range = child.range(source_file);
+            if range.pos > position {
+                // If this child begins after position, then all subsequent children will as well.
+                break;
+            }
+            if position < range.end
+                || (position == range.end && child.kind(source_file) == SyntaxKind::EndOfFileToken)
+            {
+                current = child;
+                continue 'outer;
+            }
+        }
+        return current;
+    }
+}
+
+/// Whether the position is in a comment, like `isInComment` of the JS services.
+pub fn is_in_comment(
+    source_file: &SourceFile,
+    text: &[u16],
+    position: usize,
+    scanner: &mut TextScanner,
+) -> bool {
+    let mut token = get_token_at_position(source_file, position, scanner);
+    if let Child::Node(node) = token {
+        let js_doc = iter::once(node)
+            .chain(source_file.ancestors(node))
+            .find(|&node| source_file.node(node).kind == SyntaxKind::JSDocComment);
+        if let Some(parent) = js_doc.and_then(|js_doc| source_file.parent(js_doc)) {
+            token = Child::Node(parent);
+        }
+    }
+    let range = token.range(source_file);
+    if token.start(source_file, text) <= position && position < range.end {
+        return false;
+    }
+
+    // Between two consecutive tokens, all comments are either trailing on the former
+    // or leading on the latter (and none are in both lists). The former ends where the full
+    // text of the token at the position starts.
+    let mut comment_ranges = get_trailing_comment_ranges(text, range.pos);
+    if token.kind(source_file) != SyntaxKind::JsxText {
+        comment_ranges.extend(get_leading_comment_ranges(text, range.pos));
+    }
+    comment_ranges.iter().any(|comment| {
+        comment.range.pos < position && position < comment.range.end
+            // The end marker of a single-line comment does not include the newline character.
+            // But for closed multi-line comments, the position after them is only in the comment
+            // at the end of the file, where the comment may be unterminated.
+            || position == comment.range.end
+                && (comment.kind == TriviaSyntaxKind::SingleLineCommentTrivia || position == text.len())
+    })
+}
+
+fn is_js_doc_comment_containing_node(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::JSDocComment
+            | SyntaxKind::JSDocNamepathType
+            | SyntaxKind::JSDocText
+            | SyntaxKind::JSDocLink
+            | SyntaxKind::JSDocLinkCode
+            | SyntaxKind::JSDocLinkPlain
+            | SyntaxKind::JSDocTag
+            | SyntaxKind::JSDocAugmentsTag
+            | SyntaxKind::JSDocImplementsTag
+            | SyntaxKind::JSDocAuthorTag
+            | SyntaxKind::JSDocDeprecatedTag
+            | SyntaxKind::JSDocClassTag
+            | SyntaxKind::JSDocPublicTag
+            | SyntaxKind::JSDocPrivateTag
+            | SyntaxKind::JSDocProtectedTag
+            | SyntaxKind::JSDocReadonlyTag
+            | SyntaxKind::JSDocOverrideTag
+            | SyntaxKind::JSDocCallbackTag
+            | SyntaxKind::JSDocEnumTag
+            | SyntaxKind::JSDocParameterTag
+            | SyntaxKind::JSDocReturnTag
+            | SyntaxKind::JSDocThisTag
+            | SyntaxKind::JSDocTypeTag
+            | SyntaxKind::JSDocTemplateTag
+            | SyntaxKind::JSDocTypedefTag
+            | SyntaxKind::JSDocSeeTag
+            | SyntaxKind::JSDocPropertyTag
+            | SyntaxKind::JSDocTypeLiteral
+            | SyntaxKind::JSDocSignature
+    )
+}
+
+pub fn is_punctuation(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::Punctuations(_))
+}
+
+pub fn is_function_like_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::MethodSignature
+            | SyntaxKind::CallSignature
+            | SyntaxKind::JSDocSignature
+            | SyntaxKind::ConstructSignature
+            | SyntaxKind::IndexSignature
+            | SyntaxKind::FunctionType
+            | SyntaxKind::JSDocFunctionType
+            | SyntaxKind::ConstructorType
+            | SyntaxKind::FunctionDeclaration
+            | SyntaxKind::MethodDeclaration
+            | SyntaxKind::Constructor
+            | SyntaxKind::GetAccessor
+            | SyntaxKind::SetAccessor
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::ArrowFunction
+    )
+}
+
+pub fn get_type_argument_or_type_parameter_list(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Option<&NodeArray> {
+    let node = source_file.node(node);
+    if node.kind == SyntaxKind::TypeReference || node.kind == SyntaxKind::CallExpression {
+        return node.type_arguments();
+    }
+
+    if is_function_like_kind(node.kind)
+        || node.kind == SyntaxKind::ClassDeclaration
+        || node.kind == SyntaxKind::InterfaceDeclaration
+    {
+        return node.type_parameters();
+    }
+
+    None
+}
+
+pub fn get_node_kind(source_file: &SourceFile, node: NodeId) -> ScriptElementKind {
+    let data = source_file.node(node);
+    match data.kind {
+        SyntaxKind::SourceFile => {
+            if source_file.is_external_module() {
+                ScriptElementKind::ModuleElement
+            } else {
+                ScriptElementKind::ScriptElement
+            }
+        }
+        SyntaxKind::ModuleDeclaration => ScriptElementKind::ModuleElement,
+        SyntaxKind::ClassDeclaration | SyntaxKind::ClassExpression => {
+            ScriptElementKind::ClassElement
+        }
+        SyntaxKind::InterfaceDeclaration => ScriptElementKind::InterfaceElement,
+        SyntaxKind::TypeAliasDeclaration
+        | SyntaxKind::JSDocCallbackTag
+        | SyntaxKind::JSDocTypedefTag => ScriptElementKind::TypeElement,
+        SyntaxKind::EnumDeclaration => ScriptElementKind::EnumElement,
+        SyntaxKind::VariableDeclaration => get_kind_of_variable_declaration(source_file, node),
+        SyntaxKind::BindingElement => {
+            get_kind_of_variable_declaration(source_file, get_root_declaration(source_file, node))
+        }
+        SyntaxKind::ArrowFunction
+        | SyntaxKind::FunctionDeclaration
+        | SyntaxKind::FunctionExpression => ScriptElementKind::FunctionElement,
+        SyntaxKind::GetAccessor => ScriptElementKind::MemberGetAccessorElement,
+        SyntaxKind::SetAccessor => ScriptElementKind::MemberSetAccessorElement,
+        SyntaxKind::MethodDeclaration | SyntaxKind::MethodSignature => {
+            ScriptElementKind::MemberFunctionElement
+        }
+        SyntaxKind::PropertyAssignment => match data.initializer() {
+            Some(initializer) if is_function_like_kind(source_file.node(initializer).kind) => {
+                ScriptElementKind::MemberFunctionElement
+            }
+            _ => ScriptElementKind::MemberVariableElement,
+        },
+        SyntaxKind::PropertyDeclaration
+        | SyntaxKind::PropertySignature
+        | SyntaxKind::ShorthandPropertyAssignment
+        | SyntaxKind::SpreadAssignment => ScriptElementKind::MemberVariableElement,
+        SyntaxKind::IndexSignature => ScriptElementKind::IndexSignatureElement,
+        SyntaxKind::ConstructSignature => ScriptElementKind::ConstructSignatureElement,
+        SyntaxKind::CallSignature => ScriptElementKind::CallSignatureElement,
+        SyntaxKind::Constructor => ScriptElementKind::ConstructorImplementationElement,
+        SyntaxKind::TypeParameter => ScriptElementKind::TypeParameterElement,
+        SyntaxKind::EnumMember => ScriptElementKind::EnumMemberElement,
+        SyntaxKind::Parameter => {
+            if has_syntactic_modifier(
+                source_file,
+                node,
+                ModifierFlags::PARAMETER_PROPERTY_MODIFIER,
+            ) {
+                ScriptElementKind::MemberVariableElement
+            } else {
+                ScriptElementKind::ParameterElement
+            }
+        }
+        SyntaxKind::ImportEqualsDeclaration
+        | SyntaxKind::ImportSpecifier
+        | SyntaxKind::ExportSpecifier
+        | SyntaxKind::NamespaceImport
+        | SyntaxKind::NamespaceExport => ScriptElementKind::Alias,
+        SyntaxKind::BinaryExpression => {
+            let right = match &data.data {
+                NodeData::BinaryExpression { right, .. } => *right,
+                _ => return ScriptElementKind::Unknown,
+            };
+            let right_is_function_expression =
+                source_file.node(right).kind == SyntaxKind::FunctionExpression;
+            match get_assignment_declaration_kind(source_file, node) {
+                AssignmentDeclarationKind::ObjectDefinePropertyValue
+                | AssignmentDeclarationKind::ObjectDefinePropertyExports
+                | AssignmentDeclarationKind::ObjectDefinePrototypeProperty
+                | AssignmentDeclarationKind::None => ScriptElementKind::Unknown,
+                AssignmentDeclarationKind::ExportsProperty
+                | AssignmentDeclarationKind::ModuleExports => {
+                    match get_node_kind(source_file, right) {
+                        ScriptElementKind::Unknown => ScriptElementKind::ConstElement,
+                        right_kind => right_kind,
+                    }
+                }
+                AssignmentDeclarationKind::PrototypeProperty if right_is_function_expression => {
+                    ScriptElementKind::MemberFunctionElement
+                }
+                AssignmentDeclarationKind::PrototypeProperty => {
+                    ScriptElementKind::MemberVariableElement
+                }
+                // property
+                AssignmentDeclarationKind::ThisProperty => ScriptElementKind::MemberVariableElement,
+                // static method / property
+                AssignmentDeclarationKind::Property if right_is_function_expression => {
+                    ScriptElementKind::MemberFunctionElement
+                }
+                AssignmentDeclarationKind::Property => ScriptElementKind::MemberVariableElement,
+                AssignmentDeclarationKind::Prototype => ScriptElementKind::LocalClassElement,
+            }
+        }
+        SyntaxKind::Identifier => match source_file.parent(node) {
+            Some(parent) if source_file.node(parent).kind == SyntaxKind::ImportClause => {
+                ScriptElementKind::Alias
+            }
+            _ => ScriptElementKind::Unknown,
+        },
+        SyntaxKind::ExportAssignment => match data
+            .expression()
+            .map(|expression| get_node_kind(source_file, expression))
+        {
+            // If the expression didn't come back with something (like it does for an identifiers)
+            None | Some(ScriptElementKind::Unknown) => ScriptElementKind::ConstElement,
+            Some(script_kind) => script_kind,
+        },
+        _ => ScriptElementKind::Unknown,
+    }
+}
+
+fn get_kind_of_variable_declaration(
+    source_file: &SourceFile,
+    declaration: NodeId,
+) -> ScriptElementKind {
+    if is_var_const(source_file, declaration) {
+        ScriptElementKind::ConstElement
+    } else if is_let(source_file, declaration) {
+        ScriptElementKind::LetElement
+    } else {
+        ScriptElementKind::VariableElement
+    }
+}
+
+// The modifiers of the node from its modifier keywords and JSDoc tags, in the order of
+// `getNodeModifiers`.
+pub fn get_node_modifiers(
+    source_file: &SourceFile,
+    node: NodeId,
+) -> Vec<ScriptElementKindModifier> {
+    let data = source_file.node(node);
+    let flags = if is_declaration(source_file, node) {
+        get_combined_node_flags_always_include_js_doc(source_file, node)
+    } else {
+        ModifierFlags::NONE
+    };
+
+    let mut result = Vec::new();
+    for &(flag, modifier) in &[
+        (
+            ModifierFlags::PRIVATE,
+            ScriptElementKindModifier::PrivateMemberModifier,
+        ),
+        (
+            ModifierFlags::PROTECTED,
+            ScriptElementKindModifier::ProtectedMemberModifier,
+        ),
+        (
+            ModifierFlags::PUBLIC,
+            ScriptElementKindModifier::PublicMemberModifier,
+        ),
+        (
+            ModifierFlags::STATIC,
+            ScriptElementKindModifier::StaticModifier,
+        ),
+        (
+            ModifierFlags::ABSTRACT,
+            ScriptElementKindModifier::AbstractModifier,
+        ),
+        (
+            ModifierFlags::EXPORT,
+            ScriptElementKindModifier::ExportedModifier,
+        ),
+        (
+            ModifierFlags::DEPRECATED,
+            ScriptElementKindModifier::DeprecatedModifier,
+        ),
+    ] {
+        if flags & flag != 0 {
+            result.push(modifier);
+        }
+    }
+    if data.flags & NodeFlags::AMBIENT != 0 {
+        result.push(ScriptElementKindModifier::AmbientModifier);
+    }
+    if data.kind == SyntaxKind::ExportAssignment {
+        result.push(ScriptElementKindModifier::ExportedModifier);
+    }
+    result
+}
@@ -0,0 +1,106 @@
+// The WebAssembly bindings, for browsers and other hosts that can't load the N-API addon. They
+// export the scanner and line map functions under the names and with the results of the addon,
+// so `addon.ts` can use either; the work is done in `compiler`, like for the addon.
+
+use js_sys::{Array, Object, Reflect, Uint32Array};
+use wasm_bindgen::prelude::*;
+
+use crate::compiler::parser::script_target_from_number;
+use crate::compiler::scanner::{compute_line_starts, tokenize, Tokenization};
+use crate::compiler::types::{Diagnostic, LanguageVariant, ScriptTarget};
+use crate::compiler::unicode::lookup_in_unicode_map;
+
+#[wasm_bindgen(js_name = lookupInUnicodeMap)]
+pub fn js_lookup_in_unicode_map(code: u32, map: &[u32]) -> bool {
+    lookup_in_unicode_map(code, map)
+}
+
+#[wasm_bindgen(js_name = computeLineStarts)]
+pub fn js_compute_line_starts(text: &str) -> Vec<u32> {
+    compute_line_starts(text)
+        .into_iter()
+        .map(|line_start| line_start as u32)
+        .collect()
+}
+
+fn set(object: &Object, name: &str, value: &JsValue) -> Result<(), JsValue> {
+    Reflect::set(object, &JsValue::from_str(name), value).map(drop)
+}
+
+fn get_option(options: &JsValue, name: &str) -> Result<Option<JsValue>, JsValue> {
+    if !options.is_object() {
+        return Ok(None);
+    }
+    let value = Reflect::get(options, &JsValue::from_str(name))?;
+    Ok(if value.is_undefined() {
+        None
+    } else {
+        Some(value)
+    })
+}
+
+fn create_diagnostics_array(diagnostics: &[Diagnostic]) -> Result<Array, JsValue> {
+    let array = Array::new();
+    for diagnostic in diagnostics {
+        let object = Object::new();
+        if let Some(file_name) = &diagnostic.file_name {
+            set(&object, "fileName", &JsValue::from_str(file_name))?;
+        }
+        if let Some(start) = diagnostic.start {
+            set(&object, "start", &JsValue::from(start as u32))?;
+        }
+        if let Some(length) = diagnostic.length {
+            set(&object, "length", &JsValue::from(length as u32))?;
+        }
+        set(
+            &object,
+            "messageText",
+            &JsValue::from_str(&diagnostic.message_text),
+        )?;
+        set(
+            &object,
+            "category",
+            &JsValue::from(diagnostic.category.js_value()),
+        )?;
+        set(&object, "code", &JsValue::from(diagnostic.code))?;
+        array.push(&object);
+    }
+    Ok(array)
+}
+
+fn create_tokenization_object(tokenization: &Tokenization) -> Result<Object, JsValue> {
+    let (encoded, values) = tokenization.encode();
+    let value_strings = Array::new();
+    for value in values {
+        value_strings.push(&JsValue::from_str(value));
+    }
+    let diagnostics = create_diagnostics_array(&tokenization.diagnostics)?;
+    let result = Object::new();
+    set(&result, "tokens", &Uint32Array::from(&encoded[..]))?;
+    set(&result, "values", &value_strings)?;
+    set(&result, "diagnostics", &diagnostics)?;
+    Ok(result)
+}
+
+// Takes the `(text, options?)` of the addon's `tokenize`, except for `cancellationToken`: the
+// module runs on the thread of its caller, so there is nothing to cancel from.
+#[wasm_bindgen(js_name = tokenize)]
+pub fn js_tokenize(text: String, options: JsValue) -> Result<Object, JsValue> {
+    let get_number = |name: &str| -> Result<Option<u32>, JsValue> {
+        Ok(get_option(&options, name)?
+            .and_then(|value| value.as_f64())
+            .map(|value| value as u32))
+    };
+    let language_version =
+        get_number("languageVersion")?.map_or(ScriptTarget::ESNext, script_target_from_number);
+    let language_variant = match get_number("languageVariant")? {
+        Some(1) => LanguageVariant::JSX,
+        _ => LanguageVariant::Standard,
+    };
+    let skip_trivia = get_option(&options, "skipTrivia")?
+        .and_then(|value| value.as_bool())
+        .unwrap_or(true);
+    let tokenization = tokenize(text, language_version, language_variant, skip_trivia, None)
+        .expect("tokenizing without a cancellation token can't be canceled");
+    create_tokenization_object(&tokenization)
+}
@@ -0,0 +1,208 @@
+// @ts-check
+// Calls the exports of the addon directly, for what the comparisons with the JS compiler don't
+// cover: batches of files, the promises of the async exports, cancellation and atom tables.
+// Build the addon first, with `npm run addon:build`, then run `npm run addon:test:exports`.
+const assert = require("chai").assert;
+const fs = require("fs");
+const path = require("path");
+const { loadAddon } = require("./loadAddon");
+
+const root = path.join(__dirname, "../..");
+const built = path.join(root, "built/local");
+
+/** @type {any} */
+const addon = loadAddon();
+
+/** The number of entries of a node in the table of `getTreeNodeTable`, like `ts.nativeNodeTableStride` */
+const nodeTableStride = 5;
+
+/** The values of `ts.ScriptTarget` the tests parse with */
+const ScriptTarget = { ES5: 1, ESNext: 99 };
+
+/** The code, start and length of each diagnostic */
+function spans(diagnostics) {
+    return diagnostics.map(({ code, start, length }) => [code, start, length]);
+}
+
+describe("parseFiles", () => {
+    it("returns the files in the order given", () => {
+        const files = [];
+        for (let i = 0; i < 64; i++) {
+            files.push({ fileName: `file${i}.ts`, text: `const value${i} = ${i};` });
+        }
+        const results = addon.parseFiles(files, { languageVersion: ScriptTarget.ESNext });
+        assert.deepEqual(results.map(result => result.fileName), files.map(file => file.fileName));
+        for (const [i, result] of results.entries()) {
+            assert.deepEqual(result.errors, []);
+            assert.include(result.identifiers, `value${i}`);
+            // Files given as text don't get it back.
+            assert.isUndefined(result.text);
+        }
+    });
+
+    it("reports the diagnostics of each file", () => {
+        const existing = path.join(root, "tests/cases/conformance/parser/ecmascript5/Statements/parserBlockStatement1.d.ts");
+        const missing = path.join(root, "tests/cases/does-not-exist.ts");
+        const [error, unreadable, read, clean] = addon.parseFiles([
+            { fileName: "error.ts", text: "let a = ;" },
+            missing,
+            existing,
+            { fileName: "clean.ts", text: "let a = 1;" },
+        ], { languageVersion: ScriptTarget.ESNext });
+
+        assert.deepEqual(spans(error.parseDiagnostics), [[1109, 8, 1]]);
+        assert.deepEqual(error.parseDiagnostics, addon.parseSourceFile("error.ts", "let a = ;", ScriptTarget.ESNext).parseDiagnostics);
+        assert.deepEqual(error.errors, []);
+
+        assert.strictEqual(unreadable.fileName, missing);
+        assert.deepEqual(unreadable.errors.map(diagnostic => diagnostic.code), [5083]);
+        assert.isUndefined(unreadable.tree);
+
+        assert.strictEqual(read.text, fs.readFileSync(existing, "utf8"));
+        assert.isTrue(read.isDeclarationFile);
+        assert.deepEqual(read.errors, []);
+
+        assert.deepEqual(clean.parseDiagnostics, []);
+    });
+});
+
+describe("async exports", () => {
+    const text = "/// <reference path=\"a.ts\" />\nlet a = `x${1}y`;\nclass C<T> { #p = /re/g; }\nlet b = ;\n";
+
+    /** The properties of a tree of `createSourceFileTree` but the tree, which is an external */
+    function withoutTree({ tree, ...summary }) {
+        return summary;
+    }
+
+    it("compute the line starts like computeLineStarts", async () => {
+        const promise = addon.computeLineStartsAsync(text);
+        assert.instanceOf(promise, Promise);
+        assert.deepEqual(await promise, addon.computeLineStarts(text));
+    });
+
+    it("tokenize like tokenize", async () => {
+        const options = { languageVersion: ScriptTarget.ES5, skipTrivia: false };
+        assert.deepEqual(await addon.tokenizeAsync(text, options), addon.tokenize(text, options));
+    });
+
+    it("parse like parseSourceFile", async () => {
+        assert.deepEqual(
+            await addon.parseSourceFileAsync("test.ts", text, ScriptTarget.ESNext),
+            addon.parseSourceFile("test.ts", text, ScriptTarget.ESNext));
+    });
+
+    it("create trees like createSourceFileTree", async () => {
+        const expected = addon.createSourceFileTree("test.ts", text, ScriptTarget.ESNext);
+        const actual = await addon.createSourceFileTreeAsync("test.ts", text, ScriptTarget.ESNext);
+        assert.deepEqual(withoutTree(actual), withoutTree(expected));
+        assert.deepEqual(addon.getTreeNodeTable(actual.tree), addon.getTreeNodeTable(expected.tree));
+    });
+
+    it("parse batches like parseFiles", async () => {
+        const files = [{ fileName: "a.ts", text }, { fileName: "b.ts", text: "let b = 1;" }];
+        const expected = addon.parseFiles(files, { languageVersion: ScriptTarget.ESNext });
+        const actual = await addon.parseFilesAsync(files, { languageVersion: ScriptTarget.ESNext });
+        assert.deepEqual(actual.map(withoutTree), expected.map(withoutTree));
+    });
+
+    it("resolve each promise to the result of its own call", async () => {
+        const texts = [];
+        for (let i = 0; i < 16; i++) {
+            texts.push("\n".repeat(i));
+        }
+        const results = await Promise.all(texts.map(text => addon.computeLineStartsAsync(text)));
+        assert.deepEqual(results.map(lineStarts => lineStarts.length), texts.map(text => text.length + 1));
+    });
+});
+
+describe("cancellation", () => {
+    // A text that takes the parser far longer to get through than the tests take to cancel it.
+    const largeText = "function f(a: number) { return a * 2 + g(a, [1, 2, 3]); }\n".repeat(200000);
+
+    /** Waits for the promise to reject, and returns the message of the error it rejects with */
+    async function rejection(promise) {
+        try {
+            await promise;
+        }
+        catch (e) {
+            return e.message;
+        }
+        assert.fail("the promise resolved");
+    }
+
+    it("throw from the sync exports given a canceled token", () => {
+        const cancellationToken = addon.createCancellationToken();
+        addon.requestCancellation(cancellationToken);
+        assert.throws(() => addon.parseSourceFile("test.ts", "let a;", ScriptTarget.ESNext, undefined, cancellationToken), "OperationCanceled");
+        assert.throws(() => addon.tokenize("let a;", { cancellationToken }), "OperationCanceled");
+        assert.throws(() => addon.parseFiles([{ fileName: "test.ts", text: "let a;" }], { languageVersion: ScriptTarget.ESNext, cancellationToken }), "OperationCanceled");
+    });
+
+    it("leave the results alone without a cancellation", () => {
+        const cancellationToken = addon.createCancellationToken();
+        assert.deepEqual(
+            addon.parseSourceFile("test.ts", "let a = ;", ScriptTarget.ESNext, undefined, cancellationToken),
+            addon.parseSourceFile("test.ts", "let a = ;", ScriptTarget.ESNext));
+    });
+
+    it("reject the promise of a parse canceled while it runs", async () => {
+        const cancellationToken = addon.createCancellationToken();
+        const promise = addon.parseSourceFileAsync("large.ts", largeText, ScriptTarget.ESNext, undefined, cancellationToken);
+        // Let the parse start in the thread pool before canceling it.
+        await new Promise(resolve => setTimeout(resolve, 10));
+        addon.requestCancellation(cancellationToken);
+        assert.strictEqual(await rejection(promise), "OperationCanceled");
+    });
+
+    it("reject the promise of a batch canceled while it runs", async () => {
+        const cancellationToken = addon.createCancellationToken();
+        const files = [];
+        for (let i = 0; i < 8; i++) {
+            files.push({ fileName: `large${i}.ts`, text: largeText });
+        }
+        const promise = addon.parseFilesAsync(files, { languageVersion: ScriptTarget.ESNext, cancellationToken });
+        await new Promise(resolve => setTimeout(resolve, 10));
+        addon.requestCancellation(cancellationToken);
+        assert.strictEqual(await rejection(promise), "OperationCanceled");
+    });
+});
+
+describe("atom tables", () => {
+    /** The atoms of the identifiers of a tree, by their texts */
+    function getIdentifierAtoms(result) {
+        const table = addon.getTreeNodeTable(result.tree);
+        const ids = [];
+        for (let id = 0; id < table.length / nodeTableStride; id++) {
+            ids.push(id);
+        }
+        /** @type {Map<string, number>} */
+        const atoms = new Map();
+        const records = addon.getTreeNodes(result.tree, ids).filter(record => record.atom !== undefined);
+        const texts = addon.getAtomTexts(result.tree, records.map(record => record.atom));
+        records.forEach((record, index) => atoms.set(texts[index], record.atom));
+        return atoms;
+    }
+
+    it("give the identifiers of every batch sharing a table the same atoms", () => {
+        const atomTable = addon.createAtomTable();
+        const [first] = addon.parseFiles([{ fileName: "a.ts", text: "let shared = a1 + __proto__;" }], { languageVersion: ScriptTarget.ESNext, atomTable });
+        const [second] = addon.parseFiles([{ fileName: "b.ts", text: "let b1 = __proto__ + shared;" }], { languageVersion: ScriptTarget.ESNext, atomTable });
+        const firstAtoms = getIdentifierAtoms(first);
+        const secondAtoms = getIdentifierAtoms(second);
+        assert.deepEqual([...firstAtoms.keys()].sort(), ["__proto__", "a1", "shared"]);
+        assert.strictEqual(secondAtoms.get("shared"), firstAtoms.get("shared"));
+        assert.strictEqual(secondAtoms.get("__proto__"), firstAtoms.get("__proto__"));
+        assert.notStrictEqual(secondAtoms.get("b1"), firstAtoms.get("a1"));
+    });
+
+    it("read the texts of atoms through any tree of the table", () => {
+        const atomTable = addon.createAtomTable();
+        const [first, second] = addon.parseFiles([
+            { fileName: "a.ts", text: "let a = 1;" },
+            { fileName: "b.ts", text: "let b = 2;" },
+        ], { languageVersion: ScriptTarget.ESNext, atomTable });
+        const atoms = [...getIdentifierAtoms(first).values(), ...getIdentifierAtoms(second).values()];
+        assert.deepEqual(addon.getAtomTexts(first.tree, atoms), addon.getAtomTexts(second.tree, atoms));
+        assert.deepEqual(addon.getAtomTexts(first.tree, atoms).sort(), ["a", "b"]);
+    });
+});
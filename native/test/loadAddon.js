@@ -0,0 +1,20 @@
+// @ts-check
+// Loads the addon `npm run addon:build` leaves in built/local, for the tests and benchmarks that
+// call it directly rather than through the compiler.
+const fs = require("fs");
+const path = require("path");
+
+const built = path.join(__dirname, "../../built/local");
+
+/** @returns {any} */
+function loadAddon() {
+    // `napi build --platform` names the addon after the triple, like `typescript.linux-x64-gnu.node`.
+    const prefix = `typescript.${process.platform}-${process.arch}`;
+    const addon = fs.readdirSync(built).find(name => name.startsWith(prefix) && name.endsWith(".node"));
+    if (!addon) {
+        throw new Error(`No addon for ${process.platform}-${process.arch} in ${built}`);
+    }
+    return require(path.join(built, addon));
+}
+
+module.exports = { loadAddon };
@@ -0,0 +1,107 @@
+// @ts-check
+// Compares the trees of the native parser with the ones of `ts.createSourceFile`, through the
+// lazy `SourceFile`s of `ts.createLazySourceFile` that JS code walks with `forEachChild`. Build
+// both first, with `gulp local` and `npm run addon:build`, then run `npm run addon:test:parser`.
+const assert = require("chai").assert;
+const fs = require("fs");
+const path = require("path");
+
+const root = path.join(__dirname, "../..");
+const built = path.join(root, "built/local");
+
+/** @type {any} The lazy source files and the addon helpers aren't in the public typings. */
+const ts = require(path.join(built, "typescript.js"));
+
+/** The files of a directory and its subdirectories, sorted so the report is stable. */
+function getFiles(directory) {
+    /** @type {string[]} */
+    const files = [];
+    for (const entry of fs.readdirSync(directory, { withFileTypes: true }).sort((a, b) => a.name < b.name ? -1 : a.name > b.name ? 1 : 0)) {
+        const fullPath = path.join(directory, entry.name);
+        if (entry.isDirectory()) {
+            files.push(...getFiles(fullPath));
+        }
+        else {
+            files.push(fullPath);
+        }
+    }
+    return files;
+}
+
+/** The kind, range and depth of each node of a tree, in the order `forEachChild` visits them. */
+function walk(sourceFile) {
+    /** @type {string[]} */
+    const nodes = [];
+    const visit = (node, depth) => {
+        nodes.push(`${"  ".repeat(depth)}${ts.Debug.formatSyntaxKind(node.kind)} ${node.pos}..${node.end}`);
+        ts.forEachChild(node, child => {
+            visit(child, depth + 1);
+        });
+    };
+    visit(sourceFile, 0);
+    return nodes;
+}
+
+describe("native trees", () => {
+    before(() => {
+        // Without the addon, `createLazySourceFile` falls back to the JS parser and every comparison would pass.
+        assert.isTrue(ts.isNativeFunctionAvailable("createSourceFileTree"), `the compiler in ${built} did not load the addon: ${ts.nativeAddonLoadError}`);
+    });
+
+    describe("a module file", () => {
+        const text = [
+            "/// <reference path=\"./types.d.ts\" />",
+            "// @ts-check",
+            "/** @jsx h */",
+            "import { a as b } from \"./a\";",
+            "// @ts-expect-error",
+            "const c: number = b[\"key\"];",
+            "declare module \"ambient\" { export const __proto__: string; }",
+            "export default { \"name\": 1, 2: c };",
+        ].join("\n");
+        /** @type {any} */
+        let expected;
+        /** @type {any} */
+        let actual;
+        before(() => {
+            expected = ts.createSourceFile("module.ts", text, ts.ScriptTarget.ESNext, /*setParentNodes*/ true);
+            actual = ts.createLazySourceFile("module.ts", text, ts.ScriptTarget.ESNext);
+        });
+
+        it("visits the children of nodes in the order of forEachChild", () => {
+            assert.deepEqual(walk(actual), walk(expected));
+        });
+
+        it("has the external module indicator", () => {
+            assert.isDefined(actual.externalModuleIndicator);
+            assert.deepEqual(walk(actual.externalModuleIndicator), walk(expected.externalModuleIndicator));
+            assert.strictEqual(actual.externalModuleIndicator, actual.statements[0]);
+        });
+
+        it("has the identifiers", () => {
+            assert.deepEqual(ts.arrayFrom(actual.identifiers.keys()).sort(), ts.arrayFrom(expected.identifiers.keys()).sort());
+        });
+
+        it("has the comment directives", () => {
+            assert.deepEqual(actual.commentDirectives, expected.commentDirectives);
+        });
+
+        it("has the pragmas", () => {
+            assert.deepEqual(ts.arrayFrom(actual.pragmas.entries()), ts.arrayFrom(expected.pragmas.entries()));
+        });
+    });
+
+    describe("visit the children of nodes in the order of forEachChild", () => {
+        const directory = path.join(root, "tests/cases/conformance/parser");
+        for (const file of getFiles(directory)) {
+            const fileName = path.relative(directory, file);
+            it(fileName, () => {
+                const text = fs.readFileSync(file, "utf8");
+                const scriptKind = ts.getScriptKindFromFileName(fileName);
+                const expected = ts.createSourceFile(fileName, text, ts.ScriptTarget.ESNext, /*setParentNodes*/ true, scriptKind);
+                const actual = ts.createLazySourceFile(fileName, text, ts.ScriptTarget.ESNext, scriptKind);
+                assert.deepEqual(walk(actual), walk(expected));
+            });
+        }
+    });
+});
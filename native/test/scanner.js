@@ -0,0 +1,220 @@
+// @ts-check
+// Scans every file under tests/cases/compiler and tests/cases/conformance with the native scanner
+// of the addon and with `ts.createScanner`, and reports the first token or diagnostic each file
+// differs in. Build both first, with `gulp local` and `npm run addon:build`, then run
+// `npm run addon:test:scanner`.
+const assert = require("chai").assert;
+const fs = require("fs");
+const path = require("path");
+const { loadAddon } = require("./loadAddon");
+
+const root = path.join(__dirname, "../..");
+const built = path.join(root, "built/local");
+
+const addon = loadAddon();
+/** @type {any} The internals the comparison needs, like `getTokenFlags`, aren't in the public typings. */
+const ts = require(path.join(built, "typescript.js"));
+
+/** The number of entries of a token in the `tokens` of `tokenize`, like `ts.nativeTokenEncodingStride` */
+const stride = 6;
+const noValue = 0xFFFFFFFF;
+
+/**
+ * @typedef {{ kind: number, pos: number, start: number, end: number, flags: number, value?: string }} Token
+ * @typedef {{ start: number, length: number, code: number, messageText: string }} ScanDiagnostic
+ * @typedef {{ tokens: Token[], diagnostics: ScanDiagnostic[] }} Tokenization
+ */
+
+/** @returns {Tokenization} */
+function tokenizeNative(text, languageVersion, languageVariant, skipTrivia) {
+    const { tokens, values, diagnostics } = addon.tokenize(text, { languageVersion, languageVariant, skipTrivia });
+    const result = [];
+    for (let i = 0; i < tokens.length; i += stride) {
+        const valueIndex = tokens[i + 5];
+        result.push({
+            kind: tokens[i],
+            pos: tokens[i + 1],
+            start: tokens[i + 2],
+            end: tokens[i + 3],
+            flags: tokens[i + 4],
+            value: valueIndex === noValue ? undefined : values[valueIndex],
+        });
+    }
+    return {
+        tokens: result,
+        diagnostics: diagnostics.map(({ start, length, code, messageText }) => ({ start, length, code, messageText })),
+    };
+}
+
+/** Whether the native scanner reports the value of a token of the kind: identifiers, keywords and literals. */
+function hasValue(kind) {
+    return kind === ts.SyntaxKind.Identifier
+        || kind === ts.SyntaxKind.PrivateIdentifier
+        || ts.isKeyword(kind)
+        || ts.isLiteralKind(kind)
+        || ts.isTemplateLiteralKind(kind);
+}
+
+/**
+ * Scans the text up to the end of file token the way the native `tokenize` does: `scan` alone,
+ * without the rescans the parser would drive.
+ * @returns {Tokenization}
+ */
+function tokenizeJs(text, languageVersion, languageVariant, skipTrivia) {
+    /** @type {ScanDiagnostic[]} */
+    const diagnostics = [];
+    // The scanner reports an error with its position moved to the start of the error.
+    const scanner = ts.createScanner(languageVersion, skipTrivia, languageVariant, text, (message, length) => {
+        diagnostics.push({ start: scanner.getTextPos(), length, code: message.code, messageText: message.message });
+    });
+    const tokens = [];
+    let kind;
+    do {
+        kind = scanner.scan();
+        tokens.push({
+            kind,
+            pos: scanner.getStartPos(),
+            start: scanner.getTokenPos(),
+            end: scanner.getTextPos(),
+            flags: scanner.getTokenFlags(),
+            value: hasValue(kind) ? scanner.getTokenValue() : undefined,
+        });
+    }
+    while (kind !== ts.SyntaxKind.EndOfFileToken);
+    return { tokens, diagnostics };
+}
+
+/** @param {Token | undefined} token */
+function formatToken(token) {
+    if (!token) {
+        return "(none)";
+    }
+    const value = token.value === undefined ? "" : ` ${JSON.stringify(token.value)}`;
+    return `${ts.Debug.formatSyntaxKind(token.kind)} ${token.pos}/${token.start}..${token.end} flags ${token.flags}${value}`;
+}
+
+/** @param {ScanDiagnostic | undefined} diagnostic */
+function formatDiagnostic(diagnostic) {
+    return diagnostic ? `TS${diagnostic.code} at ${diagnostic.start} (${diagnostic.length}): ${diagnostic.messageText}` : "(none)";
+}
+
+/** The line of the text a position is on, with a caret under the position. */
+function formatContext(text, position) {
+    const { line, character } = ts.computeLineAndCharacterOfPosition(ts.computeLineStarts(text), Math.min(position, text.length));
+    const lineText = text.split(/\r\n?|\n|\u2028|\u2029/)[line] || "";
+    return `line ${line + 1}, character ${character + 1}:\n    ${lineText}\n    ${" ".repeat(character)}^`;
+}
+
+/**
+ * The first token or diagnostic the JS and native scans differ in, described with the tokens
+ * before it and the line it is on, or undefined when they agree.
+ * @param {Tokenization} expected
+ * @param {Tokenization} actual
+ */
+function findFirstDivergence(text, expected, actual) {
+    const tokenCount = Math.max(expected.tokens.length, actual.tokens.length);
+    for (let i = 0; i < tokenCount; i++) {
+        const expectedToken = expected.tokens[i];
+        const actualToken = actual.tokens[i];
+        if (expectedToken && actualToken
+            && expectedToken.kind === actualToken.kind
+            && expectedToken.pos === actualToken.pos
+            && expectedToken.start === actualToken.start
+            && expectedToken.end === actualToken.end
+            && expectedToken.flags === actualToken.flags
+            && expectedToken.value === actualToken.value) {
+            continue;
+        }
+        const before = expected.tokens.slice(Math.max(0, i - 3), i).map(token => `        ${formatToken(token)}`);
+        return [
+            `token ${i} differs at ${formatContext(text, (expectedToken || actualToken).start)}`,
+            `    after:`,
+            ...before,
+            `    js:     ${formatToken(expectedToken)}`,
+            `    native: ${formatToken(actualToken)}`,
+        ].join("\n");
+    }
+    const diagnosticCount = Math.max(expected.diagnostics.length, actual.diagnostics.length);
+    for (let i = 0; i < diagnosticCount; i++) {
+        const expectedDiagnostic = expected.diagnostics[i];
+        const actualDiagnostic = actual.diagnostics[i];
+        if (expectedDiagnostic && actualDiagnostic
+            && expectedDiagnostic.code === actualDiagnostic.code
+            && expectedDiagnostic.start === actualDiagnostic.start
+            && expectedDiagnostic.length === actualDiagnostic.length
+            && expectedDiagnostic.messageText === actualDiagnostic.messageText) {
+            continue;
+        }
+        return [
+            `diagnostic ${i} differs at ${formatContext(text, (expectedDiagnostic || actualDiagnostic).start)}`,
+            `    js:     ${formatDiagnostic(expectedDiagnostic)}`,
+            `    native: ${formatDiagnostic(actualDiagnostic)}`,
+        ].join("\n");
+    }
+    return undefined;
+}
+
+/** The `// @name: value` settings of a test case, with lowercase names, like the `optionRegex` of the test runner. */
+function getSettings(text) {
+    /** @type {Map<string, string>} */
+    const settings = new Map();
+    const optionRegex = /^[\/]{2}\s*@(\w+)\s*:\s*([^\r\n]*)/gm;
+    let match;
+    while ((match = optionRegex.exec(text))) {
+        const name = match[1].toLowerCase();
+        if (!settings.has(name)) {
+            settings.set(name, match[2].trim());
+        }
+    }
+    return settings;
+}
+
+/** The first target a case sets, or the default target of the test runner, ES3. */
+function getScriptTarget(settings) {
+    const target = (settings.get("target") || "").split(",")[0].trim().toLowerCase();
+    const name = target === "es6" ? "es2015" : target;
+    const key = Object.keys(ts.ScriptTarget).find(key => key.toLowerCase() === name && key !== "Latest");
+    return key === undefined ? ts.ScriptTarget.ES3 : ts.ScriptTarget[key];
+}
+
+/** The files of a directory and its subdirectories, sorted so the report is stable. */
+function getFiles(directory) {
+    /** @type {string[]} */
+    const files = [];
+    for (const entry of fs.readdirSync(directory, { withFileTypes: true }).sort((a, b) => a.name < b.name ? -1 : a.name > b.name ? 1 : 0)) {
+        const fullPath = path.join(directory, entry.name);
+        if (entry.isDirectory()) {
+            files.push(...getFiles(fullPath));
+        }
+        else {
+            files.push(fullPath);
+        }
+    }
+    return files;
+}
+
+for (const casesDirectory of ["compiler", "conformance"]) {
+    describe(`scanner parity on tests/cases/${casesDirectory}`, () => {
+        const directory = path.join(root, "tests/cases", casesDirectory);
+        for (const file of getFiles(directory)) {
+            const fileName = path.relative(directory, file);
+            it(fileName, () => {
+                const text = fs.readFileSync(file, "utf8");
+                const settings = getSettings(text);
+                const languageVersion = getScriptTarget(settings);
+                // Cases that set `@jsx` keep their JSX in `.tsx` files of their own, which the
+                // scan of the whole case stands for.
+                const languageVariant = settings.has("jsx")
+                    ? ts.LanguageVariant.JSX
+                    : ts.getLanguageVariant(ts.getScriptKindFromFileName(fileName));
+                for (const skipTrivia of [true, false]) {
+                    const divergence = findFirstDivergence(
+                        text,
+                        tokenizeJs(text, languageVersion, languageVariant, skipTrivia),
+                        tokenizeNative(text, languageVersion, languageVariant, skipTrivia));
+                    assert.isUndefined(divergence, `${skipTrivia ? "without" : "with"} trivia, ${divergence}`);
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,71 @@
+// @ts-check
+// Checks that the WebAssembly build of the scanner and line map returns what the N-API addon
+// does for the same calls. Build both first, with `npm run addon:build` and
+// `npm run addon:build:wasm`, then run `npm run addon:test:wasm`.
+const assert = require("chai").assert;
+const fs = require("fs");
+const path = require("path");
+const { loadAddon } = require("./loadAddon");
+
+const root = path.join(__dirname, "../..");
+const built = path.join(root, "built/local");
+
+const addon = loadAddon();
+const wasm = require(path.join(built, "wasm/typescript.js"));
+
+/** The texts compared: a few that exercise the scanner's corners, and the compiler test cases. */
+function getTexts() {
+    const texts = [
+        "",
+        "var x = 1;\n",
+        "a\r\nb\rc d e\u0085f",
+        "const ã = 'ü'; // ✓\n/* 𝒳 */ let 𝒳 = `${ã}`;",
+        "'unterminated\n\"also",
+        "`a${b}c${`d${e}`}f` /re+/gi 0x1F 1_000n 1e-3 .5 0b101 0o17",
+        "<div className=\"a\">{x} text &amp; </div>",
+        "#!shebang\n#private @decorator a?.b ?? c ||= d",
+        "\\u0061bc \\u{1F600} \u{FEFF}x",
+        "/** @param {string} a */\nfunction f(a) {}\n<<<<<<< HEAD\n=======\n>>>>>>> branch\n",
+    ];
+    const casesDirectory = path.join(root, "tests/cases/compiler");
+    for (const name of fs.readdirSync(casesDirectory).sort().slice(0, 300)) {
+        texts.push(fs.readFileSync(path.join(casesDirectory, name), "utf8"));
+    }
+    return texts;
+}
+
+describe("wasm", () => {
+    const texts = getTexts();
+
+    it("computes the line starts of the addon", () => {
+        for (const text of texts) {
+            assert.deepEqual(Array.from(wasm.computeLineStarts(text)), Array.from(addon.computeLineStarts(text)), JSON.stringify(text.slice(0, 80)));
+        }
+    });
+
+    it("looks code points up in unicode maps like the addon", () => {
+        const map = [0x41, 0x5A, 0x61, 0x7A, 0xAA, 0xAA, 0x1D400, 0x1D454];
+        for (const code of [0, 0x40, 0x41, 0x50, 0x5A, 0x5B, 0x7A, 0xAA, 0xAB, 0x1D400, 0x1D455, 0x10FFFF]) {
+            assert.strictEqual(wasm.lookupInUnicodeMap(code, map), addon.lookupInUnicodeMap(code, map), `code ${code}`);
+        }
+    });
+
+    for (const options of [
+        {},
+        { skipTrivia: false },
+        { languageVariant: 1 },
+        { languageVersion: 0, skipTrivia: false },
+        { languageVersion: 1 },
+    ]) {
+        it(`tokenizes like the addon with ${JSON.stringify(options)}`, () => {
+            for (const text of texts) {
+                const expected = addon.tokenize(text, options);
+                const actual = wasm.tokenize(text, options);
+                const message = JSON.stringify(text.slice(0, 80));
+                assert.deepEqual(Array.from(actual.tokens), Array.from(expected.tokens), message);
+                assert.deepEqual(actual.values, expected.values, message);
+                assert.deepEqual(actual.diagnostics, expected.diagnostics, message);
+            }
+        });
+    }
+});
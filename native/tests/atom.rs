@@ -0,0 +1,107 @@
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use typescript::compiler::atom::{
+    escape_leading_underscores, unescape_leading_underscores, AtomTable,
+};
+
+#[test]
+fn interning_a_text_again_gives_the_same_atom() {
+    let atoms = AtomTable::new();
+    let a = atoms.intern("a");
+    let b = atoms.intern("b");
+    let a_again = atoms.intern("a");
+    assert_eq!((a.id(), b.id(), a_again.id()), (0, 1, 0));
+    assert_eq!(a, a_again);
+    assert_ne!(a, b);
+    // The atoms of a text share one allocation of it.
+    assert_eq!(a.as_str().as_ptr(), a_again.as_str().as_ptr());
+    assert_eq!(atoms.len(), 2);
+}
+
+#[test]
+fn ids_stay_the_same_as_the_table_grows() {
+    let atoms = AtomTable::new();
+    let texts: Vec<String> = (0..1000).map(|index| format!("name{index}")).collect();
+    let ids: Vec<u32> = texts.iter().map(|text| atoms.intern(text).id()).collect();
+    assert_eq!(ids, (0..1000).collect::<Vec<u32>>());
+    for (text, &id) in texts.iter().zip(&ids).rev() {
+        assert_eq!(atoms.intern(text).id(), id);
+        assert_eq!(atoms.lookup(text).map(|atom| atom.id()), Some(id));
+        assert_eq!(atoms.get(id).as_deref(), Some(text.as_str()));
+    }
+    assert_eq!(atoms.len(), 1000);
+    assert_eq!(atoms.lookup("missing"), None);
+    assert_eq!(atoms.get(1000), None);
+}
+
+#[test]
+fn threads_interning_the_same_texts_get_the_same_ids() {
+    const THREADS: usize = 8;
+    let atoms = Arc::new(AtomTable::new());
+    let texts: Arc<Vec<String>> = Arc::new((0..2000).map(|index| format!("name{index}")).collect());
+    let start = Arc::new(Barrier::new(THREADS));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread_index| {
+            let atoms = atoms.clone();
+            let texts = texts.clone();
+            let start = start.clone();
+            thread::spawn(move || {
+                start.wait();
+                // Each thread goes through the texts from another place, so that they race to
+                // intern different texts first.
+                let offset = thread_index * texts.len() / THREADS;
+                let mut ids = vec![0; texts.len()];
+                for index in (offset..texts.len()).chain(0..offset) {
+                    ids[index] = atoms.intern(&texts[index]).id();
+                }
+                ids
+            })
+        })
+        .collect();
+    let results: Vec<Vec<u32>> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    for ids in &results[1..] {
+        assert_eq!(ids, &results[0]);
+    }
+    // Each text got an id of its own, and the ids are the indexes of the table.
+    let mut ids = results[0].clone();
+    ids.sort_unstable();
+    assert_eq!(ids, (0..texts.len() as u32).collect::<Vec<u32>>());
+    assert_eq!(atoms.len(), texts.len());
+    for (text, &id) in texts.iter().zip(&results[0]) {
+        assert_eq!(atoms.get(id).as_deref(), Some(text.as_str()));
+    }
+}
+
+#[test]
+fn leading_underscores_are_escaped_and_unescaped_like_the_js_compiler() {
+    let cases = [
+        ("_", "_"),
+        ("__", "___"),
+        ("___x", "____x"),
+        ("_x", "_x"),
+        ("__proto__", "___proto__"),
+        ("", ""),
+    ];
+    for (text, escaped) in cases {
+        assert_eq!(escape_leading_underscores(text), escaped);
+        assert_eq!(unescape_leading_underscores(escaped), text);
+    }
+    // Only texts with three leading underscores are escaped ones.
+    assert_eq!(unescape_leading_underscores("__x"), "__x");
+}
+
+#[test]
+fn escaped_texts_intern_as_the_text_they_escape() {
+    let atoms = AtomTable::new();
+    let proto = atoms.intern("__proto__");
+    assert_eq!(proto.escaped_text(), "___proto__");
+    assert_eq!(atoms.intern_escaped("___proto__"), proto);
+    assert_eq!(atoms.intern_escaped("_"), atoms.intern("_"));
+    assert_eq!(atoms.intern_escaped("___x").as_str(), "__x");
+    assert_eq!(atoms.len(), 3);
+}
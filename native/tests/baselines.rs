@@ -0,0 +1,343 @@
+// Writes a `.tokens` baseline of the tokens the parser reads from each file of the test cases
+// under `TOKEN_BASELINE_CASES`, and checks it against `tests/baselines/reference` like the other
+// baselines of the test runner. After a change to the scanner, `npm run addon:test:baselines`
+// leaves the baselines that changed in `tests/baselines/local`, to compare with `gulp diff` and
+// accept with `gulp baseline-accept`.
+
+mod common;
+
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::rc::Rc;
+
+use common::{check_baseline, read_test_cases, TestCase};
+use typescript::compiler::parser::{create_source_file, for_each_child};
+use typescript::compiler::scanner::{
+    create_scanner, format_syntax_kind, format_token_flags, Scanner, TextScanner, Token,
+    Tokenization,
+};
+use typescript::compiler::types::{
+    Diagnostic, IDiagnosticMessage, NodeArray, NodeId, SourceFile, SyntaxKind, TokenSyntaxKind,
+};
+use typescript::compiler::utilities::{create_compiler_diagnostic, is_token_kind};
+
+// The directories of `tests/cases` whose cases get a `.tokens` baseline. The scanner and parser
+// conformance tests, along with the template and JSX ones for the tokens the parser rescans,
+// cover the scanner without making thousands of baselines of ordinary code.
+const TOKEN_BASELINE_CASES: &[&str] = &[
+    "tests/cases/conformance/scanner",
+    "tests/cases/conformance/parser",
+    "tests/cases/conformance/es6/templates",
+    "tests/cases/conformance/jsx",
+];
+
+enum Child {
+    Node(NodeId),
+    List(NodeArray),
+}
+
+// Reads the tokens of a parsed file in the order of the text. The tokens the tree has a node
+// for are scanned again with the rescans the parser made of them, so that a regular expression,
+// the rest of a template after `}`, a `>>` or the text of a JSX element is a single token, as the
+// parser read it. The tokens between the nodes are scanned with `scan`, like the tokens
+// `getChildren` of the JS services makes of them.
+struct TokenReader<'a> {
+    source_file: &'a SourceFile,
+    scanner: TextScanner,
+    diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    tokens: Vec<Token>,
+}
+
+impl TokenReader<'_> {
+    fn read_node(&mut self, node: NodeId) {
+        let data = self.source_file.node(node);
+        let children = RefCell::new(Vec::new());
+        for_each_child(
+            data,
+            &mut |child| {
+                children.borrow_mut().push(Child::Node(child));
+                None::<()>
+            },
+            Some(&mut |list| {
+                children.borrow_mut().push(Child::List(list.clone()));
+                None
+            }),
+        );
+        let children = children.into_inner();
+        if is_token_kind(data.kind) {
+            self.read_leaf(node);
+            return;
+        }
+        let mut pos = data.pos();
+        for child in children {
+            match child {
+                Child::Node(child) => {
+                    let range = self.source_file.node(child).range;
+                    self.read_between(node, pos, range.pos);
+                    self.read_node(child);
+                    pos = range.end;
+                }
+                Child::List(list) => {
+                    self.read_between(node, pos, list.range.pos);
+                    pos = list.range.pos;
+                    for &child in list.iter() {
+                        let range = self.source_file.node(child).range;
+                        self.read_between(node, pos, range.pos);
+                        self.read_node(child);
+                        pos = range.end;
+                    }
+                    self.read_between(node, pos, list.range.end);
+                    pos = list.range.end;
+                }
+            }
+        }
+        self.read_between(node, pos, data.end());
+    }
+
+    // The tokens from `pos` to `end` of a node that has no nodes for them, like its punctuation.
+    // Tokens that would run past `end` belong to the parser's splitting of a token the plain scan
+    // reads as one, like the `<<` of `f<<T>() => T>()`, so they are split the same way.
+    fn read_between(&mut self, parent: NodeId, mut pos: usize, end: usize) {
+        let parent_kind = self.source_file.node(parent).kind;
+        self.scanner.set_text_pos(Some(pos));
+        while pos < end {
+            let diagnostic_count = self.diagnostics.borrow().len();
+            let mut kind = if pos == self.source_file.node(parent).pos()
+                && matches!(
+                    parent_kind,
+                    SyntaxKind::JsxClosingElement | SyntaxKind::JsxClosingFragment
+                ) {
+                // The parser reads the `</` of a closing tag among the children of the element.
+                self.scanner.scan_jsx_token().into()
+            } else {
+                self.scanner.scan()
+            };
+            if self.scanner.get_text_pos() > end {
+                kind = match kind {
+                    SyntaxKind::LessThanLessThanToken => self.scanner.re_scan_less_than_token(),
+                    SyntaxKind::QuestionDotToken => self.scanner.re_scan_question_token(),
+                    _ => kind,
+                };
+            }
+            if self.scanner.get_text_pos() > end {
+                // Trivia up to the next node, which reads the token itself.
+                self.diagnostics.borrow_mut().truncate(diagnostic_count);
+                break;
+            }
+            self.push_token(kind);
+            pos = self.scanner.get_text_pos();
+            if kind == SyntaxKind::EndOfFileToken {
+                break;
+            }
+        }
+    }
+
+    fn read_leaf(&mut self, node: NodeId) {
+        let data = self.source_file.node(node);
+        // Missing nodes take up no text, where the end of file token is the only token that can.
+        if data.pos() == data.end() && data.kind != SyntaxKind::EndOfFileToken {
+            return;
+        }
+        let parent_kind = self
+            .source_file
+            .parent(node)
+            .map(|parent| self.source_file.node(parent).kind);
+        self.scanner.set_text_pos(Some(data.pos()));
+        let kind = match data.kind {
+            SyntaxKind::JsxText | SyntaxKind::JsxTextAllWhiteSpaces => {
+                self.scanner.scan_jsx_token().into()
+            }
+            SyntaxKind::StringLiteral if parent_kind == Some(SyntaxKind::JsxAttribute) => {
+                self.scanner.scan_jsx_attribute_value()
+            }
+            _ => {
+                let token = self.scanner.scan();
+                match data.kind {
+                    SyntaxKind::RegularExpressionLiteral => self.scanner.re_scan_slash_token(),
+                    SyntaxKind::TemplateMiddle | SyntaxKind::TemplateTail => {
+                        let is_tagged_template = self.is_in_tagged_template(node);
+                        self.scanner.re_scan_template_token(is_tagged_template)
+                    }
+                    SyntaxKind::TemplateHead | SyntaxKind::NoSubstitutionTemplateLiteral
+                        if self.is_in_tagged_template(node) =>
+                    {
+                        self.scanner
+                            .re_scan_template_head_or_no_substitution_template()
+                    }
+                    SyntaxKind::GreaterThanEqualsToken
+                    | SyntaxKind::GreaterThanGreaterThanToken
+                    | SyntaxKind::GreaterThanGreaterThanGreaterThanToken
+                    | SyntaxKind::GreaterThanGreaterThanEqualsToken
+                    | SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken => {
+                        self.scanner.re_scan_greater_token()
+                    }
+                    SyntaxKind::Identifier
+                        if matches!(
+                            parent_kind,
+                            Some(
+                                SyntaxKind::JsxOpeningElement
+                                    | SyntaxKind::JsxSelfClosingElement
+                                    | SyntaxKind::JsxClosingElement
+                                    | SyntaxKind::JsxAttribute
+                            )
+                        ) =>
+                    {
+                        self.scanner.scan_jsx_identifier()
+                    }
+                    SyntaxKind::Identifier if token == SyntaxKind::Unknown => {
+                        self.scanner.re_scan_invalid_identifier()
+                    }
+                    _ => token,
+                }
+            }
+        };
+        self.push_token(kind);
+    }
+
+    // Whether a part of a template literal belongs to the template of a tagged template, whose
+    // invalid escapes aren't errors.
+    fn is_in_tagged_template(&self, node: NodeId) -> bool {
+        let source_file = self.source_file;
+        let template = match source_file.node(node).kind {
+            SyntaxKind::TemplateMiddle | SyntaxKind::TemplateTail => source_file
+                .parent(node)
+                .and_then(|span| source_file.parent(span)),
+            SyntaxKind::TemplateHead => source_file.parent(node),
+            _ => Some(node),
+        };
+        template.is_some_and(|template| {
+            // The template is the last child of a tagged template, after its tag.
+            source_file.parent(template).is_some_and(|parent| {
+                let parent = source_file.node(parent);
+                parent.kind == SyntaxKind::TaggedTemplateExpression
+                    && parent.end() == source_file.node(template).end()
+            })
+        })
+    }
+
+    fn push_token(&mut self, kind: SyntaxKind) {
+        let value = match kind {
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+            | SyntaxKind::PrivateIdentifier
+            | SyntaxKind::Keywords(_)
+            | SyntaxKind::Literals(_)
+            | SyntaxKind::PseudoLiterals(_) => Some(self.scanner.get_token_value()),
+            _ => None,
+        };
+        self.tokens.push(Token {
+            kind,
+            pos: self.scanner.get_start_pos(),
+            start: self.scanner.get_token_pos(),
+            end: self.scanner.get_text_pos(),
+            flags: self.scanner.get_token_flags(),
+            value,
+        });
+    }
+}
+
+// The tokens of a parsed file along with the errors the scanner reported reading them.
+fn read_tokens(source_file: &SourceFile) -> Tokenization {
+    let diagnostics: Rc<RefCell<Vec<Diagnostic>>> = Rc::default();
+    let scan_error = {
+        let diagnostics = diagnostics.clone();
+        move |message: &IDiagnosticMessage<'static>, pos: usize, length: usize| {
+            let mut diagnostic = create_compiler_diagnostic(message, &[]);
+            diagnostic.start = Some(pos);
+            diagnostic.length = Some(length);
+            diagnostics.borrow_mut().push(diagnostic);
+        }
+    };
+    let scanner = create_scanner(
+        source_file.language_version,
+        true,
+        Some(source_file.language_variant),
+        Some(source_file.text.clone()),
+        Some(Box::new(scan_error)),
+        None,
+        None,
+    );
+    let mut reader = TokenReader {
+        source_file,
+        scanner,
+        diagnostics: diagnostics.clone(),
+        tokens: Vec::new(),
+    };
+    reader.read_node(source_file.root);
+    let tokens = reader.tokens;
+    let diagnostics = diagnostics.take();
+    Tokenization {
+        tokens,
+        diagnostics,
+    }
+}
+
+// Each file of the case, with a line per token: its range, kind, flags and text, along with its
+// value where the value isn't the text, like the cooked text of a string literal. The
+// diagnostics of the scanner follow the tokens of the file they are in.
+fn token_baseline(test_case: &TestCase) -> String {
+    let language_version = test_case.script_target();
+    let mut baseline = String::new();
+    for unit in &test_case.units {
+        let units: Vec<u16> = unit.content.encode_utf16().collect();
+        let source_file =
+            create_source_file(&unit.name, unit.content.clone(), language_version, None);
+        let tokenization = read_tokens(&source_file);
+        writeln!(baseline, "=== {} ===", unit.name).unwrap();
+        for token in &tokenization.tokens {
+            let text = String::from_utf16_lossy(&units[token.start..token.end]);
+            write!(
+                baseline,
+                "{}..{} {}",
+                token.start,
+                token.end,
+                format_syntax_kind(token.kind)
+            )
+            .unwrap();
+            let flags = format_token_flags(token.flags);
+            if !flags.is_empty() {
+                write!(baseline, " [{}]", flags.join(", ")).unwrap();
+            }
+            if !text.is_empty() {
+                write!(baseline, " {:?}", text).unwrap();
+            }
+            match &token.value {
+                Some(value) if *value != text => writeln!(baseline, " value {:?}", value).unwrap(),
+                _ => baseline.push('\n'),
+            }
+        }
+        for diagnostic in &tokenization.diagnostics {
+            writeln!(
+                baseline,
+                "!!! error TS{} at {} ({}): {}",
+                diagnostic.code,
+                diagnostic.start.unwrap_or_default(),
+                diagnostic.length.unwrap_or_default(),
+                diagnostic.message_text
+            )
+            .unwrap();
+        }
+        baseline.push('\n');
+    }
+    baseline
+}
+
+#[test]
+fn token_baselines() {
+    let mut failures = Vec::new();
+    for directory in TOKEN_BASELINE_CASES {
+        for test_case in read_test_cases(directory) {
+            if let Err(message) = check_baseline(
+                &format!("{}.tokens", test_case.name),
+                &token_baseline(&test_case),
+            ) {
+                failures.push(format!("{}: {}", test_case.path, message));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} token baselines differ:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,559 @@
+use typescript::compiler::parser::create_source_file;
+use typescript::compiler::types::{ScriptTarget, TextRange};
+use typescript::services::classifier::{create_classifier, get_encoded_syntactic_classifications};
+use typescript::services::types::{
+    ClassificationResult, ClassificationType, EndOfLineState, TokenClass,
+};
+
+// The entry of the result that starts at the position, like `getEntryAtPosition` of the
+// colorization tests.
+fn entry_at(result: &ClassificationResult, position: usize) -> Option<(usize, TokenClass)> {
+    let mut entry_position = 0;
+    for entry in &result.entries {
+        if entry_position == position {
+            return Some((entry.length, entry.classification));
+        }
+        entry_position += entry.length;
+    }
+    None
+}
+
+// Classifies the line and checks the class of each expected token, found at its first
+// occurrence in the line unless a position is given, and the state the line ends in.
+fn test_lexical_classification(
+    text: &str,
+    initial_end_of_line_state: EndOfLineState,
+    expected_entries: &[(&str, TokenClass, Option<usize>)],
+    final_end_of_line_state: EndOfLineState,
+) {
+    let result =
+        create_classifier().get_classifications_for_line(text, initial_end_of_line_state, false);
+    for &(value, classification, position) in expected_entries {
+        let position = position
+            .or_else(|| text.find(value))
+            .unwrap_or_else(|| panic!("token '{}' does not exist in text '{}'", value, text));
+        assert_eq!(
+            entry_at(&result, position),
+            Some((value.len(), classification)),
+            "classification of '{}' at {} in '{}'",
+            value,
+            position,
+            text
+        );
+    }
+    assert_eq!(
+        result.final_lex_state, final_end_of_line_state,
+        "final state of '{}'",
+        text
+    );
+}
+
+use TokenClass::*;
+
+#[test]
+fn classifies_tokens() {
+    test_lexical_classification(
+        "var x: string = \"foo\" ?? \"bar\"; //Hello",
+        EndOfLineState::None,
+        &[
+            ("var", Keyword, None),
+            (" ", Whitespace, None),
+            ("x", Identifier, None),
+            (":", Punctuation, None),
+            ("string", Keyword, None),
+            ("=", Operator, None),
+            ("\"foo\"", StringLiteral, None),
+            ("??", Operator, None),
+            ("//Hello", Comment, None),
+            (";", Punctuation, None),
+        ],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "1 / 2, 3 / 4",
+        EndOfLineState::None,
+        &[
+            ("1", NumberLiteral, None),
+            ("/", Operator, None),
+            ("4", NumberLiteral, None),
+            (",", Operator, None),
+        ],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "x = /ab+c/g; 10n",
+        EndOfLineState::None,
+        &[
+            ("/ab+c/g", RegExpLiteral, None),
+            ("10n", BigIntLiteral, None),
+        ],
+        EndOfLineState::None,
+    );
+}
+
+#[test]
+fn classifies_keywords_by_the_tokens_before_them() {
+    test_lexical_classification(
+        "a.var",
+        EndOfLineState::None,
+        &[("var", Identifier, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "a./*hello world*/ var",
+        EndOfLineState::None,
+        &[
+            ("/*hello world*/", Comment, None),
+            ("var", Identifier, None),
+        ],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "public static",
+        EndOfLineState::None,
+        &[("public", Keyword, None), ("static", Keyword, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "public var",
+        EndOfLineState::None,
+        &[("public", Keyword, None), ("var", Identifier, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "Foo<Foo,Foo<number",
+        EndOfLineState::None,
+        &[("<", Operator, None), ("number", Identifier, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "<number",
+        EndOfLineState::None,
+        &[("number", Keyword, None)],
+        EndOfLineState::None,
+    );
+}
+
+#[test]
+fn carries_strings_and_comments_across_lines() {
+    test_lexical_classification(
+        "'line1\\",
+        EndOfLineState::None,
+        &[("'line1\\", StringLiteral, None)],
+        EndOfLineState::InSingleQuoteStringLiteral,
+    );
+    test_lexical_classification(
+        "'line1\\\\",
+        EndOfLineState::None,
+        &[("'line1\\\\", StringLiteral, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "\\",
+        EndOfLineState::InDoubleQuoteStringLiteral,
+        &[("\\", StringLiteral, None)],
+        EndOfLineState::InDoubleQuoteStringLiteral,
+    );
+    test_lexical_classification(
+        "'",
+        EndOfLineState::InSingleQuoteStringLiteral,
+        &[("'", StringLiteral, None)],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "   /*/",
+        EndOfLineState::None,
+        &[("/*/", Comment, None)],
+        EndOfLineState::InMultiLineCommentTrivia,
+    );
+    test_lexical_classification(
+        "LOREM IPSUM DOLOR   ",
+        EndOfLineState::InMultiLineCommentTrivia,
+        &[("LOREM IPSUM DOLOR   ", Comment, None)],
+        EndOfLineState::InMultiLineCommentTrivia,
+    );
+    test_lexical_classification(
+        "   */     ",
+        EndOfLineState::InMultiLineCommentTrivia,
+        &[("   */", Comment, None)],
+        EndOfLineState::None,
+    );
+}
+
+#[test]
+fn carries_templates_across_lines() {
+    test_lexical_classification(
+        "`number '${ 1 + 1 }' string '${ 'hello' }'`",
+        EndOfLineState::None,
+        &[
+            ("`number '${", StringLiteral, None),
+            ("+", Operator, None),
+            ("}' string '${", StringLiteral, None),
+            ("'hello'", StringLiteral, None),
+            ("}'`", StringLiteral, None),
+        ],
+        EndOfLineState::None,
+    );
+    test_lexical_classification(
+        "`hello world",
+        EndOfLineState::None,
+        &[("`hello world", StringLiteral, None)],
+        EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate,
+    );
+    test_lexical_classification(
+        "...",
+        EndOfLineState::InTemplateMiddleOrTail,
+        &[("...", StringLiteral, None)],
+        EndOfLineState::InTemplateMiddleOrTail,
+    );
+    test_lexical_classification(
+        "...${",
+        EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate,
+        &[("...${", StringLiteral, None)],
+        EndOfLineState::InTemplateSubstitutionPosition,
+    );
+    test_lexical_classification(
+        "...${ () => { } } ${ { x: `1` } }...`",
+        EndOfLineState::InTemplateHeadOrNoSubstitutionTemplate,
+        &[
+            ("...${", StringLiteral, None),
+            ("=>", Punctuation, None),
+            ("} ${", StringLiteral, Some(16)),
+            ("`1`", StringLiteral, None),
+            ("}...`", StringLiteral, None),
+        ],
+        EndOfLineState::None,
+    );
+}
+
+#[test]
+fn classifies_conflict_markers_as_comments() {
+    test_lexical_classification(
+        "class C {\r\n<<<<<<< HEAD\r\n    v = 1;\r\n=======\r\n    v = 2;\r\n>>>>>>> Branch - a\r\n}",
+        EndOfLineState::None,
+        &[
+            ("<<<<<<< HEAD", Comment, None),
+            ("=", Operator, None),
+            ("=======\r\n    v = 2;\r\n", Comment, None),
+            (">>>>>>> Branch - a", Comment, None),
+            ("}", Punctuation, None),
+        ],
+        EndOfLineState::None,
+    );
+}
+
+#[test]
+fn encodes_spans_relative_to_the_line() {
+    let classifications = create_classifier().get_encoded_lexical_classifications(
+        "end */ x",
+        EndOfLineState::InMultiLineCommentTrivia,
+        false,
+    );
+    // The comment, from the start of the line, and the identifier, like `ClassificationType`.
+    assert_eq!(classifications.spans, vec![0, 6, 1, 7, 1, 2]);
+    assert_eq!(classifications.end_of_line_state, EndOfLineState::None);
+}
+
+// The syntactic classifications of the text in the span, or of all of it, as the text of each
+// classified span and its type, like `verify.syntacticClassificationsAre` of fourslash.
+fn syntactic_classifications(
+    file_name: &str,
+    text: &str,
+    span: Option<TextRange>,
+) -> Vec<(String, ClassificationType)> {
+    let source_file = create_source_file(file_name, text.to_string(), ScriptTarget::ESNext, None);
+    let span = span.unwrap_or(TextRange {
+        pos: 0,
+        end: text.len(),
+    });
+    let classifications = get_encoded_syntactic_classifications(&source_file, span, None).unwrap();
+    assert_eq!(classifications.end_of_line_state, EndOfLineState::None);
+    let text: Vec<u16> = text.encode_utf16().collect();
+    classifications
+        .spans
+        .chunks(3)
+        .map(|triple| {
+            let (start, length) = (triple[0] as usize, triple[1] as usize);
+            let classification = CLASSIFICATION_TYPES
+                .iter()
+                .copied()
+                .find(|&classification| classification as u32 == triple[2])
+                .unwrap_or_else(|| panic!("unknown classification type {}", triple[2]));
+            (
+                String::from_utf16_lossy(&text[start..start + length]),
+                classification,
+            )
+        })
+        .collect()
+}
+
+const CLASSIFICATION_TYPES: &[ClassificationType] = &[
+    ClassificationType::Comment,
+    ClassificationType::Identifier,
+    ClassificationType::Keyword,
+    ClassificationType::NumericLiteral,
+    ClassificationType::Operator,
+    ClassificationType::StringLiteral,
+    ClassificationType::RegularExpressionLiteral,
+    ClassificationType::WhiteSpace,
+    ClassificationType::Text,
+    ClassificationType::Punctuation,
+    ClassificationType::ClassName,
+    ClassificationType::EnumName,
+    ClassificationType::InterfaceName,
+    ClassificationType::ModuleName,
+    ClassificationType::TypeParameterName,
+    ClassificationType::TypeAliasName,
+    ClassificationType::ParameterName,
+    ClassificationType::DocCommentTagName,
+    ClassificationType::JsxOpenTagName,
+    ClassificationType::JsxCloseTagName,
+    ClassificationType::JsxSelfClosingTagName,
+    ClassificationType::JsxAttribute,
+    ClassificationType::JsxText,
+    ClassificationType::JsxAttributeStringLiteralValue,
+    ClassificationType::BigintLiteral,
+];
+
+fn assert_syntactic_classifications(
+    file_name: &str,
+    text: &str,
+    expected: &[(&str, ClassificationType)],
+) {
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|&(text, classification)| (text.to_string(), classification))
+        .collect();
+    assert_eq!(
+        syntactic_classifications(file_name, text, None),
+        expected,
+        "classifications of '{}'",
+        text
+    );
+}
+
+use ClassificationType as Type;
+
+#[test]
+fn classifies_declaration_names_by_what_they_declare() {
+    assert_syntactic_classifications(
+        "a.ts",
+        "class C<T> extends D<T> { m(p: T) { return /a/g + 1n; } }",
+        &[
+            ("class", Type::Keyword),
+            ("C", Type::ClassName),
+            ("<", Type::Punctuation),
+            ("T", Type::TypeParameterName),
+            (">", Type::Punctuation),
+            ("extends", Type::Keyword),
+            ("D", Type::Identifier),
+            ("<", Type::Punctuation),
+            ("T", Type::Identifier),
+            (">", Type::Punctuation),
+            ("{", Type::Punctuation),
+            ("m", Type::Identifier),
+            ("(", Type::Punctuation),
+            ("p", Type::ParameterName),
+            (":", Type::Punctuation),
+            ("T", Type::Identifier),
+            (")", Type::Punctuation),
+            ("{", Type::Punctuation),
+            ("return", Type::Keyword),
+            ("/a/g", Type::StringLiteral),
+            ("+", Type::Operator),
+            ("1n", Type::BigintLiteral),
+            (";", Type::Punctuation),
+            ("}", Type::Punctuation),
+            ("}", Type::Punctuation),
+        ],
+    );
+    assert_syntactic_classifications(
+        "a.ts",
+        "enum E { A = 1 }\nmodule M.N { var x = 'x' < \"y\"; }\ninterface I { f(this: I): void }",
+        &[
+            ("enum", Type::Keyword),
+            ("E", Type::EnumName),
+            ("{", Type::Punctuation),
+            ("A", Type::Identifier),
+            ("=", Type::Punctuation),
+            ("1", Type::NumericLiteral),
+            ("}", Type::Punctuation),
+            ("module", Type::Keyword),
+            ("M", Type::ModuleName),
+            (".", Type::Punctuation),
+            ("N", Type::ModuleName),
+            ("{", Type::Punctuation),
+            ("var", Type::Keyword),
+            ("x", Type::Identifier),
+            ("=", Type::Operator),
+            ("'x'", Type::StringLiteral),
+            ("<", Type::Operator),
+            ("\"y\"", Type::StringLiteral),
+            (";", Type::Punctuation),
+            ("}", Type::Punctuation),
+            ("interface", Type::Keyword),
+            ("I", Type::InterfaceName),
+            ("{", Type::Punctuation),
+            ("f", Type::Identifier),
+            ("(", Type::Punctuation),
+            ("this", Type::Keyword),
+            (":", Type::Punctuation),
+            ("I", Type::Identifier),
+            (")", Type::Punctuation),
+            (":", Type::Punctuation),
+            ("void", Type::Keyword),
+            ("}", Type::Punctuation),
+        ],
+    );
+}
+
+#[test]
+fn classifies_jsx_tags_and_attributes() {
+    assert_syntactic_classifications(
+        "a.tsx",
+        "<div id=\"x\" hidden>text {x}</div>; <Foo.Bar />",
+        &[
+            ("<", Type::Punctuation),
+            ("div", Type::JsxOpenTagName),
+            ("id", Type::JsxAttribute),
+            ("=", Type::Operator),
+            ("\"x\"", Type::JsxAttributeStringLiteralValue),
+            ("hidden", Type::JsxAttribute),
+            (">", Type::Punctuation),
+            ("text ", Type::JsxText),
+            ("{", Type::Punctuation),
+            ("x", Type::Identifier),
+            ("}", Type::Punctuation),
+            ("<", Type::Punctuation),
+            ("/", Type::Punctuation),
+            ("div", Type::JsxCloseTagName),
+            (">", Type::Punctuation),
+            (";", Type::Punctuation),
+            ("<", Type::Punctuation),
+            ("Foo.Bar", Type::JsxSelfClosingTagName),
+            ("/", Type::Punctuation),
+            (">", Type::Punctuation),
+        ],
+    );
+}
+
+#[test]
+fn classifies_js_doc_tags_and_their_names() {
+    assert_syntactic_classifications(
+        "a.ts",
+        "/**\n * @param {string} p\n * @template T\n */\nfunction f<T>(p) {}",
+        &[
+            ("/**\n * ", Type::Comment),
+            ("@", Type::Punctuation),
+            ("param", Type::DocCommentTagName),
+            (" ", Type::Comment),
+            ("{", Type::Punctuation),
+            ("string", Type::Keyword),
+            ("}", Type::Punctuation),
+            (" ", Type::Comment),
+            ("p", Type::ParameterName),
+            ("\n * ", Type::Comment),
+            ("@", Type::Punctuation),
+            ("template", Type::DocCommentTagName),
+            ("T", Type::TypeParameterName),
+            ("*/", Type::Comment),
+            ("function", Type::Keyword),
+            ("f", Type::Identifier),
+            ("<", Type::Punctuation),
+            ("T", Type::TypeParameterName),
+            (">", Type::Punctuation),
+            ("(", Type::Punctuation),
+            ("p", Type::ParameterName),
+            (")", Type::Punctuation),
+            ("{", Type::Punctuation),
+            ("}", Type::Punctuation),
+        ],
+    );
+    // A doc comment the parser attaches to no node is parsed on its own.
+    assert_syntactic_classifications(
+        "a.js",
+        "x; /** @type {number} */",
+        &[
+            ("x", Type::Identifier),
+            (";", Type::Punctuation),
+            ("/** ", Type::Comment),
+            ("@", Type::Punctuation),
+            ("type", Type::DocCommentTagName),
+            ("{", Type::Punctuation),
+            ("number", Type::Keyword),
+            ("}", Type::Punctuation),
+            ("*/", Type::Comment),
+        ],
+    );
+}
+
+#[test]
+fn classifies_triple_slash_directives_like_jsx() {
+    assert_syntactic_classifications(
+        "a.ts",
+        "/// <reference path=\"x.ts\" />\n/// <summary>\n// plain",
+        &[
+            ("/// ", Type::Comment),
+            ("<", Type::Punctuation),
+            ("reference", Type::JsxSelfClosingTagName),
+            (" ", Type::Comment),
+            ("path", Type::JsxAttribute),
+            ("=", Type::Operator),
+            ("\"x.ts\"", Type::JsxAttributeStringLiteralValue),
+            (" ", Type::Comment),
+            ("/>", Type::Punctuation),
+            ("/// <summary>", Type::Comment),
+            ("// plain", Type::Comment),
+        ],
+    );
+}
+
+#[test]
+fn classifies_disabled_merge_code_without_the_tree() {
+    assert_syntactic_classifications(
+        "a.ts",
+        "class C {\n<<<<<<< HEAD\n    v = 1;\n=======\n    v = 2;\n>>>>>>> Branch - a\n}",
+        &[
+            ("class", Type::Keyword),
+            ("C", Type::ClassName),
+            ("{", Type::Punctuation),
+            ("<<<<<<< HEAD", Type::Comment),
+            ("v", Type::Identifier),
+            ("=", Type::Operator),
+            ("1", Type::NumericLiteral),
+            (";", Type::Punctuation),
+            ("=======", Type::Comment),
+            ("v", Type::Identifier),
+            ("=", Type::Punctuation),
+            ("2", Type::NumericLiteral),
+            (";", Type::Punctuation),
+            (">>>>>>> Branch - a", Type::Comment),
+            ("}", Type::Punctuation),
+        ],
+    );
+}
+
+#[test]
+fn classifies_the_nodes_intersecting_the_span() {
+    let text = "let a = 1;\nlet b = 'é';\nlet c = 3;";
+    let start = text.find("let b").unwrap();
+    let classifications = syntactic_classifications(
+        "a.ts",
+        text,
+        Some(TextRange {
+            pos: start,
+            end: start + "let b".len(),
+        }),
+    );
+    let expected: Vec<_> = [
+        ("let", Type::Keyword),
+        ("b", Type::Identifier),
+        ("=", Type::Operator),
+        ("'é'", Type::StringLiteral),
+        (";", Type::Punctuation),
+    ]
+    .iter()
+    .map(|&(text, classification)| (text.to_string(), classification))
+    .collect();
+    assert_eq!(classifications, expected);
+}
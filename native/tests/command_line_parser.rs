@@ -0,0 +1,390 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io;
+
+use typescript::compiler::command_line_parser::{
+    get_parsed_command_line_of_config_file, ExtendedConfigCache,
+};
+use typescript::compiler::types::{CompilerOptions, JsonValue, ParseConfigHost};
+
+// A file system of the given files, so the configs of a test don't need to be on disk. It keeps
+// the paths it was asked to read.
+struct VirtualFileSystem {
+    files: HashMap<String, String>,
+    reads: RefCell<Vec<String>>,
+}
+
+impl VirtualFileSystem {
+    fn new(files: &[(&str, &str)]) -> VirtualFileSystem {
+        VirtualFileSystem {
+            files: files
+                .iter()
+                .map(|&(path, text)| (path.to_string(), text.to_string()))
+                .collect(),
+            reads: RefCell::default(),
+        }
+    }
+}
+
+impl ParseConfigHost for VirtualFileSystem {
+    fn use_case_sensitive_file_names(&self) -> bool {
+        true
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn directory_exists(&self, path: &str) -> bool {
+        let directory = format!("{}/", path.trim_end_matches('/'));
+        self.files.keys().any(|file| file.starts_with(&directory))
+    }
+
+    fn read_file(&self, path: &str) -> io::Result<String> {
+        self.reads.borrow_mut().push(path.to_string());
+        self.files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    fn realpath(&self, path: &str) -> String {
+        path.to_string()
+    }
+}
+
+fn extends_chain() -> VirtualFileSystem {
+    VirtualFileSystem::new(&[
+        (
+            "/dev/tsconfig.json",
+            r#"{ "extends": "./configs/base", "compilerOptions": { "strict": true }, "files": ["main.ts"] }"#,
+        ),
+        (
+            "/dev/tests/tsconfig.json",
+            r#"{ "extends": "../configs/base.json", "compilerOptions": { "target": "es2017" } }"#,
+        ),
+        (
+            "/dev/configs/base.json",
+            r#"{ "extends": "./first.json", "compilerOptions": { "target": "es2015", "outDir": "./out" }, "include": ["../src/*"] }"#,
+        ),
+        (
+            "/dev/configs/first.json",
+            r#"{ "compilerOptions": { "target": "es5", "module": "commonjs", "allowJs": true } }"#,
+        ),
+    ])
+}
+
+fn option<'a>(options: &'a CompilerOptions, name: &str) -> Option<&'a JsonValue> {
+    options.get(name).and_then(Option::as_ref)
+}
+
+#[test]
+fn merges_the_compiler_options_of_the_extends_chain() {
+    let host = extends_chain();
+    let parsed = get_parsed_command_line_of_config_file(
+        "tsconfig.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .unwrap();
+    assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+    assert_eq!(
+        parsed.extended_source_files,
+        ["/dev/configs/base.json", "/dev/configs/first.json"]
+    );
+
+    // The nearest config wins, and paths are relative to the config that sets them.
+    let options = &parsed.options;
+    assert_eq!(option(options, "strict"), Some(&JsonValue::Boolean(true)));
+    assert_eq!(option(options, "target"), Some(&JsonValue::Number(2.0)));
+    assert_eq!(option(options, "module"), Some(&JsonValue::Number(1.0)));
+    assert_eq!(option(options, "allowJs"), Some(&JsonValue::Boolean(true)));
+    assert_eq!(
+        option(options, "outDir"),
+        Some(&JsonValue::String("/dev/configs/out".to_string()))
+    );
+    assert_eq!(
+        option(options, "configFilePath"),
+        Some(&JsonValue::String("/dev/tsconfig.json".to_string()))
+    );
+
+    // `files` is the config's own, and the inherited `include` is rebased to its directory.
+    let specs = &parsed.config_file_specs;
+    assert_eq!(
+        specs.files_specs.as_deref(),
+        Some(&["main.ts".to_string()][..])
+    );
+    assert_eq!(
+        specs.include_specs.as_deref(),
+        Some(&["configs/../src/*".to_string()][..])
+    );
+}
+
+#[test]
+fn reads_each_extended_config_once_with_a_shared_cache() {
+    let host = extends_chain();
+    let mut extended_config_cache = ExtendedConfigCache::new();
+    let parsed = get_parsed_command_line_of_config_file(
+        "/dev/tsconfig.json",
+        &host,
+        "/",
+        &mut extended_config_cache,
+    )
+    .unwrap();
+    let tests = get_parsed_command_line_of_config_file(
+        "/dev/tests/tsconfig.json",
+        &host,
+        "/",
+        &mut extended_config_cache,
+    )
+    .unwrap();
+    assert_eq!(
+        *host.reads.borrow(),
+        [
+            "/dev/tsconfig.json",
+            "/dev/configs/base.json",
+            "/dev/configs/first.json",
+            "/dev/tests/tsconfig.json"
+        ]
+    );
+    assert_eq!(extended_config_cache.len(), 2);
+
+    // The cached configs are merged the same way as freshly read ones.
+    assert_eq!(tests.extended_source_files, parsed.extended_source_files);
+    assert_eq!(
+        option(&tests.options, "target"),
+        Some(&JsonValue::Number(4.0))
+    );
+    assert_eq!(
+        option(&tests.options, "module"),
+        Some(&JsonValue::Number(1.0))
+    );
+    assert_eq!(
+        option(&tests.options, "outDir"),
+        Some(&JsonValue::String("/dev/configs/out".to_string()))
+    );
+    assert_eq!(
+        tests.config_file_specs.include_specs.as_deref(),
+        Some(&["../configs/../src/*".to_string()][..])
+    );
+}
+
+#[test]
+fn reports_circular_extends_chains() {
+    let host = VirtualFileSystem::new(&[
+        (
+            "/dev/circular.json",
+            r#"{ "extends": "./circular2.json", "compilerOptions": { "module": "amd" } }"#,
+        ),
+        ("/dev/circular2.json", r#"{ "extends": "./circular.json" }"#),
+    ]);
+    let parsed = get_parsed_command_line_of_config_file(
+        "circular.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .unwrap();
+    assert_eq!(parsed.errors.len(), 1);
+    let error = &parsed.errors[0];
+    assert_eq!(error.code, 18000);
+    assert_eq!(
+        error.message_text,
+        "Circularity detected while resolving configuration: /dev/circular.json -> /dev/circular2.json -> /dev/circular.json"
+    );
+    assert_eq!(
+        (&error.file_name, error.start, error.length),
+        (&None, None, None)
+    );
+    assert_eq!(
+        option(&parsed.options, "module"),
+        Some(&JsonValue::Number(2.0))
+    );
+}
+
+#[test]
+fn reports_a_config_that_cannot_be_read() {
+    let host = VirtualFileSystem::new(&[]);
+    let error = get_parsed_command_line_of_config_file(
+        "missing.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(error.code, 5083);
+}
+
+#[test]
+fn resolves_a_package_name_in_extends_through_node_modules() {
+    let host = VirtualFileSystem::new(&[
+        (
+            "/dev/app/tsconfig.json",
+            r#"{ "extends": "shared-config", "compilerOptions": { "strict": true } }"#,
+        ),
+        (
+            "/dev/node_modules/shared-config/tsconfig.json",
+            r#"{ "compilerOptions": { "target": "es2015", "outDir": "./dist" } }"#,
+        ),
+    ]);
+    let parsed = get_parsed_command_line_of_config_file(
+        "app/tsconfig.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .unwrap();
+    assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+    assert_eq!(
+        parsed.extended_source_files,
+        ["/dev/node_modules/shared-config/tsconfig.json"]
+    );
+    assert_eq!(
+        option(&parsed.options, "strict"),
+        Some(&JsonValue::Boolean(true))
+    );
+    assert_eq!(
+        option(&parsed.options, "target"),
+        Some(&JsonValue::Number(2.0))
+    );
+    assert_eq!(
+        option(&parsed.options, "outDir"),
+        Some(&JsonValue::String(
+            "/dev/node_modules/shared-config/dist".to_string()
+        ))
+    );
+}
+
+#[test]
+fn resolves_extends_through_the_tsconfig_field_of_package_json() {
+    let host = VirtualFileSystem::new(&[
+        (
+            "/dev/tsconfig.json",
+            r#"{ "extends": "shared-config", "compilerOptions": { "module": "amd" } }"#,
+        ),
+        (
+            "/dev/node_modules/shared-config/package.json",
+            r#"{ "name": "shared-config", "tsconfig": "configs/strict.json" }"#,
+        ),
+        (
+            "/dev/node_modules/shared-config/configs/strict.json",
+            r#"{ "compilerOptions": { "strict": true } }"#,
+        ),
+        // Ignored, the `tsconfig` field takes precedence.
+        (
+            "/dev/node_modules/shared-config/tsconfig.json",
+            r#"{ "compilerOptions": { "strict": false } }"#,
+        ),
+    ]);
+    let parsed = get_parsed_command_line_of_config_file(
+        "tsconfig.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .unwrap();
+    assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+    assert_eq!(
+        parsed.extended_source_files,
+        ["/dev/node_modules/shared-config/configs/strict.json"]
+    );
+    assert_eq!(
+        option(&parsed.options, "strict"),
+        Some(&JsonValue::Boolean(true))
+    );
+    assert_eq!(
+        option(&parsed.options, "module"),
+        Some(&JsonValue::Number(2.0))
+    );
+}
+
+#[test]
+fn keeps_only_the_own_references_of_a_config_that_extends_another() {
+    let host = VirtualFileSystem::new(&[
+        (
+            "/dev/tsconfig.json",
+            r#"{ "extends": "./configs/base.json", "references": [{ "path": "./core", "prepend": true }] }"#,
+        ),
+        (
+            "/dev/leaf/tsconfig.json",
+            r#"{ "extends": "../configs/base.json" }"#,
+        ),
+        (
+            "/dev/configs/base.json",
+            r#"{ "compilerOptions": { "composite": true }, "references": [{ "path": "../shared" }] }"#,
+        ),
+    ]);
+    let mut extended_config_cache = ExtendedConfigCache::new();
+    let parsed = get_parsed_command_line_of_config_file(
+        "tsconfig.json",
+        &host,
+        "/dev",
+        &mut extended_config_cache,
+    )
+    .unwrap();
+    assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+    assert_eq!(
+        option(&parsed.options, "composite"),
+        Some(&JsonValue::Boolean(true))
+    );
+    let references = parsed.project_references.unwrap();
+    assert_eq!(references.len(), 1);
+    assert_eq!(
+        (
+            references[0].path.as_str(),
+            references[0].original_path.as_str()
+        ),
+        ("/dev/core", "./core")
+    );
+    assert_eq!(
+        (references[0].prepend, references[0].circular),
+        (Some(true), None)
+    );
+
+    // References are not inherited, even when the config sets none of its own.
+    let leaf = get_parsed_command_line_of_config_file(
+        "leaf/tsconfig.json",
+        &host,
+        "/dev",
+        &mut extended_config_cache,
+    )
+    .unwrap();
+    assert!(leaf.errors.is_empty(), "{:?}", leaf.errors);
+    assert_eq!(
+        option(&leaf.options, "composite"),
+        Some(&JsonValue::Boolean(true))
+    );
+    assert!(leaf.project_references.is_none());
+}
+
+#[test]
+fn reports_an_extends_target_that_does_not_exist() {
+    let config = r#"{ "extends": "./missing", "compilerOptions": { "strict": true } }"#;
+    let host = VirtualFileSystem::new(&[("/dev/tsconfig.json", config)]);
+    let parsed = get_parsed_command_line_of_config_file(
+        "tsconfig.json",
+        &host,
+        "/dev",
+        &mut ExtendedConfigCache::new(),
+    )
+    .unwrap();
+    assert_eq!(parsed.errors.len(), 1);
+    let error = &parsed.errors[0];
+    assert_eq!(error.code, 6053);
+    assert_eq!(error.message_text, "File './missing' not found.");
+    assert_eq!(
+        (error.file_name.as_deref(), error.start, error.length),
+        (
+            Some("/dev/tsconfig.json"),
+            config.find(r#""./missing""#),
+            Some(r#""./missing""#.len())
+        )
+    );
+    assert!(parsed.extended_source_files.is_empty());
+    assert_eq!(
+        option(&parsed.options, "strict"),
+        Some(&JsonValue::Boolean(true))
+    );
+}
@@ -0,0 +1,218 @@
+// What the baseline tests share with the test runner of the compiler: reading the test cases
+// under `tests/cases`, splitting them into their files like `TestCaseParser.makeUnitsFromTest`
+// does, and comparing results with the baselines of `tests/baselines/reference`.
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use typescript::compiler::path::get_normalized_absolute_path;
+use typescript::compiler::sys::LocalFileSystem;
+use typescript::compiler::types::{ParseConfigHost, ScriptTarget};
+
+pub fn repository_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(path)
+}
+
+/// A file of a test case: the whole case, or the text after one of its `// @filename:` lines.
+pub struct TestUnit {
+    pub name: String,
+    pub content: String,
+}
+
+pub struct TestCase {
+    /// The path of the case from the root of the repository, like `tests/cases/compiler/a.ts`
+    pub path: String,
+    /// The name of the case, which its baselines are named after
+    pub name: String,
+    /// The `// @name: value` settings of the case, like `target`, by their lowercase names
+    pub settings: Vec<(String, String)>,
+    pub units: Vec<TestUnit>,
+}
+
+impl TestCase {
+    pub fn setting(&self, name: &str) -> Option<&str> {
+        self.settings
+            .iter()
+            .find(|(setting, _)| setting == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The name the compiler runner gives a file of the case, which its diagnostics are reported
+    /// with: relative to the directory of the case for conformance tests, and to
+    /// `tests/cases/compiler` for the others.
+    pub fn unit_name(&self, unit: &TestUnit) -> String {
+        let root_directory = if self.path.contains("conformance") {
+            self.path
+                .rsplit_once('/')
+                .map_or("", |(directory, _)| directory)
+        } else {
+            "tests/cases/compiler"
+        };
+        get_normalized_absolute_path(&unit.name, root_directory)
+    }
+
+    /// The script target of the `@target` setting, the first of them if it lists several.
+    pub fn script_target(&self) -> ScriptTarget {
+        let target = self
+            .setting("target")
+            .and_then(|targets| targets.split(',').next())
+            .map(|target| target.trim().to_lowercase());
+        match target.as_deref() {
+            Some("es3") => ScriptTarget::ES3,
+            Some("es5") => ScriptTarget::ES5,
+            Some("es6") | Some("es2015") => ScriptTarget::ES2015,
+            Some("es2016") => ScriptTarget::ES2016,
+            Some("es2017") => ScriptTarget::ES2017,
+            Some("es2018") => ScriptTarget::ES2018,
+            Some("es2019") => ScriptTarget::ES2019,
+            Some("es2020") => ScriptTarget::ES2020,
+            Some("es2021") => ScriptTarget::ES2021,
+            Some("esnext") => ScriptTarget::ESNext,
+            // The default target of the test runner
+            _ => ScriptTarget::ES3,
+        }
+    }
+}
+
+/// The name and value of a `// @name: value` line, like the `optionRegex` of the test runner.
+fn parse_setting(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix("//")?.trim_start().strip_prefix('@')?;
+    let name_length = rest
+        .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+        .unwrap_or(rest.len());
+    if name_length == 0 {
+        return None;
+    }
+    let (name, rest) = rest.split_at(name_length);
+    let value = rest.trim_start().strip_prefix(':')?;
+    Some((name, value.trim()))
+}
+
+/// Splits a test case into its files like `TestCaseParser.makeUnitsFromTest`, joining the lines
+/// of each file with line feeds whatever the line endings of the case are.
+fn make_units_from_test(code: &str, file_name: &str) -> (Vec<TestUnit>, Vec<(String, String)>) {
+    let lines: Vec<&str> = if code.contains("\r\n") {
+        code.split("\r\n").collect()
+    } else if code.contains('\n') {
+        code.split('\n').collect()
+    } else {
+        code.split('\r').collect()
+    };
+    let mut units = Vec::new();
+    let mut settings: Vec<(String, String)> = Vec::new();
+    let mut current_name: Option<String> = None;
+    let mut current_content: Option<String> = None;
+    for line in lines {
+        if let Some((name, value)) = parse_setting(line) {
+            let name = name.to_lowercase();
+            if name != "filename" {
+                if !settings.iter().any(|(setting, _)| *setting == name) {
+                    settings.push((name, value.to_string()));
+                }
+                continue;
+            }
+            if let Some(previous_name) = current_name.take() {
+                units.push(TestUnit {
+                    name: previous_name,
+                    content: current_content.take().unwrap_or_default(),
+                });
+            }
+            current_name = Some(value.to_string());
+        } else {
+            let content = current_content.get_or_insert_with(String::new);
+            if !content.is_empty() {
+                content.push('\n');
+            }
+            content.push_str(line);
+        }
+    }
+    let name = current_name.unwrap_or_else(|| {
+        let base_name = Path::new(file_name).file_name().unwrap_or_default();
+        base_name.to_string_lossy().into_owned()
+    });
+    units.push(TestUnit {
+        name,
+        content: current_content.unwrap_or_default(),
+    });
+    (units, settings)
+}
+
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = fs::read_dir(directory)
+        .unwrap_or_else(|error| {
+            panic!("Cannot read directory '{}': {}", directory.display(), error)
+        })
+        .map(|entry| entry.expect("directory entries can be read").path())
+        .collect();
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            collect_files(&entry, files);
+        } else if matches!(
+            entry.extension().and_then(|extension| extension.to_str()),
+            Some("ts") | Some("tsx")
+        ) {
+            files.push(entry);
+        }
+    }
+}
+
+/// The test cases of a directory of `tests/cases` and its subdirectories, in order of their paths.
+/// Like the compiler runner, only `.ts` and `.tsx` files are test cases.
+pub fn read_test_cases(directory: &str) -> Vec<TestCase> {
+    let mut files = Vec::new();
+    collect_files(&repository_path(directory), &mut files);
+    let root = repository_path("");
+    files
+        .iter()
+        .map(|file| {
+            let path = file
+                .strip_prefix(&root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let code = LocalFileSystem
+                .read_file(&file.to_string_lossy())
+                .unwrap_or_else(|error| panic!("Cannot read file '{}': {}", path, error));
+            let (units, settings) = make_units_from_test(&code, &path);
+            let name = Path::new(&path)
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            TestCase {
+                path,
+                name,
+                settings,
+                units,
+            }
+        })
+        .collect()
+}
+
+/// Compares a baseline with the one of the same name in `tests/baselines/reference`, like
+/// `Baseline.runBaseline` does. A baseline that differs, or has no reference yet, is written to
+/// `tests/baselines/local`, where `gulp diff` compares it with the reference and
+/// `gulp baseline-accept` makes it the reference.
+pub fn check_baseline(file_name: &str, actual: &str) -> Result<(), String> {
+    let reference_path = repository_path("tests/baselines/reference").join(file_name);
+    let local_path = repository_path("tests/baselines/local").join(file_name);
+    let expected = fs::read_to_string(&reference_path).ok();
+    if expected.as_deref() == Some(actual) {
+        // A local baseline of an earlier run that differed would be accepted along with the others.
+        let _ = fs::remove_file(&local_path);
+        return Ok(());
+    }
+    fs::create_dir_all(local_path.parent().expect("baselines are in a directory"))
+        .and_then(|_| fs::write(&local_path, actual))
+        .unwrap_or_else(|error| panic!("Cannot write file '{}': {}", local_path.display(), error));
+    Err(match expected {
+        Some(_) => format!("The baseline file {} has changed.", file_name),
+        None => format!(
+            "New baseline created at tests/baselines/local/{}.",
+            file_name
+        ),
+    })
+}
@@ -0,0 +1,218 @@
+use typescript::compiler::parser::create_source_file;
+use typescript::compiler::types::{ScriptTarget, TextRange};
+use typescript::services::navigation_bar::get_navigation_tree;
+use typescript::services::types::{NavigationTree, ScriptElementKind};
+
+fn navigation_tree(file_name: &str, text: &str) -> NavigationTree {
+    let source_file = create_source_file(file_name, text.to_string(), ScriptTarget::ESNext, None);
+    get_navigation_tree(&source_file, None).unwrap()
+}
+
+// The items of the tree one per line, indented by their depth, with their kind and modifiers the
+// way `verify.navigationTree` spells them.
+fn outline(tree: &NavigationTree) -> String {
+    fn write_item(tree: &NavigationTree, depth: usize, lines: &mut Vec<String>) {
+        let mut line = format!(
+            "{}{} ({}",
+            "  ".repeat(depth),
+            tree.text,
+            tree.kind.js_value()
+        );
+        if !tree.kind_modifiers.is_empty() {
+            let modifiers: Vec<&str> = tree
+                .kind_modifiers
+                .iter()
+                .map(|modifier| modifier.js_value())
+                .collect();
+            line += &format!(", {}", modifiers.join(","));
+        }
+        lines.push(line + ")");
+        for child in &tree.child_items {
+            write_item(child, depth + 1, lines);
+        }
+    }
+    let mut lines = Vec::new();
+    write_item(tree, 0, &mut lines);
+    lines.join("\n")
+}
+
+fn verify_outline(file_name: &str, text: &str, expected: &str) {
+    assert_eq!(
+        outline(&navigation_tree(file_name, text)),
+        expected,
+        "navigation tree of\n{}",
+        text
+    );
+}
+
+#[test]
+fn sorts_named_declarations_after_the_others_by_name() {
+    verify_outline(
+        "test.ts",
+        "\
+function foo() {}
+class Bar { b() {} a = 1; constructor() {} }
+interface baz { (): void; new (): baz; [key: string]: any; }
+enum Color { Red, Green }
+type Alias = string;
+let zed = 0, _private = 1;",
+        "\
+<global> (script)
+  _private (let)
+  Alias (type)
+  Bar (class)
+    constructor (constructor)
+    a (property)
+    b (method)
+  baz (interface)
+    () (call)
+    new() (construct)
+    [] (index)
+  Color (enum)
+    Green (enum member)
+    Red (enum member)
+  foo (function)
+  zed (let)",
+    );
+}
+
+#[test]
+fn reports_modifiers() {
+    verify_outline(
+        "test.ts",
+        "\
+export abstract class C {
+    private static x: number;
+    protected abstract m(): void;
+    public get p() { return 1; }
+}
+declare module \"ambient\" {}
+/** @deprecated */
+export const d = 1;",
+        "\
+\"test\" (module)
+  \"ambient\" (module, declare)
+  C (class, abstract,export)
+    m (method, protected,abstract)
+    p (getter, public)
+    x (property, private,static)
+  d (const, export,deprecated)",
+    );
+}
+
+#[test]
+fn merges_declarations_of_the_same_name() {
+    verify_outline(
+        "test.ts",
+        "\
+namespace A.B { export var x; }
+namespace A.B { export var y; }
+namespace A { export var z; }
+interface I { a: number; }
+interface I { b: number; }
+class K { static m() {} m() {} }",
+        "\
+<global> (script)
+  A (module)
+    z (var, export)
+  A.B (module)
+    x (var, export)
+    y (var, export)
+  I (interface)
+    a (property)
+    b (property)
+  K (class)
+    m (method, static)
+    m (method)",
+    );
+}
+
+#[test]
+fn puts_parameter_properties_next_to_the_constructor() {
+    verify_outline(
+        "test.ts",
+        "class C { constructor(private a: number, b: string, readonly c = 0) { function inner() {} } }",
+        "\
+<global> (script)
+  C (class)
+    constructor (constructor)
+      inner (function)
+    a (property, private)
+    c (property)",
+    );
+}
+
+#[test]
+fn names_functions_after_what_they_are_assigned_or_passed_to() {
+    verify_outline(
+        "test.ts",
+        "\
+const handler = () => { const local = 1; };
+describe(\"suite\", function () {
+    it('works', () => {});
+});
+o.p.q = function () {};
+export default function () {}",
+        "\
+\"test\" (module)
+  default (function, export)
+  describe(\"suite\") callback (function)
+    it('works') callback (function)
+  handler (const)
+    local (const)
+  q (function)",
+    );
+}
+
+#[test]
+fn merges_the_members_of_es5_classes() {
+    verify_outline(
+        "test.js",
+        "\
+function C() { this.x = 1; }
+C.prototype.m = function () {};
+C.s = function () {};
+Object.defineProperty(C.prototype, \"p\", { get() { return 1; } });",
+        "\
+<global> (script)
+  C (class)
+    constructor (constructor)
+    m (function)
+    s (function)
+    p ()
+      get (method)",
+    );
+}
+
+#[test]
+fn reports_the_spans_of_the_items() {
+    let text = "\
+namespace N {
+    export class C {}
+}
+namespace N {}";
+    let tree = navigation_tree("test.ts", text);
+    assert_eq!(
+        tree.spans,
+        [TextRange {
+            pos: 0,
+            end: text.len()
+        }]
+    );
+    assert_eq!(tree.name_span, None);
+
+    let module = &tree.child_items[0];
+    assert_eq!(module.kind, ScriptElementKind::ModuleElement);
+    assert_eq!(
+        module.spans,
+        [
+            TextRange { pos: 0, end: 37 },
+            TextRange { pos: 38, end: 52 }
+        ]
+    );
+    assert_eq!(module.name_span, Some(TextRange { pos: 10, end: 11 }));
+
+    let class = &module.child_items[0];
+    assert_eq!(class.spans, [TextRange { pos: 18, end: 35 }]);
+    assert_eq!(class.name_span, Some(TextRange { pos: 31, end: 32 }));
+}
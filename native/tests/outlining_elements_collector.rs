@@ -0,0 +1,306 @@
+use typescript::compiler::parser::create_source_file;
+use typescript::compiler::types::{ScriptTarget, TextRange};
+use typescript::services::outlining_elements_collector::collect_elements;
+use typescript::services::types::{OutliningSpan, OutliningSpanKind};
+
+// The text without the `[|` and `|]` markers of the fourslash tests and the ranges they mark,
+// sorted by start like `test.ranges()`.
+fn parse_ranges(markup: &str) -> (String, Vec<TextRange>) {
+    let mut text = String::new();
+    let mut open = Vec::new();
+    let mut ranges = Vec::new();
+    let mut rest = markup;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("[|") {
+            open.push(text.encode_utf16().count());
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("|]") {
+            let pos = open.pop().expect("unbalanced range markers");
+            ranges.push(TextRange {
+                pos,
+                end: text.encode_utf16().count(),
+            });
+            rest = after;
+        } else {
+            let c = rest.chars().next().unwrap();
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    assert!(open.is_empty(), "unbalanced range markers");
+    ranges.sort_by_key(|range| (range.pos, std::cmp::Reverse(range.end)));
+    (text, ranges)
+}
+
+fn outlining_spans(file_name: &str, text: &str) -> Vec<OutliningSpan> {
+    let source_file = create_source_file(file_name, text.to_string(), ScriptTarget::ESNext, None);
+    collect_elements(&source_file, None).unwrap()
+}
+
+fn sorted(mut ranges: Vec<TextRange>) -> Vec<TextRange> {
+    ranges.sort_by_key(|range| (range.pos, std::cmp::Reverse(range.end)));
+    ranges
+}
+
+// Checks the text spans of the outlining spans of the kind, or of all kinds, against the ranges
+// of the markup, like `verify.outliningSpansInCurrentFile`.
+fn verify_outlining_spans(file_name: &str, markup: &str, kind: Option<OutliningSpanKind>) {
+    let (text, ranges) = parse_ranges(markup);
+    let spans = outlining_spans(file_name, &text)
+        .into_iter()
+        .filter(|span| kind.is_none() || Some(span.kind) == kind)
+        .map(|span| span.text_span)
+        .collect();
+    assert_eq!(sorted(spans), ranges, "outlining spans of\n{}", text);
+}
+
+// Checks the hint spans of all outlining spans against the ranges of the markup, like
+// `verify.outliningHintSpansInCurrentFile`.
+fn verify_outlining_hint_spans(file_name: &str, markup: &str) {
+    let (text, ranges) = parse_ranges(markup);
+    let spans = outlining_spans(file_name, &text)
+        .into_iter()
+        .map(|span| span.hint_span)
+        .collect();
+    assert_eq!(sorted(spans), ranges, "outlining hint spans of\n{}", text);
+}
+
+#[test]
+fn collects_regions() {
+    verify_outlining_spans(
+        "test.ts",
+        "\
+// region without label
+[|// #region
+
+// #endregion|]
+
+// region without label with trailing spaces
+[|// #region  
+
+// #endregion|]
+
+// region with label
+[|// #region label1
+
+// #endregion|]
+
+// region with extra whitespace in all valid locations
+             [|//              #region          label2    label3
+
+        //        #endregion|]
+
+// No space before directive
+[|//#region label4
+
+//#endregion|]
+
+// Nested regions
+[|// #region outer
+
+[|// #region inner
+
+// #endregion inner|]
+
+// #endregion outer|]
+
+// region delimiters not valid when there is preceding text on line
+ test // #region invalid1
+
+test // #endregion
+
+// region delimiters not valid when in multiline comment
+/*
+// #region invalid2
+*/
+
+/*
+// #endregion
+*/",
+        Some(OutliningSpanKind::Region),
+    );
+}
+
+#[test]
+fn uses_the_label_of_a_region_as_its_banner() {
+    let text = "// #region\n\n// #endregion\n//   #region  label1   label2  \n\n// #endregion\n";
+    let banners: Vec<String> = outlining_spans("test.ts", text)
+        .into_iter()
+        .map(|span| span.banner_text)
+        .collect();
+    assert_eq!(banners, ["#region", "label1   label2"]);
+}
+
+#[test]
+fn collects_import_groups() {
+    verify_outlining_spans(
+        "test.ts",
+        "\
+[|import * as ns from \"mod\";
+
+import d from \"mod\";
+import { a, b, c } from \"mod\";
+
+import r = require(\"mod\");|]
+
+// statement
+var x = 0;
+
+// another set of imports
+[|import * as ns from \"mod\";
+import d from \"mod\";
+import { a, b, c } from \"mod\";
+import r = require(\"mod\");|]",
+        Some(OutliningSpanKind::Imports),
+    );
+}
+
+#[test]
+fn collects_blocks_and_literals() {
+    verify_outlining_spans(
+        "test.ts",
+        "\
+// interface
+interface IFoo[| {
+    getDist(): number;
+}|]
+
+// class members
+class Foo[| {
+    constructor()[| {
+    }|]
+
+    public foo(): number[| {
+        return 0;
+    }|]
+
+    public member = function f()[| {
+
+    }|]
+}|]
+switch(1)[| {
+ case 1:[| break;|]
+}|]
+
+var array =[| [
+    1,
+    2
+]|]
+
+// modules
+module m1[| {
+    module m2[| { }|]
+}|]
+
+// trivia handeling
+class ClassFooWithTrivia[| /*  some comments */
+   /* more trivia */ {
+
+
+    /*some trailing trivia */
+}|] /* even more */",
+        Some(OutliningSpanKind::Code),
+    );
+}
+
+#[test]
+fn collects_jsx_elements_and_attributes() {
+    verify_outlining_spans(
+        "test.tsx",
+        "\
+import React, { Component } from 'react';
+
+export class Home extends Component[| {
+  render()[| {
+    return (
+    [|<div>
+      [|<h1>Hello, world!</h1>|]
+      [|<ul>
+        [|<li>
+          [|<a [|href='https://get.asp.net/'|]>
+            ASP.NET Core
+          </a>|]
+        </li>|]
+        [|<li>[|<a [|href='https://facebook.github.io/react/'|]>React</a>|] for client-side code</li>|]
+      </ul>|]
+      <div
+        [|accesskey=\"test\"
+        class=\"active\"
+        dir=\"auto\"|] />
+      <PageHeader [|title=\"Log in\"
+        {...[|{
+          item: true,
+          xs: 9,
+          md: 5
+        }|]}|]
+      />
+      [|<>
+          text
+      </>|]
+    </div>|]
+    );
+  }|]
+}|]",
+        Some(OutliningSpanKind::Code),
+    );
+}
+
+#[test]
+fn uses_the_tags_of_jsx_elements_as_their_banners() {
+    let text = "const x = <div>\n  <>\n    text\n  </>\n</div>;\n";
+    let banners: Vec<String> = outlining_spans("test.tsx", text)
+        .into_iter()
+        .map(|span| span.banner_text)
+        .collect();
+    assert_eq!(banners, ["<div>...</div>", "<>...</>"]);
+}
+
+#[test]
+fn hints_functions_from_their_declarations() {
+    verify_outlining_hint_spans(
+        "test.ts",
+        "\
+[|namespace NS {
+    [|function f(x: number, y: number) {
+        return x + y;
+    }|]
+
+    [|function g(
+        x: number,
+        y: number,
+    ): number {
+        return x + y;
+    }|]
+}|]",
+    );
+}
+
+#[test]
+fn collapses_functions_but_not_arrow_functions_to_definitions() {
+    let text = "function f() {\n}\nconst g = () => {\n};\n";
+    let auto_collapse: Vec<bool> = outlining_spans("test.ts", text)
+        .into_iter()
+        .map(|span| span.auto_collapse)
+        .collect();
+    assert_eq!(auto_collapse, [true, false]);
+}
+
+#[test]
+fn collects_comments_and_templates() {
+    verify_outlining_spans(
+        "test.ts",
+        "\
+[|/*
+ * a multi-line comment
+ */|]
+[|// consecutive
+// single-line comments|]
+const s = [|`a
+multi-line template`|];
+// a lone single-line comment
+const t = [|`a single-line template`|];
+const u = ``;
+",
+        None,
+    );
+}
@@ -0,0 +1,774 @@
+mod common;
+
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+use common::{read_test_cases, repository_path};
+use typescript::compiler::atom::AtomTable;
+use typescript::compiler::parser::{
+    create_source_file, create_source_file_with_cancellation, for_each_child, parse_files,
+    parse_json_text, update_source_file, walk_children, ParseFileInput, Visitor,
+};
+use typescript::compiler::types::{
+    CancellationToken, CommentDirectiveType, DiagnosticWithLocation, JsonValue, Node, NodeData,
+    NodeFlags, NodeId, OperationCanceled, ScriptTarget, SourceFile, SyntaxKind, TextChangeRange,
+    TextRange,
+};
+use typescript::compiler::utilities::get_script_kind_from_file_name;
+
+fn parse(file_name: &str, text: &str) -> SourceFile {
+    create_source_file(file_name, text.to_string(), ScriptTarget::ESNext, None)
+}
+
+fn children(source_file: &SourceFile, node: NodeId) -> Vec<NodeId> {
+    let mut children = Vec::new();
+    for_each_child(
+        source_file.node(node),
+        &mut |child| {
+            children.push(child);
+            None::<()>
+        },
+        None,
+    );
+    children
+}
+
+fn kinds(source_file: &SourceFile, nodes: &[NodeId]) -> Vec<SyntaxKind> {
+    nodes
+        .iter()
+        .map(|&node| source_file.node(node).kind)
+        .collect()
+}
+
+// The code, start and length of each diagnostic, which is what the JS tests compare.
+fn spans(diagnostics: &[DiagnosticWithLocation]) -> Vec<(u32, usize, usize)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.code, diagnostic.start, diagnostic.length))
+        .collect()
+}
+
+#[test]
+fn json_allows_trailing_commas() {
+    let source_file = parse_json_text("test.json", r#"{ "a": 1, "b": [1, 2,], }"#.to_string());
+    assert!(source_file.parse_diagnostics.is_empty());
+    assert!(source_file.json_diagnostics.is_empty());
+    let Some(JsonValue::Object(properties)) = source_file.value.map(|value| value.value) else {
+        panic!("expected an object");
+    };
+    assert_eq!(
+        properties
+            .iter()
+            .map(|property| property.name.as_str())
+            .collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+    assert!(
+        matches!(&properties[1].initializer.value, JsonValue::Array(elements) if elements.len() == 2)
+    );
+}
+
+#[test]
+fn json_reports_single_quoted_strings_name_first() {
+    let source_file = parse_json_text("test.json", "{ 'a': 'b' }".to_string());
+    assert!(source_file.parse_diagnostics.is_empty());
+    assert_eq!(
+        spans(&source_file.json_diagnostics),
+        [(1327, 2, 3), (1327, 7, 3)]
+    );
+    assert_eq!(
+        source_file.json_diagnostics[0].message_text,
+        "String literal with double quotes expected."
+    );
+}
+
+#[test]
+fn json_reports_values_that_are_not_json() {
+    let source_file = parse_json_text(
+        "test.json",
+        r#"{ "a": x, "b": undefined, "c": -1, "d": `t` }"#.to_string(),
+    );
+    assert!(source_file.parse_diagnostics.is_empty());
+    assert_eq!(
+        spans(&source_file.json_diagnostics),
+        [(1328, 7, 1), (1328, 15, 9), (1328, 40, 3)]
+    );
+
+    // Only the values that are JSON are kept.
+    let Some(JsonValue::Object(properties)) = source_file.value.map(|value| value.value) else {
+        panic!("expected an object");
+    };
+    assert_eq!(properties.len(), 1);
+    assert_eq!(properties[0].initializer.value, JsonValue::Number(-1.0));
+
+    let source_file = parse_json_text("test.json", "[1,,2]".to_string());
+    assert_eq!(spans(&source_file.json_diagnostics), [(1328, 3, 0)]);
+}
+
+#[test]
+fn json_reports_the_modifier_of_a_property() {
+    let source_file = parse_json_text("test.json", r#"{ "a"?: 1 }"#.to_string());
+    assert_eq!(spans(&source_file.json_diagnostics), [(8009, 5, 1)]);
+    assert_eq!(
+        source_file.json_diagnostics[0].message_text,
+        "The '?' modifier can only be used in TypeScript files."
+    );
+}
+
+#[test]
+fn json_reports_the_second_of_multiple_top_level_values() {
+    let source_file = parse_json_text("test.json", "{} []".to_string());
+    assert_eq!(spans(&source_file.parse_diagnostics), [(1012, 3, 1)]);
+    assert!(
+        matches!(source_file.value.map(|value| value.value), Some(JsonValue::Array(values)) if values.len() == 2)
+    );
+
+    let source_file = parse_json_text("test.json", "1 2 3".to_string());
+    assert_eq!(spans(&source_file.parse_diagnostics), [(1012, 2, 1)]);
+}
+
+#[test]
+fn json_reports_missing_commas() {
+    let source_file = parse_json_text("test.json", r#"{ "a": 1 "b": 2 }"#.to_string());
+    assert_eq!(spans(&source_file.parse_diagnostics), [(1005, 9, 3)]);
+    assert_eq!(
+        source_file.parse_diagnostics[0].message_text,
+        "',' expected."
+    );
+}
+
+#[test]
+fn json_without_a_value() {
+    let source_file = parse_json_text("test.json", String::new());
+    assert!(source_file.value.is_none());
+    assert!(source_file.parse_diagnostics.is_empty());
+}
+
+const MODULE_TEXT: &str = r#"/// <reference path="./types.d.ts" />
+// @ts-check
+/** @jsx h */
+import { a as b } from "./a";
+// @ts-expect-error
+const c: number = b["key"];
+declare module "ambient" { export const __proto__: string; }
+export default { "name": 1, 2: c };
+"#;
+
+#[test]
+fn records_the_identifiers_of_a_file() {
+    let source_file = parse("module.ts", MODULE_TEXT);
+    let identifiers: Vec<&str> = source_file.identifiers.iter().map(String::as_str).collect();
+    // Along with the identifiers (the one of the JSDoc tag too), the literals naming a module or
+    // a property, unescaped like the atoms.
+    assert_eq!(
+        identifiers,
+        [
+            "./a",
+            "2",
+            "__proto__",
+            "a",
+            "ambient",
+            "b",
+            "c",
+            "jsx",
+            "key",
+            "name"
+        ]
+    );
+}
+
+#[test]
+fn records_the_pragmas_and_comment_directives_of_a_module() {
+    let source_file = parse("module.ts", MODULE_TEXT);
+    let indicator = source_file
+        .external_module_indicator
+        .map(|node| source_file.node(node).kind);
+    assert_eq!(indicator, Some(SyntaxKind::ImportDeclaration));
+
+    let pragmas: Vec<(&str, TextRange)> = source_file
+        .pragmas
+        .iter()
+        .map(|pragma| (pragma.name.as_str(), pragma.range.range))
+        .collect();
+    assert_eq!(
+        pragmas,
+        [
+            ("reference", TextRange { pos: 0, end: 37 }),
+            ("ts-check", TextRange { pos: 38, end: 50 }),
+            ("jsx", TextRange { pos: 51, end: 64 }),
+        ]
+    );
+    let path = source_file.pragmas[0]
+        .argument("path")
+        .expect("the reference has a path");
+    assert_eq!(
+        (path.value.as_str(), path.range),
+        ("./types.d.ts", Some(TextRange { pos: 21, end: 33 }))
+    );
+    assert!(source_file.pragmas[1].arguments.is_empty());
+    let factory = source_file.pragmas[2]
+        .argument("factory")
+        .expect("the jsx pragma has a factory");
+    assert_eq!((factory.value.as_str(), factory.range), ("h", None));
+
+    let directives = source_file
+        .comment_directives
+        .expect("the file has a `@ts-expect-error` comment");
+    assert_eq!(directives.len(), 1);
+    assert_eq!(directives[0].range, TextRange { pos: 95, end: 114 });
+    assert_eq!(directives[0]._type, CommentDirectiveType::ExpectError);
+}
+
+#[test]
+fn visits_children_in_the_order_of_for_each_child() {
+    let source_file = parse(
+        "test.ts",
+        "@dec export abstract class C<T> extends B implements I { m() {} }",
+    );
+    let class = source_file.statements()[0];
+    assert_eq!(
+        kinds(&source_file, &children(&source_file, class)),
+        [
+            SyntaxKind::Decorator,
+            SyntaxKind::ExportKeyword,
+            SyntaxKind::AbstractKeyword,
+            SyntaxKind::Identifier,
+            SyntaxKind::TypeParameter,
+            SyntaxKind::HeritageClause,
+            SyntaxKind::HeritageClause,
+            SyntaxKind::MethodDeclaration,
+        ]
+    );
+
+    let source_file = parse(
+        "test.ts",
+        "let x: { readonly [K in keyof T]?: T[K] } = c ? a : b;",
+    );
+    let statement = source_file.statements()[0];
+    let declaration_list = children(&source_file, statement)[0];
+    let declaration = children(&source_file, declaration_list)[0];
+    let declaration_children = children(&source_file, declaration);
+    assert_eq!(
+        kinds(&source_file, &declaration_children),
+        [
+            SyntaxKind::Identifier,
+            SyntaxKind::MappedType,
+            SyntaxKind::ConditionalExpression
+        ]
+    );
+    assert_eq!(
+        kinds(
+            &source_file,
+            &children(&source_file, declaration_children[1])
+        ),
+        [
+            SyntaxKind::ReadonlyKeyword,
+            SyntaxKind::TypeParameter,
+            SyntaxKind::QuestionToken,
+            SyntaxKind::IndexedAccessType
+        ]
+    );
+    assert_eq!(
+        kinds(
+            &source_file,
+            &children(&source_file, declaration_children[2])
+        ),
+        [
+            SyntaxKind::Identifier,
+            SyntaxKind::QuestionToken,
+            SyntaxKind::Identifier,
+            SyntaxKind::ColonToken,
+            SyntaxKind::Identifier,
+        ]
+    );
+}
+
+#[test]
+fn passes_arrays_of_children_to_the_nodes_callback() {
+    let source_file = parse("test.ts", "f?.<T, U>(a, b);");
+    let call = children(&source_file, source_file.statements()[0])[0];
+    let mut visits = Vec::new();
+    let mut arrays = Vec::new();
+    for_each_child(
+        source_file.node(call),
+        &mut |child| {
+            visits.push(source_file.node(child).kind);
+            None::<()>
+        },
+        Some(&mut |nodes| {
+            arrays.push(kinds(&source_file, nodes));
+            None
+        }),
+    );
+    assert_eq!(
+        visits,
+        [SyntaxKind::Identifier, SyntaxKind::QuestionDotToken]
+    );
+    assert_eq!(
+        arrays,
+        [
+            vec![SyntaxKind::TypeReference, SyntaxKind::TypeReference],
+            vec![SyntaxKind::Identifier, SyntaxKind::Identifier],
+        ]
+    );
+
+    // The first callback to return a value stops the iteration.
+    let first_identifier = for_each_child(
+        source_file.node(call),
+        &mut |child| (source_file.node(child).kind == SyntaxKind::Identifier).then_some(child),
+        None,
+    );
+    assert_eq!(first_identifier, Some(children(&source_file, call)[0]));
+}
+
+// Records the nodes it visits, and walks into all of them.
+struct PreorderVisitor {
+    nodes: Vec<NodeId>,
+}
+
+impl Visitor for PreorderVisitor {
+    fn visit_node(&mut self, source_file: &SourceFile, node: NodeId) {
+        self.nodes.push(node);
+        walk_children(self, source_file, node);
+    }
+}
+
+fn preorder(source_file: &SourceFile, node: NodeId, nodes: &mut Vec<NodeId>) {
+    for child in children(source_file, node) {
+        nodes.push(child);
+        preorder(source_file, child, nodes);
+    }
+}
+
+#[test]
+fn walk_children_visits_the_children_of_for_each_child() {
+    let source_file = parse(
+        "test.tsx",
+        "import a, { b as c } from 'd';\nfor await (const [x, ...y] of z) { <div {...p}>{x}</div>; }\n",
+    );
+    let mut visitor = PreorderVisitor { nodes: Vec::new() };
+    walk_children(&mut visitor, &source_file, source_file.root);
+    let mut expected = Vec::new();
+    preorder(&source_file, source_file.root, &mut expected);
+    assert_eq!(visitor.nodes, expected);
+    assert_eq!(
+        visitor
+            .nodes
+            .last()
+            .map(|&node| source_file.node(node).kind),
+        Some(SyntaxKind::EndOfFileToken)
+    );
+}
+
+// `forEachChild` visits the children of a node in the order they appear in, so the children a
+// native walk visits should be in order too, each within its parent.
+fn check_children_of(source_file: &SourceFile, node: NodeId, errors: &mut Vec<String>) {
+    let parent = source_file.node(node);
+    let mut end = parent.range.pos;
+    for child in children(source_file, node) {
+        let child_node = source_file.node(child);
+        if child_node.parent != Some(node) {
+            errors.push(format!(
+                "{:?} has another parent than {:?}",
+                child_node.kind, parent.kind
+            ));
+        }
+        if child_node.range.pos < end || child_node.range.end > parent.range.end {
+            errors.push(format!(
+                "{:?} at {:?} is out of order in {:?} at {:?}",
+                child_node.kind, child_node.range, parent.kind, parent.range
+            ));
+        }
+        end = end.max(child_node.range.end);
+        check_children_of(source_file, child, errors);
+    }
+}
+
+#[test]
+fn children_are_visited_in_source_order() {
+    for case in read_test_cases("tests/cases/conformance/parser") {
+        for unit in &case.units {
+            let file_name = case.unit_name(unit);
+            let script_kind = get_script_kind_from_file_name(&file_name);
+            let source_file = create_source_file(
+                &file_name,
+                unit.content.clone(),
+                case.script_target(),
+                Some(script_kind),
+            );
+            let mut errors = Vec::new();
+            check_children_of(&source_file, source_file.root, &mut errors);
+            assert!(errors.is_empty(), "{}: {}", file_name, errors.join("\n"));
+        }
+    }
+}
+
+// The flags an incremental parse caches on the nodes it looks at for reuse.
+const AGGREGATED_CHILD_DATA: u32 =
+    NodeFlags::THIS_NODE_OR_ANY_SUB_NODES_HAS_ERROR | NodeFlags::HAS_AGGREGATED_CHILD_DATA;
+
+// The kind, range and flags of each node of a tree, by depth, in the order of `for_each_child`.
+fn outline(source_file: &SourceFile) -> Vec<(usize, SyntaxKind, usize, usize, u32)> {
+    fn visit(
+        source_file: &SourceFile,
+        node: NodeId,
+        depth: usize,
+        nodes: &mut Vec<(usize, SyntaxKind, usize, usize, u32)>,
+    ) {
+        let Node {
+            kind, range, flags, ..
+        } = source_file.node(node);
+        nodes.push((
+            depth,
+            *kind,
+            range.pos,
+            range.end,
+            flags & !AGGREGATED_CHILD_DATA,
+        ));
+        for child in children(source_file, node) {
+            visit(source_file, child, depth + 1, nodes);
+        }
+    }
+    let mut nodes = Vec::new();
+    visit(source_file, source_file.root, 0, &mut nodes);
+    nodes
+}
+
+// The flags of `import()` and `import.meta` the file node has.
+fn permanent_flags(source_file: &SourceFile) -> u32 {
+    source_file.node(source_file.root).flags & NodeFlags::PERMANENTLY_SET_INCREMENTAL_FLAGS
+}
+
+// Replaces the `old_length` bytes at byte `start` with `insertion`, both incrementally and with a
+// full parse of the new text, and checks that the two parses agree.
+fn check_incremental_update(
+    source_file: SourceFile,
+    start: usize,
+    old_length: usize,
+    insertion: &str,
+) -> SourceFile {
+    let old_text = &source_file.text;
+    let new_text = format!(
+        "{}{}{}",
+        &old_text[..start],
+        insertion,
+        &old_text[start + old_length..]
+    );
+    // The change range is in UTF-16 code units, like the positions of the tree.
+    let utf16_length = |text: &str| text.encode_utf16().count();
+    let pos = utf16_length(&old_text[..start]);
+    let end = pos + utf16_length(&old_text[start..start + old_length]);
+    let text_change_range = TextChangeRange::new(TextRange { pos, end }, utf16_length(insertion));
+    let full = create_source_file(
+        &source_file.file_name,
+        new_text.clone(),
+        source_file.language_version,
+        None,
+    );
+    let old_permanent_flags = permanent_flags(&source_file);
+    let incremental = update_source_file(source_file, new_text, text_change_range);
+    assert_eq!(incremental.text, full.text);
+    // Like in JS, the file keeps the flags of `import()` and `import.meta` it had before the
+    // edit, even when the edit removes what set them.
+    let mut expected = outline(&full);
+    expected[0].4 |= old_permanent_flags;
+    assert_eq!(
+        outline(&incremental),
+        expected,
+        "the trees of\n{}",
+        full.text
+    );
+    assert_eq!(
+        incremental.parse_diagnostics, full.parse_diagnostics,
+        "the diagnostics of\n{}",
+        full.text
+    );
+    assert_eq!(
+        incremental.external_module_indicator.is_some(),
+        full.external_module_indicator.is_some()
+    );
+    assert_eq!(incremental.comment_directives, full.comment_directives);
+    incremental
+}
+
+const INCREMENTAL_TEXT: &str = "\
+import { a } from \"a\";
+class C {
+    public foo1() { }
+    public foo2() {
+        return 1;
+    }
+    public foo3() { }
+}
+// @ts-ignore
+function f(x: number) { return x < 1 ? `${x}` : /re/g.source; }
+";
+
+#[test]
+fn incremental_updates_match_a_full_parse() {
+    let mut source_file = parse("test.ts", INCREMENTAL_TEXT);
+    let body = INCREMENTAL_TEXT.find("return 1").unwrap();
+    // Type a statement into a method, character by character.
+    for (index, ch) in "var x = 1;".char_indices() {
+        source_file = check_incremental_update(source_file, body + index, 0, &ch.to_string());
+    }
+    // Replace a method's name, delete the method, and break and restore a class.
+    let foo1 = source_file.text.find("foo1").unwrap();
+    source_file = check_incremental_update(source_file, foo1, 4, "bar");
+    let foo3 = source_file.text.find("    public foo3").unwrap();
+    source_file = check_incremental_update(source_file, foo3, "    public foo3() { }\n".len(), "");
+    let class = source_file.text.find("class C {").unwrap();
+    source_file = check_incremental_update(source_file, class + "class C ".len(), 1, "");
+    source_file = check_incremental_update(source_file, class + "class C ".len(), 0, "{");
+    // Edits of the regular expression, the template and the comment directive rescan them.
+    let regex = source_file.text.find("/re/").unwrap();
+    source_file = check_incremental_update(source_file, regex + 1, 0, "x");
+    let template = source_file.text.find("${x}").unwrap();
+    source_file = check_incremental_update(source_file, template + 2, 1, "x + 1");
+    let directive = source_file.text.find("@ts-ignore").unwrap();
+    source_file = check_incremental_update(source_file, directive + 1, 9, "ts-expect-error");
+    // Removing the import makes it a script.
+    let source_file =
+        check_incremental_update(source_file, 0, "import { a } from \"a\";\n".len(), "");
+    assert!(source_file.external_module_indicator.is_none());
+}
+
+#[test]
+fn incremental_updates_reuse_the_nodes_the_edit_did_not_touch() {
+    let source_file = parse("test.ts", "let a = 1;\nlet b = 2;\nlet c = 3;\n");
+    let old_statements = source_file.statements().nodes.clone();
+    let start = source_file.text.find('2').unwrap();
+    let source_file = check_incremental_update(source_file, start, 1, "20");
+    let statements = &source_file.statements().nodes;
+    assert_eq!(statements[0], old_statements[0]);
+    assert_ne!(statements[1], old_statements[1]);
+    assert_eq!(statements[2], old_statements[2]);
+    // The reused statement after the edit moved along with its text.
+    assert_eq!(
+        source_file.node(statements[2]).range,
+        TextRange { pos: 22, end: 33 }
+    );
+    assert_eq!(source_file.parent(statements[2]), Some(source_file.root));
+}
+
+#[test]
+fn incremental_updates_keep_the_flags_of_dynamic_imports_and_import_meta() {
+    let text = "const a = import(\"./a\");\nconst b = import.meta.url;\nlet c = 1;\n";
+    let source_file = parse("test.ts", text);
+    let flags =
+        NodeFlags::POSSIBLY_CONTAINS_DYNAMIC_IMPORT | NodeFlags::POSSIBLY_CONTAINS_IMPORT_META;
+    assert_eq!(permanent_flags(&source_file), flags);
+    // The edit only reparses the last statement, and reuses the ones that set the flags.
+    let one = text.find('1').unwrap();
+    let source_file = check_incremental_update(source_file, one, 1, "2");
+    assert_eq!(permanent_flags(&source_file), flags);
+    // Once set, the flags stay set, even when the edit removes the `import()`.
+    let import = source_file.text.find("import(\"./a\")").unwrap();
+    let source_file = check_incremental_update(source_file, import, "import(\"./a\")".len(), "0");
+    assert_eq!(permanent_flags(&source_file), flags);
+}
+
+#[test]
+fn incremental_updates_of_conformance_files_match_a_full_parse() {
+    let directories = [
+        "tests/cases/conformance/parser/ecmascript5/Statements",
+        "tests/cases/conformance/dynamicImport",
+        "tests/cases/conformance/es2019/importMeta",
+        "tests/cases/conformance/scanner",
+    ];
+    for directory in directories {
+        for case in read_test_cases(directory) {
+            for unit in &case.units {
+                let file_name = case.unit_name(unit);
+                if unit.content.is_empty() {
+                    continue;
+                }
+                // Delete a character in the middle of the file, then put it back.
+                let mut source_file = create_source_file(
+                    &file_name,
+                    unit.content.clone(),
+                    case.script_target(),
+                    None,
+                );
+                let middle = (unit.content.len() / 2..)
+                    .find(|&index| unit.content.is_char_boundary(index))
+                    .unwrap();
+                let deleted = unit.content[middle..]
+                    .chars()
+                    .next()
+                    .map(String::from)
+                    .unwrap_or_default();
+                source_file = check_incremental_update(source_file, middle, deleted.len(), "");
+                check_incremental_update(source_file, middle, 0, &deleted);
+            }
+        }
+    }
+}
+
+fn identifier_atom_id(source_file: &SourceFile, name: &str) -> Option<u32> {
+    source_file.nodes.iter().find_map(|node| match &node.data {
+        NodeData::Identifier { text, .. } if text.as_str() == name => Some(text.id()),
+        _ => None,
+    })
+}
+
+#[test]
+fn parse_files_returns_the_files_in_the_order_given() {
+    let inputs = (0..64)
+        .map(|index| ParseFileInput::Text {
+            file_name: format!("file{index}.ts"),
+            text: format!("const value{index} = shared;"),
+        })
+        .collect();
+    let atoms = Arc::new(AtomTable::new());
+    let results = parse_files(inputs, ScriptTarget::ESNext, None, &atoms, None)
+        .expect("nothing cancels the batch");
+    assert_eq!(results.len(), 64);
+    let shared = atoms
+        .lookup("shared")
+        .expect("the files intern their identifiers in the table given");
+    for (index, result) in results.iter().enumerate() {
+        let source_file = result.as_ref().expect("the files are given as text");
+        assert_eq!(source_file.file_name, format!("file{index}.ts"));
+        assert_eq!(source_file.text, format!("const value{index} = shared;"));
+        assert!(Arc::ptr_eq(&source_file.atoms, &atoms));
+        assert_eq!(identifier_atom_id(source_file, "shared"), Some(shared.id()));
+    }
+}
+
+#[test]
+fn parse_files_reports_the_diagnostics_of_each_file() {
+    let existing = repository_path(
+        "tests/cases/conformance/parser/ecmascript5/Statements/parserBlockStatement1.d.ts",
+    );
+    let missing = repository_path("tests/cases/does-not-exist.ts");
+    let inputs = vec![
+        ParseFileInput::Text {
+            file_name: "error.ts".to_string(),
+            text: "let a = ;".to_string(),
+        },
+        ParseFileInput::Path(missing.to_string_lossy().into_owned()),
+        ParseFileInput::Path(existing.to_string_lossy().into_owned()),
+        ParseFileInput::Text {
+            file_name: "clean.ts".to_string(),
+            text: "let a = 1;".to_string(),
+        },
+    ];
+    let atoms = Arc::new(AtomTable::new());
+    let results = parse_files(inputs, ScriptTarget::ESNext, None, &atoms, None)
+        .expect("nothing cancels the batch");
+
+    let error = results[0].as_ref().expect("the file is given as text");
+    assert_eq!(spans(&error.parse_diagnostics), [(1109, 8, 1)]);
+    assert_eq!(
+        error.parse_diagnostics,
+        parse("error.ts", "let a = ;").parse_diagnostics
+    );
+
+    let unreadable = results[1].as_ref().expect_err("the file does not exist");
+    assert_eq!(unreadable.code, 5083);
+    assert_eq!(unreadable.file_name, None);
+    assert!(unreadable.message_text.contains("does-not-exist.ts"));
+
+    let read = results[2].as_ref().expect("the file exists");
+    assert_eq!(read.text, std::fs::read_to_string(&existing).unwrap());
+    assert!(read.file_name.ends_with("/parserBlockStatement1.d.ts"));
+    assert!(read.is_declaration_file);
+
+    let clean = results[3].as_ref().expect("the file is given as text");
+    assert!(clean.parse_diagnostics.is_empty());
+}
+
+// A text that takes the parser far longer to get through than the tests take to cancel it.
+fn large_text() -> String {
+    "function f(a: number) { return a * 2 + g(a, [1, 2, 3]); }\n".repeat(200_000)
+}
+
+// Runs `operation` on another thread and cancels its token once the operation has started.
+fn cancel_while_running<T: Send + 'static>(
+    operation: impl FnOnce(CancellationToken) -> T + Send + 'static,
+) -> T {
+    let cancellation_token = CancellationToken::new();
+    let started = Arc::new(Barrier::new(2));
+    let handle = {
+        let cancellation_token = cancellation_token.clone();
+        let started = started.clone();
+        thread::spawn(move || {
+            started.wait();
+            operation(cancellation_token)
+        })
+    };
+    started.wait();
+    thread::sleep(Duration::from_millis(10));
+    cancellation_token.cancel();
+    handle.join().expect("the operation doesn't panic")
+}
+
+#[test]
+fn parsing_stops_when_the_token_is_canceled() {
+    let result = cancel_while_running(|cancellation_token| {
+        create_source_file_with_cancellation(
+            "large.ts",
+            large_text(),
+            ScriptTarget::ESNext,
+            None,
+            &cancellation_token,
+        )
+    });
+    assert_eq!(result.err(), Some(OperationCanceled));
+}
+
+#[test]
+fn parsing_with_a_token_that_is_never_canceled_completes() {
+    let cancellation_token = CancellationToken::new();
+    let text = INCREMENTAL_TEXT.to_string();
+    let source_file = create_source_file_with_cancellation(
+        "test.ts",
+        text,
+        ScriptTarget::ESNext,
+        None,
+        &cancellation_token,
+    )
+    .expect("the token is not canceled");
+    assert_eq!(
+        outline(&source_file),
+        outline(&parse("test.ts", INCREMENTAL_TEXT))
+    );
+}
+
+#[test]
+fn parsing_with_a_canceled_token_does_not_start() {
+    let cancellation_token = CancellationToken::new();
+    cancellation_token.cancel();
+    let text = "let a;".to_string();
+    let result = create_source_file_with_cancellation(
+        "test.ts",
+        text,
+        ScriptTarget::ESNext,
+        None,
+        &cancellation_token,
+    );
+    assert_eq!(result.err(), Some(OperationCanceled));
+}
+
+#[test]
+fn parse_files_stops_the_whole_batch_when_the_token_is_canceled() {
+    let result = cancel_while_running(|cancellation_token| {
+        let inputs = (0..8)
+            .map(|index| ParseFileInput::Text {
+                file_name: format!("large{index}.ts"),
+                text: large_text(),
+            })
+            .collect();
+        parse_files(
+            inputs,
+            ScriptTarget::ESNext,
+            None,
+            &Arc::new(AtomTable::new()),
+            Some(&cancellation_token),
+        )
+        .map(|results| results.len())
+    });
+    assert_eq!(result, Err(OperationCanceled));
+}
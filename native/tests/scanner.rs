@@ -0,0 +1,187 @@
+use typescript::compiler::scanner::{
+    compute_line_starts, tokenize, Tokenization, NO_TOKEN_VALUE, TOKEN_ENCODING_STRIDE,
+};
+use typescript::compiler::types::{
+    CancellationToken, KeywordSyntaxKind, LanguageVariant, LiteralSyntaxKind,
+    PunctuationSyntaxKind, ScriptTarget, SyntaxKind, TokenSyntaxKind, TriviaSyntaxKind,
+};
+
+fn tokenize_text(text: &str, skip_trivia: bool) -> Tokenization {
+    tokenize_for_target(text, ScriptTarget::ESNext, skip_trivia)
+}
+
+fn tokenize_for_target(
+    text: &str,
+    language_version: ScriptTarget,
+    skip_trivia: bool,
+) -> Tokenization {
+    tokenize(
+        text.to_string(),
+        language_version,
+        LanguageVariant::Standard,
+        skip_trivia,
+        None,
+    )
+    .expect("tokenizing without a cancellation token can't be canceled")
+}
+
+fn kinds(tokenization: &Tokenization) -> Vec<SyntaxKind> {
+    tokenization.tokens.iter().map(|token| token.kind).collect()
+}
+
+#[test]
+fn line_starts_follow_line_breaks() {
+    assert_eq!(compute_line_starts(""), vec![0]);
+    assert_eq!(compute_line_starts("a\nb\n"), vec![0, 2, 4]);
+    assert_eq!(compute_line_starts("a\rb\r\nc"), vec![0, 2, 5]);
+    assert_eq!(compute_line_starts("a\u{2028}b\u{2029}c"), vec![0, 2, 4]);
+}
+
+#[test]
+fn line_starts_count_utf16_code_units() {
+    assert_eq!(compute_line_starts("é\nü\n"), vec![0, 2, 4]);
+    assert_eq!(compute_line_starts("𝒳\na"), vec![0, 3]);
+}
+
+#[test]
+fn tokens_have_their_trivia_and_values() {
+    let tokenization = tokenize_text("var x = 1;", true);
+    assert_eq!(
+        kinds(&tokenization),
+        vec![
+            SyntaxKind::Keywords(KeywordSyntaxKind::VarKeyword),
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier),
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::EqualsToken),
+            SyntaxKind::Literals(LiteralSyntaxKind::NumericLiteral),
+            SyntaxKind::Punctuations(PunctuationSyntaxKind::SemicolonToken),
+            SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+        ]
+    );
+    let identifier = &tokenization.tokens[1];
+    assert_eq!(
+        (identifier.pos, identifier.start, identifier.end),
+        (3, 4, 5)
+    );
+    assert_eq!(identifier.value.as_deref(), Some("x"));
+    assert_eq!(tokenization.tokens[2].value, None);
+    assert!(tokenization.diagnostics.is_empty());
+}
+
+#[test]
+fn trivia_is_only_reported_when_asked_for() {
+    let tokenization = tokenize_text("a\n b", false);
+    assert_eq!(
+        kinds(&tokenization),
+        vec![
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier),
+            SyntaxKind::Trivias(TriviaSyntaxKind::NewLineTrivia),
+            SyntaxKind::Trivias(TriviaSyntaxKind::WhitespaceTrivia),
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier),
+            SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+        ]
+    );
+    assert_eq!(tokenize_text("a\n b", true).tokens.len(), 3);
+}
+
+#[test]
+fn scan_errors_are_reported_as_diagnostics() {
+    let tokenization = tokenize_text("'abc", true);
+    assert_eq!(tokenization.diagnostics.len(), 1);
+    assert_eq!(tokenization.diagnostics[0].code, 1002);
+    assert_eq!(tokenization.diagnostics[0].start, Some(4));
+}
+
+#[test]
+fn canceled_tokenizing_stops() {
+    let cancellation_token = CancellationToken::new();
+    cancellation_token.cancel();
+    let result = tokenize(
+        "a b c".to_string(),
+        ScriptTarget::ESNext,
+        LanguageVariant::Standard,
+        true,
+        Some(&cancellation_token),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn encoded_tokens_index_their_values() {
+    let tokenization = tokenize_text("a + b", true);
+    let (encoded, values) = tokenization.encode();
+    assert_eq!(
+        encoded.len(),
+        tokenization.tokens.len() * TOKEN_ENCODING_STRIDE
+    );
+    assert_eq!(values, vec!["a", "b"]);
+    let value_indices: Vec<u32> = encoded
+        .chunks(TOKEN_ENCODING_STRIDE)
+        .map(|token| token[TOKEN_ENCODING_STRIDE - 1])
+        .collect();
+    assert_eq!(value_indices, vec![0, NO_TOKEN_VALUE, 1, NO_TOKEN_VALUE]);
+    assert_eq!(
+        &encoded[..5],
+        &[tokenization.tokens[0].kind.js_value(), 0, 0, 1, 0]
+    );
+}
+
+// The kinds of the tokens of the text for the target, and the codes of its diagnostics.
+fn scan_for_target(text: &str, language_version: ScriptTarget) -> (Vec<SyntaxKind>, Vec<u32>) {
+    let tokenization = tokenize_for_target(text, language_version, true);
+    let codes = tokenization
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect();
+    (kinds(&tokenization), codes)
+}
+
+#[test]
+fn identifier_characters_follow_the_unicode_tables_of_the_target() {
+    let identifier = (
+        vec![
+            SyntaxKind::Tokens(TokenSyntaxKind::Identifier),
+            SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+        ],
+        vec![],
+    );
+    let invalid_character = (
+        vec![
+            SyntaxKind::Tokens(TokenSyntaxKind::Unknown),
+            SyntaxKind::Tokens(TokenSyntaxKind::EndOfFileToken),
+        ],
+        vec![1127],
+    );
+    // U+0220 is a letter from Unicode 3.0 on, which the ES5 table has and the ES3 one doesn't.
+    assert_eq!(
+        scan_for_target("\u{220}", ScriptTarget::ES3),
+        invalid_character
+    );
+    assert_eq!(scan_for_target("\u{220}", ScriptTarget::ES5), identifier);
+    // U+037F is a letter from Unicode 7.0 on, which only the ESNext table has.
+    assert_eq!(
+        scan_for_target("\u{37f}", ScriptTarget::ES5),
+        invalid_character
+    );
+    assert_eq!(scan_for_target("\u{37f}", ScriptTarget::ES2015), identifier);
+    assert_eq!(scan_for_target("\u{37f}", ScriptTarget::ESNext), identifier);
+}
+
+#[test]
+fn extended_unicode_escapes_in_identifiers_need_es2015() {
+    let escaped = tokenize_for_target("\\u{61}bc", ScriptTarget::ES2015, true);
+    assert_eq!(
+        escaped.tokens[0].kind,
+        SyntaxKind::Tokens(TokenSyntaxKind::Identifier)
+    );
+    assert_eq!(escaped.tokens[0].value.as_deref(), Some("abc"));
+    assert!(escaped.diagnostics.is_empty());
+    // Below ES2015, the backslash starts no escape and the rest scans on its own.
+    let (kinds, codes) = scan_for_target("\\u{61}bc", ScriptTarget::ES5);
+    assert_eq!(kinds[0], SyntaxKind::Tokens(TokenSyntaxKind::Unknown));
+    assert_eq!(codes, vec![1127]);
+    // `\u` escapes of four digits are identifiers for every target.
+    let (kinds, codes) = scan_for_target("\\u0061bc", ScriptTarget::ES3);
+    assert_eq!(kinds[0], SyntaxKind::Tokens(TokenSyntaxKind::Identifier));
+    assert!(codes.is_empty());
+}
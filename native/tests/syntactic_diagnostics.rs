@@ -0,0 +1,446 @@
+// Parses the files of the test cases under `PARITY_CASES` with the native parser, and compares
+// the syntactic diagnostics it reports with the ones the `.errors.txt` baselines of the cases
+// record: their code, start, length and message. The comparison is written as the
+// `nativeSyntacticDiagnostics.txt` baseline, a summary of how many cases and diagnostics match
+// followed by the differences in each case that doesn't, so that changes to the parity show up
+// in review. Run it with `npm run addon:test:diagnostics`, and accept a new report with
+// `gulp baseline-accept`.
+//
+// A baseline records the errors of the whole program, semantic ones included. The diagnostics
+// that count are the ones with a code the JS parser or scanner reports. The few codes the
+// checker or the binder report too only count where the native parser reports them as well,
+// so a missing one of those goes unnoticed.
+
+mod common;
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+use common::{check_baseline, read_test_cases, repository_path, TestCase};
+use typescript::compiler::parser::create_source_file;
+use typescript::compiler::scanner::compute_line_starts;
+use typescript::compiler::types::{DiagnosticWithLocation, ScriptKind};
+use typescript::compiler::utilities::get_script_kind_from_file_name;
+
+const PARITY_CASES: &[&str] = &[
+    "tests/cases/conformance/parser",
+    "tests/cases/conformance/scanner",
+];
+
+const REPORT_NAME: &str = "nativeSyntacticDiagnostics.txt";
+
+// The names of the `Diagnostics` members the JS files refer to.
+fn read_diagnostic_names(files: &[&str]) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for file in files {
+        let path = repository_path(file);
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Cannot read file '{}': {}", path.display(), error));
+        for (index, _) in text.match_indices("Diagnostics.") {
+            let name: String = text[index + "Diagnostics.".len()..]
+                .chars()
+                .take_while(|&ch| ch.is_ascii_alphanumeric() || ch == '_')
+                .collect();
+            names.insert(name);
+        }
+    }
+    names
+}
+
+// The codes of the diagnostics reported by the JS parser and scanner: the ones only they report,
+// and the ones the checker, the binder or the program report too.
+fn read_syntactic_codes() -> (HashSet<u32>, HashSet<u32>) {
+    let syntactic = read_diagnostic_names(&["src/compiler/parser.ts", "src/compiler/scanner.ts"]);
+    let semantic = read_diagnostic_names(&[
+        "src/compiler/checker.ts",
+        "src/compiler/binder.ts",
+        "src/compiler/program.ts",
+    ]);
+    // The generated map has a declaration like
+    // `pub const Name: IDiagnosticMessage<'static> = diag(1002, ...` for each message, split over
+    // several lines when it is long.
+    let map_path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/compiler/diagnostic_information_map.rs");
+    let map = fs::read_to_string(&map_path)
+        .unwrap_or_else(|error| panic!("Cannot read file '{}': {}", map_path.display(), error));
+    let mut syntactic_codes = HashSet::new();
+    let mut shared_codes = HashSet::new();
+    for declaration in map.split("pub const ").skip(1) {
+        let declaration = declaration.find(':').and_then(|colon| {
+            let (name, rest) = declaration.split_at(colon);
+            let code = rest
+                .split("diag(")
+                .nth(1)?
+                .split(',')
+                .next()?
+                .trim()
+                .parse::<u32>()
+                .ok()?;
+            Some((name, code))
+        });
+        if let Some((name, code)) = declaration {
+            if syntactic.contains(name) {
+                if semantic.contains(name) {
+                    shared_codes.insert(code);
+                } else {
+                    syntactic_codes.insert(code);
+                }
+            }
+        }
+    }
+    (syntactic_codes, shared_codes)
+}
+
+struct ExpectedDiagnostic {
+    file_name: String,
+    // The one-based line and character of the start, like the baseline has them
+    line: usize,
+    character: usize,
+    code: u32,
+    message_text: String,
+    // The squiggle under the line the diagnostic ends on, which is all that is left of its length
+    squiggle: Option<String>,
+}
+
+// A line of the summary of a baseline, like `a.ts(1,15): error TS1005: ';' expected.`
+fn parse_summary_line(line: &str) -> Option<ExpectedDiagnostic> {
+    let location_end = line.find("): ")?;
+    let location_start = line[..location_end].rfind('(')?;
+    let mut position = line[location_start + 1..location_end].split(',');
+    let line_number = position.next()?.parse().ok()?;
+    let character = position.next()?.parse().ok()?;
+    let rest = &line[location_end + "): ".len()..];
+    let rest = &rest[rest.find(" TS")? + " TS".len()..];
+    let code_end = rest.find(": ")?;
+    Some(ExpectedDiagnostic {
+        file_name: line[..location_start].to_string(),
+        line: line_number,
+        character,
+        code: rest[..code_end].parse().ok()?,
+        message_text: rest[code_end + ": ".len()..].to_string(),
+        squiggle: None,
+    })
+}
+
+// The diagnostics of an `.errors.txt` baseline. Its summary gives their location, code and
+// message, and the squiggles of the files after it their length: the squiggle under the last
+// line of a diagnostic comes right before its `!!! error` lines.
+fn parse_error_baseline(baseline: &str) -> Vec<ExpectedDiagnostic> {
+    let lines: Vec<&str> = baseline.split("\r\n").collect();
+    let mut diagnostics: Vec<ExpectedDiagnostic> = Vec::new();
+    let mut index = 0;
+    while index < lines.len()
+        && !lines[index].starts_with("==== ")
+        && !lines[index].starts_with("!!! ")
+    {
+        match parse_summary_line(lines[index]) {
+            Some(diagnostic) => diagnostics.push(diagnostic),
+            // The rest of a message of several lines
+            None if !lines[index].is_empty() => {
+                if let Some(diagnostic) = diagnostics.last_mut() {
+                    diagnostic.message_text.push('\n');
+                    diagnostic.message_text.push_str(lines[index]);
+                }
+            }
+            None => {}
+        }
+        index += 1;
+    }
+    let mut file_name = "";
+    for index in index..lines.len() {
+        let line = lines[index];
+        if let Some(header) = line.strip_prefix("==== ") {
+            file_name = header
+                .rsplit_once(" (")
+                .map_or(header, |(file_name, _)| file_name);
+            continue;
+        }
+        if index == 0 || lines[index - 1].starts_with("!!! ") || file_name.is_empty() {
+            continue;
+        }
+        let message = match line
+            .strip_prefix("!!! ")
+            .and_then(|rest| rest.split_once(" TS"))
+        {
+            Some((_, message)) => message,
+            None => continue,
+        };
+        let (code, message_text) = match message.split_once(": ") {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let diagnostic = diagnostics.iter_mut().find(|diagnostic| {
+            diagnostic.squiggle.is_none()
+                && diagnostic.file_name == file_name
+                && diagnostic.code.to_string() == code
+                && diagnostic.message_text.lines().next() == Some(message_text)
+        });
+        if let Some(diagnostic) = diagnostic {
+            diagnostic.squiggle = Some(lines[index - 1].to_string());
+        }
+    }
+    diagnostics
+}
+
+// Whether the unit is in `\s` of a JS regular expression, which squiggles keep.
+fn is_js_whitespace(ch: u16) -> bool {
+    matches!(
+        ch,
+        0x09..=0x0D | 0x20 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x2028 | 0x2029 | 0x202F | 0x205F | 0x3000 | 0xFEFF
+    )
+}
+
+// The squiggle the test runner writes under the last line of a diagnostic, like
+// `Compiler.iterateErrorBaseline` does, quirks included.
+fn ending_squiggle(
+    text: &[u16],
+    line_starts: &[usize],
+    start: usize,
+    length: usize,
+) -> Option<String> {
+    let mut lines: Vec<&[u16]> = text.split(|&ch| ch == u16::from(b'\n')).collect();
+    if lines.len() == 1 {
+        lines = text.split(|&ch| ch == u16::from(b'\r')).collect();
+    }
+    let end = start + length;
+    for (line_index, &line) in lines.iter().enumerate() {
+        let line = line.strip_suffix(&[u16::from(b'\r')]).unwrap_or(line);
+        let this_line_start = line_starts[line_index];
+        let is_last_line = line_index == lines.len() - 1;
+        let next_line_start = if is_last_line {
+            text.len()
+        } else {
+            line_starts[line_index + 1]
+        };
+        if end < this_line_start || start >= next_line_start && !is_last_line {
+            continue;
+        }
+        let squiggle_start = start.saturating_sub(this_line_start);
+        let length_on_line = length - this_line_start.saturating_sub(start);
+        let prefix: Vec<u16> = line[..squiggle_start.min(line.len())]
+            .iter()
+            .map(|&ch| {
+                if is_js_whitespace(ch) {
+                    ch
+                } else {
+                    u16::from(b' ')
+                }
+            })
+            .collect();
+        let tildes = length_on_line.min(line.len().saturating_sub(squiggle_start));
+        if is_last_line || next_line_start > end {
+            return Some(format!(
+                "    {}{}",
+                String::from_utf16_lossy(&prefix),
+                "~".repeat(tildes)
+            ));
+        }
+    }
+    None
+}
+
+struct Unit {
+    name: String,
+    text: Vec<u16>,
+    line_starts: Vec<usize>,
+}
+
+impl Unit {
+    fn position(&self, line: usize, character: usize) -> Option<usize> {
+        Some(self.line_starts.get(line.checked_sub(1)?)? + character.checked_sub(1)?)
+    }
+
+    fn line_and_character(&self, position: usize) -> (usize, usize) {
+        let line = self
+            .line_starts
+            .partition_point(|&line_start| line_start <= position)
+            - 1;
+        (line + 1, position - self.line_starts[line] + 1)
+    }
+}
+
+#[derive(Default)]
+struct Comparison {
+    matching: usize,
+    differences: Vec<String>,
+}
+
+fn format_location(unit: &Unit, start: usize, code: u32) -> String {
+    let (line, character) = unit.line_and_character(start);
+    format!("{}({},{}): error TS{}", unit.name, line, character, code)
+}
+
+fn compare_test_case(
+    test_case: &TestCase,
+    syntactic_codes: &HashSet<u32>,
+    shared_codes: &HashSet<u32>,
+) -> Comparison {
+    let mut comparison = Comparison::default();
+    let allow_js = test_case
+        .setting("allowjs")
+        .map(str::to_lowercase)
+        .as_deref()
+        == Some("true");
+    let mut units = Vec::new();
+    let mut actual: Vec<Option<DiagnosticWithLocation>> = Vec::new();
+    for unit in &test_case.units {
+        let parsed = match get_script_kind_from_file_name(&unit.name) {
+            ScriptKind::TS | ScriptKind::TSX => true,
+            ScriptKind::JS | ScriptKind::JSX => allow_js,
+            _ => false,
+        };
+        if !parsed {
+            continue;
+        }
+        let name = test_case.unit_name(unit);
+        let content = unit.content.clone();
+        let language_version = test_case.script_target();
+        let parse =
+            panic::catch_unwind(|| create_source_file(&name, content, language_version, None));
+        match parse {
+            Ok(source_file) => actual.extend(source_file.parse_diagnostics.into_iter().map(Some)),
+            Err(_) => comparison.differences.push(format!("panicked: {}", name)),
+        }
+        units.push(Unit {
+            name,
+            text: unit.content.encode_utf16().collect(),
+            line_starts: compute_line_starts(&unit.content),
+        });
+    }
+
+    let baseline_path =
+        repository_path("tests/baselines/reference").join(format!("{}.errors.txt", test_case.name));
+    let expected = fs::read_to_string(&baseline_path)
+        .map(|baseline| parse_error_baseline(&baseline))
+        .unwrap_or_default();
+    for expected in expected {
+        if !syntactic_codes.contains(&expected.code) && !shared_codes.contains(&expected.code) {
+            continue;
+        }
+        let unit = match units.iter().find(|unit| unit.name == expected.file_name) {
+            Some(unit) => unit,
+            None => continue,
+        };
+        let start = match unit.position(expected.line, expected.character) {
+            Some(start) => start,
+            None => continue,
+        };
+        let index = actual.iter().position(|diagnostic| {
+            matches!(diagnostic, Some(diagnostic) if diagnostic.file_name == unit.name
+                && diagnostic.start == start
+                && diagnostic.code == expected.code)
+        });
+        let diagnostic = match index.and_then(|index| actual[index].take()) {
+            Some(diagnostic) => diagnostic,
+            None if shared_codes.contains(&expected.code) => continue,
+            None => {
+                comparison.differences.push(format!(
+                    "missing:    {}: {}",
+                    format_location(unit, start, expected.code),
+                    expected.message_text
+                ));
+                continue;
+            }
+        };
+        let squiggle = ending_squiggle(
+            &unit.text,
+            &unit.line_starts,
+            diagnostic.start,
+            diagnostic.length,
+        );
+        if diagnostic.message_text == expected.message_text && squiggle == expected.squiggle {
+            comparison.matching += 1;
+            continue;
+        }
+        let mut difference = format!(
+            "different:  {}",
+            format_location(unit, start, expected.code)
+        );
+        for (source, message_text, squiggle) in &[
+            ("expected:", &expected.message_text, &expected.squiggle),
+            ("native:  ", &diagnostic.message_text, &squiggle),
+        ] {
+            write!(difference, "\n    {} {}", source, message_text).unwrap();
+            write!(
+                difference,
+                "\n    {}",
+                squiggle.as_deref().unwrap_or("    (no squiggle)")
+            )
+            .unwrap();
+        }
+        comparison.differences.push(difference);
+    }
+    for diagnostic in actual.into_iter().flatten() {
+        let unit = units
+            .iter()
+            .find(|unit| unit.name == diagnostic.file_name)
+            .expect("diagnostics are in the units");
+        comparison.differences.push(format!(
+            "unexpected: {}: {}",
+            format_location(unit, diagnostic.start, diagnostic.code),
+            diagnostic.message_text
+        ));
+    }
+    comparison
+}
+
+fn percentage(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        100.0
+    } else {
+        part as f64 * 100.0 / whole as f64
+    }
+}
+
+#[test]
+fn syntactic_diagnostics_parity() {
+    let (syntactic_codes, shared_codes) = read_syntactic_codes();
+    let mut cases = 0;
+    let mut matching_cases = 0;
+    let mut matching_diagnostics = 0;
+    let mut differences = 0;
+    let mut details = String::new();
+    for directory in PARITY_CASES {
+        for test_case in read_test_cases(directory) {
+            let comparison = compare_test_case(&test_case, &syntactic_codes, &shared_codes);
+            cases += 1;
+            matching_diagnostics += comparison.matching;
+            differences += comparison.differences.len();
+            if comparison.differences.is_empty() {
+                matching_cases += 1;
+                continue;
+            }
+            writeln!(details, "\n==== {} ====", test_case.path).unwrap();
+            for difference in &comparison.differences {
+                writeln!(details, "{}", difference).unwrap();
+            }
+        }
+    }
+    let mut report = format!(
+        "Syntactic diagnostics of the native parser against the .errors.txt baselines of {}.\n\n",
+        PARITY_CASES.join(" and ")
+    );
+    writeln!(
+        report,
+        "Cases: {} of {} match ({:.1}%)",
+        matching_cases,
+        cases,
+        percentage(matching_cases, cases)
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "Diagnostics: {} match, {} differ ({:.1}% match)",
+        matching_diagnostics,
+        differences,
+        percentage(matching_diagnostics, matching_diagnostics + differences)
+    )
+    .unwrap();
+    report.push_str(&details);
+    if let Err(message) = check_baseline(REPORT_NAME, &report) {
+        panic!("{}", message);
+    }
+}
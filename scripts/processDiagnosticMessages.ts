@@ -1,5 +1,6 @@
 import path = require("path");
 import fs = require("fs");
+import childProcess = require("child_process");
 
 interface DiagnosticDetails {
     category: string;
@@ -59,6 +60,16 @@ function main(): void {
 
     const messageOutput = buildDiagnosticMessageOutput(diagnosticMessages);
     writeFile("diagnosticMessages.generated.json", messageOutput);
+
+    const nativeInfoFileOutput = buildNativeInfoFileOutput(
+        diagnosticMessages,
+        "../../../" + path.relative(process.cwd(), inputFilePath).replace(/\\/g, "/"),
+        "scripts/processDiagnosticMessages.ts"
+    );
+    const nativeInfoFilePath = path.join(outputFilesDir, "../../native/src/compiler/diagnostic_information_map.rs");
+    fs.writeFileSync(nativeInfoFilePath, nativeInfoFileOutput, { encoding: "utf-8" });
+    // Leave the map the way `cargo fmt` would, so formatting the crate doesn't change it.
+    childProcess.execFileSync("rustfmt", ["--edition", "2018", nativeInfoFilePath]);
 }
 
 function checkForUniqueCodes(diagnosticTable: InputDiagnosticMessageTable) {
@@ -133,6 +144,73 @@ function buildInfoFileOutput(
     return result;
 }
 
+function buildNativeInfoFileOutput(
+    messageTable: InputDiagnosticMessageTable,
+    inputFilePathRel: string,
+    thisFilePathRel: string
+): string {
+    let result =
+        "// <auto-generated />\n" +
+        "// generated from '" +
+        inputFilePathRel +
+        "' by '" +
+        thisFilePathRel +
+        "'\n" +
+        "#![allow(non_snake_case, non_upper_case_globals)]\n" +
+        "\n" +
+        "use std::borrow::Cow;\n" +
+        "\n" +
+        "use super::types::{DiagnosticCategory, IDiagnosticMessage};\n" +
+        "\n" +
+        "const fn diag(\n" +
+        "    code: u32,\n" +
+        "    category: DiagnosticCategory,\n" +
+        "    key: &'static str,\n" +
+        "    message: &'static str,\n" +
+        "    reports_unnecessary: bool,\n" +
+        "    elided_in_compatibility_pyramid: bool,\n" +
+        "    reports_deprecated: bool,\n" +
+        ") -> IDiagnosticMessage<'static> {\n" +
+        "    IDiagnosticMessage {\n" +
+        "        key,\n" +
+        "        category,\n" +
+        "        code,\n" +
+        "        message: Cow::Borrowed(message),\n" +
+        "        reports_unnecessary,\n" +
+        "        reports_deprecated,\n" +
+        "        elided_in_compatibility_pyramid,\n" +
+        "    }\n" +
+        "}\n" +
+        "\n" +
+        "pub mod Diagnostics {\n" +
+        "    use super::*;\n" +
+        "\n";
+    messageTable.forEach(
+        (
+            {
+                code,
+                category,
+                reportsUnnecessary,
+                elidedInCompatibilityPyramid,
+                reportsDeprecated,
+            },
+            name
+        ) => {
+            const propName = convertPropertyName(name);
+            result += `    pub const ${propName}: IDiagnosticMessage<'static> = diag(${code}, DiagnosticCategory::${category}, "${createKey(
+                propName,
+                code
+            )}", ${JSON.stringify(
+                name
+            )}, ${!!reportsUnnecessary}, ${!!elidedInCompatibilityPyramid}, ${!!reportsDeprecated});\n`;
+        }
+    );
+
+    result += "}\n";
+
+    return result;
+}
+
 function buildDiagnosticMessageOutput(
     messageTable: InputDiagnosticMessageTable
 ): string {
@@ -701,21 +701,351 @@ namespace ts {
         },
     });
 
-    function loadNativeAddon(filename = "index") {
+    /**
+     * Why the compiler uses the JS implementations of every feature rather than the native
+     * addon, when it couldn't load one: the platform has no addon, none was built next to the
+     * compiler, or each addon for the platform failed to load.
+     */
+    export let nativeAddonLoadError: string | undefined;
+
+    // `npm run addon:build` names the addon after the `name` of native/napi.config.json and the
+    // triple, like `typescript.linux-x64-gnu.node`.
+    function loadNativeAddon(filename = "typescript"): Partial<AddonExports> {
         const platformName = platform();
         const archName = arch();
-        const triples = platformArchTriples[platformName][archName];
+        const triples: readonly { platformArchABI: string }[] = platformArchTriples[platformName]?.[archName] || emptyArray;
+        if (!triples.length) {
+            nativeAddonLoadError = `There is no native addon for ${platformName}-${archName}.`;
+            return {};
+        }
+        const errors: string[] = [];
         for (const triple of triples) {
             // resolve in node_modules
-            const localFilePath = `./${filename}.${triple.platformArchABI}.node`;
-            return require(localFilePath);
+            const localFilePath = join(__dirname, `${filename}.${triple.platformArchABI}.node`);
+            if (!existsSync(localFilePath)) {
+                errors.push(`${localFilePath} does not exist.`);
+                continue;
+            }
+            try {
+                return require(localFilePath);
+            }
+            catch (e) {
+                // An addon that fails to load, like one linked against another libc of the
+                // platform, is skipped for the next one.
+                errors.push(`${localFilePath} failed to load: ${e instanceof Error ? e.message : e}`);
+            }
         }
+        // Without an addon that loads on the platform, every feature uses its JS implementation.
+        nativeAddonLoadError = errors.join("\n");
+        return {};
+    }
+
+    export interface NativeDiagnostic {
+        fileName?: string;
+        start?: number;
+        length?: number;
+        messageText: string;
+        category: DiagnosticCategory;
+        code: number;
+    }
+
+    // Positions are UTF-16 offsets; `pos` includes leading trivia like `Node.pos` does.
+    export type NativeJsonNode = TextRange & (
+        | { kind: "object"; properties: NativeJsonProperty[] }
+        | { kind: "array"; elements: NativeJsonNode[] }
+        | { kind: "string"; value: string }
+        | { kind: "number"; value: number }
+        | { kind: "boolean"; value: boolean }
+        | { kind: "null"; value: null }
+    );
+
+    export interface NativeJsonProperty extends TextRange {
+        name: string;
+        namePos: number;
+        nameEnd: number;
+        initializer: NativeJsonNode;
+    }
+
+    export interface NativeJsonSourceFile {
+        fileName: string;
+        value: NativeJsonNode | undefined;
+        parseDiagnostics: NativeDiagnostic[];
+        /** Errors for text that parses but is not JSON, as reported by `convertToObject` */
+        jsonDiagnostics: NativeDiagnostic[];
+    }
+
+    /** A summary of a file parsed by the native parser; the tree itself stays native. */
+    export interface NativeSourceFile {
+        fileName: string;
+        scriptKind: ScriptKind;
+        isDeclarationFile: boolean;
+        isExternalModule: boolean;
+        hasNoDefaultLib: boolean;
+        nodeCount: number;
+        identifierCount: number;
+        statementCount: number;
+        referencedFiles: FileReference[];
+        typeReferenceDirectives: FileReference[];
+        libReferenceDirectives: FileReference[];
+        amdDependencies: AmdDependency[];
+        moduleName?: string;
+        checkJsDirective?: CheckJsDirective;
+        parseDiagnostics: NativeDiagnostic[];
+        jsDocDiagnostics: NativeDiagnostic[];
+    }
+
+    /** The summary of a file parsed by `createSourceFileTree`, with the tree it keeps native. */
+    export interface NativeSourceFileTree extends NativeSourceFile {
+        /** An external holding the native tree, to read nodes from with `getTreeNodes` */
+        tree: unknown;
+        root: number;
+        /** The id of the node that makes the file a module */
+        externalModuleIndicator?: number;
+        /** The texts of the `identifiers` map of `SourceFile` */
+        identifiers: string[];
+        commentDirectives?: CommentDirective[];
+        /** The pragmas of the leading comments, in the order they appear */
+        pragmas: PragmaPseudoMapEntry[];
+    }
+
+    /**
+     * A token of `createCancellationToken`. The native operations given one poll it as they go,
+     * and fail with an error whose message is `OperationCanceled` once `requestCancellation`
+     * was called with it.
+     */
+    export type NativeCancellationToken = unknown;
+
+    /**
+     * A table of identifier texts, each stored once and referred to by id. Every file of a
+     * `parseFiles` batch interns its identifiers in the same table.
+     */
+    export type NativeAtomTable = unknown;
+
+    export function isNativeOperationCanceled(error: unknown): boolean {
+        return error instanceof Error && error.message === "OperationCanceled";
+    }
+
+    export interface NativeParseFilesOptions {
+        languageVersion: ScriptTarget;
+        /** The script kind of every file; by default each file gets the one of its extension */
+        scriptKind?: ScriptKind;
+        /** A table of `createAtomTable` to intern the identifiers in, shared with other batches */
+        atomTable?: NativeAtomTable;
+        cancellationToken?: NativeCancellationToken;
+    }
+
+    /** A file of a `parseFiles` batch; only `fileName` and `errors` are set when the file cannot be read. */
+    export interface NativeParsedFile extends Partial<NativeSourceFileTree> {
+        fileName: string;
+        /** The text of a file given by path */
+        text?: string;
+        errors: NativeDiagnostic[];
+    }
+
+    /** The number of entries each node takes in the table of `getTreeNodeTable`; the parent id is `noNativeParent` for none */
+    export const nativeNodeTableStride = 5;
+    export const noNativeParent = 0xFFFFFFFF;
+
+    /** A list of child nodes, given as node ids */
+    export interface NativeNodeArray extends TextRange {
+        hasTrailingComma: boolean;
+        nodes: number[];
+    }
+
+    /**
+     * A node of a native tree, with the properties of the node under the names `Node` uses. The
+     * child nodes are given as ids in `children`, along with lists of them: a `NodeArray`, or a
+     * plain array where `Node` uses one. The text of identifiers is given as an atom, to read
+     * with `getAtomTexts`.
+     */
+    export interface NativeNode extends TextRange {
+        id: number;
+        kind: SyntaxKind;
+        flags: NodeFlags;
+        parent?: number;
+        jsDoc?: number[];
+        atom?: number;
+        children: MapLike<number | number[] | NativeNodeArray>;
+        [property: string]: unknown;
+    }
+
+    export interface NativeTokenizeOptions {
+        /** Defaults to `ScriptTarget.ESNext` */
+        languageVersion?: ScriptTarget;
+        languageVariant?: LanguageVariant;
+        /** Whether to leave out trivia tokens; defaults to true, like `createScanner` */
+        skipTrivia?: boolean;
+        cancellationToken?: NativeCancellationToken;
+    }
+
+    /** A token of `tokenize`; `pos` is the start of the trivia before it, like `Node.pos`. */
+    export interface NativeToken extends TextRange {
+        kind: SyntaxKind;
+        start: number;
+        flags: TokenFlags;
+        /** The value of identifiers, keywords and literals */
+        value?: string;
+    }
+
+    /**
+     * The number of entries each token takes in the `tokens` of `tokenize`: its kind, `pos`,
+     * `start`, `end`, flags, and the index of its value in `values` (`noNativeTokenValue` for none).
+     */
+    export const nativeTokenEncodingStride = 6;
+    /** The version of the layout of `tokens` this compiler reads, checked against the `capabilities` of the addon */
+    export const nativeTokenEncodingVersion = 1;
+    export const noNativeTokenValue = 0xFFFFFFFF;
+
+    export interface NativeTokenization {
+        /** The tokens, in the layout of `nativeTokenEncodingStride`, in memory owned by the addon */
+        tokens: Uint32Array;
+        values: string[];
+        diagnostics: NativeDiagnostic[];
+    }
+
+    /** Reads the tokens of `tokenize` into objects, for code that prefers them to the encoded form */
+    export function decodeNativeTokens({ tokens, values }: NativeTokenization): NativeToken[] {
+        const result: NativeToken[] = [];
+        for (let i = 0; i < tokens.length; i += nativeTokenEncodingStride) {
+            const valueIndex = tokens[i + 5];
+            result.push({
+                kind: tokens[i],
+                pos: tokens[i + 1],
+                start: tokens[i + 2],
+                end: tokens[i + 3],
+                flags: tokens[i + 4],
+                value: valueIndex === noNativeTokenValue ? undefined : values[valueIndex],
+            });
+        }
+        return result;
+    }
+
+    export interface NativeConfigFileSpecs {
+        filesSpecs?: readonly string[];
+        includeSpecs?: readonly string[];
+        excludeSpecs?: readonly string[];
+        validatedIncludeSpecs?: readonly string[];
+        validatedExcludeSpecs?: readonly string[];
+    }
+
+    /**
+     * The `extends` chain of a tsconfig.json, resolved and merged like `getParsedCommandLineOfConfigFile`.
+     * Only `errors` is set when the config file itself cannot be read.
+     */
+    export interface NativeParsedCommandLine {
+        options?: CompilerOptions;
+        watchOptions?: WatchOptions;
+        projectReferences?: readonly ProjectReference[];
+        configFileSpecs?: NativeConfigFileSpecs;
+        compileOnSave?: boolean;
+        extendedSourceFiles?: readonly string[];
+        errors: NativeDiagnostic[];
+    }
+
+    /** Like the `OutliningSpan` of the services, which the compiler cannot name */
+    export interface NativeOutliningSpan {
+        textSpan: TextSpan;
+        hintSpan: TextSpan;
+        bannerText: string;
+        autoCollapse: boolean;
+        kind: "comment" | "region" | "code" | "imports";
+    }
+
+    /** Like the `NavigationTree` of the services, which the compiler cannot name; `kind` is a `ScriptElementKind` and `kindModifiers` joins `ScriptElementKindModifier`s with commas */
+    export interface NativeNavigationTree {
+        text: string;
+        kind: string;
+        kindModifiers: string;
+        spans: TextSpan[];
+        nameSpan: TextSpan | undefined;
+        childItems?: NativeNavigationTree[];
+    }
+
+    /** The options of `tokenize` an addon honors: the standard and JSX language variants, and reporting trivia */
+    export type NativeScannerMode = "standard" | "jsx" | "trivia";
+
+    /** What an addon implements, which the compiler checks before using it */
+    export interface NativeCapabilities {
+        /** The names of the functions of the addon */
+        functions: readonly string[];
+        scannerModes: readonly NativeScannerMode[];
+        /** The version of the layout of the `tokens` of `tokenize` */
+        tokenEncodingVersion: number;
     }
 
     // exported addon properties, function, classes, values
     export interface AddonExports {
+        /** The version of the crate the addon was built from */
+        readonly version: string;
+        readonly capabilities: NativeCapabilities;
         lookupInUnicodeMap(code: number, map: readonly number[]): boolean;
-        computeLineStarts(text: string): number[];
+        computeLineStarts(text: string): Uint32Array;
+        computeLineStartsAsync(text: string): Promise<Uint32Array>;
+        /** Scans the text up to the end of file token, without the rescans the parser would do */
+        tokenize(text: string, options?: NativeTokenizeOptions): NativeTokenization;
+        tokenizeAsync(text: string, options?: NativeTokenizeOptions): Promise<NativeTokenization>;
+        parseJsonText(fileName: string, sourceText: string): NativeJsonSourceFile;
+        parseSourceFile(fileName: string, sourceText: string, languageVersion: ScriptTarget, scriptKind?: ScriptKind, cancellationToken?: NativeCancellationToken): NativeSourceFile;
+        parseSourceFileAsync(fileName: string, sourceText: string, languageVersion: ScriptTarget, scriptKind?: ScriptKind, cancellationToken?: NativeCancellationToken): Promise<NativeSourceFile>;
+        createSourceFileTree(fileName: string, sourceText: string, languageVersion: ScriptTarget, scriptKind?: ScriptKind, cancellationToken?: NativeCancellationToken): NativeSourceFileTree;
+        createSourceFileTreeAsync(fileName: string, sourceText: string, languageVersion: ScriptTarget, scriptKind?: ScriptKind, cancellationToken?: NativeCancellationToken): Promise<NativeSourceFileTree>;
+        getTreeNodes(tree: unknown, ids: readonly number[]): NativeNode[];
+        /** The kind, `pos`, `end`, flags and parent id of every node of the tree, in the layout of `nativeNodeTableStride` */
+        getTreeNodeTable(tree: unknown): Uint32Array;
+        /** The texts of atoms of the table the identifiers of the tree are interned in */
+        getAtomTexts(tree: unknown, atoms: readonly number[]): string[];
+        createAtomTable(): NativeAtomTable;
+        /** Reads and parses the files on all cores; the files are paths, or texts already in memory */
+        parseFiles(files: readonly (string | { fileName: string; text: string })[], options: NativeParseFilesOptions): NativeParsedFile[];
+        parseFilesAsync(files: readonly (string | { fileName: string; text: string })[], options: NativeParseFilesOptions): Promise<NativeParsedFile[]>;
+        createCancellationToken(): NativeCancellationToken;
+        requestCancellation(cancellationToken: NativeCancellationToken): void;
+        parseTsconfig(configFileName: string, currentDirectory?: string): NativeParsedCommandLine;
+        /** Parses every config with one cache of extended configs, like the configs of a `tsc -b` build */
+        parseTsconfigs(configFileNames: readonly string[], currentDirectory?: string): NativeParsedCommandLine[];
+        /** Like `getEncodedSyntacticClassifications` of the services, for a tree of `createSourceFileTree`; `endOfLineState` is always `EndOfLineState.None` */
+        getEncodedSyntacticClassifications(tree: unknown, start: number, length: number, cancellationToken?: NativeCancellationToken): { spans: Uint32Array; endOfLineState: number };
+        /** Like `getOutliningSpans` of the services, for a tree of `createSourceFileTree` */
+        getOutliningSpans(tree: unknown, cancellationToken?: NativeCancellationToken): NativeOutliningSpan[];
+        /** Like `getNavigationTree` of the services, for a tree of `createSourceFileTree` */
+        getNavigationTree(tree: unknown, cancellationToken?: NativeCancellationToken): NativeNavigationTree;
+    }
+
+    export type NativeFunctionName = { [K in keyof AddonExports]: AddonExports[K] extends (...args: any[]) => any ? K : never }[keyof AddonExports];
+
+    const nativeAddon = loadNativeAddon();
+    /** The addon; only call the functions `isNativeFunctionAvailable` accepts */
+    export const native = nativeAddon as AddonExports;
+    const availableNativeFunctions = getAvailableNativeFunctions(nativeAddon);
+
+    // An addon left in the output folder by an older build can lack functions the compiler calls,
+    // or encode tokens in another layout; the features it can't serve use their JS implementations.
+    function getAvailableNativeFunctions(addon: Partial<AddonExports>): Set<string> {
+        const result = new Set<string>();
+        const capabilities = addon.capabilities;
+        // An addon from before capabilities were exported can't be checked, so none of it is used.
+        if (!capabilities) {
+            return result;
+        }
+        for (const name of capabilities.functions) {
+            if (typeof addon[name as NativeFunctionName] !== "function") {
+                continue;
+            }
+            if ((name === "tokenize" || name === "tokenizeAsync") && capabilities.tokenEncodingVersion !== nativeTokenEncodingVersion) {
+                continue;
+            }
+            result.add(name);
+        }
+        return result;
+    }
+
+    /** Whether the addon has a function the compiler can call, rather than falling back to JS */
+    export function isNativeFunctionAvailable(name: NativeFunctionName): boolean {
+        return availableNativeFunctions.has(name);
+    }
+
+    /** Whether `tokenize` can be called with the options of a scanner mode */
+    export function isNativeScannerModeAvailable(mode: NativeScannerMode): boolean {
+        return isNativeFunctionAvailable("tokenize") && contains(native.capabilities.scannerModes, mode);
     }
-    export const native: AddonExports = loadNativeAddon();
 }
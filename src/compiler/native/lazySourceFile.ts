@@ -0,0 +1,235 @@
+/* @internal */
+namespace ts {
+    /**
+     * Parses a file with the native parser into a `SourceFile` whose nodes stay in the native tree
+     * until they are read: a node is only built when the property holding it (or its `parent`) is
+     * first read, so code that walks part of the tree with `forEachChild` only pays for that part.
+     */
+    export function createLazySourceFile(fileName: string, sourceText: string, languageVersion: ScriptTarget, scriptKind?: ScriptKind): SourceFile {
+        if (!canUseNativeTrees("createSourceFileTree")) {
+            return createSourceFile(fileName, sourceText, languageVersion, /*setParentNodes*/ true, scriptKind);
+        }
+        return createSourceFileFromTree(sourceText, languageVersion, native.createSourceFileTree(fileName, sourceText, languageVersion, scriptKind), []);
+    }
+
+    /**
+     * Reads and parses a batch of files in parallel, like `createLazySourceFile` does for a
+     * single file. The files are paths, or texts already in memory; a file that cannot be read
+     * gets the errors reading it instead of a `SourceFile`. Canceling the cancellation token
+     * throws an `OperationCanceledException`.
+     */
+    export function createLazySourceFiles(files: readonly (string | { fileName: string; text: string })[], languageVersion: ScriptTarget, cancellationToken?: NativeCancellationToken): { sourceFile?: SourceFile; errors: Diagnostic[] }[] {
+        if (!canUseNativeTrees("parseFiles")) {
+            return map(files, file => {
+                const fileName = isString(file) ? file : file.fileName;
+                const text = isString(file) ? sys.readFile(file) : file.text;
+                return text === undefined ?
+                    { errors: [createCompilerDiagnostic(Diagnostics.Cannot_read_file_0, fileName)] } :
+                    { sourceFile: createSourceFile(fileName, text, languageVersion, /*setParentNodes*/ true), errors: [] };
+            });
+        }
+        let results: NativeParsedFile[];
+        try {
+            results = native.parseFiles(files, { languageVersion, cancellationToken });
+        }
+        catch (e) {
+            throw isNativeOperationCanceled(e) ? new OperationCanceledException() : e;
+        }
+        // The files of the batch share an atom table, so they can share the texts read from it too.
+        const atomTexts: string[] = [];
+        return map(results, (result, index) => {
+            const file = files[index];
+            const sourceFile = result.tree === undefined ? undefined :
+                createSourceFileFromTree(isString(file) ? result.text! : file.text, languageVersion, result as NativeSourceFileTree, atomTexts);
+            return { sourceFile, errors: map(result.errors, createDiagnostic) };
+        });
+    }
+
+    // Reading the nodes of a tree takes these functions too; the JS parser stands in for an addon without them.
+    function canUseNativeTrees(parse: "createSourceFileTree" | "parseFiles") {
+        return isNativeFunctionAvailable(parse) && isNativeFunctionAvailable("getTreeNodes") && isNativeFunctionAvailable("getAtomTexts");
+    }
+
+    function createSourceFileFromTree(sourceText: string, languageVersion: ScriptTarget, result: NativeSourceFileTree, atomTexts: string[]): SourceFile {
+        const fileName = result.fileName;
+        const tree = new LazyTree(result.tree, atomTexts);
+        const sourceFile = tree.getNode(result.root) as Mutable<SourceFile>;
+        sourceFile.fileName = fileName;
+        sourceFile.text = sourceText;
+        sourceFile.languageVersion = languageVersion;
+        sourceFile.languageVariant = getLanguageVariant(result.scriptKind);
+        sourceFile.scriptKind = result.scriptKind;
+        sourceFile.isDeclarationFile = result.isDeclarationFile;
+        sourceFile.hasNoDefaultLib = result.hasNoDefaultLib;
+        sourceFile.nodeCount = result.nodeCount;
+        sourceFile.identifierCount = result.identifierCount;
+        sourceFile.referencedFiles = result.referencedFiles;
+        sourceFile.typeReferenceDirectives = result.typeReferenceDirectives;
+        sourceFile.libReferenceDirectives = result.libReferenceDirectives;
+        sourceFile.amdDependencies = result.amdDependencies;
+        sourceFile.moduleName = result.moduleName;
+        sourceFile.checkJsDirective = result.checkJsDirective;
+        sourceFile.externalModuleIndicator = result.externalModuleIndicator === undefined ? undefined : tree.getNode(result.externalModuleIndicator);
+        sourceFile.identifiers = arrayToMap(result.identifiers, identity);
+        sourceFile.commentDirectives = result.commentDirectives;
+        sourceFile.pragmas = createPragmaMap(result.pragmas);
+        sourceFile.parseDiagnostics = map(result.parseDiagnostics, diagnostic => createDiagnosticWithLocation(sourceFile, diagnostic));
+        sourceFile.bindDiagnostics = [];
+        sourceFile.jsDocDiagnostics = map(result.jsDocDiagnostics, diagnostic => createDiagnosticWithLocation(sourceFile, diagnostic));
+        return sourceFile;
+    }
+
+    /** Groups the pragmas by name like `processCommentPragmas`: a name given more than once maps to the list of its arguments */
+    function createPragmaMap(pragmas: readonly PragmaPseudoMapEntry[]): ReadonlyPragmaMap {
+        const pragmaMap = new Map() as PragmaMap;
+        for (const pragma of pragmas) {
+            if (pragmaMap.has(pragma.name)) {
+                const currentValue = pragmaMap.get(pragma.name);
+                if (currentValue instanceof Array) {
+                    currentValue.push(pragma.args);
+                }
+                else {
+                    pragmaMap.set(pragma.name, [currentValue, pragma.args]);
+                }
+                continue;
+            }
+            pragmaMap.set(pragma.name, pragma.args);
+        }
+        return pragmaMap;
+    }
+
+    function createDiagnostic(diagnostic: NativeDiagnostic): Diagnostic {
+        return {
+            file: undefined,
+            start: diagnostic.start,
+            length: diagnostic.length,
+            messageText: diagnostic.messageText,
+            category: diagnostic.category,
+            code: diagnostic.code,
+        };
+    }
+
+    function createDiagnosticWithLocation(file: SourceFile, diagnostic: NativeDiagnostic): DiagnosticWithLocation {
+        return {
+            file,
+            start: diagnostic.start!,
+            length: diagnostic.length!,
+            messageText: diagnostic.messageText,
+            category: diagnostic.category,
+            code: diagnostic.code,
+        };
+    }
+
+    /** The nodes of a native tree that were read so far, by id, and the texts of its atoms */
+    class LazyTree {
+        private readonly nodes: (Node | undefined)[] = [];
+        private readonly nodeConstructor = objectAllocator.getNodeConstructor();
+        private readonly tokenConstructor = objectAllocator.getTokenConstructor();
+        private readonly identifierConstructor = objectAllocator.getIdentifierConstructor();
+        private readonly privateIdentifierConstructor = objectAllocator.getPrivateIdentifierConstructor();
+        private readonly sourceFileConstructor = objectAllocator.getSourceFileConstructor();
+
+        constructor(private readonly tree: unknown, private readonly atomTexts: string[]) {
+        }
+
+        getNode(id: number): Node {
+            return this.nodes[id] || this.getNodes([id])[0];
+        }
+
+        /** Builds the nodes not read yet with a single call into the addon */
+        getNodes(ids: readonly number[]): Node[] {
+            const missing = filter(ids, id => !this.nodes[id]);
+            if (missing.length) {
+                const records = native.getTreeNodes(this.tree, missing);
+                this.readAtomTexts(records);
+                for (const record of records) {
+                    this.nodes[record.id] = this.createNode(record);
+                }
+            }
+            return map(ids, id => this.nodes[id]!);
+        }
+
+        private createNode(record: NativeNode): Node {
+            const { kind, pos, end } = record;
+            const node = kind === SyntaxKind.SourceFile ? new this.sourceFileConstructor(kind, pos, end) :
+                kind === SyntaxKind.Identifier ? new this.identifierConstructor(kind, pos, end) :
+                kind === SyntaxKind.PrivateIdentifier ? new this.privateIdentifierConstructor(kind, pos, end) :
+                isNodeKind(kind) ? new this.nodeConstructor(kind, pos, end) :
+                new this.tokenConstructor(kind, pos, end);
+            const properties = node as Node & MapLike<unknown>;
+            for (const key in record) {
+                switch (key) {
+                    case "id":
+                    case "kind":
+                    case "pos":
+                    case "end":
+                    case "children":
+                    case "parent":
+                    case "jsDoc":
+                        break;
+                    case "atom":
+                        // Like the factory, only set `escapedText`; `idText` reads the text from it.
+                        properties.escapedText = escapeLeadingUnderscores(this.atomTexts[record.atom!]);
+                        break;
+                    default:
+                        properties[key] = record[key];
+                }
+            }
+            if (record.parent !== undefined) {
+                const parent = record.parent;
+                this.defineLazyProperty(node, "parent", () => this.getNode(parent));
+            }
+            if (record.jsDoc) {
+                const jsDoc = record.jsDoc;
+                this.defineLazyProperty(node, "jsDoc", () => this.getNodes(jsDoc));
+            }
+            for (const name in record.children) {
+                const child = record.children[name];
+                this.defineLazyProperty(node, name, () => this.resolveChild(child));
+            }
+            return node;
+        }
+
+        /** Reads the texts of the atoms of the records that weren't read yet, with a single call into the addon */
+        private readAtomTexts(records: readonly NativeNode[]) {
+            const missing: number[] = [];
+            const isMissing: true[] = [];
+            for (const { atom } of records) {
+                if (atom !== undefined && this.atomTexts[atom] === undefined && !isMissing[atom]) {
+                    isMissing[atom] = true;
+                    missing.push(atom);
+                }
+            }
+            if (missing.length) {
+                const texts = native.getAtomTexts(this.tree, missing);
+                for (let i = 0; i < missing.length; i++) {
+                    this.atomTexts[missing[i]] = texts[i];
+                }
+            }
+        }
+
+        private resolveChild(child: number | number[] | NativeNodeArray) {
+            if (typeof child === "number") {
+                return this.getNode(child);
+            }
+            if (isArray(child)) {
+                return this.getNodes(child);
+            }
+            return setTextRangePosEnd(factory.createNodeArray(this.getNodes(child.nodes), child.hasTrailingComma), child.pos, child.end);
+        }
+
+        /** Defines a property that is computed when first read, and then kept like a plain property */
+        private defineLazyProperty(node: Node, name: string, compute: () => unknown) {
+            const define = (value: unknown) => {
+                Object.defineProperty(node, name, { value, writable: true, enumerable: true, configurable: true });
+                return value;
+            };
+            Object.defineProperty(node, name, {
+                get: () => define(compute()),
+                set: define,
+                enumerable: true,
+                configurable: true,
+            });
+        }
+    }
+}
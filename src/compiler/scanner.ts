@@ -293,7 +293,36 @@ namespace ts {
     const commentDirectiveRegExMultiLine = /^(?:\/|\*)*\s*@(ts-expect-error|ts-ignore)/;
 
     function lookupInUnicodeMap(code: number, map: readonly number[]): boolean {
-        return native.lookupInUnicodeMap(code, map);
+        if (isNativeFunctionAvailable("lookupInUnicodeMap")) {
+            return native.lookupInUnicodeMap(code, map);
+        }
+        // Bail out quickly if it couldn't possibly be in the map.
+        if (code < map[0]) {
+            return false;
+        }
+
+        // Perform binary search in one of the Unicode range maps
+        let lo = 0;
+        let hi: number = map.length;
+        let mid: number;
+
+        while (lo + 1 < hi) {
+            mid = lo + (hi - lo) / 2;
+            // mid has to be even to catch a range's beginning
+            mid -= mid % 2;
+            if (map[mid] <= code && code <= map[mid + 1]) {
+                return true;
+            }
+
+            if (code < map[mid]) {
+                hi = mid;
+            }
+            else {
+                lo = mid + 2;
+            }
+        }
+
+        return false;
     }
 
     /* @internal */ export function isUnicodeIdentifierStart(code: number, languageVersion: ScriptTarget | undefined) {
@@ -330,33 +359,34 @@ namespace ts {
 
     /* @internal */
     export function computeLineStarts(text: string): number[] {
-        // const result: number[] = new Array();
-        // let pos = 0;
-        // let lineStart = 0;
-        // while (pos < text.length) {
-        //     const ch = text.charCodeAt(pos);
-        //     pos++;
-        //     switch (ch) {
-        //         case CharacterCodes.carriageReturn:
-        //             if (text.charCodeAt(pos) === CharacterCodes.lineFeed) {
-        //                 pos++;
-        //             }
-        //         // falls through
-        //         case CharacterCodes.lineFeed:
-        //             result.push(lineStart);
-        //             lineStart = pos;
-        //             break;
-        //         default:
-        //             if (ch > CharacterCodes.maxAsciiCharacter && isLineBreak(ch)) {
-        //                 result.push(lineStart);
-        //                 lineStart = pos;
-        //             }
-        //             break;
-        //     }
-        // }
-        // result.push(lineStart);
-        // return result;
-        return native.computeLineStarts(text);
+        // The line starts of the addon are a `Uint32Array` it owns, while the line maps of the
+        // compiler are arrays, so this stays in JS rather than copying them over one by one.
+        const result: number[] = new Array();
+        let pos = 0;
+        let lineStart = 0;
+        while (pos < text.length) {
+            const ch = text.charCodeAt(pos);
+            pos++;
+            switch (ch) {
+                case CharacterCodes.carriageReturn:
+                    if (text.charCodeAt(pos) === CharacterCodes.lineFeed) {
+                        pos++;
+                    }
+                // falls through
+                case CharacterCodes.lineFeed:
+                    result.push(lineStart);
+                    lineStart = pos;
+                    break;
+                default:
+                    if (ch > CharacterCodes.maxAsciiCharacter && isLineBreak(ch)) {
+                        result.push(lineStart);
+                        lineStart = pos;
+                    }
+                    break;
+            }
+        }
+        result.push(lineStart);
+        return result;
     }
 
     export function getPositionOfLineAndCharacter(sourceFile: SourceFileLike, line: number, character: number): number;